@@ -1,6 +1,6 @@
 //! Validation of business rules
 
-use crate::Invoice;
+use crate::{ApplicableTradeSettlementFinancialCard, CountryCode, ReasonCode, Invoice, ReferencedDocumentTypeCode, PaymentMeansCode, SpecifiedTaxRegistration, TaxRegistrationSchemeCode, VATCategoryCode};
 
 pub struct BusinessRuleViolation {
     pub rule_id: String,
@@ -27,48 +27,48 @@ const BUSINESS_RULES: &[fn(&Invoice) -> Result<(), BusinessRuleViolation>] = &[
     br_14,
     br_15,
     // br_16,
-    // br_17,
-    // br_18,
-    // br_19,
-    // br_20,
+    br_17,
+    br_18,
+    br_19,
+    br_20,
     // br_21,
     // br_22,
     // br_23,
     // br_24,
     // br_25,
-    // br_26,
-    // br_27,
-    // br_28,
-    // br_29,
-    // br_30,
+    br_26,
+    br_27,
+    br_28,
+    br_29,
+    br_30,
     // br_31,
     // br_32,
-    // br_33,
+    br_33,
     // br_36,
     // br_37,
-    // br_38,
+    br_38,
     // br_41,
-    // br_42,
+    br_42,
     // br_43,
-    // br_44,
+    br_44,
     // br_45,
     // br_46,
     // br_47,
     // br_48,
-    // br_49,
-    // br_50,
-    // br_51,
-    // br_52,
-    // br_53,
-    // br_54,
+    br_49,
+    br_50,
+    br_51,
+    br_52,
+    br_53,
+    br_54,
     // br_55,
-    // br_56,
+    br_56,
     // br_57,
-    // br_61,
+    br_61,
     // br_62,
     // br_63,
     // br_64,
-    // br_65,
+    br_65,
     // br_co_03,
     br_co_04,
     // br_co_05,
@@ -85,14 +85,23 @@ const BUSINESS_RULES: &[fn(&Invoice) -> Result<(), BusinessRuleViolation>] = &[
     br_co_16,
     br_co_17,
     // br_co_18,
-    // br_co_19,
-    // br_co_20,
+    br_co_19,
+    br_co_20,
     // br_co_21,
     // br_co_22,
     // br_co_23,
     // br_co_24,
     br_co_25,
-    // br_co_26,
+    br_co_26,
+    br_s_02,
+    br_z_02,
+    br_e_02,
+    br_ae_02,
+    br_ic_02,
+    br_g_02,
+    br_o_02,
+    br_cl_19,
+    br_cl_20,
 ];
 
 /// Validate the business rules of a ZUGFeRD invoice
@@ -156,6 +165,404 @@ fn br_15(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     br_115.discard_value().check(rule, "BR-115")
 }
 
+/// BR-17: The Payee name (BT-59) shall be provided in the Invoice, if the Payee (BG-10) is different from the Seller (BG-4).
+fn br_17(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-17", "The Payee name (BT-59) shall be provided in the Invoice, if the Payee (BG-10) is different from the Seller (BG-4).");
+    if let Some(bg_10) = &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.payee_trade_party {
+        bg_10.name.check(rule, "BT-59")?;
+    }
+    Ok(())
+}
+
+/// BR-18: The Seller tax representative name (BT-62) shall be provided in the Invoice, if the Seller (BG-4) has a Seller tax representative party (BG-11).
+fn br_18(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-18", "The Seller tax representative name (BT-62) shall be provided in the Invoice, if the Seller (BG-4) has a Seller tax representative party (BG-11).");
+    if let Some(bg_11) = &invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.seller_tax_representative_trade_party {
+        bg_11.name.check(rule, "BT-62")?;
+    }
+    Ok(())
+}
+
+/// BR-19: The Seller tax representative postal address (BG-12) shall be provided in the Invoice, if the Seller (BG-4) has a Seller tax representative party (BG-11).
+fn br_19(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-19", "The Seller tax representative postal address (BG-12) shall be provided in the Invoice, if the Seller (BG-4) has a Seller tax representative party (BG-11).");
+    if let Some(bg_11) = &invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.seller_tax_representative_trade_party {
+        bg_11.postal_trade_address.discard_value().check(rule, "BG-12")?;
+    }
+    Ok(())
+}
+
+/// BR-20: The Seller tax representative postal address (BG-12) shall contain a Tax representative country code (BT-69), if the Seller (BG-4) has a Seller tax representative party (BG-11).
+fn br_20(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-20", "The Seller tax representative postal address (BG-12) shall contain a Tax representative country code (BT-69), if the Seller (BG-4) has a Seller tax representative party (BG-11).");
+    let bg_12 = invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.seller_tax_representative_trade_party.as_ref()
+        .and_then(|bg_11| bg_11.postal_trade_address.as_ref());
+    if bg_12.is_some_and(|bg_12| bg_12.country_id == CountryCode::NotSet) {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "'BT-69' is missing".to_string(),
+            fields: vec![],
+        });
+    }
+    Ok(())
+}
+
+/// BR-49: A Payment instruction (BG-16) shall specify the Payment means type code (BT-81).
+fn br_49(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-49", "A Payment instruction (BG-16) shall specify the Payment means type code (BT-81).");
+    for bg_16 in &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_payment_means {
+        if bg_16.type_code.as_str().trim().is_empty() {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: "Payment means type code is empty".to_string(),
+                fields: vec![],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// BR-50: A Payment account identifier (BT-84) shall be present if Credit transfer (BG-17) information is provided in the Invoice.
+fn br_50(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-50", "A Payment account identifier (BT-84) shall be present if Credit transfer (BG-17) information is provided in the Invoice.");
+    for bg_16 in &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_payment_means {
+        if let Some(bg_17) = bg_16.payee_party_creditor_financial_account {
+            if bg_17.ibanid.is_none() && bg_17.proprietary_id.is_none() {
+                return Err(BusinessRuleViolation {
+                    rule_id: rule.0.to_string(),
+                    rule_text: rule.1.to_string(),
+                    message: "Payment account identifier is missing".to_string(),
+                    fields: vec![("BT-81".to_string(), bg_16.type_code.as_str().to_string())],
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// BR-51: In accordance with card payments security standards an invoice should never include a full card primary account number (BT-87). At the moment PCI Security Standards Council has defined that the first 6 digits and last 4 digits are the maximum number of digits to be shown.
+fn br_51(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-51", "In accordance with card payments security standards an invoice should never include a full card primary account number (BT-87). At the moment PCI Security Standards Council has defined that the first 6 digits and last 4 digits are the maximum number of digits to be shown.");
+    for bg_16 in &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_payment_means {
+        if let Some(bg_18) = bg_16.applicable_trade_settlement_financial_card {
+            let digits = bg_18.id.chars().filter(|c| c.is_ascii_digit()).count();
+            if digits > ApplicableTradeSettlementFinancialCard::MAX_VISIBLE_DIGITS {
+                return Err(BusinessRuleViolation {
+                    rule_id: rule.0.to_string(),
+                    rule_text: rule.1.to_string(),
+                    message: format!("Card account number shows {} digits, at most 10 are allowed", digits),
+                    fields: vec![],
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// BR-26: Each Invoice line (BG-25) shall contain the Item net price (BT-146).
+fn br_26(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    // Should never fail because structure is enforced, but maybe something changes in the future
+    let rule = ("BR-26", "Each Invoice line (BG-25) shall contain the Item net price (BT-146).");
+    for line in &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items {
+        if line.specified_line_trade_agreement.net_price_product_trade_price.charge_amount.is_nan() {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: "Item net price is missing".to_string(),
+                fields: vec![("line_id".to_string(), line.associated_document_line_document.line_id.to_string())],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// BR-27: The Item net price (BT-146) shall NOT be negative.
+fn br_27(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-27", "The Item net price (BT-146) shall NOT be negative.");
+    for line in &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items {
+        let bt_146 = line.specified_line_trade_agreement.net_price_product_trade_price.charge_amount;
+        if bt_146 < 0.0 {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: "Item net price is negative".to_string(),
+                fields: vec![
+                    ("line_id".to_string(), line.associated_document_line_document.line_id.to_string()),
+                    ("BT-146".to_string(), format!("{:.2}", bt_146)),
+                ],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// BR-28: The Item gross price (BT-148) shall NOT be negative.
+fn br_28(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-28", "The Item gross price (BT-148) shall NOT be negative.");
+    for line in &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items {
+        let Some(bg_148) = line.specified_line_trade_agreement.gross_price_product_trade_price.as_ref() else {
+            continue;
+        };
+        if bg_148.charge_amount < 0.0 {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: "Item gross price is negative".to_string(),
+                fields: vec![
+                    ("line_id".to_string(), line.associated_document_line_document.line_id.to_string()),
+                    ("BT-148".to_string(), format!("{:.2}", bg_148.charge_amount)),
+                ],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// BR-29: If both Invoicing period start date (BT-73) and Invoicing period end date (BT-74) are given then the Invoicing period end date (BT-74) shall be later or equal to the Invoicing period start date (BT-73).
+fn br_29(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-29", "If both Invoicing period start date (BT-73) and Invoicing period end date (BT-74) are given then the Invoicing period end date (BT-74) shall be later or equal to the Invoicing period start date (BT-73).");
+    let Some(bg_14) = &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.billing_specified_period else {
+        return Ok(());
+    };
+    if let (Some(bt_73), Some(bt_74)) = (&bg_14.start_date_time, &bg_14.end_date_time) {
+        if bt_74.date_time_string.to_naive_date() < bt_73.date_time_string.to_naive_date() {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: "Invoicing period ends before it starts".to_string(),
+                fields: vec![
+                    ("BT-73".to_string(), bt_73.date_time_string.to_string()),
+                    ("BT-74".to_string(), bt_74.date_time_string.to_string()),
+                ],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// BR-30: If both Invoice line period start date (BT-134) and Invoice line period end date (BT-135) are given then the Invoice line period end date (BT-135) shall be later or equal to the Invoice line period start date (BT-134).
+fn br_30(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-30", "If both Invoice line period start date (BT-134) and Invoice line period end date (BT-135) are given then the Invoice line period end date (BT-135) shall be later or equal to the Invoice line period start date (BT-134).");
+    for line in &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items {
+        let Some(bg_26) = &line.specified_line_trade_settlement.billing_specified_period else {
+            continue;
+        };
+        if let (Some(bt_134), Some(bt_135)) = (&bg_26.start_date_time, &bg_26.end_date_time) {
+            if bt_135.date_time_string.to_naive_date() < bt_134.date_time_string.to_naive_date() {
+                return Err(BusinessRuleViolation {
+                    rule_id: rule.0.to_string(),
+                    rule_text: rule.1.to_string(),
+                    message: "Invoice line period ends before it starts".to_string(),
+                    fields: vec![
+                        ("line_id".to_string(), line.associated_document_line_document.line_id.to_string()),
+                        ("BT-134".to_string(), bt_134.date_time_string.to_string()),
+                        ("BT-135".to_string(), bt_135.date_time_string.to_string()),
+                    ],
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// BR-33: Each Document level allowance (BG-20) shall have a Document level allowance reason (BT-97) or a Document level allowance reason code (BT-98).
+fn br_33(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-33", "Each Document level allowance (BG-20) shall have a Document level allowance reason (BT-97) or a Document level allowance reason code (BT-98).");
+    for bg_20 in invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_allowance_charge.iter()
+        .filter(|allowance_charge| !allowance_charge.is_charge())
+    {
+        bg_20.reason.discard_value().or(bg_20.reason_code.discard_value()).check(rule, "BT-97")?;
+    }
+    Ok(())
+}
+
+/// BR-38: Each Document level charge (BG-21) shall have a Document level charge reason (BT-104) or a Document level charge reason code (BT-105).
+fn br_38(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-38", "Each Document level charge (BG-21) shall have a Document level charge reason (BT-104) or a Document level charge reason code (BT-105).");
+    for bg_21 in invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_allowance_charge.iter()
+        .filter(|allowance_charge| allowance_charge.is_charge())
+    {
+        bg_21.reason.discard_value().or(bg_21.reason_code.discard_value()).check(rule, "BT-104")?;
+    }
+    Ok(())
+}
+
+/// BR-42: Each Invoice line allowance (BG-27) shall have an Invoice line allowance reason (BT-139) or an Invoice line allowance reason code (BT-140).
+fn br_42(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-42", "Each Invoice line allowance (BG-27) shall have an Invoice line allowance reason (BT-139) or an Invoice line allowance reason code (BT-140).");
+    for line in &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items {
+        for bg_27 in line.specified_line_trade_settlement.specified_trade_allowance_charge.iter()
+            .filter(|allowance_charge| !allowance_charge.charge_indicator.indicator)
+        {
+            bg_27.reason.discard_value().or(bg_27.reason_code.discard_value()).check(rule, "BT-139")?;
+        }
+    }
+    Ok(())
+}
+
+/// BR-44: Each Invoice line charge (BG-28) shall have an Invoice line charge reason (BT-144) or an Invoice line charge reason code (BT-145).
+fn br_44(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-44", "Each Invoice line charge (BG-28) shall have an Invoice line charge reason (BT-144) or an Invoice line charge reason code (BT-145).");
+    for line in &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items {
+        for bg_28 in line.specified_line_trade_settlement.specified_trade_allowance_charge.iter()
+            .filter(|allowance_charge| allowance_charge.charge_indicator.indicator)
+        {
+            bg_28.reason.discard_value().or(bg_28.reason_code.discard_value()).check(rule, "BT-144")?;
+        }
+    }
+    Ok(())
+}
+
+/// BR-52: Each Additional supporting document (BG-24) shall contain a Supporting document reference (BT-122).
+fn br_52(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-52", "Each Additional supporting document (BG-24) shall contain a Supporting document reference (BT-122).");
+    for bg_24 in invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.additional_referenced_document.iter()
+        .filter(|document| document.type_code == ReferencedDocumentTypeCode::RelatedDocument)
+    {
+        if bg_24.issuer_assigned_id.trim().is_empty() {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: "'BT-122' is missing".to_string(),
+                fields: vec![("BT-123".to_string(), bg_24.name.unwrap_or_default().to_string())],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// BR-53: If the VAT accounting currency code (BT-6) is present, then the Invoice total VAT amount in accounting currency (BT-111) shall be provided.
+fn br_53(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-53", "If the VAT accounting currency code (BT-6) is present, then the Invoice total VAT amount in accounting currency (BT-111) shall be provided.");
+    let settlement = &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement;
+    if let Some(bt_6) = settlement.tax_currency_code {
+        let bt_111 = settlement.specified_trade_settlement_header_monetary_summation.tax_total_amount_in_tax_currency.clone().check(rule, "BT-111")?;
+        if bt_111.currency_id != bt_6 {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: format!("Total VAT amount in accounting currency is given in {} instead of {}", bt_111.currency_id.as_str(), bt_6.as_str()),
+                fields: vec![],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// BR-56: Each Seller tax representative party (BG-11) shall have a Seller tax representative VAT identifier (BT-63).
+fn br_56(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-56", "Each Seller tax representative party (BG-11) shall have a Seller tax representative VAT identifier (BT-63).");
+    if invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.seller_tax_representative_trade_party.is_some()
+        && !has_tax_representative_vat_identifier(invoice)
+    {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "'BT-63' is missing".to_string(),
+            fields: vec![],
+        });
+    }
+    Ok(())
+}
+
+/// BR-54: Each Item attribute (BG-32) shall contain an Item attribute name (BT-160) and an Item attribute value (BT-161).
+fn br_54(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-54", "Each Item attribute (BG-32) shall contain an Item attribute name (BT-160) and an Item attribute value (BT-161).");
+    for line in &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items {
+        for bg_32 in &line.specified_trade_product.applicable_product_characteristic {
+            if bg_32.description.trim().is_empty() || bg_32.value.trim().is_empty() {
+                return Err(BusinessRuleViolation {
+                    rule_id: rule.0.to_string(),
+                    rule_text: rule.1.to_string(),
+                    message: "Item attribute without name or value".to_string(),
+                    fields: vec![
+                        ("line_id".to_string(), line.associated_document_line_document.line_id.to_string()),
+                        ("BT-160".to_string(), bg_32.description.to_string()),
+                        ("BT-161".to_string(), bg_32.value.to_string()),
+                    ],
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// BR-61: If the Payment means type code (BT-81) means SEPA credit transfer, Local credit transfer or Non-SEPA international credit transfer, the Payment account identifier (BT-84) shall be present.
+fn br_61(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-61", "If the Payment means type code (BT-81) means SEPA credit transfer, Local credit transfer or Non-SEPA international credit transfer, the Payment account identifier (BT-84) shall be present.");
+    for bg_16 in &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_payment_means {
+        if !matches!(bg_16.type_code, PaymentMeansCode::CreditTransfer | PaymentMeansCode::SepaCreditTransfer) {
+            continue;
+        }
+        let bt_84_present = bg_16.payee_party_creditor_financial_account
+            .is_some_and(|bg_17| bg_17.ibanid.is_some() || bg_17.proprietary_id.is_some());
+        if !bt_84_present {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: "Payment account identifier is missing".to_string(),
+                fields: vec![("BT-81".to_string(), bg_16.type_code.as_str().to_string())],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// BR-65: The Item classification identifier identification scheme identifier (BT-158-1) shall be present.
+fn br_65(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-65", "The Item classification identifier identification scheme identifier (BT-158-1) shall be present.");
+    for line in &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items {
+        for bt_158 in &line.specified_trade_product.designated_product_classification {
+            if bt_158.class_code.list_id.trim().is_empty() {
+                return Err(BusinessRuleViolation {
+                    rule_id: rule.0.to_string(),
+                    rule_text: rule.1.to_string(),
+                    message: "'BT-158-1' is missing".to_string(),
+                    fields: vec![
+                        ("line_id".to_string(), line.associated_document_line_document.line_id.to_string()),
+                        ("BT-158".to_string(), bt_158.class_code.value.to_string()),
+                    ],
+                });
+            }
+        }
+    }
+    Ok(())
+}
+
+/// BR-CL-19: Coded allowance reasons MUST belong to the UNCL 5189 code list
+fn br_cl_19(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-CL-19", "Coded allowance reasons MUST belong to the UNCL 5189 code list");
+    if let Some((_, code)) = allowance_charge_reason_codes(invoice)
+        .find(|(charge, code)| !charge && matches!(code, ReasonCode::Charge(_)))
+    {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "Allowance has a charge reason code".to_string(),
+            fields: vec![("BT-98".to_string(), code.as_str().to_string())],
+        });
+    }
+    Ok(())
+}
+
+/// BR-CL-20: Coded charge reasons MUST belong to the UNCL 7161 code list
+fn br_cl_20(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-CL-20", "Coded charge reasons MUST belong to the UNCL 7161 code list");
+    if let Some((_, code)) = allowance_charge_reason_codes(invoice)
+        .find(|(charge, code)| *charge && matches!(code, ReasonCode::Allowance(_)))
+    {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "Charge has an allowance reason code".to_string(),
+            fields: vec![("BT-105".to_string(), code.as_str().to_string())],
+        });
+    }
+    Ok(())
+}
+
 /// BR-CO-4: Each Invoice line (BG-25) shall be categorized with an Invoiced item VAT category code (BT-151).
 fn br_co_04(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     // Should never fail because structure is enforced, but maybe something changes in the future
@@ -176,9 +583,9 @@ fn br_co_04(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
 /// BR-CO-10: Sum of Invoice line net amount (BT-106) = ∑ Invoice line net amount (BT-131).
 fn br_co_10(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     let rule = ("BR-CO-10", "Sum of Invoice line net amount (BT-106) = ∑ Invoice line net amount (BT-131).");
-    let br_106 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.line_total_amount.check(rule, "BR-106")?;
+    let br_106 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.line_total_amount.check(rule, "BR-106")?.value;
     let bt_131_sum = invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items.iter()
-        .map(|line| line.specified_line_trade_settlement.specified_trade_settlement_line_monetary_summation.line_total_amount)
+        .map(|line| line.specified_line_trade_settlement.specified_trade_settlement_line_monetary_summation.line_total_amount.value)
         .sum::<f64>();
 
     check_float_eq!(rule; br_106, bt_131_sum; br_106, bt_131_sum)
@@ -188,11 +595,11 @@ fn br_co_10(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
 fn br_co_13(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     let rule = ("BR-CO-13", "Invoice total amount without VAT (BT-109) = ∑ Invoice line net amount (BT-131) - Sum of allowances on document level (BT-107) + Sum of charges on document level (BT-108).");
     let bt_131_sum = invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items.iter()
-        .map(|line| line.specified_line_trade_settlement.specified_trade_settlement_line_monetary_summation.line_total_amount)
+        .map(|line| line.specified_line_trade_settlement.specified_trade_settlement_line_monetary_summation.line_total_amount.value)
         .sum::<f64>();
-    let br_107 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.allowance_total_amount.unwrap_or(0.0);
-    let br_108 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.charge_total_amount.unwrap_or(0.0);
-    let bt_109 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.tax_basis_total_amount.check(rule, "BT-109")?;
+    let br_107 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.allowance_total_amount.map_or(0.0, |amount| amount.value);
+    let br_108 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.charge_total_amount.map_or(0.0, |amount| amount.value);
+    let bt_109 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.tax_basis_total_amount.check(rule, "BT-109")?.value;
 
     check_float_eq!(rule; bt_109, bt_131_sum - br_107 + br_108; bt_109, bt_131_sum, br_107, br_108)
 }
@@ -202,7 +609,7 @@ fn br_co_14(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     let rule = ("BR-CO-14", "Invoice total VAT amount (BT-110) = ∑ VAT category tax amount (BT-117)");
     let bt_110 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.tax_total_amount.clone().check(rule, "BT-110")?.amount;
     let bt_117_sum = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.applicable_trade_tax.iter()
-        .filter_map(|bg_23| bg_23.calculated_amount)
+        .filter_map(|bg_23| bg_23.calculated_amount.map(|amount| amount.value))
         .sum::<f64>();
     check_float_eq!(rule; bt_110, bt_117_sum; bt_110, bt_117_sum)
 }
@@ -210,9 +617,9 @@ fn br_co_14(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
 /// BR-CO-15: Invoice total amount with VAT (BT-112) = Invoice total amount without VAT (BT-109) + Invoice total VAT amount (BT-110).
 fn br_co_15(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     let rule = ("BR-CO-15", "Invoice total amount with VAT (BT-112) = Invoice total amount without VAT (BT-109) + Invoice total VAT amount (BT-110).");
-    let bt_109 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.tax_basis_total_amount.check(rule, "BT-109")?;
+    let bt_109 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.tax_basis_total_amount.check(rule, "BT-109")?.value;
     let bt_110 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.tax_total_amount.clone().check(rule, "BT-110")?.amount;
-    let bt_112 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.grand_total_amount.check(rule, "BT-112")?;
+    let bt_112 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.grand_total_amount.check(rule, "BT-112")?.value;
 
     check_float_eq!(rule; bt_112, bt_109 + bt_110; bt_112, bt_109, bt_110)
 }
@@ -220,13 +627,13 @@ fn br_co_15(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
 /// BR-CO-16: Amount due for payment (BT-115) = Invoice total amount with VAT (BT-112) - Paid amount (BT-113) + Rounding amount (BT-114).
 fn br_co_16(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     let rule = ("BR-CO-16", "Amount due for payment (BT-115) = Invoice total amount with VAT (BT-112) - Paid amount (BT-113) + Rounding amount (BT-114).");
-    let bt_112 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.grand_total_amount.check(rule, "BT-112")?;
+    let bt_112 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.grand_total_amount.check(rule, "BT-112")?.value;
     // TODO: neither exists so far
     // let bt_113 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.paid_amount.unwrap_or(0.0);
     // let bt_114 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.rounding_amount.unwrap_or(0.0);
     let bt_113 = 0.0;
     let bt_114 = 0.0;
-    let bt_115 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.due_payable_amount.check(rule, "BT-115")?;
+    let bt_115 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.due_payable_amount.check(rule, "BT-115")?.value;
 
     check_float_eq!(rule; bt_115, bt_112 - bt_113 + bt_114; bt_115, bt_112, bt_113, bt_114)
 }
@@ -235,19 +642,56 @@ fn br_co_16(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
 fn br_co_17(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     let rule = ("BR-CO-17", "VAT category tax amount (BT-117) = VAT category taxable amount (BT-116) x (VAT category rate (BT-119) / 100), rounded to two decimals");
     let bg_23 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.applicable_trade_tax.check(rule, "BG-23")?;
-    let bt_117 = bg_23.calculated_amount.check(rule, "BT-117")?;
-    let bt_116 = bg_23.basis_amount.check(rule, "BT-116")?;
+    let bt_117 = bg_23.calculated_amount.check(rule, "BT-117")?.value;
+    let bt_116 = bg_23.basis_amount.check(rule, "BT-116")?.value;
     let bt_119 = bg_23.rate_applicable_percent.check(rule, "BT-119")?;
     check_float_eq!(rule; bt_117, bt_116 * (bt_119 / 100.0); bt_117, bt_116, bt_119)
 }
 
+/// BR-CO-19: If Invoicing period (BG-14) is used, the Invoicing period start date (BT-73) or the Invoicing period end date (BT-74) shall be filled, or both.
+fn br_co_19(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-CO-19", "If Invoicing period (BG-14) is used, the Invoicing period start date (BT-73) or the Invoicing period end date (BT-74) shall be filled, or both.");
+    if let Some(bg_14) = &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.billing_specified_period {
+        if bg_14.start_date_time.is_none() && bg_14.end_date_time.is_none() {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: "Invoicing period has neither start nor end date".to_string(),
+                fields: vec![],
+            });
+        }
+    }
+    Ok(())
+}
+
+/// BR-CO-20: If Invoice line period (BG-26) is used, the Invoice line period start date (BT-134) or the Invoice line period end date (BT-135) shall be filled, or both.
+fn br_co_20(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-CO-20", "If Invoice line period (BG-26) is used, the Invoice line period start date (BT-134) or the Invoice line period end date (BT-135) shall be filled, or both.");
+    for line in &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items {
+        let Some(bg_26) = &line.specified_line_trade_settlement.billing_specified_period else {
+            continue;
+        };
+        if bg_26.start_date_time.is_none() && bg_26.end_date_time.is_none() {
+            return Err(BusinessRuleViolation {
+                rule_id: rule.0.to_string(),
+                rule_text: rule.1.to_string(),
+                message: "Invoice line period has neither start nor end date".to_string(),
+                fields: vec![
+                    ("line_id".to_string(), line.associated_document_line_document.line_id.to_string()),
+                ],
+            });
+        }
+    }
+    Ok(())
+}
+
 /// BR-CO-25: In case the Amount due for payment (BT-115) is positive, either the Payment due date (BT-9) or the Payment terms (BT-20) shall be present.
 fn br_co_25(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     let rule = ("BR-CO-25", "In case the Amount due for payment (BT-115) is positive, either the Payment due date (BT-9) or the Payment terms (BT-20) shall be present.");
-    let bt_115 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.due_payable_amount.check(rule, "BT-115")?;
-    let bt_20_00 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_payment_terms.as_ref().check(rule, "BT-20-00")?;
-    let bt_9 = bt_20_00.due_date_time.as_ref();
-    let bt_20 = bt_20_00.description;
+    let bt_115 = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_settlement_header_monetary_summation.due_payable_amount.check(rule, "BT-115")?.value;
+    let bt_20_00 = &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_payment_terms;
+    let bt_9 = bt_20_00.iter().find_map(|payment_terms| payment_terms.due_date_time.as_ref());
+    let bt_20 = bt_20_00.iter().find_map(|payment_terms| payment_terms.description.as_ref());
 
     if bt_115 > 0.0 && bt_9.is_none() && bt_20.is_none() {
         return Err(BusinessRuleViolation {
@@ -264,6 +708,190 @@ fn br_co_25(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
     Ok(())
 }
 
+/// BR-CO-26: In order for the buyer to automatically identify a supplier, the Seller identifier (BT-29), the Seller legal registration identifier (BT-30) and/or the Seller VAT identifier (BT-31) shall be present.
+fn br_co_26(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-CO-26", "In order for the buyer to automatically identify a supplier, the Seller identifier (BT-29), the Seller legal registration identifier (BT-30) and/or the Seller VAT identifier (BT-31) shall be present.");
+    let seller = &invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.seller_trade_party;
+    let bt_29_present = !seller.id.is_empty() || !seller.global_id.is_empty();
+    let bt_30_present = seller.specified_legal_organization.as_ref().is_some_and(|organization| organization.id.is_some());
+    let bt_31_present = has_tax_registration(&seller.specified_tax_registration, TaxRegistrationSchemeCode::VatIdentifier);
+
+    if !bt_29_present && !bt_30_present && !bt_31_present {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "Seller identifier, legal registration identifier and VAT identifier are missing".to_string(),
+            fields: vec![],
+        });
+    }
+    Ok(())
+}
+
+/// BR-S-02: An Invoice that contains an Invoice line (BG-25), a Document level allowance (BG-20) or a Document level charge (BG-21) where the VAT category code (BT-151, BT-95 or BT-102) is "Standard rated" shall contain the Seller VAT Identifier (BT-31), the Seller tax registration identifier (BT-32) and/or the Seller tax representative VAT identifier (BT-63).
+fn br_s_02(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-S-02", "An Invoice that contains an Invoice line (BG-25), a Document level allowance (BG-20) or a Document level charge (BG-21) where the VAT category code (BT-151, BT-95 or BT-102) is \"Standard rated\" shall contain the Seller VAT Identifier (BT-31), the Seller tax registration identifier (BT-32) and/or the Seller tax representative VAT identifier (BT-63).");
+    check_seller_tax_registration(invoice, rule, VATCategoryCode::StandardRate)
+}
+
+/// BR-Z-02: An Invoice that contains an Invoice line (BG-25), a Document level allowance (BG-20) or a Document level charge (BG-21) where the VAT category code (BT-151, BT-95 or BT-102) is "Zero rated" shall contain the Seller VAT Identifier (BT-31), the Seller tax registration identifier (BT-32) and/or the Seller tax representative VAT identifier (BT-63).
+fn br_z_02(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-Z-02", "An Invoice that contains an Invoice line (BG-25), a Document level allowance (BG-20) or a Document level charge (BG-21) where the VAT category code (BT-151, BT-95 or BT-102) is \"Zero rated\" shall contain the Seller VAT Identifier (BT-31), the Seller tax registration identifier (BT-32) and/or the Seller tax representative VAT identifier (BT-63).");
+    check_seller_tax_registration(invoice, rule, VATCategoryCode::ZeroRatedGoods)
+}
+
+/// BR-E-02: An Invoice that contains an Invoice line (BG-25), a Document level allowance (BG-20) or a Document level charge (BG-21) where the VAT category code (BT-151, BT-95 or BT-102) is "Exempt from VAT" shall contain the Seller VAT Identifier (BT-31), the Seller tax registration identifier (BT-32) and/or the Seller tax representative VAT identifier (BT-63).
+fn br_e_02(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-E-02", "An Invoice that contains an Invoice line (BG-25), a Document level allowance (BG-20) or a Document level charge (BG-21) where the VAT category code (BT-151, BT-95 or BT-102) is \"Exempt from VAT\" shall contain the Seller VAT Identifier (BT-31), the Seller tax registration identifier (BT-32) and/or the Seller tax representative VAT identifier (BT-63).");
+    check_seller_tax_registration(invoice, rule, VATCategoryCode::ExemptFromTax)
+}
+
+/// BR-AE-02: An Invoice that contains an Invoice line (BG-25) where the Invoiced item VAT category code (BT-151) is "Reverse charge" shall contain the Seller VAT Identifier (BT-31), the Seller Tax registration identifier (BT-32) and/or the Seller tax representative VAT identifier (BT-63) and the Buyer VAT identifier (BT-48) and/or the Buyer legal registration identifier (BT-47).
+fn br_ae_02(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-AE-02", "An Invoice that contains an Invoice line (BG-25) where the Invoiced item VAT category code (BT-151) is \"Reverse charge\" shall contain the Seller VAT Identifier (BT-31), the Seller Tax registration identifier (BT-32) and/or the Seller tax representative VAT identifier (BT-63) and the Buyer VAT identifier (BT-48) and/or the Buyer legal registration identifier (BT-47).");
+    check_seller_tax_registration(invoice, rule, VATCategoryCode::VatReverseCharge)?;
+
+    let buyer = &invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.buyer_trade_party;
+    let bt_48_present = has_tax_registration(&buyer.specified_tax_registration, TaxRegistrationSchemeCode::VatIdentifier);
+    let bt_47_present = buyer.specified_legal_organization.as_ref().is_some_and(|organization| organization.id.is_some());
+
+    if contains_vat_category(invoice, VATCategoryCode::VatReverseCharge) && !bt_48_present && !bt_47_present {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "Buyer VAT identifier and Buyer legal registration identifier are missing".to_string(),
+            fields: vec![],
+        });
+    }
+    Ok(())
+}
+
+/// BR-IC-02: An Invoice that contains an Invoice line (BG-25) where the Invoiced item VAT category code (BT-151) is "Intra-community supply" shall contain the Seller VAT Identifier (BT-31) or the Seller tax representative VAT identifier (BT-63) and the Buyer VAT identifier (BT-48).
+fn br_ic_02(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-IC-02", "An Invoice that contains an Invoice line (BG-25) where the Invoiced item VAT category code (BT-151) is \"Intra-community supply\" shall contain the Seller VAT Identifier (BT-31) or the Seller tax representative VAT identifier (BT-63) and the Buyer VAT identifier (BT-48).");
+    if !contains_vat_category(invoice, VATCategoryCode::VatExemptEEAIntraCommunity) {
+        return Ok(());
+    }
+    let agreement = &invoice.supply_chain_trade_transaction.applicable_header_trade_agreement;
+    let bt_31_present = has_tax_registration(&agreement.seller_trade_party.specified_tax_registration, TaxRegistrationSchemeCode::VatIdentifier);
+    let bt_63_present = has_tax_representative_vat_identifier(invoice);
+    let bt_48_present = has_tax_registration(&agreement.buyer_trade_party.specified_tax_registration, TaxRegistrationSchemeCode::VatIdentifier);
+
+    if !(bt_31_present || bt_63_present) || !bt_48_present {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "Seller VAT identifier or Buyer VAT identifier is missing".to_string(),
+            fields: vec![
+                ("BT-31".to_string(), bt_31_present.to_string()),
+                ("BT-63".to_string(), bt_63_present.to_string()),
+                ("BT-48".to_string(), bt_48_present.to_string()),
+            ],
+        });
+    }
+    Ok(())
+}
+
+/// BR-G-02: An Invoice that contains an Invoice line (BG-25) where the Invoiced item VAT category code (BT-151) is "Export outside the EU" shall contain the Seller VAT Identifier (BT-31) or the Seller tax representative VAT identifier (BT-63).
+fn br_g_02(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-G-02", "An Invoice that contains an Invoice line (BG-25) where the Invoiced item VAT category code (BT-151) is \"Export outside the EU\" shall contain the Seller VAT Identifier (BT-31) or the Seller tax representative VAT identifier (BT-63).");
+    if !contains_vat_category(invoice, VATCategoryCode::FreeExportItemTaxNotCharged) {
+        return Ok(());
+    }
+    let seller = &invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.seller_trade_party;
+
+    if !has_tax_registration(&seller.specified_tax_registration, TaxRegistrationSchemeCode::VatIdentifier)
+        && !has_tax_representative_vat_identifier(invoice)
+    {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "Seller VAT identifier and Seller tax representative VAT identifier are missing".to_string(),
+            fields: vec![],
+        });
+    }
+    Ok(())
+}
+
+/// BR-O-02: An Invoice that contains an Invoice line (BG-25) where the Invoiced item VAT category code (BT-151) is "Not subject to VAT" shall not contain the Seller VAT identifier (BT-31), the Seller tax representative VAT identifier (BT-63) or the Buyer VAT identifier (BT-48).
+fn br_o_02(invoice: &Invoice) -> Result<(), BusinessRuleViolation> {
+    let rule = ("BR-O-02", "An Invoice that contains an Invoice line (BG-25) where the Invoiced item VAT category code (BT-151) is \"Not subject to VAT\" shall not contain the Seller VAT identifier (BT-31), the Seller tax representative VAT identifier (BT-63) or the Buyer VAT identifier (BT-48).");
+    if !contains_vat_category(invoice, VATCategoryCode::ServiceOutsideScopeOfTax) {
+        return Ok(());
+    }
+    let agreement = &invoice.supply_chain_trade_transaction.applicable_header_trade_agreement;
+    let bt_31_present = has_tax_registration(&agreement.seller_trade_party.specified_tax_registration, TaxRegistrationSchemeCode::VatIdentifier);
+    let bt_63_present = has_tax_representative_vat_identifier(invoice);
+    let bt_48_present = has_tax_registration(&agreement.buyer_trade_party.specified_tax_registration, TaxRegistrationSchemeCode::VatIdentifier);
+
+    if bt_31_present || bt_63_present || bt_48_present {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "Seller VAT identifier, Seller tax representative VAT identifier or Buyer VAT identifier must not be present".to_string(),
+            fields: vec![
+                ("BT-31".to_string(), bt_31_present.to_string()),
+                ("BT-63".to_string(), bt_63_present.to_string()),
+                ("BT-48".to_string(), bt_48_present.to_string()),
+            ],
+        });
+    }
+    Ok(())
+}
+
+/// Checks the seller's VAT identifier, tax registration identifier or tax representative's VAT identifier if the invoice uses the given VAT category
+fn check_seller_tax_registration(invoice: &Invoice, rule: (&str, &str), category_code: VATCategoryCode) -> Result<(), BusinessRuleViolation> {
+    if !contains_vat_category(invoice, category_code) {
+        return Ok(());
+    }
+    let seller = &invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.seller_trade_party;
+
+    if !has_tax_registration(&seller.specified_tax_registration, TaxRegistrationSchemeCode::VatIdentifier)
+        && !has_tax_registration(&seller.specified_tax_registration, TaxRegistrationSchemeCode::FiscalNumber)
+        && !has_tax_representative_vat_identifier(invoice)
+    {
+        return Err(BusinessRuleViolation {
+            rule_id: rule.0.to_string(),
+            rule_text: rule.1.to_string(),
+            message: "Seller VAT identifier, Seller tax registration identifier and Seller tax representative VAT identifier are missing".to_string(),
+            fields: vec![("VAT category".to_string(), category_code.as_str().to_string())],
+        });
+    }
+    Ok(())
+}
+
+/// Checks if any VAT breakdown or invoice line uses the given VAT category
+fn contains_vat_category(invoice: &Invoice, category_code: VATCategoryCode) -> bool {
+    let settlement = &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement;
+
+    settlement.applicable_trade_tax.iter().any(|bg_23| bg_23.category_code == category_code)
+        || settlement.specified_trade_allowance_charge.iter()
+            .any(|bg_20_21| bg_20_21.category_trade_tax.category_code == category_code)
+        || invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items.iter()
+            .any(|line| line.specified_line_trade_settlement.applicable_trade_tax.category_code == category_code)
+}
+
+fn has_tax_registration(registrations: &[SpecifiedTaxRegistration], scheme_id: TaxRegistrationSchemeCode) -> bool {
+    registrations.iter().any(|registration| registration.id.scheme_id == scheme_id)
+}
+
+/// Checks if the seller's tax representative (BG-11) has a VAT identifier (BT-63)
+fn has_tax_representative_vat_identifier(invoice: &Invoice) -> bool {
+    invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.seller_tax_representative_trade_party.as_ref()
+        .and_then(|bg_11| bg_11.specified_tax_registration.as_ref())
+        .is_some_and(|registration| registration.id.scheme_id == TaxRegistrationSchemeCode::VatIdentifier)
+}
+
+/// Reason codes of all allowances and charges on document and line level, paired with their charge indicator
+fn allowance_charge_reason_codes<'a>(invoice: &'a Invoice) -> impl Iterator<Item = (bool, ReasonCode)> + 'a {
+    let document_level = invoice.supply_chain_trade_transaction.applicable_header_trade_settlement.specified_trade_allowance_charge.iter()
+        .filter_map(|allowance_charge| allowance_charge.reason_code.map(|code| (allowance_charge.is_charge(), code)));
+    let line_level = invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items.iter()
+        .flat_map(|line| line.specified_line_trade_settlement.specified_trade_allowance_charge.iter())
+        .filter_map(|allowance_charge| allowance_charge.reason_code.map(|code| (allowance_charge.charge_indicator.indicator, code)));
+
+    document_level.chain(line_level)
+}
+
 /// Shortcut to handle possibly missing values
 trait OptionExt<T> {
     /// Check if the Option is Some, otherwise return a BusinessRuleViolation
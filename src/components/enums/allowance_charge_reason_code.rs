@@ -34,7 +34,7 @@ impl AllowanceChargeReasonCode {
             AllowanceChargeReasonCode::SpecialAgreement => "64",
             AllowanceChargeReasonCode::ProductionErrorDiscount => "65",
             AllowanceChargeReasonCode::NewOutletDiscount => "66",
-            AllowanceChargeReasonCode::SampleDiscount => "66",
+            AllowanceChargeReasonCode::SampleDiscount => "67",
             AllowanceChargeReasonCode::EndOfRangeDiscount => "68",
             AllowanceChargeReasonCode::IncotermDiscount => "70",
             AllowanceChargeReasonCode::PointOfSalesThresholdAllowance => "71",
@@ -0,0 +1,764 @@
+use serde::{Serialize, Serializer};
+
+// based on the UNTDID 7161 Special service description code list, as restricted by EN 16931 for charge reason codes (BT-105, BT-145):
+// https://ec.europa.eu/digital-building-blocks/sites/display/DIGITAL/Registry+of+supporting+artefacts+to+implement+EN16931
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ChargeReasonCode {
+    /// Advertising
+    Advertising,
+    /// Telecommunication
+    Telecommunication,
+    /// Technical modification
+    TechnicalModification,
+    /// Job-order production
+    JobOrderProduction,
+    /// Outlays
+    Outlays,
+    /// Off-premises
+    OffPremises,
+    /// Additional processing
+    AdditionalProcessing,
+    /// Attesting
+    Attesting,
+    /// Acceptance
+    Acceptance,
+    /// Rush delivery
+    RushDelivery,
+    /// Special construction
+    SpecialConstruction,
+    /// Airport facilities
+    AirportFacilities,
+    /// Concession
+    Concession,
+    /// Compulsory storage
+    CompulsoryStorage,
+    /// Fuel removal
+    FuelRemoval,
+    /// Into plane
+    IntoPlane,
+    /// Overtime
+    Overtime,
+    /// Tooling
+    Tooling,
+    /// Miscellaneous
+    Miscellaneous,
+    /// Additional packaging
+    AdditionalPackaging,
+    /// Dunnage
+    Dunnage,
+    /// Containerisation
+    Containerisation,
+    /// Carton packing
+    CartonPacking,
+    /// Hessian wrapped
+    HessianWrapped,
+    /// Polyethylene wrap packing
+    PolyethyleneWrapPacking,
+    /// Miscellaneous treatment
+    MiscellaneousTreatment,
+    /// Enamelling treatment
+    EnamellingTreatment,
+    /// Heat treatment
+    HeatTreatment,
+    /// Plating treatment
+    PlatingTreatment,
+    /// Painting
+    Painting,
+    /// Polishing
+    Polishing,
+    /// Priming
+    Priming,
+    /// Preservation treatment
+    PreservationTreatment,
+    /// Fitting
+    Fitting,
+    /// Consolidation
+    Consolidation,
+    /// Bill of lading
+    BillOfLading,
+    /// Airbag
+    Airbag,
+    /// Transfer
+    Transfer,
+    /// Slipsheet
+    Slipsheet,
+    /// Binding
+    Binding,
+    /// Repair or replacement of broken returnable package
+    RepairOrReplacementOfBrokenReturnablePackage,
+    /// Efficient logistics
+    EfficientLogistics,
+    /// Merchandising
+    Merchandising,
+    /// Product mix
+    ProductMix,
+    /// Other services
+    OtherServices,
+    /// Pick-up
+    PickUp,
+    /// Chronic illness
+    ChronicIllness,
+    /// New product introduction
+    NewProductIntroduction,
+    /// Direct delivery
+    DirectDelivery,
+    /// Diversion
+    Diversion,
+    /// Disconnect
+    Disconnect,
+    /// Distribution
+    Distribution,
+    /// Handling of hazardous cargo
+    HandlingOfHazardousCargo,
+    /// Rents and leases
+    RentsAndLeases,
+    /// Location differential
+    LocationDifferential,
+    /// Aircraft refueling
+    AircraftRefueling,
+    /// Fuel shipped into storage
+    FuelShippedIntoStorage,
+    /// Cash on delivery
+    CashOnDelivery,
+    /// Small order processing service
+    SmallOrderProcessingService,
+    /// Clerical or administrative services
+    ClericalOrAdministrativeServices,
+    /// Guarantee
+    Guarantee,
+    /// Collection and recycling
+    CollectionAndRecycling,
+    /// Copyright fee collection
+    CopyrightFeeCollection,
+    /// Veterinary inspection service
+    VeterinaryInspectionService,
+    /// Pensioner service
+    PensionerService,
+    /// Medicine free pass holder
+    MedicineFreePassHolder,
+    /// Environmental protection service
+    EnvironmentalProtectionService,
+    /// Environmental clean-up service
+    EnvironmentalCleanUpService,
+    /// National cheque processing service outside account area
+    NationalChequeProcessingServiceOutsideAccountArea,
+    /// National payment service outside account area
+    NationalPaymentServiceOutsideAccountArea,
+    /// National payment service within account area
+    NationalPaymentServiceWithinAccountArea,
+    /// Adjustments
+    Adjustments,
+    /// Authentication
+    Authentication,
+    /// Cataloguing
+    Cataloguing,
+    /// Cartage
+    Cartage,
+    /// Certification
+    Certification,
+    /// Certificate of conformance
+    CertificateOfConformance,
+    /// Certificate of origin
+    CertificateOfOrigin,
+    /// Cutting
+    Cutting,
+    /// Consular service
+    ConsularService,
+    /// Customer collection
+    CustomerCollection,
+    /// Payroll payment service
+    PayrollPaymentService,
+    /// Cash transportation
+    CashTransportation,
+    /// Home banking service
+    HomeBankingService,
+    /// Bilateral agreement service
+    BilateralAgreementService,
+    /// Insurance brokerage service
+    InsuranceBrokerageService,
+    /// Cheque generation
+    ChequeGeneration,
+    /// Preferential merchandising location
+    PreferentialMerchandisingLocation,
+    /// Crane
+    Crane,
+    /// Special colour service
+    SpecialColourService,
+    /// Sorting
+    Sorting,
+    /// Battery collection and recycling
+    BatteryCollectionAndRecycling,
+    /// Product take back fee
+    ProductTakeBackFee,
+    /// Quality control released
+    QualityControlReleased,
+    /// Quality control held
+    QualityControlHeld,
+    /// Quality control embargo
+    QualityControlEmbargo,
+    /// Car loading
+    CarLoading,
+    /// Cleaning
+    Cleaning,
+    /// Cigarette stamping
+    CigaretteStamping,
+    /// Count and recount
+    CountAndRecount,
+    /// Layout/design
+    LayoutDesign,
+    /// Assortment allowance
+    AssortmentAllowance,
+    /// Driver assigned unloading
+    DriverAssignedUnloading,
+    /// Debtor bound
+    DebtorBound,
+    /// Dealer allowance
+    DealerAllowance,
+    /// Allowance transferable to the consumer
+    AllowanceTransferableToTheConsumer,
+    /// Growth of business
+    GrowthOfBusiness,
+    /// Introduction allowance
+    IntroductionAllowance,
+    /// Multi-buy promotion
+    MultiBuyPromotion,
+    /// Partnership
+    Partnership,
+    /// Return handling
+    ReturnHandling,
+    /// Minimum order not fulfilled charge
+    MinimumOrderNotFulfilledCharge,
+    /// Point of sales threshold allowance
+    PointOfSalesThresholdAllowance,
+    /// Wholesaling discount
+    WholesalingDiscount,
+    /// Documentary credits transfer commission
+    DocumentaryCreditsTransferCommission,
+    /// Delivery
+    Delivery,
+    /// Engraving
+    Engraving,
+    /// Expediting
+    Expediting,
+    /// Exchange rate guarantee
+    ExchangeRateGuarantee,
+    /// Fabrication
+    Fabrication,
+    /// Freight equalization
+    FreightEqualization,
+    /// Freight extraordinary handling
+    FreightExtraordinaryHandling,
+    /// Freight service
+    FreightService,
+    /// Filling/handling
+    FillingHandling,
+    /// Financing
+    Financing,
+    /// Grinding
+    Grinding,
+    /// Hose
+    Hose,
+    /// Handling
+    Handling,
+    /// Hoisting and hauling
+    HoistingAndHauling,
+    /// Installation
+    Installation,
+    /// Installation and warranty
+    InstallationAndWarranty,
+    /// Inside delivery
+    InsideDelivery,
+    /// Inspection
+    Inspection,
+    /// Insurance
+    Insurance,
+    /// Installation and training
+    InstallationAndTraining,
+    /// Invoicing
+    Invoicing,
+    /// Koshering
+    Koshering,
+    /// Carrier count
+    CarrierCount,
+    /// Labelling
+    Labelling,
+    /// Labour
+    Labour,
+    /// Repair and return
+    RepairAndReturn,
+    /// Legalisation
+    Legalisation,
+    /// Mounting
+    Mounting,
+    /// Mail invoice
+    MailInvoice,
+    /// Mail invoice to each location
+    MailInvoiceToEachLocation,
+    /// Non-returnable containers
+    NonReturnableContainers,
+    /// Outside cable connectors
+    OutsideCableConnectors,
+    /// Invoice with shipment
+    InvoiceWithShipment,
+    /// Phosphatizing (steel treatment)
+    Phosphatizing,
+    /// Packing
+    Packing,
+    /// Palletizing
+    Palletizing,
+    /// Price variation
+    PriceVariation,
+    /// Repacking
+    Repacking,
+    /// Repair
+    Repair,
+    /// Returnable container
+    ReturnableContainer,
+    /// Restocking
+    Restocking,
+    /// Re-delivery
+    ReDelivery,
+    /// Refurbishing
+    Refurbishing,
+    /// Rail wagon hire
+    RailWagonHire,
+    /// Loading
+    Loading,
+    /// Salvaging
+    Salvaging,
+    /// Shipping and handling
+    ShippingAndHandling,
+    /// Special packaging
+    SpecialPackaging,
+    /// Stamping
+    Stamping,
+    /// Consignee unload
+    ConsigneeUnload,
+    /// Shrink-wrap
+    ShrinkWrap,
+    /// Special handling
+    SpecialHandling,
+    /// Special finish
+    SpecialFinish,
+    /// Set-up
+    SetUp,
+    /// Tank renting
+    TankRenting,
+    /// Testing
+    Testing,
+    /// Transportation - third party billing
+    TransportationThirdPartyBilling,
+    /// Transportation by vendor
+    TransportationByVendor,
+    /// Drop yard
+    DropYard,
+    /// Drop dock
+    DropDock,
+    /// Warehousing
+    Warehousing,
+    /// Combine all same day shipment
+    CombineAllSameDayShipment,
+    /// Split pick-up
+    SplitPickUp,
+    /// Mutually defined
+    MutuallyDefined,
+}
+
+impl ChargeReasonCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ChargeReasonCode::Advertising => "AA",
+            ChargeReasonCode::Telecommunication => "AAA",
+            ChargeReasonCode::TechnicalModification => "AAC",
+            ChargeReasonCode::JobOrderProduction => "AAD",
+            ChargeReasonCode::Outlays => "AAE",
+            ChargeReasonCode::OffPremises => "AAF",
+            ChargeReasonCode::AdditionalProcessing => "AAH",
+            ChargeReasonCode::Attesting => "AAI",
+            ChargeReasonCode::Acceptance => "AAS",
+            ChargeReasonCode::RushDelivery => "AAT",
+            ChargeReasonCode::SpecialConstruction => "AAV",
+            ChargeReasonCode::AirportFacilities => "AAY",
+            ChargeReasonCode::Concession => "AAZ",
+            ChargeReasonCode::CompulsoryStorage => "ABA",
+            ChargeReasonCode::FuelRemoval => "ABB",
+            ChargeReasonCode::IntoPlane => "ABC",
+            ChargeReasonCode::Overtime => "ABD",
+            ChargeReasonCode::Tooling => "ABF",
+            ChargeReasonCode::Miscellaneous => "ABK",
+            ChargeReasonCode::AdditionalPackaging => "ABL",
+            ChargeReasonCode::Dunnage => "ABN",
+            ChargeReasonCode::Containerisation => "ABR",
+            ChargeReasonCode::CartonPacking => "ABS",
+            ChargeReasonCode::HessianWrapped => "ABT",
+            ChargeReasonCode::PolyethyleneWrapPacking => "ABU",
+            ChargeReasonCode::MiscellaneousTreatment => "ACF",
+            ChargeReasonCode::EnamellingTreatment => "ACG",
+            ChargeReasonCode::HeatTreatment => "ACH",
+            ChargeReasonCode::PlatingTreatment => "ACI",
+            ChargeReasonCode::Painting => "ACJ",
+            ChargeReasonCode::Polishing => "ACK",
+            ChargeReasonCode::Priming => "ACL",
+            ChargeReasonCode::PreservationTreatment => "ACM",
+            ChargeReasonCode::Fitting => "ACS",
+            ChargeReasonCode::Consolidation => "ADC",
+            ChargeReasonCode::BillOfLading => "ADE",
+            ChargeReasonCode::Airbag => "ADJ",
+            ChargeReasonCode::Transfer => "ADK",
+            ChargeReasonCode::Slipsheet => "ADL",
+            ChargeReasonCode::Binding => "ADM",
+            ChargeReasonCode::RepairOrReplacementOfBrokenReturnablePackage => "ADN",
+            ChargeReasonCode::EfficientLogistics => "ADO",
+            ChargeReasonCode::Merchandising => "ADP",
+            ChargeReasonCode::ProductMix => "ADQ",
+            ChargeReasonCode::OtherServices => "ADR",
+            ChargeReasonCode::PickUp => "ADT",
+            ChargeReasonCode::ChronicIllness => "ADW",
+            ChargeReasonCode::NewProductIntroduction => "ADY",
+            ChargeReasonCode::DirectDelivery => "ADZ",
+            ChargeReasonCode::Diversion => "AEA",
+            ChargeReasonCode::Disconnect => "AEB",
+            ChargeReasonCode::Distribution => "AEC",
+            ChargeReasonCode::HandlingOfHazardousCargo => "AED",
+            ChargeReasonCode::RentsAndLeases => "AEF",
+            ChargeReasonCode::LocationDifferential => "AEH",
+            ChargeReasonCode::AircraftRefueling => "AEI",
+            ChargeReasonCode::FuelShippedIntoStorage => "AEJ",
+            ChargeReasonCode::CashOnDelivery => "AEK",
+            ChargeReasonCode::SmallOrderProcessingService => "AEL",
+            ChargeReasonCode::ClericalOrAdministrativeServices => "AEM",
+            ChargeReasonCode::Guarantee => "AEN",
+            ChargeReasonCode::CollectionAndRecycling => "AEO",
+            ChargeReasonCode::CopyrightFeeCollection => "AEP",
+            ChargeReasonCode::VeterinaryInspectionService => "AES",
+            ChargeReasonCode::PensionerService => "AET",
+            ChargeReasonCode::MedicineFreePassHolder => "AEU",
+            ChargeReasonCode::EnvironmentalProtectionService => "AEV",
+            ChargeReasonCode::EnvironmentalCleanUpService => "AEW",
+            ChargeReasonCode::NationalChequeProcessingServiceOutsideAccountArea => "AEX",
+            ChargeReasonCode::NationalPaymentServiceOutsideAccountArea => "AEY",
+            ChargeReasonCode::NationalPaymentServiceWithinAccountArea => "AEZ",
+            ChargeReasonCode::Adjustments => "AJ",
+            ChargeReasonCode::Authentication => "AU",
+            ChargeReasonCode::Cataloguing => "CA",
+            ChargeReasonCode::Cartage => "CAB",
+            ChargeReasonCode::Certification => "CAD",
+            ChargeReasonCode::CertificateOfConformance => "CAE",
+            ChargeReasonCode::CertificateOfOrigin => "CAF",
+            ChargeReasonCode::Cutting => "CAI",
+            ChargeReasonCode::ConsularService => "CAJ",
+            ChargeReasonCode::CustomerCollection => "CAK",
+            ChargeReasonCode::PayrollPaymentService => "CAL",
+            ChargeReasonCode::CashTransportation => "CAM",
+            ChargeReasonCode::HomeBankingService => "CAN",
+            ChargeReasonCode::BilateralAgreementService => "CAO",
+            ChargeReasonCode::InsuranceBrokerageService => "CAP",
+            ChargeReasonCode::ChequeGeneration => "CAQ",
+            ChargeReasonCode::PreferentialMerchandisingLocation => "CAR",
+            ChargeReasonCode::Crane => "CAS",
+            ChargeReasonCode::SpecialColourService => "CAT",
+            ChargeReasonCode::Sorting => "CAU",
+            ChargeReasonCode::BatteryCollectionAndRecycling => "CAV",
+            ChargeReasonCode::ProductTakeBackFee => "CAW",
+            ChargeReasonCode::QualityControlReleased => "CAX",
+            ChargeReasonCode::QualityControlHeld => "CAY",
+            ChargeReasonCode::QualityControlEmbargo => "CAZ",
+            ChargeReasonCode::CarLoading => "CD",
+            ChargeReasonCode::Cleaning => "CG",
+            ChargeReasonCode::CigaretteStamping => "CS",
+            ChargeReasonCode::CountAndRecount => "CT",
+            ChargeReasonCode::LayoutDesign => "DAB",
+            ChargeReasonCode::AssortmentAllowance => "DAC",
+            ChargeReasonCode::DriverAssignedUnloading => "DAD",
+            ChargeReasonCode::DebtorBound => "DAF",
+            ChargeReasonCode::DealerAllowance => "DAG",
+            ChargeReasonCode::AllowanceTransferableToTheConsumer => "DAH",
+            ChargeReasonCode::GrowthOfBusiness => "DAI",
+            ChargeReasonCode::IntroductionAllowance => "DAJ",
+            ChargeReasonCode::MultiBuyPromotion => "DAK",
+            ChargeReasonCode::Partnership => "DAL",
+            ChargeReasonCode::ReturnHandling => "DAM",
+            ChargeReasonCode::MinimumOrderNotFulfilledCharge => "DAN",
+            ChargeReasonCode::PointOfSalesThresholdAllowance => "DAO",
+            ChargeReasonCode::WholesalingDiscount => "DAP",
+            ChargeReasonCode::DocumentaryCreditsTransferCommission => "DAQ",
+            ChargeReasonCode::Delivery => "DL",
+            ChargeReasonCode::Engraving => "EG",
+            ChargeReasonCode::Expediting => "EP",
+            ChargeReasonCode::ExchangeRateGuarantee => "ER",
+            ChargeReasonCode::Fabrication => "FAA",
+            ChargeReasonCode::FreightEqualization => "FAB",
+            ChargeReasonCode::FreightExtraordinaryHandling => "FAC",
+            ChargeReasonCode::FreightService => "FC",
+            ChargeReasonCode::FillingHandling => "FH",
+            ChargeReasonCode::Financing => "FI",
+            ChargeReasonCode::Grinding => "GAA",
+            ChargeReasonCode::Hose => "HAA",
+            ChargeReasonCode::Handling => "HD",
+            ChargeReasonCode::HoistingAndHauling => "HH",
+            ChargeReasonCode::Installation => "IAA",
+            ChargeReasonCode::InstallationAndWarranty => "IAB",
+            ChargeReasonCode::InsideDelivery => "ID",
+            ChargeReasonCode::Inspection => "IF",
+            ChargeReasonCode::Insurance => "IN",
+            ChargeReasonCode::InstallationAndTraining => "IR",
+            ChargeReasonCode::Invoicing => "IS",
+            ChargeReasonCode::Koshering => "KO",
+            ChargeReasonCode::CarrierCount => "L1",
+            ChargeReasonCode::Labelling => "LA",
+            ChargeReasonCode::Labour => "LAA",
+            ChargeReasonCode::RepairAndReturn => "LAB",
+            ChargeReasonCode::Legalisation => "LF",
+            ChargeReasonCode::Mounting => "MAE",
+            ChargeReasonCode::MailInvoice => "MI",
+            ChargeReasonCode::MailInvoiceToEachLocation => "ML",
+            ChargeReasonCode::NonReturnableContainers => "NAA",
+            ChargeReasonCode::OutsideCableConnectors => "OA",
+            ChargeReasonCode::InvoiceWithShipment => "PA",
+            ChargeReasonCode::Phosphatizing => "PAA",
+            ChargeReasonCode::Packing => "PC",
+            ChargeReasonCode::Palletizing => "PL",
+            ChargeReasonCode::PriceVariation => "PRV",
+            ChargeReasonCode::Repacking => "RAB",
+            ChargeReasonCode::Repair => "RAC",
+            ChargeReasonCode::ReturnableContainer => "RAD",
+            ChargeReasonCode::Restocking => "RAF",
+            ChargeReasonCode::ReDelivery => "RE",
+            ChargeReasonCode::Refurbishing => "RF",
+            ChargeReasonCode::RailWagonHire => "RH",
+            ChargeReasonCode::Loading => "RV",
+            ChargeReasonCode::Salvaging => "SA",
+            ChargeReasonCode::ShippingAndHandling => "SAA",
+            ChargeReasonCode::SpecialPackaging => "SAD",
+            ChargeReasonCode::Stamping => "SAE",
+            ChargeReasonCode::ConsigneeUnload => "SAI",
+            ChargeReasonCode::ShrinkWrap => "SG",
+            ChargeReasonCode::SpecialHandling => "SH",
+            ChargeReasonCode::SpecialFinish => "SM",
+            ChargeReasonCode::SetUp => "SU",
+            ChargeReasonCode::TankRenting => "TAB",
+            ChargeReasonCode::Testing => "TAC",
+            ChargeReasonCode::TransportationThirdPartyBilling => "TT",
+            ChargeReasonCode::TransportationByVendor => "TV",
+            ChargeReasonCode::DropYard => "V1",
+            ChargeReasonCode::DropDock => "V2",
+            ChargeReasonCode::Warehousing => "WH",
+            ChargeReasonCode::CombineAllSameDayShipment => "XAA",
+            ChargeReasonCode::SplitPickUp => "YY",
+            ChargeReasonCode::MutuallyDefined => "ZZZ",
+        }
+    }
+}
+
+impl Serialize for ChargeReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl TryFrom<&str> for ChargeReasonCode {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "AA" => Ok(ChargeReasonCode::Advertising),
+            "AAA" => Ok(ChargeReasonCode::Telecommunication),
+            "AAC" => Ok(ChargeReasonCode::TechnicalModification),
+            "AAD" => Ok(ChargeReasonCode::JobOrderProduction),
+            "AAE" => Ok(ChargeReasonCode::Outlays),
+            "AAF" => Ok(ChargeReasonCode::OffPremises),
+            "AAH" => Ok(ChargeReasonCode::AdditionalProcessing),
+            "AAI" => Ok(ChargeReasonCode::Attesting),
+            "AAS" => Ok(ChargeReasonCode::Acceptance),
+            "AAT" => Ok(ChargeReasonCode::RushDelivery),
+            "AAV" => Ok(ChargeReasonCode::SpecialConstruction),
+            "AAY" => Ok(ChargeReasonCode::AirportFacilities),
+            "AAZ" => Ok(ChargeReasonCode::Concession),
+            "ABA" => Ok(ChargeReasonCode::CompulsoryStorage),
+            "ABB" => Ok(ChargeReasonCode::FuelRemoval),
+            "ABC" => Ok(ChargeReasonCode::IntoPlane),
+            "ABD" => Ok(ChargeReasonCode::Overtime),
+            "ABF" => Ok(ChargeReasonCode::Tooling),
+            "ABK" => Ok(ChargeReasonCode::Miscellaneous),
+            "ABL" => Ok(ChargeReasonCode::AdditionalPackaging),
+            "ABN" => Ok(ChargeReasonCode::Dunnage),
+            "ABR" => Ok(ChargeReasonCode::Containerisation),
+            "ABS" => Ok(ChargeReasonCode::CartonPacking),
+            "ABT" => Ok(ChargeReasonCode::HessianWrapped),
+            "ABU" => Ok(ChargeReasonCode::PolyethyleneWrapPacking),
+            "ACF" => Ok(ChargeReasonCode::MiscellaneousTreatment),
+            "ACG" => Ok(ChargeReasonCode::EnamellingTreatment),
+            "ACH" => Ok(ChargeReasonCode::HeatTreatment),
+            "ACI" => Ok(ChargeReasonCode::PlatingTreatment),
+            "ACJ" => Ok(ChargeReasonCode::Painting),
+            "ACK" => Ok(ChargeReasonCode::Polishing),
+            "ACL" => Ok(ChargeReasonCode::Priming),
+            "ACM" => Ok(ChargeReasonCode::PreservationTreatment),
+            "ACS" => Ok(ChargeReasonCode::Fitting),
+            "ADC" => Ok(ChargeReasonCode::Consolidation),
+            "ADE" => Ok(ChargeReasonCode::BillOfLading),
+            "ADJ" => Ok(ChargeReasonCode::Airbag),
+            "ADK" => Ok(ChargeReasonCode::Transfer),
+            "ADL" => Ok(ChargeReasonCode::Slipsheet),
+            "ADM" => Ok(ChargeReasonCode::Binding),
+            "ADN" => Ok(ChargeReasonCode::RepairOrReplacementOfBrokenReturnablePackage),
+            "ADO" => Ok(ChargeReasonCode::EfficientLogistics),
+            "ADP" => Ok(ChargeReasonCode::Merchandising),
+            "ADQ" => Ok(ChargeReasonCode::ProductMix),
+            "ADR" => Ok(ChargeReasonCode::OtherServices),
+            "ADT" => Ok(ChargeReasonCode::PickUp),
+            "ADW" => Ok(ChargeReasonCode::ChronicIllness),
+            "ADY" => Ok(ChargeReasonCode::NewProductIntroduction),
+            "ADZ" => Ok(ChargeReasonCode::DirectDelivery),
+            "AEA" => Ok(ChargeReasonCode::Diversion),
+            "AEB" => Ok(ChargeReasonCode::Disconnect),
+            "AEC" => Ok(ChargeReasonCode::Distribution),
+            "AED" => Ok(ChargeReasonCode::HandlingOfHazardousCargo),
+            "AEF" => Ok(ChargeReasonCode::RentsAndLeases),
+            "AEH" => Ok(ChargeReasonCode::LocationDifferential),
+            "AEI" => Ok(ChargeReasonCode::AircraftRefueling),
+            "AEJ" => Ok(ChargeReasonCode::FuelShippedIntoStorage),
+            "AEK" => Ok(ChargeReasonCode::CashOnDelivery),
+            "AEL" => Ok(ChargeReasonCode::SmallOrderProcessingService),
+            "AEM" => Ok(ChargeReasonCode::ClericalOrAdministrativeServices),
+            "AEN" => Ok(ChargeReasonCode::Guarantee),
+            "AEO" => Ok(ChargeReasonCode::CollectionAndRecycling),
+            "AEP" => Ok(ChargeReasonCode::CopyrightFeeCollection),
+            "AES" => Ok(ChargeReasonCode::VeterinaryInspectionService),
+            "AET" => Ok(ChargeReasonCode::PensionerService),
+            "AEU" => Ok(ChargeReasonCode::MedicineFreePassHolder),
+            "AEV" => Ok(ChargeReasonCode::EnvironmentalProtectionService),
+            "AEW" => Ok(ChargeReasonCode::EnvironmentalCleanUpService),
+            "AEX" => Ok(ChargeReasonCode::NationalChequeProcessingServiceOutsideAccountArea),
+            "AEY" => Ok(ChargeReasonCode::NationalPaymentServiceOutsideAccountArea),
+            "AEZ" => Ok(ChargeReasonCode::NationalPaymentServiceWithinAccountArea),
+            "AJ" => Ok(ChargeReasonCode::Adjustments),
+            "AU" => Ok(ChargeReasonCode::Authentication),
+            "CA" => Ok(ChargeReasonCode::Cataloguing),
+            "CAB" => Ok(ChargeReasonCode::Cartage),
+            "CAD" => Ok(ChargeReasonCode::Certification),
+            "CAE" => Ok(ChargeReasonCode::CertificateOfConformance),
+            "CAF" => Ok(ChargeReasonCode::CertificateOfOrigin),
+            "CAI" => Ok(ChargeReasonCode::Cutting),
+            "CAJ" => Ok(ChargeReasonCode::ConsularService),
+            "CAK" => Ok(ChargeReasonCode::CustomerCollection),
+            "CAL" => Ok(ChargeReasonCode::PayrollPaymentService),
+            "CAM" => Ok(ChargeReasonCode::CashTransportation),
+            "CAN" => Ok(ChargeReasonCode::HomeBankingService),
+            "CAO" => Ok(ChargeReasonCode::BilateralAgreementService),
+            "CAP" => Ok(ChargeReasonCode::InsuranceBrokerageService),
+            "CAQ" => Ok(ChargeReasonCode::ChequeGeneration),
+            "CAR" => Ok(ChargeReasonCode::PreferentialMerchandisingLocation),
+            "CAS" => Ok(ChargeReasonCode::Crane),
+            "CAT" => Ok(ChargeReasonCode::SpecialColourService),
+            "CAU" => Ok(ChargeReasonCode::Sorting),
+            "CAV" => Ok(ChargeReasonCode::BatteryCollectionAndRecycling),
+            "CAW" => Ok(ChargeReasonCode::ProductTakeBackFee),
+            "CAX" => Ok(ChargeReasonCode::QualityControlReleased),
+            "CAY" => Ok(ChargeReasonCode::QualityControlHeld),
+            "CAZ" => Ok(ChargeReasonCode::QualityControlEmbargo),
+            "CD" => Ok(ChargeReasonCode::CarLoading),
+            "CG" => Ok(ChargeReasonCode::Cleaning),
+            "CS" => Ok(ChargeReasonCode::CigaretteStamping),
+            "CT" => Ok(ChargeReasonCode::CountAndRecount),
+            "DAB" => Ok(ChargeReasonCode::LayoutDesign),
+            "DAC" => Ok(ChargeReasonCode::AssortmentAllowance),
+            "DAD" => Ok(ChargeReasonCode::DriverAssignedUnloading),
+            "DAF" => Ok(ChargeReasonCode::DebtorBound),
+            "DAG" => Ok(ChargeReasonCode::DealerAllowance),
+            "DAH" => Ok(ChargeReasonCode::AllowanceTransferableToTheConsumer),
+            "DAI" => Ok(ChargeReasonCode::GrowthOfBusiness),
+            "DAJ" => Ok(ChargeReasonCode::IntroductionAllowance),
+            "DAK" => Ok(ChargeReasonCode::MultiBuyPromotion),
+            "DAL" => Ok(ChargeReasonCode::Partnership),
+            "DAM" => Ok(ChargeReasonCode::ReturnHandling),
+            "DAN" => Ok(ChargeReasonCode::MinimumOrderNotFulfilledCharge),
+            "DAO" => Ok(ChargeReasonCode::PointOfSalesThresholdAllowance),
+            "DAP" => Ok(ChargeReasonCode::WholesalingDiscount),
+            "DAQ" => Ok(ChargeReasonCode::DocumentaryCreditsTransferCommission),
+            "DL" => Ok(ChargeReasonCode::Delivery),
+            "EG" => Ok(ChargeReasonCode::Engraving),
+            "EP" => Ok(ChargeReasonCode::Expediting),
+            "ER" => Ok(ChargeReasonCode::ExchangeRateGuarantee),
+            "FAA" => Ok(ChargeReasonCode::Fabrication),
+            "FAB" => Ok(ChargeReasonCode::FreightEqualization),
+            "FAC" => Ok(ChargeReasonCode::FreightExtraordinaryHandling),
+            "FC" => Ok(ChargeReasonCode::FreightService),
+            "FH" => Ok(ChargeReasonCode::FillingHandling),
+            "FI" => Ok(ChargeReasonCode::Financing),
+            "GAA" => Ok(ChargeReasonCode::Grinding),
+            "HAA" => Ok(ChargeReasonCode::Hose),
+            "HD" => Ok(ChargeReasonCode::Handling),
+            "HH" => Ok(ChargeReasonCode::HoistingAndHauling),
+            "IAA" => Ok(ChargeReasonCode::Installation),
+            "IAB" => Ok(ChargeReasonCode::InstallationAndWarranty),
+            "ID" => Ok(ChargeReasonCode::InsideDelivery),
+            "IF" => Ok(ChargeReasonCode::Inspection),
+            "IN" => Ok(ChargeReasonCode::Insurance),
+            "IR" => Ok(ChargeReasonCode::InstallationAndTraining),
+            "IS" => Ok(ChargeReasonCode::Invoicing),
+            "KO" => Ok(ChargeReasonCode::Koshering),
+            "L1" => Ok(ChargeReasonCode::CarrierCount),
+            "LA" => Ok(ChargeReasonCode::Labelling),
+            "LAA" => Ok(ChargeReasonCode::Labour),
+            "LAB" => Ok(ChargeReasonCode::RepairAndReturn),
+            "LF" => Ok(ChargeReasonCode::Legalisation),
+            "MAE" => Ok(ChargeReasonCode::Mounting),
+            "MI" => Ok(ChargeReasonCode::MailInvoice),
+            "ML" => Ok(ChargeReasonCode::MailInvoiceToEachLocation),
+            "NAA" => Ok(ChargeReasonCode::NonReturnableContainers),
+            "OA" => Ok(ChargeReasonCode::OutsideCableConnectors),
+            "PA" => Ok(ChargeReasonCode::InvoiceWithShipment),
+            "PAA" => Ok(ChargeReasonCode::Phosphatizing),
+            "PC" => Ok(ChargeReasonCode::Packing),
+            "PL" => Ok(ChargeReasonCode::Palletizing),
+            "PRV" => Ok(ChargeReasonCode::PriceVariation),
+            "RAB" => Ok(ChargeReasonCode::Repacking),
+            "RAC" => Ok(ChargeReasonCode::Repair),
+            "RAD" => Ok(ChargeReasonCode::ReturnableContainer),
+            "RAF" => Ok(ChargeReasonCode::Restocking),
+            "RE" => Ok(ChargeReasonCode::ReDelivery),
+            "RF" => Ok(ChargeReasonCode::Refurbishing),
+            "RH" => Ok(ChargeReasonCode::RailWagonHire),
+            "RV" => Ok(ChargeReasonCode::Loading),
+            "SA" => Ok(ChargeReasonCode::Salvaging),
+            "SAA" => Ok(ChargeReasonCode::ShippingAndHandling),
+            "SAD" => Ok(ChargeReasonCode::SpecialPackaging),
+            "SAE" => Ok(ChargeReasonCode::Stamping),
+            "SAI" => Ok(ChargeReasonCode::ConsigneeUnload),
+            "SG" => Ok(ChargeReasonCode::ShrinkWrap),
+            "SH" => Ok(ChargeReasonCode::SpecialHandling),
+            "SM" => Ok(ChargeReasonCode::SpecialFinish),
+            "SU" => Ok(ChargeReasonCode::SetUp),
+            "TAB" => Ok(ChargeReasonCode::TankRenting),
+            "TAC" => Ok(ChargeReasonCode::Testing),
+            "TT" => Ok(ChargeReasonCode::TransportationThirdPartyBilling),
+            "TV" => Ok(ChargeReasonCode::TransportationByVendor),
+            "V1" => Ok(ChargeReasonCode::DropYard),
+            "V2" => Ok(ChargeReasonCode::DropDock),
+            "WH" => Ok(ChargeReasonCode::Warehousing),
+            "XAA" => Ok(ChargeReasonCode::CombineAllSameDayShipment),
+            "YY" => Ok(ChargeReasonCode::SplitPickUp),
+            "ZZZ" => Ok(ChargeReasonCode::MutuallyDefined),
+            _ => Err("Charge reason code not detected".into()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::ChargeReasonCode;
+
+    #[test]
+    fn test_try_from() {
+        for code in [ChargeReasonCode::FreightService, ChargeReasonCode::Packing, ChargeReasonCode::Advertising] {
+            let parsed_code = ChargeReasonCode::try_from(code.as_str()).unwrap();
+            assert_eq!(parsed_code, code);
+        }
+        assert!(ChargeReasonCode::try_from("XYZ").is_err());
+    }
+}
@@ -1,38 +1,552 @@
-use serde::{Serialize,Serializer};
+use serde::{Serialize, Serializer};
 
-#[derive(Debug,Clone,Copy)]
+// based on the ISO 4217 list of currency codes, as used by EN 16931 for the invoice currency code (BT-5) and tax currency code (BT-6).
+// Fund, precious metal and testing codes without minor units are left out:
+// https://www.six-group.com/en/products-services/financial-information/data-standards.html
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum CurrencyCode {
+    /// UAE Dirham
+    UaeDirham,
+    /// Afghani
+    AfghanAfghani,
+    /// Lek
+    AlbanianLek,
+    /// Armenian Dram
+    ArmenianDram,
+    /// Kwanza
+    AngolanKwanza,
+    /// Argentine Peso
+    ArgentinePeso,
+    /// Australian Dollar
+    AustralianDollar,
+    /// Aruban Florin
+    ArubanFlorin,
+    /// Azerbaijan Manat
+    AzerbaijaniManat,
+    /// Convertible Mark
+    ConvertibleMark,
+    /// Barbados Dollar
+    BarbadosDollar,
+    /// Taka
+    BangladeshiTaka,
+    /// Bulgarian Lev
+    BulgarianLev,
+    /// Bahraini Dinar
+    BahrainiDinar,
+    /// Burundi Franc
+    BurundiFranc,
+    /// Bermudian Dollar
+    BermudianDollar,
+    /// Brunei Dollar
+    BruneiDollar,
+    /// Boliviano
+    Boliviano,
+    /// Mvdol
+    BolivianMvdol,
+    /// Brazilian Real
+    BrazilianReal,
+    /// Bahamian Dollar
+    BahamianDollar,
+    /// Ngultrum
+    BhutaneseNgultrum,
+    /// Pula
+    BotswanaPula,
+    /// Belarusian Ruble
+    BelarusianRuble,
+    /// Belize Dollar
+    BelizeDollar,
+    /// Canadian Dollar
+    CanadianDollar,
+    /// Congolese Franc
+    CongoleseFranc,
+    /// WIR Euro
+    WirEuro,
+    /// Swiss Franc
+    SwissFranc,
+    /// WIR Franc
+    WirFranc,
+    /// Unidad de Fomento
+    UnidadDeFomento,
+    /// Chilean Peso
+    ChileanPeso,
+    /// Yuan Renminbi
+    YuanRenminbi,
+    /// Colombian Peso
+    ColombianPeso,
+    /// Unidad de Valor Real
+    UnidadDeValorReal,
+    /// Costa Rican Colon
+    CostaRicanColon,
+    /// Cuban Peso
+    CubanPeso,
+    /// Cabo Verde Escudo
+    CaboVerdeEscudo,
+    /// Czech Koruna
+    CzechKoruna,
+    /// Djibouti Franc
+    DjiboutiFranc,
+    /// Danish Krone
+    DanishKrone,
+    /// Dominican Peso
+    DominicanPeso,
+    /// Algerian Dinar
+    AlgerianDinar,
+    /// Egyptian Pound
+    EgyptianPound,
+    /// Nakfa
+    EritreanNakfa,
+    /// Ethiopian Birr
+    EthiopianBirr,
+    /// Euro
     Euro,
+    /// Fiji Dollar
+    FijiDollar,
+    /// Falkland Islands Pound
+    FalklandIslandsPound,
+    /// Pound Sterling
     BritishPound,
-    SwissFranc,
+    /// Lari
+    GeorgianLari,
+    /// Ghana Cedi
+    GhanaCedi,
+    /// Gibraltar Pound
+    GibraltarPound,
+    /// Dalasi
+    GambianDalasi,
+    /// Guinean Franc
+    GuineanFranc,
+    /// Quetzal
+    GuatemalanQuetzal,
+    /// Guyana Dollar
+    GuyanaDollar,
+    /// Hong Kong Dollar
+    HongKongDollar,
+    /// Lempira
+    HonduranLempira,
+    /// Gourde
+    HaitianGourde,
+    /// Forint
+    HungarianForint,
+    /// Rupiah
+    IndonesianRupiah,
+    /// New Israeli Sheqel
+    NewIsraeliSheqel,
+    /// Indian Rupee
+    IndianRupee,
+    /// Iraqi Dinar
+    IraqiDinar,
+    /// Iranian Rial
+    IranianRial,
+    /// Iceland Krona
+    IcelandKrona,
+    /// Jamaican Dollar
+    JamaicanDollar,
+    /// Jordanian Dinar
+    JordanianDinar,
+    /// Yen
+    JapaneseYen,
+    /// Kenyan Shilling
+    KenyanShilling,
+    /// Som
+    KyrgyzstaniSom,
+    /// Riel
+    CambodianRiel,
+    /// Comorian Franc
+    ComorianFranc,
+    /// North Korean Won
+    NorthKoreanWon,
+    /// Won
+    SouthKoreanWon,
+    /// Kuwaiti Dinar
+    KuwaitiDinar,
+    /// Cayman Islands Dollar
+    CaymanIslandsDollar,
+    /// Tenge
+    KazakhstaniTenge,
+    /// Lao Kip
+    LaoKip,
+    /// Lebanese Pound
+    LebanesePound,
+    /// Sri Lanka Rupee
+    SriLankaRupee,
+    /// Liberian Dollar
+    LiberianDollar,
+    /// Loti
+    LesothoLoti,
+    /// Libyan Dinar
+    LibyanDinar,
+    /// Moroccan Dirham
+    MoroccanDirham,
+    /// Moldovan Leu
+    MoldovanLeu,
+    /// Malagasy Ariary
+    MalagasyAriary,
+    /// Denar
+    MacedonianDenar,
+    /// Kyat
+    MyanmarKyat,
+    /// Tugrik
+    MongolianTugrik,
+    /// Pataca
+    MacanesePataca,
+    /// Ouguiya
+    MauritanianOuguiya,
+    /// Mauritius Rupee
+    MauritiusRupee,
+    /// Rufiyaa
+    MaldivianRufiyaa,
+    /// Malawi Kwacha
+    MalawiKwacha,
+    /// Mexican Peso
+    MexicanPeso,
+    /// Mexican Unidad de Inversion (UDI)
+    MexicanUnidadDeInversion,
+    /// Malaysian Ringgit
+    MalaysianRinggit,
+    /// Mozambique Metical
+    MozambiqueMetical,
+    /// Namibia Dollar
+    NamibiaDollar,
+    /// Naira
+    NigerianNaira,
+    /// Cordoba Oro
+    CordobaOro,
+    /// Norwegian Krone
     NorwegianKrone,
-    SwedishKrona,
-    DanishKrone,
+    /// Nepalese Rupee
+    NepaleseRupee,
+    /// New Zealand Dollar
+    NewZealandDollar,
+    /// Rial Omani
+    RialOmani,
+    /// Balboa
+    PanamanianBalboa,
+    /// Sol
+    PeruvianSol,
+    /// Kina
+    PapuaNewGuineanKina,
+    /// Philippine Peso
+    PhilippinePeso,
+    /// Pakistan Rupee
+    PakistanRupee,
+    /// Zloty
     PolishZloty,
-    HungarianForint,
-    CzechKoruna,
+    /// Guarani
+    ParaguayanGuarani,
+    /// Qatari Rial
+    QatariRial,
+    /// Romanian Leu
     RomanianLeu,
-    BulgarianLev,
-    CroatianKuna,
+    /// Serbian Dinar
+    SerbianDinar,
+    /// Russian Ruble
+    RussianRuble,
+    /// Rwanda Franc
+    RwandaFranc,
+    /// Saudi Riyal
+    SaudiRiyal,
+    /// Solomon Islands Dollar
+    SolomonIslandsDollar,
+    /// Seychelles Rupee
+    SeychellesRupee,
+    /// Sudanese Pound
+    SudanesePound,
+    /// Swedish Krona
+    SwedishKrona,
+    /// Singapore Dollar
+    SingaporeDollar,
+    /// Saint Helena Pound
+    SaintHelenaPound,
+    /// Leone
+    SierraLeoneLeone,
+    /// Somali Shilling
+    SomaliShilling,
+    /// Surinam Dollar
+    SurinamDollar,
+    /// South Sudanese Pound
+    SouthSudanesePound,
+    /// Dobra
+    SaoTomeAndPrincipeDobra,
+    /// El Salvador Colon
+    ElSalvadorColon,
+    /// Syrian Pound
+    SyrianPound,
+    /// Lilangeni
+    SwaziLilangeni,
+    /// Baht
+    ThaiBaht,
+    /// Somoni
+    TajikistaniSomoni,
+    /// Turkmenistan New Manat
+    TurkmenistanNewManat,
+    /// Tunisian Dinar
+    TunisianDinar,
+    /// Pa'anga
+    TonganPaanga,
+    /// Turkish Lira
+    TurkishLira,
+    /// Trinidad and Tobago Dollar
+    TrinidadAndTobagoDollar,
+    /// New Taiwan Dollar
+    NewTaiwanDollar,
+    /// Tanzanian Shilling
+    TanzanianShilling,
+    /// Hryvnia
+    UkrainianHryvnia,
+    /// Uganda Shilling
+    UgandaShilling,
+    /// US Dollar
+    UsDollar,
+    /// US Dollar (Next day)
+    UsDollarNextDay,
+    /// Uruguay Peso en Unidades Indexadas (UI)
+    UruguayPesoEnUnidadesIndexadas,
+    /// Peso Uruguayo
+    PesoUruguayo,
+    /// Unidad Previsional
+    UnidadPrevisional,
+    /// Uzbekistan Sum
+    UzbekistanSum,
+    /// Bolívar Soberano (digital)
+    BolivarDigital,
+    /// Bolívar Soberano
+    BolivarSoberano,
+    /// Dong
+    VietnameseDong,
+    /// Vatu
+    VanuatuVatu,
+    /// Tala
+    SamoanTala,
+    /// CFA Franc BEAC
+    CfaFrancBeac,
+    /// East Caribbean Dollar
+    EastCaribbeanDollar,
+    /// Caribbean Guilder
+    CaribbeanGuilder,
+    /// CFA Franc BCEAO
+    CfaFrancBceao,
+    /// CFP Franc
+    CfpFranc,
+    /// Yemeni Rial
+    YemeniRial,
+    /// Rand
+    SouthAfricanRand,
+    /// Zambian Kwacha
+    ZambianKwacha,
+    /// Zimbabwe Gold
+    ZimbabweGold,
 }
 
 impl CurrencyCode {
     pub fn as_str(&self) -> &str {
         match self {
+            CurrencyCode::UaeDirham => "AED",
+            CurrencyCode::AfghanAfghani => "AFN",
+            CurrencyCode::AlbanianLek => "ALL",
+            CurrencyCode::ArmenianDram => "AMD",
+            CurrencyCode::AngolanKwanza => "AOA",
+            CurrencyCode::ArgentinePeso => "ARS",
+            CurrencyCode::AustralianDollar => "AUD",
+            CurrencyCode::ArubanFlorin => "AWG",
+            CurrencyCode::AzerbaijaniManat => "AZN",
+            CurrencyCode::ConvertibleMark => "BAM",
+            CurrencyCode::BarbadosDollar => "BBD",
+            CurrencyCode::BangladeshiTaka => "BDT",
+            CurrencyCode::BulgarianLev => "BGN",
+            CurrencyCode::BahrainiDinar => "BHD",
+            CurrencyCode::BurundiFranc => "BIF",
+            CurrencyCode::BermudianDollar => "BMD",
+            CurrencyCode::BruneiDollar => "BND",
+            CurrencyCode::Boliviano => "BOB",
+            CurrencyCode::BolivianMvdol => "BOV",
+            CurrencyCode::BrazilianReal => "BRL",
+            CurrencyCode::BahamianDollar => "BSD",
+            CurrencyCode::BhutaneseNgultrum => "BTN",
+            CurrencyCode::BotswanaPula => "BWP",
+            CurrencyCode::BelarusianRuble => "BYN",
+            CurrencyCode::BelizeDollar => "BZD",
+            CurrencyCode::CanadianDollar => "CAD",
+            CurrencyCode::CongoleseFranc => "CDF",
+            CurrencyCode::WirEuro => "CHE",
+            CurrencyCode::SwissFranc => "CHF",
+            CurrencyCode::WirFranc => "CHW",
+            CurrencyCode::UnidadDeFomento => "CLF",
+            CurrencyCode::ChileanPeso => "CLP",
+            CurrencyCode::YuanRenminbi => "CNY",
+            CurrencyCode::ColombianPeso => "COP",
+            CurrencyCode::UnidadDeValorReal => "COU",
+            CurrencyCode::CostaRicanColon => "CRC",
+            CurrencyCode::CubanPeso => "CUP",
+            CurrencyCode::CaboVerdeEscudo => "CVE",
+            CurrencyCode::CzechKoruna => "CZK",
+            CurrencyCode::DjiboutiFranc => "DJF",
+            CurrencyCode::DanishKrone => "DKK",
+            CurrencyCode::DominicanPeso => "DOP",
+            CurrencyCode::AlgerianDinar => "DZD",
+            CurrencyCode::EgyptianPound => "EGP",
+            CurrencyCode::EritreanNakfa => "ERN",
+            CurrencyCode::EthiopianBirr => "ETB",
             CurrencyCode::Euro => "EUR",
+            CurrencyCode::FijiDollar => "FJD",
+            CurrencyCode::FalklandIslandsPound => "FKP",
             CurrencyCode::BritishPound => "GBP",
-            CurrencyCode::SwissFranc => "CHF",
+            CurrencyCode::GeorgianLari => "GEL",
+            CurrencyCode::GhanaCedi => "GHS",
+            CurrencyCode::GibraltarPound => "GIP",
+            CurrencyCode::GambianDalasi => "GMD",
+            CurrencyCode::GuineanFranc => "GNF",
+            CurrencyCode::GuatemalanQuetzal => "GTQ",
+            CurrencyCode::GuyanaDollar => "GYD",
+            CurrencyCode::HongKongDollar => "HKD",
+            CurrencyCode::HonduranLempira => "HNL",
+            CurrencyCode::HaitianGourde => "HTG",
+            CurrencyCode::HungarianForint => "HUF",
+            CurrencyCode::IndonesianRupiah => "IDR",
+            CurrencyCode::NewIsraeliSheqel => "ILS",
+            CurrencyCode::IndianRupee => "INR",
+            CurrencyCode::IraqiDinar => "IQD",
+            CurrencyCode::IranianRial => "IRR",
+            CurrencyCode::IcelandKrona => "ISK",
+            CurrencyCode::JamaicanDollar => "JMD",
+            CurrencyCode::JordanianDinar => "JOD",
+            CurrencyCode::JapaneseYen => "JPY",
+            CurrencyCode::KenyanShilling => "KES",
+            CurrencyCode::KyrgyzstaniSom => "KGS",
+            CurrencyCode::CambodianRiel => "KHR",
+            CurrencyCode::ComorianFranc => "KMF",
+            CurrencyCode::NorthKoreanWon => "KPW",
+            CurrencyCode::SouthKoreanWon => "KRW",
+            CurrencyCode::KuwaitiDinar => "KWD",
+            CurrencyCode::CaymanIslandsDollar => "KYD",
+            CurrencyCode::KazakhstaniTenge => "KZT",
+            CurrencyCode::LaoKip => "LAK",
+            CurrencyCode::LebanesePound => "LBP",
+            CurrencyCode::SriLankaRupee => "LKR",
+            CurrencyCode::LiberianDollar => "LRD",
+            CurrencyCode::LesothoLoti => "LSL",
+            CurrencyCode::LibyanDinar => "LYD",
+            CurrencyCode::MoroccanDirham => "MAD",
+            CurrencyCode::MoldovanLeu => "MDL",
+            CurrencyCode::MalagasyAriary => "MGA",
+            CurrencyCode::MacedonianDenar => "MKD",
+            CurrencyCode::MyanmarKyat => "MMK",
+            CurrencyCode::MongolianTugrik => "MNT",
+            CurrencyCode::MacanesePataca => "MOP",
+            CurrencyCode::MauritanianOuguiya => "MRU",
+            CurrencyCode::MauritiusRupee => "MUR",
+            CurrencyCode::MaldivianRufiyaa => "MVR",
+            CurrencyCode::MalawiKwacha => "MWK",
+            CurrencyCode::MexicanPeso => "MXN",
+            CurrencyCode::MexicanUnidadDeInversion => "MXV",
+            CurrencyCode::MalaysianRinggit => "MYR",
+            CurrencyCode::MozambiqueMetical => "MZN",
+            CurrencyCode::NamibiaDollar => "NAD",
+            CurrencyCode::NigerianNaira => "NGN",
+            CurrencyCode::CordobaOro => "NIO",
             CurrencyCode::NorwegianKrone => "NOK",
-            CurrencyCode::SwedishKrona => "SEK",
-            CurrencyCode::DanishKrone => "DKK",
+            CurrencyCode::NepaleseRupee => "NPR",
+            CurrencyCode::NewZealandDollar => "NZD",
+            CurrencyCode::RialOmani => "OMR",
+            CurrencyCode::PanamanianBalboa => "PAB",
+            CurrencyCode::PeruvianSol => "PEN",
+            CurrencyCode::PapuaNewGuineanKina => "PGK",
+            CurrencyCode::PhilippinePeso => "PHP",
+            CurrencyCode::PakistanRupee => "PKR",
             CurrencyCode::PolishZloty => "PLN",
-            CurrencyCode::HungarianForint => "HUF",
-            CurrencyCode::CzechKoruna => "CZK",
+            CurrencyCode::ParaguayanGuarani => "PYG",
+            CurrencyCode::QatariRial => "QAR",
             CurrencyCode::RomanianLeu => "RON",
-            CurrencyCode::BulgarianLev => "BGN",
-            CurrencyCode::CroatianKuna => "HRK",
+            CurrencyCode::SerbianDinar => "RSD",
+            CurrencyCode::RussianRuble => "RUB",
+            CurrencyCode::RwandaFranc => "RWF",
+            CurrencyCode::SaudiRiyal => "SAR",
+            CurrencyCode::SolomonIslandsDollar => "SBD",
+            CurrencyCode::SeychellesRupee => "SCR",
+            CurrencyCode::SudanesePound => "SDG",
+            CurrencyCode::SwedishKrona => "SEK",
+            CurrencyCode::SingaporeDollar => "SGD",
+            CurrencyCode::SaintHelenaPound => "SHP",
+            CurrencyCode::SierraLeoneLeone => "SLE",
+            CurrencyCode::SomaliShilling => "SOS",
+            CurrencyCode::SurinamDollar => "SRD",
+            CurrencyCode::SouthSudanesePound => "SSP",
+            CurrencyCode::SaoTomeAndPrincipeDobra => "STN",
+            CurrencyCode::ElSalvadorColon => "SVC",
+            CurrencyCode::SyrianPound => "SYP",
+            CurrencyCode::SwaziLilangeni => "SZL",
+            CurrencyCode::ThaiBaht => "THB",
+            CurrencyCode::TajikistaniSomoni => "TJS",
+            CurrencyCode::TurkmenistanNewManat => "TMT",
+            CurrencyCode::TunisianDinar => "TND",
+            CurrencyCode::TonganPaanga => "TOP",
+            CurrencyCode::TurkishLira => "TRY",
+            CurrencyCode::TrinidadAndTobagoDollar => "TTD",
+            CurrencyCode::NewTaiwanDollar => "TWD",
+            CurrencyCode::TanzanianShilling => "TZS",
+            CurrencyCode::UkrainianHryvnia => "UAH",
+            CurrencyCode::UgandaShilling => "UGX",
+            CurrencyCode::UsDollar => "USD",
+            CurrencyCode::UsDollarNextDay => "USN",
+            CurrencyCode::UruguayPesoEnUnidadesIndexadas => "UYI",
+            CurrencyCode::PesoUruguayo => "UYU",
+            CurrencyCode::UnidadPrevisional => "UYW",
+            CurrencyCode::UzbekistanSum => "UZS",
+            CurrencyCode::BolivarDigital => "VED",
+            CurrencyCode::BolivarSoberano => "VES",
+            CurrencyCode::VietnameseDong => "VND",
+            CurrencyCode::VanuatuVatu => "VUV",
+            CurrencyCode::SamoanTala => "WST",
+            CurrencyCode::CfaFrancBeac => "XAF",
+            CurrencyCode::EastCaribbeanDollar => "XCD",
+            CurrencyCode::CaribbeanGuilder => "XCG",
+            CurrencyCode::CfaFrancBceao => "XOF",
+            CurrencyCode::CfpFranc => "XPF",
+            CurrencyCode::YemeniRial => "YER",
+            CurrencyCode::SouthAfricanRand => "ZAR",
+            CurrencyCode::ZambianKwacha => "ZMW",
+            CurrencyCode::ZimbabweGold => "ZWG",
+        }
+    }
+
+    /// Number of decimal places of the minor unit, e.g. 2 for Euro cents and 0 for the Yen
+    pub fn minor_units(&self) -> u8 {
+        match self {
+            CurrencyCode::BurundiFranc
+            | CurrencyCode::ChileanPeso
+            | CurrencyCode::DjiboutiFranc
+            | CurrencyCode::GuineanFranc
+            | CurrencyCode::IcelandKrona
+            | CurrencyCode::JapaneseYen
+            | CurrencyCode::ComorianFranc
+            | CurrencyCode::SouthKoreanWon
+            | CurrencyCode::ParaguayanGuarani
+            | CurrencyCode::RwandaFranc
+            | CurrencyCode::UgandaShilling
+            | CurrencyCode::UruguayPesoEnUnidadesIndexadas
+            | CurrencyCode::VietnameseDong
+            | CurrencyCode::VanuatuVatu
+            | CurrencyCode::CfaFrancBeac
+            | CurrencyCode::CfaFrancBceao
+            | CurrencyCode::CfpFranc => 0,
+            CurrencyCode::BahrainiDinar
+            | CurrencyCode::IraqiDinar
+            | CurrencyCode::JordanianDinar
+            | CurrencyCode::KuwaitiDinar
+            | CurrencyCode::LibyanDinar
+            | CurrencyCode::RialOmani
+            | CurrencyCode::TunisianDinar => 3,
+            CurrencyCode::UnidadDeFomento
+            | CurrencyCode::UnidadPrevisional => 4,
+            _ => 2,
         }
     }
+
+    /// Rounds an amount to the minor units of the currency
+    pub fn round(&self, amount: f64) -> f64 {
+        let factor = 10f64.powi(self.minor_units() as i32);
+        (amount * factor).round() / factor
+    }
 }
 
 impl Serialize for CurrencyCode {
@@ -42,4 +556,179 @@ impl Serialize for CurrencyCode {
     {
         serializer.serialize_str(self.as_str())
     }
-}
\ No newline at end of file
+}
+
+impl TryFrom<&str> for CurrencyCode {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "AED" => Ok(CurrencyCode::UaeDirham),
+            "AFN" => Ok(CurrencyCode::AfghanAfghani),
+            "ALL" => Ok(CurrencyCode::AlbanianLek),
+            "AMD" => Ok(CurrencyCode::ArmenianDram),
+            "AOA" => Ok(CurrencyCode::AngolanKwanza),
+            "ARS" => Ok(CurrencyCode::ArgentinePeso),
+            "AUD" => Ok(CurrencyCode::AustralianDollar),
+            "AWG" => Ok(CurrencyCode::ArubanFlorin),
+            "AZN" => Ok(CurrencyCode::AzerbaijaniManat),
+            "BAM" => Ok(CurrencyCode::ConvertibleMark),
+            "BBD" => Ok(CurrencyCode::BarbadosDollar),
+            "BDT" => Ok(CurrencyCode::BangladeshiTaka),
+            "BGN" => Ok(CurrencyCode::BulgarianLev),
+            "BHD" => Ok(CurrencyCode::BahrainiDinar),
+            "BIF" => Ok(CurrencyCode::BurundiFranc),
+            "BMD" => Ok(CurrencyCode::BermudianDollar),
+            "BND" => Ok(CurrencyCode::BruneiDollar),
+            "BOB" => Ok(CurrencyCode::Boliviano),
+            "BOV" => Ok(CurrencyCode::BolivianMvdol),
+            "BRL" => Ok(CurrencyCode::BrazilianReal),
+            "BSD" => Ok(CurrencyCode::BahamianDollar),
+            "BTN" => Ok(CurrencyCode::BhutaneseNgultrum),
+            "BWP" => Ok(CurrencyCode::BotswanaPula),
+            "BYN" => Ok(CurrencyCode::BelarusianRuble),
+            "BZD" => Ok(CurrencyCode::BelizeDollar),
+            "CAD" => Ok(CurrencyCode::CanadianDollar),
+            "CDF" => Ok(CurrencyCode::CongoleseFranc),
+            "CHE" => Ok(CurrencyCode::WirEuro),
+            "CHF" => Ok(CurrencyCode::SwissFranc),
+            "CHW" => Ok(CurrencyCode::WirFranc),
+            "CLF" => Ok(CurrencyCode::UnidadDeFomento),
+            "CLP" => Ok(CurrencyCode::ChileanPeso),
+            "CNY" => Ok(CurrencyCode::YuanRenminbi),
+            "COP" => Ok(CurrencyCode::ColombianPeso),
+            "COU" => Ok(CurrencyCode::UnidadDeValorReal),
+            "CRC" => Ok(CurrencyCode::CostaRicanColon),
+            "CUP" => Ok(CurrencyCode::CubanPeso),
+            "CVE" => Ok(CurrencyCode::CaboVerdeEscudo),
+            "CZK" => Ok(CurrencyCode::CzechKoruna),
+            "DJF" => Ok(CurrencyCode::DjiboutiFranc),
+            "DKK" => Ok(CurrencyCode::DanishKrone),
+            "DOP" => Ok(CurrencyCode::DominicanPeso),
+            "DZD" => Ok(CurrencyCode::AlgerianDinar),
+            "EGP" => Ok(CurrencyCode::EgyptianPound),
+            "ERN" => Ok(CurrencyCode::EritreanNakfa),
+            "ETB" => Ok(CurrencyCode::EthiopianBirr),
+            "EUR" => Ok(CurrencyCode::Euro),
+            "FJD" => Ok(CurrencyCode::FijiDollar),
+            "FKP" => Ok(CurrencyCode::FalklandIslandsPound),
+            "GBP" => Ok(CurrencyCode::BritishPound),
+            "GEL" => Ok(CurrencyCode::GeorgianLari),
+            "GHS" => Ok(CurrencyCode::GhanaCedi),
+            "GIP" => Ok(CurrencyCode::GibraltarPound),
+            "GMD" => Ok(CurrencyCode::GambianDalasi),
+            "GNF" => Ok(CurrencyCode::GuineanFranc),
+            "GTQ" => Ok(CurrencyCode::GuatemalanQuetzal),
+            "GYD" => Ok(CurrencyCode::GuyanaDollar),
+            "HKD" => Ok(CurrencyCode::HongKongDollar),
+            "HNL" => Ok(CurrencyCode::HonduranLempira),
+            "HTG" => Ok(CurrencyCode::HaitianGourde),
+            "HUF" => Ok(CurrencyCode::HungarianForint),
+            "IDR" => Ok(CurrencyCode::IndonesianRupiah),
+            "ILS" => Ok(CurrencyCode::NewIsraeliSheqel),
+            "INR" => Ok(CurrencyCode::IndianRupee),
+            "IQD" => Ok(CurrencyCode::IraqiDinar),
+            "IRR" => Ok(CurrencyCode::IranianRial),
+            "ISK" => Ok(CurrencyCode::IcelandKrona),
+            "JMD" => Ok(CurrencyCode::JamaicanDollar),
+            "JOD" => Ok(CurrencyCode::JordanianDinar),
+            "JPY" => Ok(CurrencyCode::JapaneseYen),
+            "KES" => Ok(CurrencyCode::KenyanShilling),
+            "KGS" => Ok(CurrencyCode::KyrgyzstaniSom),
+            "KHR" => Ok(CurrencyCode::CambodianRiel),
+            "KMF" => Ok(CurrencyCode::ComorianFranc),
+            "KPW" => Ok(CurrencyCode::NorthKoreanWon),
+            "KRW" => Ok(CurrencyCode::SouthKoreanWon),
+            "KWD" => Ok(CurrencyCode::KuwaitiDinar),
+            "KYD" => Ok(CurrencyCode::CaymanIslandsDollar),
+            "KZT" => Ok(CurrencyCode::KazakhstaniTenge),
+            "LAK" => Ok(CurrencyCode::LaoKip),
+            "LBP" => Ok(CurrencyCode::LebanesePound),
+            "LKR" => Ok(CurrencyCode::SriLankaRupee),
+            "LRD" => Ok(CurrencyCode::LiberianDollar),
+            "LSL" => Ok(CurrencyCode::LesothoLoti),
+            "LYD" => Ok(CurrencyCode::LibyanDinar),
+            "MAD" => Ok(CurrencyCode::MoroccanDirham),
+            "MDL" => Ok(CurrencyCode::MoldovanLeu),
+            "MGA" => Ok(CurrencyCode::MalagasyAriary),
+            "MKD" => Ok(CurrencyCode::MacedonianDenar),
+            "MMK" => Ok(CurrencyCode::MyanmarKyat),
+            "MNT" => Ok(CurrencyCode::MongolianTugrik),
+            "MOP" => Ok(CurrencyCode::MacanesePataca),
+            "MRU" => Ok(CurrencyCode::MauritanianOuguiya),
+            "MUR" => Ok(CurrencyCode::MauritiusRupee),
+            "MVR" => Ok(CurrencyCode::MaldivianRufiyaa),
+            "MWK" => Ok(CurrencyCode::MalawiKwacha),
+            "MXN" => Ok(CurrencyCode::MexicanPeso),
+            "MXV" => Ok(CurrencyCode::MexicanUnidadDeInversion),
+            "MYR" => Ok(CurrencyCode::MalaysianRinggit),
+            "MZN" => Ok(CurrencyCode::MozambiqueMetical),
+            "NAD" => Ok(CurrencyCode::NamibiaDollar),
+            "NGN" => Ok(CurrencyCode::NigerianNaira),
+            "NIO" => Ok(CurrencyCode::CordobaOro),
+            "NOK" => Ok(CurrencyCode::NorwegianKrone),
+            "NPR" => Ok(CurrencyCode::NepaleseRupee),
+            "NZD" => Ok(CurrencyCode::NewZealandDollar),
+            "OMR" => Ok(CurrencyCode::RialOmani),
+            "PAB" => Ok(CurrencyCode::PanamanianBalboa),
+            "PEN" => Ok(CurrencyCode::PeruvianSol),
+            "PGK" => Ok(CurrencyCode::PapuaNewGuineanKina),
+            "PHP" => Ok(CurrencyCode::PhilippinePeso),
+            "PKR" => Ok(CurrencyCode::PakistanRupee),
+            "PLN" => Ok(CurrencyCode::PolishZloty),
+            "PYG" => Ok(CurrencyCode::ParaguayanGuarani),
+            "QAR" => Ok(CurrencyCode::QatariRial),
+            "RON" => Ok(CurrencyCode::RomanianLeu),
+            "RSD" => Ok(CurrencyCode::SerbianDinar),
+            "RUB" => Ok(CurrencyCode::RussianRuble),
+            "RWF" => Ok(CurrencyCode::RwandaFranc),
+            "SAR" => Ok(CurrencyCode::SaudiRiyal),
+            "SBD" => Ok(CurrencyCode::SolomonIslandsDollar),
+            "SCR" => Ok(CurrencyCode::SeychellesRupee),
+            "SDG" => Ok(CurrencyCode::SudanesePound),
+            "SEK" => Ok(CurrencyCode::SwedishKrona),
+            "SGD" => Ok(CurrencyCode::SingaporeDollar),
+            "SHP" => Ok(CurrencyCode::SaintHelenaPound),
+            "SLE" => Ok(CurrencyCode::SierraLeoneLeone),
+            "SOS" => Ok(CurrencyCode::SomaliShilling),
+            "SRD" => Ok(CurrencyCode::SurinamDollar),
+            "SSP" => Ok(CurrencyCode::SouthSudanesePound),
+            "STN" => Ok(CurrencyCode::SaoTomeAndPrincipeDobra),
+            "SVC" => Ok(CurrencyCode::ElSalvadorColon),
+            "SYP" => Ok(CurrencyCode::SyrianPound),
+            "SZL" => Ok(CurrencyCode::SwaziLilangeni),
+            "THB" => Ok(CurrencyCode::ThaiBaht),
+            "TJS" => Ok(CurrencyCode::TajikistaniSomoni),
+            "TMT" => Ok(CurrencyCode::TurkmenistanNewManat),
+            "TND" => Ok(CurrencyCode::TunisianDinar),
+            "TOP" => Ok(CurrencyCode::TonganPaanga),
+            "TRY" => Ok(CurrencyCode::TurkishLira),
+            "TTD" => Ok(CurrencyCode::TrinidadAndTobagoDollar),
+            "TWD" => Ok(CurrencyCode::NewTaiwanDollar),
+            "TZS" => Ok(CurrencyCode::TanzanianShilling),
+            "UAH" => Ok(CurrencyCode::UkrainianHryvnia),
+            "UGX" => Ok(CurrencyCode::UgandaShilling),
+            "USD" => Ok(CurrencyCode::UsDollar),
+            "USN" => Ok(CurrencyCode::UsDollarNextDay),
+            "UYI" => Ok(CurrencyCode::UruguayPesoEnUnidadesIndexadas),
+            "UYU" => Ok(CurrencyCode::PesoUruguayo),
+            "UYW" => Ok(CurrencyCode::UnidadPrevisional),
+            "UZS" => Ok(CurrencyCode::UzbekistanSum),
+            "VED" => Ok(CurrencyCode::BolivarDigital),
+            "VES" => Ok(CurrencyCode::BolivarSoberano),
+            "VND" => Ok(CurrencyCode::VietnameseDong),
+            "VUV" => Ok(CurrencyCode::VanuatuVatu),
+            "WST" => Ok(CurrencyCode::SamoanTala),
+            "XAF" => Ok(CurrencyCode::CfaFrancBeac),
+            "XCD" => Ok(CurrencyCode::EastCaribbeanDollar),
+            "XCG" => Ok(CurrencyCode::CaribbeanGuilder),
+            "XOF" => Ok(CurrencyCode::CfaFrancBceao),
+            "XPF" => Ok(CurrencyCode::CfpFranc),
+            "YER" => Ok(CurrencyCode::YemeniRial),
+            "ZAR" => Ok(CurrencyCode::SouthAfricanRand),
+            "ZMW" => Ok(CurrencyCode::ZambianKwacha),
+            "ZWG" => Ok(CurrencyCode::ZimbabweGold),
+            _ => Err("Currency code not detected".into()),
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use serde::{Serialize, Serializer};
+
+// based on the Electronic Address Scheme (EAS) code list:
+// https://ec.europa.eu/digital-building-blocks/sites/display/DIGITAL/Registry+of+supporting+artefacts+to+implement+EN16931
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ElectronicAddressSchemeCode {
+    /// System Information et Repertoire des Entreprise et des Etablissements: SIRENE
+    Sirene,
+    /// Organisationsnummer (Swedish legal entities)
+    Organisationsnummer,
+    /// SIRET-CODE
+    SiretCode,
+    /// LY-tunnus
+    LyTunnus,
+    /// Data Universal Numbering System (D-U-N-S Number)
+    Duns,
+    /// Global Location Number
+    Gln,
+    /// DANISH CHAMBER OF COMMERCE Scheme (EDIRA compliant)
+    DanishChamberOfCommerce,
+    /// FTI - Ediforum Italia, (EDIRA compliant)
+    FtiEdiforumItalia,
+    /// Vereniging van Kamers van Koophandel en Fabrieken in Nederland (Association of Chambers of Commerce and Industry in the Netherlands), Scheme (EDIRA compliant)
+    KamerVanKoophandel,
+    /// Directorates of the European Commission
+    DirectoratesOfTheEuropeanCommission,
+    /// SIA Object Identifiers
+    SiaObjectIdentifiers,
+    /// SECETI Object Identifiers
+    SecetiObjectIdentifiers,
+    /// Standard Company Code
+    StandardCompanyCode,
+    /// Australian Business Number (ABN) Scheme
+    AustralianBusinessNumber,
+    /// Identification number of economic subjects (ICO)
+    IdentificationNumberOfEconomicSubjects,
+    /// Identification number of economic subject (ICO) Act on State Statistics 89/2012 Coll.
+    IdentificationNumberOfEconomicSubjectActOnStateStatistics,
+    /// Teikoku Company Code
+    TeikokuCompanyCode,
+    /// Odette International Limited
+    OdetteInternational,
+    /// Numéro d'identification suisse des enterprises (IDE), Swiss Unique Business Identification Number (UIDB)
+    SwissUniqueBusinessIdentificationNumber,
+    /// DIGSTORG
+    Digstorg,
+    /// Corporate Number of The Social Security and Tax Number System
+    CorporateNumberOfTheSocialSecurityAndTaxNumberSystem,
+    /// Dutch Originator's Identification Number
+    DutchOriginatorsIdentificationNumber,
+    /// Centre of Registers and Information Systems of the Ministry of Justice
+    CentreOfRegistersAndInformationSystems,
+    /// Enhetsregisteret ved Bronnoysundregisterne
+    Enhetsregisteret,
+    /// UBL.BE party identifier
+    UblBePartyIdentifier,
+    /// KOIOS Open Technical Dictionary
+    KoiosOpenTechnicalDictionary,
+    /// Singapore UEN identifier
+    SingaporeUen,
+    /// Kennitala - Iceland legal id for individuals and legal entities
+    Kennitala,
+    /// ERSTORG
+    Erstorg,
+    /// Legal Entity Identifier (LEI)
+    LegalEntityIdentifier,
+    /// Legal entity code (Lithuania)
+    LithuanianLegalEntityCode,
+    /// Codice Univoco Unità Organizzativa iPA
+    CodiceUnivocoUnitaOrganizzativaIpa,
+    /// Indirizzo di Posta Elettronica Certificata
+    IndirizzoDiPostaElettronicaCertificata,
+    /// eDelivery Network Participant identifier
+    EDeliveryNetworkParticipantIdentifier,
+    /// Leitweg-ID
+    LeitwegId,
+    /// CODDEST
+    Coddest,
+    /// Numero d'entreprise / ondernemingsnummer / Unternehmensnummer
+    NumeroDEntreprise,
+    /// GS1 identification keys
+    Gs1IdentificationKeys,
+    /// CODICE FISCALE
+    CodiceFiscale,
+    /// PARTITA IVA
+    PartitaIva,
+    /// Finnish Organization Identifier
+    FinnishOrganizationIdentifier,
+    /// Finnish Organization Value Add Tax Identifier
+    FinnishOrganizationValueAddTaxIdentifier,
+    /// Net service ID
+    NetServiceId,
+    /// OVTcode
+    OvtCode,
+    /// The Netherlands Chamber of Commerce and Industry establishment number
+    NetherlandsChamberOfCommerceEstablishmentNumber,
+    /// Unified registration number (Latvia)
+    LatvianUnifiedRegistrationNumber,
+    /// The registered number of the qualified invoice issuer (Japan)
+    JapaneseQualifiedInvoiceIssuerNumber,
+    /// FRCTC ELECTRONIC ADDRESS
+    FrctcElectronicAddress,
+    /// National e-Invoicing Framework (Malaysia)
+    MalaysianNationalEInvoicingFramework,
+    /// UAE Tax Identification Number (TIN)
+    UaeTaxIdentificationNumber,
+    /// Danish Ministry of the Interior and Health
+    DanishMinistryOfTheInteriorAndHealth,
+    /// Hungary VAT number
+    HungaryVatNumber,
+    /// Business Registers Network
+    BusinessRegistersNetwork,
+    /// Österreichische Umsatzsteuer-Identifikationsnummer
+    AustriaVatNumber,
+    /// Österreichisches Verwaltungs bzw. Organisationskennzeichen
+    AustriaAdministrativeIdentifier,
+    /// SOCIETY FOR WORLDWIDE INTERBANK FINANCIAL, TELECOMMUNICATION S.W.I.F.T
+    Swift,
+    /// Kennziffer des Unternehmensregisters
+    KennzifferDesUnternehmensregisters,
+    /// Agencia Española de Administración Tributaria
+    AgenciaEspanolaDeAdministracionTributaria,
+    /// Andorra VAT number
+    AndorraVatNumber,
+    /// Albania VAT number
+    AlbaniaVatNumber,
+    /// Bosnia and Herzegovina VAT number
+    BosniaAndHerzegovinaVatNumber,
+    /// Belgium VAT number
+    BelgiumVatNumber,
+    /// Bulgaria VAT number
+    BulgariaVatNumber,
+    /// Switzerland VAT number
+    SwitzerlandVatNumber,
+    /// Cyprus VAT number
+    CyprusVatNumber,
+    /// Czech Republic VAT number
+    CzechRepublicVatNumber,
+    /// Germany VAT number
+    GermanyVatNumber,
+    /// Estonia VAT number
+    EstoniaVatNumber,
+    /// United Kingdom VAT number
+    UnitedKingdomVatNumber,
+    /// Greece VAT number
+    GreeceVatNumber,
+    /// Croatia VAT number
+    CroatiaVatNumber,
+    /// Ireland VAT number
+    IrelandVatNumber,
+    /// Liechtenstein VAT number
+    LiechtensteinVatNumber,
+    /// Lithuania VAT number
+    LithuaniaVatNumber,
+    /// Luxemburg VAT number
+    LuxemburgVatNumber,
+    /// Latvia VAT number
+    LatviaVatNumber,
+    /// Monaco VAT number
+    MonacoVatNumber,
+    /// Montenegro VAT number
+    MontenegroVatNumber,
+    /// Macedonia, the former Yugoslav Republic of VAT number
+    NorthMacedoniaVatNumber,
+    /// Malta VAT number
+    MaltaVatNumber,
+    /// Netherlands VAT number
+    NetherlandsVatNumber,
+    /// Poland VAT number
+    PolandVatNumber,
+    /// Portugal VAT number
+    PortugalVatNumber,
+    /// Romania VAT number
+    RomaniaVatNumber,
+    /// Serbia VAT number
+    SerbiaVatNumber,
+    /// Slovenia VAT number
+    SloveniaVatNumber,
+    /// Slovakia VAT number
+    SlovakiaVatNumber,
+    /// San Marino VAT number
+    SanMarinoVatNumber,
+    /// Turkey VAT number
+    TurkeyVatNumber,
+    /// Holy See (Vatican City State) VAT number
+    HolySeeVatNumber,
+    /// French VAT number
+    FranceVatNumber,
+    /// Employer Identification Number (EIN, USA)
+    EmployerIdentificationNumber,
+    /// O.F.T.P. (ODETTE File Transfer Protocol)
+    Oftp,
+    /// X.400 address for mail text
+    X400Address,
+    /// AS2 exchange
+    As2Exchange,
+    /// File Transfer Protocol
+    FileTransferProtocol,
+    /// Electronic mail
+    ElectronicMail,
+}
+
+impl ElectronicAddressSchemeCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ElectronicAddressSchemeCode::Sirene => "0002",
+            ElectronicAddressSchemeCode::Organisationsnummer => "0007",
+            ElectronicAddressSchemeCode::SiretCode => "0009",
+            ElectronicAddressSchemeCode::LyTunnus => "0037",
+            ElectronicAddressSchemeCode::Duns => "0060",
+            ElectronicAddressSchemeCode::Gln => "0088",
+            ElectronicAddressSchemeCode::DanishChamberOfCommerce => "0096",
+            ElectronicAddressSchemeCode::FtiEdiforumItalia => "0097",
+            ElectronicAddressSchemeCode::KamerVanKoophandel => "0106",
+            ElectronicAddressSchemeCode::DirectoratesOfTheEuropeanCommission => "0130",
+            ElectronicAddressSchemeCode::SiaObjectIdentifiers => "0135",
+            ElectronicAddressSchemeCode::SecetiObjectIdentifiers => "0142",
+            ElectronicAddressSchemeCode::StandardCompanyCode => "0147",
+            ElectronicAddressSchemeCode::AustralianBusinessNumber => "0151",
+            ElectronicAddressSchemeCode::IdentificationNumberOfEconomicSubjects => "0154",
+            ElectronicAddressSchemeCode::IdentificationNumberOfEconomicSubjectActOnStateStatistics => "0158",
+            ElectronicAddressSchemeCode::TeikokuCompanyCode => "0170",
+            ElectronicAddressSchemeCode::OdetteInternational => "0177",
+            ElectronicAddressSchemeCode::SwissUniqueBusinessIdentificationNumber => "0183",
+            ElectronicAddressSchemeCode::Digstorg => "0184",
+            ElectronicAddressSchemeCode::CorporateNumberOfTheSocialSecurityAndTaxNumberSystem => "0188",
+            ElectronicAddressSchemeCode::DutchOriginatorsIdentificationNumber => "0190",
+            ElectronicAddressSchemeCode::CentreOfRegistersAndInformationSystems => "0191",
+            ElectronicAddressSchemeCode::Enhetsregisteret => "0192",
+            ElectronicAddressSchemeCode::UblBePartyIdentifier => "0193",
+            ElectronicAddressSchemeCode::KoiosOpenTechnicalDictionary => "0194",
+            ElectronicAddressSchemeCode::SingaporeUen => "0195",
+            ElectronicAddressSchemeCode::Kennitala => "0196",
+            ElectronicAddressSchemeCode::Erstorg => "0198",
+            ElectronicAddressSchemeCode::LegalEntityIdentifier => "0199",
+            ElectronicAddressSchemeCode::LithuanianLegalEntityCode => "0200",
+            ElectronicAddressSchemeCode::CodiceUnivocoUnitaOrganizzativaIpa => "0201",
+            ElectronicAddressSchemeCode::IndirizzoDiPostaElettronicaCertificata => "0202",
+            ElectronicAddressSchemeCode::EDeliveryNetworkParticipantIdentifier => "0203",
+            ElectronicAddressSchemeCode::LeitwegId => "0204",
+            ElectronicAddressSchemeCode::Coddest => "0205",
+            ElectronicAddressSchemeCode::NumeroDEntreprise => "0208",
+            ElectronicAddressSchemeCode::Gs1IdentificationKeys => "0209",
+            ElectronicAddressSchemeCode::CodiceFiscale => "0210",
+            ElectronicAddressSchemeCode::PartitaIva => "0211",
+            ElectronicAddressSchemeCode::FinnishOrganizationIdentifier => "0212",
+            ElectronicAddressSchemeCode::FinnishOrganizationValueAddTaxIdentifier => "0213",
+            ElectronicAddressSchemeCode::NetServiceId => "0215",
+            ElectronicAddressSchemeCode::OvtCode => "0216",
+            ElectronicAddressSchemeCode::NetherlandsChamberOfCommerceEstablishmentNumber => "0217",
+            ElectronicAddressSchemeCode::LatvianUnifiedRegistrationNumber => "0218",
+            ElectronicAddressSchemeCode::JapaneseQualifiedInvoiceIssuerNumber => "0221",
+            ElectronicAddressSchemeCode::FrctcElectronicAddress => "0225",
+            ElectronicAddressSchemeCode::MalaysianNationalEInvoicingFramework => "0230",
+            ElectronicAddressSchemeCode::UaeTaxIdentificationNumber => "0235",
+            ElectronicAddressSchemeCode::DanishMinistryOfTheInteriorAndHealth => "9901",
+            ElectronicAddressSchemeCode::HungaryVatNumber => "9910",
+            ElectronicAddressSchemeCode::BusinessRegistersNetwork => "9913",
+            ElectronicAddressSchemeCode::AustriaVatNumber => "9914",
+            ElectronicAddressSchemeCode::AustriaAdministrativeIdentifier => "9915",
+            ElectronicAddressSchemeCode::Swift => "9918",
+            ElectronicAddressSchemeCode::KennzifferDesUnternehmensregisters => "9919",
+            ElectronicAddressSchemeCode::AgenciaEspanolaDeAdministracionTributaria => "9920",
+            ElectronicAddressSchemeCode::AndorraVatNumber => "9922",
+            ElectronicAddressSchemeCode::AlbaniaVatNumber => "9923",
+            ElectronicAddressSchemeCode::BosniaAndHerzegovinaVatNumber => "9924",
+            ElectronicAddressSchemeCode::BelgiumVatNumber => "9925",
+            ElectronicAddressSchemeCode::BulgariaVatNumber => "9926",
+            ElectronicAddressSchemeCode::SwitzerlandVatNumber => "9927",
+            ElectronicAddressSchemeCode::CyprusVatNumber => "9928",
+            ElectronicAddressSchemeCode::CzechRepublicVatNumber => "9929",
+            ElectronicAddressSchemeCode::GermanyVatNumber => "9930",
+            ElectronicAddressSchemeCode::EstoniaVatNumber => "9931",
+            ElectronicAddressSchemeCode::UnitedKingdomVatNumber => "9932",
+            ElectronicAddressSchemeCode::GreeceVatNumber => "9933",
+            ElectronicAddressSchemeCode::CroatiaVatNumber => "9934",
+            ElectronicAddressSchemeCode::IrelandVatNumber => "9935",
+            ElectronicAddressSchemeCode::LiechtensteinVatNumber => "9936",
+            ElectronicAddressSchemeCode::LithuaniaVatNumber => "9937",
+            ElectronicAddressSchemeCode::LuxemburgVatNumber => "9938",
+            ElectronicAddressSchemeCode::LatviaVatNumber => "9939",
+            ElectronicAddressSchemeCode::MonacoVatNumber => "9940",
+            ElectronicAddressSchemeCode::MontenegroVatNumber => "9941",
+            ElectronicAddressSchemeCode::NorthMacedoniaVatNumber => "9942",
+            ElectronicAddressSchemeCode::MaltaVatNumber => "9943",
+            ElectronicAddressSchemeCode::NetherlandsVatNumber => "9944",
+            ElectronicAddressSchemeCode::PolandVatNumber => "9945",
+            ElectronicAddressSchemeCode::PortugalVatNumber => "9946",
+            ElectronicAddressSchemeCode::RomaniaVatNumber => "9947",
+            ElectronicAddressSchemeCode::SerbiaVatNumber => "9948",
+            ElectronicAddressSchemeCode::SloveniaVatNumber => "9949",
+            ElectronicAddressSchemeCode::SlovakiaVatNumber => "9950",
+            ElectronicAddressSchemeCode::SanMarinoVatNumber => "9951",
+            ElectronicAddressSchemeCode::TurkeyVatNumber => "9952",
+            ElectronicAddressSchemeCode::HolySeeVatNumber => "9953",
+            ElectronicAddressSchemeCode::FranceVatNumber => "9957",
+            ElectronicAddressSchemeCode::EmployerIdentificationNumber => "9959",
+            ElectronicAddressSchemeCode::Oftp => "AN",
+            ElectronicAddressSchemeCode::X400Address => "AQ",
+            ElectronicAddressSchemeCode::As2Exchange => "AS",
+            ElectronicAddressSchemeCode::FileTransferProtocol => "AU",
+            ElectronicAddressSchemeCode::ElectronicMail => "EM",
+        }
+    }
+}
+
+impl Serialize for ElectronicAddressSchemeCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,57 @@
+use serde::{Serialize, Serializer};
+
+// MIME codes allowed for attached supporting documents by EN 16931 (BR-CL-24)
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum MimeCode {
+    /// application/pdf
+    Pdf,
+    /// image/png
+    Png,
+    /// image/jpeg
+    Jpeg,
+    /// text/csv
+    Csv,
+    /// application/vnd.openxmlformats-officedocument.spreadsheetml.sheet (Excel)
+    OfficeOpenXmlSpreadsheet,
+    /// application/vnd.oasis.opendocument.spreadsheet (OpenDocument)
+    OpenDocumentSpreadsheet,
+}
+
+impl MimeCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            MimeCode::Pdf => "application/pdf",
+            MimeCode::Png => "image/png",
+            MimeCode::Jpeg => "image/jpeg",
+            MimeCode::Csv => "text/csv",
+            MimeCode::OfficeOpenXmlSpreadsheet => "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet",
+            MimeCode::OpenDocumentSpreadsheet => "application/vnd.oasis.opendocument.spreadsheet",
+        }
+    }
+}
+
+impl Serialize for MimeCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl TryFrom<&str> for MimeCode {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "application/pdf" => Ok(MimeCode::Pdf),
+            "image/png" => Ok(MimeCode::Png),
+            "image/jpeg" => Ok(MimeCode::Jpeg),
+            "text/csv" => Ok(MimeCode::Csv),
+            "application/vnd.openxmlformats-officedocument.spreadsheetml.sheet" => Ok(MimeCode::OfficeOpenXmlSpreadsheet),
+            "application/vnd.oasis.opendocument.spreadsheet" => Ok(MimeCode::OpenDocumentSpreadsheet),
+            _ => Err(format!("MIME code {} is not allowed for attachments", value)),
+        }
+    }
+}
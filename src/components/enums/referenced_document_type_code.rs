@@ -0,0 +1,38 @@
+use serde::{Serialize, Serializer};
+
+// subset of UNTDID 1001 (Document name code) used for additional referenced documents
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ReferencedDocumentTypeCode {
+    /// Validated priced tender, references the tender or lot the invoice belongs to
+    ///
+    /// BT-17
+    ValidatedPricedTender,
+    /// Invoicing data sheet, references an object the invoice is based on, e.g. a subscription or meter number
+    ///
+    /// BT-18
+    InvoicingDataSheet,
+    /// Related document, e.g. a timesheet or a delivery note supporting the invoice
+    ///
+    /// BG-24
+    RelatedDocument,
+}
+
+impl ReferencedDocumentTypeCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReferencedDocumentTypeCode::ValidatedPricedTender => "50",
+            ReferencedDocumentTypeCode::InvoicingDataSheet => "130",
+            ReferencedDocumentTypeCode::RelatedDocument => "916",
+        }
+    }
+}
+
+impl Serialize for ReferencedDocumentTypeCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
@@ -0,0 +1,31 @@
+use serde::{Serialize, Serializer};
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum TaxRegistrationSchemeCode {
+    /// VAT identifier, e.g. the German USt-IdNr.
+    ///
+    /// BT-31 (seller), BT-48 (buyer), BT-63 (seller tax representative)
+    VatIdentifier,
+    /// Local tax registration identifier, e.g. the German Steuernummer
+    ///
+    /// BT-32
+    FiscalNumber,
+}
+
+impl TaxRegistrationSchemeCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            TaxRegistrationSchemeCode::VatIdentifier => "VA",
+            TaxRegistrationSchemeCode::FiscalNumber => "FC",
+        }
+    }
+}
+
+impl Serialize for TaxRegistrationSchemeCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
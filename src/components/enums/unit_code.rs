@@ -1,14 +1,8671 @@
-use serde::{Serialize,Serializer};
+use serde::{Serialize, Serializer};
 
-#[derive(Debug,Clone,Copy)]
+// based on UN/ECE Recommendation N°20 "Codes for Units of Measure Used in International Trade"
+// and UN/ECE Recommendation N°21 "Codes for Passengers, Types of Cargo, Packages and Packaging Materials" (prefixed with "X"),
+// as used by EN 16931 for the invoiced quantity unit of measure code (BT-130, BT-150):
+// https://unece.org/trade/uncefact/cl-recommendations
+
+#[derive(Clone, Copy, PartialEq, Debug)]
 pub enum UnitCode {
+    /// Group (10)
+    Group,
+    /// Outfit (11)
+    Outfit,
+    /// Ration (13)
+    Ration,
+    /// Shot (14)
+    Shot,
+    /// Stick, military (15)
+    StickMilitary,
+    /// Twenty foot container (20)
+    TwentyFootContainer,
+    /// Forty foot container (21)
+    FortyFootContainer,
+    /// Decilitre per gram (22)
+    DecilitrePerGram,
+    /// Gram per cubic centimetre (23)
+    GramPerCubicCentimetre,
+    /// Theoretical pound (24)
+    TheoreticalPound,
+    /// Gram per square centimetre (25)
+    GramPerSquareCentimetre,
+    /// Theoretical ton (27)
+    TheoreticalTon,
+    /// Kilogram per square metre (28)
+    KilogramPerSquareMetre,
+    /// Kilopascal square metre per gram (33)
+    KilopascalSquareMetrePerGram,
+    /// Kilopascal per millimetre (34)
+    KilopascalPerMillimetre,
+    /// Millilitre per square centimetre second (35)
+    MillilitrePerSquareCentimetreSecond,
+    /// Ounce per square foot (37)
+    OuncePerSquareFoot,
+    /// Ounce per square foot per 0,01inch (38)
+    OuncePerSquareFootPer001inch,
+    /// Millilitre per second (40)
+    MillilitrePerSecond,
+    /// Millilitre per minute (41)
+    MillilitrePerMinute,
+    /// Sitas (56)
+    Sitas,
+    /// Mesh (57)
+    Mesh,
+    /// Net kilogram (58)
+    NetKilogram,
+    /// Part per million (59)
+    PartPerMillion,
+    /// Percent weight (60)
+    PercentWeight,
+    /// Part per billion (US) (61)
+    PartPerBillionUs,
+    /// Millipascal (74)
+    Millipascal,
+    /// Milli-inch (77)
+    MilliInch,
+    /// Pound per square inch absolute (80)
+    PoundPerSquareInchAbsolute,
+    /// Henry (81)
+    Henry,
+    /// Foot pound-force (85)
+    FootPoundForce,
+    /// Pound per cubic foot (87)
+    PoundPerCubicFoot,
+    /// Poise (89)
+    Poise,
+    /// Stokes (91)
+    Stokes,
+    /// Fixed rate (1I)
+    FixedRate,
+    /// Radian per second (2A)
+    RadianPerSecond,
+    /// Radian per second squared (2B)
+    RadianPerSecondSquared,
+    /// Roentgen (2C)
+    Roentgen,
+    /// Volt AC (2G)
+    VoltAc,
+    /// Volt DC (2H)
+    VoltDc,
+    /// British thermal unit (international table) per hour (2I)
+    BritishThermalUnitInternationalTablePerHour,
+    /// Cubic centimetre per second (2J)
+    CubicCentimetrePerSecond,
+    /// Cubic foot per hour (2K)
+    CubicFootPerHour,
+    /// Cubic foot per minute (2L)
+    CubicFootPerMinute,
+    /// Centimetre per second (2M)
+    CentimetrePerSecond,
+    /// Decibel (2N)
+    Decibel,
+    /// Kilobyte (2P)
+    Kilobyte,
+    /// Kilobecquerel (2Q)
+    Kilobecquerel,
+    /// Kilocurie (2R)
+    Kilocurie,
+    /// Megagram (2U)
+    Megagram,
+    /// Metre per minute (2X)
+    MetrePerMinute,
+    /// Milliroentgen (2Y)
+    Milliroentgen,
+    /// Millivolt (2Z)
+    Millivolt,
+    /// Megajoule (3B)
+    Megajoule,
+    /// Manmonth (3C)
+    Manmonth,
+    /// Centistokes (4C)
+    Centistokes,
+    /// Microlitre (4G)
+    Microlitre,
+    /// Micrometre (micron) (4H)
+    MicrometreMicron,
+    /// Milliampere (4K)
+    Milliampere,
+    /// Megabyte (4L)
+    Megabyte,
+    /// Milligram per hour (4M)
+    MilligramPerHour,
+    /// Megabecquerel (4N)
+    Megabecquerel,
+    /// Microfarad (4O)
+    Microfarad,
+    /// Newton per metre (4P)
+    NewtonPerMetre,
+    /// Ounce inch (4Q)
+    OunceInch,
+    /// Ounce foot (4R)
+    OunceFoot,
+    /// Picofarad (4T)
+    Picofarad,
+    /// Pound per hour (4U)
+    PoundPerHour,
+    /// Ton (US) per hour (4W)
+    TonUsPerHour,
+    /// Kilolitre per hour (4X)
+    KilolitrePerHour,
+    /// Barrel (US) per minute (5A)
+    BarrelUsPerMinute,
+    /// Batch (5B)
+    Batch,
+    /// MMSCF/day (5E)
+    MmscfPerDay,
+    /// Hydraulic horse power (5J)
+    HydraulicHorsePower,
+    /// Ampere square metre per joule second (A10)
+    AmpereSquareMetrePerJouleSecond,
+    /// Angstrom (A11)
+    Angstrom,
+    /// Astronomical unit (A12)
+    AstronomicalUnit,
+    /// Attojoule (A13)
+    Attojoule,
+    /// Barn (A14)
+    Barn,
+    /// Barn per electronvolt (A15)
+    BarnPerElectronvolt,
+    /// Barn per steradian electronvolt (A16)
+    BarnPerSteradianElectronvolt,
+    /// Barn per steradian (A17)
+    BarnPerSteradian,
+    /// Becquerel per kilogram (A18)
+    BecquerelPerKilogram,
+    /// Becquerel per cubic metre (A19)
+    BecquerelPerCubicMetre,
+    /// Ampere per centimetre (A2)
+    AmperePerCentimetre,
+    /// British thermal unit (international table) per second square foot degree Rankine (A20)
+    BritishThermalUnitInternationalTablePerSecondSquareFootDegreeRankine,
+    /// British thermal unit (international table) per pound degree Rankine (A21)
+    BritishThermalUnitInternationalTablePerPoundDegreeRankine,
+    /// British thermal unit (international table) per second foot degree Rankine (A22)
+    BritishThermalUnitInternationalTablePerSecondFootDegreeRankine,
+    /// British thermal unit (international table) per hour square foot degree Rankine (A23)
+    BritishThermalUnitInternationalTablePerHourSquareFootDegreeRankine,
+    /// Candela per square metre (A24)
+    CandelaPerSquareMetre,
+    /// Coulomb metre (A26)
+    CoulombMetre,
+    /// Coulomb metre squared per volt (A27)
+    CoulombMetreSquaredPerVolt,
+    /// Coulomb per cubic centimetre (A28)
+    CoulombPerCubicCentimetre,
+    /// Coulomb per cubic metre (A29)
+    CoulombPerCubicMetre,
+    /// Ampere per millimetre (A3)
+    AmperePerMillimetre,
+    /// Coulomb per cubic millimetre (A30)
+    CoulombPerCubicMillimetre,
+    /// Coulomb per kilogram second (A31)
+    CoulombPerKilogramSecond,
+    /// Coulomb per mole (A32)
+    CoulombPerMole,
+    /// Coulomb per square centimetre (A33)
+    CoulombPerSquareCentimetre,
+    /// Coulomb per square metre (A34)
+    CoulombPerSquareMetre,
+    /// Coulomb per square millimetre (A35)
+    CoulombPerSquareMillimetre,
+    /// Cubic centimetre per mole (A36)
+    CubicCentimetrePerMole,
+    /// Cubic decimetre per mole (A37)
+    CubicDecimetrePerMole,
+    /// Cubic metre per coulomb (A38)
+    CubicMetrePerCoulomb,
+    /// Cubic metre per kilogram (A39)
+    CubicMetrePerKilogram,
+    /// Ampere per square centimetre (A4)
+    AmperePerSquareCentimetre,
+    /// Cubic metre per mole (A40)
+    CubicMetrePerMole,
+    /// Ampere per square metre (A41)
+    AmperePerSquareMetre,
+    /// Curie per kilogram (A42)
+    CuriePerKilogram,
+    /// Deadweight tonnage (A43)
+    DeadweightTonnage,
+    /// Decalitre (A44)
+    Decalitre,
+    /// Decametre (A45)
+    Decametre,
+    /// Decitex (A47)
+    Decitex,
+    /// Degree Rankine (A48)
+    DegreeRankine,
+    /// Denier (A49)
+    Denier,
+    /// Ampere square metre (A5)
+    AmpereSquareMetre,
+    /// Electronvolt (A53)
+    Electronvolt,
+    /// Electronvolt per metre (A54)
+    ElectronvoltPerMetre,
+    /// Electronvolt square metre (A55)
+    ElectronvoltSquareMetre,
+    /// Electronvolt square metre per kilogram (A56)
+    ElectronvoltSquareMetrePerKilogram,
+    /// 8-part cloud cover (A59)
+    EightPartCloudCover,
+    /// Ampere per square metre kelvin squared (A6)
+    AmperePerSquareMetreKelvinSquared,
+    /// Exajoule (A68)
+    Exajoule,
+    /// Farad per metre (A69)
+    FaradPerMetre,
+    /// Ampere per square millimetre (A7)
+    AmperePerSquareMillimetre,
+    /// Femtojoule (A70)
+    Femtojoule,
+    /// Femtometre (A71)
+    Femtometre,
+    /// Foot per second squared (A73)
+    FootPerSecondSquared,
+    /// Foot pound-force per second (A74)
+    FootPoundForcePerSecond,
+    /// Freight ton (A75)
+    FreightTon,
+    /// Gal (A76)
+    Gal,
+    /// Ampere second (A8)
+    AmpereSecond,
+    /// Gigacoulomb per cubic metre (A84)
+    GigacoulombPerCubicMetre,
+    /// Gigaelectronvolt (A85)
+    Gigaelectronvolt,
+    /// Gigahertz (A86)
+    Gigahertz,
+    /// Gigaohm (A87)
+    Gigaohm,
+    /// Gigaohm metre (A88)
+    GigaohmMetre,
+    /// Gigapascal (A89)
+    Gigapascal,
+    /// Rate (A9)
+    Rate,
+    /// Gigawatt (A90)
+    Gigawatt,
+    /// Gon (A91)
+    Gon,
+    /// Gram per cubic metre (A93)
+    GramPerCubicMetre,
+    /// Gram per mole (A94)
+    GramPerMole,
+    /// Gray (A95)
+    Gray,
+    /// Gray per second (A96)
+    GrayPerSecond,
+    /// Hectopascal (A97)
+    Hectopascal,
+    /// Henry per metre (A98)
+    HenryPerMetre,
+    /// Bit (A99)
+    Bit,
+    /// Ball (AA)
+    Ball,
+    /// Bulk pack (AB)
+    BulkPack,
+    /// Acre (ACR)
+    Acre,
+    /// Activity (ACT)
+    Activity,
+    /// Byte (AD)
+    Byte,
+    /// Ampere per metre (AE)
+    AmperePerMetre,
+    /// Additional minute (AH)
+    AdditionalMinute,
+    /// Average minute per call (AI)
+    AverageMinutePerCall,
+    /// Fathom (AK)
+    Fathom,
+    /// Access line (AL)
+    AccessLine,
+    /// Ampere hour (AMH)
+    AmpereHour,
+    /// Ampere (AMP)
+    Ampere,
+    /// Year (ANN)
+    Year,
+    /// Troy ounce or apothecary ounce (APZ)
+    TroyOunceOrApothecaryOunce,
+    /// Anti-hemophilic factor (AHF) unit (AQ)
+    AntiHemophilicFactorAhfUnit,
+    /// Assortment (AS)
+    Assortment,
+    /// Alcoholic strength by mass (ASM)
+    AlcoholicStrengthByMass,
+    /// Alcoholic strength by volume (ASU)
+    AlcoholicStrengthByVolume,
+    /// Standard atmosphere (ATM)
+    StandardAtmosphere,
+    /// American wire gauge (AWG)
+    AmericanWireGauge,
+    /// Assembly (AY)
+    Assembly,
+    /// British thermal unit (international table) per pound (AZ)
+    BritishThermalUnitInternationalTablePerPound,
+    /// Barrel (US) per day (B1)
+    BarrelUsPerDay,
+    /// Bit per second (B10)
+    BitPerSecond,
+    /// Joule per kilogram kelvin (B11)
+    JoulePerKilogramKelvin,
+    /// Joule per metre (B12)
+    JoulePerMetre,
+    /// Joule per square metre (B13)
+    JoulePerSquareMetre,
+    /// Joule per metre to the fourth power (B14)
+    JoulePerMetreToTheFourthPower,
+    /// Joule per mole (B15)
+    JoulePerMole,
+    /// Joule per mole kelvin (B16)
+    JoulePerMoleKelvin,
+    /// Credit (B17)
+    Credit,
+    /// Joule second (B18)
+    JouleSecond,
+    /// Digit (B19)
+    Digit,
+    /// Joule square metre per kilogram (B20)
+    JouleSquareMetrePerKilogram,
+    /// Kelvin per watt (B21)
+    KelvinPerWatt,
+    /// Kiloampere (B22)
+    Kiloampere,
+    /// Kiloampere per square metre (B23)
+    KiloamperePerSquareMetre,
+    /// Kiloampere per metre (B24)
+    KiloamperePerMetre,
+    /// Kilobecquerel per kilogram (B25)
+    KilobecquerelPerKilogram,
+    /// Kilocoulomb (B26)
+    Kilocoulomb,
+    /// Kilocoulomb per cubic metre (B27)
+    KilocoulombPerCubicMetre,
+    /// Kilocoulomb per square metre (B28)
+    KilocoulombPerSquareMetre,
+    /// Kiloelectronvolt (B29)
+    Kiloelectronvolt,
+    /// Batting pound (B3)
+    BattingPound,
+    /// Gibibit (B30)
+    Gibibit,
+    /// Kilogram metre per second (B31)
+    KilogramMetrePerSecond,
+    /// Kilogram metre squared (B32)
+    KilogramMetreSquared,
+    /// Kilogram metre squared per second (B33)
+    KilogramMetreSquaredPerSecond,
+    /// Kilogram per cubic decimetre (B34)
+    KilogramPerCubicDecimetre,
+    /// Kilogram per litre (B35)
+    KilogramPerLitre,
+    /// Barrel, imperial (B4)
+    BarrelImperial,
+    /// Kilojoule per kelvin (B41)
+    KilojoulePerKelvin,
+    /// Kilojoule per kilogram (B42)
+    KilojoulePerKilogram,
+    /// Kilojoule per kilogram kelvin (B43)
+    KilojoulePerKilogramKelvin,
+    /// Kilojoule per mole (B44)
+    KilojoulePerMole,
+    /// Kilomole (B45)
+    Kilomole,
+    /// Kilomole per cubic metre (B46)
+    KilomolePerCubicMetre,
+    /// Kilonewton (B47)
+    Kilonewton,
+    /// Kilonewton metre (B48)
+    KilonewtonMetre,
+    /// Kiloohm (B49)
+    Kiloohm,
+    /// Kiloohm metre (B50)
+    KiloohmMetre,
+    /// Kilosecond (B52)
+    Kilosecond,
+    /// Kilosiemens (B53)
+    Kilosiemens,
+    /// Kilosiemens per metre (B54)
+    KilosiemensPerMetre,
+    /// Kilovolt per metre (B55)
+    KilovoltPerMetre,
+    /// Kiloweber per metre (B56)
+    KiloweberPerMetre,
+    /// Light year (B57)
+    LightYear,
+    /// Litre per mole (B58)
+    LitrePerMole,
+    /// Lumen hour (B59)
+    LumenHour,
+    /// Lumen per square metre (B60)
+    LumenPerSquareMetre,
+    /// Lumen per watt (B61)
+    LumenPerWatt,
+    /// Lumen second (B62)
+    LumenSecond,
+    /// Lux hour (B63)
+    LuxHour,
+    /// Lux second (B64)
+    LuxSecond,
+    /// Megaampere per square metre (B66)
+    MegaamperePerSquareMetre,
+    /// Megabecquerel per kilogram (B67)
+    MegabecquerelPerKilogram,
+    /// Gigabit (B68)
+    Gigabit,
+    /// Megacoulomb per cubic metre (B69)
+    MegacoulombPerCubicMetre,
+    /// Cycle (B7)
+    Cycle,
+    /// Megacoulomb per square metre (B70)
+    MegacoulombPerSquareMetre,
+    /// Megaelectronvolt (B71)
+    Megaelectronvolt,
+    /// Megagram per cubic metre (B72)
+    MegagramPerCubicMetre,
+    /// Meganewton (B73)
+    Meganewton,
+    /// Meganewton metre (B74)
+    MeganewtonMetre,
+    /// Megaohm (B75)
+    Megaohm,
+    /// Megaohm metre (B76)
+    MegaohmMetre,
+    /// Megasiemens per metre (B77)
+    MegasiemensPerMetre,
+    /// Megavolt (B78)
+    Megavolt,
+    /// Megavolt per metre (B79)
+    MegavoltPerMetre,
+    /// Joule per cubic metre (B8)
+    JoulePerCubicMetre,
+    /// Gigabit per second (B80)
+    GigabitPerSecond,
+    /// Reciprocal metre squared reciprocal second (B81)
+    ReciprocalMetreSquaredReciprocalSecond,
+    /// Inch per linear foot (B82)
+    InchPerLinearFoot,
+    /// Metre to the fourth power (B83)
+    MetreToTheFourthPower,
+    /// Microampere (B84)
+    Microampere,
+    /// Microbar (B85)
+    Microbar,
+    /// Microcoulomb (B86)
+    Microcoulomb,
+    /// Microcoulomb per cubic metre (B87)
+    MicrocoulombPerCubicMetre,
+    /// Microcoulomb per square metre (B88)
+    MicrocoulombPerSquareMetre,
+    /// Microfarad per metre (B89)
+    MicrofaradPerMetre,
+    /// Microhenry (B90)
+    Microhenry,
+    /// Microhenry per metre (B91)
+    MicrohenryPerMetre,
+    /// Micronewton (B92)
+    Micronewton,
+    /// Micronewton metre (B93)
+    MicronewtonMetre,
+    /// Microohm (B94)
+    Microohm,
+    /// Microohm metre (B95)
+    MicroohmMetre,
+    /// Micropascal (B96)
+    Micropascal,
+    /// Microradian (B97)
+    Microradian,
+    /// Microsecond (B98)
+    Microsecond,
+    /// Microsiemens (B99)
+    Microsiemens,
+    /// Bar [unit of pressure] (BAR)
+    BarUnitOfPressure,
+    /// Base box (BB)
+    BaseBox,
+    /// Board foot (BFT)
+    BoardFoot,
+    /// Brake horse power (BHP)
+    BrakeHorsePower,
+    /// Billion (EUR) (BIL)
+    BillionEur,
+    /// Dry barrel (US) (BLD)
+    DryBarrelUs,
+    /// Barrel (US) (BLL)
+    BarrelUs,
+    /// Hundred board foot (BP)
+    HundredBoardFoot,
+    /// Beats per minute (BPM)
+    BeatsPerMinute,
+    /// Becquerel (BQL)
+    Becquerel,
+    /// British thermal unit (international table) (BTU)
+    BritishThermalUnitInternationalTable,
+    /// Bushel (US) (BUA)
+    BushelUs,
+    /// Bushel (UK) (BUI)
+    BushelUk,
+    /// Call (C0)
+    Call,
+    /// Millifarad (C10)
+    Millifarad,
+    /// Milligal (C11)
+    Milligal,
+    /// Milligram per metre (C12)
+    MilligramPerMetre,
+    /// Milligray (C13)
+    Milligray,
+    /// Millihenry (C14)
+    Millihenry,
+    /// Millijoule (C15)
+    Millijoule,
+    /// Millimetre per second (C16)
+    MillimetrePerSecond,
+    /// Millimetre squared per second (C17)
+    MillimetreSquaredPerSecond,
+    /// Millimole (C18)
+    Millimole,
+    /// Mole per kilogram (C19)
+    MolePerKilogram,
+    /// Millinewton (C20)
+    Millinewton,
+    /// Kibibit (C21)
+    Kibibit,
+    /// Millinewton per metre (C22)
+    MillinewtonPerMetre,
+    /// Milliohm metre (C23)
+    MilliohmMetre,
+    /// Millipascal second (C24)
+    MillipascalSecond,
+    /// Milliradian (C25)
+    Milliradian,
+    /// Millisecond (C26)
+    Millisecond,
+    /// Millisiemens (C27)
+    Millisiemens,
+    /// Millisievert (C28)
+    Millisievert,
+    /// Millitesla (C29)
+    Millitesla,
+    /// Microvolt per metre (C3)
+    MicrovoltPerMetre,
+    /// Millivolt per metre (C30)
+    MillivoltPerMetre,
+    /// Milliwatt (C31)
+    Milliwatt,
+    /// Milliwatt per square metre (C32)
+    MilliwattPerSquareMetre,
+    /// Milliweber (C33)
+    Milliweber,
+    /// Mole (C34)
+    Mole,
+    /// Mole per cubic decimetre (C35)
+    MolePerCubicDecimetre,
+    /// Mole per cubic metre (C36)
+    MolePerCubicMetre,
+    /// Kilobit (C37)
+    Kilobit,
+    /// Mole per litre (C38)
+    MolePerLitre,
+    /// Nanoampere (C39)
+    Nanoampere,
+    /// Nanocoulomb (C40)
+    Nanocoulomb,
+    /// Nanofarad (C41)
+    Nanofarad,
+    /// Nanofarad per metre (C42)
+    NanofaradPerMetre,
+    /// Nanohenry (C43)
+    Nanohenry,
+    /// Nanohenry per metre (C44)
+    NanohenryPerMetre,
+    /// Nanometre (C45)
+    Nanometre,
+    /// Nanoohm metre (C46)
+    NanoohmMetre,
+    /// Nanosecond (C47)
+    Nanosecond,
+    /// Nanotesla (C48)
+    Nanotesla,
+    /// Nanowatt (C49)
+    Nanowatt,
+    /// Neper (C50)
+    Neper,
+    /// Neper per second (C51)
+    NeperPerSecond,
+    /// Picometre (C52)
+    Picometre,
+    /// Newton metre second (C53)
+    NewtonMetreSecond,
+    /// Newton metre squared per kilogram squared (C54)
+    NewtonMetreSquaredPerKilogramSquared,
+    /// Newton per square metre (C55)
+    NewtonPerSquareMetre,
+    /// Newton per square millimetre (C56)
+    NewtonPerSquareMillimetre,
+    /// Newton second (C57)
+    NewtonSecond,
+    /// Newton second per metre (C58)
+    NewtonSecondPerMetre,
+    /// Octave (C59)
+    Octave,
+    /// Ohm centimetre (C60)
+    OhmCentimetre,
+    /// Ohm metre (C61)
+    OhmMetre,
+    /// One (C62)
+    One,
+    /// Parsec (C63)
+    Parsec,
+    /// Pascal per kelvin (C64)
+    PascalPerKelvin,
+    /// Pascal second (C65)
+    PascalSecond,
+    /// Pascal second per cubic metre (C66)
+    PascalSecondPerCubicMetre,
+    /// Pascal second per metre (C67)
+    PascalSecondPerMetre,
+    /// Petajoule (C68)
+    Petajoule,
+    /// Phon (C69)
+    Phon,
+    /// Centipoise (C7)
+    Centipoise,
+    /// Picoampere (C70)
+    Picoampere,
+    /// Picocoulomb (C71)
+    Picocoulomb,
+    /// Picofarad per metre (C72)
+    PicofaradPerMetre,
+    /// Picohenry (C73)
+    Picohenry,
+    /// Kilobit per second (C74)
+    KilobitPerSecond,
+    /// Picowatt (C75)
+    Picowatt,
+    /// Picowatt per square metre (C76)
+    PicowattPerSquareMetre,
+    /// Pound-force (C78)
+    PoundForce,
+    /// Kilovolt ampere hour (C79)
+    KilovoltAmpereHour,
+    /// Millicoulomb per kilogram (C8)
+    MillicoulombPerKilogram,
+    /// Rad (C80)
+    Rad,
+    /// Radian (C81)
+    Radian,
+    /// Radian square metre per mole (C82)
+    RadianSquareMetrePerMole,
+    /// Radian square metre per kilogram (C83)
+    RadianSquareMetrePerKilogram,
+    /// Radian per metre (C84)
+    RadianPerMetre,
+    /// Reciprocal angstrom (C85)
+    ReciprocalAngstrom,
+    /// Reciprocal cubic metre (C86)
+    ReciprocalCubicMetre,
+    /// Reciprocal cubic metre per second (C87)
+    ReciprocalCubicMetrePerSecond,
+    /// Reciprocal electron volt per cubic metre (C88)
+    ReciprocalElectronVoltPerCubicMetre,
+    /// Reciprocal henry (C89)
+    ReciprocalHenry,
+    /// Coil group (C9)
+    CoilGroup,
+    /// Reciprocal joule per cubic metre (C90)
+    ReciprocalJoulePerCubicMetre,
+    /// Reciprocal kelvin or kelvin to the power minus one (C91)
+    ReciprocalKelvinOrKelvinToThePowerMinusOne,
+    /// Reciprocal metre (C92)
+    ReciprocalMetre,
+    /// Reciprocal square metre (C93)
+    ReciprocalSquareMetre,
+    /// Reciprocal minute (C94)
+    ReciprocalMinute,
+    /// Reciprocal mole (C95)
+    ReciprocalMole,
+    /// Reciprocal pascal or pascal to the power minus one (C96)
+    ReciprocalPascalOrPascalToThePowerMinusOne,
+    /// Reciprocal second (C97)
+    ReciprocalSecond,
+    /// Reciprocal second per metre squared (C99)
+    ReciprocalSecondPerMetreSquared,
+    /// Carrying capacity in metric ton (CCT)
+    CarryingCapacityInMetricTon,
+    /// Candela (CDL)
+    Candela,
+    /// Degree Celsius (CEL)
+    DegreeCelsius,
+    /// Hundred (CEN)
+    Hundred,
+    /// Card (CG)
+    Card,
+    /// Centigram (CGM)
+    Centigram,
+    /// Coulomb per kilogram (CKG)
+    CoulombPerKilogram,
+    /// Hundred leave (CLF)
+    HundredLeave,
+    /// Centilitre (CLT)
+    Centilitre,
+    /// Square centimetre (CMK)
+    SquareCentimetre,
+    /// Cubic centimetre (CMQ)
+    CubicCentimetre,
+    /// Centimetre (CMT)
+    Centimetre,
+    /// Hundred pack (CNP)
+    HundredPack,
+    /// Cental (UK) (CNT)
+    CentalUk,
+    /// Coulomb (COU)
+    Coulomb,
+    /// Content gram (CTG)
+    ContentGram,
+    /// Metric carat (CTM)
+    MetricCarat,
+    /// Content ton (metric) (CTN)
+    ContentTonMetric,
+    /// Curie (CUR)
+    Curie,
+    /// Hundred pound (cwt) / hundred weight (US) (CWA)
+    HundredPoundCwtPerHundredWeightUs,
+    /// Hundred weight (UK) (CWI)
+    HundredWeightUk,
+    /// Kilowatt hour per hour (D03)
+    KilowattHourPerHour,
+    /// Lot [unit of weight] (D04)
+    LotUnitOfWeight,
+    /// Reciprocal second per steradian (D1)
+    ReciprocalSecondPerSteradian,
+    /// Siemens per metre (D10)
+    SiemensPerMetre,
+    /// Mebibit (D11)
+    Mebibit,
+    /// Siemens square metre per mole (D12)
+    SiemensSquareMetrePerMole,
+    /// Sievert (D13)
+    Sievert,
+    /// Sone (D15)
+    Sone,
+    /// Square centimetre per erg (D16)
+    SquareCentimetrePerErg,
+    /// Square centimetre per steradian erg (D17)
+    SquareCentimetrePerSteradianErg,
+    /// Metre kelvin (D18)
+    MetreKelvin,
+    /// Square metre kelvin per watt (D19)
+    SquareMetreKelvinPerWatt,
+    /// Reciprocal second per steradian metre squared (D2)
+    ReciprocalSecondPerSteradianMetreSquared,
+    /// Square metre per joule (D20)
+    SquareMetrePerJoule,
+    /// Square metre per kilogram (D21)
+    SquareMetrePerKilogram,
+    /// Square metre per mole (D22)
+    SquareMetrePerMole,
+    /// Pen gram (protein) (D23)
+    PenGramProtein,
+    /// Square metre per steradian (D24)
+    SquareMetrePerSteradian,
+    /// Square metre per steradian joule (D25)
+    SquareMetrePerSteradianJoule,
+    /// Square metre per volt second (D26)
+    SquareMetrePerVoltSecond,
+    /// Steradian (D27)
+    Steradian,
+    /// Terahertz (D29)
+    Terahertz,
+    /// Terajoule (D30)
+    Terajoule,
+    /// Terawatt (D31)
+    Terawatt,
+    /// Terawatt hour (D32)
+    TerawattHour,
+    /// Tesla (D33)
+    Tesla,
+    /// Tex (D34)
+    Tex,
+    /// Megabit (D36)
+    Megabit,
+    /// Tonne per cubic metre (D41)
+    TonnePerCubicMetre,
+    /// Tropical year (D42)
+    TropicalYear,
+    /// Unified atomic mass unit (D43)
+    UnifiedAtomicMassUnit,
+    /// Var (D44)
+    Var,
+    /// Volt squared per kelvin squared (D45)
+    VoltSquaredPerKelvinSquared,
+    /// Volt - ampere (D46)
+    VoltAmpere,
+    /// Volt per centimetre (D47)
+    VoltPerCentimetre,
+    /// Volt per kelvin (D48)
+    VoltPerKelvin,
+    /// Millivolt per kelvin (D49)
+    MillivoltPerKelvin,
+    /// Kilogram per square centimetre (D5)
+    KilogramPerSquareCentimetre,
+    /// Volt per metre (D50)
+    VoltPerMetre,
+    /// Volt per millimetre (D51)
+    VoltPerMillimetre,
+    /// Watt per kelvin (D52)
+    WattPerKelvin,
+    /// Watt per metre kelvin (D53)
+    WattPerMetreKelvin,
+    /// Watt per square metre (D54)
+    WattPerSquareMetre,
+    /// Watt per square metre kelvin (D55)
+    WattPerSquareMetreKelvin,
+    /// Watt per square metre kelvin to the fourth power (D56)
+    WattPerSquareMetreKelvinToTheFourthPower,
+    /// Watt per steradian (D57)
+    WattPerSteradian,
+    /// Watt per steradian square metre (D58)
+    WattPerSteradianSquareMetre,
+    /// Weber per metre (D59)
+    WeberPerMetre,
+    /// Roentgen per second (D6)
+    RoentgenPerSecond,
+    /// Weber per millimetre (D60)
+    WeberPerMillimetre,
+    /// Minute [unit of angle] (D61)
+    MinuteUnitOfAngle,
+    /// Second [unit of angle] (D62)
+    SecondUnitOfAngle,
+    /// Book (D63)
+    Book,
+    /// Round (D65)
+    Round,
+    /// Number of words (D68)
+    NumberOfWords,
+    /// Inch to the fourth power (D69)
+    InchToTheFourthPower,
+    /// Joule square metre (D73)
+    JouleSquareMetre,
+    /// Kilogram per mole (D74)
+    KilogramPerMole,
+    /// Megacoulomb (D77)
+    Megacoulomb,
+    /// Megajoule per second (D78)
+    MegajoulePerSecond,
+    /// Microwatt (D80)
+    Microwatt,
+    /// Microtesla (D81)
+    Microtesla,
+    /// Microvolt (D82)
+    Microvolt,
+    /// Millinewton metre (D83)
+    MillinewtonMetre,
+    /// Microwatt per square metre (D85)
+    MicrowattPerSquareMetre,
+    /// Millicoulomb (D86)
+    Millicoulomb,
+    /// Millimole per kilogram (D87)
+    MillimolePerKilogram,
+    /// Millicoulomb per cubic metre (D88)
+    MillicoulombPerCubicMetre,
+    /// Millicoulomb per square metre (D89)
+    MillicoulombPerSquareMetre,
+    /// Rem (D91)
+    Rem,
+    /// Second per cubic metre (D93)
+    SecondPerCubicMetre,
+    /// Second per cubic metre radian (D94)
+    SecondPerCubicMetreRadian,
+    /// Joule per gram (D95)
+    JoulePerGram,
+    /// Decare (DAA)
+    Decare,
+    /// Ten day (DAD)
+    TenDay,
+    /// Day (DAY)
+    Day,
+    /// Dry pound (DB)
+    DryPound,
+    /// Decibel-milliwatts (DBM)
+    DecibelMilliwatts,
+    /// Decibel watt (DBW)
+    DecibelWatt,
+    /// Disk (disc) (DC)
+    DiskDisc,
+    /// Degree [unit of angle] (DD)
+    DegreeUnitOfAngle,
+    /// Decade (DEC)
+    Decade,
+    /// Decigram (DG)
+    Decigram,
+    /// Decagram (DJ)
+    Decagram,
+    /// Decilitre (DLT)
+    Decilitre,
+    /// Cubic decametre (DMA)
+    CubicDecametre,
+    /// Square decimetre (DMK)
+    SquareDecimetre,
+    /// Standard kilolitre (DMO)
+    StandardKilolitre,
+    /// Cubic decimetre (DMQ)
+    CubicDecimetre,
+    /// Decimetre (DMT)
+    Decimetre,
+    /// Decinewton metre (DN)
+    DecinewtonMetre,
+    /// Dozen piece (DPC)
+    DozenPiece,
+    /// Dozen pair (DPR)
+    DozenPair,
+    /// Displacement tonnage (DPT)
+    DisplacementTonnage,
+    /// Dram (US) (DRA)
+    DramUs,
+    /// Dram (UK) (DRI)
+    DramUk,
+    /// Dozen roll (DRL)
+    DozenRoll,
+    /// Dry ton (DT)
+    DryTon,
+    /// Decitonne (DTN)
+    Decitonne,
+    /// Pennyweight (DWT)
+    Pennyweight,
+    /// Dozen (DZN)
+    Dozen,
+    /// Dozen pack (DZP)
+    DozenPack,
+    /// Newton per square centimetre (E01)
+    NewtonPerSquareCentimetre,
+    /// Megawatt hour per hour (E07)
+    MegawattHourPerHour,
+    /// Megawatt per hertz (E08)
+    MegawattPerHertz,
+    /// Milliampere hour (E09)
+    MilliampereHour,
+    /// Degree day (E10)
+    DegreeDay,
+    /// Mille (E12)
+    Mille,
+    /// Kilocalorie (international table) (E14)
+    KilocalorieInternationalTable,
+    /// Kilocalorie (thermochemical) per hour (E15)
+    KilocalorieThermochemicalPerHour,
+    /// Million Btu(IT) per hour (E16)
+    MillionBtuItPerHour,
+    /// Cubic foot per second (E17)
+    CubicFootPerSecond,
+    /// Tonne per hour (E18)
+    TonnePerHour,
+    /// Ping (E19)
+    Ping,
+    /// Megabit per second (E20)
+    MegabitPerSecond,
+    /// Shares (E21)
+    Shares,
+    /// TEU (E22)
+    Teu,
+    /// Tyre (E23)
+    Tyre,
+    /// Active unit (E25)
+    ActiveUnit,
+    /// Dose (E27)
+    Dose,
+    /// Air dry ton (E28)
+    AirDryTon,
+    /// Strand (E30)
+    Strand,
+    /// Square metre per litre (E31)
+    SquareMetrePerLitre,
+    /// Litre per hour (E32)
+    LitrePerHour,
+    /// Foot per thousand (E33)
+    FootPerThousand,
+    /// Gigabyte (E34)
+    Gigabyte,
+    /// Terabyte (E35)
+    Terabyte,
+    /// Petabyte (E36)
+    Petabyte,
+    /// Pixel (E37)
+    Pixel,
+    /// Megapixels (E38)
+    Megapixels,
+    /// Dots per inch (E39)
+    DotsPerInch,
+    /// Gross kilogram (E4)
+    GrossKilogram,
+    /// Part per hundred thousand (E40)
+    PartPerHundredThousand,
+    /// Kilogram-force per square millimetre (E41)
+    KilogramForcePerSquareMillimetre,
+    /// Kilogram-force per square centimetre (E42)
+    KilogramForcePerSquareCentimetre,
+    /// Joule per square centimetre (E43)
+    JoulePerSquareCentimetre,
+    /// Kilogram-force metre per square centimetre (E44)
+    KilogramForceMetrePerSquareCentimetre,
+    /// Milliohm (E45)
+    Milliohm,
+    /// Kilowatt hour per cubic metre (E46)
+    KilowattHourPerCubicMetre,
+    /// Kilowatt hour per kelvin (E47)
+    KilowattHourPerKelvin,
+    /// Service unit (E48)
+    ServiceUnit,
+    /// Working day (E49)
+    WorkingDay,
+    /// Accounting unit (E50)
+    AccountingUnit,
+    /// Job (E51)
+    Job,
+    /// Run foot (E52)
+    RunFoot,
+    /// Test (E53)
+    Test,
+    /// Trip (E54)
+    Trip,
+    /// Use (E55)
+    Use,
+    /// Well (E56)
+    Well,
+    /// Zone (E57)
+    Zone,
+    /// Exabit per second (E58)
+    ExabitPerSecond,
+    /// Exbibyte (E59)
+    Exbibyte,
+    /// Pebibyte (E60)
+    Pebibyte,
+    /// Tebibyte (E61)
+    Tebibyte,
+    /// Gibibyte (E62)
+    Gibibyte,
+    /// Mebibyte (E63)
+    Mebibyte,
+    /// Kibibyte (E64)
+    Kibibyte,
+    /// Exbibit per metre (E65)
+    ExbibitPerMetre,
+    /// Exbibit per square metre (E66)
+    ExbibitPerSquareMetre,
+    /// Exbibit per cubic metre (E67)
+    ExbibitPerCubicMetre,
+    /// Gigabyte per second (E68)
+    GigabytePerSecond,
+    /// Gibibit per metre (E69)
+    GibibitPerMetre,
+    /// Gibibit per square metre (E70)
+    GibibitPerSquareMetre,
+    /// Gibibit per cubic metre (E71)
+    GibibitPerCubicMetre,
+    /// Kibibit per metre (E72)
+    KibibitPerMetre,
+    /// Kibibit per square metre (E73)
+    KibibitPerSquareMetre,
+    /// Kibibit per cubic metre (E74)
+    KibibitPerCubicMetre,
+    /// Mebibit per metre (E75)
+    MebibitPerMetre,
+    /// Mebibit per square metre (E76)
+    MebibitPerSquareMetre,
+    /// Mebibit per cubic metre (E77)
+    MebibitPerCubicMetre,
+    /// Petabit (E78)
+    Petabit,
+    /// Petabit per second (E79)
+    PetabitPerSecond,
+    /// Pebibit per metre (E80)
+    PebibitPerMetre,
+    /// Pebibit per square metre (E81)
+    PebibitPerSquareMetre,
+    /// Pebibit per cubic metre (E82)
+    PebibitPerCubicMetre,
+    /// Terabit (E83)
+    Terabit,
+    /// Terabit per second (E84)
+    TerabitPerSecond,
+    /// Tebibit per metre (E85)
+    TebibitPerMetre,
+    /// Tebibit per cubic metre (E86)
+    TebibitPerCubicMetre,
+    /// Tebibit per square metre (E87)
+    TebibitPerSquareMetre,
+    /// Bit per metre (E88)
+    BitPerMetre,
+    /// Bit per square metre (E89)
+    BitPerSquareMetre,
+    /// Reciprocal centimetre (E90)
+    ReciprocalCentimetre,
+    /// Reciprocal day (E91)
+    ReciprocalDay,
+    /// Cubic decimetre per hour (E92)
+    CubicDecimetrePerHour,
+    /// Kilogram per hour (E93)
+    KilogramPerHour,
+    /// Kilomole per second (E94)
+    KilomolePerSecond,
+    /// Mole per second (E95)
+    MolePerSecond,
+    /// Degree per second (E96)
+    DegreePerSecond,
+    /// Millimetre per degree Celcius metre (E97)
+    MillimetrePerDegreeCelciusMetre,
+    /// Degree Celsius per kelvin (E98)
+    DegreeCelsiusPerKelvin,
+    /// Hectopascal per bar (E99)
+    HectopascalPerBar,
+    /// Each (EA)
+    Each,
+    /// Electronic mail box (EB)
+    ElectronicMailBox,
+    /// Equivalent gallon (EQ)
+    EquivalentGallon,
+    /// Bit per cubic metre (F01)
+    BitPerCubicMetre,
+    /// Kelvin per kelvin (F02)
+    KelvinPerKelvin,
+    /// Kilopascal per bar (F03)
+    KilopascalPerBar,
+    /// Millibar per bar (F04)
+    MillibarPerBar,
+    /// Megapascal per bar (F05)
+    MegapascalPerBar,
+    /// Poise per bar (F06)
+    PoisePerBar,
+    /// Pascal per bar (F07)
+    PascalPerBar,
+    /// Milliampere per inch (F08)
+    MilliamperePerInch,
+    /// Kelvin per hour (F10)
+    KelvinPerHour,
+    /// Kelvin per minute (F11)
+    KelvinPerMinute,
+    /// Kelvin per second (F12)
+    KelvinPerSecond,
+    /// Slug (F13)
+    Slug,
+    /// Gram per kelvin (F14)
+    GramPerKelvin,
+    /// Kilogram per kelvin (F15)
+    KilogramPerKelvin,
+    /// Milligram per kelvin (F16)
+    MilligramPerKelvin,
+    /// Pound-force per foot (F17)
+    PoundForcePerFoot,
+    /// Kilogram square centimetre (F18)
+    KilogramSquareCentimetre,
+    /// Kilogram square millimetre (F19)
+    KilogramSquareMillimetre,
+    /// Pound inch squared (F20)
+    PoundInchSquared,
+    /// Pound-force inch (F21)
+    PoundForceInch,
+    /// Pound-force foot per ampere (F22)
+    PoundForceFootPerAmpere,
+    /// Gram per cubic decimetre (F23)
+    GramPerCubicDecimetre,
+    /// Kilogram per kilomol (F24)
+    KilogramPerKilomol,
+    /// Gram per hertz (F25)
+    GramPerHertz,
+    /// Gram per day (F26)
+    GramPerDay,
+    /// Gram per hour (F27)
+    GramPerHour,
+    /// Gram per minute (F28)
+    GramPerMinute,
+    /// Gram per second (F29)
+    GramPerSecond,
+    /// Kilogram per day (F30)
+    KilogramPerDay,
+    /// Kilogram per minute (F31)
+    KilogramPerMinute,
+    /// Milligram per day (F32)
+    MilligramPerDay,
+    /// Milligram per minute (F33)
+    MilligramPerMinute,
+    /// Milligram per second (F34)
+    MilligramPerSecond,
+    /// Gram per day kelvin (F35)
+    GramPerDayKelvin,
+    /// Gram per hour kelvin (F36)
+    GramPerHourKelvin,
+    /// Gram per minute kelvin (F37)
+    GramPerMinuteKelvin,
+    /// Gram per second kelvin (F38)
+    GramPerSecondKelvin,
+    /// Kilogram per day kelvin (F39)
+    KilogramPerDayKelvin,
+    /// Kilogram per hour kelvin (F40)
+    KilogramPerHourKelvin,
+    /// Kilogram per minute kelvin (F41)
+    KilogramPerMinuteKelvin,
+    /// Kilogram per second kelvin (F42)
+    KilogramPerSecondKelvin,
+    /// Milligram per day kelvin (F43)
+    MilligramPerDayKelvin,
+    /// Milligram per hour kelvin (F44)
+    MilligramPerHourKelvin,
+    /// Milligram per minute kelvin (F45)
+    MilligramPerMinuteKelvin,
+    /// Milligram per second kelvin (F46)
+    MilligramPerSecondKelvin,
+    /// Newton per millimetre (F47)
+    NewtonPerMillimetre,
+    /// Pound-force per inch (F48)
+    PoundForcePerInch,
+    /// Rod [unit of distance] (F49)
+    RodUnitOfDistance,
+    /// Micrometre per kelvin (F50)
+    MicrometrePerKelvin,
+    /// Centimetre per kelvin (F51)
+    CentimetrePerKelvin,
+    /// Metre per kelvin (F52)
+    MetrePerKelvin,
+    /// Millimetre per kelvin (F53)
+    MillimetrePerKelvin,
+    /// Milliohm per metre (F54)
+    MilliohmPerMetre,
+    /// Ohm per mile (statute mile) (F55)
+    OhmPerMileStatuteMile,
+    /// Ohm per kilometre (F56)
+    OhmPerKilometre,
+    /// Milliampere per pound-force per square inch (F57)
+    MilliamperePerPoundForcePerSquareInch,
+    /// Reciprocal bar (F58)
+    ReciprocalBar,
+    /// Milliampere per bar (F59)
+    MilliamperePerBar,
+    /// Degree Celsius per bar (F60)
+    DegreeCelsiusPerBar,
+    /// Kelvin per bar (F61)
+    KelvinPerBar,
+    /// Gram per day bar (F62)
+    GramPerDayBar,
+    /// Gram per hour bar (F63)
+    GramPerHourBar,
+    /// Gram per minute bar (F64)
+    GramPerMinuteBar,
+    /// Gram per second bar (F65)
+    GramPerSecondBar,
+    /// Kilogram per day bar (F66)
+    KilogramPerDayBar,
+    /// Kilogram per hour bar (F67)
+    KilogramPerHourBar,
+    /// Kilogram per minute bar (F68)
+    KilogramPerMinuteBar,
+    /// Kilogram per second bar (F69)
+    KilogramPerSecondBar,
+    /// Milligram per day bar (F70)
+    MilligramPerDayBar,
+    /// Milligram per hour bar (F71)
+    MilligramPerHourBar,
+    /// Milligram per minute bar (F72)
+    MilligramPerMinuteBar,
+    /// Milligram per second bar (F73)
+    MilligramPerSecondBar,
+    /// Gram per bar (F74)
+    GramPerBar,
+    /// Milligram per bar (F75)
+    MilligramPerBar,
+    /// Milliampere per millimetre (F76)
+    MilliamperePerMillimetre,
+    /// Pascal second per kelvin (F77)
+    PascalSecondPerKelvin,
+    /// Inch of water (F78)
+    InchOfWater,
+    /// Inch of mercury (F79)
+    InchOfMercury,
+    /// Water horse power (F80)
+    WaterHorsePower,
+    /// Bar per kelvin (F81)
+    BarPerKelvin,
+    /// Hectopascal per kelvin (F82)
+    HectopascalPerKelvin,
+    /// Kilopascal per kelvin (F83)
+    KilopascalPerKelvin,
+    /// Millibar per kelvin (F84)
+    MillibarPerKelvin,
+    /// Megapascal per kelvin (F85)
+    MegapascalPerKelvin,
+    /// Poise per kelvin (F86)
+    PoisePerKelvin,
+    /// Volt per litre minute (F87)
+    VoltPerLitreMinute,
+    /// Newton centimetre (F88)
+    NewtonCentimetre,
+    /// Newton metre per degree (F89)
+    NewtonMetrePerDegree,
+    /// Newton per ampere (F90)
+    NewtonPerAmpere,
+    /// Bar litre per second (F91)
+    BarLitrePerSecond,
+    /// Bar cubic metre per second (F92)
+    BarCubicMetrePerSecond,
+    /// Hectopascal litre per second (F93)
+    HectopascalLitrePerSecond,
+    /// Hectopascal cubic metre per second (F94)
+    HectopascalCubicMetrePerSecond,
+    /// Millibar litre per second (F95)
+    MillibarLitrePerSecond,
+    /// Millibar cubic metre per second (F96)
+    MillibarCubicMetrePerSecond,
+    /// Megapascal litre per second (F97)
+    MegapascalLitrePerSecond,
+    /// Megapascal cubic metre per second (F98)
+    MegapascalCubicMetrePerSecond,
+    /// Pascal litre per second (F99)
+    PascalLitrePerSecond,
+    /// Degree Fahrenheit (FAH)
+    DegreeFahrenheit,
+    /// Farad (FAR)
+    Farad,
+    /// Fibre metre (FBM)
+    FibreMetre,
+    /// Thousand cubic foot (FC)
+    ThousandCubicFoot,
+    /// Hundred cubic metre (FF)
+    HundredCubicMetre,
+    /// Micromole (FH)
+    Micromole,
+    /// Failures in time (FIT)
+    FailuresInTime,
+    /// Flake ton (FL)
+    FlakeTon,
+    /// Formazin nephelometric unit (FNU)
+    FormazinNephelometricUnit,
+    /// Foot (FOT)
+    Foot,
+    /// Pound per square foot (FP)
+    PoundPerSquareFoot,
+    /// Foot per minute (FR)
+    FootPerMinute,
+    /// Foot per second (FS)
+    FootPerSecond,
+    /// Square foot (FTK)
+    SquareFoot,
+    /// Cubic foot (FTQ)
+    CubicFoot,
+    /// Pascal cubic metre per second (G01)
+    PascalCubicMetrePerSecond,
+    /// Centimetre per bar (G04)
+    CentimetrePerBar,
+    /// Metre per bar (G05)
+    MetrePerBar,
+    /// Millimetre per bar (G06)
+    MillimetrePerBar,
+    /// Square inch per second (G08)
+    SquareInchPerSecond,
+    /// Square metre per second kelvin (G09)
+    SquareMetrePerSecondKelvin,
+    /// Stokes per kelvin (G10)
+    StokesPerKelvin,
+    /// Gram per cubic centimetre bar (G11)
+    GramPerCubicCentimetreBar,
+    /// Gram per cubic decimetre bar (G12)
+    GramPerCubicDecimetreBar,
+    /// Gram per litre bar (G13)
+    GramPerLitreBar,
+    /// Gram per cubic metre bar (G14)
+    GramPerCubicMetreBar,
+    /// Gram per millilitre bar (G15)
+    GramPerMillilitreBar,
+    /// Kilogram per cubic centimetre bar (G16)
+    KilogramPerCubicCentimetreBar,
+    /// Kilogram per litre bar (G17)
+    KilogramPerLitreBar,
+    /// Kilogram per cubic metre bar (G18)
+    KilogramPerCubicMetreBar,
+    /// Newton metre per kilogram (G19)
+    NewtonMetrePerKilogram,
+    /// US gallon per minute (G2)
+    UsGallonPerMinute,
+    /// Pound-force foot per pound (G20)
+    PoundForceFootPerPound,
+    /// Cup [unit of volume] (G21)
+    CupUnitOfVolume,
+    /// Peck (G23)
+    Peck,
+    /// Tablespoon (US) (G24)
+    TablespoonUs,
+    /// Teaspoon (US) (G25)
+    TeaspoonUs,
+    /// Stere (G26)
+    Stere,
+    /// Cubic centimetre per kelvin (G27)
+    CubicCentimetrePerKelvin,
+    /// Litre per kelvin (G28)
+    LitrePerKelvin,
+    /// Cubic metre per kelvin (G29)
+    CubicMetrePerKelvin,
+    /// Imperial gallon per minute (G3)
+    ImperialGallonPerMinute,
+    /// Millilitre per kelvin (G30)
+    MillilitrePerKelvin,
+    /// Kilogram per cubic centimetre (G31)
+    KilogramPerCubicCentimetre,
+    /// Ounce (avoirdupois) per cubic yard (G32)
+    OunceAvoirdupoisPerCubicYard,
+    /// Gram per cubic centimetre kelvin (G33)
+    GramPerCubicCentimetreKelvin,
+    /// Gram per cubic decimetre kelvin (G34)
+    GramPerCubicDecimetreKelvin,
+    /// Gram per litre kelvin (G35)
+    GramPerLitreKelvin,
+    /// Gram per cubic metre kelvin (G36)
+    GramPerCubicMetreKelvin,
+    /// Gram per millilitre kelvin (G37)
+    GramPerMillilitreKelvin,
+    /// Kilogram per cubic centimetre kelvin (G38)
+    KilogramPerCubicCentimetreKelvin,
+    /// Kilogram per litre kelvin (G39)
+    KilogramPerLitreKelvin,
+    /// Kilogram per cubic metre kelvin (G40)
+    KilogramPerCubicMetreKelvin,
+    /// Square metre per second bar (G41)
+    SquareMetrePerSecondBar,
+    /// Microsiemens per centimetre (G42)
+    MicrosiemensPerCentimetre,
+    /// Microsiemens per metre (G43)
+    MicrosiemensPerMetre,
+    /// Nanosiemens per centimetre (G44)
+    NanosiemensPerCentimetre,
+    /// Nanosiemens per metre (G45)
+    NanosiemensPerMetre,
+    /// Stokes per bar (G46)
+    StokesPerBar,
+    /// Cubic centimetre per day (G47)
+    CubicCentimetrePerDay,
+    /// Cubic centimetre per hour (G48)
+    CubicCentimetrePerHour,
+    /// Cubic centimetre per minute (G49)
+    CubicCentimetrePerMinute,
+    /// Gallon (US) per hour (G50)
+    GallonUsPerHour,
+    /// Litre per second (G51)
+    LitrePerSecond,
+    /// Cubic metre per day (G52)
+    CubicMetrePerDay,
+    /// Cubic metre per minute (G53)
+    CubicMetrePerMinute,
+    /// Millilitre per day (G54)
+    MillilitrePerDay,
+    /// Millilitre per hour (G55)
+    MillilitrePerHour,
+    /// Cubic inch per hour (G56)
+    CubicInchPerHour,
+    /// Cubic inch per minute (G57)
+    CubicInchPerMinute,
+    /// Cubic inch per second (G58)
+    CubicInchPerSecond,
+    /// Milliampere per litre minute (G59)
+    MilliamperePerLitreMinute,
+    /// Volt per bar (G60)
+    VoltPerBar,
+    /// Cubic centimetre per day kelvin (G61)
+    CubicCentimetrePerDayKelvin,
+    /// Cubic centimetre per hour kelvin (G62)
+    CubicCentimetrePerHourKelvin,
+    /// Cubic centimetre per minute kelvin (G63)
+    CubicCentimetrePerMinuteKelvin,
+    /// Cubic centimetre per second kelvin (G64)
+    CubicCentimetrePerSecondKelvin,
+    /// Litre per day kelvin (G65)
+    LitrePerDayKelvin,
+    /// Litre per hour kelvin (G66)
+    LitrePerHourKelvin,
+    /// Litre per minute kelvin (G67)
+    LitrePerMinuteKelvin,
+    /// Litre per second kelvin (G68)
+    LitrePerSecondKelvin,
+    /// Cubic metre per day kelvin (G69)
+    CubicMetrePerDayKelvin,
+    /// Cubic metre per hour kelvin (G70)
+    CubicMetrePerHourKelvin,
+    /// Cubic metre per minute kelvin (G71)
+    CubicMetrePerMinuteKelvin,
+    /// Cubic metre per second kelvin (G72)
+    CubicMetrePerSecondKelvin,
+    /// Millilitre per day kelvin (G73)
+    MillilitrePerDayKelvin,
+    /// Millilitre per hour kelvin (G74)
+    MillilitrePerHourKelvin,
+    /// Millilitre per minute kelvin (G75)
+    MillilitrePerMinuteKelvin,
+    /// Millilitre per second kelvin (G76)
+    MillilitrePerSecondKelvin,
+    /// Millipascal second per kelvin (G77)
+    MillipascalSecondPerKelvin,
+    /// Cubic centimetre per day bar (G78)
+    CubicCentimetrePerDayBar,
+    /// Cubic centimetre per hour bar (G79)
+    CubicCentimetrePerHourBar,
+    /// Cubic centimetre per minute bar (G80)
+    CubicCentimetrePerMinuteBar,
+    /// Cubic centimetre per second bar (G81)
+    CubicCentimetrePerSecondBar,
+    /// Litre per day bar (G82)
+    LitrePerDayBar,
+    /// Litre per hour bar (G83)
+    LitrePerHourBar,
+    /// Litre per minute bar (G84)
+    LitrePerMinuteBar,
+    /// Litre per second bar (G85)
+    LitrePerSecondBar,
+    /// Cubic metre per day bar (G86)
+    CubicMetrePerDayBar,
+    /// Cubic metre per hour bar (G87)
+    CubicMetrePerHourBar,
+    /// Cubic metre per minute bar (G88)
+    CubicMetrePerMinuteBar,
+    /// Cubic metre per second bar (G89)
+    CubicMetrePerSecondBar,
+    /// Millilitre per day bar (G90)
+    MillilitrePerDayBar,
+    /// Millilitre per hour bar (G91)
+    MillilitrePerHourBar,
+    /// Millilitre per minute bar (G92)
+    MillilitrePerMinuteBar,
+    /// Millilitre per second bar (G93)
+    MillilitrePerSecondBar,
+    /// Cubic centimetre per bar (G94)
+    CubicCentimetrePerBar,
+    /// Litre per bar (G95)
+    LitrePerBar,
+    /// Cubic metre per bar (G96)
+    CubicMetrePerBar,
+    /// Millilitre per bar (G97)
+    MillilitrePerBar,
+    /// Microhenry per kiloohm (G98)
+    MicrohenryPerKiloohm,
+    /// Microhenry per ohm (G99)
+    MicrohenryPerOhm,
+    /// Gallon (US) per day (GB)
+    GallonUsPerDay,
+    /// Gigabecquerel (GBQ)
+    Gigabecquerel,
+    /// Gram, dry weight (GDW)
+    GramDryWeight,
+    /// Pound per gallon (US) (GE)
+    PoundPerGallonUs,
+    /// Gram per metre (gram per 100 centimetres) (GF)
+    GramPerMetreGramPer100Centimetres,
+    /// Gram of fissile isotope (GFI)
+    GramOfFissileIsotope,
+    /// Great gross (GGR)
+    GreatGross,
+    /// Gill (US) (GIA)
+    GillUs,
+    /// Gram, including container (GIC)
+    GramIncludingContainer,
+    /// Gill (UK) (GII)
+    GillUk,
+    /// Gram, including inner packaging (GIP)
+    GramIncludingInnerPackaging,
+    /// Gram per millilitre (GJ)
+    GramPerMillilitre,
+    /// Gram per litre (GL)
+    GramPerLitre,
+    /// Dry gallon (US) (GLD)
+    DryGallonUs,
+    /// Gallon (UK) (GLI)
+    GallonUk,
+    /// Gallon (US) (GLL)
+    GallonUs,
+    /// Gram per square metre (GM)
+    GramPerSquareMetre,
+    /// Milligram per square metre (GO)
+    MilligramPerSquareMetre,
+    /// Milligram per cubic metre (GP)
+    MilligramPerCubicMetre,
+    /// Microgram per cubic metre (GQ)
+    MicrogramPerCubicMetre,
+    /// Gram (GRM)
+    Gram,
+    /// Grain (GRN)
+    Grain,
+    /// Gross (GRO)
+    Gross,
+    /// Gigajoule (GV)
+    Gigajoule,
+    /// Gigawatt hour (GWH)
+    GigawattHour,
+    /// Henry per kiloohm (H03)
+    HenryPerKiloohm,
+    /// Henry per ohm (H04)
+    HenryPerOhm,
+    /// Millihenry per kiloohm (H05)
+    MillihenryPerKiloohm,
+    /// Millihenry per ohm (H06)
+    MillihenryPerOhm,
+    /// Pascal second per bar (H07)
+    PascalSecondPerBar,
+    /// Microbecquerel (H08)
+    Microbecquerel,
+    /// Reciprocal year (H09)
+    ReciprocalYear,
+    /// Reciprocal hour (H10)
+    ReciprocalHour,
+    /// Reciprocal month (H11)
+    ReciprocalMonth,
+    /// Degree Celsius per hour (H12)
+    DegreeCelsiusPerHour,
+    /// Degree Celsius per minute (H13)
+    DegreeCelsiusPerMinute,
+    /// Degree Celsius per second (H14)
+    DegreeCelsiusPerSecond,
+    /// Square centimetre per gram (H15)
+    SquareCentimetrePerGram,
+    /// Square decametre (H16)
+    SquareDecametre,
+    /// Square hectometre (H18)
+    SquareHectometre,
+    /// Cubic hectometre (H19)
+    CubicHectometre,
+    /// Cubic kilometre (H20)
+    CubicKilometre,
+    /// Blank (H21)
+    Blank,
+    /// Volt square inch per pound-force (H22)
+    VoltSquareInchPerPoundForce,
+    /// Volt per inch (H23)
+    VoltPerInch,
+    /// Volt per microsecond (H24)
+    VoltPerMicrosecond,
+    /// Percent per kelvin (H25)
+    PercentPerKelvin,
+    /// Ohm per metre (H26)
+    OhmPerMetre,
+    /// Degree per metre (H27)
+    DegreePerMetre,
+    /// Microfarad per kilometre (H28)
+    MicrofaradPerKilometre,
+    /// Microgram per litre (H29)
+    MicrogramPerLitre,
+    /// Square micrometre (square micron) (H30)
+    SquareMicrometreSquareMicron,
+    /// Ampere per kilogram (H31)
+    AmperePerKilogram,
+    /// Ampere squared second (H32)
+    AmpereSquaredSecond,
+    /// Farad per kilometre (H33)
+    FaradPerKilometre,
+    /// Hertz metre (H34)
+    HertzMetre,
+    /// Kelvin metre per watt (H35)
+    KelvinMetrePerWatt,
+    /// Megaohm per kilometre (H36)
+    MegaohmPerKilometre,
+    /// Megaohm per metre (H37)
+    MegaohmPerMetre,
+    /// Megaampere (H38)
+    Megaampere,
+    /// Megahertz kilometre (H39)
+    MegahertzKilometre,
+    /// Newton per ampere (H40)
+    NewtonPerAmpereH40,
+    /// Newton metre watt to the power minus 0,5 (H41)
+    NewtonMetreWattToThePowerMinus05,
+    /// Pascal per metre (H42)
+    PascalPerMetre,
+    /// Siemens per centimetre (H43)
+    SiemensPerCentimetre,
+    /// Teraohm (H44)
+    Teraohm,
+    /// Volt second per metre (H45)
+    VoltSecondPerMetre,
+    /// Volt per second (H46)
+    VoltPerSecond,
+    /// Watt per cubic metre (H47)
+    WattPerCubicMetre,
+    /// Attofarad (H48)
+    Attofarad,
+    /// Centimetre per hour (H49)
+    CentimetrePerHour,
+    /// Reciprocal cubic centimetre (H50)
+    ReciprocalCubicCentimetre,
+    /// Decibel per kilometre (H51)
+    DecibelPerKilometre,
+    /// Decibel per metre (H52)
+    DecibelPerMetre,
+    /// Kilogram per bar (H53)
+    KilogramPerBar,
+    /// Kilogram per cubic decimetre kelvin (H54)
+    KilogramPerCubicDecimetreKelvin,
+    /// Kilogram per cubic decimetre bar (H55)
+    KilogramPerCubicDecimetreBar,
+    /// Kilogram per square metre second (H56)
+    KilogramPerSquareMetreSecond,
+    /// Inch per two pi radiant (H57)
+    InchPerTwoPiRadiant,
+    /// Metre per volt second (H58)
+    MetrePerVoltSecond,
+    /// Square metre per newton (H59)
+    SquareMetrePerNewton,
+    /// Cubic metre per cubic metre (H60)
+    CubicMetrePerCubicMetre,
+    /// Millisiemens per centimetre (H61)
+    MillisiemensPerCentimetre,
+    /// Millivolt per minute (H62)
+    MillivoltPerMinute,
+    /// Milligram per square centimetre (H63)
+    MilligramPerSquareCentimetre,
+    /// Milligram per gram (H64)
+    MilligramPerGram,
+    /// Millilitre per cubic metre (H65)
+    MillilitrePerCubicMetre,
+    /// Millimetre per year (H66)
+    MillimetrePerYear,
+    /// Millimetre per hour (H67)
+    MillimetrePerHour,
+    /// Millimole per gram (H68)
+    MillimolePerGram,
+    /// Picopascal per kilometre (H69)
+    PicopascalPerKilometre,
+    /// Picosecond (H70)
+    Picosecond,
+    /// Percent per month (H71)
+    PercentPerMonth,
+    /// Percent per hectobar (H72)
+    PercentPerHectobar,
+    /// Percent per decakelvin (H73)
+    PercentPerDecakelvin,
+    /// Watt per metre (H74)
+    WattPerMetre,
+    /// Decapascal (H75)
+    Decapascal,
+    /// Gram per millimetre (H76)
+    GramPerMillimetre,
+    /// Module width (H77)
+    ModuleWidth,
+    /// French gauge (H79)
+    FrenchGauge,
+    /// Rack unit (H80)
+    RackUnit,
+    /// Millimetre per minute (H81)
+    MillimetrePerMinute,
+    /// Big point (H82)
+    BigPoint,
+    /// Litre per kilogram (H83)
+    LitrePerKilogram,
+    /// Gram millimetre (H84)
+    GramMillimetre,
+    /// Reciprocal week (H85)
+    ReciprocalWeek,
+    /// Piece (H87)
     Piece,
+    /// Megaohm kilometre (H88)
+    MegaohmKilometre,
+    /// Percent per ohm (H89)
+    PercentPerOhm,
+    /// Percent per degree (H90)
+    PercentPerDegree,
+    /// Percent per ten thousand (H91)
+    PercentPerTenThousand,
+    /// Percent per one hundred thousand (H92)
+    PercentPerOneHundredThousand,
+    /// Percent per hundred (H93)
+    PercentPerHundred,
+    /// Percent per thousand (H94)
+    PercentPerThousand,
+    /// Percent per volt (H95)
+    PercentPerVolt,
+    /// Percent per bar (H96)
+    PercentPerBar,
+    /// Percent per inch (H98)
+    PercentPerInch,
+    /// Percent per metre (H99)
+    PercentPerMetre,
+    /// Hank (HA)
+    Hank,
+    /// Piece Day (HAD)
+    PieceDay,
+    /// Hectare (HBA)
+    Hectare,
+    /// Hundred boxes (HBX)
+    HundredBoxes,
+    /// Hundred count (HC)
+    HundredCount,
+    /// Hundred kilogram, dry weight (HDW)
+    HundredKilogramDryWeight,
+    /// Head (HEA)
+    Head,
+    /// Hectogram (HGM)
+    Hectogram,
+    /// Hundred cubic foot (HH)
+    HundredCubicFoot,
+    /// Hundred international unit (HIU)
+    HundredInternationalUnit,
+    /// Hundred kilogram, net mass (HKM)
+    HundredKilogramNetMass,
+    /// Hectolitre (HLT)
+    Hectolitre,
+    /// Mile per hour (statute mile) (HM)
+    MilePerHourStatuteMile,
+    /// Piece Month (HMO)
+    PieceMonth,
+    /// Million cubic metre (HMQ)
+    MillionCubicMetre,
+    /// Hectometre (HMT)
+    Hectometre,
+    /// Hectolitre of pure alcohol (HPA)
+    HectolitreOfPureAlcohol,
+    /// Hertz (HTZ)
+    Hertz,
+    /// Hour (HUR)
+    Hour,
+    /// Piece Week (HWE)
+    PieceWeek,
+    /// Inch pound (pound inch) (IA)
+    InchPoundPoundInch,
+    /// Person (IE)
+    Person,
+    /// Inch (INH)
+    Inch,
+    /// Square inch (INK)
+    SquareInch,
+    /// Cubic inch (INQ)
+    CubicInch,
+    /// International sugar degree (ISD)
+    InternationalSugarDegree,
+    /// Inch per second (IU)
+    InchPerSecond,
+    /// International unit per gram (IUG)
+    InternationalUnitPerGram,
+    /// Inch per second squared (IV)
+    InchPerSecondSquared,
+    /// Percent per millimetre (J10)
+    PercentPerMillimetre,
+    /// Per mille per psi (J12)
+    PerMillePerPsi,
+    /// Degree API (J13)
+    DegreeApi,
+    /// Degree Baume (origin scale) (J14)
+    DegreeBaumeOriginScale,
+    /// Degree Baume (US heavy) (J15)
+    DegreeBaumeUsHeavy,
+    /// Degree Baume (US light) (J16)
+    DegreeBaumeUsLight,
+    /// Degree Balling (J17)
+    DegreeBalling,
+    /// Degree Brix (J18)
+    DegreeBrix,
+    /// Degree Fahrenheit hour square foot per British thermal unit (thermochemical) (J19)
+    DegreeFahrenheitHourSquareFootPerBritishThermalUnitThermochemical,
+    /// Joule per kilogram (J2)
+    JoulePerKilogram,
+    /// Degree Fahrenheit per kelvin (J20)
+    DegreeFahrenheitPerKelvin,
+    /// Degree Fahrenheit per bar (J21)
+    DegreeFahrenheitPerBar,
+    /// Degree Fahrenheit hour square foot per British thermal unit (international table) (J22)
+    DegreeFahrenheitHourSquareFootPerBritishThermalUnitInternationalTable,
+    /// Degree Fahrenheit per hour (J23)
+    DegreeFahrenheitPerHour,
+    /// Degree Fahrenheit per minute (J24)
+    DegreeFahrenheitPerMinute,
+    /// Degree Fahrenheit per second (J25)
+    DegreeFahrenheitPerSecond,
+    /// Reciprocal degree Fahrenheit (J26)
+    ReciprocalDegreeFahrenheit,
+    /// Degree Oechsle (J27)
+    DegreeOechsle,
+    /// Degree Rankine per hour (J28)
+    DegreeRankinePerHour,
+    /// Degree Rankine per minute (J29)
+    DegreeRankinePerMinute,
+    /// Degree Rankine per second (J30)
+    DegreeRankinePerSecond,
+    /// Degree Twaddell (J31)
+    DegreeTwaddell,
+    /// Micropoise (J32)
+    Micropoise,
+    /// Microgram per kilogram (J33)
+    MicrogramPerKilogram,
+    /// Microgram per cubic metre kelvin (J34)
+    MicrogramPerCubicMetreKelvin,
+    /// Microgram per cubic metre bar (J35)
+    MicrogramPerCubicMetreBar,
+    /// Microlitre per litre (J36)
+    MicrolitrePerLitre,
+    /// Baud (J38)
+    Baud,
+    /// British thermal unit (mean) (J39)
+    BritishThermalUnitMean,
+    /// British thermal unit (international table) foot per hour square foot degree Fahrenheit (J40)
+    BritishThermalUnitInternationalTableFootPerHourSquareFootDegreeFahrenheit,
+    /// British thermal unit (international table) inch per hour square foot degree Fahrenheit (J41)
+    BritishThermalUnitInternationalTableInchPerHourSquareFootDegreeFahrenheit,
+    /// British thermal unit (international table) inch per second square foot degree Fahrenheit (J42)
+    BritishThermalUnitInternationalTableInchPerSecondSquareFootDegreeFahrenheit,
+    /// British thermal unit (international table) per pound degree Fahrenheit (J43)
+    BritishThermalUnitInternationalTablePerPoundDegreeFahrenheit,
+    /// British thermal unit (international table) per minute (J44)
+    BritishThermalUnitInternationalTablePerMinute,
+    /// British thermal unit (international table) per second (J45)
+    BritishThermalUnitInternationalTablePerSecond,
+    /// British thermal unit (thermochemical) foot per hour square foot degree Fahrenheit (J46)
+    BritishThermalUnitThermochemicalFootPerHourSquareFootDegreeFahrenheit,
+    /// British thermal unit (thermochemical) per hour (J47)
+    BritishThermalUnitThermochemicalPerHour,
+    /// British thermal unit (thermochemical) inch per hour square foot degree Fahrenheit (J48)
+    BritishThermalUnitThermochemicalInchPerHourSquareFootDegreeFahrenheit,
+    /// British thermal unit (thermochemical) inch per second square foot degree Fahrenheit (J49)
+    BritishThermalUnitThermochemicalInchPerSecondSquareFootDegreeFahrenheit,
+    /// British thermal unit (thermochemical) per pound degree Fahrenheit (J50)
+    BritishThermalUnitThermochemicalPerPoundDegreeFahrenheit,
+    /// British thermal unit (thermochemical) per minute (J51)
+    BritishThermalUnitThermochemicalPerMinute,
+    /// British thermal unit (thermochemical) per second (J52)
+    BritishThermalUnitThermochemicalPerSecond,
+    /// Coulomb square metre per kilogram (J53)
+    CoulombSquareMetrePerKilogram,
+    /// Megabaud (J54)
+    Megabaud,
+    /// Watt second (J55)
+    WattSecond,
+    /// Bar per bar (J56)
+    BarPerBar,
+    /// Barrel (UK petroleum) (J57)
+    BarrelUkPetroleum,
+    /// Barrel (UK petroleum) per minute (J58)
+    BarrelUkPetroleumPerMinute,
+    /// Barrel (UK petroleum) per day (J59)
+    BarrelUkPetroleumPerDay,
+    /// Barrel (UK petroleum) per hour (J60)
+    BarrelUkPetroleumPerHour,
+    /// Barrel (UK petroleum) per second (J61)
+    BarrelUkPetroleumPerSecond,
+    /// Barrel (US petroleum) per hour (J62)
+    BarrelUsPetroleumPerHour,
+    /// Barrel (US petroleum) per second (J63)
+    BarrelUsPetroleumPerSecond,
+    /// Bushel (UK) per day (J64)
+    BushelUkPerDay,
+    /// Bushel (UK) per hour (J65)
+    BushelUkPerHour,
+    /// Bushel (UK) per minute (J66)
+    BushelUkPerMinute,
+    /// Bushel (UK) per second (J67)
+    BushelUkPerSecond,
+    /// Bushel (US dry) per day (J68)
+    BushelUsDryPerDay,
+    /// Bushel (US dry) per hour (J69)
+    BushelUsDryPerHour,
+    /// Bushel (US dry) per minute (J70)
+    BushelUsDryPerMinute,
+    /// Bushel (US dry) per second (J71)
+    BushelUsDryPerSecond,
+    /// Centinewton metre (J72)
+    CentinewtonMetre,
+    /// Centipoise per kelvin (J73)
+    CentipoisePerKelvin,
+    /// Centipoise per bar (J74)
+    CentipoisePerBar,
+    /// Calorie (mean) (J75)
+    CalorieMean,
+    /// Calorie (international table) per gram degree Celsius (J76)
+    CalorieInternationalTablePerGramDegreeCelsius,
+    /// Calorie (thermochemical) per centimetre second degree Celsius (J78)
+    CalorieThermochemicalPerCentimetreSecondDegreeCelsius,
+    /// Calorie (thermochemical) per gram degree Celsius (J79)
+    CalorieThermochemicalPerGramDegreeCelsius,
+    /// Calorie (thermochemical) per minute (J81)
+    CalorieThermochemicalPerMinute,
+    /// Calorie (thermochemical) per second (J82)
+    CalorieThermochemicalPerSecond,
+    /// Clo (J83)
+    Clo,
+    /// Centimetre per second kelvin (J84)
+    CentimetrePerSecondKelvin,
+    /// Centimetre per second bar (J85)
+    CentimetrePerSecondBar,
+    /// Cubic centimetre per cubic metre (J87)
+    CubicCentimetrePerCubicMetre,
+    /// Cubic decimetre per day (J90)
+    CubicDecimetrePerDay,
+    /// Cubic decimetre per minute (J92)
+    CubicDecimetrePerMinute,
+    /// Cubic decimetre per second (J93)
+    CubicDecimetrePerSecond,
+    /// Ounce (UK fluid) per day (J95)
+    OunceUkFluidPerDay,
+    /// Ounce (UK fluid) per hour (J96)
+    OunceUkFluidPerHour,
+    /// Ounce (UK fluid) per minute (J97)
+    OunceUkFluidPerMinute,
+    /// Ounce (UK fluid) per second (J98)
+    OunceUkFluidPerSecond,
+    /// Ounce (US fluid) per day (J99)
+    OunceUsFluidPerDay,
+    /// Joule per kelvin (JE)
+    JoulePerKelvin,
+    /// Megajoule per kilogram (JK)
+    MegajoulePerKilogram,
+    /// Megajoule per cubic metre (JM)
+    MegajoulePerCubicMetre,
+    /// Pipeline joint (JNT)
+    PipelineJoint,
+    /// Joule (JOU)
+    Joule,
+    /// Hundred metre (JPS)
+    HundredMetre,
+    /// Number of jewels (JWL)
+    NumberOfJewels,
+    /// Kilowatt demand (K1)
+    KilowattDemand,
+    /// Ounce (US fluid) per hour (K10)
+    OunceUsFluidPerHour,
+    /// Ounce (US fluid) per minute (K11)
+    OunceUsFluidPerMinute,
+    /// Ounce (US fluid) per second (K12)
+    OunceUsFluidPerSecond,
+    /// Foot per degree Fahrenheit (K13)
+    FootPerDegreeFahrenheit,
+    /// Foot per hour (K14)
+    FootPerHour,
+    /// Foot pound-force per hour (K15)
+    FootPoundForcePerHour,
+    /// Foot pound-force per minute (K16)
+    FootPoundForcePerMinute,
+    /// Foot per psi (K17)
+    FootPerPsi,
+    /// Foot per second degree Fahrenheit (K18)
+    FootPerSecondDegreeFahrenheit,
+    /// Foot per second psi (K19)
+    FootPerSecondPsi,
+    /// Kilovolt ampere reactive demand (K2)
+    KilovoltAmpereReactiveDemand,
+    /// Reciprocal cubic foot (K20)
+    ReciprocalCubicFoot,
+    /// Cubic foot per degree Fahrenheit (K21)
+    CubicFootPerDegreeFahrenheit,
+    /// Cubic foot per day (K22)
+    CubicFootPerDay,
+    /// Cubic foot per psi (K23)
+    CubicFootPerPsi,
+    /// Gallon (UK) per day (K26)
+    GallonUkPerDay,
+    /// Gallon (UK) per hour (K27)
+    GallonUkPerHour,
+    /// Gallon (UK) per second (K28)
+    GallonUkPerSecond,
+    /// Kilovolt ampere reactive hour (K3)
+    KilovoltAmpereReactiveHour,
+    /// Gallon (US liquid) per second (K30)
+    GallonUsLiquidPerSecond,
+    /// Gram-force per square centimetre (K31)
+    GramForcePerSquareCentimetre,
+    /// Gill (UK) per day (K32)
+    GillUkPerDay,
+    /// Gill (UK) per hour (K33)
+    GillUkPerHour,
+    /// Gill (UK) per minute (K34)
+    GillUkPerMinute,
+    /// Gill (UK) per second (K35)
+    GillUkPerSecond,
+    /// Gill (US) per day (K36)
+    GillUsPerDay,
+    /// Gill (US) per hour (K37)
+    GillUsPerHour,
+    /// Gill (US) per minute (K38)
+    GillUsPerMinute,
+    /// Gill (US) per second (K39)
+    GillUsPerSecond,
+    /// Standard acceleration of free fall (K40)
+    StandardAccelerationOfFreeFall,
+    /// Grain per gallon (US) (K41)
+    GrainPerGallonUs,
+    /// Horsepower (boiler) (K42)
+    HorsepowerBoiler,
+    /// Horsepower (electric) (K43)
+    HorsepowerElectric,
+    /// Inch per degree Fahrenheit (K45)
+    InchPerDegreeFahrenheit,
+    /// Inch per psi (K46)
+    InchPerPsi,
+    /// Inch per second degree Fahrenheit (K47)
+    InchPerSecondDegreeFahrenheit,
+    /// Inch per second psi (K48)
+    InchPerSecondPsi,
+    /// Reciprocal cubic inch (K49)
+    ReciprocalCubicInch,
+    /// Kilobaud (K50)
+    Kilobaud,
+    /// Kilocalorie (mean) (K51)
+    KilocalorieMean,
+    /// Kilocalorie (international table) per hour metre degree Celsius (K52)
+    KilocalorieInternationalTablePerHourMetreDegreeCelsius,
+    /// Kilocalorie (thermochemical) (K53)
+    KilocalorieThermochemical,
+    /// Kilocalorie (thermochemical) per minute (K54)
+    KilocalorieThermochemicalPerMinute,
+    /// Kilocalorie (thermochemical) per second (K55)
+    KilocalorieThermochemicalPerSecond,
+    /// Kilomole per hour (K58)
+    KilomolePerHour,
+    /// Kilomole per cubic metre kelvin (K59)
+    KilomolePerCubicMetreKelvin,
+    /// Kilolitre (K6)
+    Kilolitre,
+    /// Kilomole per cubic metre bar (K60)
+    KilomolePerCubicMetreBar,
+    /// Kilomole per minute (K61)
+    KilomolePerMinute,
+    /// Litre per litre (K62)
+    LitrePerLitre,
+    /// Reciprocal litre (K63)
+    ReciprocalLitre,
+    /// Pound (avoirdupois) per degree Fahrenheit (K64)
+    PoundAvoirdupoisPerDegreeFahrenheit,
+    /// Pound (avoirdupois) square foot (K65)
+    PoundAvoirdupoisSquareFoot,
+    /// Pound (avoirdupois) per day (K66)
+    PoundAvoirdupoisPerDay,
+    /// Pound per foot hour (K67)
+    PoundPerFootHour,
+    /// Pound per foot second (K68)
+    PoundPerFootSecond,
+    /// Pound (avoirdupois) per cubic foot degree Fahrenheit (K69)
+    PoundAvoirdupoisPerCubicFootDegreeFahrenheit,
+    /// Pound (avoirdupois) per cubic foot psi (K70)
+    PoundAvoirdupoisPerCubicFootPsi,
+    /// Pound (avoirdupois) per gallon (UK) (K71)
+    PoundAvoirdupoisPerGallonUk,
+    /// Pound (avoirdupois) per hour degree Fahrenheit (K73)
+    PoundAvoirdupoisPerHourDegreeFahrenheit,
+    /// Pound (avoirdupois) per hour psi (K74)
+    PoundAvoirdupoisPerHourPsi,
+    /// Pound (avoirdupois) per cubic inch degree Fahrenheit (K75)
+    PoundAvoirdupoisPerCubicInchDegreeFahrenheit,
+    /// Pound (avoirdupois) per cubic inch psi (K76)
+    PoundAvoirdupoisPerCubicInchPsi,
+    /// Pound (avoirdupois) per psi (K77)
+    PoundAvoirdupoisPerPsi,
+    /// Pound (avoirdupois) per minute (K78)
+    PoundAvoirdupoisPerMinute,
+    /// Pound (avoirdupois) per minute degree Fahrenheit (K79)
+    PoundAvoirdupoisPerMinuteDegreeFahrenheit,
+    /// Pound (avoirdupois) per minute psi (K80)
+    PoundAvoirdupoisPerMinutePsi,
+    /// Pound (avoirdupois) per second (K81)
+    PoundAvoirdupoisPerSecond,
+    /// Pound (avoirdupois) per second degree Fahrenheit (K82)
+    PoundAvoirdupoisPerSecondDegreeFahrenheit,
+    /// Pound (avoirdupois) per second psi (K83)
+    PoundAvoirdupoisPerSecondPsi,
+    /// Pound per cubic yard (K84)
+    PoundPerCubicYard,
+    /// Pound-force per square foot (K85)
+    PoundForcePerSquareFoot,
+    /// Pound-force per square inch degree Fahrenheit (K86)
+    PoundForcePerSquareInchDegreeFahrenheit,
+    /// Psi cubic inch per second (K87)
+    PsiCubicInchPerSecond,
+    /// Psi litre per second (K88)
+    PsiLitrePerSecond,
+    /// Psi cubic metre per second (K89)
+    PsiCubicMetrePerSecond,
+    /// Psi cubic yard per second (K90)
+    PsiCubicYardPerSecond,
+    /// Pound-force second per square foot (K91)
+    PoundForceSecondPerSquareFoot,
+    /// Pound-force second per square inch (K92)
+    PoundForceSecondPerSquareInch,
+    /// Reciprocal psi (K93)
+    ReciprocalPsi,
+    /// Quart (UK liquid) per day (K94)
+    QuartUkLiquidPerDay,
+    /// Quart (UK liquid) per hour (K95)
+    QuartUkLiquidPerHour,
+    /// Quart (UK liquid) per minute (K96)
+    QuartUkLiquidPerMinute,
+    /// Quart (UK liquid) per second (K97)
+    QuartUkLiquidPerSecond,
+    /// Quart (US liquid) per day (K98)
+    QuartUsLiquidPerDay,
+    /// Quart (US liquid) per hour (K99)
+    QuartUsLiquidPerHour,
+    /// Cake (KA)
+    Cake,
+    /// Katal (KAT)
+    Katal,
+    /// Kilocharacter (KB)
+    Kilocharacter,
+    /// Kilobar (KBA)
+    Kilobar,
+    /// Kilogram of choline chloride (KCC)
+    KilogramOfCholineChloride,
+    /// Kilogram drained net weight (KDW)
+    KilogramDrainedNetWeight,
+    /// Kelvin (KEL)
+    Kelvin,
+    /// Kilogram (KGM)
+    Kilogram,
+    /// Kilogram per second (KGS)
+    KilogramPerSecond,
+    /// Kilogram of hydrogen peroxide (KHY)
+    KilogramOfHydrogenPeroxide,
+    /// Kilohertz (KHZ)
+    Kilohertz,
+    /// Kilogram per millimetre width (KI)
+    KilogramPerMillimetreWidth,
+    /// Kilogram, including container (KIC)
+    KilogramIncludingContainer,
+    /// Kilogram, including inner packaging (KIP)
+    KilogramIncludingInnerPackaging,
+    /// Kilosegment (KJ)
+    Kilosegment,
+    /// Kilojoule (KJO)
+    Kilojoule,
+    /// Kilogram per metre (KL)
+    KilogramPerMetre,
+    /// Lactic dry material percentage (KLK)
+    LacticDryMaterialPercentage,
+    /// Kilolux (KLX)
+    Kilolux,
+    /// Kilogram of methylamine (KMA)
+    KilogramOfMethylamine,
+    /// Kilometre per hour (KMH)
+    KilometrePerHour,
+    /// Square kilometre (KMK)
+    SquareKilometre,
+    /// Kilogram per cubic metre (KMQ)
+    KilogramPerCubicMetre,
+    /// Kilometre (KMT)
+    Kilometre,
+    /// Kilogram of nitrogen (KNI)
+    KilogramOfNitrogen,
+    /// Kilonewton per square metre (KNM)
+    KilonewtonPerSquareMetre,
+    /// Kilogram named substance (KNS)
+    KilogramNamedSubstance,
+    /// Knot (KNT)
+    Knot,
+    /// Milliequivalence caustic potash per gram of product (KO)
+    MilliequivalenceCausticPotashPerGramOfProduct,
+    /// Kilopascal (KPA)
+    Kilopascal,
+    /// Kilogram of potassium hydroxide (caustic potash) (KPH)
+    KilogramOfPotassiumHydroxideCausticPotash,
+    /// Kilogram of potassium oxide (KPO)
+    KilogramOfPotassiumOxide,
+    /// Kilogram of phosphorus pentoxide (phosphoric anhydride) (KPP)
+    KilogramOfPhosphorusPentoxidePhosphoricAnhydride,
+    /// Kiloroentgen (KR)
+    Kiloroentgen,
+    /// Kilogram of substance 90 % dry (KSD)
+    KilogramOfSubstance90PercentDry,
+    /// Kilogram of sodium hydroxide (caustic soda) (KSH)
+    KilogramOfSodiumHydroxideCausticSoda,
+    /// Kit (KT)
+    Kit,
+    /// Kilotonne (KTN)
+    Kilotonne,
+    /// Kilogram of uranium (KUR)
+    KilogramOfUranium,
+    /// Kilovolt - ampere (KVA)
+    KilovoltAmpere,
+    /// Kilovar (KVR)
+    Kilovar,
+    /// Kilovolt (KVT)
+    Kilovolt,
+    /// Kilogram per millimetre (KW)
+    KilogramPerMillimetre,
+    /// Kilowatt hour (KWH)
+    KilowattHour,
+    /// Kilowatt hour per normalized cubic metre (KWN)
+    KilowattHourPerNormalizedCubicMetre,
+    /// Kilogram of tungsten trioxide (KWO)
+    KilogramOfTungstenTrioxide,
+    /// Kilowatt hour per standard cubic metre (KWS)
+    KilowattHourPerStandardCubicMetre,
+    /// Kilowatt (KWT)
+    Kilowatt,
+    /// Kilowatt year (KWY)
+    KilowattYear,
+    /// Millilitre per kilogram (KX)
+    MillilitrePerKilogram,
+    /// Quart (US liquid) per minute (L10)
+    QuartUsLiquidPerMinute,
+    /// Quart (US liquid) per second (L11)
+    QuartUsLiquidPerSecond,
+    /// Metre per second kelvin (L12)
+    MetrePerSecondKelvin,
+    /// Metre per second bar (L13)
+    MetrePerSecondBar,
+    /// Square metre hour degree Celsius per kilocalorie (international table) (L14)
+    SquareMetreHourDegreeCelsiusPerKilocalorieInternationalTable,
+    /// Millipascal second per kelvin (L15)
+    MillipascalSecondPerKelvinL15,
+    /// Millipascal second per bar (L16)
+    MillipascalSecondPerBar,
+    /// Milligram per cubic metre kelvin (L17)
+    MilligramPerCubicMetreKelvin,
+    /// Milligram per cubic metre bar (L18)
+    MilligramPerCubicMetreBar,
+    /// Millilitre per litre (L19)
+    MillilitrePerLitre,
+    /// Litre per minute (L2)
+    LitrePerMinute,
+    /// Reciprocal cubic millimetre (L20)
+    ReciprocalCubicMillimetre,
+    /// Cubic millimetre per cubic metre (L21)
+    CubicMillimetrePerCubicMetre,
+    /// Mole per hour (L23)
+    MolePerHour,
+    /// Mole per kilogram kelvin (L24)
+    MolePerKilogramKelvin,
+    /// Mole per kilogram bar (L25)
+    MolePerKilogramBar,
+    /// Mole per litre kelvin (L26)
+    MolePerLitreKelvin,
+    /// Mole per litre bar (L27)
+    MolePerLitreBar,
+    /// Mole per cubic metre kelvin (L28)
+    MolePerCubicMetreKelvin,
+    /// Mole per cubic metre bar (L29)
+    MolePerCubicMetreBar,
+    /// Mole per minute (L30)
+    MolePerMinute,
+    /// Milliroentgen aequivalent men (L31)
+    MilliroentgenAequivalentMen,
+    /// Nanogram per kilogram (L32)
+    NanogramPerKilogram,
+    /// Ounce (avoirdupois) per day (L33)
+    OunceAvoirdupoisPerDay,
+    /// Ounce (avoirdupois) per hour (L34)
+    OunceAvoirdupoisPerHour,
+    /// Ounce (avoirdupois) per minute (L35)
+    OunceAvoirdupoisPerMinute,
+    /// Ounce (avoirdupois) per second (L36)
+    OunceAvoirdupoisPerSecond,
+    /// Ounce (avoirdupois) per gallon (UK) (L37)
+    OunceAvoirdupoisPerGallonUk,
+    /// Ounce (avoirdupois) per gallon (US) (L38)
+    OunceAvoirdupoisPerGallonUs,
+    /// Ounce (avoirdupois) per cubic inch (L39)
+    OunceAvoirdupoisPerCubicInch,
+    /// Ounce (avoirdupois)-force (L40)
+    OunceAvoirdupoisForce,
+    /// Ounce (avoirdupois)-force inch (L41)
+    OunceAvoirdupoisForceInch,
+    /// Picosiemens per metre (L42)
+    PicosiemensPerMetre,
+    /// Peck (UK) (L43)
+    PeckUk,
+    /// Peck (UK) per day (L44)
+    PeckUkPerDay,
+    /// Peck (UK) per hour (L45)
+    PeckUkPerHour,
+    /// Peck (UK) per minute (L46)
+    PeckUkPerMinute,
+    /// Peck (UK) per second (L47)
+    PeckUkPerSecond,
+    /// Peck (US dry) per day (L48)
+    PeckUsDryPerDay,
+    /// Peck (US dry) per hour (L49)
+    PeckUsDryPerHour,
+    /// Peck (US dry) per minute (L50)
+    PeckUsDryPerMinute,
+    /// Peck (US dry) per second (L51)
+    PeckUsDryPerSecond,
+    /// Psi per psi (L52)
+    PsiPerPsi,
+    /// Pint (UK) per day (L53)
+    PintUkPerDay,
+    /// Pint (UK) per hour (L54)
+    PintUkPerHour,
+    /// Pint (UK) per minute (L55)
+    PintUkPerMinute,
+    /// Pint (UK) per second (L56)
+    PintUkPerSecond,
+    /// Pint (US liquid) per day (L57)
+    PintUsLiquidPerDay,
+    /// Pint (US liquid) per hour (L58)
+    PintUsLiquidPerHour,
+    /// Pint (US liquid) per minute (L59)
+    PintUsLiquidPerMinute,
+    /// Pint (US liquid) per second (L60)
+    PintUsLiquidPerSecond,
+    /// Slug per day (L63)
+    SlugPerDay,
+    /// Slug per foot second (L64)
+    SlugPerFootSecond,
+    /// Slug per cubic foot (L65)
+    SlugPerCubicFoot,
+    /// Slug per hour (L66)
+    SlugPerHour,
+    /// Slug per minute (L67)
+    SlugPerMinute,
+    /// Slug per second (L68)
+    SlugPerSecond,
+    /// Tonne per kelvin (L69)
+    TonnePerKelvin,
+    /// Tonne per bar (L70)
+    TonnePerBar,
+    /// Tonne per day (L71)
+    TonnePerDay,
+    /// Tonne per day kelvin (L72)
+    TonnePerDayKelvin,
+    /// Tonne per day bar (L73)
+    TonnePerDayBar,
+    /// Tonne per hour kelvin (L74)
+    TonnePerHourKelvin,
+    /// Tonne per hour bar (L75)
+    TonnePerHourBar,
+    /// Tonne per cubic metre kelvin (L76)
+    TonnePerCubicMetreKelvin,
+    /// Tonne per cubic metre bar (L77)
+    TonnePerCubicMetreBar,
+    /// Tonne per minute (L78)
+    TonnePerMinute,
+    /// Tonne per minute kelvin (L79)
+    TonnePerMinuteKelvin,
+    /// Tonne per minute bar (L80)
+    TonnePerMinuteBar,
+    /// Tonne per second (L81)
+    TonnePerSecond,
+    /// Tonne per second kelvin (L82)
+    TonnePerSecondKelvin,
+    /// Tonne per second bar (L83)
+    TonnePerSecondBar,
+    /// Ton (UK shipping) (L84)
+    TonUkShipping,
+    /// Ton long per day (L85)
+    TonLongPerDay,
+    /// Ton (US shipping) (L86)
+    TonUsShipping,
+    /// Ton short per degree Fahrenheit (L87)
+    TonShortPerDegreeFahrenheit,
+    /// Ton short per day (L88)
+    TonShortPerDay,
+    /// Ton short per hour degree Fahrenheit (L89)
+    TonShortPerHourDegreeFahrenheit,
+    /// Ton short per hour psi (L90)
+    TonShortPerHourPsi,
+    /// Ton short per psi (L91)
+    TonShortPerPsi,
+    /// Ton (UK long) per cubic yard (L92)
+    TonUkLongPerCubicYard,
+    /// Ton (US short) per cubic yard (L93)
+    TonUsShortPerCubicYard,
+    /// Ton-force (US short) (L94)
+    TonForceUsShort,
+    /// Common year (L95)
+    CommonYear,
+    /// Sidereal year (L96)
+    SiderealYear,
+    /// Yard per degree Fahrenheit (L98)
+    YardPerDegreeFahrenheit,
+    /// Yard per psi (L99)
+    YardPerPsi,
+    /// Pound per cubic inch (LA)
+    PoundPerCubicInch,
+    /// Lactose excess percentage (LAC)
+    LactoseExcessPercentage,
+    /// Pound (LBR)
+    Pound,
+    /// Troy pound (US) (LBT)
+    TroyPoundUs,
+    /// Litre per day (LD)
+    LitrePerDay,
+    /// Leaf (LEF)
+    Leaf,
+    /// Linear foot (LF)
+    LinearFoot,
+    /// Labour hour (LH)
+    LabourHour,
+    /// Link (LK)
+    Link,
+    /// Linear metre (LM)
+    LinearMetre,
+    /// Length (LN)
+    Length,
+    /// Lot [unit of procurement] (LO)
+    LotUnitOfProcurement,
+    /// Liquid pound (LP)
+    LiquidPound,
+    /// Litre of pure alcohol (LPA)
+    LitreOfPureAlcohol,
+    /// Layer (LR)
+    Layer,
+    /// Lump sum (LS)
+    LumpSum,
+    /// Ton (UK) or long ton (US) (LTN)
+    TonUkOrLongTonUs,
+    /// Litre (LTR)
+    Litre,
+    /// Metric ton, lubricating oil (LUB)
+    MetricTonLubricatingOil,
+    /// Lumen (LUM)
+    Lumen,
+    /// Lux (LUX)
+    Lux,
+    /// Linear yard (LY)
+    LinearYard,
+    /// Milligram per litre (M1)
+    MilligramPerLitre,
+    /// Reciprocal cubic yard (M10)
+    ReciprocalCubicYard,
+    /// Cubic yard per degree Fahrenheit (M11)
+    CubicYardPerDegreeFahrenheit,
+    /// Cubic yard per day (M12)
+    CubicYardPerDay,
+    /// Cubic yard per hour (M13)
+    CubicYardPerHour,
+    /// Cubic yard per psi (M14)
+    CubicYardPerPsi,
+    /// Cubic yard per minute (M15)
+    CubicYardPerMinute,
+    /// Cubic yard per second (M16)
+    CubicYardPerSecond,
+    /// Kilohertz metre (M17)
+    KilohertzMetre,
+    /// Gigahertz metre (M18)
+    GigahertzMetre,
+    /// Beaufort (M19)
+    Beaufort,
+    /// Reciprocal megakelvin or megakelvin to the power minus one (M20)
+    ReciprocalMegakelvinOrMegakelvinToThePowerMinusOne,
+    /// Reciprocal kilovolt - ampere reciprocal hour (M21)
+    ReciprocalKilovoltAmpereReciprocalHour,
+    /// Millilitre per square centimetre minute (M22)
+    MillilitrePerSquareCentimetreMinute,
+    /// Newton per centimetre (M23)
+    NewtonPerCentimetre,
+    /// Ohm kilometre (M24)
+    OhmKilometre,
+    /// Percent per degree Celsius (M25)
+    PercentPerDegreeCelsius,
+    /// Gigaohm per metre (M26)
+    GigaohmPerMetre,
+    /// Megahertz metre (M27)
+    MegahertzMetre,
+    /// Kilogram per kilogram (M29)
+    KilogramPerKilogram,
+    /// Reciprocal volt - ampere reciprocal second (M30)
+    ReciprocalVoltAmpereReciprocalSecond,
+    /// Kilogram per kilometre (M31)
+    KilogramPerKilometre,
+    /// Pascal second per litre (M32)
+    PascalSecondPerLitre,
+    /// Millimole per litre (M33)
+    MillimolePerLitre,
+    /// Newton metre per square metre (M34)
+    NewtonMetrePerSquareMetre,
+    /// Millivolt - ampere (M35)
+    MillivoltAmpere,
+    /// 30-day month (M36)
+    ThirtyDayMonth,
+    /// Actual/360 (M37)
+    ActualPer360,
+    /// Kilometre per second squared (M38)
+    KilometrePerSecondSquared,
+    /// Centimetre per second squared (M39)
+    CentimetrePerSecondSquared,
+    /// Monetary value (M4)
+    MonetaryValue,
+    /// Yard per second squared (M40)
+    YardPerSecondSquared,
+    /// Millimetre per second squared (M41)
+    MillimetrePerSecondSquared,
+    /// Mile (statute mile) per second squared (M42)
+    MileStatuteMilePerSecondSquared,
+    /// Mil (M43)
+    Mil,
+    /// Revolution (M44)
+    Revolution,
+    /// Degree [unit of angle] per second squared (M45)
+    DegreeUnitOfAnglePerSecondSquared,
+    /// Revolution per minute (M46)
+    RevolutionPerMinute,
+    /// Circular mil (M47)
+    CircularMil,
+    /// Square mile (based on U.S. survey foot) (M48)
+    SquareMileBasedOnUSSurveyFoot,
+    /// Chain (based on U.S. survey foot) (M49)
+    ChainBasedOnUSSurveyFoot,
+    /// Microcurie (M5)
+    Microcurie,
+    /// Furlong (M50)
+    Furlong,
+    /// Foot (U.S. survey) (M51)
+    FootUSSurvey,
+    /// Mile (based on U.S. survey foot) (M52)
+    MileBasedOnUSSurveyFoot,
+    /// Metre per pascal (M53)
+    MetrePerPascal,
+    /// Metre per radiant (M55)
+    MetrePerRadiant,
+    /// Shake (M56)
+    Shake,
+    /// Mile per minute (M57)
+    MilePerMinute,
+    /// Mile per second (M58)
+    MilePerSecond,
+    /// Metre per second pascal (M59)
+    MetrePerSecondPascal,
+    /// Metre per hour (M60)
+    MetrePerHour,
+    /// Inch per year (M61)
+    InchPerYear,
+    /// Kilometre per second (M62)
+    KilometrePerSecond,
+    /// Inch per minute (M63)
+    InchPerMinute,
+    /// Yard per second (M64)
+    YardPerSecond,
+    /// Yard per minute (M65)
+    YardPerMinute,
+    /// Yard per hour (M66)
+    YardPerHour,
+    /// Acre-foot (based on U.S. survey foot) (M67)
+    AcreFootBasedOnUSSurveyFoot,
+    /// Cord (128 ft3) (M68)
+    Cord128Ft3,
+    /// Cubic mile (UK statute) (M69)
+    CubicMileUkStatute,
+    /// Micro-inch (M7)
+    MicroInch,
+    /// Ton, register (M70)
+    TonRegister,
+    /// Cubic metre per pascal (M71)
+    CubicMetrePerPascal,
+    /// Bel (M72)
+    Bel,
+    /// Kilogram per cubic metre pascal (M73)
+    KilogramPerCubicMetrePascal,
+    /// Kilogram per pascal (M74)
+    KilogramPerPascal,
+    /// Kilopound-force (M75)
+    KilopoundForce,
+    /// Poundal (M76)
+    Poundal,
+    /// Kilogram metre per second squared (M77)
+    KilogramMetrePerSecondSquared,
+    /// Pond (M78)
+    Pond,
+    /// Square foot per hour (M79)
+    SquareFootPerHour,
+    /// Stokes per pascal (M80)
+    StokesPerPascal,
+    /// Square centimetre per second (M81)
+    SquareCentimetrePerSecond,
+    /// Square metre per second pascal (M82)
+    SquareMetrePerSecondPascal,
+    /// Denier (textile) (M83)
+    DenierTextile,
+    /// Pound per yard (M84)
+    PoundPerYard,
+    /// Ton, assay (M85)
+    TonAssay,
+    /// Pfund (M86)
+    Pfund,
+    /// Kilogram per second pascal (M87)
+    KilogramPerSecondPascal,
+    /// Tonne per month (M88)
+    TonnePerMonth,
+    /// Tonne per year (M89)
+    TonnePerYear,
+    /// Million Btu per 1000 cubic foot (M9)
+    MillionBtuPer1000CubicFoot,
+    /// Kilopound per hour (M90)
+    KilopoundPerHour,
+    /// Pound per day (M91)
+    PoundPerDay,
+    /// Pound-force per foot (textile) (M92)
+    PoundForcePerFootTextile,
+    /// Newton metre per radian (M93)
+    NewtonMetrePerRadian,
+    /// Kilogram metre (M94)
+    KilogramMetre,
+    /// Poundal foot (M95)
+    PoundalFoot,
+    /// Poundal inch (M96)
+    PoundalInch,
+    /// Dyne metre (M97)
+    DyneMetre,
+    /// Kilogram centimetre per second (M98)
+    KilogramCentimetrePerSecond,
+    /// Gram centimetre per second (M99)
+    GramCentimetrePerSecond,
+    /// Megavolt ampere reactive hour (MAH)
+    MegavoltAmpereReactiveHour,
+    /// Megalitre (MAL)
+    Megalitre,
+    /// Megametre (MAM)
+    Megametre,
+    /// Megavar (MAR)
+    Megavar,
+    /// Megawatt (MAW)
+    Megawatt,
+    /// Thousand standard brick equivalent (MBE)
+    ThousandStandardBrickEquivalent,
+    /// Thousand board foot (MBF)
+    ThousandBoardFoot,
+    /// Millibar (MBR)
+    Millibar,
+    /// Microgram (MC)
+    Microgram,
+    /// Millicurie (MCU)
+    Millicurie,
+    /// Air dry metric ton (MD)
+    AirDryMetricTon,
+    /// Milligram (MGM)
+    Milligram,
+    /// Megahertz (MHZ)
+    Megahertz,
+    /// Square mile (statute mile) (MIK)
+    SquareMileStatuteMile,
+    /// Thousand (MIL)
+    Thousand,
+    /// Minute [unit of time] (MIN)
+    MinuteUnitOfTime,
+    /// Million (MIO)
+    Million,
+    /// Million international unit (MIU)
+    MillionInternationalUnit,
+    /// Square metre day (MKD)
+    SquareMetreDay,
+    /// Square metre month (MKM)
+    SquareMetreMonth,
+    /// Square metre week (MKW)
+    SquareMetreWeek,
+    /// Milliard (MLD)
+    Milliard,
+    /// Millilitre (MLT)
+    Millilitre,
+    /// Square millimetre (MMK)
+    SquareMillimetre,
+    /// Cubic millimetre (MMQ)
+    CubicMillimetre,
+    /// Millimetre (MMT)
+    Millimetre,
+    /// Kilogram, dry weight (MND)
+    KilogramDryWeight,
+    /// Mega joule per normalised cubic metre (MNJ)
+    MegaJoulePerNormalisedCubicMetre,
+    /// Month (MON)
+    Month,
+    /// Megapascal (MPA)
+    Megapascal,
+    /// Cubic metre day (MQD)
+    CubicMetreDay,
+    /// Cubic metre per hour (MQH)
+    CubicMetrePerHour,
+    /// Cubic metre month (MQM)
+    CubicMetreMonth,
+    /// Cubic metre per second (MQS)
+    CubicMetrePerSecond,
+    /// Cubic metre week (MQW)
+    CubicMetreWeek,
+    /// Metre day (MRD)
+    MetreDay,
+    /// Metre month (MRM)
+    MetreMonth,
+    /// Metre week (MRW)
+    MetreWeek,
+    /// Metre per second squared (MSK)
+    MetrePerSecondSquared,
+    /// Square metre (MTK)
+    SquareMetre,
+    /// Cubic metre (MTQ)
+    CubicMetre,
+    /// Metre (MTR)
+    Metre,
+    /// Metre per second (MTS)
+    MetrePerSecond,
+    /// Millihertz (MTZ)
+    Millihertz,
+    /// Megavolt - ampere (MVA)
+    MegavoltAmpere,
+    /// Megawatt hour (1000 kW.h) (MWH)
+    MegawattHour1000KwH,
+    /// Pen calorie (N1)
+    PenCalorie,
+    /// Pound foot per second (N10)
+    PoundFootPerSecond,
+    /// Pound inch per second (N11)
+    PoundInchPerSecond,
+    /// Pferdestaerke (N12)
+    Pferdestaerke,
+    /// Centimetre of mercury (0 degC) (N13)
+    CentimetreOfMercury0Degc,
+    /// Centimetre of water (4 degC) (N14)
+    CentimetreOfWater4Degc,
+    /// Foot of water (39.2 degF) (N15)
+    FootOfWater392Degf,
+    /// Inch of mercury (32 degF) (N16)
+    InchOfMercury32Degf,
+    /// Inch of mercury (60 degF) (N17)
+    InchOfMercury60Degf,
+    /// Inch of water (39.2 degF) (N18)
+    InchOfWater392Degf,
+    /// Inch of water (60 degF) (N19)
+    InchOfWater60Degf,
+    /// Kip per square inch (N20)
+    KipPerSquareInch,
+    /// Poundal per square foot (N21)
+    PoundalPerSquareFoot,
+    /// Ounce (avoirdupois) per square inch (N22)
+    OunceAvoirdupoisPerSquareInch,
+    /// Conventional metre of water (N23)
+    ConventionalMetreOfWater,
+    /// Gram per square millimetre (N24)
+    GramPerSquareMillimetre,
+    /// Pound per square yard (N25)
+    PoundPerSquareYard,
+    /// Poundal per square inch (N26)
+    PoundalPerSquareInch,
+    /// Foot to the fourth power (N27)
+    FootToTheFourthPower,
+    /// Cubic decimetre per kilogram (N28)
+    CubicDecimetrePerKilogram,
+    /// Cubic foot per pound (N29)
+    CubicFootPerPound,
+    /// Print point (N3)
+    PrintPoint,
+    /// Cubic inch per pound (N30)
+    CubicInchPerPound,
+    /// Kilonewton per metre (N31)
+    KilonewtonPerMetre,
+    /// Poundal per inch (N32)
+    PoundalPerInch,
+    /// Pound-force per yard (N33)
+    PoundForcePerYard,
+    /// Poundal second per square foot (N34)
+    PoundalSecondPerSquareFoot,
+    /// Poise per pascal (N35)
+    PoisePerPascal,
+    /// Newton second per square metre (N36)
+    NewtonSecondPerSquareMetre,
+    /// Kilogram per metre second (N37)
+    KilogramPerMetreSecond,
+    /// Kilogram per metre minute (N38)
+    KilogramPerMetreMinute,
+    /// Kilogram per metre day (N39)
+    KilogramPerMetreDay,
+    /// Kilogram per metre hour (N40)
+    KilogramPerMetreHour,
+    /// Gram per centimetre second (N41)
+    GramPerCentimetreSecond,
+    /// Poundal second per square inch (N42)
+    PoundalSecondPerSquareInch,
+    /// Pound per foot minute (N43)
+    PoundPerFootMinute,
+    /// Pound per foot day (N44)
+    PoundPerFootDay,
+    /// Cubic metre per second pascal (N45)
+    CubicMetrePerSecondPascal,
+    /// Foot poundal (N46)
+    FootPoundal,
+    /// Inch poundal (N47)
+    InchPoundal,
+    /// Watt per square centimetre (N48)
+    WattPerSquareCentimetre,
+    /// Watt per square inch (N49)
+    WattPerSquareInch,
+    /// British thermal unit (international table) per square foot hour (N50)
+    BritishThermalUnitInternationalTablePerSquareFootHour,
+    /// British thermal unit (thermochemical) per square foot hour (N51)
+    BritishThermalUnitThermochemicalPerSquareFootHour,
+    /// British thermal unit (thermochemical) per square foot minute (N52)
+    BritishThermalUnitThermochemicalPerSquareFootMinute,
+    /// British thermal unit (international table) per square foot second (N53)
+    BritishThermalUnitInternationalTablePerSquareFootSecond,
+    /// British thermal unit (thermochemical) per square foot second (N54)
+    BritishThermalUnitThermochemicalPerSquareFootSecond,
+    /// British thermal unit (international table) per square inch second (N55)
+    BritishThermalUnitInternationalTablePerSquareInchSecond,
+    /// Calorie (thermochemical) per square centimetre minute (N56)
+    CalorieThermochemicalPerSquareCentimetreMinute,
+    /// Calorie (thermochemical) per square centimetre second (N57)
+    CalorieThermochemicalPerSquareCentimetreSecond,
+    /// British thermal unit (international table) per cubic foot (N58)
+    BritishThermalUnitInternationalTablePerCubicFoot,
+    /// British thermal unit (thermochemical) per cubic foot (N59)
+    BritishThermalUnitThermochemicalPerCubicFoot,
+    /// British thermal unit (international table) per degree Fahrenheit (N60)
+    BritishThermalUnitInternationalTablePerDegreeFahrenheit,
+    /// British thermal unit (thermochemical) per degree Fahrenheit (N61)
+    BritishThermalUnitThermochemicalPerDegreeFahrenheit,
+    /// British thermal unit (international table) per degree Rankine (N62)
+    BritishThermalUnitInternationalTablePerDegreeRankine,
+    /// British thermal unit (thermochemical) per degree Rankine (N63)
+    BritishThermalUnitThermochemicalPerDegreeRankine,
+    /// British thermal unit (thermochemical) per pound degree Rankine (N64)
+    BritishThermalUnitThermochemicalPerPoundDegreeRankine,
+    /// Kilocalorie (international table) per gram kelvin (N65)
+    KilocalorieInternationalTablePerGramKelvin,
+    /// British thermal unit (39 degF) (N66)
+    BritishThermalUnit39Degf,
+    /// British thermal unit (59 degF) (N67)
+    BritishThermalUnit59Degf,
+    /// British thermal unit (60 degF) (N68)
+    BritishThermalUnit60Degf,
+    /// Calorie (20 degC) (N69)
+    Calorie20Degc,
+    /// Quad (1015 BtuIT) (N70)
+    Quad1015Btuit,
+    /// Therm (EC) (N71)
+    ThermEc,
+    /// Therm (U.S.) (N72)
+    ThermUS,
+    /// British thermal unit (thermochemical) per pound (N73)
+    BritishThermalUnitThermochemicalPerPound,
+    /// British thermal unit (international table) per hour square foot degree Fahrenheit (N74)
+    BritishThermalUnitInternationalTablePerHourSquareFootDegreeFahrenheit,
+    /// British thermal unit (thermochemical) per hour square foot degree Fahrenheit (N75)
+    BritishThermalUnitThermochemicalPerHourSquareFootDegreeFahrenheit,
+    /// British thermal unit (international table) per second square foot degree Fahrenheit (N76)
+    BritishThermalUnitInternationalTablePerSecondSquareFootDegreeFahrenheit,
+    /// British thermal unit (thermochemical) per second square foot degree Fahrenheit (N77)
+    BritishThermalUnitThermochemicalPerSecondSquareFootDegreeFahrenheit,
+    /// Kilowatt per square metre kelvin (N78)
+    KilowattPerSquareMetreKelvin,
+    /// Kelvin per pascal (N79)
+    KelvinPerPascal,
+    /// Watt per metre degree Celsius (N80)
+    WattPerMetreDegreeCelsius,
+    /// Kilowatt per metre kelvin (N81)
+    KilowattPerMetreKelvin,
+    /// Kilowatt per metre degree Celsius (N82)
+    KilowattPerMetreDegreeCelsius,
+    /// Metre per degree Celcius metre (N83)
+    MetrePerDegreeCelciusMetre,
+    /// Degree Fahrenheit hour per British thermal unit (international table) (N84)
+    DegreeFahrenheitHourPerBritishThermalUnitInternationalTable,
+    /// Degree Fahrenheit hour per British thermal unit (thermochemical) (N85)
+    DegreeFahrenheitHourPerBritishThermalUnitThermochemical,
+    /// Degree Fahrenheit second per British thermal unit (international table) (N86)
+    DegreeFahrenheitSecondPerBritishThermalUnitInternationalTable,
+    /// Degree Fahrenheit second per British thermal unit (thermochemical) (N87)
+    DegreeFahrenheitSecondPerBritishThermalUnitThermochemical,
+    /// Degree Fahrenheit hour square foot per British thermal unit (international table) inch (N88)
+    DegreeFahrenheitHourSquareFootPerBritishThermalUnitInternationalTableInch,
+    /// Degree Fahrenheit hour square foot per British thermal unit (thermochemical) inch (N89)
+    DegreeFahrenheitHourSquareFootPerBritishThermalUnitThermochemicalInch,
+    /// Kilofarad (N90)
+    Kilofarad,
+    /// Reciprocal joule (N91)
+    ReciprocalJoule,
+    /// Picosiemens (N92)
+    Picosiemens,
+    /// Ampere per pascal (N93)
+    AmperePerPascal,
+    /// Franklin (N94)
+    Franklin,
+    /// Ampere minute (N95)
+    AmpereMinute,
+    /// Biot (N96)
+    Biot,
+    /// Gilbert (N97)
+    Gilbert,
+    /// Volt per pascal (N98)
+    VoltPerPascal,
+    /// Picovolt (N99)
+    Picovolt,
+    /// Milligram per kilogram (NA)
+    MilligramPerKilogram,
+    /// Number of articles (NAR)
+    NumberOfArticles,
+    /// Number of cells (NCL)
+    NumberOfCells,
+    /// Newton (NEW)
+    Newton,
+    /// Message (NF)
+    Message,
+    /// Nil (NIL)
+    Nil,
+    /// Number of international units (NIU)
+    NumberOfInternationalUnits,
+    /// Load (NL)
+    Load,
+    /// Normalised cubic metre (NM3)
+    NormalisedCubicMetre,
+    /// Nautical mile (NMI)
+    NauticalMile,
+    /// Number of packs (NMP)
+    NumberOfPacks,
+    /// Number of parts (NPT)
+    NumberOfParts,
+    /// Net ton (NT)
+    NetTon,
+    /// Nephelometric turbidity unit (NTU)
+    NephelometricTurbidityUnit,
+    /// Newton metre (NU)
+    NewtonMetre,
+    /// Part per thousand (NX)
+    PartPerThousand,
+    /// Panel (OA)
+    Panel,
+    /// Ozone depletion equivalent (ODE)
+    OzoneDepletionEquivalent,
+    /// ODS grams (ODG)
+    OdsGrams,
+    /// ODS kilograms (ODK)
+    OdsKilograms,
+    /// ODS milligrams (ODM)
+    OdsMilligrams,
+    /// Ohm (OHM)
+    Ohm,
+    /// Ounce per square yard (ON)
+    OuncePerSquareYard,
+    /// Ounce (avoirdupois) (ONZ)
+    OunceAvoirdupois,
+    /// Oscillations per minute (OPM)
+    OscillationsPerMinute,
+    /// Overtime hour (OT)
+    OvertimeHour,
+    /// Fluid ounce (US) (OZA)
+    FluidOunceUs,
+    /// Fluid ounce (UK) (OZI)
+    FluidOunceUk,
+    /// Percent (P1)
+    Percent,
+    /// Coulomb per metre (P10)
+    CoulombPerMetre,
+    /// Kiloweber (P11)
+    Kiloweber,
+    /// Gamma (P12)
+    Gamma,
+    /// Kilotesla (P13)
+    Kilotesla,
+    /// Joule per second (P14)
+    JoulePerSecond,
+    /// Joule per minute (P15)
+    JoulePerMinute,
+    /// Joule per hour (P16)
+    JoulePerHour,
+    /// Joule per day (P17)
+    JoulePerDay,
+    /// Kilojoule per second (P18)
+    KilojoulePerSecond,
+    /// Kilojoule per minute (P19)
+    KilojoulePerMinute,
+    /// Pound per foot (P2)
+    PoundPerFoot,
+    /// Kilojoule per hour (P20)
+    KilojoulePerHour,
+    /// Kilojoule per day (P21)
+    KilojoulePerDay,
+    /// Nanoohm (P22)
+    Nanoohm,
+    /// Ohm circular-mil per foot (P23)
+    OhmCircularMilPerFoot,
+    /// Kilohenry (P24)
+    Kilohenry,
+    /// Lumen per square foot (P25)
+    LumenPerSquareFoot,
+    /// Phot (P26)
+    Phot,
+    /// Footcandle (P27)
+    Footcandle,
+    /// Candela per square inch (P28)
+    CandelaPerSquareInch,
+    /// Footlambert (P29)
+    Footlambert,
+    /// Lambert (P30)
+    Lambert,
+    /// Stilb (P31)
+    Stilb,
+    /// Candela per square foot (P32)
+    CandelaPerSquareFoot,
+    /// Kilocandela (P33)
+    Kilocandela,
+    /// Millicandela (P34)
+    Millicandela,
+    /// Hefner-Kerze (P35)
+    HefnerKerze,
+    /// International candle (P36)
+    InternationalCandle,
+    /// British thermal unit (international table) per square foot (P37)
+    BritishThermalUnitInternationalTablePerSquareFoot,
+    /// British thermal unit (thermochemical) per square foot (P38)
+    BritishThermalUnitThermochemicalPerSquareFoot,
+    /// Calorie (thermochemical) per square centimetre (P39)
+    CalorieThermochemicalPerSquareCentimetre,
+    /// Langley (P40)
+    Langley,
+    /// Decade (logarithmic) (P41)
+    DecadeLogarithmic,
+    /// Pascal squared second (P42)
+    PascalSquaredSecond,
+    /// Bel per metre (P43)
+    BelPerMetre,
+    /// Pound mole (P44)
+    PoundMole,
+    /// Pound mole per second (P45)
+    PoundMolePerSecond,
+    /// Pound mole per minute (P46)
+    PoundMolePerMinute,
+    /// Kilomole per kilogram (P47)
+    KilomolePerKilogram,
+    /// Pound mole per pound (P48)
+    PoundMolePerPound,
+    /// Newton square metre per ampere (P49)
+    NewtonSquareMetrePerAmpere,
+    /// Five pack (P5)
+    FivePack,
+    /// Weber metre (P50)
+    WeberMetre,
+    /// Mol per kilogram pascal (P51)
+    MolPerKilogramPascal,
+    /// Mol per cubic metre pascal (P52)
+    MolPerCubicMetrePascal,
+    /// Unit pole (P53)
+    UnitPole,
+    /// Milligray per second (P54)
+    MilligrayPerSecond,
+    /// Microgray per second (P55)
+    MicrograyPerSecond,
+    /// Nanogray per second (P56)
+    NanograyPerSecond,
+    /// Gray per minute (P57)
+    GrayPerMinute,
+    /// Milligray per minute (P58)
+    MilligrayPerMinute,
+    /// Microgray per minute (P59)
+    MicrograyPerMinute,
+    /// Nanogray per minute (P60)
+    NanograyPerMinute,
+    /// Gray per hour (P61)
+    GrayPerHour,
+    /// Milligray per hour (P62)
+    MilligrayPerHour,
+    /// Microgray per hour (P63)
+    MicrograyPerHour,
+    /// Nanogray per hour (P64)
+    NanograyPerHour,
+    /// Sievert per second (P65)
+    SievertPerSecond,
+    /// Millisievert per second (P66)
+    MillisievertPerSecond,
+    /// Microsievert per second (P67)
+    MicrosievertPerSecond,
+    /// Nanosievert per second (P68)
+    NanosievertPerSecond,
+    /// Rem per second (P69)
+    RemPerSecond,
+    /// Sievert per hour (P70)
+    SievertPerHour,
+    /// Millisievert per hour (P71)
+    MillisievertPerHour,
+    /// Microsievert per hour (P72)
+    MicrosievertPerHour,
+    /// Nanosievert per hour (P73)
+    NanosievertPerHour,
+    /// Sievert per minute (P74)
+    SievertPerMinute,
+    /// Millisievert per minute (P75)
+    MillisievertPerMinute,
+    /// Microsievert per minute (P76)
+    MicrosievertPerMinute,
+    /// Nanosievert per minute (P77)
+    NanosievertPerMinute,
+    /// Reciprocal square inch (P78)
+    ReciprocalSquareInch,
+    /// Pascal square metre per kilogram (P79)
+    PascalSquareMetrePerKilogram,
+    /// Millipascal per metre (P80)
+    MillipascalPerMetre,
+    /// Kilopascal per metre (P81)
+    KilopascalPerMetre,
+    /// Hectopascal per metre (P82)
+    HectopascalPerMetre,
+    /// Standard atmosphere per metre (P83)
+    StandardAtmospherePerMetre,
+    /// Technical atmosphere per metre (P84)
+    TechnicalAtmospherePerMetre,
+    /// Torr per metre (P85)
+    TorrPerMetre,
+    /// Psi per inch (P86)
+    PsiPerInch,
+    /// Cubic metre per second square metre (P87)
+    CubicMetrePerSecondSquareMetre,
+    /// Rhe (P88)
+    Rhe,
+    /// Pound-force foot per inch (P89)
+    PoundForceFootPerInch,
+    /// Pound-force inch per inch (P90)
+    PoundForceInchPerInch,
+    /// Perm (0 degC) (P91)
+    Perm0Degc,
+    /// Perm (23 degC) (P92)
+    Perm23Degc,
+    /// Byte per second (P93)
+    BytePerSecond,
+    /// Kilobyte per second (P94)
+    KilobytePerSecond,
+    /// Megabyte per second (P95)
+    MegabytePerSecond,
+    /// Reciprocal volt (P96)
+    ReciprocalVolt,
+    /// Reciprocal radian (P97)
+    ReciprocalRadian,
+    /// Pascal to the power sum of stoichiometric numbers (P98)
+    PascalToThePowerSumOfStoichiometricNumbers,
+    /// Mole per cubic metre to the power sum of stoichiometric numbers (P99)
+    MolePerCubicMetreToThePowerSumOfStoichiometricNumbers,
+    /// Pascal (PAL)
+    Pascal,
+    /// Pad (PD)
+    Pad,
+    /// Proof litre (PFL)
+    ProofLitre,
+    /// Proof gallon (PGL)
+    ProofGallon,
+    /// Pitch (PI)
+    Pitch,
+    /// Degree Plato (PLA)
+    DegreePlato,
+    /// Pound per inch of length (PO)
+    PoundPerInchOfLength,
+    /// Page per inch (PQ)
+    PagePerInch,
+    /// Pair (PR)
+    Pair,
+    /// Pound-force per square inch (PS)
+    PoundForcePerSquareInch,
+    /// Dry pint (US) (PTD)
+    DryPintUs,
+    /// Pint (UK) (PTI)
+    PintUk,
+    /// Liquid pint (US) (PTL)
+    LiquidPintUs,
+    /// Portion (PTN)
+    Portion,
+    /// Joule per tesla (Q10)
+    JoulePerTesla,
+    /// Erlang (Q11)
+    Erlang,
+    /// Octet (Q12)
+    Octet,
+    /// Octet per second (Q13)
+    OctetPerSecond,
+    /// Shannon (Q14)
+    Shannon,
+    /// Hartley (Q15)
+    Hartley,
+    /// Natural unit of information (Q16)
+    NaturalUnitOfInformation,
+    /// Shannon per second (Q17)
+    ShannonPerSecond,
+    /// Hartley per second (Q18)
+    HartleyPerSecond,
+    /// Natural unit of information per second (Q19)
+    NaturalUnitOfInformationPerSecond,
+    /// Second per kilogramm (Q20)
+    SecondPerKilogramm,
+    /// Watt square metre (Q21)
+    WattSquareMetre,
+    /// Second per radian cubic metre (Q22)
+    SecondPerRadianCubicMetre,
+    /// Weber to the power minus one (Q23)
+    WeberToThePowerMinusOne,
+    /// Reciprocal inch (Q24)
+    ReciprocalInch,
+    /// Dioptre (Q25)
+    Dioptre,
+    /// One per one (Q26)
+    OnePerOne,
+    /// Newton metre per metre (Q27)
+    NewtonMetrePerMetre,
+    /// Kilogram per square metre pascal second (Q28)
+    KilogramPerSquareMetrePascalSecond,
+    /// Microgram per hectogram (Q29)
+    MicrogramPerHectogram,
+    /// Meal (Q3)
+    Meal,
+    /// PH (potential of Hydrogen) (Q30)
+    PhPotentialOfHydrogen,
+    /// Kilojoule per gram (Q31)
+    KilojoulePerGram,
+    /// Femtolitre (Q32)
+    Femtolitre,
+    /// Picolitre (Q33)
+    Picolitre,
+    /// Nanolitre (Q34)
+    Nanolitre,
+    /// Megawatts per minute (Q35)
+    MegawattsPerMinute,
+    /// Square metre per cubic metre (Q36)
+    SquareMetrePerCubicMetre,
+    /// Standard cubic metre per day (Q37)
+    StandardCubicMetrePerDay,
+    /// Standard cubic metre per hour (Q38)
+    StandardCubicMetrePerHour,
+    /// Normalized cubic metre per day (Q39)
+    NormalizedCubicMetrePerDay,
+    /// Normalized cubic metre per hour (Q40)
+    NormalizedCubicMetrePerHour,
+    /// Joule per normalised cubic metre (Q41)
+    JoulePerNormalisedCubicMetre,
+    /// Joule per standard cubic metre (Q42)
+    JoulePerStandardCubicMetre,
+    /// Page - facsimile (QA)
+    PageFacsimile,
+    /// Quarter (of a year) (QAN)
+    QuarterOfAYear,
+    /// Page - hardcopy (QB)
+    PageHardcopy,
+    /// Quire (QR)
+    Quire,
+    /// Dry quart (US) (QTD)
+    DryQuartUs,
+    /// Quart (UK) (QTI)
+    QuartUk,
+    /// Liquid quart (US) (QTL)
+    LiquidQuartUs,
+    /// Quarter (UK) (QTR)
+    QuarterUk,
+    /// Pica (R1)
+    Pica,
+    /// Thousand cubic metre (R9)
+    ThousandCubicMetre,
+    /// Running or operating hour (RH)
+    RunningOrOperatingHour,
+    /// Ream (RM)
+    Ream,
+    /// Room (ROM)
+    Room,
+    /// Pound per ream (RP)
+    PoundPerReam,
+    /// Revolutions per minute (RPM)
+    RevolutionsPerMinute,
+    /// Revolutions per second (RPS)
+    RevolutionsPerSecond,
+    /// Revenue ton mile (RT)
+    RevenueTonMile,
+    /// Square foot per second (S3)
+    SquareFootPerSecond,
+    /// Square metre per second (S4)
+    SquareMetrePerSecond,
+    /// Half year (6 months) (SAN)
+    HalfYear6Months,
+    /// Score (SCO)
+    Score,
+    /// Scruple (SCR)
+    Scruple,
+    /// Second [unit of time] (SEC)
+    SecondUnitOfTime,
+    /// Set (SET)
+    Set,
+    /// Segment (SG)
+    Segment,
+    /// Siemens (SIE)
+    Siemens,
+    /// Standard cubic metre (SM3)
+    StandardCubicMetre,
+    /// Mile (statute mile) (SMI)
+    MileStatuteMile,
+    /// Square (SQ)
+    Square,
+    /// Square, roofing (SQR)
+    SquareRoofing,
+    /// Strip (SR)
+    Strip,
+    /// Stick (STC)
+    Stick,
+    /// Stone (UK) (STI)
+    StoneUk,
+    /// Stick, cigarette (STK)
+    StickCigarette,
+    /// Standard litre (STL)
+    StandardLitre,
+    /// Ton (US) or short ton (UK/US) (STN)
+    TonUsOrShortTonUkPerUs,
+    /// Straw (STW)
+    Straw,
+    /// Skein (SW)
+    Skein,
+    /// Shipment (SX)
+    Shipment,
+    /// Syringe (SYR)
+    Syringe,
+    /// Telecommunication line in service (T0)
+    TelecommunicationLineInService,
+    /// Thousand piece (T3)
+    ThousandPiece,
+    /// Kiloampere hour (thousand ampere hour) (TAH)
+    KiloampereHourThousandAmpereHour,
+    /// Total acid number (TAN)
+    TotalAcidNumber,
+    /// Thousand square inch (TI)
+    ThousandSquareInch,
+    /// Metric ton, including container (TIC)
+    MetricTonIncludingContainer,
+    /// Metric ton, including inner packaging (TIP)
+    MetricTonIncludingInnerPackaging,
+    /// Tonne kilometre (TKM)
+    TonneKilometre,
+    /// Kilogram of imported meat, less offal (TMS)
+    KilogramOfImportedMeatLessOffal,
+    /// Tonne (metric ton) (TNE)
+    TonneMetricTon,
+    /// Ten pack (TP)
+    TenPack,
+    /// Teeth per inch (TPI)
+    TeethPerInch,
+    /// Ten pair (TPR)
+    TenPair,
+    /// Thousand cubic metre per day (TQD)
+    ThousandCubicMetrePerDay,
+    /// Trillion (EUR) (TRL)
+    TrillionEur,
+    /// Ten set (TST)
+    TenSet,
+    /// Ten thousand sticks (TTS)
+    TenThousandSticks,
+    /// Treatment (U1)
+    Treatment,
+    /// Tablet (U2)
+    Tablet,
+    /// Telecommunication line in service average (UB)
+    TelecommunicationLineInServiceAverage,
+    /// Telecommunication port (UC)
+    TelecommunicationPort,
+    /// Volt - ampere per kilogram (VA)
+    VoltAmperePerKilogram,
+    /// Volt (VLT)
+    Volt,
+    /// Percent volume (VP)
+    PercentVolume,
+    /// Wet kilo (W2)
+    WetKilo,
+    /// Watt per kilogram (WA)
+    WattPerKilogram,
+    /// Wet pound (WB)
+    WetPound,
+    /// Cord (WCD)
+    Cord,
+    /// Wet ton (WE)
+    WetTon,
+    /// Weber (WEB)
+    Weber,
+    /// Week (WEE)
+    Week,
+    /// Wine gallon (WG)
+    WineGallon,
+    /// Watt hour (WHR)
+    WattHour,
+    /// Working month (WM)
+    WorkingMonth,
+    /// Standard (WSD)
+    Standard,
+    /// Watt (WTT)
+    Watt,
+    /// Gunter's chain (X1)
+    GuntersChain,
+    /// Square yard (YDK)
+    SquareYard,
+    /// Cubic yard (YDQ)
+    CubicYard,
+    /// Yard (YRD)
+    Yard,
+    /// Hanging container (Z11)
+    HangingContainer,
+    /// Nanomole (Z9)
+    Nanomole,
+    /// Page (ZP)
+    Page,
+    /// Mutually defined (ZZ)
+    MutuallyDefined,
+    /// Drum, steel (X1A)
+    DrumSteel,
+    /// Drum, aluminium (X1B)
+    DrumAluminium,
+    /// Drum, plywood (X1D)
+    DrumPlywood,
+    /// Container, flexible (X1F)
+    ContainerFlexible,
+    /// Drum, fibre (X1G)
+    DrumFibre,
+    /// Drum, wooden (X1W)
+    DrumWooden,
+    /// Barrel, wooden (X2C)
+    BarrelWooden,
+    /// Jerrican, steel (X3A)
+    JerricanSteel,
+    /// Jerrican, plastic (X3H)
+    JerricanPlastic,
+    /// Bag, super bulk (X43)
+    BagSuperBulk,
+    /// Bag, polybag (X44)
+    BagPolybag,
+    /// Box, steel (X4A)
+    BoxSteel,
+    /// Box, aluminium (X4B)
+    BoxAluminium,
+    /// Box, natural wood (X4C)
+    BoxNaturalWood,
+    /// Box, plywood (X4D)
+    BoxPlywood,
+    /// Box, reconstituted wood (X4F)
+    BoxReconstitutedWood,
+    /// Box, fibreboard (X4G)
+    BoxFibreboard,
+    /// Box, plastic (X4H)
+    BoxPlastic,
+    /// Bag, woven plastic (X5H)
+    BagWovenPlastic,
+    /// Bag, textile (X5L)
+    BagTextile,
+    /// Bag, paper (X5M)
+    BagPaper,
+    /// Composite packaging, plastic receptacle (X6H)
+    CompositePackagingPlasticReceptacle,
+    /// Composite packaging, glass receptacle (X6P)
+    CompositePackagingGlassReceptacle,
+    /// Case, car (X7A)
+    CaseCar,
+    /// Case, wooden (X7B)
+    CaseWooden,
+    /// Pallet, wooden (X8A)
+    PalletWooden,
+    /// Crate, wooden (X8B)
+    CrateWooden,
+    /// Bundle, wooden (X8C)
+    BundleWooden,
+    /// Intermediate bulk container, rigid plastic (XAA)
+    IntermediateBulkContainerRigidPlastic,
+    /// Receptacle, fibre (XAB)
+    ReceptacleFibre,
+    /// Receptacle, paper (XAC)
+    ReceptaclePaper,
+    /// Receptacle, wooden (XAD)
+    ReceptacleWooden,
+    /// Aerosol (XAE)
+    Aerosol,
+    /// Pallet, modular, collars 80cms * 60cms (XAF)
+    PalletModularCollars80cms60cms,
+    /// Pallet, shrinkwrapped (XAG)
+    PalletShrinkwrapped,
+    /// Pallet, 100cms * 110cms (XAH)
+    Pallet100cms110cms,
+    /// Clamshell (XAI)
+    Clamshell,
+    /// Cone (XAJ)
+    Cone,
+    /// Ball (XAL)
+    PackageBall,
+    /// Ampoule, non-protected (XAM)
+    AmpouleNonProtected,
+    /// Ampoule, protected (XAP)
+    AmpouleProtected,
+    /// Atomizer (XAT)
+    Atomizer,
+    /// Capsule (XAV)
+    Capsule,
+    /// Belt (XB4)
+    Belt,
+    /// Barrel (XBA)
+    Barrel,
+    /// Bobbin (XBB)
+    Bobbin,
+    /// Bottlecrate / bottlerack (XBC)
+    BottlecratePerBottlerack,
+    /// Board (XBD)
+    Board,
+    /// Bundle (XBE)
+    Bundle,
+    /// Balloon, non-protected (XBF)
+    BalloonNonProtected,
+    /// Bag (XBG)
+    Bag,
+    /// Bunch (XBH)
+    Bunch,
+    /// Bin (XBI)
+    Bin,
+    /// Bucket (XBJ)
+    Bucket,
+    /// Basket (XBK)
+    Basket,
+    /// Bale, compressed (XBL)
+    BaleCompressed,
+    /// Basin (XBM)
+    Basin,
+    /// Bale, non-compressed (XBN)
+    BaleNonCompressed,
+    /// Bottle, non-protected, cylindrical (XBO)
+    BottleNonProtectedCylindrical,
+    /// Balloon, protected (XBP)
+    BalloonProtected,
+    /// Bottle, protected cylindrical (XBQ)
+    BottleProtectedCylindrical,
+    /// Bar (XBR)
+    Bar,
+    /// Bottle, non-protected, bulbous (XBS)
+    BottleNonProtectedBulbous,
+    /// Bolt (XBT)
+    Bolt,
+    /// Butt (XBU)
+    Butt,
+    /// Bottle, protected bulbous (XBV)
+    BottleProtectedBulbous,
+    /// Box, for liquids (XBW)
+    BoxForLiquids,
+    /// Box (XBX)
+    Box,
+    /// Board, in bundle/bunch/truss (XBY)
+    BoardInBundlePerBunchPerTruss,
+    /// Bars, in bundle/bunch/truss (XBZ)
+    BarsInBundlePerBunchPerTruss,
+    /// Can, rectangular (XCA)
+    CanRectangular,
+    /// Crate, beer (XCB)
+    CrateBeer,
+    /// Churn (XCC)
+    Churn,
+    /// Can, with handle and spout (XCD)
+    CanWithHandleAndSpout,
+    /// Creel (XCE)
+    Creel,
+    /// Coffer (XCF)
+    Coffer,
+    /// Cage (XCG)
+    Cage,
+    /// Chest (XCH)
+    Chest,
+    /// Canister (XCI)
+    Canister,
+    /// Coffin (XCJ)
+    Coffin,
+    /// Cask (XCK)
+    Cask,
+    /// Coil (XCL)
+    Coil,
+    /// Card (XCM)
+    PackageCard,
+    /// Container, not otherwise specified as transport equipment (XCN)
+    ContainerNotOtherwiseSpecifiedAsTransportEquipment,
+    /// Carboy, non-protected (XCO)
+    CarboyNonProtected,
+    /// Carboy, protected (XCP)
+    CarboyProtected,
+    /// Cartridge (XCQ)
+    Cartridge,
+    /// Crate (XCR)
+    Crate,
+    /// Case (XCS)
+    Case,
+    /// Carton (XCT)
+    Carton,
+    /// Cup (XCU)
+    Cup,
+    /// Cover (XCV)
+    Cover,
+    /// Cage, roll (XCW)
+    CageRoll,
+    /// Can, cylindrical (XCX)
+    CanCylindrical,
+    /// Cylinder (XCY)
+    Cylinder,
+    /// Canvas (XCZ)
+    Canvas,
+    /// Crate, multiple layer, plastic (XDA)
+    CrateMultipleLayerPlastic,
+    /// Crate, multiple layer, wooden (XDB)
+    CrateMultipleLayerWooden,
+    /// Crate, multiple layer, cardboard (XDC)
+    CrateMultipleLayerCardboard,
+    /// Cage, Commonwealth Handling Equipment Pool (CHEP) (XDG)
+    CageCommonwealthHandlingEquipmentPoolChep,
+    /// Box, Commonwealth Handling Equipment Pool (CHEP), Eurobox (XDH)
+    BoxCommonwealthHandlingEquipmentPoolChepEurobox,
+    /// Drum, iron (XDI)
+    DrumIron,
+    /// Demijohn, non-protected (XDJ)
+    DemijohnNonProtected,
+    /// Crate, bulk, cardboard (XDK)
+    CrateBulkCardboard,
+    /// Crate, bulk, plastic (XDL)
+    CrateBulkPlastic,
+    /// Crate, bulk, wooden (XDM)
+    CrateBulkWooden,
+    /// Dispenser (XDN)
+    Dispenser,
+    /// Demijohn, protected (XDP)
+    DemijohnProtected,
+    /// Drum (XDR)
+    Drum,
+    /// Tray, one layer no cover, plastic (XDS)
+    TrayOneLayerNoCoverPlastic,
+    /// Tray, one layer no cover, wooden (XDT)
+    TrayOneLayerNoCoverWooden,
+    /// Tray, one layer no cover, polystyrene (XDU)
+    TrayOneLayerNoCoverPolystyrene,
+    /// Tray, one layer no cover, cardboard (XDV)
+    TrayOneLayerNoCoverCardboard,
+    /// Tray, two layers no cover, plastic tray (XDW)
+    TrayTwoLayersNoCoverPlasticTray,
+    /// Tray, two layers no cover, wooden (XDX)
+    TrayTwoLayersNoCoverWooden,
+    /// Tray, two layers no cover, cardboard (XDY)
+    TrayTwoLayersNoCoverCardboard,
+    /// Bag, plastic (XEC)
+    BagPlastic,
+    /// Case, with pallet base (XED)
+    CaseWithPalletBase,
+    /// Case, with pallet base, wooden (XEE)
+    CaseWithPalletBaseWooden,
+    /// Case, with pallet base, cardboard (XEF)
+    CaseWithPalletBaseCardboard,
+    /// Case, with pallet base, plastic (XEG)
+    CaseWithPalletBasePlastic,
+    /// Case, with pallet base, metal (XEH)
+    CaseWithPalletBaseMetal,
+    /// Case, isothermic (XEI)
+    CaseIsothermic,
+    /// Envelope (XEN)
+    Envelope,
+    /// Flexibag (XFB)
+    Flexibag,
+    /// Crate, fruit (XFC)
+    CrateFruit,
+    /// Crate, framed (XFD)
+    CrateFramed,
+    /// Flexitank (XFE)
+    Flexitank,
+    /// Firkin (XFI)
+    Firkin,
+    /// Flask (XFL)
+    Flask,
+    /// Footlocker (XFO)
+    Footlocker,
+    /// Filmpack (XFP)
+    Filmpack,
+    /// Frame (XFR)
+    Frame,
+    /// Foodtainer (XFT)
+    Foodtainer,
+    /// Cart, flatbed (XFW)
+    CartFlatbed,
+    /// Bag, flexible container (XFX)
+    BagFlexibleContainer,
+    /// Bottle, gas (XGB)
+    BottleGas,
+    /// Girder (XGI)
+    Girder,
+    /// Container, gallon (XGL)
+    ContainerGallon,
+    /// Receptacle, glass (XGR)
+    ReceptacleGlass,
+    /// Tray, containing horizontally stacked flat items (XGU)
+    TrayContainingHorizontallyStackedFlatItems,
+    /// Bag, gunny (XGY)
+    BagGunny,
+    /// Girders, in bundle/bunch/truss (XGZ)
+    GirdersInBundlePerBunchPerTruss,
+    /// Basket, with handle, plastic (XHA)
+    BasketWithHandlePlastic,
+    /// Basket, with handle, wooden (XHB)
+    BasketWithHandleWooden,
+    /// Basket, with handle, cardboard (XHC)
+    BasketWithHandleCardboard,
+    /// Hogshead (XHG)
+    Hogshead,
+    /// Hanger (XHN)
+    Hanger,
+    /// Hamper (XHR)
+    Hamper,
+    /// Package, display, wooden (XIA)
+    PackageDisplayWooden,
+    /// Package, display, cardboard (XIB)
+    PackageDisplayCardboard,
+    /// Package, display, plastic (XIC)
+    PackageDisplayPlastic,
+    /// Package, display, metal (XID)
+    PackageDisplayMetal,
+    /// Package, show (XIE)
+    PackageShow,
+    /// Package, flow (XIF)
+    PackageFlow,
+    /// Package, paper wrapped (XIG)
+    PackagePaperWrapped,
+    /// Drum, plastic (XIH)
+    DrumPlastic,
+    /// Package, cardboard, with bottle grip-holes (XIK)
+    PackageCardboardWithBottleGripHoles,
+    /// Tray, rigid, lidded stackable (CEN TS 14482:2002) (XIL)
+    TrayRigidLiddedStackableCenTs144822002,
+    /// Ingot (XIN)
+    Ingot,
+    /// Ingots, in bundle/bunch/truss (XIZ)
+    IngotsInBundlePerBunchPerTruss,
+    /// Bag, jumbo (XJB)
+    BagJumbo,
+    /// Jerrican, rectangular (XJC)
+    JerricanRectangular,
+    /// Jug (XJG)
+    Jug,
+    /// Jar (XJR)
+    Jar,
+    /// Jutebag (XJT)
+    Jutebag,
+    /// Jerrican, cylindrical (XJY)
+    JerricanCylindrical,
+    /// Keg (XKG)
+    Keg,
+    /// Kit (XKI)
+    PackageKit,
+    /// Luggage (XLE)
+    Luggage,
+    /// Log (XLG)
+    Log,
+    /// Lot (XLT)
+    Lot,
+    /// Lug (XLU)
+    Lug,
+    /// Liftvan (XLV)
+    Liftvan,
+    /// Logs, in bundle/bunch/truss (XLZ)
+    LogsInBundlePerBunchPerTruss,
+    /// Crate, metal (XMA)
+    CrateMetal,
+    /// Bag, multiply (XMB)
+    BagMultiply,
+    /// Crate, milk (XMC)
+    CrateMilk,
+    /// Container, metal (XME)
+    ContainerMetal,
+    /// Receptacle, metal (XMR)
+    ReceptacleMetal,
+    /// Sack, multi-wall (XMS)
+    SackMultiWall,
+    /// Mat (XMT)
+    Mat,
+    /// Receptacle, plastic wrapped (XMW)
+    ReceptaclePlasticWrapped,
+    /// Matchbox (XMX)
+    Matchbox,
+    /// Not available (XNA)
+    NotAvailable,
+    /// Unpacked or unpackaged (XNE)
+    UnpackedOrUnpackaged,
+    /// Unpacked or unpackaged, single unit (XNF)
+    UnpackedOrUnpackagedSingleUnit,
+    /// Unpacked or unpackaged, multiple units (XNG)
+    UnpackedOrUnpackagedMultipleUnits,
+    /// Nest (XNS)
+    Nest,
+    /// Net (XNT)
+    Net,
+    /// Net, tube, plastic (XNU)
+    NetTubePlastic,
+    /// Net, tube, textile (XNV)
+    NetTubeTextile,
+    /// Two sided cage on wheels with fixing strap (XO1)
+    TwoSidedCageOnWheelsWithFixingStrap,
+    /// Trolley (XO2)
+    Trolley,
+    /// Oneway pallet ISO 0 - 1/2 EURO pallet (XO3)
+    OnewayPalletIso01Per2EuroPallet,
+    /// Oneway pallet ISO 1 - 1/1 EURO pallet (XO4)
+    OnewayPalletIso11Per1EuroPallet,
+    /// Oneway pallet ISO 2 - 2/1 EURO pallet (XO5)
+    OnewayPalletIso22Per1EuroPallet,
+    /// Pallet with exceptional dimensions (XO6)
+    PalletWithExceptionalDimensions,
+    /// Wooden pallet 40 cm x 80 cm (XO7)
+    WoodenPallet40CmX80Cm,
+    /// Plastic pallet SRS 60 cm x 80 cm (XO8)
+    PlasticPalletSrs60CmX80Cm,
+    /// Plastic pallet SRS 80 cm x 120 cm (XO9)
+    PlasticPalletSrs80CmX120Cm,
+    /// Pallet, CHEP 40 cm x 60 cm (XOA)
+    PalletChep40CmX60Cm,
+    /// Pallet, CHEP 80 cm x 120 cm (XOB)
+    PalletChep80CmX120Cm,
+    /// Pallet, CHEP 100 cm x 120 cm (XOC)
+    PalletChep100CmX120Cm,
+    /// Pallet, AS 4068-1993 (XOD)
+    PalletAs40681993,
+    /// Pallet, ISO T11 (XOE)
+    PalletIsoT11,
+    /// Platform, unspecified weight or dimension (XOF)
+    PlatformUnspecifiedWeightOrDimension,
+    /// Pallet ISO 0 - 1/2 EURO pallet (XOG)
+    PalletIso01Per2EuroPallet,
+    /// Pallet ISO 1 - 1/1 EURO pallet (XOH)
+    PalletIso11Per1EuroPallet,
+    /// Pallet ISO 2 - 2/1 EURO pallet (XOI)
+    PalletIso22Per1EuroPallet,
+    /// 1/4 EURO pallet (XOJ)
+    QuarterEuroPallet,
+    /// Block (XOK)
+    Block,
+    /// 1/8 EURO pallet (XOL)
+    EighthEuroPallet,
+    /// Synthetic pallet ISO 1 (XOM)
+    SyntheticPalletIso1,
+    /// Synthetic pallet ISO 2 (XON)
+    SyntheticPalletIso2,
+    /// Wholesaler pallet (XOP)
+    WholesalerPallet,
+    /// Pallet 80 X 100 cm (XOQ)
+    Pallet80X100Cm,
+    /// Pallet 60 X 100 cm (XOR)
+    Pallet60X100Cm,
+    /// Oneway pallet (XOS)
+    OnewayPallet,
+    /// Octabin (XOT)
+    Octabin,
+    /// Container, outer (XOU)
+    ContainerOuter,
+    /// Returnable pallet (XOV)
+    ReturnablePallet,
+    /// Large bag, pallet sized (XOW)
+    LargeBagPalletSized,
+    /// A wheeled pallet with raised rim (81 x 67 x 135) (XOX)
+    AWheeledPalletWithRaisedRim81X67X135,
+    /// A wheeled pallet with raised rim (81 x 72 x 135) (XOY)
+    AWheeledPalletWithRaisedRim81X72X135,
+    /// Wheeled pallet with raised rim (81 x 60 x 16) (XOZ)
+    WheeledPalletWithRaisedRim81X60X16,
+    /// CHEP pallet 60 cm x 80 cm (XP1)
+    ChepPallet60CmX80Cm,
+    /// Pan (XP2)
+    Pan,
+    /// LPR pallet 60 cm x 80 cm (XP3)
+    LprPallet60CmX80Cm,
+    /// LPR pallet 80 cm x 120 cm (XP4)
+    LprPallet80CmX120Cm,
+    /// Packet (XPA)
+    Packet,
+    /// Pallet, box combined open-ended box and pallet (XPB)
+    PalletBoxCombinedOpenEndedBoxAndPallet,
+    /// Parcel (XPC)
+    Parcel,
+    /// Pallet, modular, collars 80cms * 100cms (XPD)
+    PalletModularCollars80cms100cms,
+    /// Pallet, modular, collars 80cms * 120cms (XPE)
+    PalletModularCollars80cms120cms,
+    /// Pen (XPF)
+    Pen,
+    /// Plate (XPG)
+    Plate,
+    /// Pitcher (XPH)
+    Pitcher,
+    /// Pipe (XPI)
+    Pipe,
+    /// Punnet (XPJ)
+    Punnet,
+    /// Package (XPK)
+    Package,
+    /// Pail (XPL)
+    Pail,
+    /// Plank (XPN)
+    Plank,
+    /// Pouch (XPO)
+    Pouch,
+    /// Piece (XPP)
+    PackagePiece,
+    /// Receptacle, plastic (XPR)
+    ReceptaclePlastic,
+    /// Pot (XPT)
+    Pot,
+    /// Tray (XPU)
+    Tray,
+    /// Pipes, in bundle/bunch/truss (XPV)
+    PipesInBundlePerBunchPerTruss,
+    /// Pallet (XPX)
+    Pallet,
+    /// Plates, in bundle/bunch/truss (XPY)
+    PlatesInBundlePerBunchPerTruss,
+    /// Planks, in bundle/bunch/truss (XPZ)
+    PlanksInBundlePerBunchPerTruss,
+    /// Drum, steel, non-removable head (XQA)
+    DrumSteelNonRemovableHead,
+    /// Drum, steel, removable head (XQB)
+    DrumSteelRemovableHead,
+    /// Drum, aluminium, non-removable head (XQC)
+    DrumAluminiumNonRemovableHead,
+    /// Drum, aluminium, removable head (XQD)
+    DrumAluminiumRemovableHead,
+    /// Drum, plastic, non-removable head (XQF)
+    DrumPlasticNonRemovableHead,
+    /// Drum, plastic, removable head (XQG)
+    DrumPlasticRemovableHead,
+    /// Barrel, wooden, bung type (XQH)
+    BarrelWoodenBungType,
+    /// Barrel, wooden, removable head (XQJ)
+    BarrelWoodenRemovableHead,
+    /// Jerrican, steel, non-removable head (XQK)
+    JerricanSteelNonRemovableHead,
+    /// Jerrican, steel, removable head (XQL)
+    JerricanSteelRemovableHead,
+    /// Jerrican, plastic, non-removable head (XQM)
+    JerricanPlasticNonRemovableHead,
+    /// Jerrican, plastic, removable head (XQN)
+    JerricanPlasticRemovableHead,
+    /// Box, wooden, natural wood, ordinary (XQP)
+    BoxWoodenNaturalWoodOrdinary,
+    /// Box, wooden, natural wood, with sift proof walls (XQQ)
+    BoxWoodenNaturalWoodWithSiftProofWalls,
+    /// Box, plastic, expanded (XQR)
+    BoxPlasticExpanded,
+    /// Box, plastic, solid (XQS)
+    BoxPlasticSolid,
+    /// Rod (XRD)
+    Rod,
+    /// Ring (XRG)
+    Ring,
+    /// Rack, clothing hanger (XRJ)
+    RackClothingHanger,
+    /// Rack (XRK)
+    Rack,
+    /// Reel (XRL)
+    Reel,
+    /// Roll (XRO)
+    Roll,
+    /// Rednet (XRT)
+    Rednet,
+    /// Rods, in bundle/bunch/truss (XRZ)
+    RodsInBundlePerBunchPerTruss,
+    /// Sack (XSA)
+    Sack,
+    /// Slab (XSB)
+    Slab,
+    /// Crate, shallow (XSC)
+    CrateShallow,
+    /// Spindle (XSD)
+    Spindle,
+    /// Sea-chest (XSE)
+    SeaChest,
+    /// Sachet (XSH)
+    Sachet,
+    /// Skid (XSI)
+    Skid,
+    /// Case, skeleton (XSK)
+    CaseSkeleton,
+    /// Slipsheet (XSL)
+    Slipsheet,
+    /// Sheetmetal (XSM)
+    Sheetmetal,
+    /// Spool (XSO)
+    Spool,
+    /// Sheet, plastic wrapping (XSP)
+    SheetPlasticWrapping,
+    /// Case, steel (XSS)
+    CaseSteel,
+    /// Sheet (XST)
+    Sheet,
+    /// Suitcase (XSU)
+    Suitcase,
+    /// Envelope, steel (XSV)
+    EnvelopeSteel,
+    /// Shrinkwrapped (XSW)
+    Shrinkwrapped,
+    /// Set (XSX)
+    PackageSet,
+    /// Sleeve (XSY)
+    Sleeve,
+    /// Sheets, in bundle/bunch/truss (XSZ)
+    SheetsInBundlePerBunchPerTruss,
+    /// Tablet (XT1)
+    PackageTablet,
+    /// Tub (XTB)
+    Tub,
+    /// Tea-chest (XTC)
+    TeaChest,
+    /// Collapsible tube (XTD)
+    CollapsibleTube,
+    /// Tyre (XTE)
+    PackageTyre,
+    /// Tank container, generic (XTG)
+    TankContainerGeneric,
+    /// Tierce (XTI)
+    Tierce,
+    /// Tank, rectangular (XTK)
+    TankRectangular,
+    /// Tub, with lid (XTL)
+    TubWithLid,
+    /// Tin (XTN)
+    Tin,
+    /// Tun (XTO)
+    Tun,
+    /// Trunk (XTR)
+    Trunk,
+    /// Truss (XTS)
+    Truss,
+    /// Bag, tote (XTT)
+    BagTote,
+    /// Tube (XTU)
+    Tube,
+    /// Tube, with nozzle (XTV)
+    TubeWithNozzle,
+    /// Pallet, triwall (XTW)
+    PalletTriwall,
+    /// Tank, cylindrical (XTY)
+    TankCylindrical,
+    /// Tubes, in bundle/bunch/truss (XTZ)
+    TubesInBundlePerBunchPerTruss,
+    /// Uncaged (XUC)
+    Uncaged,
+    /// Unit (XUN)
+    Unit,
+    /// Vat (XVA)
+    Vat,
+    /// Bulk, gas (at 1031 mbar and 15 degC) (XVG)
+    BulkGasAt1031MbarAnd15Degc,
+    /// Vial (XVI)
+    Vial,
+    /// Vanpack (XVK)
+    Vanpack,
+    /// Bulk, liquid (XVL)
+    BulkLiquid,
+    /// Vehicle (XVN)
+    Vehicle,
+    /// Bulk, solid, large particles ("nodules") (XVO)
+    BulkSolidLargeParticlesNodules,
+    /// Vacuum-packed (XVP)
+    VacuumPacked,
+    /// Bulk, liquefied gas (at abnormal temperature/pressure) (XVQ)
+    BulkLiquefiedGasAtAbnormalTemperaturePerPressure,
+    /// Bulk, solid, granular particles ("grains") (XVR)
+    BulkSolidGranularParticlesGrains,
+    /// Bulk, scrap metal (XVS)
+    BulkScrapMetal,
+    /// Bulk, solid, fine particles ("powders") (XVY)
+    BulkSolidFineParticlesPowders,
+    /// Intermediate bulk container (XWA)
+    IntermediateBulkContainer,
+    /// Wickerbottle (XWB)
+    Wickerbottle,
+    /// Intermediate bulk container, steel (XWC)
+    IntermediateBulkContainerSteel,
+    /// Intermediate bulk container, aluminium (XWD)
+    IntermediateBulkContainerAluminium,
+    /// Intermediate bulk container, metal (XWF)
+    IntermediateBulkContainerMetal,
+    /// Intermediate bulk container, steel, pressurised > 10 kpa (XWG)
+    IntermediateBulkContainerSteelPressurisedOver10Kpa,
+    /// Intermediate bulk container, aluminium, pressurised > 10 kpa (XWH)
+    IntermediateBulkContainerAluminiumPressurisedOver10Kpa,
+    /// Intermediate bulk container, metal, pressure 10 kpa (XWJ)
+    IntermediateBulkContainerMetalPressure10Kpa,
+    /// Intermediate bulk container, steel, liquid (XWK)
+    IntermediateBulkContainerSteelLiquid,
+    /// Intermediate bulk container, aluminium, liquid (XWL)
+    IntermediateBulkContainerAluminiumLiquid,
+    /// Intermediate bulk container, metal, liquid (XWM)
+    IntermediateBulkContainerMetalLiquid,
+    /// Intermediate bulk container, woven plastic, without coat/liner (XWN)
+    IntermediateBulkContainerWovenPlasticWithoutCoatPerLiner,
+    /// Intermediate bulk container, woven plastic, coated (XWP)
+    IntermediateBulkContainerWovenPlasticCoated,
+    /// Intermediate bulk container, woven plastic, with liner (XWQ)
+    IntermediateBulkContainerWovenPlasticWithLiner,
+    /// Intermediate bulk container, woven plastic, coated and liner (XWR)
+    IntermediateBulkContainerWovenPlasticCoatedAndLiner,
+    /// Intermediate bulk container, plastic film (XWS)
+    IntermediateBulkContainerPlasticFilm,
+    /// Intermediate bulk container, textile with out coat/liner (XWT)
+    IntermediateBulkContainerTextileWithOutCoatPerLiner,
+    /// Intermediate bulk container, natural wood, with inner liner (XWU)
+    IntermediateBulkContainerNaturalWoodWithInnerLiner,
+    /// Intermediate bulk container, textile, coated (XWV)
+    IntermediateBulkContainerTextileCoated,
+    /// Intermediate bulk container, textile, with liner (XWW)
+    IntermediateBulkContainerTextileWithLiner,
+    /// Intermediate bulk container, textile, coated and liner (XWX)
+    IntermediateBulkContainerTextileCoatedAndLiner,
+    /// Intermediate bulk container, plywood, with inner liner (XWY)
+    IntermediateBulkContainerPlywoodWithInnerLiner,
+    /// Intermediate bulk container, reconstituted wood, with inner liner (XWZ)
+    IntermediateBulkContainerReconstitutedWoodWithInnerLiner,
+    /// Bag, woven plastic, without inner coat/liner (XXA)
+    BagWovenPlasticWithoutInnerCoatPerLiner,
+    /// Bag, woven plastic, sift proof (XXB)
+    BagWovenPlasticSiftProof,
+    /// Bag, woven plastic, water resistant (XXC)
+    BagWovenPlasticWaterResistant,
+    /// Bag, plastics film (XXD)
+    BagPlasticsFilm,
+    /// Bag, textile, without inner coat/liner (XXF)
+    BagTextileWithoutInnerCoatPerLiner,
+    /// Bag, textile, sift proof (XXG)
+    BagTextileSiftProof,
+    /// Bag, textile, water resistant (XXH)
+    BagTextileWaterResistant,
+    /// Bag, paper, multi-wall (XXJ)
+    BagPaperMultiWall,
+    /// Bag, paper, multi-wall, water resistant (XXK)
+    BagPaperMultiWallWaterResistant,
+    /// Composite packaging, plastic receptacle in steel drum (XYA)
+    CompositePackagingPlasticReceptacleInSteelDrum,
+    /// Composite packaging, plastic receptacle in steel crate box (XYB)
+    CompositePackagingPlasticReceptacleInSteelCrateBox,
+    /// Composite packaging, plastic receptacle in aluminium drum (XYC)
+    CompositePackagingPlasticReceptacleInAluminiumDrum,
+    /// Composite packaging, plastic receptacle in aluminium crate (XYD)
+    CompositePackagingPlasticReceptacleInAluminiumCrate,
+    /// Composite packaging, plastic receptacle in wooden box (XYF)
+    CompositePackagingPlasticReceptacleInWoodenBox,
+    /// Composite packaging, plastic receptacle in plywood drum (XYG)
+    CompositePackagingPlasticReceptacleInPlywoodDrum,
+    /// Composite packaging, plastic receptacle in plywood box (XYH)
+    CompositePackagingPlasticReceptacleInPlywoodBox,
+    /// Composite packaging, plastic receptacle in fibre drum (XYJ)
+    CompositePackagingPlasticReceptacleInFibreDrum,
+    /// Composite packaging, plastic receptacle in fibreboard box (XYK)
+    CompositePackagingPlasticReceptacleInFibreboardBox,
+    /// Composite packaging, plastic receptacle in plastic drum (XYL)
+    CompositePackagingPlasticReceptacleInPlasticDrum,
+    /// Composite packaging, plastic receptacle in solid plastic box (XYM)
+    CompositePackagingPlasticReceptacleInSolidPlasticBox,
+    /// Composite packaging, glass receptacle in steel drum (XYN)
+    CompositePackagingGlassReceptacleInSteelDrum,
+    /// Composite packaging, glass receptacle in steel crate box (XYP)
+    CompositePackagingGlassReceptacleInSteelCrateBox,
+    /// Composite packaging, glass receptacle in aluminium drum (XYQ)
+    CompositePackagingGlassReceptacleInAluminiumDrum,
+    /// Composite packaging, glass receptacle in aluminium crate (XYR)
+    CompositePackagingGlassReceptacleInAluminiumCrate,
+    /// Composite packaging, glass receptacle in wooden box (XYS)
+    CompositePackagingGlassReceptacleInWoodenBox,
+    /// Composite packaging, glass receptacle in plywood drum (XYT)
+    CompositePackagingGlassReceptacleInPlywoodDrum,
+    /// Composite packaging, glass receptacle in wickerwork hamper (XYV)
+    CompositePackagingGlassReceptacleInWickerworkHamper,
+    /// Composite packaging, glass receptacle in fibre drum (XYW)
+    CompositePackagingGlassReceptacleInFibreDrum,
+    /// Composite packaging, glass receptacle in fibreboard box (XYX)
+    CompositePackagingGlassReceptacleInFibreboardBox,
+    /// Composite packaging, glass receptacle in expandable plastic pack (XYY)
+    CompositePackagingGlassReceptacleInExpandablePlasticPack,
+    /// Composite packaging, glass receptacle in solid plastic pack (XYZ)
+    CompositePackagingGlassReceptacleInSolidPlasticPack,
+    /// Intermediate bulk container, paper, multi-wall (XZA)
+    IntermediateBulkContainerPaperMultiWall,
+    /// Bag, large (XZB)
+    BagLarge,
+    /// Intermediate bulk container, paper, multi-wall, water resistant (XZC)
+    IntermediateBulkContainerPaperMultiWallWaterResistant,
+    /// Intermediate bulk container, rigid plastic, with structural equipment, solids (XZD)
+    IntermediateBulkContainerRigidPlasticWithStructuralEquipmentSolids,
+    /// Intermediate bulk container, rigid plastic, freestanding, solids (XZF)
+    IntermediateBulkContainerRigidPlasticFreestandingSolids,
+    /// Intermediate bulk container, rigid plastic, with structural equipment, pressurised (XZG)
+    IntermediateBulkContainerRigidPlasticWithStructuralEquipmentPressurised,
+    /// Intermediate bulk container, rigid plastic, freestanding, pressurised (XZH)
+    IntermediateBulkContainerRigidPlasticFreestandingPressurised,
+    /// Intermediate bulk container, rigid plastic, with structural equipment, liquids (XZJ)
+    IntermediateBulkContainerRigidPlasticWithStructuralEquipmentLiquids,
+    /// Intermediate bulk container, rigid plastic, freestanding, liquids (XZK)
+    IntermediateBulkContainerRigidPlasticFreestandingLiquids,
+    /// Intermediate bulk container, composite, rigid plastic, solids (XZL)
+    IntermediateBulkContainerCompositeRigidPlasticSolids,
+    /// Intermediate bulk container, composite, flexible plastic, solids (XZM)
+    IntermediateBulkContainerCompositeFlexiblePlasticSolids,
+    /// Intermediate bulk container, composite, rigid plastic, pressurised (XZN)
+    IntermediateBulkContainerCompositeRigidPlasticPressurised,
+    /// Intermediate bulk container, composite, flexible plastic, pressurised (XZP)
+    IntermediateBulkContainerCompositeFlexiblePlasticPressurised,
+    /// Intermediate bulk container, composite, rigid plastic, liquids (XZQ)
+    IntermediateBulkContainerCompositeRigidPlasticLiquids,
+    /// Intermediate bulk container, composite, flexible plastic, liquids (XZR)
+    IntermediateBulkContainerCompositeFlexiblePlasticLiquids,
+    /// Intermediate bulk container, composite (XZS)
+    IntermediateBulkContainerComposite,
+    /// Intermediate bulk container, fibreboard (XZT)
+    IntermediateBulkContainerFibreboard,
+    /// Intermediate bulk container, flexible (XZU)
+    IntermediateBulkContainerFlexible,
+    /// Intermediate bulk container, metal, other than steel (XZV)
+    IntermediateBulkContainerMetalOtherThanSteel,
+    /// Intermediate bulk container, natural wood (XZW)
+    IntermediateBulkContainerNaturalWood,
+    /// Intermediate bulk container, plywood (XZX)
+    IntermediateBulkContainerPlywood,
+    /// Intermediate bulk container, reconstituted wood (XZY)
+    IntermediateBulkContainerReconstitutedWood,
+    /// Mutually defined (XZZ)
+    PackageMutuallyDefined,
 }
 
 impl UnitCode {
     pub fn as_str(&self) -> &str {
         match self {
+            UnitCode::Group => "10",
+            UnitCode::Outfit => "11",
+            UnitCode::Ration => "13",
+            UnitCode::Shot => "14",
+            UnitCode::StickMilitary => "15",
+            UnitCode::TwentyFootContainer => "20",
+            UnitCode::FortyFootContainer => "21",
+            UnitCode::DecilitrePerGram => "22",
+            UnitCode::GramPerCubicCentimetre => "23",
+            UnitCode::TheoreticalPound => "24",
+            UnitCode::GramPerSquareCentimetre => "25",
+            UnitCode::TheoreticalTon => "27",
+            UnitCode::KilogramPerSquareMetre => "28",
+            UnitCode::KilopascalSquareMetrePerGram => "33",
+            UnitCode::KilopascalPerMillimetre => "34",
+            UnitCode::MillilitrePerSquareCentimetreSecond => "35",
+            UnitCode::OuncePerSquareFoot => "37",
+            UnitCode::OuncePerSquareFootPer001inch => "38",
+            UnitCode::MillilitrePerSecond => "40",
+            UnitCode::MillilitrePerMinute => "41",
+            UnitCode::Sitas => "56",
+            UnitCode::Mesh => "57",
+            UnitCode::NetKilogram => "58",
+            UnitCode::PartPerMillion => "59",
+            UnitCode::PercentWeight => "60",
+            UnitCode::PartPerBillionUs => "61",
+            UnitCode::Millipascal => "74",
+            UnitCode::MilliInch => "77",
+            UnitCode::PoundPerSquareInchAbsolute => "80",
+            UnitCode::Henry => "81",
+            UnitCode::FootPoundForce => "85",
+            UnitCode::PoundPerCubicFoot => "87",
+            UnitCode::Poise => "89",
+            UnitCode::Stokes => "91",
+            UnitCode::FixedRate => "1I",
+            UnitCode::RadianPerSecond => "2A",
+            UnitCode::RadianPerSecondSquared => "2B",
+            UnitCode::Roentgen => "2C",
+            UnitCode::VoltAc => "2G",
+            UnitCode::VoltDc => "2H",
+            UnitCode::BritishThermalUnitInternationalTablePerHour => "2I",
+            UnitCode::CubicCentimetrePerSecond => "2J",
+            UnitCode::CubicFootPerHour => "2K",
+            UnitCode::CubicFootPerMinute => "2L",
+            UnitCode::CentimetrePerSecond => "2M",
+            UnitCode::Decibel => "2N",
+            UnitCode::Kilobyte => "2P",
+            UnitCode::Kilobecquerel => "2Q",
+            UnitCode::Kilocurie => "2R",
+            UnitCode::Megagram => "2U",
+            UnitCode::MetrePerMinute => "2X",
+            UnitCode::Milliroentgen => "2Y",
+            UnitCode::Millivolt => "2Z",
+            UnitCode::Megajoule => "3B",
+            UnitCode::Manmonth => "3C",
+            UnitCode::Centistokes => "4C",
+            UnitCode::Microlitre => "4G",
+            UnitCode::MicrometreMicron => "4H",
+            UnitCode::Milliampere => "4K",
+            UnitCode::Megabyte => "4L",
+            UnitCode::MilligramPerHour => "4M",
+            UnitCode::Megabecquerel => "4N",
+            UnitCode::Microfarad => "4O",
+            UnitCode::NewtonPerMetre => "4P",
+            UnitCode::OunceInch => "4Q",
+            UnitCode::OunceFoot => "4R",
+            UnitCode::Picofarad => "4T",
+            UnitCode::PoundPerHour => "4U",
+            UnitCode::TonUsPerHour => "4W",
+            UnitCode::KilolitrePerHour => "4X",
+            UnitCode::BarrelUsPerMinute => "5A",
+            UnitCode::Batch => "5B",
+            UnitCode::MmscfPerDay => "5E",
+            UnitCode::HydraulicHorsePower => "5J",
+            UnitCode::AmpereSquareMetrePerJouleSecond => "A10",
+            UnitCode::Angstrom => "A11",
+            UnitCode::AstronomicalUnit => "A12",
+            UnitCode::Attojoule => "A13",
+            UnitCode::Barn => "A14",
+            UnitCode::BarnPerElectronvolt => "A15",
+            UnitCode::BarnPerSteradianElectronvolt => "A16",
+            UnitCode::BarnPerSteradian => "A17",
+            UnitCode::BecquerelPerKilogram => "A18",
+            UnitCode::BecquerelPerCubicMetre => "A19",
+            UnitCode::AmperePerCentimetre => "A2",
+            UnitCode::BritishThermalUnitInternationalTablePerSecondSquareFootDegreeRankine => "A20",
+            UnitCode::BritishThermalUnitInternationalTablePerPoundDegreeRankine => "A21",
+            UnitCode::BritishThermalUnitInternationalTablePerSecondFootDegreeRankine => "A22",
+            UnitCode::BritishThermalUnitInternationalTablePerHourSquareFootDegreeRankine => "A23",
+            UnitCode::CandelaPerSquareMetre => "A24",
+            UnitCode::CoulombMetre => "A26",
+            UnitCode::CoulombMetreSquaredPerVolt => "A27",
+            UnitCode::CoulombPerCubicCentimetre => "A28",
+            UnitCode::CoulombPerCubicMetre => "A29",
+            UnitCode::AmperePerMillimetre => "A3",
+            UnitCode::CoulombPerCubicMillimetre => "A30",
+            UnitCode::CoulombPerKilogramSecond => "A31",
+            UnitCode::CoulombPerMole => "A32",
+            UnitCode::CoulombPerSquareCentimetre => "A33",
+            UnitCode::CoulombPerSquareMetre => "A34",
+            UnitCode::CoulombPerSquareMillimetre => "A35",
+            UnitCode::CubicCentimetrePerMole => "A36",
+            UnitCode::CubicDecimetrePerMole => "A37",
+            UnitCode::CubicMetrePerCoulomb => "A38",
+            UnitCode::CubicMetrePerKilogram => "A39",
+            UnitCode::AmperePerSquareCentimetre => "A4",
+            UnitCode::CubicMetrePerMole => "A40",
+            UnitCode::AmperePerSquareMetre => "A41",
+            UnitCode::CuriePerKilogram => "A42",
+            UnitCode::DeadweightTonnage => "A43",
+            UnitCode::Decalitre => "A44",
+            UnitCode::Decametre => "A45",
+            UnitCode::Decitex => "A47",
+            UnitCode::DegreeRankine => "A48",
+            UnitCode::Denier => "A49",
+            UnitCode::AmpereSquareMetre => "A5",
+            UnitCode::Electronvolt => "A53",
+            UnitCode::ElectronvoltPerMetre => "A54",
+            UnitCode::ElectronvoltSquareMetre => "A55",
+            UnitCode::ElectronvoltSquareMetrePerKilogram => "A56",
+            UnitCode::EightPartCloudCover => "A59",
+            UnitCode::AmperePerSquareMetreKelvinSquared => "A6",
+            UnitCode::Exajoule => "A68",
+            UnitCode::FaradPerMetre => "A69",
+            UnitCode::AmperePerSquareMillimetre => "A7",
+            UnitCode::Femtojoule => "A70",
+            UnitCode::Femtometre => "A71",
+            UnitCode::FootPerSecondSquared => "A73",
+            UnitCode::FootPoundForcePerSecond => "A74",
+            UnitCode::FreightTon => "A75",
+            UnitCode::Gal => "A76",
+            UnitCode::AmpereSecond => "A8",
+            UnitCode::GigacoulombPerCubicMetre => "A84",
+            UnitCode::Gigaelectronvolt => "A85",
+            UnitCode::Gigahertz => "A86",
+            UnitCode::Gigaohm => "A87",
+            UnitCode::GigaohmMetre => "A88",
+            UnitCode::Gigapascal => "A89",
+            UnitCode::Rate => "A9",
+            UnitCode::Gigawatt => "A90",
+            UnitCode::Gon => "A91",
+            UnitCode::GramPerCubicMetre => "A93",
+            UnitCode::GramPerMole => "A94",
+            UnitCode::Gray => "A95",
+            UnitCode::GrayPerSecond => "A96",
+            UnitCode::Hectopascal => "A97",
+            UnitCode::HenryPerMetre => "A98",
+            UnitCode::Bit => "A99",
+            UnitCode::Ball => "AA",
+            UnitCode::BulkPack => "AB",
+            UnitCode::Acre => "ACR",
+            UnitCode::Activity => "ACT",
+            UnitCode::Byte => "AD",
+            UnitCode::AmperePerMetre => "AE",
+            UnitCode::AdditionalMinute => "AH",
+            UnitCode::AverageMinutePerCall => "AI",
+            UnitCode::Fathom => "AK",
+            UnitCode::AccessLine => "AL",
+            UnitCode::AmpereHour => "AMH",
+            UnitCode::Ampere => "AMP",
+            UnitCode::Year => "ANN",
+            UnitCode::TroyOunceOrApothecaryOunce => "APZ",
+            UnitCode::AntiHemophilicFactorAhfUnit => "AQ",
+            UnitCode::Assortment => "AS",
+            UnitCode::AlcoholicStrengthByMass => "ASM",
+            UnitCode::AlcoholicStrengthByVolume => "ASU",
+            UnitCode::StandardAtmosphere => "ATM",
+            UnitCode::AmericanWireGauge => "AWG",
+            UnitCode::Assembly => "AY",
+            UnitCode::BritishThermalUnitInternationalTablePerPound => "AZ",
+            UnitCode::BarrelUsPerDay => "B1",
+            UnitCode::BitPerSecond => "B10",
+            UnitCode::JoulePerKilogramKelvin => "B11",
+            UnitCode::JoulePerMetre => "B12",
+            UnitCode::JoulePerSquareMetre => "B13",
+            UnitCode::JoulePerMetreToTheFourthPower => "B14",
+            UnitCode::JoulePerMole => "B15",
+            UnitCode::JoulePerMoleKelvin => "B16",
+            UnitCode::Credit => "B17",
+            UnitCode::JouleSecond => "B18",
+            UnitCode::Digit => "B19",
+            UnitCode::JouleSquareMetrePerKilogram => "B20",
+            UnitCode::KelvinPerWatt => "B21",
+            UnitCode::Kiloampere => "B22",
+            UnitCode::KiloamperePerSquareMetre => "B23",
+            UnitCode::KiloamperePerMetre => "B24",
+            UnitCode::KilobecquerelPerKilogram => "B25",
+            UnitCode::Kilocoulomb => "B26",
+            UnitCode::KilocoulombPerCubicMetre => "B27",
+            UnitCode::KilocoulombPerSquareMetre => "B28",
+            UnitCode::Kiloelectronvolt => "B29",
+            UnitCode::BattingPound => "B3",
+            UnitCode::Gibibit => "B30",
+            UnitCode::KilogramMetrePerSecond => "B31",
+            UnitCode::KilogramMetreSquared => "B32",
+            UnitCode::KilogramMetreSquaredPerSecond => "B33",
+            UnitCode::KilogramPerCubicDecimetre => "B34",
+            UnitCode::KilogramPerLitre => "B35",
+            UnitCode::BarrelImperial => "B4",
+            UnitCode::KilojoulePerKelvin => "B41",
+            UnitCode::KilojoulePerKilogram => "B42",
+            UnitCode::KilojoulePerKilogramKelvin => "B43",
+            UnitCode::KilojoulePerMole => "B44",
+            UnitCode::Kilomole => "B45",
+            UnitCode::KilomolePerCubicMetre => "B46",
+            UnitCode::Kilonewton => "B47",
+            UnitCode::KilonewtonMetre => "B48",
+            UnitCode::Kiloohm => "B49",
+            UnitCode::KiloohmMetre => "B50",
+            UnitCode::Kilosecond => "B52",
+            UnitCode::Kilosiemens => "B53",
+            UnitCode::KilosiemensPerMetre => "B54",
+            UnitCode::KilovoltPerMetre => "B55",
+            UnitCode::KiloweberPerMetre => "B56",
+            UnitCode::LightYear => "B57",
+            UnitCode::LitrePerMole => "B58",
+            UnitCode::LumenHour => "B59",
+            UnitCode::LumenPerSquareMetre => "B60",
+            UnitCode::LumenPerWatt => "B61",
+            UnitCode::LumenSecond => "B62",
+            UnitCode::LuxHour => "B63",
+            UnitCode::LuxSecond => "B64",
+            UnitCode::MegaamperePerSquareMetre => "B66",
+            UnitCode::MegabecquerelPerKilogram => "B67",
+            UnitCode::Gigabit => "B68",
+            UnitCode::MegacoulombPerCubicMetre => "B69",
+            UnitCode::Cycle => "B7",
+            UnitCode::MegacoulombPerSquareMetre => "B70",
+            UnitCode::Megaelectronvolt => "B71",
+            UnitCode::MegagramPerCubicMetre => "B72",
+            UnitCode::Meganewton => "B73",
+            UnitCode::MeganewtonMetre => "B74",
+            UnitCode::Megaohm => "B75",
+            UnitCode::MegaohmMetre => "B76",
+            UnitCode::MegasiemensPerMetre => "B77",
+            UnitCode::Megavolt => "B78",
+            UnitCode::MegavoltPerMetre => "B79",
+            UnitCode::JoulePerCubicMetre => "B8",
+            UnitCode::GigabitPerSecond => "B80",
+            UnitCode::ReciprocalMetreSquaredReciprocalSecond => "B81",
+            UnitCode::InchPerLinearFoot => "B82",
+            UnitCode::MetreToTheFourthPower => "B83",
+            UnitCode::Microampere => "B84",
+            UnitCode::Microbar => "B85",
+            UnitCode::Microcoulomb => "B86",
+            UnitCode::MicrocoulombPerCubicMetre => "B87",
+            UnitCode::MicrocoulombPerSquareMetre => "B88",
+            UnitCode::MicrofaradPerMetre => "B89",
+            UnitCode::Microhenry => "B90",
+            UnitCode::MicrohenryPerMetre => "B91",
+            UnitCode::Micronewton => "B92",
+            UnitCode::MicronewtonMetre => "B93",
+            UnitCode::Microohm => "B94",
+            UnitCode::MicroohmMetre => "B95",
+            UnitCode::Micropascal => "B96",
+            UnitCode::Microradian => "B97",
+            UnitCode::Microsecond => "B98",
+            UnitCode::Microsiemens => "B99",
+            UnitCode::BarUnitOfPressure => "BAR",
+            UnitCode::BaseBox => "BB",
+            UnitCode::BoardFoot => "BFT",
+            UnitCode::BrakeHorsePower => "BHP",
+            UnitCode::BillionEur => "BIL",
+            UnitCode::DryBarrelUs => "BLD",
+            UnitCode::BarrelUs => "BLL",
+            UnitCode::HundredBoardFoot => "BP",
+            UnitCode::BeatsPerMinute => "BPM",
+            UnitCode::Becquerel => "BQL",
+            UnitCode::BritishThermalUnitInternationalTable => "BTU",
+            UnitCode::BushelUs => "BUA",
+            UnitCode::BushelUk => "BUI",
+            UnitCode::Call => "C0",
+            UnitCode::Millifarad => "C10",
+            UnitCode::Milligal => "C11",
+            UnitCode::MilligramPerMetre => "C12",
+            UnitCode::Milligray => "C13",
+            UnitCode::Millihenry => "C14",
+            UnitCode::Millijoule => "C15",
+            UnitCode::MillimetrePerSecond => "C16",
+            UnitCode::MillimetreSquaredPerSecond => "C17",
+            UnitCode::Millimole => "C18",
+            UnitCode::MolePerKilogram => "C19",
+            UnitCode::Millinewton => "C20",
+            UnitCode::Kibibit => "C21",
+            UnitCode::MillinewtonPerMetre => "C22",
+            UnitCode::MilliohmMetre => "C23",
+            UnitCode::MillipascalSecond => "C24",
+            UnitCode::Milliradian => "C25",
+            UnitCode::Millisecond => "C26",
+            UnitCode::Millisiemens => "C27",
+            UnitCode::Millisievert => "C28",
+            UnitCode::Millitesla => "C29",
+            UnitCode::MicrovoltPerMetre => "C3",
+            UnitCode::MillivoltPerMetre => "C30",
+            UnitCode::Milliwatt => "C31",
+            UnitCode::MilliwattPerSquareMetre => "C32",
+            UnitCode::Milliweber => "C33",
+            UnitCode::Mole => "C34",
+            UnitCode::MolePerCubicDecimetre => "C35",
+            UnitCode::MolePerCubicMetre => "C36",
+            UnitCode::Kilobit => "C37",
+            UnitCode::MolePerLitre => "C38",
+            UnitCode::Nanoampere => "C39",
+            UnitCode::Nanocoulomb => "C40",
+            UnitCode::Nanofarad => "C41",
+            UnitCode::NanofaradPerMetre => "C42",
+            UnitCode::Nanohenry => "C43",
+            UnitCode::NanohenryPerMetre => "C44",
+            UnitCode::Nanometre => "C45",
+            UnitCode::NanoohmMetre => "C46",
+            UnitCode::Nanosecond => "C47",
+            UnitCode::Nanotesla => "C48",
+            UnitCode::Nanowatt => "C49",
+            UnitCode::Neper => "C50",
+            UnitCode::NeperPerSecond => "C51",
+            UnitCode::Picometre => "C52",
+            UnitCode::NewtonMetreSecond => "C53",
+            UnitCode::NewtonMetreSquaredPerKilogramSquared => "C54",
+            UnitCode::NewtonPerSquareMetre => "C55",
+            UnitCode::NewtonPerSquareMillimetre => "C56",
+            UnitCode::NewtonSecond => "C57",
+            UnitCode::NewtonSecondPerMetre => "C58",
+            UnitCode::Octave => "C59",
+            UnitCode::OhmCentimetre => "C60",
+            UnitCode::OhmMetre => "C61",
+            UnitCode::One => "C62",
+            UnitCode::Parsec => "C63",
+            UnitCode::PascalPerKelvin => "C64",
+            UnitCode::PascalSecond => "C65",
+            UnitCode::PascalSecondPerCubicMetre => "C66",
+            UnitCode::PascalSecondPerMetre => "C67",
+            UnitCode::Petajoule => "C68",
+            UnitCode::Phon => "C69",
+            UnitCode::Centipoise => "C7",
+            UnitCode::Picoampere => "C70",
+            UnitCode::Picocoulomb => "C71",
+            UnitCode::PicofaradPerMetre => "C72",
+            UnitCode::Picohenry => "C73",
+            UnitCode::KilobitPerSecond => "C74",
+            UnitCode::Picowatt => "C75",
+            UnitCode::PicowattPerSquareMetre => "C76",
+            UnitCode::PoundForce => "C78",
+            UnitCode::KilovoltAmpereHour => "C79",
+            UnitCode::MillicoulombPerKilogram => "C8",
+            UnitCode::Rad => "C80",
+            UnitCode::Radian => "C81",
+            UnitCode::RadianSquareMetrePerMole => "C82",
+            UnitCode::RadianSquareMetrePerKilogram => "C83",
+            UnitCode::RadianPerMetre => "C84",
+            UnitCode::ReciprocalAngstrom => "C85",
+            UnitCode::ReciprocalCubicMetre => "C86",
+            UnitCode::ReciprocalCubicMetrePerSecond => "C87",
+            UnitCode::ReciprocalElectronVoltPerCubicMetre => "C88",
+            UnitCode::ReciprocalHenry => "C89",
+            UnitCode::CoilGroup => "C9",
+            UnitCode::ReciprocalJoulePerCubicMetre => "C90",
+            UnitCode::ReciprocalKelvinOrKelvinToThePowerMinusOne => "C91",
+            UnitCode::ReciprocalMetre => "C92",
+            UnitCode::ReciprocalSquareMetre => "C93",
+            UnitCode::ReciprocalMinute => "C94",
+            UnitCode::ReciprocalMole => "C95",
+            UnitCode::ReciprocalPascalOrPascalToThePowerMinusOne => "C96",
+            UnitCode::ReciprocalSecond => "C97",
+            UnitCode::ReciprocalSecondPerMetreSquared => "C99",
+            UnitCode::CarryingCapacityInMetricTon => "CCT",
+            UnitCode::Candela => "CDL",
+            UnitCode::DegreeCelsius => "CEL",
+            UnitCode::Hundred => "CEN",
+            UnitCode::Card => "CG",
+            UnitCode::Centigram => "CGM",
+            UnitCode::CoulombPerKilogram => "CKG",
+            UnitCode::HundredLeave => "CLF",
+            UnitCode::Centilitre => "CLT",
+            UnitCode::SquareCentimetre => "CMK",
+            UnitCode::CubicCentimetre => "CMQ",
+            UnitCode::Centimetre => "CMT",
+            UnitCode::HundredPack => "CNP",
+            UnitCode::CentalUk => "CNT",
+            UnitCode::Coulomb => "COU",
+            UnitCode::ContentGram => "CTG",
+            UnitCode::MetricCarat => "CTM",
+            UnitCode::ContentTonMetric => "CTN",
+            UnitCode::Curie => "CUR",
+            UnitCode::HundredPoundCwtPerHundredWeightUs => "CWA",
+            UnitCode::HundredWeightUk => "CWI",
+            UnitCode::KilowattHourPerHour => "D03",
+            UnitCode::LotUnitOfWeight => "D04",
+            UnitCode::ReciprocalSecondPerSteradian => "D1",
+            UnitCode::SiemensPerMetre => "D10",
+            UnitCode::Mebibit => "D11",
+            UnitCode::SiemensSquareMetrePerMole => "D12",
+            UnitCode::Sievert => "D13",
+            UnitCode::Sone => "D15",
+            UnitCode::SquareCentimetrePerErg => "D16",
+            UnitCode::SquareCentimetrePerSteradianErg => "D17",
+            UnitCode::MetreKelvin => "D18",
+            UnitCode::SquareMetreKelvinPerWatt => "D19",
+            UnitCode::ReciprocalSecondPerSteradianMetreSquared => "D2",
+            UnitCode::SquareMetrePerJoule => "D20",
+            UnitCode::SquareMetrePerKilogram => "D21",
+            UnitCode::SquareMetrePerMole => "D22",
+            UnitCode::PenGramProtein => "D23",
+            UnitCode::SquareMetrePerSteradian => "D24",
+            UnitCode::SquareMetrePerSteradianJoule => "D25",
+            UnitCode::SquareMetrePerVoltSecond => "D26",
+            UnitCode::Steradian => "D27",
+            UnitCode::Terahertz => "D29",
+            UnitCode::Terajoule => "D30",
+            UnitCode::Terawatt => "D31",
+            UnitCode::TerawattHour => "D32",
+            UnitCode::Tesla => "D33",
+            UnitCode::Tex => "D34",
+            UnitCode::Megabit => "D36",
+            UnitCode::TonnePerCubicMetre => "D41",
+            UnitCode::TropicalYear => "D42",
+            UnitCode::UnifiedAtomicMassUnit => "D43",
+            UnitCode::Var => "D44",
+            UnitCode::VoltSquaredPerKelvinSquared => "D45",
+            UnitCode::VoltAmpere => "D46",
+            UnitCode::VoltPerCentimetre => "D47",
+            UnitCode::VoltPerKelvin => "D48",
+            UnitCode::MillivoltPerKelvin => "D49",
+            UnitCode::KilogramPerSquareCentimetre => "D5",
+            UnitCode::VoltPerMetre => "D50",
+            UnitCode::VoltPerMillimetre => "D51",
+            UnitCode::WattPerKelvin => "D52",
+            UnitCode::WattPerMetreKelvin => "D53",
+            UnitCode::WattPerSquareMetre => "D54",
+            UnitCode::WattPerSquareMetreKelvin => "D55",
+            UnitCode::WattPerSquareMetreKelvinToTheFourthPower => "D56",
+            UnitCode::WattPerSteradian => "D57",
+            UnitCode::WattPerSteradianSquareMetre => "D58",
+            UnitCode::WeberPerMetre => "D59",
+            UnitCode::RoentgenPerSecond => "D6",
+            UnitCode::WeberPerMillimetre => "D60",
+            UnitCode::MinuteUnitOfAngle => "D61",
+            UnitCode::SecondUnitOfAngle => "D62",
+            UnitCode::Book => "D63",
+            UnitCode::Round => "D65",
+            UnitCode::NumberOfWords => "D68",
+            UnitCode::InchToTheFourthPower => "D69",
+            UnitCode::JouleSquareMetre => "D73",
+            UnitCode::KilogramPerMole => "D74",
+            UnitCode::Megacoulomb => "D77",
+            UnitCode::MegajoulePerSecond => "D78",
+            UnitCode::Microwatt => "D80",
+            UnitCode::Microtesla => "D81",
+            UnitCode::Microvolt => "D82",
+            UnitCode::MillinewtonMetre => "D83",
+            UnitCode::MicrowattPerSquareMetre => "D85",
+            UnitCode::Millicoulomb => "D86",
+            UnitCode::MillimolePerKilogram => "D87",
+            UnitCode::MillicoulombPerCubicMetre => "D88",
+            UnitCode::MillicoulombPerSquareMetre => "D89",
+            UnitCode::Rem => "D91",
+            UnitCode::SecondPerCubicMetre => "D93",
+            UnitCode::SecondPerCubicMetreRadian => "D94",
+            UnitCode::JoulePerGram => "D95",
+            UnitCode::Decare => "DAA",
+            UnitCode::TenDay => "DAD",
+            UnitCode::Day => "DAY",
+            UnitCode::DryPound => "DB",
+            UnitCode::DecibelMilliwatts => "DBM",
+            UnitCode::DecibelWatt => "DBW",
+            UnitCode::DiskDisc => "DC",
+            UnitCode::DegreeUnitOfAngle => "DD",
+            UnitCode::Decade => "DEC",
+            UnitCode::Decigram => "DG",
+            UnitCode::Decagram => "DJ",
+            UnitCode::Decilitre => "DLT",
+            UnitCode::CubicDecametre => "DMA",
+            UnitCode::SquareDecimetre => "DMK",
+            UnitCode::StandardKilolitre => "DMO",
+            UnitCode::CubicDecimetre => "DMQ",
+            UnitCode::Decimetre => "DMT",
+            UnitCode::DecinewtonMetre => "DN",
+            UnitCode::DozenPiece => "DPC",
+            UnitCode::DozenPair => "DPR",
+            UnitCode::DisplacementTonnage => "DPT",
+            UnitCode::DramUs => "DRA",
+            UnitCode::DramUk => "DRI",
+            UnitCode::DozenRoll => "DRL",
+            UnitCode::DryTon => "DT",
+            UnitCode::Decitonne => "DTN",
+            UnitCode::Pennyweight => "DWT",
+            UnitCode::Dozen => "DZN",
+            UnitCode::DozenPack => "DZP",
+            UnitCode::NewtonPerSquareCentimetre => "E01",
+            UnitCode::MegawattHourPerHour => "E07",
+            UnitCode::MegawattPerHertz => "E08",
+            UnitCode::MilliampereHour => "E09",
+            UnitCode::DegreeDay => "E10",
+            UnitCode::Mille => "E12",
+            UnitCode::KilocalorieInternationalTable => "E14",
+            UnitCode::KilocalorieThermochemicalPerHour => "E15",
+            UnitCode::MillionBtuItPerHour => "E16",
+            UnitCode::CubicFootPerSecond => "E17",
+            UnitCode::TonnePerHour => "E18",
+            UnitCode::Ping => "E19",
+            UnitCode::MegabitPerSecond => "E20",
+            UnitCode::Shares => "E21",
+            UnitCode::Teu => "E22",
+            UnitCode::Tyre => "E23",
+            UnitCode::ActiveUnit => "E25",
+            UnitCode::Dose => "E27",
+            UnitCode::AirDryTon => "E28",
+            UnitCode::Strand => "E30",
+            UnitCode::SquareMetrePerLitre => "E31",
+            UnitCode::LitrePerHour => "E32",
+            UnitCode::FootPerThousand => "E33",
+            UnitCode::Gigabyte => "E34",
+            UnitCode::Terabyte => "E35",
+            UnitCode::Petabyte => "E36",
+            UnitCode::Pixel => "E37",
+            UnitCode::Megapixels => "E38",
+            UnitCode::DotsPerInch => "E39",
+            UnitCode::GrossKilogram => "E4",
+            UnitCode::PartPerHundredThousand => "E40",
+            UnitCode::KilogramForcePerSquareMillimetre => "E41",
+            UnitCode::KilogramForcePerSquareCentimetre => "E42",
+            UnitCode::JoulePerSquareCentimetre => "E43",
+            UnitCode::KilogramForceMetrePerSquareCentimetre => "E44",
+            UnitCode::Milliohm => "E45",
+            UnitCode::KilowattHourPerCubicMetre => "E46",
+            UnitCode::KilowattHourPerKelvin => "E47",
+            UnitCode::ServiceUnit => "E48",
+            UnitCode::WorkingDay => "E49",
+            UnitCode::AccountingUnit => "E50",
+            UnitCode::Job => "E51",
+            UnitCode::RunFoot => "E52",
+            UnitCode::Test => "E53",
+            UnitCode::Trip => "E54",
+            UnitCode::Use => "E55",
+            UnitCode::Well => "E56",
+            UnitCode::Zone => "E57",
+            UnitCode::ExabitPerSecond => "E58",
+            UnitCode::Exbibyte => "E59",
+            UnitCode::Pebibyte => "E60",
+            UnitCode::Tebibyte => "E61",
+            UnitCode::Gibibyte => "E62",
+            UnitCode::Mebibyte => "E63",
+            UnitCode::Kibibyte => "E64",
+            UnitCode::ExbibitPerMetre => "E65",
+            UnitCode::ExbibitPerSquareMetre => "E66",
+            UnitCode::ExbibitPerCubicMetre => "E67",
+            UnitCode::GigabytePerSecond => "E68",
+            UnitCode::GibibitPerMetre => "E69",
+            UnitCode::GibibitPerSquareMetre => "E70",
+            UnitCode::GibibitPerCubicMetre => "E71",
+            UnitCode::KibibitPerMetre => "E72",
+            UnitCode::KibibitPerSquareMetre => "E73",
+            UnitCode::KibibitPerCubicMetre => "E74",
+            UnitCode::MebibitPerMetre => "E75",
+            UnitCode::MebibitPerSquareMetre => "E76",
+            UnitCode::MebibitPerCubicMetre => "E77",
+            UnitCode::Petabit => "E78",
+            UnitCode::PetabitPerSecond => "E79",
+            UnitCode::PebibitPerMetre => "E80",
+            UnitCode::PebibitPerSquareMetre => "E81",
+            UnitCode::PebibitPerCubicMetre => "E82",
+            UnitCode::Terabit => "E83",
+            UnitCode::TerabitPerSecond => "E84",
+            UnitCode::TebibitPerMetre => "E85",
+            UnitCode::TebibitPerCubicMetre => "E86",
+            UnitCode::TebibitPerSquareMetre => "E87",
+            UnitCode::BitPerMetre => "E88",
+            UnitCode::BitPerSquareMetre => "E89",
+            UnitCode::ReciprocalCentimetre => "E90",
+            UnitCode::ReciprocalDay => "E91",
+            UnitCode::CubicDecimetrePerHour => "E92",
+            UnitCode::KilogramPerHour => "E93",
+            UnitCode::KilomolePerSecond => "E94",
+            UnitCode::MolePerSecond => "E95",
+            UnitCode::DegreePerSecond => "E96",
+            UnitCode::MillimetrePerDegreeCelciusMetre => "E97",
+            UnitCode::DegreeCelsiusPerKelvin => "E98",
+            UnitCode::HectopascalPerBar => "E99",
+            UnitCode::Each => "EA",
+            UnitCode::ElectronicMailBox => "EB",
+            UnitCode::EquivalentGallon => "EQ",
+            UnitCode::BitPerCubicMetre => "F01",
+            UnitCode::KelvinPerKelvin => "F02",
+            UnitCode::KilopascalPerBar => "F03",
+            UnitCode::MillibarPerBar => "F04",
+            UnitCode::MegapascalPerBar => "F05",
+            UnitCode::PoisePerBar => "F06",
+            UnitCode::PascalPerBar => "F07",
+            UnitCode::MilliamperePerInch => "F08",
+            UnitCode::KelvinPerHour => "F10",
+            UnitCode::KelvinPerMinute => "F11",
+            UnitCode::KelvinPerSecond => "F12",
+            UnitCode::Slug => "F13",
+            UnitCode::GramPerKelvin => "F14",
+            UnitCode::KilogramPerKelvin => "F15",
+            UnitCode::MilligramPerKelvin => "F16",
+            UnitCode::PoundForcePerFoot => "F17",
+            UnitCode::KilogramSquareCentimetre => "F18",
+            UnitCode::KilogramSquareMillimetre => "F19",
+            UnitCode::PoundInchSquared => "F20",
+            UnitCode::PoundForceInch => "F21",
+            UnitCode::PoundForceFootPerAmpere => "F22",
+            UnitCode::GramPerCubicDecimetre => "F23",
+            UnitCode::KilogramPerKilomol => "F24",
+            UnitCode::GramPerHertz => "F25",
+            UnitCode::GramPerDay => "F26",
+            UnitCode::GramPerHour => "F27",
+            UnitCode::GramPerMinute => "F28",
+            UnitCode::GramPerSecond => "F29",
+            UnitCode::KilogramPerDay => "F30",
+            UnitCode::KilogramPerMinute => "F31",
+            UnitCode::MilligramPerDay => "F32",
+            UnitCode::MilligramPerMinute => "F33",
+            UnitCode::MilligramPerSecond => "F34",
+            UnitCode::GramPerDayKelvin => "F35",
+            UnitCode::GramPerHourKelvin => "F36",
+            UnitCode::GramPerMinuteKelvin => "F37",
+            UnitCode::GramPerSecondKelvin => "F38",
+            UnitCode::KilogramPerDayKelvin => "F39",
+            UnitCode::KilogramPerHourKelvin => "F40",
+            UnitCode::KilogramPerMinuteKelvin => "F41",
+            UnitCode::KilogramPerSecondKelvin => "F42",
+            UnitCode::MilligramPerDayKelvin => "F43",
+            UnitCode::MilligramPerHourKelvin => "F44",
+            UnitCode::MilligramPerMinuteKelvin => "F45",
+            UnitCode::MilligramPerSecondKelvin => "F46",
+            UnitCode::NewtonPerMillimetre => "F47",
+            UnitCode::PoundForcePerInch => "F48",
+            UnitCode::RodUnitOfDistance => "F49",
+            UnitCode::MicrometrePerKelvin => "F50",
+            UnitCode::CentimetrePerKelvin => "F51",
+            UnitCode::MetrePerKelvin => "F52",
+            UnitCode::MillimetrePerKelvin => "F53",
+            UnitCode::MilliohmPerMetre => "F54",
+            UnitCode::OhmPerMileStatuteMile => "F55",
+            UnitCode::OhmPerKilometre => "F56",
+            UnitCode::MilliamperePerPoundForcePerSquareInch => "F57",
+            UnitCode::ReciprocalBar => "F58",
+            UnitCode::MilliamperePerBar => "F59",
+            UnitCode::DegreeCelsiusPerBar => "F60",
+            UnitCode::KelvinPerBar => "F61",
+            UnitCode::GramPerDayBar => "F62",
+            UnitCode::GramPerHourBar => "F63",
+            UnitCode::GramPerMinuteBar => "F64",
+            UnitCode::GramPerSecondBar => "F65",
+            UnitCode::KilogramPerDayBar => "F66",
+            UnitCode::KilogramPerHourBar => "F67",
+            UnitCode::KilogramPerMinuteBar => "F68",
+            UnitCode::KilogramPerSecondBar => "F69",
+            UnitCode::MilligramPerDayBar => "F70",
+            UnitCode::MilligramPerHourBar => "F71",
+            UnitCode::MilligramPerMinuteBar => "F72",
+            UnitCode::MilligramPerSecondBar => "F73",
+            UnitCode::GramPerBar => "F74",
+            UnitCode::MilligramPerBar => "F75",
+            UnitCode::MilliamperePerMillimetre => "F76",
+            UnitCode::PascalSecondPerKelvin => "F77",
+            UnitCode::InchOfWater => "F78",
+            UnitCode::InchOfMercury => "F79",
+            UnitCode::WaterHorsePower => "F80",
+            UnitCode::BarPerKelvin => "F81",
+            UnitCode::HectopascalPerKelvin => "F82",
+            UnitCode::KilopascalPerKelvin => "F83",
+            UnitCode::MillibarPerKelvin => "F84",
+            UnitCode::MegapascalPerKelvin => "F85",
+            UnitCode::PoisePerKelvin => "F86",
+            UnitCode::VoltPerLitreMinute => "F87",
+            UnitCode::NewtonCentimetre => "F88",
+            UnitCode::NewtonMetrePerDegree => "F89",
+            UnitCode::NewtonPerAmpere => "F90",
+            UnitCode::BarLitrePerSecond => "F91",
+            UnitCode::BarCubicMetrePerSecond => "F92",
+            UnitCode::HectopascalLitrePerSecond => "F93",
+            UnitCode::HectopascalCubicMetrePerSecond => "F94",
+            UnitCode::MillibarLitrePerSecond => "F95",
+            UnitCode::MillibarCubicMetrePerSecond => "F96",
+            UnitCode::MegapascalLitrePerSecond => "F97",
+            UnitCode::MegapascalCubicMetrePerSecond => "F98",
+            UnitCode::PascalLitrePerSecond => "F99",
+            UnitCode::DegreeFahrenheit => "FAH",
+            UnitCode::Farad => "FAR",
+            UnitCode::FibreMetre => "FBM",
+            UnitCode::ThousandCubicFoot => "FC",
+            UnitCode::HundredCubicMetre => "FF",
+            UnitCode::Micromole => "FH",
+            UnitCode::FailuresInTime => "FIT",
+            UnitCode::FlakeTon => "FL",
+            UnitCode::FormazinNephelometricUnit => "FNU",
+            UnitCode::Foot => "FOT",
+            UnitCode::PoundPerSquareFoot => "FP",
+            UnitCode::FootPerMinute => "FR",
+            UnitCode::FootPerSecond => "FS",
+            UnitCode::SquareFoot => "FTK",
+            UnitCode::CubicFoot => "FTQ",
+            UnitCode::PascalCubicMetrePerSecond => "G01",
+            UnitCode::CentimetrePerBar => "G04",
+            UnitCode::MetrePerBar => "G05",
+            UnitCode::MillimetrePerBar => "G06",
+            UnitCode::SquareInchPerSecond => "G08",
+            UnitCode::SquareMetrePerSecondKelvin => "G09",
+            UnitCode::StokesPerKelvin => "G10",
+            UnitCode::GramPerCubicCentimetreBar => "G11",
+            UnitCode::GramPerCubicDecimetreBar => "G12",
+            UnitCode::GramPerLitreBar => "G13",
+            UnitCode::GramPerCubicMetreBar => "G14",
+            UnitCode::GramPerMillilitreBar => "G15",
+            UnitCode::KilogramPerCubicCentimetreBar => "G16",
+            UnitCode::KilogramPerLitreBar => "G17",
+            UnitCode::KilogramPerCubicMetreBar => "G18",
+            UnitCode::NewtonMetrePerKilogram => "G19",
+            UnitCode::UsGallonPerMinute => "G2",
+            UnitCode::PoundForceFootPerPound => "G20",
+            UnitCode::CupUnitOfVolume => "G21",
+            UnitCode::Peck => "G23",
+            UnitCode::TablespoonUs => "G24",
+            UnitCode::TeaspoonUs => "G25",
+            UnitCode::Stere => "G26",
+            UnitCode::CubicCentimetrePerKelvin => "G27",
+            UnitCode::LitrePerKelvin => "G28",
+            UnitCode::CubicMetrePerKelvin => "G29",
+            UnitCode::ImperialGallonPerMinute => "G3",
+            UnitCode::MillilitrePerKelvin => "G30",
+            UnitCode::KilogramPerCubicCentimetre => "G31",
+            UnitCode::OunceAvoirdupoisPerCubicYard => "G32",
+            UnitCode::GramPerCubicCentimetreKelvin => "G33",
+            UnitCode::GramPerCubicDecimetreKelvin => "G34",
+            UnitCode::GramPerLitreKelvin => "G35",
+            UnitCode::GramPerCubicMetreKelvin => "G36",
+            UnitCode::GramPerMillilitreKelvin => "G37",
+            UnitCode::KilogramPerCubicCentimetreKelvin => "G38",
+            UnitCode::KilogramPerLitreKelvin => "G39",
+            UnitCode::KilogramPerCubicMetreKelvin => "G40",
+            UnitCode::SquareMetrePerSecondBar => "G41",
+            UnitCode::MicrosiemensPerCentimetre => "G42",
+            UnitCode::MicrosiemensPerMetre => "G43",
+            UnitCode::NanosiemensPerCentimetre => "G44",
+            UnitCode::NanosiemensPerMetre => "G45",
+            UnitCode::StokesPerBar => "G46",
+            UnitCode::CubicCentimetrePerDay => "G47",
+            UnitCode::CubicCentimetrePerHour => "G48",
+            UnitCode::CubicCentimetrePerMinute => "G49",
+            UnitCode::GallonUsPerHour => "G50",
+            UnitCode::LitrePerSecond => "G51",
+            UnitCode::CubicMetrePerDay => "G52",
+            UnitCode::CubicMetrePerMinute => "G53",
+            UnitCode::MillilitrePerDay => "G54",
+            UnitCode::MillilitrePerHour => "G55",
+            UnitCode::CubicInchPerHour => "G56",
+            UnitCode::CubicInchPerMinute => "G57",
+            UnitCode::CubicInchPerSecond => "G58",
+            UnitCode::MilliamperePerLitreMinute => "G59",
+            UnitCode::VoltPerBar => "G60",
+            UnitCode::CubicCentimetrePerDayKelvin => "G61",
+            UnitCode::CubicCentimetrePerHourKelvin => "G62",
+            UnitCode::CubicCentimetrePerMinuteKelvin => "G63",
+            UnitCode::CubicCentimetrePerSecondKelvin => "G64",
+            UnitCode::LitrePerDayKelvin => "G65",
+            UnitCode::LitrePerHourKelvin => "G66",
+            UnitCode::LitrePerMinuteKelvin => "G67",
+            UnitCode::LitrePerSecondKelvin => "G68",
+            UnitCode::CubicMetrePerDayKelvin => "G69",
+            UnitCode::CubicMetrePerHourKelvin => "G70",
+            UnitCode::CubicMetrePerMinuteKelvin => "G71",
+            UnitCode::CubicMetrePerSecondKelvin => "G72",
+            UnitCode::MillilitrePerDayKelvin => "G73",
+            UnitCode::MillilitrePerHourKelvin => "G74",
+            UnitCode::MillilitrePerMinuteKelvin => "G75",
+            UnitCode::MillilitrePerSecondKelvin => "G76",
+            UnitCode::MillipascalSecondPerKelvin => "G77",
+            UnitCode::CubicCentimetrePerDayBar => "G78",
+            UnitCode::CubicCentimetrePerHourBar => "G79",
+            UnitCode::CubicCentimetrePerMinuteBar => "G80",
+            UnitCode::CubicCentimetrePerSecondBar => "G81",
+            UnitCode::LitrePerDayBar => "G82",
+            UnitCode::LitrePerHourBar => "G83",
+            UnitCode::LitrePerMinuteBar => "G84",
+            UnitCode::LitrePerSecondBar => "G85",
+            UnitCode::CubicMetrePerDayBar => "G86",
+            UnitCode::CubicMetrePerHourBar => "G87",
+            UnitCode::CubicMetrePerMinuteBar => "G88",
+            UnitCode::CubicMetrePerSecondBar => "G89",
+            UnitCode::MillilitrePerDayBar => "G90",
+            UnitCode::MillilitrePerHourBar => "G91",
+            UnitCode::MillilitrePerMinuteBar => "G92",
+            UnitCode::MillilitrePerSecondBar => "G93",
+            UnitCode::CubicCentimetrePerBar => "G94",
+            UnitCode::LitrePerBar => "G95",
+            UnitCode::CubicMetrePerBar => "G96",
+            UnitCode::MillilitrePerBar => "G97",
+            UnitCode::MicrohenryPerKiloohm => "G98",
+            UnitCode::MicrohenryPerOhm => "G99",
+            UnitCode::GallonUsPerDay => "GB",
+            UnitCode::Gigabecquerel => "GBQ",
+            UnitCode::GramDryWeight => "GDW",
+            UnitCode::PoundPerGallonUs => "GE",
+            UnitCode::GramPerMetreGramPer100Centimetres => "GF",
+            UnitCode::GramOfFissileIsotope => "GFI",
+            UnitCode::GreatGross => "GGR",
+            UnitCode::GillUs => "GIA",
+            UnitCode::GramIncludingContainer => "GIC",
+            UnitCode::GillUk => "GII",
+            UnitCode::GramIncludingInnerPackaging => "GIP",
+            UnitCode::GramPerMillilitre => "GJ",
+            UnitCode::GramPerLitre => "GL",
+            UnitCode::DryGallonUs => "GLD",
+            UnitCode::GallonUk => "GLI",
+            UnitCode::GallonUs => "GLL",
+            UnitCode::GramPerSquareMetre => "GM",
+            UnitCode::MilligramPerSquareMetre => "GO",
+            UnitCode::MilligramPerCubicMetre => "GP",
+            UnitCode::MicrogramPerCubicMetre => "GQ",
+            UnitCode::Gram => "GRM",
+            UnitCode::Grain => "GRN",
+            UnitCode::Gross => "GRO",
+            UnitCode::Gigajoule => "GV",
+            UnitCode::GigawattHour => "GWH",
+            UnitCode::HenryPerKiloohm => "H03",
+            UnitCode::HenryPerOhm => "H04",
+            UnitCode::MillihenryPerKiloohm => "H05",
+            UnitCode::MillihenryPerOhm => "H06",
+            UnitCode::PascalSecondPerBar => "H07",
+            UnitCode::Microbecquerel => "H08",
+            UnitCode::ReciprocalYear => "H09",
+            UnitCode::ReciprocalHour => "H10",
+            UnitCode::ReciprocalMonth => "H11",
+            UnitCode::DegreeCelsiusPerHour => "H12",
+            UnitCode::DegreeCelsiusPerMinute => "H13",
+            UnitCode::DegreeCelsiusPerSecond => "H14",
+            UnitCode::SquareCentimetrePerGram => "H15",
+            UnitCode::SquareDecametre => "H16",
+            UnitCode::SquareHectometre => "H18",
+            UnitCode::CubicHectometre => "H19",
+            UnitCode::CubicKilometre => "H20",
+            UnitCode::Blank => "H21",
+            UnitCode::VoltSquareInchPerPoundForce => "H22",
+            UnitCode::VoltPerInch => "H23",
+            UnitCode::VoltPerMicrosecond => "H24",
+            UnitCode::PercentPerKelvin => "H25",
+            UnitCode::OhmPerMetre => "H26",
+            UnitCode::DegreePerMetre => "H27",
+            UnitCode::MicrofaradPerKilometre => "H28",
+            UnitCode::MicrogramPerLitre => "H29",
+            UnitCode::SquareMicrometreSquareMicron => "H30",
+            UnitCode::AmperePerKilogram => "H31",
+            UnitCode::AmpereSquaredSecond => "H32",
+            UnitCode::FaradPerKilometre => "H33",
+            UnitCode::HertzMetre => "H34",
+            UnitCode::KelvinMetrePerWatt => "H35",
+            UnitCode::MegaohmPerKilometre => "H36",
+            UnitCode::MegaohmPerMetre => "H37",
+            UnitCode::Megaampere => "H38",
+            UnitCode::MegahertzKilometre => "H39",
+            UnitCode::NewtonPerAmpereH40 => "H40",
+            UnitCode::NewtonMetreWattToThePowerMinus05 => "H41",
+            UnitCode::PascalPerMetre => "H42",
+            UnitCode::SiemensPerCentimetre => "H43",
+            UnitCode::Teraohm => "H44",
+            UnitCode::VoltSecondPerMetre => "H45",
+            UnitCode::VoltPerSecond => "H46",
+            UnitCode::WattPerCubicMetre => "H47",
+            UnitCode::Attofarad => "H48",
+            UnitCode::CentimetrePerHour => "H49",
+            UnitCode::ReciprocalCubicCentimetre => "H50",
+            UnitCode::DecibelPerKilometre => "H51",
+            UnitCode::DecibelPerMetre => "H52",
+            UnitCode::KilogramPerBar => "H53",
+            UnitCode::KilogramPerCubicDecimetreKelvin => "H54",
+            UnitCode::KilogramPerCubicDecimetreBar => "H55",
+            UnitCode::KilogramPerSquareMetreSecond => "H56",
+            UnitCode::InchPerTwoPiRadiant => "H57",
+            UnitCode::MetrePerVoltSecond => "H58",
+            UnitCode::SquareMetrePerNewton => "H59",
+            UnitCode::CubicMetrePerCubicMetre => "H60",
+            UnitCode::MillisiemensPerCentimetre => "H61",
+            UnitCode::MillivoltPerMinute => "H62",
+            UnitCode::MilligramPerSquareCentimetre => "H63",
+            UnitCode::MilligramPerGram => "H64",
+            UnitCode::MillilitrePerCubicMetre => "H65",
+            UnitCode::MillimetrePerYear => "H66",
+            UnitCode::MillimetrePerHour => "H67",
+            UnitCode::MillimolePerGram => "H68",
+            UnitCode::PicopascalPerKilometre => "H69",
+            UnitCode::Picosecond => "H70",
+            UnitCode::PercentPerMonth => "H71",
+            UnitCode::PercentPerHectobar => "H72",
+            UnitCode::PercentPerDecakelvin => "H73",
+            UnitCode::WattPerMetre => "H74",
+            UnitCode::Decapascal => "H75",
+            UnitCode::GramPerMillimetre => "H76",
+            UnitCode::ModuleWidth => "H77",
+            UnitCode::FrenchGauge => "H79",
+            UnitCode::RackUnit => "H80",
+            UnitCode::MillimetrePerMinute => "H81",
+            UnitCode::BigPoint => "H82",
+            UnitCode::LitrePerKilogram => "H83",
+            UnitCode::GramMillimetre => "H84",
+            UnitCode::ReciprocalWeek => "H85",
             UnitCode::Piece => "H87",
+            UnitCode::MegaohmKilometre => "H88",
+            UnitCode::PercentPerOhm => "H89",
+            UnitCode::PercentPerDegree => "H90",
+            UnitCode::PercentPerTenThousand => "H91",
+            UnitCode::PercentPerOneHundredThousand => "H92",
+            UnitCode::PercentPerHundred => "H93",
+            UnitCode::PercentPerThousand => "H94",
+            UnitCode::PercentPerVolt => "H95",
+            UnitCode::PercentPerBar => "H96",
+            UnitCode::PercentPerInch => "H98",
+            UnitCode::PercentPerMetre => "H99",
+            UnitCode::Hank => "HA",
+            UnitCode::PieceDay => "HAD",
+            UnitCode::Hectare => "HBA",
+            UnitCode::HundredBoxes => "HBX",
+            UnitCode::HundredCount => "HC",
+            UnitCode::HundredKilogramDryWeight => "HDW",
+            UnitCode::Head => "HEA",
+            UnitCode::Hectogram => "HGM",
+            UnitCode::HundredCubicFoot => "HH",
+            UnitCode::HundredInternationalUnit => "HIU",
+            UnitCode::HundredKilogramNetMass => "HKM",
+            UnitCode::Hectolitre => "HLT",
+            UnitCode::MilePerHourStatuteMile => "HM",
+            UnitCode::PieceMonth => "HMO",
+            UnitCode::MillionCubicMetre => "HMQ",
+            UnitCode::Hectometre => "HMT",
+            UnitCode::HectolitreOfPureAlcohol => "HPA",
+            UnitCode::Hertz => "HTZ",
+            UnitCode::Hour => "HUR",
+            UnitCode::PieceWeek => "HWE",
+            UnitCode::InchPoundPoundInch => "IA",
+            UnitCode::Person => "IE",
+            UnitCode::Inch => "INH",
+            UnitCode::SquareInch => "INK",
+            UnitCode::CubicInch => "INQ",
+            UnitCode::InternationalSugarDegree => "ISD",
+            UnitCode::InchPerSecond => "IU",
+            UnitCode::InternationalUnitPerGram => "IUG",
+            UnitCode::InchPerSecondSquared => "IV",
+            UnitCode::PercentPerMillimetre => "J10",
+            UnitCode::PerMillePerPsi => "J12",
+            UnitCode::DegreeApi => "J13",
+            UnitCode::DegreeBaumeOriginScale => "J14",
+            UnitCode::DegreeBaumeUsHeavy => "J15",
+            UnitCode::DegreeBaumeUsLight => "J16",
+            UnitCode::DegreeBalling => "J17",
+            UnitCode::DegreeBrix => "J18",
+            UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitThermochemical => "J19",
+            UnitCode::JoulePerKilogram => "J2",
+            UnitCode::DegreeFahrenheitPerKelvin => "J20",
+            UnitCode::DegreeFahrenheitPerBar => "J21",
+            UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitInternationalTable => "J22",
+            UnitCode::DegreeFahrenheitPerHour => "J23",
+            UnitCode::DegreeFahrenheitPerMinute => "J24",
+            UnitCode::DegreeFahrenheitPerSecond => "J25",
+            UnitCode::ReciprocalDegreeFahrenheit => "J26",
+            UnitCode::DegreeOechsle => "J27",
+            UnitCode::DegreeRankinePerHour => "J28",
+            UnitCode::DegreeRankinePerMinute => "J29",
+            UnitCode::DegreeRankinePerSecond => "J30",
+            UnitCode::DegreeTwaddell => "J31",
+            UnitCode::Micropoise => "J32",
+            UnitCode::MicrogramPerKilogram => "J33",
+            UnitCode::MicrogramPerCubicMetreKelvin => "J34",
+            UnitCode::MicrogramPerCubicMetreBar => "J35",
+            UnitCode::MicrolitrePerLitre => "J36",
+            UnitCode::Baud => "J38",
+            UnitCode::BritishThermalUnitMean => "J39",
+            UnitCode::BritishThermalUnitInternationalTableFootPerHourSquareFootDegreeFahrenheit => "J40",
+            UnitCode::BritishThermalUnitInternationalTableInchPerHourSquareFootDegreeFahrenheit => "J41",
+            UnitCode::BritishThermalUnitInternationalTableInchPerSecondSquareFootDegreeFahrenheit => "J42",
+            UnitCode::BritishThermalUnitInternationalTablePerPoundDegreeFahrenheit => "J43",
+            UnitCode::BritishThermalUnitInternationalTablePerMinute => "J44",
+            UnitCode::BritishThermalUnitInternationalTablePerSecond => "J45",
+            UnitCode::BritishThermalUnitThermochemicalFootPerHourSquareFootDegreeFahrenheit => "J46",
+            UnitCode::BritishThermalUnitThermochemicalPerHour => "J47",
+            UnitCode::BritishThermalUnitThermochemicalInchPerHourSquareFootDegreeFahrenheit => "J48",
+            UnitCode::BritishThermalUnitThermochemicalInchPerSecondSquareFootDegreeFahrenheit => "J49",
+            UnitCode::BritishThermalUnitThermochemicalPerPoundDegreeFahrenheit => "J50",
+            UnitCode::BritishThermalUnitThermochemicalPerMinute => "J51",
+            UnitCode::BritishThermalUnitThermochemicalPerSecond => "J52",
+            UnitCode::CoulombSquareMetrePerKilogram => "J53",
+            UnitCode::Megabaud => "J54",
+            UnitCode::WattSecond => "J55",
+            UnitCode::BarPerBar => "J56",
+            UnitCode::BarrelUkPetroleum => "J57",
+            UnitCode::BarrelUkPetroleumPerMinute => "J58",
+            UnitCode::BarrelUkPetroleumPerDay => "J59",
+            UnitCode::BarrelUkPetroleumPerHour => "J60",
+            UnitCode::BarrelUkPetroleumPerSecond => "J61",
+            UnitCode::BarrelUsPetroleumPerHour => "J62",
+            UnitCode::BarrelUsPetroleumPerSecond => "J63",
+            UnitCode::BushelUkPerDay => "J64",
+            UnitCode::BushelUkPerHour => "J65",
+            UnitCode::BushelUkPerMinute => "J66",
+            UnitCode::BushelUkPerSecond => "J67",
+            UnitCode::BushelUsDryPerDay => "J68",
+            UnitCode::BushelUsDryPerHour => "J69",
+            UnitCode::BushelUsDryPerMinute => "J70",
+            UnitCode::BushelUsDryPerSecond => "J71",
+            UnitCode::CentinewtonMetre => "J72",
+            UnitCode::CentipoisePerKelvin => "J73",
+            UnitCode::CentipoisePerBar => "J74",
+            UnitCode::CalorieMean => "J75",
+            UnitCode::CalorieInternationalTablePerGramDegreeCelsius => "J76",
+            UnitCode::CalorieThermochemicalPerCentimetreSecondDegreeCelsius => "J78",
+            UnitCode::CalorieThermochemicalPerGramDegreeCelsius => "J79",
+            UnitCode::CalorieThermochemicalPerMinute => "J81",
+            UnitCode::CalorieThermochemicalPerSecond => "J82",
+            UnitCode::Clo => "J83",
+            UnitCode::CentimetrePerSecondKelvin => "J84",
+            UnitCode::CentimetrePerSecondBar => "J85",
+            UnitCode::CubicCentimetrePerCubicMetre => "J87",
+            UnitCode::CubicDecimetrePerDay => "J90",
+            UnitCode::CubicDecimetrePerMinute => "J92",
+            UnitCode::CubicDecimetrePerSecond => "J93",
+            UnitCode::OunceUkFluidPerDay => "J95",
+            UnitCode::OunceUkFluidPerHour => "J96",
+            UnitCode::OunceUkFluidPerMinute => "J97",
+            UnitCode::OunceUkFluidPerSecond => "J98",
+            UnitCode::OunceUsFluidPerDay => "J99",
+            UnitCode::JoulePerKelvin => "JE",
+            UnitCode::MegajoulePerKilogram => "JK",
+            UnitCode::MegajoulePerCubicMetre => "JM",
+            UnitCode::PipelineJoint => "JNT",
+            UnitCode::Joule => "JOU",
+            UnitCode::HundredMetre => "JPS",
+            UnitCode::NumberOfJewels => "JWL",
+            UnitCode::KilowattDemand => "K1",
+            UnitCode::OunceUsFluidPerHour => "K10",
+            UnitCode::OunceUsFluidPerMinute => "K11",
+            UnitCode::OunceUsFluidPerSecond => "K12",
+            UnitCode::FootPerDegreeFahrenheit => "K13",
+            UnitCode::FootPerHour => "K14",
+            UnitCode::FootPoundForcePerHour => "K15",
+            UnitCode::FootPoundForcePerMinute => "K16",
+            UnitCode::FootPerPsi => "K17",
+            UnitCode::FootPerSecondDegreeFahrenheit => "K18",
+            UnitCode::FootPerSecondPsi => "K19",
+            UnitCode::KilovoltAmpereReactiveDemand => "K2",
+            UnitCode::ReciprocalCubicFoot => "K20",
+            UnitCode::CubicFootPerDegreeFahrenheit => "K21",
+            UnitCode::CubicFootPerDay => "K22",
+            UnitCode::CubicFootPerPsi => "K23",
+            UnitCode::GallonUkPerDay => "K26",
+            UnitCode::GallonUkPerHour => "K27",
+            UnitCode::GallonUkPerSecond => "K28",
+            UnitCode::KilovoltAmpereReactiveHour => "K3",
+            UnitCode::GallonUsLiquidPerSecond => "K30",
+            UnitCode::GramForcePerSquareCentimetre => "K31",
+            UnitCode::GillUkPerDay => "K32",
+            UnitCode::GillUkPerHour => "K33",
+            UnitCode::GillUkPerMinute => "K34",
+            UnitCode::GillUkPerSecond => "K35",
+            UnitCode::GillUsPerDay => "K36",
+            UnitCode::GillUsPerHour => "K37",
+            UnitCode::GillUsPerMinute => "K38",
+            UnitCode::GillUsPerSecond => "K39",
+            UnitCode::StandardAccelerationOfFreeFall => "K40",
+            UnitCode::GrainPerGallonUs => "K41",
+            UnitCode::HorsepowerBoiler => "K42",
+            UnitCode::HorsepowerElectric => "K43",
+            UnitCode::InchPerDegreeFahrenheit => "K45",
+            UnitCode::InchPerPsi => "K46",
+            UnitCode::InchPerSecondDegreeFahrenheit => "K47",
+            UnitCode::InchPerSecondPsi => "K48",
+            UnitCode::ReciprocalCubicInch => "K49",
+            UnitCode::Kilobaud => "K50",
+            UnitCode::KilocalorieMean => "K51",
+            UnitCode::KilocalorieInternationalTablePerHourMetreDegreeCelsius => "K52",
+            UnitCode::KilocalorieThermochemical => "K53",
+            UnitCode::KilocalorieThermochemicalPerMinute => "K54",
+            UnitCode::KilocalorieThermochemicalPerSecond => "K55",
+            UnitCode::KilomolePerHour => "K58",
+            UnitCode::KilomolePerCubicMetreKelvin => "K59",
+            UnitCode::Kilolitre => "K6",
+            UnitCode::KilomolePerCubicMetreBar => "K60",
+            UnitCode::KilomolePerMinute => "K61",
+            UnitCode::LitrePerLitre => "K62",
+            UnitCode::ReciprocalLitre => "K63",
+            UnitCode::PoundAvoirdupoisPerDegreeFahrenheit => "K64",
+            UnitCode::PoundAvoirdupoisSquareFoot => "K65",
+            UnitCode::PoundAvoirdupoisPerDay => "K66",
+            UnitCode::PoundPerFootHour => "K67",
+            UnitCode::PoundPerFootSecond => "K68",
+            UnitCode::PoundAvoirdupoisPerCubicFootDegreeFahrenheit => "K69",
+            UnitCode::PoundAvoirdupoisPerCubicFootPsi => "K70",
+            UnitCode::PoundAvoirdupoisPerGallonUk => "K71",
+            UnitCode::PoundAvoirdupoisPerHourDegreeFahrenheit => "K73",
+            UnitCode::PoundAvoirdupoisPerHourPsi => "K74",
+            UnitCode::PoundAvoirdupoisPerCubicInchDegreeFahrenheit => "K75",
+            UnitCode::PoundAvoirdupoisPerCubicInchPsi => "K76",
+            UnitCode::PoundAvoirdupoisPerPsi => "K77",
+            UnitCode::PoundAvoirdupoisPerMinute => "K78",
+            UnitCode::PoundAvoirdupoisPerMinuteDegreeFahrenheit => "K79",
+            UnitCode::PoundAvoirdupoisPerMinutePsi => "K80",
+            UnitCode::PoundAvoirdupoisPerSecond => "K81",
+            UnitCode::PoundAvoirdupoisPerSecondDegreeFahrenheit => "K82",
+            UnitCode::PoundAvoirdupoisPerSecondPsi => "K83",
+            UnitCode::PoundPerCubicYard => "K84",
+            UnitCode::PoundForcePerSquareFoot => "K85",
+            UnitCode::PoundForcePerSquareInchDegreeFahrenheit => "K86",
+            UnitCode::PsiCubicInchPerSecond => "K87",
+            UnitCode::PsiLitrePerSecond => "K88",
+            UnitCode::PsiCubicMetrePerSecond => "K89",
+            UnitCode::PsiCubicYardPerSecond => "K90",
+            UnitCode::PoundForceSecondPerSquareFoot => "K91",
+            UnitCode::PoundForceSecondPerSquareInch => "K92",
+            UnitCode::ReciprocalPsi => "K93",
+            UnitCode::QuartUkLiquidPerDay => "K94",
+            UnitCode::QuartUkLiquidPerHour => "K95",
+            UnitCode::QuartUkLiquidPerMinute => "K96",
+            UnitCode::QuartUkLiquidPerSecond => "K97",
+            UnitCode::QuartUsLiquidPerDay => "K98",
+            UnitCode::QuartUsLiquidPerHour => "K99",
+            UnitCode::Cake => "KA",
+            UnitCode::Katal => "KAT",
+            UnitCode::Kilocharacter => "KB",
+            UnitCode::Kilobar => "KBA",
+            UnitCode::KilogramOfCholineChloride => "KCC",
+            UnitCode::KilogramDrainedNetWeight => "KDW",
+            UnitCode::Kelvin => "KEL",
+            UnitCode::Kilogram => "KGM",
+            UnitCode::KilogramPerSecond => "KGS",
+            UnitCode::KilogramOfHydrogenPeroxide => "KHY",
+            UnitCode::Kilohertz => "KHZ",
+            UnitCode::KilogramPerMillimetreWidth => "KI",
+            UnitCode::KilogramIncludingContainer => "KIC",
+            UnitCode::KilogramIncludingInnerPackaging => "KIP",
+            UnitCode::Kilosegment => "KJ",
+            UnitCode::Kilojoule => "KJO",
+            UnitCode::KilogramPerMetre => "KL",
+            UnitCode::LacticDryMaterialPercentage => "KLK",
+            UnitCode::Kilolux => "KLX",
+            UnitCode::KilogramOfMethylamine => "KMA",
+            UnitCode::KilometrePerHour => "KMH",
+            UnitCode::SquareKilometre => "KMK",
+            UnitCode::KilogramPerCubicMetre => "KMQ",
+            UnitCode::Kilometre => "KMT",
+            UnitCode::KilogramOfNitrogen => "KNI",
+            UnitCode::KilonewtonPerSquareMetre => "KNM",
+            UnitCode::KilogramNamedSubstance => "KNS",
+            UnitCode::Knot => "KNT",
+            UnitCode::MilliequivalenceCausticPotashPerGramOfProduct => "KO",
+            UnitCode::Kilopascal => "KPA",
+            UnitCode::KilogramOfPotassiumHydroxideCausticPotash => "KPH",
+            UnitCode::KilogramOfPotassiumOxide => "KPO",
+            UnitCode::KilogramOfPhosphorusPentoxidePhosphoricAnhydride => "KPP",
+            UnitCode::Kiloroentgen => "KR",
+            UnitCode::KilogramOfSubstance90PercentDry => "KSD",
+            UnitCode::KilogramOfSodiumHydroxideCausticSoda => "KSH",
+            UnitCode::Kit => "KT",
+            UnitCode::Kilotonne => "KTN",
+            UnitCode::KilogramOfUranium => "KUR",
+            UnitCode::KilovoltAmpere => "KVA",
+            UnitCode::Kilovar => "KVR",
+            UnitCode::Kilovolt => "KVT",
+            UnitCode::KilogramPerMillimetre => "KW",
+            UnitCode::KilowattHour => "KWH",
+            UnitCode::KilowattHourPerNormalizedCubicMetre => "KWN",
+            UnitCode::KilogramOfTungstenTrioxide => "KWO",
+            UnitCode::KilowattHourPerStandardCubicMetre => "KWS",
+            UnitCode::Kilowatt => "KWT",
+            UnitCode::KilowattYear => "KWY",
+            UnitCode::MillilitrePerKilogram => "KX",
+            UnitCode::QuartUsLiquidPerMinute => "L10",
+            UnitCode::QuartUsLiquidPerSecond => "L11",
+            UnitCode::MetrePerSecondKelvin => "L12",
+            UnitCode::MetrePerSecondBar => "L13",
+            UnitCode::SquareMetreHourDegreeCelsiusPerKilocalorieInternationalTable => "L14",
+            UnitCode::MillipascalSecondPerKelvinL15 => "L15",
+            UnitCode::MillipascalSecondPerBar => "L16",
+            UnitCode::MilligramPerCubicMetreKelvin => "L17",
+            UnitCode::MilligramPerCubicMetreBar => "L18",
+            UnitCode::MillilitrePerLitre => "L19",
+            UnitCode::LitrePerMinute => "L2",
+            UnitCode::ReciprocalCubicMillimetre => "L20",
+            UnitCode::CubicMillimetrePerCubicMetre => "L21",
+            UnitCode::MolePerHour => "L23",
+            UnitCode::MolePerKilogramKelvin => "L24",
+            UnitCode::MolePerKilogramBar => "L25",
+            UnitCode::MolePerLitreKelvin => "L26",
+            UnitCode::MolePerLitreBar => "L27",
+            UnitCode::MolePerCubicMetreKelvin => "L28",
+            UnitCode::MolePerCubicMetreBar => "L29",
+            UnitCode::MolePerMinute => "L30",
+            UnitCode::MilliroentgenAequivalentMen => "L31",
+            UnitCode::NanogramPerKilogram => "L32",
+            UnitCode::OunceAvoirdupoisPerDay => "L33",
+            UnitCode::OunceAvoirdupoisPerHour => "L34",
+            UnitCode::OunceAvoirdupoisPerMinute => "L35",
+            UnitCode::OunceAvoirdupoisPerSecond => "L36",
+            UnitCode::OunceAvoirdupoisPerGallonUk => "L37",
+            UnitCode::OunceAvoirdupoisPerGallonUs => "L38",
+            UnitCode::OunceAvoirdupoisPerCubicInch => "L39",
+            UnitCode::OunceAvoirdupoisForce => "L40",
+            UnitCode::OunceAvoirdupoisForceInch => "L41",
+            UnitCode::PicosiemensPerMetre => "L42",
+            UnitCode::PeckUk => "L43",
+            UnitCode::PeckUkPerDay => "L44",
+            UnitCode::PeckUkPerHour => "L45",
+            UnitCode::PeckUkPerMinute => "L46",
+            UnitCode::PeckUkPerSecond => "L47",
+            UnitCode::PeckUsDryPerDay => "L48",
+            UnitCode::PeckUsDryPerHour => "L49",
+            UnitCode::PeckUsDryPerMinute => "L50",
+            UnitCode::PeckUsDryPerSecond => "L51",
+            UnitCode::PsiPerPsi => "L52",
+            UnitCode::PintUkPerDay => "L53",
+            UnitCode::PintUkPerHour => "L54",
+            UnitCode::PintUkPerMinute => "L55",
+            UnitCode::PintUkPerSecond => "L56",
+            UnitCode::PintUsLiquidPerDay => "L57",
+            UnitCode::PintUsLiquidPerHour => "L58",
+            UnitCode::PintUsLiquidPerMinute => "L59",
+            UnitCode::PintUsLiquidPerSecond => "L60",
+            UnitCode::SlugPerDay => "L63",
+            UnitCode::SlugPerFootSecond => "L64",
+            UnitCode::SlugPerCubicFoot => "L65",
+            UnitCode::SlugPerHour => "L66",
+            UnitCode::SlugPerMinute => "L67",
+            UnitCode::SlugPerSecond => "L68",
+            UnitCode::TonnePerKelvin => "L69",
+            UnitCode::TonnePerBar => "L70",
+            UnitCode::TonnePerDay => "L71",
+            UnitCode::TonnePerDayKelvin => "L72",
+            UnitCode::TonnePerDayBar => "L73",
+            UnitCode::TonnePerHourKelvin => "L74",
+            UnitCode::TonnePerHourBar => "L75",
+            UnitCode::TonnePerCubicMetreKelvin => "L76",
+            UnitCode::TonnePerCubicMetreBar => "L77",
+            UnitCode::TonnePerMinute => "L78",
+            UnitCode::TonnePerMinuteKelvin => "L79",
+            UnitCode::TonnePerMinuteBar => "L80",
+            UnitCode::TonnePerSecond => "L81",
+            UnitCode::TonnePerSecondKelvin => "L82",
+            UnitCode::TonnePerSecondBar => "L83",
+            UnitCode::TonUkShipping => "L84",
+            UnitCode::TonLongPerDay => "L85",
+            UnitCode::TonUsShipping => "L86",
+            UnitCode::TonShortPerDegreeFahrenheit => "L87",
+            UnitCode::TonShortPerDay => "L88",
+            UnitCode::TonShortPerHourDegreeFahrenheit => "L89",
+            UnitCode::TonShortPerHourPsi => "L90",
+            UnitCode::TonShortPerPsi => "L91",
+            UnitCode::TonUkLongPerCubicYard => "L92",
+            UnitCode::TonUsShortPerCubicYard => "L93",
+            UnitCode::TonForceUsShort => "L94",
+            UnitCode::CommonYear => "L95",
+            UnitCode::SiderealYear => "L96",
+            UnitCode::YardPerDegreeFahrenheit => "L98",
+            UnitCode::YardPerPsi => "L99",
+            UnitCode::PoundPerCubicInch => "LA",
+            UnitCode::LactoseExcessPercentage => "LAC",
+            UnitCode::Pound => "LBR",
+            UnitCode::TroyPoundUs => "LBT",
+            UnitCode::LitrePerDay => "LD",
+            UnitCode::Leaf => "LEF",
+            UnitCode::LinearFoot => "LF",
+            UnitCode::LabourHour => "LH",
+            UnitCode::Link => "LK",
+            UnitCode::LinearMetre => "LM",
+            UnitCode::Length => "LN",
+            UnitCode::LotUnitOfProcurement => "LO",
+            UnitCode::LiquidPound => "LP",
+            UnitCode::LitreOfPureAlcohol => "LPA",
+            UnitCode::Layer => "LR",
+            UnitCode::LumpSum => "LS",
+            UnitCode::TonUkOrLongTonUs => "LTN",
+            UnitCode::Litre => "LTR",
+            UnitCode::MetricTonLubricatingOil => "LUB",
+            UnitCode::Lumen => "LUM",
+            UnitCode::Lux => "LUX",
+            UnitCode::LinearYard => "LY",
+            UnitCode::MilligramPerLitre => "M1",
+            UnitCode::ReciprocalCubicYard => "M10",
+            UnitCode::CubicYardPerDegreeFahrenheit => "M11",
+            UnitCode::CubicYardPerDay => "M12",
+            UnitCode::CubicYardPerHour => "M13",
+            UnitCode::CubicYardPerPsi => "M14",
+            UnitCode::CubicYardPerMinute => "M15",
+            UnitCode::CubicYardPerSecond => "M16",
+            UnitCode::KilohertzMetre => "M17",
+            UnitCode::GigahertzMetre => "M18",
+            UnitCode::Beaufort => "M19",
+            UnitCode::ReciprocalMegakelvinOrMegakelvinToThePowerMinusOne => "M20",
+            UnitCode::ReciprocalKilovoltAmpereReciprocalHour => "M21",
+            UnitCode::MillilitrePerSquareCentimetreMinute => "M22",
+            UnitCode::NewtonPerCentimetre => "M23",
+            UnitCode::OhmKilometre => "M24",
+            UnitCode::PercentPerDegreeCelsius => "M25",
+            UnitCode::GigaohmPerMetre => "M26",
+            UnitCode::MegahertzMetre => "M27",
+            UnitCode::KilogramPerKilogram => "M29",
+            UnitCode::ReciprocalVoltAmpereReciprocalSecond => "M30",
+            UnitCode::KilogramPerKilometre => "M31",
+            UnitCode::PascalSecondPerLitre => "M32",
+            UnitCode::MillimolePerLitre => "M33",
+            UnitCode::NewtonMetrePerSquareMetre => "M34",
+            UnitCode::MillivoltAmpere => "M35",
+            UnitCode::ThirtyDayMonth => "M36",
+            UnitCode::ActualPer360 => "M37",
+            UnitCode::KilometrePerSecondSquared => "M38",
+            UnitCode::CentimetrePerSecondSquared => "M39",
+            UnitCode::MonetaryValue => "M4",
+            UnitCode::YardPerSecondSquared => "M40",
+            UnitCode::MillimetrePerSecondSquared => "M41",
+            UnitCode::MileStatuteMilePerSecondSquared => "M42",
+            UnitCode::Mil => "M43",
+            UnitCode::Revolution => "M44",
+            UnitCode::DegreeUnitOfAnglePerSecondSquared => "M45",
+            UnitCode::RevolutionPerMinute => "M46",
+            UnitCode::CircularMil => "M47",
+            UnitCode::SquareMileBasedOnUSSurveyFoot => "M48",
+            UnitCode::ChainBasedOnUSSurveyFoot => "M49",
+            UnitCode::Microcurie => "M5",
+            UnitCode::Furlong => "M50",
+            UnitCode::FootUSSurvey => "M51",
+            UnitCode::MileBasedOnUSSurveyFoot => "M52",
+            UnitCode::MetrePerPascal => "M53",
+            UnitCode::MetrePerRadiant => "M55",
+            UnitCode::Shake => "M56",
+            UnitCode::MilePerMinute => "M57",
+            UnitCode::MilePerSecond => "M58",
+            UnitCode::MetrePerSecondPascal => "M59",
+            UnitCode::MetrePerHour => "M60",
+            UnitCode::InchPerYear => "M61",
+            UnitCode::KilometrePerSecond => "M62",
+            UnitCode::InchPerMinute => "M63",
+            UnitCode::YardPerSecond => "M64",
+            UnitCode::YardPerMinute => "M65",
+            UnitCode::YardPerHour => "M66",
+            UnitCode::AcreFootBasedOnUSSurveyFoot => "M67",
+            UnitCode::Cord128Ft3 => "M68",
+            UnitCode::CubicMileUkStatute => "M69",
+            UnitCode::MicroInch => "M7",
+            UnitCode::TonRegister => "M70",
+            UnitCode::CubicMetrePerPascal => "M71",
+            UnitCode::Bel => "M72",
+            UnitCode::KilogramPerCubicMetrePascal => "M73",
+            UnitCode::KilogramPerPascal => "M74",
+            UnitCode::KilopoundForce => "M75",
+            UnitCode::Poundal => "M76",
+            UnitCode::KilogramMetrePerSecondSquared => "M77",
+            UnitCode::Pond => "M78",
+            UnitCode::SquareFootPerHour => "M79",
+            UnitCode::StokesPerPascal => "M80",
+            UnitCode::SquareCentimetrePerSecond => "M81",
+            UnitCode::SquareMetrePerSecondPascal => "M82",
+            UnitCode::DenierTextile => "M83",
+            UnitCode::PoundPerYard => "M84",
+            UnitCode::TonAssay => "M85",
+            UnitCode::Pfund => "M86",
+            UnitCode::KilogramPerSecondPascal => "M87",
+            UnitCode::TonnePerMonth => "M88",
+            UnitCode::TonnePerYear => "M89",
+            UnitCode::MillionBtuPer1000CubicFoot => "M9",
+            UnitCode::KilopoundPerHour => "M90",
+            UnitCode::PoundPerDay => "M91",
+            UnitCode::PoundForcePerFootTextile => "M92",
+            UnitCode::NewtonMetrePerRadian => "M93",
+            UnitCode::KilogramMetre => "M94",
+            UnitCode::PoundalFoot => "M95",
+            UnitCode::PoundalInch => "M96",
+            UnitCode::DyneMetre => "M97",
+            UnitCode::KilogramCentimetrePerSecond => "M98",
+            UnitCode::GramCentimetrePerSecond => "M99",
+            UnitCode::MegavoltAmpereReactiveHour => "MAH",
+            UnitCode::Megalitre => "MAL",
+            UnitCode::Megametre => "MAM",
+            UnitCode::Megavar => "MAR",
+            UnitCode::Megawatt => "MAW",
+            UnitCode::ThousandStandardBrickEquivalent => "MBE",
+            UnitCode::ThousandBoardFoot => "MBF",
+            UnitCode::Millibar => "MBR",
+            UnitCode::Microgram => "MC",
+            UnitCode::Millicurie => "MCU",
+            UnitCode::AirDryMetricTon => "MD",
+            UnitCode::Milligram => "MGM",
+            UnitCode::Megahertz => "MHZ",
+            UnitCode::SquareMileStatuteMile => "MIK",
+            UnitCode::Thousand => "MIL",
+            UnitCode::MinuteUnitOfTime => "MIN",
+            UnitCode::Million => "MIO",
+            UnitCode::MillionInternationalUnit => "MIU",
+            UnitCode::SquareMetreDay => "MKD",
+            UnitCode::SquareMetreMonth => "MKM",
+            UnitCode::SquareMetreWeek => "MKW",
+            UnitCode::Milliard => "MLD",
+            UnitCode::Millilitre => "MLT",
+            UnitCode::SquareMillimetre => "MMK",
+            UnitCode::CubicMillimetre => "MMQ",
+            UnitCode::Millimetre => "MMT",
+            UnitCode::KilogramDryWeight => "MND",
+            UnitCode::MegaJoulePerNormalisedCubicMetre => "MNJ",
+            UnitCode::Month => "MON",
+            UnitCode::Megapascal => "MPA",
+            UnitCode::CubicMetreDay => "MQD",
+            UnitCode::CubicMetrePerHour => "MQH",
+            UnitCode::CubicMetreMonth => "MQM",
+            UnitCode::CubicMetrePerSecond => "MQS",
+            UnitCode::CubicMetreWeek => "MQW",
+            UnitCode::MetreDay => "MRD",
+            UnitCode::MetreMonth => "MRM",
+            UnitCode::MetreWeek => "MRW",
+            UnitCode::MetrePerSecondSquared => "MSK",
+            UnitCode::SquareMetre => "MTK",
+            UnitCode::CubicMetre => "MTQ",
+            UnitCode::Metre => "MTR",
+            UnitCode::MetrePerSecond => "MTS",
+            UnitCode::Millihertz => "MTZ",
+            UnitCode::MegavoltAmpere => "MVA",
+            UnitCode::MegawattHour1000KwH => "MWH",
+            UnitCode::PenCalorie => "N1",
+            UnitCode::PoundFootPerSecond => "N10",
+            UnitCode::PoundInchPerSecond => "N11",
+            UnitCode::Pferdestaerke => "N12",
+            UnitCode::CentimetreOfMercury0Degc => "N13",
+            UnitCode::CentimetreOfWater4Degc => "N14",
+            UnitCode::FootOfWater392Degf => "N15",
+            UnitCode::InchOfMercury32Degf => "N16",
+            UnitCode::InchOfMercury60Degf => "N17",
+            UnitCode::InchOfWater392Degf => "N18",
+            UnitCode::InchOfWater60Degf => "N19",
+            UnitCode::KipPerSquareInch => "N20",
+            UnitCode::PoundalPerSquareFoot => "N21",
+            UnitCode::OunceAvoirdupoisPerSquareInch => "N22",
+            UnitCode::ConventionalMetreOfWater => "N23",
+            UnitCode::GramPerSquareMillimetre => "N24",
+            UnitCode::PoundPerSquareYard => "N25",
+            UnitCode::PoundalPerSquareInch => "N26",
+            UnitCode::FootToTheFourthPower => "N27",
+            UnitCode::CubicDecimetrePerKilogram => "N28",
+            UnitCode::CubicFootPerPound => "N29",
+            UnitCode::PrintPoint => "N3",
+            UnitCode::CubicInchPerPound => "N30",
+            UnitCode::KilonewtonPerMetre => "N31",
+            UnitCode::PoundalPerInch => "N32",
+            UnitCode::PoundForcePerYard => "N33",
+            UnitCode::PoundalSecondPerSquareFoot => "N34",
+            UnitCode::PoisePerPascal => "N35",
+            UnitCode::NewtonSecondPerSquareMetre => "N36",
+            UnitCode::KilogramPerMetreSecond => "N37",
+            UnitCode::KilogramPerMetreMinute => "N38",
+            UnitCode::KilogramPerMetreDay => "N39",
+            UnitCode::KilogramPerMetreHour => "N40",
+            UnitCode::GramPerCentimetreSecond => "N41",
+            UnitCode::PoundalSecondPerSquareInch => "N42",
+            UnitCode::PoundPerFootMinute => "N43",
+            UnitCode::PoundPerFootDay => "N44",
+            UnitCode::CubicMetrePerSecondPascal => "N45",
+            UnitCode::FootPoundal => "N46",
+            UnitCode::InchPoundal => "N47",
+            UnitCode::WattPerSquareCentimetre => "N48",
+            UnitCode::WattPerSquareInch => "N49",
+            UnitCode::BritishThermalUnitInternationalTablePerSquareFootHour => "N50",
+            UnitCode::BritishThermalUnitThermochemicalPerSquareFootHour => "N51",
+            UnitCode::BritishThermalUnitThermochemicalPerSquareFootMinute => "N52",
+            UnitCode::BritishThermalUnitInternationalTablePerSquareFootSecond => "N53",
+            UnitCode::BritishThermalUnitThermochemicalPerSquareFootSecond => "N54",
+            UnitCode::BritishThermalUnitInternationalTablePerSquareInchSecond => "N55",
+            UnitCode::CalorieThermochemicalPerSquareCentimetreMinute => "N56",
+            UnitCode::CalorieThermochemicalPerSquareCentimetreSecond => "N57",
+            UnitCode::BritishThermalUnitInternationalTablePerCubicFoot => "N58",
+            UnitCode::BritishThermalUnitThermochemicalPerCubicFoot => "N59",
+            UnitCode::BritishThermalUnitInternationalTablePerDegreeFahrenheit => "N60",
+            UnitCode::BritishThermalUnitThermochemicalPerDegreeFahrenheit => "N61",
+            UnitCode::BritishThermalUnitInternationalTablePerDegreeRankine => "N62",
+            UnitCode::BritishThermalUnitThermochemicalPerDegreeRankine => "N63",
+            UnitCode::BritishThermalUnitThermochemicalPerPoundDegreeRankine => "N64",
+            UnitCode::KilocalorieInternationalTablePerGramKelvin => "N65",
+            UnitCode::BritishThermalUnit39Degf => "N66",
+            UnitCode::BritishThermalUnit59Degf => "N67",
+            UnitCode::BritishThermalUnit60Degf => "N68",
+            UnitCode::Calorie20Degc => "N69",
+            UnitCode::Quad1015Btuit => "N70",
+            UnitCode::ThermEc => "N71",
+            UnitCode::ThermUS => "N72",
+            UnitCode::BritishThermalUnitThermochemicalPerPound => "N73",
+            UnitCode::BritishThermalUnitInternationalTablePerHourSquareFootDegreeFahrenheit => "N74",
+            UnitCode::BritishThermalUnitThermochemicalPerHourSquareFootDegreeFahrenheit => "N75",
+            UnitCode::BritishThermalUnitInternationalTablePerSecondSquareFootDegreeFahrenheit => "N76",
+            UnitCode::BritishThermalUnitThermochemicalPerSecondSquareFootDegreeFahrenheit => "N77",
+            UnitCode::KilowattPerSquareMetreKelvin => "N78",
+            UnitCode::KelvinPerPascal => "N79",
+            UnitCode::WattPerMetreDegreeCelsius => "N80",
+            UnitCode::KilowattPerMetreKelvin => "N81",
+            UnitCode::KilowattPerMetreDegreeCelsius => "N82",
+            UnitCode::MetrePerDegreeCelciusMetre => "N83",
+            UnitCode::DegreeFahrenheitHourPerBritishThermalUnitInternationalTable => "N84",
+            UnitCode::DegreeFahrenheitHourPerBritishThermalUnitThermochemical => "N85",
+            UnitCode::DegreeFahrenheitSecondPerBritishThermalUnitInternationalTable => "N86",
+            UnitCode::DegreeFahrenheitSecondPerBritishThermalUnitThermochemical => "N87",
+            UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitInternationalTableInch => "N88",
+            UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitThermochemicalInch => "N89",
+            UnitCode::Kilofarad => "N90",
+            UnitCode::ReciprocalJoule => "N91",
+            UnitCode::Picosiemens => "N92",
+            UnitCode::AmperePerPascal => "N93",
+            UnitCode::Franklin => "N94",
+            UnitCode::AmpereMinute => "N95",
+            UnitCode::Biot => "N96",
+            UnitCode::Gilbert => "N97",
+            UnitCode::VoltPerPascal => "N98",
+            UnitCode::Picovolt => "N99",
+            UnitCode::MilligramPerKilogram => "NA",
+            UnitCode::NumberOfArticles => "NAR",
+            UnitCode::NumberOfCells => "NCL",
+            UnitCode::Newton => "NEW",
+            UnitCode::Message => "NF",
+            UnitCode::Nil => "NIL",
+            UnitCode::NumberOfInternationalUnits => "NIU",
+            UnitCode::Load => "NL",
+            UnitCode::NormalisedCubicMetre => "NM3",
+            UnitCode::NauticalMile => "NMI",
+            UnitCode::NumberOfPacks => "NMP",
+            UnitCode::NumberOfParts => "NPT",
+            UnitCode::NetTon => "NT",
+            UnitCode::NephelometricTurbidityUnit => "NTU",
+            UnitCode::NewtonMetre => "NU",
+            UnitCode::PartPerThousand => "NX",
+            UnitCode::Panel => "OA",
+            UnitCode::OzoneDepletionEquivalent => "ODE",
+            UnitCode::OdsGrams => "ODG",
+            UnitCode::OdsKilograms => "ODK",
+            UnitCode::OdsMilligrams => "ODM",
+            UnitCode::Ohm => "OHM",
+            UnitCode::OuncePerSquareYard => "ON",
+            UnitCode::OunceAvoirdupois => "ONZ",
+            UnitCode::OscillationsPerMinute => "OPM",
+            UnitCode::OvertimeHour => "OT",
+            UnitCode::FluidOunceUs => "OZA",
+            UnitCode::FluidOunceUk => "OZI",
+            UnitCode::Percent => "P1",
+            UnitCode::CoulombPerMetre => "P10",
+            UnitCode::Kiloweber => "P11",
+            UnitCode::Gamma => "P12",
+            UnitCode::Kilotesla => "P13",
+            UnitCode::JoulePerSecond => "P14",
+            UnitCode::JoulePerMinute => "P15",
+            UnitCode::JoulePerHour => "P16",
+            UnitCode::JoulePerDay => "P17",
+            UnitCode::KilojoulePerSecond => "P18",
+            UnitCode::KilojoulePerMinute => "P19",
+            UnitCode::PoundPerFoot => "P2",
+            UnitCode::KilojoulePerHour => "P20",
+            UnitCode::KilojoulePerDay => "P21",
+            UnitCode::Nanoohm => "P22",
+            UnitCode::OhmCircularMilPerFoot => "P23",
+            UnitCode::Kilohenry => "P24",
+            UnitCode::LumenPerSquareFoot => "P25",
+            UnitCode::Phot => "P26",
+            UnitCode::Footcandle => "P27",
+            UnitCode::CandelaPerSquareInch => "P28",
+            UnitCode::Footlambert => "P29",
+            UnitCode::Lambert => "P30",
+            UnitCode::Stilb => "P31",
+            UnitCode::CandelaPerSquareFoot => "P32",
+            UnitCode::Kilocandela => "P33",
+            UnitCode::Millicandela => "P34",
+            UnitCode::HefnerKerze => "P35",
+            UnitCode::InternationalCandle => "P36",
+            UnitCode::BritishThermalUnitInternationalTablePerSquareFoot => "P37",
+            UnitCode::BritishThermalUnitThermochemicalPerSquareFoot => "P38",
+            UnitCode::CalorieThermochemicalPerSquareCentimetre => "P39",
+            UnitCode::Langley => "P40",
+            UnitCode::DecadeLogarithmic => "P41",
+            UnitCode::PascalSquaredSecond => "P42",
+            UnitCode::BelPerMetre => "P43",
+            UnitCode::PoundMole => "P44",
+            UnitCode::PoundMolePerSecond => "P45",
+            UnitCode::PoundMolePerMinute => "P46",
+            UnitCode::KilomolePerKilogram => "P47",
+            UnitCode::PoundMolePerPound => "P48",
+            UnitCode::NewtonSquareMetrePerAmpere => "P49",
+            UnitCode::FivePack => "P5",
+            UnitCode::WeberMetre => "P50",
+            UnitCode::MolPerKilogramPascal => "P51",
+            UnitCode::MolPerCubicMetrePascal => "P52",
+            UnitCode::UnitPole => "P53",
+            UnitCode::MilligrayPerSecond => "P54",
+            UnitCode::MicrograyPerSecond => "P55",
+            UnitCode::NanograyPerSecond => "P56",
+            UnitCode::GrayPerMinute => "P57",
+            UnitCode::MilligrayPerMinute => "P58",
+            UnitCode::MicrograyPerMinute => "P59",
+            UnitCode::NanograyPerMinute => "P60",
+            UnitCode::GrayPerHour => "P61",
+            UnitCode::MilligrayPerHour => "P62",
+            UnitCode::MicrograyPerHour => "P63",
+            UnitCode::NanograyPerHour => "P64",
+            UnitCode::SievertPerSecond => "P65",
+            UnitCode::MillisievertPerSecond => "P66",
+            UnitCode::MicrosievertPerSecond => "P67",
+            UnitCode::NanosievertPerSecond => "P68",
+            UnitCode::RemPerSecond => "P69",
+            UnitCode::SievertPerHour => "P70",
+            UnitCode::MillisievertPerHour => "P71",
+            UnitCode::MicrosievertPerHour => "P72",
+            UnitCode::NanosievertPerHour => "P73",
+            UnitCode::SievertPerMinute => "P74",
+            UnitCode::MillisievertPerMinute => "P75",
+            UnitCode::MicrosievertPerMinute => "P76",
+            UnitCode::NanosievertPerMinute => "P77",
+            UnitCode::ReciprocalSquareInch => "P78",
+            UnitCode::PascalSquareMetrePerKilogram => "P79",
+            UnitCode::MillipascalPerMetre => "P80",
+            UnitCode::KilopascalPerMetre => "P81",
+            UnitCode::HectopascalPerMetre => "P82",
+            UnitCode::StandardAtmospherePerMetre => "P83",
+            UnitCode::TechnicalAtmospherePerMetre => "P84",
+            UnitCode::TorrPerMetre => "P85",
+            UnitCode::PsiPerInch => "P86",
+            UnitCode::CubicMetrePerSecondSquareMetre => "P87",
+            UnitCode::Rhe => "P88",
+            UnitCode::PoundForceFootPerInch => "P89",
+            UnitCode::PoundForceInchPerInch => "P90",
+            UnitCode::Perm0Degc => "P91",
+            UnitCode::Perm23Degc => "P92",
+            UnitCode::BytePerSecond => "P93",
+            UnitCode::KilobytePerSecond => "P94",
+            UnitCode::MegabytePerSecond => "P95",
+            UnitCode::ReciprocalVolt => "P96",
+            UnitCode::ReciprocalRadian => "P97",
+            UnitCode::PascalToThePowerSumOfStoichiometricNumbers => "P98",
+            UnitCode::MolePerCubicMetreToThePowerSumOfStoichiometricNumbers => "P99",
+            UnitCode::Pascal => "PAL",
+            UnitCode::Pad => "PD",
+            UnitCode::ProofLitre => "PFL",
+            UnitCode::ProofGallon => "PGL",
+            UnitCode::Pitch => "PI",
+            UnitCode::DegreePlato => "PLA",
+            UnitCode::PoundPerInchOfLength => "PO",
+            UnitCode::PagePerInch => "PQ",
+            UnitCode::Pair => "PR",
+            UnitCode::PoundForcePerSquareInch => "PS",
+            UnitCode::DryPintUs => "PTD",
+            UnitCode::PintUk => "PTI",
+            UnitCode::LiquidPintUs => "PTL",
+            UnitCode::Portion => "PTN",
+            UnitCode::JoulePerTesla => "Q10",
+            UnitCode::Erlang => "Q11",
+            UnitCode::Octet => "Q12",
+            UnitCode::OctetPerSecond => "Q13",
+            UnitCode::Shannon => "Q14",
+            UnitCode::Hartley => "Q15",
+            UnitCode::NaturalUnitOfInformation => "Q16",
+            UnitCode::ShannonPerSecond => "Q17",
+            UnitCode::HartleyPerSecond => "Q18",
+            UnitCode::NaturalUnitOfInformationPerSecond => "Q19",
+            UnitCode::SecondPerKilogramm => "Q20",
+            UnitCode::WattSquareMetre => "Q21",
+            UnitCode::SecondPerRadianCubicMetre => "Q22",
+            UnitCode::WeberToThePowerMinusOne => "Q23",
+            UnitCode::ReciprocalInch => "Q24",
+            UnitCode::Dioptre => "Q25",
+            UnitCode::OnePerOne => "Q26",
+            UnitCode::NewtonMetrePerMetre => "Q27",
+            UnitCode::KilogramPerSquareMetrePascalSecond => "Q28",
+            UnitCode::MicrogramPerHectogram => "Q29",
+            UnitCode::Meal => "Q3",
+            UnitCode::PhPotentialOfHydrogen => "Q30",
+            UnitCode::KilojoulePerGram => "Q31",
+            UnitCode::Femtolitre => "Q32",
+            UnitCode::Picolitre => "Q33",
+            UnitCode::Nanolitre => "Q34",
+            UnitCode::MegawattsPerMinute => "Q35",
+            UnitCode::SquareMetrePerCubicMetre => "Q36",
+            UnitCode::StandardCubicMetrePerDay => "Q37",
+            UnitCode::StandardCubicMetrePerHour => "Q38",
+            UnitCode::NormalizedCubicMetrePerDay => "Q39",
+            UnitCode::NormalizedCubicMetrePerHour => "Q40",
+            UnitCode::JoulePerNormalisedCubicMetre => "Q41",
+            UnitCode::JoulePerStandardCubicMetre => "Q42",
+            UnitCode::PageFacsimile => "QA",
+            UnitCode::QuarterOfAYear => "QAN",
+            UnitCode::PageHardcopy => "QB",
+            UnitCode::Quire => "QR",
+            UnitCode::DryQuartUs => "QTD",
+            UnitCode::QuartUk => "QTI",
+            UnitCode::LiquidQuartUs => "QTL",
+            UnitCode::QuarterUk => "QTR",
+            UnitCode::Pica => "R1",
+            UnitCode::ThousandCubicMetre => "R9",
+            UnitCode::RunningOrOperatingHour => "RH",
+            UnitCode::Ream => "RM",
+            UnitCode::Room => "ROM",
+            UnitCode::PoundPerReam => "RP",
+            UnitCode::RevolutionsPerMinute => "RPM",
+            UnitCode::RevolutionsPerSecond => "RPS",
+            UnitCode::RevenueTonMile => "RT",
+            UnitCode::SquareFootPerSecond => "S3",
+            UnitCode::SquareMetrePerSecond => "S4",
+            UnitCode::HalfYear6Months => "SAN",
+            UnitCode::Score => "SCO",
+            UnitCode::Scruple => "SCR",
+            UnitCode::SecondUnitOfTime => "SEC",
+            UnitCode::Set => "SET",
+            UnitCode::Segment => "SG",
+            UnitCode::Siemens => "SIE",
+            UnitCode::StandardCubicMetre => "SM3",
+            UnitCode::MileStatuteMile => "SMI",
+            UnitCode::Square => "SQ",
+            UnitCode::SquareRoofing => "SQR",
+            UnitCode::Strip => "SR",
+            UnitCode::Stick => "STC",
+            UnitCode::StoneUk => "STI",
+            UnitCode::StickCigarette => "STK",
+            UnitCode::StandardLitre => "STL",
+            UnitCode::TonUsOrShortTonUkPerUs => "STN",
+            UnitCode::Straw => "STW",
+            UnitCode::Skein => "SW",
+            UnitCode::Shipment => "SX",
+            UnitCode::Syringe => "SYR",
+            UnitCode::TelecommunicationLineInService => "T0",
+            UnitCode::ThousandPiece => "T3",
+            UnitCode::KiloampereHourThousandAmpereHour => "TAH",
+            UnitCode::TotalAcidNumber => "TAN",
+            UnitCode::ThousandSquareInch => "TI",
+            UnitCode::MetricTonIncludingContainer => "TIC",
+            UnitCode::MetricTonIncludingInnerPackaging => "TIP",
+            UnitCode::TonneKilometre => "TKM",
+            UnitCode::KilogramOfImportedMeatLessOffal => "TMS",
+            UnitCode::TonneMetricTon => "TNE",
+            UnitCode::TenPack => "TP",
+            UnitCode::TeethPerInch => "TPI",
+            UnitCode::TenPair => "TPR",
+            UnitCode::ThousandCubicMetrePerDay => "TQD",
+            UnitCode::TrillionEur => "TRL",
+            UnitCode::TenSet => "TST",
+            UnitCode::TenThousandSticks => "TTS",
+            UnitCode::Treatment => "U1",
+            UnitCode::Tablet => "U2",
+            UnitCode::TelecommunicationLineInServiceAverage => "UB",
+            UnitCode::TelecommunicationPort => "UC",
+            UnitCode::VoltAmperePerKilogram => "VA",
+            UnitCode::Volt => "VLT",
+            UnitCode::PercentVolume => "VP",
+            UnitCode::WetKilo => "W2",
+            UnitCode::WattPerKilogram => "WA",
+            UnitCode::WetPound => "WB",
+            UnitCode::Cord => "WCD",
+            UnitCode::WetTon => "WE",
+            UnitCode::Weber => "WEB",
+            UnitCode::Week => "WEE",
+            UnitCode::WineGallon => "WG",
+            UnitCode::WattHour => "WHR",
+            UnitCode::WorkingMonth => "WM",
+            UnitCode::Standard => "WSD",
+            UnitCode::Watt => "WTT",
+            UnitCode::GuntersChain => "X1",
+            UnitCode::SquareYard => "YDK",
+            UnitCode::CubicYard => "YDQ",
+            UnitCode::Yard => "YRD",
+            UnitCode::HangingContainer => "Z11",
+            UnitCode::Nanomole => "Z9",
+            UnitCode::Page => "ZP",
+            UnitCode::MutuallyDefined => "ZZ",
+            UnitCode::DrumSteel => "X1A",
+            UnitCode::DrumAluminium => "X1B",
+            UnitCode::DrumPlywood => "X1D",
+            UnitCode::ContainerFlexible => "X1F",
+            UnitCode::DrumFibre => "X1G",
+            UnitCode::DrumWooden => "X1W",
+            UnitCode::BarrelWooden => "X2C",
+            UnitCode::JerricanSteel => "X3A",
+            UnitCode::JerricanPlastic => "X3H",
+            UnitCode::BagSuperBulk => "X43",
+            UnitCode::BagPolybag => "X44",
+            UnitCode::BoxSteel => "X4A",
+            UnitCode::BoxAluminium => "X4B",
+            UnitCode::BoxNaturalWood => "X4C",
+            UnitCode::BoxPlywood => "X4D",
+            UnitCode::BoxReconstitutedWood => "X4F",
+            UnitCode::BoxFibreboard => "X4G",
+            UnitCode::BoxPlastic => "X4H",
+            UnitCode::BagWovenPlastic => "X5H",
+            UnitCode::BagTextile => "X5L",
+            UnitCode::BagPaper => "X5M",
+            UnitCode::CompositePackagingPlasticReceptacle => "X6H",
+            UnitCode::CompositePackagingGlassReceptacle => "X6P",
+            UnitCode::CaseCar => "X7A",
+            UnitCode::CaseWooden => "X7B",
+            UnitCode::PalletWooden => "X8A",
+            UnitCode::CrateWooden => "X8B",
+            UnitCode::BundleWooden => "X8C",
+            UnitCode::IntermediateBulkContainerRigidPlastic => "XAA",
+            UnitCode::ReceptacleFibre => "XAB",
+            UnitCode::ReceptaclePaper => "XAC",
+            UnitCode::ReceptacleWooden => "XAD",
+            UnitCode::Aerosol => "XAE",
+            UnitCode::PalletModularCollars80cms60cms => "XAF",
+            UnitCode::PalletShrinkwrapped => "XAG",
+            UnitCode::Pallet100cms110cms => "XAH",
+            UnitCode::Clamshell => "XAI",
+            UnitCode::Cone => "XAJ",
+            UnitCode::PackageBall => "XAL",
+            UnitCode::AmpouleNonProtected => "XAM",
+            UnitCode::AmpouleProtected => "XAP",
+            UnitCode::Atomizer => "XAT",
+            UnitCode::Capsule => "XAV",
+            UnitCode::Belt => "XB4",
+            UnitCode::Barrel => "XBA",
+            UnitCode::Bobbin => "XBB",
+            UnitCode::BottlecratePerBottlerack => "XBC",
+            UnitCode::Board => "XBD",
+            UnitCode::Bundle => "XBE",
+            UnitCode::BalloonNonProtected => "XBF",
+            UnitCode::Bag => "XBG",
+            UnitCode::Bunch => "XBH",
+            UnitCode::Bin => "XBI",
+            UnitCode::Bucket => "XBJ",
+            UnitCode::Basket => "XBK",
+            UnitCode::BaleCompressed => "XBL",
+            UnitCode::Basin => "XBM",
+            UnitCode::BaleNonCompressed => "XBN",
+            UnitCode::BottleNonProtectedCylindrical => "XBO",
+            UnitCode::BalloonProtected => "XBP",
+            UnitCode::BottleProtectedCylindrical => "XBQ",
+            UnitCode::Bar => "XBR",
+            UnitCode::BottleNonProtectedBulbous => "XBS",
+            UnitCode::Bolt => "XBT",
+            UnitCode::Butt => "XBU",
+            UnitCode::BottleProtectedBulbous => "XBV",
+            UnitCode::BoxForLiquids => "XBW",
+            UnitCode::Box => "XBX",
+            UnitCode::BoardInBundlePerBunchPerTruss => "XBY",
+            UnitCode::BarsInBundlePerBunchPerTruss => "XBZ",
+            UnitCode::CanRectangular => "XCA",
+            UnitCode::CrateBeer => "XCB",
+            UnitCode::Churn => "XCC",
+            UnitCode::CanWithHandleAndSpout => "XCD",
+            UnitCode::Creel => "XCE",
+            UnitCode::Coffer => "XCF",
+            UnitCode::Cage => "XCG",
+            UnitCode::Chest => "XCH",
+            UnitCode::Canister => "XCI",
+            UnitCode::Coffin => "XCJ",
+            UnitCode::Cask => "XCK",
+            UnitCode::Coil => "XCL",
+            UnitCode::PackageCard => "XCM",
+            UnitCode::ContainerNotOtherwiseSpecifiedAsTransportEquipment => "XCN",
+            UnitCode::CarboyNonProtected => "XCO",
+            UnitCode::CarboyProtected => "XCP",
+            UnitCode::Cartridge => "XCQ",
+            UnitCode::Crate => "XCR",
+            UnitCode::Case => "XCS",
+            UnitCode::Carton => "XCT",
+            UnitCode::Cup => "XCU",
+            UnitCode::Cover => "XCV",
+            UnitCode::CageRoll => "XCW",
+            UnitCode::CanCylindrical => "XCX",
+            UnitCode::Cylinder => "XCY",
+            UnitCode::Canvas => "XCZ",
+            UnitCode::CrateMultipleLayerPlastic => "XDA",
+            UnitCode::CrateMultipleLayerWooden => "XDB",
+            UnitCode::CrateMultipleLayerCardboard => "XDC",
+            UnitCode::CageCommonwealthHandlingEquipmentPoolChep => "XDG",
+            UnitCode::BoxCommonwealthHandlingEquipmentPoolChepEurobox => "XDH",
+            UnitCode::DrumIron => "XDI",
+            UnitCode::DemijohnNonProtected => "XDJ",
+            UnitCode::CrateBulkCardboard => "XDK",
+            UnitCode::CrateBulkPlastic => "XDL",
+            UnitCode::CrateBulkWooden => "XDM",
+            UnitCode::Dispenser => "XDN",
+            UnitCode::DemijohnProtected => "XDP",
+            UnitCode::Drum => "XDR",
+            UnitCode::TrayOneLayerNoCoverPlastic => "XDS",
+            UnitCode::TrayOneLayerNoCoverWooden => "XDT",
+            UnitCode::TrayOneLayerNoCoverPolystyrene => "XDU",
+            UnitCode::TrayOneLayerNoCoverCardboard => "XDV",
+            UnitCode::TrayTwoLayersNoCoverPlasticTray => "XDW",
+            UnitCode::TrayTwoLayersNoCoverWooden => "XDX",
+            UnitCode::TrayTwoLayersNoCoverCardboard => "XDY",
+            UnitCode::BagPlastic => "XEC",
+            UnitCode::CaseWithPalletBase => "XED",
+            UnitCode::CaseWithPalletBaseWooden => "XEE",
+            UnitCode::CaseWithPalletBaseCardboard => "XEF",
+            UnitCode::CaseWithPalletBasePlastic => "XEG",
+            UnitCode::CaseWithPalletBaseMetal => "XEH",
+            UnitCode::CaseIsothermic => "XEI",
+            UnitCode::Envelope => "XEN",
+            UnitCode::Flexibag => "XFB",
+            UnitCode::CrateFruit => "XFC",
+            UnitCode::CrateFramed => "XFD",
+            UnitCode::Flexitank => "XFE",
+            UnitCode::Firkin => "XFI",
+            UnitCode::Flask => "XFL",
+            UnitCode::Footlocker => "XFO",
+            UnitCode::Filmpack => "XFP",
+            UnitCode::Frame => "XFR",
+            UnitCode::Foodtainer => "XFT",
+            UnitCode::CartFlatbed => "XFW",
+            UnitCode::BagFlexibleContainer => "XFX",
+            UnitCode::BottleGas => "XGB",
+            UnitCode::Girder => "XGI",
+            UnitCode::ContainerGallon => "XGL",
+            UnitCode::ReceptacleGlass => "XGR",
+            UnitCode::TrayContainingHorizontallyStackedFlatItems => "XGU",
+            UnitCode::BagGunny => "XGY",
+            UnitCode::GirdersInBundlePerBunchPerTruss => "XGZ",
+            UnitCode::BasketWithHandlePlastic => "XHA",
+            UnitCode::BasketWithHandleWooden => "XHB",
+            UnitCode::BasketWithHandleCardboard => "XHC",
+            UnitCode::Hogshead => "XHG",
+            UnitCode::Hanger => "XHN",
+            UnitCode::Hamper => "XHR",
+            UnitCode::PackageDisplayWooden => "XIA",
+            UnitCode::PackageDisplayCardboard => "XIB",
+            UnitCode::PackageDisplayPlastic => "XIC",
+            UnitCode::PackageDisplayMetal => "XID",
+            UnitCode::PackageShow => "XIE",
+            UnitCode::PackageFlow => "XIF",
+            UnitCode::PackagePaperWrapped => "XIG",
+            UnitCode::DrumPlastic => "XIH",
+            UnitCode::PackageCardboardWithBottleGripHoles => "XIK",
+            UnitCode::TrayRigidLiddedStackableCenTs144822002 => "XIL",
+            UnitCode::Ingot => "XIN",
+            UnitCode::IngotsInBundlePerBunchPerTruss => "XIZ",
+            UnitCode::BagJumbo => "XJB",
+            UnitCode::JerricanRectangular => "XJC",
+            UnitCode::Jug => "XJG",
+            UnitCode::Jar => "XJR",
+            UnitCode::Jutebag => "XJT",
+            UnitCode::JerricanCylindrical => "XJY",
+            UnitCode::Keg => "XKG",
+            UnitCode::PackageKit => "XKI",
+            UnitCode::Luggage => "XLE",
+            UnitCode::Log => "XLG",
+            UnitCode::Lot => "XLT",
+            UnitCode::Lug => "XLU",
+            UnitCode::Liftvan => "XLV",
+            UnitCode::LogsInBundlePerBunchPerTruss => "XLZ",
+            UnitCode::CrateMetal => "XMA",
+            UnitCode::BagMultiply => "XMB",
+            UnitCode::CrateMilk => "XMC",
+            UnitCode::ContainerMetal => "XME",
+            UnitCode::ReceptacleMetal => "XMR",
+            UnitCode::SackMultiWall => "XMS",
+            UnitCode::Mat => "XMT",
+            UnitCode::ReceptaclePlasticWrapped => "XMW",
+            UnitCode::Matchbox => "XMX",
+            UnitCode::NotAvailable => "XNA",
+            UnitCode::UnpackedOrUnpackaged => "XNE",
+            UnitCode::UnpackedOrUnpackagedSingleUnit => "XNF",
+            UnitCode::UnpackedOrUnpackagedMultipleUnits => "XNG",
+            UnitCode::Nest => "XNS",
+            UnitCode::Net => "XNT",
+            UnitCode::NetTubePlastic => "XNU",
+            UnitCode::NetTubeTextile => "XNV",
+            UnitCode::TwoSidedCageOnWheelsWithFixingStrap => "XO1",
+            UnitCode::Trolley => "XO2",
+            UnitCode::OnewayPalletIso01Per2EuroPallet => "XO3",
+            UnitCode::OnewayPalletIso11Per1EuroPallet => "XO4",
+            UnitCode::OnewayPalletIso22Per1EuroPallet => "XO5",
+            UnitCode::PalletWithExceptionalDimensions => "XO6",
+            UnitCode::WoodenPallet40CmX80Cm => "XO7",
+            UnitCode::PlasticPalletSrs60CmX80Cm => "XO8",
+            UnitCode::PlasticPalletSrs80CmX120Cm => "XO9",
+            UnitCode::PalletChep40CmX60Cm => "XOA",
+            UnitCode::PalletChep80CmX120Cm => "XOB",
+            UnitCode::PalletChep100CmX120Cm => "XOC",
+            UnitCode::PalletAs40681993 => "XOD",
+            UnitCode::PalletIsoT11 => "XOE",
+            UnitCode::PlatformUnspecifiedWeightOrDimension => "XOF",
+            UnitCode::PalletIso01Per2EuroPallet => "XOG",
+            UnitCode::PalletIso11Per1EuroPallet => "XOH",
+            UnitCode::PalletIso22Per1EuroPallet => "XOI",
+            UnitCode::QuarterEuroPallet => "XOJ",
+            UnitCode::Block => "XOK",
+            UnitCode::EighthEuroPallet => "XOL",
+            UnitCode::SyntheticPalletIso1 => "XOM",
+            UnitCode::SyntheticPalletIso2 => "XON",
+            UnitCode::WholesalerPallet => "XOP",
+            UnitCode::Pallet80X100Cm => "XOQ",
+            UnitCode::Pallet60X100Cm => "XOR",
+            UnitCode::OnewayPallet => "XOS",
+            UnitCode::Octabin => "XOT",
+            UnitCode::ContainerOuter => "XOU",
+            UnitCode::ReturnablePallet => "XOV",
+            UnitCode::LargeBagPalletSized => "XOW",
+            UnitCode::AWheeledPalletWithRaisedRim81X67X135 => "XOX",
+            UnitCode::AWheeledPalletWithRaisedRim81X72X135 => "XOY",
+            UnitCode::WheeledPalletWithRaisedRim81X60X16 => "XOZ",
+            UnitCode::ChepPallet60CmX80Cm => "XP1",
+            UnitCode::Pan => "XP2",
+            UnitCode::LprPallet60CmX80Cm => "XP3",
+            UnitCode::LprPallet80CmX120Cm => "XP4",
+            UnitCode::Packet => "XPA",
+            UnitCode::PalletBoxCombinedOpenEndedBoxAndPallet => "XPB",
+            UnitCode::Parcel => "XPC",
+            UnitCode::PalletModularCollars80cms100cms => "XPD",
+            UnitCode::PalletModularCollars80cms120cms => "XPE",
+            UnitCode::Pen => "XPF",
+            UnitCode::Plate => "XPG",
+            UnitCode::Pitcher => "XPH",
+            UnitCode::Pipe => "XPI",
+            UnitCode::Punnet => "XPJ",
+            UnitCode::Package => "XPK",
+            UnitCode::Pail => "XPL",
+            UnitCode::Plank => "XPN",
+            UnitCode::Pouch => "XPO",
+            UnitCode::PackagePiece => "XPP",
+            UnitCode::ReceptaclePlastic => "XPR",
+            UnitCode::Pot => "XPT",
+            UnitCode::Tray => "XPU",
+            UnitCode::PipesInBundlePerBunchPerTruss => "XPV",
+            UnitCode::Pallet => "XPX",
+            UnitCode::PlatesInBundlePerBunchPerTruss => "XPY",
+            UnitCode::PlanksInBundlePerBunchPerTruss => "XPZ",
+            UnitCode::DrumSteelNonRemovableHead => "XQA",
+            UnitCode::DrumSteelRemovableHead => "XQB",
+            UnitCode::DrumAluminiumNonRemovableHead => "XQC",
+            UnitCode::DrumAluminiumRemovableHead => "XQD",
+            UnitCode::DrumPlasticNonRemovableHead => "XQF",
+            UnitCode::DrumPlasticRemovableHead => "XQG",
+            UnitCode::BarrelWoodenBungType => "XQH",
+            UnitCode::BarrelWoodenRemovableHead => "XQJ",
+            UnitCode::JerricanSteelNonRemovableHead => "XQK",
+            UnitCode::JerricanSteelRemovableHead => "XQL",
+            UnitCode::JerricanPlasticNonRemovableHead => "XQM",
+            UnitCode::JerricanPlasticRemovableHead => "XQN",
+            UnitCode::BoxWoodenNaturalWoodOrdinary => "XQP",
+            UnitCode::BoxWoodenNaturalWoodWithSiftProofWalls => "XQQ",
+            UnitCode::BoxPlasticExpanded => "XQR",
+            UnitCode::BoxPlasticSolid => "XQS",
+            UnitCode::Rod => "XRD",
+            UnitCode::Ring => "XRG",
+            UnitCode::RackClothingHanger => "XRJ",
+            UnitCode::Rack => "XRK",
+            UnitCode::Reel => "XRL",
+            UnitCode::Roll => "XRO",
+            UnitCode::Rednet => "XRT",
+            UnitCode::RodsInBundlePerBunchPerTruss => "XRZ",
+            UnitCode::Sack => "XSA",
+            UnitCode::Slab => "XSB",
+            UnitCode::CrateShallow => "XSC",
+            UnitCode::Spindle => "XSD",
+            UnitCode::SeaChest => "XSE",
+            UnitCode::Sachet => "XSH",
+            UnitCode::Skid => "XSI",
+            UnitCode::CaseSkeleton => "XSK",
+            UnitCode::Slipsheet => "XSL",
+            UnitCode::Sheetmetal => "XSM",
+            UnitCode::Spool => "XSO",
+            UnitCode::SheetPlasticWrapping => "XSP",
+            UnitCode::CaseSteel => "XSS",
+            UnitCode::Sheet => "XST",
+            UnitCode::Suitcase => "XSU",
+            UnitCode::EnvelopeSteel => "XSV",
+            UnitCode::Shrinkwrapped => "XSW",
+            UnitCode::PackageSet => "XSX",
+            UnitCode::Sleeve => "XSY",
+            UnitCode::SheetsInBundlePerBunchPerTruss => "XSZ",
+            UnitCode::PackageTablet => "XT1",
+            UnitCode::Tub => "XTB",
+            UnitCode::TeaChest => "XTC",
+            UnitCode::CollapsibleTube => "XTD",
+            UnitCode::PackageTyre => "XTE",
+            UnitCode::TankContainerGeneric => "XTG",
+            UnitCode::Tierce => "XTI",
+            UnitCode::TankRectangular => "XTK",
+            UnitCode::TubWithLid => "XTL",
+            UnitCode::Tin => "XTN",
+            UnitCode::Tun => "XTO",
+            UnitCode::Trunk => "XTR",
+            UnitCode::Truss => "XTS",
+            UnitCode::BagTote => "XTT",
+            UnitCode::Tube => "XTU",
+            UnitCode::TubeWithNozzle => "XTV",
+            UnitCode::PalletTriwall => "XTW",
+            UnitCode::TankCylindrical => "XTY",
+            UnitCode::TubesInBundlePerBunchPerTruss => "XTZ",
+            UnitCode::Uncaged => "XUC",
+            UnitCode::Unit => "XUN",
+            UnitCode::Vat => "XVA",
+            UnitCode::BulkGasAt1031MbarAnd15Degc => "XVG",
+            UnitCode::Vial => "XVI",
+            UnitCode::Vanpack => "XVK",
+            UnitCode::BulkLiquid => "XVL",
+            UnitCode::Vehicle => "XVN",
+            UnitCode::BulkSolidLargeParticlesNodules => "XVO",
+            UnitCode::VacuumPacked => "XVP",
+            UnitCode::BulkLiquefiedGasAtAbnormalTemperaturePerPressure => "XVQ",
+            UnitCode::BulkSolidGranularParticlesGrains => "XVR",
+            UnitCode::BulkScrapMetal => "XVS",
+            UnitCode::BulkSolidFineParticlesPowders => "XVY",
+            UnitCode::IntermediateBulkContainer => "XWA",
+            UnitCode::Wickerbottle => "XWB",
+            UnitCode::IntermediateBulkContainerSteel => "XWC",
+            UnitCode::IntermediateBulkContainerAluminium => "XWD",
+            UnitCode::IntermediateBulkContainerMetal => "XWF",
+            UnitCode::IntermediateBulkContainerSteelPressurisedOver10Kpa => "XWG",
+            UnitCode::IntermediateBulkContainerAluminiumPressurisedOver10Kpa => "XWH",
+            UnitCode::IntermediateBulkContainerMetalPressure10Kpa => "XWJ",
+            UnitCode::IntermediateBulkContainerSteelLiquid => "XWK",
+            UnitCode::IntermediateBulkContainerAluminiumLiquid => "XWL",
+            UnitCode::IntermediateBulkContainerMetalLiquid => "XWM",
+            UnitCode::IntermediateBulkContainerWovenPlasticWithoutCoatPerLiner => "XWN",
+            UnitCode::IntermediateBulkContainerWovenPlasticCoated => "XWP",
+            UnitCode::IntermediateBulkContainerWovenPlasticWithLiner => "XWQ",
+            UnitCode::IntermediateBulkContainerWovenPlasticCoatedAndLiner => "XWR",
+            UnitCode::IntermediateBulkContainerPlasticFilm => "XWS",
+            UnitCode::IntermediateBulkContainerTextileWithOutCoatPerLiner => "XWT",
+            UnitCode::IntermediateBulkContainerNaturalWoodWithInnerLiner => "XWU",
+            UnitCode::IntermediateBulkContainerTextileCoated => "XWV",
+            UnitCode::IntermediateBulkContainerTextileWithLiner => "XWW",
+            UnitCode::IntermediateBulkContainerTextileCoatedAndLiner => "XWX",
+            UnitCode::IntermediateBulkContainerPlywoodWithInnerLiner => "XWY",
+            UnitCode::IntermediateBulkContainerReconstitutedWoodWithInnerLiner => "XWZ",
+            UnitCode::BagWovenPlasticWithoutInnerCoatPerLiner => "XXA",
+            UnitCode::BagWovenPlasticSiftProof => "XXB",
+            UnitCode::BagWovenPlasticWaterResistant => "XXC",
+            UnitCode::BagPlasticsFilm => "XXD",
+            UnitCode::BagTextileWithoutInnerCoatPerLiner => "XXF",
+            UnitCode::BagTextileSiftProof => "XXG",
+            UnitCode::BagTextileWaterResistant => "XXH",
+            UnitCode::BagPaperMultiWall => "XXJ",
+            UnitCode::BagPaperMultiWallWaterResistant => "XXK",
+            UnitCode::CompositePackagingPlasticReceptacleInSteelDrum => "XYA",
+            UnitCode::CompositePackagingPlasticReceptacleInSteelCrateBox => "XYB",
+            UnitCode::CompositePackagingPlasticReceptacleInAluminiumDrum => "XYC",
+            UnitCode::CompositePackagingPlasticReceptacleInAluminiumCrate => "XYD",
+            UnitCode::CompositePackagingPlasticReceptacleInWoodenBox => "XYF",
+            UnitCode::CompositePackagingPlasticReceptacleInPlywoodDrum => "XYG",
+            UnitCode::CompositePackagingPlasticReceptacleInPlywoodBox => "XYH",
+            UnitCode::CompositePackagingPlasticReceptacleInFibreDrum => "XYJ",
+            UnitCode::CompositePackagingPlasticReceptacleInFibreboardBox => "XYK",
+            UnitCode::CompositePackagingPlasticReceptacleInPlasticDrum => "XYL",
+            UnitCode::CompositePackagingPlasticReceptacleInSolidPlasticBox => "XYM",
+            UnitCode::CompositePackagingGlassReceptacleInSteelDrum => "XYN",
+            UnitCode::CompositePackagingGlassReceptacleInSteelCrateBox => "XYP",
+            UnitCode::CompositePackagingGlassReceptacleInAluminiumDrum => "XYQ",
+            UnitCode::CompositePackagingGlassReceptacleInAluminiumCrate => "XYR",
+            UnitCode::CompositePackagingGlassReceptacleInWoodenBox => "XYS",
+            UnitCode::CompositePackagingGlassReceptacleInPlywoodDrum => "XYT",
+            UnitCode::CompositePackagingGlassReceptacleInWickerworkHamper => "XYV",
+            UnitCode::CompositePackagingGlassReceptacleInFibreDrum => "XYW",
+            UnitCode::CompositePackagingGlassReceptacleInFibreboardBox => "XYX",
+            UnitCode::CompositePackagingGlassReceptacleInExpandablePlasticPack => "XYY",
+            UnitCode::CompositePackagingGlassReceptacleInSolidPlasticPack => "XYZ",
+            UnitCode::IntermediateBulkContainerPaperMultiWall => "XZA",
+            UnitCode::BagLarge => "XZB",
+            UnitCode::IntermediateBulkContainerPaperMultiWallWaterResistant => "XZC",
+            UnitCode::IntermediateBulkContainerRigidPlasticWithStructuralEquipmentSolids => "XZD",
+            UnitCode::IntermediateBulkContainerRigidPlasticFreestandingSolids => "XZF",
+            UnitCode::IntermediateBulkContainerRigidPlasticWithStructuralEquipmentPressurised => "XZG",
+            UnitCode::IntermediateBulkContainerRigidPlasticFreestandingPressurised => "XZH",
+            UnitCode::IntermediateBulkContainerRigidPlasticWithStructuralEquipmentLiquids => "XZJ",
+            UnitCode::IntermediateBulkContainerRigidPlasticFreestandingLiquids => "XZK",
+            UnitCode::IntermediateBulkContainerCompositeRigidPlasticSolids => "XZL",
+            UnitCode::IntermediateBulkContainerCompositeFlexiblePlasticSolids => "XZM",
+            UnitCode::IntermediateBulkContainerCompositeRigidPlasticPressurised => "XZN",
+            UnitCode::IntermediateBulkContainerCompositeFlexiblePlasticPressurised => "XZP",
+            UnitCode::IntermediateBulkContainerCompositeRigidPlasticLiquids => "XZQ",
+            UnitCode::IntermediateBulkContainerCompositeFlexiblePlasticLiquids => "XZR",
+            UnitCode::IntermediateBulkContainerComposite => "XZS",
+            UnitCode::IntermediateBulkContainerFibreboard => "XZT",
+            UnitCode::IntermediateBulkContainerFlexible => "XZU",
+            UnitCode::IntermediateBulkContainerMetalOtherThanSteel => "XZV",
+            UnitCode::IntermediateBulkContainerNaturalWood => "XZW",
+            UnitCode::IntermediateBulkContainerPlywood => "XZX",
+            UnitCode::IntermediateBulkContainerReconstitutedWood => "XZY",
+            UnitCode::PackageMutuallyDefined => "XZZ",
+        }
+    }
+
+    /// Human readable name of the unit
+    pub fn name(&self) -> &str {
+        match self {
+            UnitCode::Group => "Group",
+            UnitCode::Outfit => "Outfit",
+            UnitCode::Ration => "Ration",
+            UnitCode::Shot => "Shot",
+            UnitCode::StickMilitary => "Stick, military",
+            UnitCode::TwentyFootContainer => "Twenty foot container",
+            UnitCode::FortyFootContainer => "Forty foot container",
+            UnitCode::DecilitrePerGram => "Decilitre per gram",
+            UnitCode::GramPerCubicCentimetre => "Gram per cubic centimetre",
+            UnitCode::TheoreticalPound => "Theoretical pound",
+            UnitCode::GramPerSquareCentimetre => "Gram per square centimetre",
+            UnitCode::TheoreticalTon => "Theoretical ton",
+            UnitCode::KilogramPerSquareMetre => "Kilogram per square metre",
+            UnitCode::KilopascalSquareMetrePerGram => "Kilopascal square metre per gram",
+            UnitCode::KilopascalPerMillimetre => "Kilopascal per millimetre",
+            UnitCode::MillilitrePerSquareCentimetreSecond => "Millilitre per square centimetre second",
+            UnitCode::OuncePerSquareFoot => "Ounce per square foot",
+            UnitCode::OuncePerSquareFootPer001inch => "Ounce per square foot per 0,01inch",
+            UnitCode::MillilitrePerSecond => "Millilitre per second",
+            UnitCode::MillilitrePerMinute => "Millilitre per minute",
+            UnitCode::Sitas => "Sitas",
+            UnitCode::Mesh => "Mesh",
+            UnitCode::NetKilogram => "Net kilogram",
+            UnitCode::PartPerMillion => "Part per million",
+            UnitCode::PercentWeight => "Percent weight",
+            UnitCode::PartPerBillionUs => "Part per billion (US)",
+            UnitCode::Millipascal => "Millipascal",
+            UnitCode::MilliInch => "Milli-inch",
+            UnitCode::PoundPerSquareInchAbsolute => "Pound per square inch absolute",
+            UnitCode::Henry => "Henry",
+            UnitCode::FootPoundForce => "Foot pound-force",
+            UnitCode::PoundPerCubicFoot => "Pound per cubic foot",
+            UnitCode::Poise => "Poise",
+            UnitCode::Stokes => "Stokes",
+            UnitCode::FixedRate => "Fixed rate",
+            UnitCode::RadianPerSecond => "Radian per second",
+            UnitCode::RadianPerSecondSquared => "Radian per second squared",
+            UnitCode::Roentgen => "Roentgen",
+            UnitCode::VoltAc => "Volt AC",
+            UnitCode::VoltDc => "Volt DC",
+            UnitCode::BritishThermalUnitInternationalTablePerHour => "British thermal unit (international table) per hour",
+            UnitCode::CubicCentimetrePerSecond => "Cubic centimetre per second",
+            UnitCode::CubicFootPerHour => "Cubic foot per hour",
+            UnitCode::CubicFootPerMinute => "Cubic foot per minute",
+            UnitCode::CentimetrePerSecond => "Centimetre per second",
+            UnitCode::Decibel => "Decibel",
+            UnitCode::Kilobyte => "Kilobyte",
+            UnitCode::Kilobecquerel => "Kilobecquerel",
+            UnitCode::Kilocurie => "Kilocurie",
+            UnitCode::Megagram => "Megagram",
+            UnitCode::MetrePerMinute => "Metre per minute",
+            UnitCode::Milliroentgen => "Milliroentgen",
+            UnitCode::Millivolt => "Millivolt",
+            UnitCode::Megajoule => "Megajoule",
+            UnitCode::Manmonth => "Manmonth",
+            UnitCode::Centistokes => "Centistokes",
+            UnitCode::Microlitre => "Microlitre",
+            UnitCode::MicrometreMicron => "Micrometre (micron)",
+            UnitCode::Milliampere => "Milliampere",
+            UnitCode::Megabyte => "Megabyte",
+            UnitCode::MilligramPerHour => "Milligram per hour",
+            UnitCode::Megabecquerel => "Megabecquerel",
+            UnitCode::Microfarad => "Microfarad",
+            UnitCode::NewtonPerMetre => "Newton per metre",
+            UnitCode::OunceInch => "Ounce inch",
+            UnitCode::OunceFoot => "Ounce foot",
+            UnitCode::Picofarad => "Picofarad",
+            UnitCode::PoundPerHour => "Pound per hour",
+            UnitCode::TonUsPerHour => "Ton (US) per hour",
+            UnitCode::KilolitrePerHour => "Kilolitre per hour",
+            UnitCode::BarrelUsPerMinute => "Barrel (US) per minute",
+            UnitCode::Batch => "Batch",
+            UnitCode::MmscfPerDay => "MMSCF/day",
+            UnitCode::HydraulicHorsePower => "Hydraulic horse power",
+            UnitCode::AmpereSquareMetrePerJouleSecond => "Ampere square metre per joule second",
+            UnitCode::Angstrom => "Angstrom",
+            UnitCode::AstronomicalUnit => "Astronomical unit",
+            UnitCode::Attojoule => "Attojoule",
+            UnitCode::Barn => "Barn",
+            UnitCode::BarnPerElectronvolt => "Barn per electronvolt",
+            UnitCode::BarnPerSteradianElectronvolt => "Barn per steradian electronvolt",
+            UnitCode::BarnPerSteradian => "Barn per steradian",
+            UnitCode::BecquerelPerKilogram => "Becquerel per kilogram",
+            UnitCode::BecquerelPerCubicMetre => "Becquerel per cubic metre",
+            UnitCode::AmperePerCentimetre => "Ampere per centimetre",
+            UnitCode::BritishThermalUnitInternationalTablePerSecondSquareFootDegreeRankine => "British thermal unit (international table) per second square foot degree Rankine",
+            UnitCode::BritishThermalUnitInternationalTablePerPoundDegreeRankine => "British thermal unit (international table) per pound degree Rankine",
+            UnitCode::BritishThermalUnitInternationalTablePerSecondFootDegreeRankine => "British thermal unit (international table) per second foot degree Rankine",
+            UnitCode::BritishThermalUnitInternationalTablePerHourSquareFootDegreeRankine => "British thermal unit (international table) per hour square foot degree Rankine",
+            UnitCode::CandelaPerSquareMetre => "Candela per square metre",
+            UnitCode::CoulombMetre => "Coulomb metre",
+            UnitCode::CoulombMetreSquaredPerVolt => "Coulomb metre squared per volt",
+            UnitCode::CoulombPerCubicCentimetre => "Coulomb per cubic centimetre",
+            UnitCode::CoulombPerCubicMetre => "Coulomb per cubic metre",
+            UnitCode::AmperePerMillimetre => "Ampere per millimetre",
+            UnitCode::CoulombPerCubicMillimetre => "Coulomb per cubic millimetre",
+            UnitCode::CoulombPerKilogramSecond => "Coulomb per kilogram second",
+            UnitCode::CoulombPerMole => "Coulomb per mole",
+            UnitCode::CoulombPerSquareCentimetre => "Coulomb per square centimetre",
+            UnitCode::CoulombPerSquareMetre => "Coulomb per square metre",
+            UnitCode::CoulombPerSquareMillimetre => "Coulomb per square millimetre",
+            UnitCode::CubicCentimetrePerMole => "Cubic centimetre per mole",
+            UnitCode::CubicDecimetrePerMole => "Cubic decimetre per mole",
+            UnitCode::CubicMetrePerCoulomb => "Cubic metre per coulomb",
+            UnitCode::CubicMetrePerKilogram => "Cubic metre per kilogram",
+            UnitCode::AmperePerSquareCentimetre => "Ampere per square centimetre",
+            UnitCode::CubicMetrePerMole => "Cubic metre per mole",
+            UnitCode::AmperePerSquareMetre => "Ampere per square metre",
+            UnitCode::CuriePerKilogram => "Curie per kilogram",
+            UnitCode::DeadweightTonnage => "Deadweight tonnage",
+            UnitCode::Decalitre => "Decalitre",
+            UnitCode::Decametre => "Decametre",
+            UnitCode::Decitex => "Decitex",
+            UnitCode::DegreeRankine => "Degree Rankine",
+            UnitCode::Denier => "Denier",
+            UnitCode::AmpereSquareMetre => "Ampere square metre",
+            UnitCode::Electronvolt => "Electronvolt",
+            UnitCode::ElectronvoltPerMetre => "Electronvolt per metre",
+            UnitCode::ElectronvoltSquareMetre => "Electronvolt square metre",
+            UnitCode::ElectronvoltSquareMetrePerKilogram => "Electronvolt square metre per kilogram",
+            UnitCode::EightPartCloudCover => "8-part cloud cover",
+            UnitCode::AmperePerSquareMetreKelvinSquared => "Ampere per square metre kelvin squared",
+            UnitCode::Exajoule => "Exajoule",
+            UnitCode::FaradPerMetre => "Farad per metre",
+            UnitCode::AmperePerSquareMillimetre => "Ampere per square millimetre",
+            UnitCode::Femtojoule => "Femtojoule",
+            UnitCode::Femtometre => "Femtometre",
+            UnitCode::FootPerSecondSquared => "Foot per second squared",
+            UnitCode::FootPoundForcePerSecond => "Foot pound-force per second",
+            UnitCode::FreightTon => "Freight ton",
+            UnitCode::Gal => "Gal",
+            UnitCode::AmpereSecond => "Ampere second",
+            UnitCode::GigacoulombPerCubicMetre => "Gigacoulomb per cubic metre",
+            UnitCode::Gigaelectronvolt => "Gigaelectronvolt",
+            UnitCode::Gigahertz => "Gigahertz",
+            UnitCode::Gigaohm => "Gigaohm",
+            UnitCode::GigaohmMetre => "Gigaohm metre",
+            UnitCode::Gigapascal => "Gigapascal",
+            UnitCode::Rate => "Rate",
+            UnitCode::Gigawatt => "Gigawatt",
+            UnitCode::Gon => "Gon",
+            UnitCode::GramPerCubicMetre => "Gram per cubic metre",
+            UnitCode::GramPerMole => "Gram per mole",
+            UnitCode::Gray => "Gray",
+            UnitCode::GrayPerSecond => "Gray per second",
+            UnitCode::Hectopascal => "Hectopascal",
+            UnitCode::HenryPerMetre => "Henry per metre",
+            UnitCode::Bit => "Bit",
+            UnitCode::Ball => "Ball",
+            UnitCode::BulkPack => "Bulk pack",
+            UnitCode::Acre => "Acre",
+            UnitCode::Activity => "Activity",
+            UnitCode::Byte => "Byte",
+            UnitCode::AmperePerMetre => "Ampere per metre",
+            UnitCode::AdditionalMinute => "Additional minute",
+            UnitCode::AverageMinutePerCall => "Average minute per call",
+            UnitCode::Fathom => "Fathom",
+            UnitCode::AccessLine => "Access line",
+            UnitCode::AmpereHour => "Ampere hour",
+            UnitCode::Ampere => "Ampere",
+            UnitCode::Year => "Year",
+            UnitCode::TroyOunceOrApothecaryOunce => "Troy ounce or apothecary ounce",
+            UnitCode::AntiHemophilicFactorAhfUnit => "Anti-hemophilic factor (AHF) unit",
+            UnitCode::Assortment => "Assortment",
+            UnitCode::AlcoholicStrengthByMass => "Alcoholic strength by mass",
+            UnitCode::AlcoholicStrengthByVolume => "Alcoholic strength by volume",
+            UnitCode::StandardAtmosphere => "Standard atmosphere",
+            UnitCode::AmericanWireGauge => "American wire gauge",
+            UnitCode::Assembly => "Assembly",
+            UnitCode::BritishThermalUnitInternationalTablePerPound => "British thermal unit (international table) per pound",
+            UnitCode::BarrelUsPerDay => "Barrel (US) per day",
+            UnitCode::BitPerSecond => "Bit per second",
+            UnitCode::JoulePerKilogramKelvin => "Joule per kilogram kelvin",
+            UnitCode::JoulePerMetre => "Joule per metre",
+            UnitCode::JoulePerSquareMetre => "Joule per square metre",
+            UnitCode::JoulePerMetreToTheFourthPower => "Joule per metre to the fourth power",
+            UnitCode::JoulePerMole => "Joule per mole",
+            UnitCode::JoulePerMoleKelvin => "Joule per mole kelvin",
+            UnitCode::Credit => "Credit",
+            UnitCode::JouleSecond => "Joule second",
+            UnitCode::Digit => "Digit",
+            UnitCode::JouleSquareMetrePerKilogram => "Joule square metre per kilogram",
+            UnitCode::KelvinPerWatt => "Kelvin per watt",
+            UnitCode::Kiloampere => "Kiloampere",
+            UnitCode::KiloamperePerSquareMetre => "Kiloampere per square metre",
+            UnitCode::KiloamperePerMetre => "Kiloampere per metre",
+            UnitCode::KilobecquerelPerKilogram => "Kilobecquerel per kilogram",
+            UnitCode::Kilocoulomb => "Kilocoulomb",
+            UnitCode::KilocoulombPerCubicMetre => "Kilocoulomb per cubic metre",
+            UnitCode::KilocoulombPerSquareMetre => "Kilocoulomb per square metre",
+            UnitCode::Kiloelectronvolt => "Kiloelectronvolt",
+            UnitCode::BattingPound => "Batting pound",
+            UnitCode::Gibibit => "Gibibit",
+            UnitCode::KilogramMetrePerSecond => "Kilogram metre per second",
+            UnitCode::KilogramMetreSquared => "Kilogram metre squared",
+            UnitCode::KilogramMetreSquaredPerSecond => "Kilogram metre squared per second",
+            UnitCode::KilogramPerCubicDecimetre => "Kilogram per cubic decimetre",
+            UnitCode::KilogramPerLitre => "Kilogram per litre",
+            UnitCode::BarrelImperial => "Barrel, imperial",
+            UnitCode::KilojoulePerKelvin => "Kilojoule per kelvin",
+            UnitCode::KilojoulePerKilogram => "Kilojoule per kilogram",
+            UnitCode::KilojoulePerKilogramKelvin => "Kilojoule per kilogram kelvin",
+            UnitCode::KilojoulePerMole => "Kilojoule per mole",
+            UnitCode::Kilomole => "Kilomole",
+            UnitCode::KilomolePerCubicMetre => "Kilomole per cubic metre",
+            UnitCode::Kilonewton => "Kilonewton",
+            UnitCode::KilonewtonMetre => "Kilonewton metre",
+            UnitCode::Kiloohm => "Kiloohm",
+            UnitCode::KiloohmMetre => "Kiloohm metre",
+            UnitCode::Kilosecond => "Kilosecond",
+            UnitCode::Kilosiemens => "Kilosiemens",
+            UnitCode::KilosiemensPerMetre => "Kilosiemens per metre",
+            UnitCode::KilovoltPerMetre => "Kilovolt per metre",
+            UnitCode::KiloweberPerMetre => "Kiloweber per metre",
+            UnitCode::LightYear => "Light year",
+            UnitCode::LitrePerMole => "Litre per mole",
+            UnitCode::LumenHour => "Lumen hour",
+            UnitCode::LumenPerSquareMetre => "Lumen per square metre",
+            UnitCode::LumenPerWatt => "Lumen per watt",
+            UnitCode::LumenSecond => "Lumen second",
+            UnitCode::LuxHour => "Lux hour",
+            UnitCode::LuxSecond => "Lux second",
+            UnitCode::MegaamperePerSquareMetre => "Megaampere per square metre",
+            UnitCode::MegabecquerelPerKilogram => "Megabecquerel per kilogram",
+            UnitCode::Gigabit => "Gigabit",
+            UnitCode::MegacoulombPerCubicMetre => "Megacoulomb per cubic metre",
+            UnitCode::Cycle => "Cycle",
+            UnitCode::MegacoulombPerSquareMetre => "Megacoulomb per square metre",
+            UnitCode::Megaelectronvolt => "Megaelectronvolt",
+            UnitCode::MegagramPerCubicMetre => "Megagram per cubic metre",
+            UnitCode::Meganewton => "Meganewton",
+            UnitCode::MeganewtonMetre => "Meganewton metre",
+            UnitCode::Megaohm => "Megaohm",
+            UnitCode::MegaohmMetre => "Megaohm metre",
+            UnitCode::MegasiemensPerMetre => "Megasiemens per metre",
+            UnitCode::Megavolt => "Megavolt",
+            UnitCode::MegavoltPerMetre => "Megavolt per metre",
+            UnitCode::JoulePerCubicMetre => "Joule per cubic metre",
+            UnitCode::GigabitPerSecond => "Gigabit per second",
+            UnitCode::ReciprocalMetreSquaredReciprocalSecond => "Reciprocal metre squared reciprocal second",
+            UnitCode::InchPerLinearFoot => "Inch per linear foot",
+            UnitCode::MetreToTheFourthPower => "Metre to the fourth power",
+            UnitCode::Microampere => "Microampere",
+            UnitCode::Microbar => "Microbar",
+            UnitCode::Microcoulomb => "Microcoulomb",
+            UnitCode::MicrocoulombPerCubicMetre => "Microcoulomb per cubic metre",
+            UnitCode::MicrocoulombPerSquareMetre => "Microcoulomb per square metre",
+            UnitCode::MicrofaradPerMetre => "Microfarad per metre",
+            UnitCode::Microhenry => "Microhenry",
+            UnitCode::MicrohenryPerMetre => "Microhenry per metre",
+            UnitCode::Micronewton => "Micronewton",
+            UnitCode::MicronewtonMetre => "Micronewton metre",
+            UnitCode::Microohm => "Microohm",
+            UnitCode::MicroohmMetre => "Microohm metre",
+            UnitCode::Micropascal => "Micropascal",
+            UnitCode::Microradian => "Microradian",
+            UnitCode::Microsecond => "Microsecond",
+            UnitCode::Microsiemens => "Microsiemens",
+            UnitCode::BarUnitOfPressure => "Bar [unit of pressure]",
+            UnitCode::BaseBox => "Base box",
+            UnitCode::BoardFoot => "Board foot",
+            UnitCode::BrakeHorsePower => "Brake horse power",
+            UnitCode::BillionEur => "Billion (EUR)",
+            UnitCode::DryBarrelUs => "Dry barrel (US)",
+            UnitCode::BarrelUs => "Barrel (US)",
+            UnitCode::HundredBoardFoot => "Hundred board foot",
+            UnitCode::BeatsPerMinute => "Beats per minute",
+            UnitCode::Becquerel => "Becquerel",
+            UnitCode::BritishThermalUnitInternationalTable => "British thermal unit (international table)",
+            UnitCode::BushelUs => "Bushel (US)",
+            UnitCode::BushelUk => "Bushel (UK)",
+            UnitCode::Call => "Call",
+            UnitCode::Millifarad => "Millifarad",
+            UnitCode::Milligal => "Milligal",
+            UnitCode::MilligramPerMetre => "Milligram per metre",
+            UnitCode::Milligray => "Milligray",
+            UnitCode::Millihenry => "Millihenry",
+            UnitCode::Millijoule => "Millijoule",
+            UnitCode::MillimetrePerSecond => "Millimetre per second",
+            UnitCode::MillimetreSquaredPerSecond => "Millimetre squared per second",
+            UnitCode::Millimole => "Millimole",
+            UnitCode::MolePerKilogram => "Mole per kilogram",
+            UnitCode::Millinewton => "Millinewton",
+            UnitCode::Kibibit => "Kibibit",
+            UnitCode::MillinewtonPerMetre => "Millinewton per metre",
+            UnitCode::MilliohmMetre => "Milliohm metre",
+            UnitCode::MillipascalSecond => "Millipascal second",
+            UnitCode::Milliradian => "Milliradian",
+            UnitCode::Millisecond => "Millisecond",
+            UnitCode::Millisiemens => "Millisiemens",
+            UnitCode::Millisievert => "Millisievert",
+            UnitCode::Millitesla => "Millitesla",
+            UnitCode::MicrovoltPerMetre => "Microvolt per metre",
+            UnitCode::MillivoltPerMetre => "Millivolt per metre",
+            UnitCode::Milliwatt => "Milliwatt",
+            UnitCode::MilliwattPerSquareMetre => "Milliwatt per square metre",
+            UnitCode::Milliweber => "Milliweber",
+            UnitCode::Mole => "Mole",
+            UnitCode::MolePerCubicDecimetre => "Mole per cubic decimetre",
+            UnitCode::MolePerCubicMetre => "Mole per cubic metre",
+            UnitCode::Kilobit => "Kilobit",
+            UnitCode::MolePerLitre => "Mole per litre",
+            UnitCode::Nanoampere => "Nanoampere",
+            UnitCode::Nanocoulomb => "Nanocoulomb",
+            UnitCode::Nanofarad => "Nanofarad",
+            UnitCode::NanofaradPerMetre => "Nanofarad per metre",
+            UnitCode::Nanohenry => "Nanohenry",
+            UnitCode::NanohenryPerMetre => "Nanohenry per metre",
+            UnitCode::Nanometre => "Nanometre",
+            UnitCode::NanoohmMetre => "Nanoohm metre",
+            UnitCode::Nanosecond => "Nanosecond",
+            UnitCode::Nanotesla => "Nanotesla",
+            UnitCode::Nanowatt => "Nanowatt",
+            UnitCode::Neper => "Neper",
+            UnitCode::NeperPerSecond => "Neper per second",
+            UnitCode::Picometre => "Picometre",
+            UnitCode::NewtonMetreSecond => "Newton metre second",
+            UnitCode::NewtonMetreSquaredPerKilogramSquared => "Newton metre squared per kilogram squared",
+            UnitCode::NewtonPerSquareMetre => "Newton per square metre",
+            UnitCode::NewtonPerSquareMillimetre => "Newton per square millimetre",
+            UnitCode::NewtonSecond => "Newton second",
+            UnitCode::NewtonSecondPerMetre => "Newton second per metre",
+            UnitCode::Octave => "Octave",
+            UnitCode::OhmCentimetre => "Ohm centimetre",
+            UnitCode::OhmMetre => "Ohm metre",
+            UnitCode::One => "One",
+            UnitCode::Parsec => "Parsec",
+            UnitCode::PascalPerKelvin => "Pascal per kelvin",
+            UnitCode::PascalSecond => "Pascal second",
+            UnitCode::PascalSecondPerCubicMetre => "Pascal second per cubic metre",
+            UnitCode::PascalSecondPerMetre => "Pascal second per metre",
+            UnitCode::Petajoule => "Petajoule",
+            UnitCode::Phon => "Phon",
+            UnitCode::Centipoise => "Centipoise",
+            UnitCode::Picoampere => "Picoampere",
+            UnitCode::Picocoulomb => "Picocoulomb",
+            UnitCode::PicofaradPerMetre => "Picofarad per metre",
+            UnitCode::Picohenry => "Picohenry",
+            UnitCode::KilobitPerSecond => "Kilobit per second",
+            UnitCode::Picowatt => "Picowatt",
+            UnitCode::PicowattPerSquareMetre => "Picowatt per square metre",
+            UnitCode::PoundForce => "Pound-force",
+            UnitCode::KilovoltAmpereHour => "Kilovolt ampere hour",
+            UnitCode::MillicoulombPerKilogram => "Millicoulomb per kilogram",
+            UnitCode::Rad => "Rad",
+            UnitCode::Radian => "Radian",
+            UnitCode::RadianSquareMetrePerMole => "Radian square metre per mole",
+            UnitCode::RadianSquareMetrePerKilogram => "Radian square metre per kilogram",
+            UnitCode::RadianPerMetre => "Radian per metre",
+            UnitCode::ReciprocalAngstrom => "Reciprocal angstrom",
+            UnitCode::ReciprocalCubicMetre => "Reciprocal cubic metre",
+            UnitCode::ReciprocalCubicMetrePerSecond => "Reciprocal cubic metre per second",
+            UnitCode::ReciprocalElectronVoltPerCubicMetre => "Reciprocal electron volt per cubic metre",
+            UnitCode::ReciprocalHenry => "Reciprocal henry",
+            UnitCode::CoilGroup => "Coil group",
+            UnitCode::ReciprocalJoulePerCubicMetre => "Reciprocal joule per cubic metre",
+            UnitCode::ReciprocalKelvinOrKelvinToThePowerMinusOne => "Reciprocal kelvin or kelvin to the power minus one",
+            UnitCode::ReciprocalMetre => "Reciprocal metre",
+            UnitCode::ReciprocalSquareMetre => "Reciprocal square metre",
+            UnitCode::ReciprocalMinute => "Reciprocal minute",
+            UnitCode::ReciprocalMole => "Reciprocal mole",
+            UnitCode::ReciprocalPascalOrPascalToThePowerMinusOne => "Reciprocal pascal or pascal to the power minus one",
+            UnitCode::ReciprocalSecond => "Reciprocal second",
+            UnitCode::ReciprocalSecondPerMetreSquared => "Reciprocal second per metre squared",
+            UnitCode::CarryingCapacityInMetricTon => "Carrying capacity in metric ton",
+            UnitCode::Candela => "Candela",
+            UnitCode::DegreeCelsius => "Degree Celsius",
+            UnitCode::Hundred => "Hundred",
+            UnitCode::Card => "Card",
+            UnitCode::Centigram => "Centigram",
+            UnitCode::CoulombPerKilogram => "Coulomb per kilogram",
+            UnitCode::HundredLeave => "Hundred leave",
+            UnitCode::Centilitre => "Centilitre",
+            UnitCode::SquareCentimetre => "Square centimetre",
+            UnitCode::CubicCentimetre => "Cubic centimetre",
+            UnitCode::Centimetre => "Centimetre",
+            UnitCode::HundredPack => "Hundred pack",
+            UnitCode::CentalUk => "Cental (UK)",
+            UnitCode::Coulomb => "Coulomb",
+            UnitCode::ContentGram => "Content gram",
+            UnitCode::MetricCarat => "Metric carat",
+            UnitCode::ContentTonMetric => "Content ton (metric)",
+            UnitCode::Curie => "Curie",
+            UnitCode::HundredPoundCwtPerHundredWeightUs => "Hundred pound (cwt) / hundred weight (US)",
+            UnitCode::HundredWeightUk => "Hundred weight (UK)",
+            UnitCode::KilowattHourPerHour => "Kilowatt hour per hour",
+            UnitCode::LotUnitOfWeight => "Lot [unit of weight]",
+            UnitCode::ReciprocalSecondPerSteradian => "Reciprocal second per steradian",
+            UnitCode::SiemensPerMetre => "Siemens per metre",
+            UnitCode::Mebibit => "Mebibit",
+            UnitCode::SiemensSquareMetrePerMole => "Siemens square metre per mole",
+            UnitCode::Sievert => "Sievert",
+            UnitCode::Sone => "Sone",
+            UnitCode::SquareCentimetrePerErg => "Square centimetre per erg",
+            UnitCode::SquareCentimetrePerSteradianErg => "Square centimetre per steradian erg",
+            UnitCode::MetreKelvin => "Metre kelvin",
+            UnitCode::SquareMetreKelvinPerWatt => "Square metre kelvin per watt",
+            UnitCode::ReciprocalSecondPerSteradianMetreSquared => "Reciprocal second per steradian metre squared",
+            UnitCode::SquareMetrePerJoule => "Square metre per joule",
+            UnitCode::SquareMetrePerKilogram => "Square metre per kilogram",
+            UnitCode::SquareMetrePerMole => "Square metre per mole",
+            UnitCode::PenGramProtein => "Pen gram (protein)",
+            UnitCode::SquareMetrePerSteradian => "Square metre per steradian",
+            UnitCode::SquareMetrePerSteradianJoule => "Square metre per steradian joule",
+            UnitCode::SquareMetrePerVoltSecond => "Square metre per volt second",
+            UnitCode::Steradian => "Steradian",
+            UnitCode::Terahertz => "Terahertz",
+            UnitCode::Terajoule => "Terajoule",
+            UnitCode::Terawatt => "Terawatt",
+            UnitCode::TerawattHour => "Terawatt hour",
+            UnitCode::Tesla => "Tesla",
+            UnitCode::Tex => "Tex",
+            UnitCode::Megabit => "Megabit",
+            UnitCode::TonnePerCubicMetre => "Tonne per cubic metre",
+            UnitCode::TropicalYear => "Tropical year",
+            UnitCode::UnifiedAtomicMassUnit => "Unified atomic mass unit",
+            UnitCode::Var => "Var",
+            UnitCode::VoltSquaredPerKelvinSquared => "Volt squared per kelvin squared",
+            UnitCode::VoltAmpere => "Volt - ampere",
+            UnitCode::VoltPerCentimetre => "Volt per centimetre",
+            UnitCode::VoltPerKelvin => "Volt per kelvin",
+            UnitCode::MillivoltPerKelvin => "Millivolt per kelvin",
+            UnitCode::KilogramPerSquareCentimetre => "Kilogram per square centimetre",
+            UnitCode::VoltPerMetre => "Volt per metre",
+            UnitCode::VoltPerMillimetre => "Volt per millimetre",
+            UnitCode::WattPerKelvin => "Watt per kelvin",
+            UnitCode::WattPerMetreKelvin => "Watt per metre kelvin",
+            UnitCode::WattPerSquareMetre => "Watt per square metre",
+            UnitCode::WattPerSquareMetreKelvin => "Watt per square metre kelvin",
+            UnitCode::WattPerSquareMetreKelvinToTheFourthPower => "Watt per square metre kelvin to the fourth power",
+            UnitCode::WattPerSteradian => "Watt per steradian",
+            UnitCode::WattPerSteradianSquareMetre => "Watt per steradian square metre",
+            UnitCode::WeberPerMetre => "Weber per metre",
+            UnitCode::RoentgenPerSecond => "Roentgen per second",
+            UnitCode::WeberPerMillimetre => "Weber per millimetre",
+            UnitCode::MinuteUnitOfAngle => "Minute [unit of angle]",
+            UnitCode::SecondUnitOfAngle => "Second [unit of angle]",
+            UnitCode::Book => "Book",
+            UnitCode::Round => "Round",
+            UnitCode::NumberOfWords => "Number of words",
+            UnitCode::InchToTheFourthPower => "Inch to the fourth power",
+            UnitCode::JouleSquareMetre => "Joule square metre",
+            UnitCode::KilogramPerMole => "Kilogram per mole",
+            UnitCode::Megacoulomb => "Megacoulomb",
+            UnitCode::MegajoulePerSecond => "Megajoule per second",
+            UnitCode::Microwatt => "Microwatt",
+            UnitCode::Microtesla => "Microtesla",
+            UnitCode::Microvolt => "Microvolt",
+            UnitCode::MillinewtonMetre => "Millinewton metre",
+            UnitCode::MicrowattPerSquareMetre => "Microwatt per square metre",
+            UnitCode::Millicoulomb => "Millicoulomb",
+            UnitCode::MillimolePerKilogram => "Millimole per kilogram",
+            UnitCode::MillicoulombPerCubicMetre => "Millicoulomb per cubic metre",
+            UnitCode::MillicoulombPerSquareMetre => "Millicoulomb per square metre",
+            UnitCode::Rem => "Rem",
+            UnitCode::SecondPerCubicMetre => "Second per cubic metre",
+            UnitCode::SecondPerCubicMetreRadian => "Second per cubic metre radian",
+            UnitCode::JoulePerGram => "Joule per gram",
+            UnitCode::Decare => "Decare",
+            UnitCode::TenDay => "Ten day",
+            UnitCode::Day => "Day",
+            UnitCode::DryPound => "Dry pound",
+            UnitCode::DecibelMilliwatts => "Decibel-milliwatts",
+            UnitCode::DecibelWatt => "Decibel watt",
+            UnitCode::DiskDisc => "Disk (disc)",
+            UnitCode::DegreeUnitOfAngle => "Degree [unit of angle]",
+            UnitCode::Decade => "Decade",
+            UnitCode::Decigram => "Decigram",
+            UnitCode::Decagram => "Decagram",
+            UnitCode::Decilitre => "Decilitre",
+            UnitCode::CubicDecametre => "Cubic decametre",
+            UnitCode::SquareDecimetre => "Square decimetre",
+            UnitCode::StandardKilolitre => "Standard kilolitre",
+            UnitCode::CubicDecimetre => "Cubic decimetre",
+            UnitCode::Decimetre => "Decimetre",
+            UnitCode::DecinewtonMetre => "Decinewton metre",
+            UnitCode::DozenPiece => "Dozen piece",
+            UnitCode::DozenPair => "Dozen pair",
+            UnitCode::DisplacementTonnage => "Displacement tonnage",
+            UnitCode::DramUs => "Dram (US)",
+            UnitCode::DramUk => "Dram (UK)",
+            UnitCode::DozenRoll => "Dozen roll",
+            UnitCode::DryTon => "Dry ton",
+            UnitCode::Decitonne => "Decitonne",
+            UnitCode::Pennyweight => "Pennyweight",
+            UnitCode::Dozen => "Dozen",
+            UnitCode::DozenPack => "Dozen pack",
+            UnitCode::NewtonPerSquareCentimetre => "Newton per square centimetre",
+            UnitCode::MegawattHourPerHour => "Megawatt hour per hour",
+            UnitCode::MegawattPerHertz => "Megawatt per hertz",
+            UnitCode::MilliampereHour => "Milliampere hour",
+            UnitCode::DegreeDay => "Degree day",
+            UnitCode::Mille => "Mille",
+            UnitCode::KilocalorieInternationalTable => "Kilocalorie (international table)",
+            UnitCode::KilocalorieThermochemicalPerHour => "Kilocalorie (thermochemical) per hour",
+            UnitCode::MillionBtuItPerHour => "Million Btu(IT) per hour",
+            UnitCode::CubicFootPerSecond => "Cubic foot per second",
+            UnitCode::TonnePerHour => "Tonne per hour",
+            UnitCode::Ping => "Ping",
+            UnitCode::MegabitPerSecond => "Megabit per second",
+            UnitCode::Shares => "Shares",
+            UnitCode::Teu => "TEU",
+            UnitCode::Tyre => "Tyre",
+            UnitCode::ActiveUnit => "Active unit",
+            UnitCode::Dose => "Dose",
+            UnitCode::AirDryTon => "Air dry ton",
+            UnitCode::Strand => "Strand",
+            UnitCode::SquareMetrePerLitre => "Square metre per litre",
+            UnitCode::LitrePerHour => "Litre per hour",
+            UnitCode::FootPerThousand => "Foot per thousand",
+            UnitCode::Gigabyte => "Gigabyte",
+            UnitCode::Terabyte => "Terabyte",
+            UnitCode::Petabyte => "Petabyte",
+            UnitCode::Pixel => "Pixel",
+            UnitCode::Megapixels => "Megapixels",
+            UnitCode::DotsPerInch => "Dots per inch",
+            UnitCode::GrossKilogram => "Gross kilogram",
+            UnitCode::PartPerHundredThousand => "Part per hundred thousand",
+            UnitCode::KilogramForcePerSquareMillimetre => "Kilogram-force per square millimetre",
+            UnitCode::KilogramForcePerSquareCentimetre => "Kilogram-force per square centimetre",
+            UnitCode::JoulePerSquareCentimetre => "Joule per square centimetre",
+            UnitCode::KilogramForceMetrePerSquareCentimetre => "Kilogram-force metre per square centimetre",
+            UnitCode::Milliohm => "Milliohm",
+            UnitCode::KilowattHourPerCubicMetre => "Kilowatt hour per cubic metre",
+            UnitCode::KilowattHourPerKelvin => "Kilowatt hour per kelvin",
+            UnitCode::ServiceUnit => "Service unit",
+            UnitCode::WorkingDay => "Working day",
+            UnitCode::AccountingUnit => "Accounting unit",
+            UnitCode::Job => "Job",
+            UnitCode::RunFoot => "Run foot",
+            UnitCode::Test => "Test",
+            UnitCode::Trip => "Trip",
+            UnitCode::Use => "Use",
+            UnitCode::Well => "Well",
+            UnitCode::Zone => "Zone",
+            UnitCode::ExabitPerSecond => "Exabit per second",
+            UnitCode::Exbibyte => "Exbibyte",
+            UnitCode::Pebibyte => "Pebibyte",
+            UnitCode::Tebibyte => "Tebibyte",
+            UnitCode::Gibibyte => "Gibibyte",
+            UnitCode::Mebibyte => "Mebibyte",
+            UnitCode::Kibibyte => "Kibibyte",
+            UnitCode::ExbibitPerMetre => "Exbibit per metre",
+            UnitCode::ExbibitPerSquareMetre => "Exbibit per square metre",
+            UnitCode::ExbibitPerCubicMetre => "Exbibit per cubic metre",
+            UnitCode::GigabytePerSecond => "Gigabyte per second",
+            UnitCode::GibibitPerMetre => "Gibibit per metre",
+            UnitCode::GibibitPerSquareMetre => "Gibibit per square metre",
+            UnitCode::GibibitPerCubicMetre => "Gibibit per cubic metre",
+            UnitCode::KibibitPerMetre => "Kibibit per metre",
+            UnitCode::KibibitPerSquareMetre => "Kibibit per square metre",
+            UnitCode::KibibitPerCubicMetre => "Kibibit per cubic metre",
+            UnitCode::MebibitPerMetre => "Mebibit per metre",
+            UnitCode::MebibitPerSquareMetre => "Mebibit per square metre",
+            UnitCode::MebibitPerCubicMetre => "Mebibit per cubic metre",
+            UnitCode::Petabit => "Petabit",
+            UnitCode::PetabitPerSecond => "Petabit per second",
+            UnitCode::PebibitPerMetre => "Pebibit per metre",
+            UnitCode::PebibitPerSquareMetre => "Pebibit per square metre",
+            UnitCode::PebibitPerCubicMetre => "Pebibit per cubic metre",
+            UnitCode::Terabit => "Terabit",
+            UnitCode::TerabitPerSecond => "Terabit per second",
+            UnitCode::TebibitPerMetre => "Tebibit per metre",
+            UnitCode::TebibitPerCubicMetre => "Tebibit per cubic metre",
+            UnitCode::TebibitPerSquareMetre => "Tebibit per square metre",
+            UnitCode::BitPerMetre => "Bit per metre",
+            UnitCode::BitPerSquareMetre => "Bit per square metre",
+            UnitCode::ReciprocalCentimetre => "Reciprocal centimetre",
+            UnitCode::ReciprocalDay => "Reciprocal day",
+            UnitCode::CubicDecimetrePerHour => "Cubic decimetre per hour",
+            UnitCode::KilogramPerHour => "Kilogram per hour",
+            UnitCode::KilomolePerSecond => "Kilomole per second",
+            UnitCode::MolePerSecond => "Mole per second",
+            UnitCode::DegreePerSecond => "Degree per second",
+            UnitCode::MillimetrePerDegreeCelciusMetre => "Millimetre per degree Celcius metre",
+            UnitCode::DegreeCelsiusPerKelvin => "Degree Celsius per kelvin",
+            UnitCode::HectopascalPerBar => "Hectopascal per bar",
+            UnitCode::Each => "Each",
+            UnitCode::ElectronicMailBox => "Electronic mail box",
+            UnitCode::EquivalentGallon => "Equivalent gallon",
+            UnitCode::BitPerCubicMetre => "Bit per cubic metre",
+            UnitCode::KelvinPerKelvin => "Kelvin per kelvin",
+            UnitCode::KilopascalPerBar => "Kilopascal per bar",
+            UnitCode::MillibarPerBar => "Millibar per bar",
+            UnitCode::MegapascalPerBar => "Megapascal per bar",
+            UnitCode::PoisePerBar => "Poise per bar",
+            UnitCode::PascalPerBar => "Pascal per bar",
+            UnitCode::MilliamperePerInch => "Milliampere per inch",
+            UnitCode::KelvinPerHour => "Kelvin per hour",
+            UnitCode::KelvinPerMinute => "Kelvin per minute",
+            UnitCode::KelvinPerSecond => "Kelvin per second",
+            UnitCode::Slug => "Slug",
+            UnitCode::GramPerKelvin => "Gram per kelvin",
+            UnitCode::KilogramPerKelvin => "Kilogram per kelvin",
+            UnitCode::MilligramPerKelvin => "Milligram per kelvin",
+            UnitCode::PoundForcePerFoot => "Pound-force per foot",
+            UnitCode::KilogramSquareCentimetre => "Kilogram square centimetre",
+            UnitCode::KilogramSquareMillimetre => "Kilogram square millimetre",
+            UnitCode::PoundInchSquared => "Pound inch squared",
+            UnitCode::PoundForceInch => "Pound-force inch",
+            UnitCode::PoundForceFootPerAmpere => "Pound-force foot per ampere",
+            UnitCode::GramPerCubicDecimetre => "Gram per cubic decimetre",
+            UnitCode::KilogramPerKilomol => "Kilogram per kilomol",
+            UnitCode::GramPerHertz => "Gram per hertz",
+            UnitCode::GramPerDay => "Gram per day",
+            UnitCode::GramPerHour => "Gram per hour",
+            UnitCode::GramPerMinute => "Gram per minute",
+            UnitCode::GramPerSecond => "Gram per second",
+            UnitCode::KilogramPerDay => "Kilogram per day",
+            UnitCode::KilogramPerMinute => "Kilogram per minute",
+            UnitCode::MilligramPerDay => "Milligram per day",
+            UnitCode::MilligramPerMinute => "Milligram per minute",
+            UnitCode::MilligramPerSecond => "Milligram per second",
+            UnitCode::GramPerDayKelvin => "Gram per day kelvin",
+            UnitCode::GramPerHourKelvin => "Gram per hour kelvin",
+            UnitCode::GramPerMinuteKelvin => "Gram per minute kelvin",
+            UnitCode::GramPerSecondKelvin => "Gram per second kelvin",
+            UnitCode::KilogramPerDayKelvin => "Kilogram per day kelvin",
+            UnitCode::KilogramPerHourKelvin => "Kilogram per hour kelvin",
+            UnitCode::KilogramPerMinuteKelvin => "Kilogram per minute kelvin",
+            UnitCode::KilogramPerSecondKelvin => "Kilogram per second kelvin",
+            UnitCode::MilligramPerDayKelvin => "Milligram per day kelvin",
+            UnitCode::MilligramPerHourKelvin => "Milligram per hour kelvin",
+            UnitCode::MilligramPerMinuteKelvin => "Milligram per minute kelvin",
+            UnitCode::MilligramPerSecondKelvin => "Milligram per second kelvin",
+            UnitCode::NewtonPerMillimetre => "Newton per millimetre",
+            UnitCode::PoundForcePerInch => "Pound-force per inch",
+            UnitCode::RodUnitOfDistance => "Rod [unit of distance]",
+            UnitCode::MicrometrePerKelvin => "Micrometre per kelvin",
+            UnitCode::CentimetrePerKelvin => "Centimetre per kelvin",
+            UnitCode::MetrePerKelvin => "Metre per kelvin",
+            UnitCode::MillimetrePerKelvin => "Millimetre per kelvin",
+            UnitCode::MilliohmPerMetre => "Milliohm per metre",
+            UnitCode::OhmPerMileStatuteMile => "Ohm per mile (statute mile)",
+            UnitCode::OhmPerKilometre => "Ohm per kilometre",
+            UnitCode::MilliamperePerPoundForcePerSquareInch => "Milliampere per pound-force per square inch",
+            UnitCode::ReciprocalBar => "Reciprocal bar",
+            UnitCode::MilliamperePerBar => "Milliampere per bar",
+            UnitCode::DegreeCelsiusPerBar => "Degree Celsius per bar",
+            UnitCode::KelvinPerBar => "Kelvin per bar",
+            UnitCode::GramPerDayBar => "Gram per day bar",
+            UnitCode::GramPerHourBar => "Gram per hour bar",
+            UnitCode::GramPerMinuteBar => "Gram per minute bar",
+            UnitCode::GramPerSecondBar => "Gram per second bar",
+            UnitCode::KilogramPerDayBar => "Kilogram per day bar",
+            UnitCode::KilogramPerHourBar => "Kilogram per hour bar",
+            UnitCode::KilogramPerMinuteBar => "Kilogram per minute bar",
+            UnitCode::KilogramPerSecondBar => "Kilogram per second bar",
+            UnitCode::MilligramPerDayBar => "Milligram per day bar",
+            UnitCode::MilligramPerHourBar => "Milligram per hour bar",
+            UnitCode::MilligramPerMinuteBar => "Milligram per minute bar",
+            UnitCode::MilligramPerSecondBar => "Milligram per second bar",
+            UnitCode::GramPerBar => "Gram per bar",
+            UnitCode::MilligramPerBar => "Milligram per bar",
+            UnitCode::MilliamperePerMillimetre => "Milliampere per millimetre",
+            UnitCode::PascalSecondPerKelvin => "Pascal second per kelvin",
+            UnitCode::InchOfWater => "Inch of water",
+            UnitCode::InchOfMercury => "Inch of mercury",
+            UnitCode::WaterHorsePower => "Water horse power",
+            UnitCode::BarPerKelvin => "Bar per kelvin",
+            UnitCode::HectopascalPerKelvin => "Hectopascal per kelvin",
+            UnitCode::KilopascalPerKelvin => "Kilopascal per kelvin",
+            UnitCode::MillibarPerKelvin => "Millibar per kelvin",
+            UnitCode::MegapascalPerKelvin => "Megapascal per kelvin",
+            UnitCode::PoisePerKelvin => "Poise per kelvin",
+            UnitCode::VoltPerLitreMinute => "Volt per litre minute",
+            UnitCode::NewtonCentimetre => "Newton centimetre",
+            UnitCode::NewtonMetrePerDegree => "Newton metre per degree",
+            UnitCode::NewtonPerAmpere => "Newton per ampere",
+            UnitCode::BarLitrePerSecond => "Bar litre per second",
+            UnitCode::BarCubicMetrePerSecond => "Bar cubic metre per second",
+            UnitCode::HectopascalLitrePerSecond => "Hectopascal litre per second",
+            UnitCode::HectopascalCubicMetrePerSecond => "Hectopascal cubic metre per second",
+            UnitCode::MillibarLitrePerSecond => "Millibar litre per second",
+            UnitCode::MillibarCubicMetrePerSecond => "Millibar cubic metre per second",
+            UnitCode::MegapascalLitrePerSecond => "Megapascal litre per second",
+            UnitCode::MegapascalCubicMetrePerSecond => "Megapascal cubic metre per second",
+            UnitCode::PascalLitrePerSecond => "Pascal litre per second",
+            UnitCode::DegreeFahrenheit => "Degree Fahrenheit",
+            UnitCode::Farad => "Farad",
+            UnitCode::FibreMetre => "Fibre metre",
+            UnitCode::ThousandCubicFoot => "Thousand cubic foot",
+            UnitCode::HundredCubicMetre => "Hundred cubic metre",
+            UnitCode::Micromole => "Micromole",
+            UnitCode::FailuresInTime => "Failures in time",
+            UnitCode::FlakeTon => "Flake ton",
+            UnitCode::FormazinNephelometricUnit => "Formazin nephelometric unit",
+            UnitCode::Foot => "Foot",
+            UnitCode::PoundPerSquareFoot => "Pound per square foot",
+            UnitCode::FootPerMinute => "Foot per minute",
+            UnitCode::FootPerSecond => "Foot per second",
+            UnitCode::SquareFoot => "Square foot",
+            UnitCode::CubicFoot => "Cubic foot",
+            UnitCode::PascalCubicMetrePerSecond => "Pascal cubic metre per second",
+            UnitCode::CentimetrePerBar => "Centimetre per bar",
+            UnitCode::MetrePerBar => "Metre per bar",
+            UnitCode::MillimetrePerBar => "Millimetre per bar",
+            UnitCode::SquareInchPerSecond => "Square inch per second",
+            UnitCode::SquareMetrePerSecondKelvin => "Square metre per second kelvin",
+            UnitCode::StokesPerKelvin => "Stokes per kelvin",
+            UnitCode::GramPerCubicCentimetreBar => "Gram per cubic centimetre bar",
+            UnitCode::GramPerCubicDecimetreBar => "Gram per cubic decimetre bar",
+            UnitCode::GramPerLitreBar => "Gram per litre bar",
+            UnitCode::GramPerCubicMetreBar => "Gram per cubic metre bar",
+            UnitCode::GramPerMillilitreBar => "Gram per millilitre bar",
+            UnitCode::KilogramPerCubicCentimetreBar => "Kilogram per cubic centimetre bar",
+            UnitCode::KilogramPerLitreBar => "Kilogram per litre bar",
+            UnitCode::KilogramPerCubicMetreBar => "Kilogram per cubic metre bar",
+            UnitCode::NewtonMetrePerKilogram => "Newton metre per kilogram",
+            UnitCode::UsGallonPerMinute => "US gallon per minute",
+            UnitCode::PoundForceFootPerPound => "Pound-force foot per pound",
+            UnitCode::CupUnitOfVolume => "Cup [unit of volume]",
+            UnitCode::Peck => "Peck",
+            UnitCode::TablespoonUs => "Tablespoon (US)",
+            UnitCode::TeaspoonUs => "Teaspoon (US)",
+            UnitCode::Stere => "Stere",
+            UnitCode::CubicCentimetrePerKelvin => "Cubic centimetre per kelvin",
+            UnitCode::LitrePerKelvin => "Litre per kelvin",
+            UnitCode::CubicMetrePerKelvin => "Cubic metre per kelvin",
+            UnitCode::ImperialGallonPerMinute => "Imperial gallon per minute",
+            UnitCode::MillilitrePerKelvin => "Millilitre per kelvin",
+            UnitCode::KilogramPerCubicCentimetre => "Kilogram per cubic centimetre",
+            UnitCode::OunceAvoirdupoisPerCubicYard => "Ounce (avoirdupois) per cubic yard",
+            UnitCode::GramPerCubicCentimetreKelvin => "Gram per cubic centimetre kelvin",
+            UnitCode::GramPerCubicDecimetreKelvin => "Gram per cubic decimetre kelvin",
+            UnitCode::GramPerLitreKelvin => "Gram per litre kelvin",
+            UnitCode::GramPerCubicMetreKelvin => "Gram per cubic metre kelvin",
+            UnitCode::GramPerMillilitreKelvin => "Gram per millilitre kelvin",
+            UnitCode::KilogramPerCubicCentimetreKelvin => "Kilogram per cubic centimetre kelvin",
+            UnitCode::KilogramPerLitreKelvin => "Kilogram per litre kelvin",
+            UnitCode::KilogramPerCubicMetreKelvin => "Kilogram per cubic metre kelvin",
+            UnitCode::SquareMetrePerSecondBar => "Square metre per second bar",
+            UnitCode::MicrosiemensPerCentimetre => "Microsiemens per centimetre",
+            UnitCode::MicrosiemensPerMetre => "Microsiemens per metre",
+            UnitCode::NanosiemensPerCentimetre => "Nanosiemens per centimetre",
+            UnitCode::NanosiemensPerMetre => "Nanosiemens per metre",
+            UnitCode::StokesPerBar => "Stokes per bar",
+            UnitCode::CubicCentimetrePerDay => "Cubic centimetre per day",
+            UnitCode::CubicCentimetrePerHour => "Cubic centimetre per hour",
+            UnitCode::CubicCentimetrePerMinute => "Cubic centimetre per minute",
+            UnitCode::GallonUsPerHour => "Gallon (US) per hour",
+            UnitCode::LitrePerSecond => "Litre per second",
+            UnitCode::CubicMetrePerDay => "Cubic metre per day",
+            UnitCode::CubicMetrePerMinute => "Cubic metre per minute",
+            UnitCode::MillilitrePerDay => "Millilitre per day",
+            UnitCode::MillilitrePerHour => "Millilitre per hour",
+            UnitCode::CubicInchPerHour => "Cubic inch per hour",
+            UnitCode::CubicInchPerMinute => "Cubic inch per minute",
+            UnitCode::CubicInchPerSecond => "Cubic inch per second",
+            UnitCode::MilliamperePerLitreMinute => "Milliampere per litre minute",
+            UnitCode::VoltPerBar => "Volt per bar",
+            UnitCode::CubicCentimetrePerDayKelvin => "Cubic centimetre per day kelvin",
+            UnitCode::CubicCentimetrePerHourKelvin => "Cubic centimetre per hour kelvin",
+            UnitCode::CubicCentimetrePerMinuteKelvin => "Cubic centimetre per minute kelvin",
+            UnitCode::CubicCentimetrePerSecondKelvin => "Cubic centimetre per second kelvin",
+            UnitCode::LitrePerDayKelvin => "Litre per day kelvin",
+            UnitCode::LitrePerHourKelvin => "Litre per hour kelvin",
+            UnitCode::LitrePerMinuteKelvin => "Litre per minute kelvin",
+            UnitCode::LitrePerSecondKelvin => "Litre per second kelvin",
+            UnitCode::CubicMetrePerDayKelvin => "Cubic metre per day kelvin",
+            UnitCode::CubicMetrePerHourKelvin => "Cubic metre per hour kelvin",
+            UnitCode::CubicMetrePerMinuteKelvin => "Cubic metre per minute kelvin",
+            UnitCode::CubicMetrePerSecondKelvin => "Cubic metre per second kelvin",
+            UnitCode::MillilitrePerDayKelvin => "Millilitre per day kelvin",
+            UnitCode::MillilitrePerHourKelvin => "Millilitre per hour kelvin",
+            UnitCode::MillilitrePerMinuteKelvin => "Millilitre per minute kelvin",
+            UnitCode::MillilitrePerSecondKelvin => "Millilitre per second kelvin",
+            UnitCode::MillipascalSecondPerKelvin => "Millipascal second per kelvin",
+            UnitCode::CubicCentimetrePerDayBar => "Cubic centimetre per day bar",
+            UnitCode::CubicCentimetrePerHourBar => "Cubic centimetre per hour bar",
+            UnitCode::CubicCentimetrePerMinuteBar => "Cubic centimetre per minute bar",
+            UnitCode::CubicCentimetrePerSecondBar => "Cubic centimetre per second bar",
+            UnitCode::LitrePerDayBar => "Litre per day bar",
+            UnitCode::LitrePerHourBar => "Litre per hour bar",
+            UnitCode::LitrePerMinuteBar => "Litre per minute bar",
+            UnitCode::LitrePerSecondBar => "Litre per second bar",
+            UnitCode::CubicMetrePerDayBar => "Cubic metre per day bar",
+            UnitCode::CubicMetrePerHourBar => "Cubic metre per hour bar",
+            UnitCode::CubicMetrePerMinuteBar => "Cubic metre per minute bar",
+            UnitCode::CubicMetrePerSecondBar => "Cubic metre per second bar",
+            UnitCode::MillilitrePerDayBar => "Millilitre per day bar",
+            UnitCode::MillilitrePerHourBar => "Millilitre per hour bar",
+            UnitCode::MillilitrePerMinuteBar => "Millilitre per minute bar",
+            UnitCode::MillilitrePerSecondBar => "Millilitre per second bar",
+            UnitCode::CubicCentimetrePerBar => "Cubic centimetre per bar",
+            UnitCode::LitrePerBar => "Litre per bar",
+            UnitCode::CubicMetrePerBar => "Cubic metre per bar",
+            UnitCode::MillilitrePerBar => "Millilitre per bar",
+            UnitCode::MicrohenryPerKiloohm => "Microhenry per kiloohm",
+            UnitCode::MicrohenryPerOhm => "Microhenry per ohm",
+            UnitCode::GallonUsPerDay => "Gallon (US) per day",
+            UnitCode::Gigabecquerel => "Gigabecquerel",
+            UnitCode::GramDryWeight => "Gram, dry weight",
+            UnitCode::PoundPerGallonUs => "Pound per gallon (US)",
+            UnitCode::GramPerMetreGramPer100Centimetres => "Gram per metre (gram per 100 centimetres)",
+            UnitCode::GramOfFissileIsotope => "Gram of fissile isotope",
+            UnitCode::GreatGross => "Great gross",
+            UnitCode::GillUs => "Gill (US)",
+            UnitCode::GramIncludingContainer => "Gram, including container",
+            UnitCode::GillUk => "Gill (UK)",
+            UnitCode::GramIncludingInnerPackaging => "Gram, including inner packaging",
+            UnitCode::GramPerMillilitre => "Gram per millilitre",
+            UnitCode::GramPerLitre => "Gram per litre",
+            UnitCode::DryGallonUs => "Dry gallon (US)",
+            UnitCode::GallonUk => "Gallon (UK)",
+            UnitCode::GallonUs => "Gallon (US)",
+            UnitCode::GramPerSquareMetre => "Gram per square metre",
+            UnitCode::MilligramPerSquareMetre => "Milligram per square metre",
+            UnitCode::MilligramPerCubicMetre => "Milligram per cubic metre",
+            UnitCode::MicrogramPerCubicMetre => "Microgram per cubic metre",
+            UnitCode::Gram => "Gram",
+            UnitCode::Grain => "Grain",
+            UnitCode::Gross => "Gross",
+            UnitCode::Gigajoule => "Gigajoule",
+            UnitCode::GigawattHour => "Gigawatt hour",
+            UnitCode::HenryPerKiloohm => "Henry per kiloohm",
+            UnitCode::HenryPerOhm => "Henry per ohm",
+            UnitCode::MillihenryPerKiloohm => "Millihenry per kiloohm",
+            UnitCode::MillihenryPerOhm => "Millihenry per ohm",
+            UnitCode::PascalSecondPerBar => "Pascal second per bar",
+            UnitCode::Microbecquerel => "Microbecquerel",
+            UnitCode::ReciprocalYear => "Reciprocal year",
+            UnitCode::ReciprocalHour => "Reciprocal hour",
+            UnitCode::ReciprocalMonth => "Reciprocal month",
+            UnitCode::DegreeCelsiusPerHour => "Degree Celsius per hour",
+            UnitCode::DegreeCelsiusPerMinute => "Degree Celsius per minute",
+            UnitCode::DegreeCelsiusPerSecond => "Degree Celsius per second",
+            UnitCode::SquareCentimetrePerGram => "Square centimetre per gram",
+            UnitCode::SquareDecametre => "Square decametre",
+            UnitCode::SquareHectometre => "Square hectometre",
+            UnitCode::CubicHectometre => "Cubic hectometre",
+            UnitCode::CubicKilometre => "Cubic kilometre",
+            UnitCode::Blank => "Blank",
+            UnitCode::VoltSquareInchPerPoundForce => "Volt square inch per pound-force",
+            UnitCode::VoltPerInch => "Volt per inch",
+            UnitCode::VoltPerMicrosecond => "Volt per microsecond",
+            UnitCode::PercentPerKelvin => "Percent per kelvin",
+            UnitCode::OhmPerMetre => "Ohm per metre",
+            UnitCode::DegreePerMetre => "Degree per metre",
+            UnitCode::MicrofaradPerKilometre => "Microfarad per kilometre",
+            UnitCode::MicrogramPerLitre => "Microgram per litre",
+            UnitCode::SquareMicrometreSquareMicron => "Square micrometre (square micron)",
+            UnitCode::AmperePerKilogram => "Ampere per kilogram",
+            UnitCode::AmpereSquaredSecond => "Ampere squared second",
+            UnitCode::FaradPerKilometre => "Farad per kilometre",
+            UnitCode::HertzMetre => "Hertz metre",
+            UnitCode::KelvinMetrePerWatt => "Kelvin metre per watt",
+            UnitCode::MegaohmPerKilometre => "Megaohm per kilometre",
+            UnitCode::MegaohmPerMetre => "Megaohm per metre",
+            UnitCode::Megaampere => "Megaampere",
+            UnitCode::MegahertzKilometre => "Megahertz kilometre",
+            UnitCode::NewtonPerAmpereH40 => "Newton per ampere",
+            UnitCode::NewtonMetreWattToThePowerMinus05 => "Newton metre watt to the power minus 0,5",
+            UnitCode::PascalPerMetre => "Pascal per metre",
+            UnitCode::SiemensPerCentimetre => "Siemens per centimetre",
+            UnitCode::Teraohm => "Teraohm",
+            UnitCode::VoltSecondPerMetre => "Volt second per metre",
+            UnitCode::VoltPerSecond => "Volt per second",
+            UnitCode::WattPerCubicMetre => "Watt per cubic metre",
+            UnitCode::Attofarad => "Attofarad",
+            UnitCode::CentimetrePerHour => "Centimetre per hour",
+            UnitCode::ReciprocalCubicCentimetre => "Reciprocal cubic centimetre",
+            UnitCode::DecibelPerKilometre => "Decibel per kilometre",
+            UnitCode::DecibelPerMetre => "Decibel per metre",
+            UnitCode::KilogramPerBar => "Kilogram per bar",
+            UnitCode::KilogramPerCubicDecimetreKelvin => "Kilogram per cubic decimetre kelvin",
+            UnitCode::KilogramPerCubicDecimetreBar => "Kilogram per cubic decimetre bar",
+            UnitCode::KilogramPerSquareMetreSecond => "Kilogram per square metre second",
+            UnitCode::InchPerTwoPiRadiant => "Inch per two pi radiant",
+            UnitCode::MetrePerVoltSecond => "Metre per volt second",
+            UnitCode::SquareMetrePerNewton => "Square metre per newton",
+            UnitCode::CubicMetrePerCubicMetre => "Cubic metre per cubic metre",
+            UnitCode::MillisiemensPerCentimetre => "Millisiemens per centimetre",
+            UnitCode::MillivoltPerMinute => "Millivolt per minute",
+            UnitCode::MilligramPerSquareCentimetre => "Milligram per square centimetre",
+            UnitCode::MilligramPerGram => "Milligram per gram",
+            UnitCode::MillilitrePerCubicMetre => "Millilitre per cubic metre",
+            UnitCode::MillimetrePerYear => "Millimetre per year",
+            UnitCode::MillimetrePerHour => "Millimetre per hour",
+            UnitCode::MillimolePerGram => "Millimole per gram",
+            UnitCode::PicopascalPerKilometre => "Picopascal per kilometre",
+            UnitCode::Picosecond => "Picosecond",
+            UnitCode::PercentPerMonth => "Percent per month",
+            UnitCode::PercentPerHectobar => "Percent per hectobar",
+            UnitCode::PercentPerDecakelvin => "Percent per decakelvin",
+            UnitCode::WattPerMetre => "Watt per metre",
+            UnitCode::Decapascal => "Decapascal",
+            UnitCode::GramPerMillimetre => "Gram per millimetre",
+            UnitCode::ModuleWidth => "Module width",
+            UnitCode::FrenchGauge => "French gauge",
+            UnitCode::RackUnit => "Rack unit",
+            UnitCode::MillimetrePerMinute => "Millimetre per minute",
+            UnitCode::BigPoint => "Big point",
+            UnitCode::LitrePerKilogram => "Litre per kilogram",
+            UnitCode::GramMillimetre => "Gram millimetre",
+            UnitCode::ReciprocalWeek => "Reciprocal week",
+            UnitCode::Piece => "Piece",
+            UnitCode::MegaohmKilometre => "Megaohm kilometre",
+            UnitCode::PercentPerOhm => "Percent per ohm",
+            UnitCode::PercentPerDegree => "Percent per degree",
+            UnitCode::PercentPerTenThousand => "Percent per ten thousand",
+            UnitCode::PercentPerOneHundredThousand => "Percent per one hundred thousand",
+            UnitCode::PercentPerHundred => "Percent per hundred",
+            UnitCode::PercentPerThousand => "Percent per thousand",
+            UnitCode::PercentPerVolt => "Percent per volt",
+            UnitCode::PercentPerBar => "Percent per bar",
+            UnitCode::PercentPerInch => "Percent per inch",
+            UnitCode::PercentPerMetre => "Percent per metre",
+            UnitCode::Hank => "Hank",
+            UnitCode::PieceDay => "Piece Day",
+            UnitCode::Hectare => "Hectare",
+            UnitCode::HundredBoxes => "Hundred boxes",
+            UnitCode::HundredCount => "Hundred count",
+            UnitCode::HundredKilogramDryWeight => "Hundred kilogram, dry weight",
+            UnitCode::Head => "Head",
+            UnitCode::Hectogram => "Hectogram",
+            UnitCode::HundredCubicFoot => "Hundred cubic foot",
+            UnitCode::HundredInternationalUnit => "Hundred international unit",
+            UnitCode::HundredKilogramNetMass => "Hundred kilogram, net mass",
+            UnitCode::Hectolitre => "Hectolitre",
+            UnitCode::MilePerHourStatuteMile => "Mile per hour (statute mile)",
+            UnitCode::PieceMonth => "Piece Month",
+            UnitCode::MillionCubicMetre => "Million cubic metre",
+            UnitCode::Hectometre => "Hectometre",
+            UnitCode::HectolitreOfPureAlcohol => "Hectolitre of pure alcohol",
+            UnitCode::Hertz => "Hertz",
+            UnitCode::Hour => "Hour",
+            UnitCode::PieceWeek => "Piece Week",
+            UnitCode::InchPoundPoundInch => "Inch pound (pound inch)",
+            UnitCode::Person => "Person",
+            UnitCode::Inch => "Inch",
+            UnitCode::SquareInch => "Square inch",
+            UnitCode::CubicInch => "Cubic inch",
+            UnitCode::InternationalSugarDegree => "International sugar degree",
+            UnitCode::InchPerSecond => "Inch per second",
+            UnitCode::InternationalUnitPerGram => "International unit per gram",
+            UnitCode::InchPerSecondSquared => "Inch per second squared",
+            UnitCode::PercentPerMillimetre => "Percent per millimetre",
+            UnitCode::PerMillePerPsi => "Per mille per psi",
+            UnitCode::DegreeApi => "Degree API",
+            UnitCode::DegreeBaumeOriginScale => "Degree Baume (origin scale)",
+            UnitCode::DegreeBaumeUsHeavy => "Degree Baume (US heavy)",
+            UnitCode::DegreeBaumeUsLight => "Degree Baume (US light)",
+            UnitCode::DegreeBalling => "Degree Balling",
+            UnitCode::DegreeBrix => "Degree Brix",
+            UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitThermochemical => "Degree Fahrenheit hour square foot per British thermal unit (thermochemical)",
+            UnitCode::JoulePerKilogram => "Joule per kilogram",
+            UnitCode::DegreeFahrenheitPerKelvin => "Degree Fahrenheit per kelvin",
+            UnitCode::DegreeFahrenheitPerBar => "Degree Fahrenheit per bar",
+            UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitInternationalTable => "Degree Fahrenheit hour square foot per British thermal unit (international table)",
+            UnitCode::DegreeFahrenheitPerHour => "Degree Fahrenheit per hour",
+            UnitCode::DegreeFahrenheitPerMinute => "Degree Fahrenheit per minute",
+            UnitCode::DegreeFahrenheitPerSecond => "Degree Fahrenheit per second",
+            UnitCode::ReciprocalDegreeFahrenheit => "Reciprocal degree Fahrenheit",
+            UnitCode::DegreeOechsle => "Degree Oechsle",
+            UnitCode::DegreeRankinePerHour => "Degree Rankine per hour",
+            UnitCode::DegreeRankinePerMinute => "Degree Rankine per minute",
+            UnitCode::DegreeRankinePerSecond => "Degree Rankine per second",
+            UnitCode::DegreeTwaddell => "Degree Twaddell",
+            UnitCode::Micropoise => "Micropoise",
+            UnitCode::MicrogramPerKilogram => "Microgram per kilogram",
+            UnitCode::MicrogramPerCubicMetreKelvin => "Microgram per cubic metre kelvin",
+            UnitCode::MicrogramPerCubicMetreBar => "Microgram per cubic metre bar",
+            UnitCode::MicrolitrePerLitre => "Microlitre per litre",
+            UnitCode::Baud => "Baud",
+            UnitCode::BritishThermalUnitMean => "British thermal unit (mean)",
+            UnitCode::BritishThermalUnitInternationalTableFootPerHourSquareFootDegreeFahrenheit => "British thermal unit (international table) foot per hour square foot degree Fahrenheit",
+            UnitCode::BritishThermalUnitInternationalTableInchPerHourSquareFootDegreeFahrenheit => "British thermal unit (international table) inch per hour square foot degree Fahrenheit",
+            UnitCode::BritishThermalUnitInternationalTableInchPerSecondSquareFootDegreeFahrenheit => "British thermal unit (international table) inch per second square foot degree Fahrenheit",
+            UnitCode::BritishThermalUnitInternationalTablePerPoundDegreeFahrenheit => "British thermal unit (international table) per pound degree Fahrenheit",
+            UnitCode::BritishThermalUnitInternationalTablePerMinute => "British thermal unit (international table) per minute",
+            UnitCode::BritishThermalUnitInternationalTablePerSecond => "British thermal unit (international table) per second",
+            UnitCode::BritishThermalUnitThermochemicalFootPerHourSquareFootDegreeFahrenheit => "British thermal unit (thermochemical) foot per hour square foot degree Fahrenheit",
+            UnitCode::BritishThermalUnitThermochemicalPerHour => "British thermal unit (thermochemical) per hour",
+            UnitCode::BritishThermalUnitThermochemicalInchPerHourSquareFootDegreeFahrenheit => "British thermal unit (thermochemical) inch per hour square foot degree Fahrenheit",
+            UnitCode::BritishThermalUnitThermochemicalInchPerSecondSquareFootDegreeFahrenheit => "British thermal unit (thermochemical) inch per second square foot degree Fahrenheit",
+            UnitCode::BritishThermalUnitThermochemicalPerPoundDegreeFahrenheit => "British thermal unit (thermochemical) per pound degree Fahrenheit",
+            UnitCode::BritishThermalUnitThermochemicalPerMinute => "British thermal unit (thermochemical) per minute",
+            UnitCode::BritishThermalUnitThermochemicalPerSecond => "British thermal unit (thermochemical) per second",
+            UnitCode::CoulombSquareMetrePerKilogram => "Coulomb square metre per kilogram",
+            UnitCode::Megabaud => "Megabaud",
+            UnitCode::WattSecond => "Watt second",
+            UnitCode::BarPerBar => "Bar per bar",
+            UnitCode::BarrelUkPetroleum => "Barrel (UK petroleum)",
+            UnitCode::BarrelUkPetroleumPerMinute => "Barrel (UK petroleum) per minute",
+            UnitCode::BarrelUkPetroleumPerDay => "Barrel (UK petroleum) per day",
+            UnitCode::BarrelUkPetroleumPerHour => "Barrel (UK petroleum) per hour",
+            UnitCode::BarrelUkPetroleumPerSecond => "Barrel (UK petroleum) per second",
+            UnitCode::BarrelUsPetroleumPerHour => "Barrel (US petroleum) per hour",
+            UnitCode::BarrelUsPetroleumPerSecond => "Barrel (US petroleum) per second",
+            UnitCode::BushelUkPerDay => "Bushel (UK) per day",
+            UnitCode::BushelUkPerHour => "Bushel (UK) per hour",
+            UnitCode::BushelUkPerMinute => "Bushel (UK) per minute",
+            UnitCode::BushelUkPerSecond => "Bushel (UK) per second",
+            UnitCode::BushelUsDryPerDay => "Bushel (US dry) per day",
+            UnitCode::BushelUsDryPerHour => "Bushel (US dry) per hour",
+            UnitCode::BushelUsDryPerMinute => "Bushel (US dry) per minute",
+            UnitCode::BushelUsDryPerSecond => "Bushel (US dry) per second",
+            UnitCode::CentinewtonMetre => "Centinewton metre",
+            UnitCode::CentipoisePerKelvin => "Centipoise per kelvin",
+            UnitCode::CentipoisePerBar => "Centipoise per bar",
+            UnitCode::CalorieMean => "Calorie (mean)",
+            UnitCode::CalorieInternationalTablePerGramDegreeCelsius => "Calorie (international table) per gram degree Celsius",
+            UnitCode::CalorieThermochemicalPerCentimetreSecondDegreeCelsius => "Calorie (thermochemical) per centimetre second degree Celsius",
+            UnitCode::CalorieThermochemicalPerGramDegreeCelsius => "Calorie (thermochemical) per gram degree Celsius",
+            UnitCode::CalorieThermochemicalPerMinute => "Calorie (thermochemical) per minute",
+            UnitCode::CalorieThermochemicalPerSecond => "Calorie (thermochemical) per second",
+            UnitCode::Clo => "Clo",
+            UnitCode::CentimetrePerSecondKelvin => "Centimetre per second kelvin",
+            UnitCode::CentimetrePerSecondBar => "Centimetre per second bar",
+            UnitCode::CubicCentimetrePerCubicMetre => "Cubic centimetre per cubic metre",
+            UnitCode::CubicDecimetrePerDay => "Cubic decimetre per day",
+            UnitCode::CubicDecimetrePerMinute => "Cubic decimetre per minute",
+            UnitCode::CubicDecimetrePerSecond => "Cubic decimetre per second",
+            UnitCode::OunceUkFluidPerDay => "Ounce (UK fluid) per day",
+            UnitCode::OunceUkFluidPerHour => "Ounce (UK fluid) per hour",
+            UnitCode::OunceUkFluidPerMinute => "Ounce (UK fluid) per minute",
+            UnitCode::OunceUkFluidPerSecond => "Ounce (UK fluid) per second",
+            UnitCode::OunceUsFluidPerDay => "Ounce (US fluid) per day",
+            UnitCode::JoulePerKelvin => "Joule per kelvin",
+            UnitCode::MegajoulePerKilogram => "Megajoule per kilogram",
+            UnitCode::MegajoulePerCubicMetre => "Megajoule per cubic metre",
+            UnitCode::PipelineJoint => "Pipeline joint",
+            UnitCode::Joule => "Joule",
+            UnitCode::HundredMetre => "Hundred metre",
+            UnitCode::NumberOfJewels => "Number of jewels",
+            UnitCode::KilowattDemand => "Kilowatt demand",
+            UnitCode::OunceUsFluidPerHour => "Ounce (US fluid) per hour",
+            UnitCode::OunceUsFluidPerMinute => "Ounce (US fluid) per minute",
+            UnitCode::OunceUsFluidPerSecond => "Ounce (US fluid) per second",
+            UnitCode::FootPerDegreeFahrenheit => "Foot per degree Fahrenheit",
+            UnitCode::FootPerHour => "Foot per hour",
+            UnitCode::FootPoundForcePerHour => "Foot pound-force per hour",
+            UnitCode::FootPoundForcePerMinute => "Foot pound-force per minute",
+            UnitCode::FootPerPsi => "Foot per psi",
+            UnitCode::FootPerSecondDegreeFahrenheit => "Foot per second degree Fahrenheit",
+            UnitCode::FootPerSecondPsi => "Foot per second psi",
+            UnitCode::KilovoltAmpereReactiveDemand => "Kilovolt ampere reactive demand",
+            UnitCode::ReciprocalCubicFoot => "Reciprocal cubic foot",
+            UnitCode::CubicFootPerDegreeFahrenheit => "Cubic foot per degree Fahrenheit",
+            UnitCode::CubicFootPerDay => "Cubic foot per day",
+            UnitCode::CubicFootPerPsi => "Cubic foot per psi",
+            UnitCode::GallonUkPerDay => "Gallon (UK) per day",
+            UnitCode::GallonUkPerHour => "Gallon (UK) per hour",
+            UnitCode::GallonUkPerSecond => "Gallon (UK) per second",
+            UnitCode::KilovoltAmpereReactiveHour => "Kilovolt ampere reactive hour",
+            UnitCode::GallonUsLiquidPerSecond => "Gallon (US liquid) per second",
+            UnitCode::GramForcePerSquareCentimetre => "Gram-force per square centimetre",
+            UnitCode::GillUkPerDay => "Gill (UK) per day",
+            UnitCode::GillUkPerHour => "Gill (UK) per hour",
+            UnitCode::GillUkPerMinute => "Gill (UK) per minute",
+            UnitCode::GillUkPerSecond => "Gill (UK) per second",
+            UnitCode::GillUsPerDay => "Gill (US) per day",
+            UnitCode::GillUsPerHour => "Gill (US) per hour",
+            UnitCode::GillUsPerMinute => "Gill (US) per minute",
+            UnitCode::GillUsPerSecond => "Gill (US) per second",
+            UnitCode::StandardAccelerationOfFreeFall => "Standard acceleration of free fall",
+            UnitCode::GrainPerGallonUs => "Grain per gallon (US)",
+            UnitCode::HorsepowerBoiler => "Horsepower (boiler)",
+            UnitCode::HorsepowerElectric => "Horsepower (electric)",
+            UnitCode::InchPerDegreeFahrenheit => "Inch per degree Fahrenheit",
+            UnitCode::InchPerPsi => "Inch per psi",
+            UnitCode::InchPerSecondDegreeFahrenheit => "Inch per second degree Fahrenheit",
+            UnitCode::InchPerSecondPsi => "Inch per second psi",
+            UnitCode::ReciprocalCubicInch => "Reciprocal cubic inch",
+            UnitCode::Kilobaud => "Kilobaud",
+            UnitCode::KilocalorieMean => "Kilocalorie (mean)",
+            UnitCode::KilocalorieInternationalTablePerHourMetreDegreeCelsius => "Kilocalorie (international table) per hour metre degree Celsius",
+            UnitCode::KilocalorieThermochemical => "Kilocalorie (thermochemical)",
+            UnitCode::KilocalorieThermochemicalPerMinute => "Kilocalorie (thermochemical) per minute",
+            UnitCode::KilocalorieThermochemicalPerSecond => "Kilocalorie (thermochemical) per second",
+            UnitCode::KilomolePerHour => "Kilomole per hour",
+            UnitCode::KilomolePerCubicMetreKelvin => "Kilomole per cubic metre kelvin",
+            UnitCode::Kilolitre => "Kilolitre",
+            UnitCode::KilomolePerCubicMetreBar => "Kilomole per cubic metre bar",
+            UnitCode::KilomolePerMinute => "Kilomole per minute",
+            UnitCode::LitrePerLitre => "Litre per litre",
+            UnitCode::ReciprocalLitre => "Reciprocal litre",
+            UnitCode::PoundAvoirdupoisPerDegreeFahrenheit => "Pound (avoirdupois) per degree Fahrenheit",
+            UnitCode::PoundAvoirdupoisSquareFoot => "Pound (avoirdupois) square foot",
+            UnitCode::PoundAvoirdupoisPerDay => "Pound (avoirdupois) per day",
+            UnitCode::PoundPerFootHour => "Pound per foot hour",
+            UnitCode::PoundPerFootSecond => "Pound per foot second",
+            UnitCode::PoundAvoirdupoisPerCubicFootDegreeFahrenheit => "Pound (avoirdupois) per cubic foot degree Fahrenheit",
+            UnitCode::PoundAvoirdupoisPerCubicFootPsi => "Pound (avoirdupois) per cubic foot psi",
+            UnitCode::PoundAvoirdupoisPerGallonUk => "Pound (avoirdupois) per gallon (UK)",
+            UnitCode::PoundAvoirdupoisPerHourDegreeFahrenheit => "Pound (avoirdupois) per hour degree Fahrenheit",
+            UnitCode::PoundAvoirdupoisPerHourPsi => "Pound (avoirdupois) per hour psi",
+            UnitCode::PoundAvoirdupoisPerCubicInchDegreeFahrenheit => "Pound (avoirdupois) per cubic inch degree Fahrenheit",
+            UnitCode::PoundAvoirdupoisPerCubicInchPsi => "Pound (avoirdupois) per cubic inch psi",
+            UnitCode::PoundAvoirdupoisPerPsi => "Pound (avoirdupois) per psi",
+            UnitCode::PoundAvoirdupoisPerMinute => "Pound (avoirdupois) per minute",
+            UnitCode::PoundAvoirdupoisPerMinuteDegreeFahrenheit => "Pound (avoirdupois) per minute degree Fahrenheit",
+            UnitCode::PoundAvoirdupoisPerMinutePsi => "Pound (avoirdupois) per minute psi",
+            UnitCode::PoundAvoirdupoisPerSecond => "Pound (avoirdupois) per second",
+            UnitCode::PoundAvoirdupoisPerSecondDegreeFahrenheit => "Pound (avoirdupois) per second degree Fahrenheit",
+            UnitCode::PoundAvoirdupoisPerSecondPsi => "Pound (avoirdupois) per second psi",
+            UnitCode::PoundPerCubicYard => "Pound per cubic yard",
+            UnitCode::PoundForcePerSquareFoot => "Pound-force per square foot",
+            UnitCode::PoundForcePerSquareInchDegreeFahrenheit => "Pound-force per square inch degree Fahrenheit",
+            UnitCode::PsiCubicInchPerSecond => "Psi cubic inch per second",
+            UnitCode::PsiLitrePerSecond => "Psi litre per second",
+            UnitCode::PsiCubicMetrePerSecond => "Psi cubic metre per second",
+            UnitCode::PsiCubicYardPerSecond => "Psi cubic yard per second",
+            UnitCode::PoundForceSecondPerSquareFoot => "Pound-force second per square foot",
+            UnitCode::PoundForceSecondPerSquareInch => "Pound-force second per square inch",
+            UnitCode::ReciprocalPsi => "Reciprocal psi",
+            UnitCode::QuartUkLiquidPerDay => "Quart (UK liquid) per day",
+            UnitCode::QuartUkLiquidPerHour => "Quart (UK liquid) per hour",
+            UnitCode::QuartUkLiquidPerMinute => "Quart (UK liquid) per minute",
+            UnitCode::QuartUkLiquidPerSecond => "Quart (UK liquid) per second",
+            UnitCode::QuartUsLiquidPerDay => "Quart (US liquid) per day",
+            UnitCode::QuartUsLiquidPerHour => "Quart (US liquid) per hour",
+            UnitCode::Cake => "Cake",
+            UnitCode::Katal => "Katal",
+            UnitCode::Kilocharacter => "Kilocharacter",
+            UnitCode::Kilobar => "Kilobar",
+            UnitCode::KilogramOfCholineChloride => "Kilogram of choline chloride",
+            UnitCode::KilogramDrainedNetWeight => "Kilogram drained net weight",
+            UnitCode::Kelvin => "Kelvin",
+            UnitCode::Kilogram => "Kilogram",
+            UnitCode::KilogramPerSecond => "Kilogram per second",
+            UnitCode::KilogramOfHydrogenPeroxide => "Kilogram of hydrogen peroxide",
+            UnitCode::Kilohertz => "Kilohertz",
+            UnitCode::KilogramPerMillimetreWidth => "Kilogram per millimetre width",
+            UnitCode::KilogramIncludingContainer => "Kilogram, including container",
+            UnitCode::KilogramIncludingInnerPackaging => "Kilogram, including inner packaging",
+            UnitCode::Kilosegment => "Kilosegment",
+            UnitCode::Kilojoule => "Kilojoule",
+            UnitCode::KilogramPerMetre => "Kilogram per metre",
+            UnitCode::LacticDryMaterialPercentage => "Lactic dry material percentage",
+            UnitCode::Kilolux => "Kilolux",
+            UnitCode::KilogramOfMethylamine => "Kilogram of methylamine",
+            UnitCode::KilometrePerHour => "Kilometre per hour",
+            UnitCode::SquareKilometre => "Square kilometre",
+            UnitCode::KilogramPerCubicMetre => "Kilogram per cubic metre",
+            UnitCode::Kilometre => "Kilometre",
+            UnitCode::KilogramOfNitrogen => "Kilogram of nitrogen",
+            UnitCode::KilonewtonPerSquareMetre => "Kilonewton per square metre",
+            UnitCode::KilogramNamedSubstance => "Kilogram named substance",
+            UnitCode::Knot => "Knot",
+            UnitCode::MilliequivalenceCausticPotashPerGramOfProduct => "Milliequivalence caustic potash per gram of product",
+            UnitCode::Kilopascal => "Kilopascal",
+            UnitCode::KilogramOfPotassiumHydroxideCausticPotash => "Kilogram of potassium hydroxide (caustic potash)",
+            UnitCode::KilogramOfPotassiumOxide => "Kilogram of potassium oxide",
+            UnitCode::KilogramOfPhosphorusPentoxidePhosphoricAnhydride => "Kilogram of phosphorus pentoxide (phosphoric anhydride)",
+            UnitCode::Kiloroentgen => "Kiloroentgen",
+            UnitCode::KilogramOfSubstance90PercentDry => "Kilogram of substance 90 % dry",
+            UnitCode::KilogramOfSodiumHydroxideCausticSoda => "Kilogram of sodium hydroxide (caustic soda)",
+            UnitCode::Kit => "Kit",
+            UnitCode::Kilotonne => "Kilotonne",
+            UnitCode::KilogramOfUranium => "Kilogram of uranium",
+            UnitCode::KilovoltAmpere => "Kilovolt - ampere",
+            UnitCode::Kilovar => "Kilovar",
+            UnitCode::Kilovolt => "Kilovolt",
+            UnitCode::KilogramPerMillimetre => "Kilogram per millimetre",
+            UnitCode::KilowattHour => "Kilowatt hour",
+            UnitCode::KilowattHourPerNormalizedCubicMetre => "Kilowatt hour per normalized cubic metre",
+            UnitCode::KilogramOfTungstenTrioxide => "Kilogram of tungsten trioxide",
+            UnitCode::KilowattHourPerStandardCubicMetre => "Kilowatt hour per standard cubic metre",
+            UnitCode::Kilowatt => "Kilowatt",
+            UnitCode::KilowattYear => "Kilowatt year",
+            UnitCode::MillilitrePerKilogram => "Millilitre per kilogram",
+            UnitCode::QuartUsLiquidPerMinute => "Quart (US liquid) per minute",
+            UnitCode::QuartUsLiquidPerSecond => "Quart (US liquid) per second",
+            UnitCode::MetrePerSecondKelvin => "Metre per second kelvin",
+            UnitCode::MetrePerSecondBar => "Metre per second bar",
+            UnitCode::SquareMetreHourDegreeCelsiusPerKilocalorieInternationalTable => "Square metre hour degree Celsius per kilocalorie (international table)",
+            UnitCode::MillipascalSecondPerKelvinL15 => "Millipascal second per kelvin",
+            UnitCode::MillipascalSecondPerBar => "Millipascal second per bar",
+            UnitCode::MilligramPerCubicMetreKelvin => "Milligram per cubic metre kelvin",
+            UnitCode::MilligramPerCubicMetreBar => "Milligram per cubic metre bar",
+            UnitCode::MillilitrePerLitre => "Millilitre per litre",
+            UnitCode::LitrePerMinute => "Litre per minute",
+            UnitCode::ReciprocalCubicMillimetre => "Reciprocal cubic millimetre",
+            UnitCode::CubicMillimetrePerCubicMetre => "Cubic millimetre per cubic metre",
+            UnitCode::MolePerHour => "Mole per hour",
+            UnitCode::MolePerKilogramKelvin => "Mole per kilogram kelvin",
+            UnitCode::MolePerKilogramBar => "Mole per kilogram bar",
+            UnitCode::MolePerLitreKelvin => "Mole per litre kelvin",
+            UnitCode::MolePerLitreBar => "Mole per litre bar",
+            UnitCode::MolePerCubicMetreKelvin => "Mole per cubic metre kelvin",
+            UnitCode::MolePerCubicMetreBar => "Mole per cubic metre bar",
+            UnitCode::MolePerMinute => "Mole per minute",
+            UnitCode::MilliroentgenAequivalentMen => "Milliroentgen aequivalent men",
+            UnitCode::NanogramPerKilogram => "Nanogram per kilogram",
+            UnitCode::OunceAvoirdupoisPerDay => "Ounce (avoirdupois) per day",
+            UnitCode::OunceAvoirdupoisPerHour => "Ounce (avoirdupois) per hour",
+            UnitCode::OunceAvoirdupoisPerMinute => "Ounce (avoirdupois) per minute",
+            UnitCode::OunceAvoirdupoisPerSecond => "Ounce (avoirdupois) per second",
+            UnitCode::OunceAvoirdupoisPerGallonUk => "Ounce (avoirdupois) per gallon (UK)",
+            UnitCode::OunceAvoirdupoisPerGallonUs => "Ounce (avoirdupois) per gallon (US)",
+            UnitCode::OunceAvoirdupoisPerCubicInch => "Ounce (avoirdupois) per cubic inch",
+            UnitCode::OunceAvoirdupoisForce => "Ounce (avoirdupois)-force",
+            UnitCode::OunceAvoirdupoisForceInch => "Ounce (avoirdupois)-force inch",
+            UnitCode::PicosiemensPerMetre => "Picosiemens per metre",
+            UnitCode::PeckUk => "Peck (UK)",
+            UnitCode::PeckUkPerDay => "Peck (UK) per day",
+            UnitCode::PeckUkPerHour => "Peck (UK) per hour",
+            UnitCode::PeckUkPerMinute => "Peck (UK) per minute",
+            UnitCode::PeckUkPerSecond => "Peck (UK) per second",
+            UnitCode::PeckUsDryPerDay => "Peck (US dry) per day",
+            UnitCode::PeckUsDryPerHour => "Peck (US dry) per hour",
+            UnitCode::PeckUsDryPerMinute => "Peck (US dry) per minute",
+            UnitCode::PeckUsDryPerSecond => "Peck (US dry) per second",
+            UnitCode::PsiPerPsi => "Psi per psi",
+            UnitCode::PintUkPerDay => "Pint (UK) per day",
+            UnitCode::PintUkPerHour => "Pint (UK) per hour",
+            UnitCode::PintUkPerMinute => "Pint (UK) per minute",
+            UnitCode::PintUkPerSecond => "Pint (UK) per second",
+            UnitCode::PintUsLiquidPerDay => "Pint (US liquid) per day",
+            UnitCode::PintUsLiquidPerHour => "Pint (US liquid) per hour",
+            UnitCode::PintUsLiquidPerMinute => "Pint (US liquid) per minute",
+            UnitCode::PintUsLiquidPerSecond => "Pint (US liquid) per second",
+            UnitCode::SlugPerDay => "Slug per day",
+            UnitCode::SlugPerFootSecond => "Slug per foot second",
+            UnitCode::SlugPerCubicFoot => "Slug per cubic foot",
+            UnitCode::SlugPerHour => "Slug per hour",
+            UnitCode::SlugPerMinute => "Slug per minute",
+            UnitCode::SlugPerSecond => "Slug per second",
+            UnitCode::TonnePerKelvin => "Tonne per kelvin",
+            UnitCode::TonnePerBar => "Tonne per bar",
+            UnitCode::TonnePerDay => "Tonne per day",
+            UnitCode::TonnePerDayKelvin => "Tonne per day kelvin",
+            UnitCode::TonnePerDayBar => "Tonne per day bar",
+            UnitCode::TonnePerHourKelvin => "Tonne per hour kelvin",
+            UnitCode::TonnePerHourBar => "Tonne per hour bar",
+            UnitCode::TonnePerCubicMetreKelvin => "Tonne per cubic metre kelvin",
+            UnitCode::TonnePerCubicMetreBar => "Tonne per cubic metre bar",
+            UnitCode::TonnePerMinute => "Tonne per minute",
+            UnitCode::TonnePerMinuteKelvin => "Tonne per minute kelvin",
+            UnitCode::TonnePerMinuteBar => "Tonne per minute bar",
+            UnitCode::TonnePerSecond => "Tonne per second",
+            UnitCode::TonnePerSecondKelvin => "Tonne per second kelvin",
+            UnitCode::TonnePerSecondBar => "Tonne per second bar",
+            UnitCode::TonUkShipping => "Ton (UK shipping)",
+            UnitCode::TonLongPerDay => "Ton long per day",
+            UnitCode::TonUsShipping => "Ton (US shipping)",
+            UnitCode::TonShortPerDegreeFahrenheit => "Ton short per degree Fahrenheit",
+            UnitCode::TonShortPerDay => "Ton short per day",
+            UnitCode::TonShortPerHourDegreeFahrenheit => "Ton short per hour degree Fahrenheit",
+            UnitCode::TonShortPerHourPsi => "Ton short per hour psi",
+            UnitCode::TonShortPerPsi => "Ton short per psi",
+            UnitCode::TonUkLongPerCubicYard => "Ton (UK long) per cubic yard",
+            UnitCode::TonUsShortPerCubicYard => "Ton (US short) per cubic yard",
+            UnitCode::TonForceUsShort => "Ton-force (US short)",
+            UnitCode::CommonYear => "Common year",
+            UnitCode::SiderealYear => "Sidereal year",
+            UnitCode::YardPerDegreeFahrenheit => "Yard per degree Fahrenheit",
+            UnitCode::YardPerPsi => "Yard per psi",
+            UnitCode::PoundPerCubicInch => "Pound per cubic inch",
+            UnitCode::LactoseExcessPercentage => "Lactose excess percentage",
+            UnitCode::Pound => "Pound",
+            UnitCode::TroyPoundUs => "Troy pound (US)",
+            UnitCode::LitrePerDay => "Litre per day",
+            UnitCode::Leaf => "Leaf",
+            UnitCode::LinearFoot => "Linear foot",
+            UnitCode::LabourHour => "Labour hour",
+            UnitCode::Link => "Link",
+            UnitCode::LinearMetre => "Linear metre",
+            UnitCode::Length => "Length",
+            UnitCode::LotUnitOfProcurement => "Lot [unit of procurement]",
+            UnitCode::LiquidPound => "Liquid pound",
+            UnitCode::LitreOfPureAlcohol => "Litre of pure alcohol",
+            UnitCode::Layer => "Layer",
+            UnitCode::LumpSum => "Lump sum",
+            UnitCode::TonUkOrLongTonUs => "Ton (UK) or long ton (US)",
+            UnitCode::Litre => "Litre",
+            UnitCode::MetricTonLubricatingOil => "Metric ton, lubricating oil",
+            UnitCode::Lumen => "Lumen",
+            UnitCode::Lux => "Lux",
+            UnitCode::LinearYard => "Linear yard",
+            UnitCode::MilligramPerLitre => "Milligram per litre",
+            UnitCode::ReciprocalCubicYard => "Reciprocal cubic yard",
+            UnitCode::CubicYardPerDegreeFahrenheit => "Cubic yard per degree Fahrenheit",
+            UnitCode::CubicYardPerDay => "Cubic yard per day",
+            UnitCode::CubicYardPerHour => "Cubic yard per hour",
+            UnitCode::CubicYardPerPsi => "Cubic yard per psi",
+            UnitCode::CubicYardPerMinute => "Cubic yard per minute",
+            UnitCode::CubicYardPerSecond => "Cubic yard per second",
+            UnitCode::KilohertzMetre => "Kilohertz metre",
+            UnitCode::GigahertzMetre => "Gigahertz metre",
+            UnitCode::Beaufort => "Beaufort",
+            UnitCode::ReciprocalMegakelvinOrMegakelvinToThePowerMinusOne => "Reciprocal megakelvin or megakelvin to the power minus one",
+            UnitCode::ReciprocalKilovoltAmpereReciprocalHour => "Reciprocal kilovolt - ampere reciprocal hour",
+            UnitCode::MillilitrePerSquareCentimetreMinute => "Millilitre per square centimetre minute",
+            UnitCode::NewtonPerCentimetre => "Newton per centimetre",
+            UnitCode::OhmKilometre => "Ohm kilometre",
+            UnitCode::PercentPerDegreeCelsius => "Percent per degree Celsius",
+            UnitCode::GigaohmPerMetre => "Gigaohm per metre",
+            UnitCode::MegahertzMetre => "Megahertz metre",
+            UnitCode::KilogramPerKilogram => "Kilogram per kilogram",
+            UnitCode::ReciprocalVoltAmpereReciprocalSecond => "Reciprocal volt - ampere reciprocal second",
+            UnitCode::KilogramPerKilometre => "Kilogram per kilometre",
+            UnitCode::PascalSecondPerLitre => "Pascal second per litre",
+            UnitCode::MillimolePerLitre => "Millimole per litre",
+            UnitCode::NewtonMetrePerSquareMetre => "Newton metre per square metre",
+            UnitCode::MillivoltAmpere => "Millivolt - ampere",
+            UnitCode::ThirtyDayMonth => "30-day month",
+            UnitCode::ActualPer360 => "Actual/360",
+            UnitCode::KilometrePerSecondSquared => "Kilometre per second squared",
+            UnitCode::CentimetrePerSecondSquared => "Centimetre per second squared",
+            UnitCode::MonetaryValue => "Monetary value",
+            UnitCode::YardPerSecondSquared => "Yard per second squared",
+            UnitCode::MillimetrePerSecondSquared => "Millimetre per second squared",
+            UnitCode::MileStatuteMilePerSecondSquared => "Mile (statute mile) per second squared",
+            UnitCode::Mil => "Mil",
+            UnitCode::Revolution => "Revolution",
+            UnitCode::DegreeUnitOfAnglePerSecondSquared => "Degree [unit of angle] per second squared",
+            UnitCode::RevolutionPerMinute => "Revolution per minute",
+            UnitCode::CircularMil => "Circular mil",
+            UnitCode::SquareMileBasedOnUSSurveyFoot => "Square mile (based on U.S. survey foot)",
+            UnitCode::ChainBasedOnUSSurveyFoot => "Chain (based on U.S. survey foot)",
+            UnitCode::Microcurie => "Microcurie",
+            UnitCode::Furlong => "Furlong",
+            UnitCode::FootUSSurvey => "Foot (U.S. survey)",
+            UnitCode::MileBasedOnUSSurveyFoot => "Mile (based on U.S. survey foot)",
+            UnitCode::MetrePerPascal => "Metre per pascal",
+            UnitCode::MetrePerRadiant => "Metre per radiant",
+            UnitCode::Shake => "Shake",
+            UnitCode::MilePerMinute => "Mile per minute",
+            UnitCode::MilePerSecond => "Mile per second",
+            UnitCode::MetrePerSecondPascal => "Metre per second pascal",
+            UnitCode::MetrePerHour => "Metre per hour",
+            UnitCode::InchPerYear => "Inch per year",
+            UnitCode::KilometrePerSecond => "Kilometre per second",
+            UnitCode::InchPerMinute => "Inch per minute",
+            UnitCode::YardPerSecond => "Yard per second",
+            UnitCode::YardPerMinute => "Yard per minute",
+            UnitCode::YardPerHour => "Yard per hour",
+            UnitCode::AcreFootBasedOnUSSurveyFoot => "Acre-foot (based on U.S. survey foot)",
+            UnitCode::Cord128Ft3 => "Cord (128 ft3)",
+            UnitCode::CubicMileUkStatute => "Cubic mile (UK statute)",
+            UnitCode::MicroInch => "Micro-inch",
+            UnitCode::TonRegister => "Ton, register",
+            UnitCode::CubicMetrePerPascal => "Cubic metre per pascal",
+            UnitCode::Bel => "Bel",
+            UnitCode::KilogramPerCubicMetrePascal => "Kilogram per cubic metre pascal",
+            UnitCode::KilogramPerPascal => "Kilogram per pascal",
+            UnitCode::KilopoundForce => "Kilopound-force",
+            UnitCode::Poundal => "Poundal",
+            UnitCode::KilogramMetrePerSecondSquared => "Kilogram metre per second squared",
+            UnitCode::Pond => "Pond",
+            UnitCode::SquareFootPerHour => "Square foot per hour",
+            UnitCode::StokesPerPascal => "Stokes per pascal",
+            UnitCode::SquareCentimetrePerSecond => "Square centimetre per second",
+            UnitCode::SquareMetrePerSecondPascal => "Square metre per second pascal",
+            UnitCode::DenierTextile => "Denier (textile)",
+            UnitCode::PoundPerYard => "Pound per yard",
+            UnitCode::TonAssay => "Ton, assay",
+            UnitCode::Pfund => "Pfund",
+            UnitCode::KilogramPerSecondPascal => "Kilogram per second pascal",
+            UnitCode::TonnePerMonth => "Tonne per month",
+            UnitCode::TonnePerYear => "Tonne per year",
+            UnitCode::MillionBtuPer1000CubicFoot => "Million Btu per 1000 cubic foot",
+            UnitCode::KilopoundPerHour => "Kilopound per hour",
+            UnitCode::PoundPerDay => "Pound per day",
+            UnitCode::PoundForcePerFootTextile => "Pound-force per foot (textile)",
+            UnitCode::NewtonMetrePerRadian => "Newton metre per radian",
+            UnitCode::KilogramMetre => "Kilogram metre",
+            UnitCode::PoundalFoot => "Poundal foot",
+            UnitCode::PoundalInch => "Poundal inch",
+            UnitCode::DyneMetre => "Dyne metre",
+            UnitCode::KilogramCentimetrePerSecond => "Kilogram centimetre per second",
+            UnitCode::GramCentimetrePerSecond => "Gram centimetre per second",
+            UnitCode::MegavoltAmpereReactiveHour => "Megavolt ampere reactive hour",
+            UnitCode::Megalitre => "Megalitre",
+            UnitCode::Megametre => "Megametre",
+            UnitCode::Megavar => "Megavar",
+            UnitCode::Megawatt => "Megawatt",
+            UnitCode::ThousandStandardBrickEquivalent => "Thousand standard brick equivalent",
+            UnitCode::ThousandBoardFoot => "Thousand board foot",
+            UnitCode::Millibar => "Millibar",
+            UnitCode::Microgram => "Microgram",
+            UnitCode::Millicurie => "Millicurie",
+            UnitCode::AirDryMetricTon => "Air dry metric ton",
+            UnitCode::Milligram => "Milligram",
+            UnitCode::Megahertz => "Megahertz",
+            UnitCode::SquareMileStatuteMile => "Square mile (statute mile)",
+            UnitCode::Thousand => "Thousand",
+            UnitCode::MinuteUnitOfTime => "Minute [unit of time]",
+            UnitCode::Million => "Million",
+            UnitCode::MillionInternationalUnit => "Million international unit",
+            UnitCode::SquareMetreDay => "Square metre day",
+            UnitCode::SquareMetreMonth => "Square metre month",
+            UnitCode::SquareMetreWeek => "Square metre week",
+            UnitCode::Milliard => "Milliard",
+            UnitCode::Millilitre => "Millilitre",
+            UnitCode::SquareMillimetre => "Square millimetre",
+            UnitCode::CubicMillimetre => "Cubic millimetre",
+            UnitCode::Millimetre => "Millimetre",
+            UnitCode::KilogramDryWeight => "Kilogram, dry weight",
+            UnitCode::MegaJoulePerNormalisedCubicMetre => "Mega joule per normalised cubic metre",
+            UnitCode::Month => "Month",
+            UnitCode::Megapascal => "Megapascal",
+            UnitCode::CubicMetreDay => "Cubic metre day",
+            UnitCode::CubicMetrePerHour => "Cubic metre per hour",
+            UnitCode::CubicMetreMonth => "Cubic metre month",
+            UnitCode::CubicMetrePerSecond => "Cubic metre per second",
+            UnitCode::CubicMetreWeek => "Cubic metre week",
+            UnitCode::MetreDay => "Metre day",
+            UnitCode::MetreMonth => "Metre month",
+            UnitCode::MetreWeek => "Metre week",
+            UnitCode::MetrePerSecondSquared => "Metre per second squared",
+            UnitCode::SquareMetre => "Square metre",
+            UnitCode::CubicMetre => "Cubic metre",
+            UnitCode::Metre => "Metre",
+            UnitCode::MetrePerSecond => "Metre per second",
+            UnitCode::Millihertz => "Millihertz",
+            UnitCode::MegavoltAmpere => "Megavolt - ampere",
+            UnitCode::MegawattHour1000KwH => "Megawatt hour (1000 kW.h)",
+            UnitCode::PenCalorie => "Pen calorie",
+            UnitCode::PoundFootPerSecond => "Pound foot per second",
+            UnitCode::PoundInchPerSecond => "Pound inch per second",
+            UnitCode::Pferdestaerke => "Pferdestaerke",
+            UnitCode::CentimetreOfMercury0Degc => "Centimetre of mercury (0 degC)",
+            UnitCode::CentimetreOfWater4Degc => "Centimetre of water (4 degC)",
+            UnitCode::FootOfWater392Degf => "Foot of water (39.2 degF)",
+            UnitCode::InchOfMercury32Degf => "Inch of mercury (32 degF)",
+            UnitCode::InchOfMercury60Degf => "Inch of mercury (60 degF)",
+            UnitCode::InchOfWater392Degf => "Inch of water (39.2 degF)",
+            UnitCode::InchOfWater60Degf => "Inch of water (60 degF)",
+            UnitCode::KipPerSquareInch => "Kip per square inch",
+            UnitCode::PoundalPerSquareFoot => "Poundal per square foot",
+            UnitCode::OunceAvoirdupoisPerSquareInch => "Ounce (avoirdupois) per square inch",
+            UnitCode::ConventionalMetreOfWater => "Conventional metre of water",
+            UnitCode::GramPerSquareMillimetre => "Gram per square millimetre",
+            UnitCode::PoundPerSquareYard => "Pound per square yard",
+            UnitCode::PoundalPerSquareInch => "Poundal per square inch",
+            UnitCode::FootToTheFourthPower => "Foot to the fourth power",
+            UnitCode::CubicDecimetrePerKilogram => "Cubic decimetre per kilogram",
+            UnitCode::CubicFootPerPound => "Cubic foot per pound",
+            UnitCode::PrintPoint => "Print point",
+            UnitCode::CubicInchPerPound => "Cubic inch per pound",
+            UnitCode::KilonewtonPerMetre => "Kilonewton per metre",
+            UnitCode::PoundalPerInch => "Poundal per inch",
+            UnitCode::PoundForcePerYard => "Pound-force per yard",
+            UnitCode::PoundalSecondPerSquareFoot => "Poundal second per square foot",
+            UnitCode::PoisePerPascal => "Poise per pascal",
+            UnitCode::NewtonSecondPerSquareMetre => "Newton second per square metre",
+            UnitCode::KilogramPerMetreSecond => "Kilogram per metre second",
+            UnitCode::KilogramPerMetreMinute => "Kilogram per metre minute",
+            UnitCode::KilogramPerMetreDay => "Kilogram per metre day",
+            UnitCode::KilogramPerMetreHour => "Kilogram per metre hour",
+            UnitCode::GramPerCentimetreSecond => "Gram per centimetre second",
+            UnitCode::PoundalSecondPerSquareInch => "Poundal second per square inch",
+            UnitCode::PoundPerFootMinute => "Pound per foot minute",
+            UnitCode::PoundPerFootDay => "Pound per foot day",
+            UnitCode::CubicMetrePerSecondPascal => "Cubic metre per second pascal",
+            UnitCode::FootPoundal => "Foot poundal",
+            UnitCode::InchPoundal => "Inch poundal",
+            UnitCode::WattPerSquareCentimetre => "Watt per square centimetre",
+            UnitCode::WattPerSquareInch => "Watt per square inch",
+            UnitCode::BritishThermalUnitInternationalTablePerSquareFootHour => "British thermal unit (international table) per square foot hour",
+            UnitCode::BritishThermalUnitThermochemicalPerSquareFootHour => "British thermal unit (thermochemical) per square foot hour",
+            UnitCode::BritishThermalUnitThermochemicalPerSquareFootMinute => "British thermal unit (thermochemical) per square foot minute",
+            UnitCode::BritishThermalUnitInternationalTablePerSquareFootSecond => "British thermal unit (international table) per square foot second",
+            UnitCode::BritishThermalUnitThermochemicalPerSquareFootSecond => "British thermal unit (thermochemical) per square foot second",
+            UnitCode::BritishThermalUnitInternationalTablePerSquareInchSecond => "British thermal unit (international table) per square inch second",
+            UnitCode::CalorieThermochemicalPerSquareCentimetreMinute => "Calorie (thermochemical) per square centimetre minute",
+            UnitCode::CalorieThermochemicalPerSquareCentimetreSecond => "Calorie (thermochemical) per square centimetre second",
+            UnitCode::BritishThermalUnitInternationalTablePerCubicFoot => "British thermal unit (international table) per cubic foot",
+            UnitCode::BritishThermalUnitThermochemicalPerCubicFoot => "British thermal unit (thermochemical) per cubic foot",
+            UnitCode::BritishThermalUnitInternationalTablePerDegreeFahrenheit => "British thermal unit (international table) per degree Fahrenheit",
+            UnitCode::BritishThermalUnitThermochemicalPerDegreeFahrenheit => "British thermal unit (thermochemical) per degree Fahrenheit",
+            UnitCode::BritishThermalUnitInternationalTablePerDegreeRankine => "British thermal unit (international table) per degree Rankine",
+            UnitCode::BritishThermalUnitThermochemicalPerDegreeRankine => "British thermal unit (thermochemical) per degree Rankine",
+            UnitCode::BritishThermalUnitThermochemicalPerPoundDegreeRankine => "British thermal unit (thermochemical) per pound degree Rankine",
+            UnitCode::KilocalorieInternationalTablePerGramKelvin => "Kilocalorie (international table) per gram kelvin",
+            UnitCode::BritishThermalUnit39Degf => "British thermal unit (39 degF)",
+            UnitCode::BritishThermalUnit59Degf => "British thermal unit (59 degF)",
+            UnitCode::BritishThermalUnit60Degf => "British thermal unit (60 degF)",
+            UnitCode::Calorie20Degc => "Calorie (20 degC)",
+            UnitCode::Quad1015Btuit => "Quad (1015 BtuIT)",
+            UnitCode::ThermEc => "Therm (EC)",
+            UnitCode::ThermUS => "Therm (U.S.)",
+            UnitCode::BritishThermalUnitThermochemicalPerPound => "British thermal unit (thermochemical) per pound",
+            UnitCode::BritishThermalUnitInternationalTablePerHourSquareFootDegreeFahrenheit => "British thermal unit (international table) per hour square foot degree Fahrenheit",
+            UnitCode::BritishThermalUnitThermochemicalPerHourSquareFootDegreeFahrenheit => "British thermal unit (thermochemical) per hour square foot degree Fahrenheit",
+            UnitCode::BritishThermalUnitInternationalTablePerSecondSquareFootDegreeFahrenheit => "British thermal unit (international table) per second square foot degree Fahrenheit",
+            UnitCode::BritishThermalUnitThermochemicalPerSecondSquareFootDegreeFahrenheit => "British thermal unit (thermochemical) per second square foot degree Fahrenheit",
+            UnitCode::KilowattPerSquareMetreKelvin => "Kilowatt per square metre kelvin",
+            UnitCode::KelvinPerPascal => "Kelvin per pascal",
+            UnitCode::WattPerMetreDegreeCelsius => "Watt per metre degree Celsius",
+            UnitCode::KilowattPerMetreKelvin => "Kilowatt per metre kelvin",
+            UnitCode::KilowattPerMetreDegreeCelsius => "Kilowatt per metre degree Celsius",
+            UnitCode::MetrePerDegreeCelciusMetre => "Metre per degree Celcius metre",
+            UnitCode::DegreeFahrenheitHourPerBritishThermalUnitInternationalTable => "Degree Fahrenheit hour per British thermal unit (international table)",
+            UnitCode::DegreeFahrenheitHourPerBritishThermalUnitThermochemical => "Degree Fahrenheit hour per British thermal unit (thermochemical)",
+            UnitCode::DegreeFahrenheitSecondPerBritishThermalUnitInternationalTable => "Degree Fahrenheit second per British thermal unit (international table)",
+            UnitCode::DegreeFahrenheitSecondPerBritishThermalUnitThermochemical => "Degree Fahrenheit second per British thermal unit (thermochemical)",
+            UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitInternationalTableInch => "Degree Fahrenheit hour square foot per British thermal unit (international table) inch",
+            UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitThermochemicalInch => "Degree Fahrenheit hour square foot per British thermal unit (thermochemical) inch",
+            UnitCode::Kilofarad => "Kilofarad",
+            UnitCode::ReciprocalJoule => "Reciprocal joule",
+            UnitCode::Picosiemens => "Picosiemens",
+            UnitCode::AmperePerPascal => "Ampere per pascal",
+            UnitCode::Franklin => "Franklin",
+            UnitCode::AmpereMinute => "Ampere minute",
+            UnitCode::Biot => "Biot",
+            UnitCode::Gilbert => "Gilbert",
+            UnitCode::VoltPerPascal => "Volt per pascal",
+            UnitCode::Picovolt => "Picovolt",
+            UnitCode::MilligramPerKilogram => "Milligram per kilogram",
+            UnitCode::NumberOfArticles => "Number of articles",
+            UnitCode::NumberOfCells => "Number of cells",
+            UnitCode::Newton => "Newton",
+            UnitCode::Message => "Message",
+            UnitCode::Nil => "Nil",
+            UnitCode::NumberOfInternationalUnits => "Number of international units",
+            UnitCode::Load => "Load",
+            UnitCode::NormalisedCubicMetre => "Normalised cubic metre",
+            UnitCode::NauticalMile => "Nautical mile",
+            UnitCode::NumberOfPacks => "Number of packs",
+            UnitCode::NumberOfParts => "Number of parts",
+            UnitCode::NetTon => "Net ton",
+            UnitCode::NephelometricTurbidityUnit => "Nephelometric turbidity unit",
+            UnitCode::NewtonMetre => "Newton metre",
+            UnitCode::PartPerThousand => "Part per thousand",
+            UnitCode::Panel => "Panel",
+            UnitCode::OzoneDepletionEquivalent => "Ozone depletion equivalent",
+            UnitCode::OdsGrams => "ODS grams",
+            UnitCode::OdsKilograms => "ODS kilograms",
+            UnitCode::OdsMilligrams => "ODS milligrams",
+            UnitCode::Ohm => "Ohm",
+            UnitCode::OuncePerSquareYard => "Ounce per square yard",
+            UnitCode::OunceAvoirdupois => "Ounce (avoirdupois)",
+            UnitCode::OscillationsPerMinute => "Oscillations per minute",
+            UnitCode::OvertimeHour => "Overtime hour",
+            UnitCode::FluidOunceUs => "Fluid ounce (US)",
+            UnitCode::FluidOunceUk => "Fluid ounce (UK)",
+            UnitCode::Percent => "Percent",
+            UnitCode::CoulombPerMetre => "Coulomb per metre",
+            UnitCode::Kiloweber => "Kiloweber",
+            UnitCode::Gamma => "Gamma",
+            UnitCode::Kilotesla => "Kilotesla",
+            UnitCode::JoulePerSecond => "Joule per second",
+            UnitCode::JoulePerMinute => "Joule per minute",
+            UnitCode::JoulePerHour => "Joule per hour",
+            UnitCode::JoulePerDay => "Joule per day",
+            UnitCode::KilojoulePerSecond => "Kilojoule per second",
+            UnitCode::KilojoulePerMinute => "Kilojoule per minute",
+            UnitCode::PoundPerFoot => "Pound per foot",
+            UnitCode::KilojoulePerHour => "Kilojoule per hour",
+            UnitCode::KilojoulePerDay => "Kilojoule per day",
+            UnitCode::Nanoohm => "Nanoohm",
+            UnitCode::OhmCircularMilPerFoot => "Ohm circular-mil per foot",
+            UnitCode::Kilohenry => "Kilohenry",
+            UnitCode::LumenPerSquareFoot => "Lumen per square foot",
+            UnitCode::Phot => "Phot",
+            UnitCode::Footcandle => "Footcandle",
+            UnitCode::CandelaPerSquareInch => "Candela per square inch",
+            UnitCode::Footlambert => "Footlambert",
+            UnitCode::Lambert => "Lambert",
+            UnitCode::Stilb => "Stilb",
+            UnitCode::CandelaPerSquareFoot => "Candela per square foot",
+            UnitCode::Kilocandela => "Kilocandela",
+            UnitCode::Millicandela => "Millicandela",
+            UnitCode::HefnerKerze => "Hefner-Kerze",
+            UnitCode::InternationalCandle => "International candle",
+            UnitCode::BritishThermalUnitInternationalTablePerSquareFoot => "British thermal unit (international table) per square foot",
+            UnitCode::BritishThermalUnitThermochemicalPerSquareFoot => "British thermal unit (thermochemical) per square foot",
+            UnitCode::CalorieThermochemicalPerSquareCentimetre => "Calorie (thermochemical) per square centimetre",
+            UnitCode::Langley => "Langley",
+            UnitCode::DecadeLogarithmic => "Decade (logarithmic)",
+            UnitCode::PascalSquaredSecond => "Pascal squared second",
+            UnitCode::BelPerMetre => "Bel per metre",
+            UnitCode::PoundMole => "Pound mole",
+            UnitCode::PoundMolePerSecond => "Pound mole per second",
+            UnitCode::PoundMolePerMinute => "Pound mole per minute",
+            UnitCode::KilomolePerKilogram => "Kilomole per kilogram",
+            UnitCode::PoundMolePerPound => "Pound mole per pound",
+            UnitCode::NewtonSquareMetrePerAmpere => "Newton square metre per ampere",
+            UnitCode::FivePack => "Five pack",
+            UnitCode::WeberMetre => "Weber metre",
+            UnitCode::MolPerKilogramPascal => "Mol per kilogram pascal",
+            UnitCode::MolPerCubicMetrePascal => "Mol per cubic metre pascal",
+            UnitCode::UnitPole => "Unit pole",
+            UnitCode::MilligrayPerSecond => "Milligray per second",
+            UnitCode::MicrograyPerSecond => "Microgray per second",
+            UnitCode::NanograyPerSecond => "Nanogray per second",
+            UnitCode::GrayPerMinute => "Gray per minute",
+            UnitCode::MilligrayPerMinute => "Milligray per minute",
+            UnitCode::MicrograyPerMinute => "Microgray per minute",
+            UnitCode::NanograyPerMinute => "Nanogray per minute",
+            UnitCode::GrayPerHour => "Gray per hour",
+            UnitCode::MilligrayPerHour => "Milligray per hour",
+            UnitCode::MicrograyPerHour => "Microgray per hour",
+            UnitCode::NanograyPerHour => "Nanogray per hour",
+            UnitCode::SievertPerSecond => "Sievert per second",
+            UnitCode::MillisievertPerSecond => "Millisievert per second",
+            UnitCode::MicrosievertPerSecond => "Microsievert per second",
+            UnitCode::NanosievertPerSecond => "Nanosievert per second",
+            UnitCode::RemPerSecond => "Rem per second",
+            UnitCode::SievertPerHour => "Sievert per hour",
+            UnitCode::MillisievertPerHour => "Millisievert per hour",
+            UnitCode::MicrosievertPerHour => "Microsievert per hour",
+            UnitCode::NanosievertPerHour => "Nanosievert per hour",
+            UnitCode::SievertPerMinute => "Sievert per minute",
+            UnitCode::MillisievertPerMinute => "Millisievert per minute",
+            UnitCode::MicrosievertPerMinute => "Microsievert per minute",
+            UnitCode::NanosievertPerMinute => "Nanosievert per minute",
+            UnitCode::ReciprocalSquareInch => "Reciprocal square inch",
+            UnitCode::PascalSquareMetrePerKilogram => "Pascal square metre per kilogram",
+            UnitCode::MillipascalPerMetre => "Millipascal per metre",
+            UnitCode::KilopascalPerMetre => "Kilopascal per metre",
+            UnitCode::HectopascalPerMetre => "Hectopascal per metre",
+            UnitCode::StandardAtmospherePerMetre => "Standard atmosphere per metre",
+            UnitCode::TechnicalAtmospherePerMetre => "Technical atmosphere per metre",
+            UnitCode::TorrPerMetre => "Torr per metre",
+            UnitCode::PsiPerInch => "Psi per inch",
+            UnitCode::CubicMetrePerSecondSquareMetre => "Cubic metre per second square metre",
+            UnitCode::Rhe => "Rhe",
+            UnitCode::PoundForceFootPerInch => "Pound-force foot per inch",
+            UnitCode::PoundForceInchPerInch => "Pound-force inch per inch",
+            UnitCode::Perm0Degc => "Perm (0 degC)",
+            UnitCode::Perm23Degc => "Perm (23 degC)",
+            UnitCode::BytePerSecond => "Byte per second",
+            UnitCode::KilobytePerSecond => "Kilobyte per second",
+            UnitCode::MegabytePerSecond => "Megabyte per second",
+            UnitCode::ReciprocalVolt => "Reciprocal volt",
+            UnitCode::ReciprocalRadian => "Reciprocal radian",
+            UnitCode::PascalToThePowerSumOfStoichiometricNumbers => "Pascal to the power sum of stoichiometric numbers",
+            UnitCode::MolePerCubicMetreToThePowerSumOfStoichiometricNumbers => "Mole per cubic metre to the power sum of stoichiometric numbers",
+            UnitCode::Pascal => "Pascal",
+            UnitCode::Pad => "Pad",
+            UnitCode::ProofLitre => "Proof litre",
+            UnitCode::ProofGallon => "Proof gallon",
+            UnitCode::Pitch => "Pitch",
+            UnitCode::DegreePlato => "Degree Plato",
+            UnitCode::PoundPerInchOfLength => "Pound per inch of length",
+            UnitCode::PagePerInch => "Page per inch",
+            UnitCode::Pair => "Pair",
+            UnitCode::PoundForcePerSquareInch => "Pound-force per square inch",
+            UnitCode::DryPintUs => "Dry pint (US)",
+            UnitCode::PintUk => "Pint (UK)",
+            UnitCode::LiquidPintUs => "Liquid pint (US)",
+            UnitCode::Portion => "Portion",
+            UnitCode::JoulePerTesla => "Joule per tesla",
+            UnitCode::Erlang => "Erlang",
+            UnitCode::Octet => "Octet",
+            UnitCode::OctetPerSecond => "Octet per second",
+            UnitCode::Shannon => "Shannon",
+            UnitCode::Hartley => "Hartley",
+            UnitCode::NaturalUnitOfInformation => "Natural unit of information",
+            UnitCode::ShannonPerSecond => "Shannon per second",
+            UnitCode::HartleyPerSecond => "Hartley per second",
+            UnitCode::NaturalUnitOfInformationPerSecond => "Natural unit of information per second",
+            UnitCode::SecondPerKilogramm => "Second per kilogramm",
+            UnitCode::WattSquareMetre => "Watt square metre",
+            UnitCode::SecondPerRadianCubicMetre => "Second per radian cubic metre",
+            UnitCode::WeberToThePowerMinusOne => "Weber to the power minus one",
+            UnitCode::ReciprocalInch => "Reciprocal inch",
+            UnitCode::Dioptre => "Dioptre",
+            UnitCode::OnePerOne => "One per one",
+            UnitCode::NewtonMetrePerMetre => "Newton metre per metre",
+            UnitCode::KilogramPerSquareMetrePascalSecond => "Kilogram per square metre pascal second",
+            UnitCode::MicrogramPerHectogram => "Microgram per hectogram",
+            UnitCode::Meal => "Meal",
+            UnitCode::PhPotentialOfHydrogen => "PH (potential of Hydrogen)",
+            UnitCode::KilojoulePerGram => "Kilojoule per gram",
+            UnitCode::Femtolitre => "Femtolitre",
+            UnitCode::Picolitre => "Picolitre",
+            UnitCode::Nanolitre => "Nanolitre",
+            UnitCode::MegawattsPerMinute => "Megawatts per minute",
+            UnitCode::SquareMetrePerCubicMetre => "Square metre per cubic metre",
+            UnitCode::StandardCubicMetrePerDay => "Standard cubic metre per day",
+            UnitCode::StandardCubicMetrePerHour => "Standard cubic metre per hour",
+            UnitCode::NormalizedCubicMetrePerDay => "Normalized cubic metre per day",
+            UnitCode::NormalizedCubicMetrePerHour => "Normalized cubic metre per hour",
+            UnitCode::JoulePerNormalisedCubicMetre => "Joule per normalised cubic metre",
+            UnitCode::JoulePerStandardCubicMetre => "Joule per standard cubic metre",
+            UnitCode::PageFacsimile => "Page - facsimile",
+            UnitCode::QuarterOfAYear => "Quarter (of a year)",
+            UnitCode::PageHardcopy => "Page - hardcopy",
+            UnitCode::Quire => "Quire",
+            UnitCode::DryQuartUs => "Dry quart (US)",
+            UnitCode::QuartUk => "Quart (UK)",
+            UnitCode::LiquidQuartUs => "Liquid quart (US)",
+            UnitCode::QuarterUk => "Quarter (UK)",
+            UnitCode::Pica => "Pica",
+            UnitCode::ThousandCubicMetre => "Thousand cubic metre",
+            UnitCode::RunningOrOperatingHour => "Running or operating hour",
+            UnitCode::Ream => "Ream",
+            UnitCode::Room => "Room",
+            UnitCode::PoundPerReam => "Pound per ream",
+            UnitCode::RevolutionsPerMinute => "Revolutions per minute",
+            UnitCode::RevolutionsPerSecond => "Revolutions per second",
+            UnitCode::RevenueTonMile => "Revenue ton mile",
+            UnitCode::SquareFootPerSecond => "Square foot per second",
+            UnitCode::SquareMetrePerSecond => "Square metre per second",
+            UnitCode::HalfYear6Months => "Half year (6 months)",
+            UnitCode::Score => "Score",
+            UnitCode::Scruple => "Scruple",
+            UnitCode::SecondUnitOfTime => "Second [unit of time]",
+            UnitCode::Set => "Set",
+            UnitCode::Segment => "Segment",
+            UnitCode::Siemens => "Siemens",
+            UnitCode::StandardCubicMetre => "Standard cubic metre",
+            UnitCode::MileStatuteMile => "Mile (statute mile)",
+            UnitCode::Square => "Square",
+            UnitCode::SquareRoofing => "Square, roofing",
+            UnitCode::Strip => "Strip",
+            UnitCode::Stick => "Stick",
+            UnitCode::StoneUk => "Stone (UK)",
+            UnitCode::StickCigarette => "Stick, cigarette",
+            UnitCode::StandardLitre => "Standard litre",
+            UnitCode::TonUsOrShortTonUkPerUs => "Ton (US) or short ton (UK/US)",
+            UnitCode::Straw => "Straw",
+            UnitCode::Skein => "Skein",
+            UnitCode::Shipment => "Shipment",
+            UnitCode::Syringe => "Syringe",
+            UnitCode::TelecommunicationLineInService => "Telecommunication line in service",
+            UnitCode::ThousandPiece => "Thousand piece",
+            UnitCode::KiloampereHourThousandAmpereHour => "Kiloampere hour (thousand ampere hour)",
+            UnitCode::TotalAcidNumber => "Total acid number",
+            UnitCode::ThousandSquareInch => "Thousand square inch",
+            UnitCode::MetricTonIncludingContainer => "Metric ton, including container",
+            UnitCode::MetricTonIncludingInnerPackaging => "Metric ton, including inner packaging",
+            UnitCode::TonneKilometre => "Tonne kilometre",
+            UnitCode::KilogramOfImportedMeatLessOffal => "Kilogram of imported meat, less offal",
+            UnitCode::TonneMetricTon => "Tonne (metric ton)",
+            UnitCode::TenPack => "Ten pack",
+            UnitCode::TeethPerInch => "Teeth per inch",
+            UnitCode::TenPair => "Ten pair",
+            UnitCode::ThousandCubicMetrePerDay => "Thousand cubic metre per day",
+            UnitCode::TrillionEur => "Trillion (EUR)",
+            UnitCode::TenSet => "Ten set",
+            UnitCode::TenThousandSticks => "Ten thousand sticks",
+            UnitCode::Treatment => "Treatment",
+            UnitCode::Tablet => "Tablet",
+            UnitCode::TelecommunicationLineInServiceAverage => "Telecommunication line in service average",
+            UnitCode::TelecommunicationPort => "Telecommunication port",
+            UnitCode::VoltAmperePerKilogram => "Volt - ampere per kilogram",
+            UnitCode::Volt => "Volt",
+            UnitCode::PercentVolume => "Percent volume",
+            UnitCode::WetKilo => "Wet kilo",
+            UnitCode::WattPerKilogram => "Watt per kilogram",
+            UnitCode::WetPound => "Wet pound",
+            UnitCode::Cord => "Cord",
+            UnitCode::WetTon => "Wet ton",
+            UnitCode::Weber => "Weber",
+            UnitCode::Week => "Week",
+            UnitCode::WineGallon => "Wine gallon",
+            UnitCode::WattHour => "Watt hour",
+            UnitCode::WorkingMonth => "Working month",
+            UnitCode::Standard => "Standard",
+            UnitCode::Watt => "Watt",
+            UnitCode::GuntersChain => "Gunter's chain",
+            UnitCode::SquareYard => "Square yard",
+            UnitCode::CubicYard => "Cubic yard",
+            UnitCode::Yard => "Yard",
+            UnitCode::HangingContainer => "Hanging container",
+            UnitCode::Nanomole => "Nanomole",
+            UnitCode::Page => "Page",
+            UnitCode::MutuallyDefined => "Mutually defined",
+            UnitCode::DrumSteel => "Drum, steel",
+            UnitCode::DrumAluminium => "Drum, aluminium",
+            UnitCode::DrumPlywood => "Drum, plywood",
+            UnitCode::ContainerFlexible => "Container, flexible",
+            UnitCode::DrumFibre => "Drum, fibre",
+            UnitCode::DrumWooden => "Drum, wooden",
+            UnitCode::BarrelWooden => "Barrel, wooden",
+            UnitCode::JerricanSteel => "Jerrican, steel",
+            UnitCode::JerricanPlastic => "Jerrican, plastic",
+            UnitCode::BagSuperBulk => "Bag, super bulk",
+            UnitCode::BagPolybag => "Bag, polybag",
+            UnitCode::BoxSteel => "Box, steel",
+            UnitCode::BoxAluminium => "Box, aluminium",
+            UnitCode::BoxNaturalWood => "Box, natural wood",
+            UnitCode::BoxPlywood => "Box, plywood",
+            UnitCode::BoxReconstitutedWood => "Box, reconstituted wood",
+            UnitCode::BoxFibreboard => "Box, fibreboard",
+            UnitCode::BoxPlastic => "Box, plastic",
+            UnitCode::BagWovenPlastic => "Bag, woven plastic",
+            UnitCode::BagTextile => "Bag, textile",
+            UnitCode::BagPaper => "Bag, paper",
+            UnitCode::CompositePackagingPlasticReceptacle => "Composite packaging, plastic receptacle",
+            UnitCode::CompositePackagingGlassReceptacle => "Composite packaging, glass receptacle",
+            UnitCode::CaseCar => "Case, car",
+            UnitCode::CaseWooden => "Case, wooden",
+            UnitCode::PalletWooden => "Pallet, wooden",
+            UnitCode::CrateWooden => "Crate, wooden",
+            UnitCode::BundleWooden => "Bundle, wooden",
+            UnitCode::IntermediateBulkContainerRigidPlastic => "Intermediate bulk container, rigid plastic",
+            UnitCode::ReceptacleFibre => "Receptacle, fibre",
+            UnitCode::ReceptaclePaper => "Receptacle, paper",
+            UnitCode::ReceptacleWooden => "Receptacle, wooden",
+            UnitCode::Aerosol => "Aerosol",
+            UnitCode::PalletModularCollars80cms60cms => "Pallet, modular, collars 80cms * 60cms",
+            UnitCode::PalletShrinkwrapped => "Pallet, shrinkwrapped",
+            UnitCode::Pallet100cms110cms => "Pallet, 100cms * 110cms",
+            UnitCode::Clamshell => "Clamshell",
+            UnitCode::Cone => "Cone",
+            UnitCode::PackageBall => "Ball",
+            UnitCode::AmpouleNonProtected => "Ampoule, non-protected",
+            UnitCode::AmpouleProtected => "Ampoule, protected",
+            UnitCode::Atomizer => "Atomizer",
+            UnitCode::Capsule => "Capsule",
+            UnitCode::Belt => "Belt",
+            UnitCode::Barrel => "Barrel",
+            UnitCode::Bobbin => "Bobbin",
+            UnitCode::BottlecratePerBottlerack => "Bottlecrate / bottlerack",
+            UnitCode::Board => "Board",
+            UnitCode::Bundle => "Bundle",
+            UnitCode::BalloonNonProtected => "Balloon, non-protected",
+            UnitCode::Bag => "Bag",
+            UnitCode::Bunch => "Bunch",
+            UnitCode::Bin => "Bin",
+            UnitCode::Bucket => "Bucket",
+            UnitCode::Basket => "Basket",
+            UnitCode::BaleCompressed => "Bale, compressed",
+            UnitCode::Basin => "Basin",
+            UnitCode::BaleNonCompressed => "Bale, non-compressed",
+            UnitCode::BottleNonProtectedCylindrical => "Bottle, non-protected, cylindrical",
+            UnitCode::BalloonProtected => "Balloon, protected",
+            UnitCode::BottleProtectedCylindrical => "Bottle, protected cylindrical",
+            UnitCode::Bar => "Bar",
+            UnitCode::BottleNonProtectedBulbous => "Bottle, non-protected, bulbous",
+            UnitCode::Bolt => "Bolt",
+            UnitCode::Butt => "Butt",
+            UnitCode::BottleProtectedBulbous => "Bottle, protected bulbous",
+            UnitCode::BoxForLiquids => "Box, for liquids",
+            UnitCode::Box => "Box",
+            UnitCode::BoardInBundlePerBunchPerTruss => "Board, in bundle/bunch/truss",
+            UnitCode::BarsInBundlePerBunchPerTruss => "Bars, in bundle/bunch/truss",
+            UnitCode::CanRectangular => "Can, rectangular",
+            UnitCode::CrateBeer => "Crate, beer",
+            UnitCode::Churn => "Churn",
+            UnitCode::CanWithHandleAndSpout => "Can, with handle and spout",
+            UnitCode::Creel => "Creel",
+            UnitCode::Coffer => "Coffer",
+            UnitCode::Cage => "Cage",
+            UnitCode::Chest => "Chest",
+            UnitCode::Canister => "Canister",
+            UnitCode::Coffin => "Coffin",
+            UnitCode::Cask => "Cask",
+            UnitCode::Coil => "Coil",
+            UnitCode::PackageCard => "Card",
+            UnitCode::ContainerNotOtherwiseSpecifiedAsTransportEquipment => "Container, not otherwise specified as transport equipment",
+            UnitCode::CarboyNonProtected => "Carboy, non-protected",
+            UnitCode::CarboyProtected => "Carboy, protected",
+            UnitCode::Cartridge => "Cartridge",
+            UnitCode::Crate => "Crate",
+            UnitCode::Case => "Case",
+            UnitCode::Carton => "Carton",
+            UnitCode::Cup => "Cup",
+            UnitCode::Cover => "Cover",
+            UnitCode::CageRoll => "Cage, roll",
+            UnitCode::CanCylindrical => "Can, cylindrical",
+            UnitCode::Cylinder => "Cylinder",
+            UnitCode::Canvas => "Canvas",
+            UnitCode::CrateMultipleLayerPlastic => "Crate, multiple layer, plastic",
+            UnitCode::CrateMultipleLayerWooden => "Crate, multiple layer, wooden",
+            UnitCode::CrateMultipleLayerCardboard => "Crate, multiple layer, cardboard",
+            UnitCode::CageCommonwealthHandlingEquipmentPoolChep => "Cage, Commonwealth Handling Equipment Pool (CHEP)",
+            UnitCode::BoxCommonwealthHandlingEquipmentPoolChepEurobox => "Box, Commonwealth Handling Equipment Pool (CHEP), Eurobox",
+            UnitCode::DrumIron => "Drum, iron",
+            UnitCode::DemijohnNonProtected => "Demijohn, non-protected",
+            UnitCode::CrateBulkCardboard => "Crate, bulk, cardboard",
+            UnitCode::CrateBulkPlastic => "Crate, bulk, plastic",
+            UnitCode::CrateBulkWooden => "Crate, bulk, wooden",
+            UnitCode::Dispenser => "Dispenser",
+            UnitCode::DemijohnProtected => "Demijohn, protected",
+            UnitCode::Drum => "Drum",
+            UnitCode::TrayOneLayerNoCoverPlastic => "Tray, one layer no cover, plastic",
+            UnitCode::TrayOneLayerNoCoverWooden => "Tray, one layer no cover, wooden",
+            UnitCode::TrayOneLayerNoCoverPolystyrene => "Tray, one layer no cover, polystyrene",
+            UnitCode::TrayOneLayerNoCoverCardboard => "Tray, one layer no cover, cardboard",
+            UnitCode::TrayTwoLayersNoCoverPlasticTray => "Tray, two layers no cover, plastic tray",
+            UnitCode::TrayTwoLayersNoCoverWooden => "Tray, two layers no cover, wooden",
+            UnitCode::TrayTwoLayersNoCoverCardboard => "Tray, two layers no cover, cardboard",
+            UnitCode::BagPlastic => "Bag, plastic",
+            UnitCode::CaseWithPalletBase => "Case, with pallet base",
+            UnitCode::CaseWithPalletBaseWooden => "Case, with pallet base, wooden",
+            UnitCode::CaseWithPalletBaseCardboard => "Case, with pallet base, cardboard",
+            UnitCode::CaseWithPalletBasePlastic => "Case, with pallet base, plastic",
+            UnitCode::CaseWithPalletBaseMetal => "Case, with pallet base, metal",
+            UnitCode::CaseIsothermic => "Case, isothermic",
+            UnitCode::Envelope => "Envelope",
+            UnitCode::Flexibag => "Flexibag",
+            UnitCode::CrateFruit => "Crate, fruit",
+            UnitCode::CrateFramed => "Crate, framed",
+            UnitCode::Flexitank => "Flexitank",
+            UnitCode::Firkin => "Firkin",
+            UnitCode::Flask => "Flask",
+            UnitCode::Footlocker => "Footlocker",
+            UnitCode::Filmpack => "Filmpack",
+            UnitCode::Frame => "Frame",
+            UnitCode::Foodtainer => "Foodtainer",
+            UnitCode::CartFlatbed => "Cart, flatbed",
+            UnitCode::BagFlexibleContainer => "Bag, flexible container",
+            UnitCode::BottleGas => "Bottle, gas",
+            UnitCode::Girder => "Girder",
+            UnitCode::ContainerGallon => "Container, gallon",
+            UnitCode::ReceptacleGlass => "Receptacle, glass",
+            UnitCode::TrayContainingHorizontallyStackedFlatItems => "Tray, containing horizontally stacked flat items",
+            UnitCode::BagGunny => "Bag, gunny",
+            UnitCode::GirdersInBundlePerBunchPerTruss => "Girders, in bundle/bunch/truss",
+            UnitCode::BasketWithHandlePlastic => "Basket, with handle, plastic",
+            UnitCode::BasketWithHandleWooden => "Basket, with handle, wooden",
+            UnitCode::BasketWithHandleCardboard => "Basket, with handle, cardboard",
+            UnitCode::Hogshead => "Hogshead",
+            UnitCode::Hanger => "Hanger",
+            UnitCode::Hamper => "Hamper",
+            UnitCode::PackageDisplayWooden => "Package, display, wooden",
+            UnitCode::PackageDisplayCardboard => "Package, display, cardboard",
+            UnitCode::PackageDisplayPlastic => "Package, display, plastic",
+            UnitCode::PackageDisplayMetal => "Package, display, metal",
+            UnitCode::PackageShow => "Package, show",
+            UnitCode::PackageFlow => "Package, flow",
+            UnitCode::PackagePaperWrapped => "Package, paper wrapped",
+            UnitCode::DrumPlastic => "Drum, plastic",
+            UnitCode::PackageCardboardWithBottleGripHoles => "Package, cardboard, with bottle grip-holes",
+            UnitCode::TrayRigidLiddedStackableCenTs144822002 => "Tray, rigid, lidded stackable (CEN TS 14482:2002)",
+            UnitCode::Ingot => "Ingot",
+            UnitCode::IngotsInBundlePerBunchPerTruss => "Ingots, in bundle/bunch/truss",
+            UnitCode::BagJumbo => "Bag, jumbo",
+            UnitCode::JerricanRectangular => "Jerrican, rectangular",
+            UnitCode::Jug => "Jug",
+            UnitCode::Jar => "Jar",
+            UnitCode::Jutebag => "Jutebag",
+            UnitCode::JerricanCylindrical => "Jerrican, cylindrical",
+            UnitCode::Keg => "Keg",
+            UnitCode::PackageKit => "Kit",
+            UnitCode::Luggage => "Luggage",
+            UnitCode::Log => "Log",
+            UnitCode::Lot => "Lot",
+            UnitCode::Lug => "Lug",
+            UnitCode::Liftvan => "Liftvan",
+            UnitCode::LogsInBundlePerBunchPerTruss => "Logs, in bundle/bunch/truss",
+            UnitCode::CrateMetal => "Crate, metal",
+            UnitCode::BagMultiply => "Bag, multiply",
+            UnitCode::CrateMilk => "Crate, milk",
+            UnitCode::ContainerMetal => "Container, metal",
+            UnitCode::ReceptacleMetal => "Receptacle, metal",
+            UnitCode::SackMultiWall => "Sack, multi-wall",
+            UnitCode::Mat => "Mat",
+            UnitCode::ReceptaclePlasticWrapped => "Receptacle, plastic wrapped",
+            UnitCode::Matchbox => "Matchbox",
+            UnitCode::NotAvailable => "Not available",
+            UnitCode::UnpackedOrUnpackaged => "Unpacked or unpackaged",
+            UnitCode::UnpackedOrUnpackagedSingleUnit => "Unpacked or unpackaged, single unit",
+            UnitCode::UnpackedOrUnpackagedMultipleUnits => "Unpacked or unpackaged, multiple units",
+            UnitCode::Nest => "Nest",
+            UnitCode::Net => "Net",
+            UnitCode::NetTubePlastic => "Net, tube, plastic",
+            UnitCode::NetTubeTextile => "Net, tube, textile",
+            UnitCode::TwoSidedCageOnWheelsWithFixingStrap => "Two sided cage on wheels with fixing strap",
+            UnitCode::Trolley => "Trolley",
+            UnitCode::OnewayPalletIso01Per2EuroPallet => "Oneway pallet ISO 0 - 1/2 EURO pallet",
+            UnitCode::OnewayPalletIso11Per1EuroPallet => "Oneway pallet ISO 1 - 1/1 EURO pallet",
+            UnitCode::OnewayPalletIso22Per1EuroPallet => "Oneway pallet ISO 2 - 2/1 EURO pallet",
+            UnitCode::PalletWithExceptionalDimensions => "Pallet with exceptional dimensions",
+            UnitCode::WoodenPallet40CmX80Cm => "Wooden pallet 40 cm x 80 cm",
+            UnitCode::PlasticPalletSrs60CmX80Cm => "Plastic pallet SRS 60 cm x 80 cm",
+            UnitCode::PlasticPalletSrs80CmX120Cm => "Plastic pallet SRS 80 cm x 120 cm",
+            UnitCode::PalletChep40CmX60Cm => "Pallet, CHEP 40 cm x 60 cm",
+            UnitCode::PalletChep80CmX120Cm => "Pallet, CHEP 80 cm x 120 cm",
+            UnitCode::PalletChep100CmX120Cm => "Pallet, CHEP 100 cm x 120 cm",
+            UnitCode::PalletAs40681993 => "Pallet, AS 4068-1993",
+            UnitCode::PalletIsoT11 => "Pallet, ISO T11",
+            UnitCode::PlatformUnspecifiedWeightOrDimension => "Platform, unspecified weight or dimension",
+            UnitCode::PalletIso01Per2EuroPallet => "Pallet ISO 0 - 1/2 EURO pallet",
+            UnitCode::PalletIso11Per1EuroPallet => "Pallet ISO 1 - 1/1 EURO pallet",
+            UnitCode::PalletIso22Per1EuroPallet => "Pallet ISO 2 - 2/1 EURO pallet",
+            UnitCode::QuarterEuroPallet => "1/4 EURO pallet",
+            UnitCode::Block => "Block",
+            UnitCode::EighthEuroPallet => "1/8 EURO pallet",
+            UnitCode::SyntheticPalletIso1 => "Synthetic pallet ISO 1",
+            UnitCode::SyntheticPalletIso2 => "Synthetic pallet ISO 2",
+            UnitCode::WholesalerPallet => "Wholesaler pallet",
+            UnitCode::Pallet80X100Cm => "Pallet 80 X 100 cm",
+            UnitCode::Pallet60X100Cm => "Pallet 60 X 100 cm",
+            UnitCode::OnewayPallet => "Oneway pallet",
+            UnitCode::Octabin => "Octabin",
+            UnitCode::ContainerOuter => "Container, outer",
+            UnitCode::ReturnablePallet => "Returnable pallet",
+            UnitCode::LargeBagPalletSized => "Large bag, pallet sized",
+            UnitCode::AWheeledPalletWithRaisedRim81X67X135 => "A wheeled pallet with raised rim (81 x 67 x 135)",
+            UnitCode::AWheeledPalletWithRaisedRim81X72X135 => "A wheeled pallet with raised rim (81 x 72 x 135)",
+            UnitCode::WheeledPalletWithRaisedRim81X60X16 => "Wheeled pallet with raised rim (81 x 60 x 16)",
+            UnitCode::ChepPallet60CmX80Cm => "CHEP pallet 60 cm x 80 cm",
+            UnitCode::Pan => "Pan",
+            UnitCode::LprPallet60CmX80Cm => "LPR pallet 60 cm x 80 cm",
+            UnitCode::LprPallet80CmX120Cm => "LPR pallet 80 cm x 120 cm",
+            UnitCode::Packet => "Packet",
+            UnitCode::PalletBoxCombinedOpenEndedBoxAndPallet => "Pallet, box combined open-ended box and pallet",
+            UnitCode::Parcel => "Parcel",
+            UnitCode::PalletModularCollars80cms100cms => "Pallet, modular, collars 80cms * 100cms",
+            UnitCode::PalletModularCollars80cms120cms => "Pallet, modular, collars 80cms * 120cms",
+            UnitCode::Pen => "Pen",
+            UnitCode::Plate => "Plate",
+            UnitCode::Pitcher => "Pitcher",
+            UnitCode::Pipe => "Pipe",
+            UnitCode::Punnet => "Punnet",
+            UnitCode::Package => "Package",
+            UnitCode::Pail => "Pail",
+            UnitCode::Plank => "Plank",
+            UnitCode::Pouch => "Pouch",
+            UnitCode::PackagePiece => "Piece",
+            UnitCode::ReceptaclePlastic => "Receptacle, plastic",
+            UnitCode::Pot => "Pot",
+            UnitCode::Tray => "Tray",
+            UnitCode::PipesInBundlePerBunchPerTruss => "Pipes, in bundle/bunch/truss",
+            UnitCode::Pallet => "Pallet",
+            UnitCode::PlatesInBundlePerBunchPerTruss => "Plates, in bundle/bunch/truss",
+            UnitCode::PlanksInBundlePerBunchPerTruss => "Planks, in bundle/bunch/truss",
+            UnitCode::DrumSteelNonRemovableHead => "Drum, steel, non-removable head",
+            UnitCode::DrumSteelRemovableHead => "Drum, steel, removable head",
+            UnitCode::DrumAluminiumNonRemovableHead => "Drum, aluminium, non-removable head",
+            UnitCode::DrumAluminiumRemovableHead => "Drum, aluminium, removable head",
+            UnitCode::DrumPlasticNonRemovableHead => "Drum, plastic, non-removable head",
+            UnitCode::DrumPlasticRemovableHead => "Drum, plastic, removable head",
+            UnitCode::BarrelWoodenBungType => "Barrel, wooden, bung type",
+            UnitCode::BarrelWoodenRemovableHead => "Barrel, wooden, removable head",
+            UnitCode::JerricanSteelNonRemovableHead => "Jerrican, steel, non-removable head",
+            UnitCode::JerricanSteelRemovableHead => "Jerrican, steel, removable head",
+            UnitCode::JerricanPlasticNonRemovableHead => "Jerrican, plastic, non-removable head",
+            UnitCode::JerricanPlasticRemovableHead => "Jerrican, plastic, removable head",
+            UnitCode::BoxWoodenNaturalWoodOrdinary => "Box, wooden, natural wood, ordinary",
+            UnitCode::BoxWoodenNaturalWoodWithSiftProofWalls => "Box, wooden, natural wood, with sift proof walls",
+            UnitCode::BoxPlasticExpanded => "Box, plastic, expanded",
+            UnitCode::BoxPlasticSolid => "Box, plastic, solid",
+            UnitCode::Rod => "Rod",
+            UnitCode::Ring => "Ring",
+            UnitCode::RackClothingHanger => "Rack, clothing hanger",
+            UnitCode::Rack => "Rack",
+            UnitCode::Reel => "Reel",
+            UnitCode::Roll => "Roll",
+            UnitCode::Rednet => "Rednet",
+            UnitCode::RodsInBundlePerBunchPerTruss => "Rods, in bundle/bunch/truss",
+            UnitCode::Sack => "Sack",
+            UnitCode::Slab => "Slab",
+            UnitCode::CrateShallow => "Crate, shallow",
+            UnitCode::Spindle => "Spindle",
+            UnitCode::SeaChest => "Sea-chest",
+            UnitCode::Sachet => "Sachet",
+            UnitCode::Skid => "Skid",
+            UnitCode::CaseSkeleton => "Case, skeleton",
+            UnitCode::Slipsheet => "Slipsheet",
+            UnitCode::Sheetmetal => "Sheetmetal",
+            UnitCode::Spool => "Spool",
+            UnitCode::SheetPlasticWrapping => "Sheet, plastic wrapping",
+            UnitCode::CaseSteel => "Case, steel",
+            UnitCode::Sheet => "Sheet",
+            UnitCode::Suitcase => "Suitcase",
+            UnitCode::EnvelopeSteel => "Envelope, steel",
+            UnitCode::Shrinkwrapped => "Shrinkwrapped",
+            UnitCode::PackageSet => "Set",
+            UnitCode::Sleeve => "Sleeve",
+            UnitCode::SheetsInBundlePerBunchPerTruss => "Sheets, in bundle/bunch/truss",
+            UnitCode::PackageTablet => "Tablet",
+            UnitCode::Tub => "Tub",
+            UnitCode::TeaChest => "Tea-chest",
+            UnitCode::CollapsibleTube => "Collapsible tube",
+            UnitCode::PackageTyre => "Tyre",
+            UnitCode::TankContainerGeneric => "Tank container, generic",
+            UnitCode::Tierce => "Tierce",
+            UnitCode::TankRectangular => "Tank, rectangular",
+            UnitCode::TubWithLid => "Tub, with lid",
+            UnitCode::Tin => "Tin",
+            UnitCode::Tun => "Tun",
+            UnitCode::Trunk => "Trunk",
+            UnitCode::Truss => "Truss",
+            UnitCode::BagTote => "Bag, tote",
+            UnitCode::Tube => "Tube",
+            UnitCode::TubeWithNozzle => "Tube, with nozzle",
+            UnitCode::PalletTriwall => "Pallet, triwall",
+            UnitCode::TankCylindrical => "Tank, cylindrical",
+            UnitCode::TubesInBundlePerBunchPerTruss => "Tubes, in bundle/bunch/truss",
+            UnitCode::Uncaged => "Uncaged",
+            UnitCode::Unit => "Unit",
+            UnitCode::Vat => "Vat",
+            UnitCode::BulkGasAt1031MbarAnd15Degc => "Bulk, gas (at 1031 mbar and 15 degC)",
+            UnitCode::Vial => "Vial",
+            UnitCode::Vanpack => "Vanpack",
+            UnitCode::BulkLiquid => "Bulk, liquid",
+            UnitCode::Vehicle => "Vehicle",
+            UnitCode::BulkSolidLargeParticlesNodules => "Bulk, solid, large particles (\"nodules\")",
+            UnitCode::VacuumPacked => "Vacuum-packed",
+            UnitCode::BulkLiquefiedGasAtAbnormalTemperaturePerPressure => "Bulk, liquefied gas (at abnormal temperature/pressure)",
+            UnitCode::BulkSolidGranularParticlesGrains => "Bulk, solid, granular particles (\"grains\")",
+            UnitCode::BulkScrapMetal => "Bulk, scrap metal",
+            UnitCode::BulkSolidFineParticlesPowders => "Bulk, solid, fine particles (\"powders\")",
+            UnitCode::IntermediateBulkContainer => "Intermediate bulk container",
+            UnitCode::Wickerbottle => "Wickerbottle",
+            UnitCode::IntermediateBulkContainerSteel => "Intermediate bulk container, steel",
+            UnitCode::IntermediateBulkContainerAluminium => "Intermediate bulk container, aluminium",
+            UnitCode::IntermediateBulkContainerMetal => "Intermediate bulk container, metal",
+            UnitCode::IntermediateBulkContainerSteelPressurisedOver10Kpa => "Intermediate bulk container, steel, pressurised > 10 kpa",
+            UnitCode::IntermediateBulkContainerAluminiumPressurisedOver10Kpa => "Intermediate bulk container, aluminium, pressurised > 10 kpa",
+            UnitCode::IntermediateBulkContainerMetalPressure10Kpa => "Intermediate bulk container, metal, pressure 10 kpa",
+            UnitCode::IntermediateBulkContainerSteelLiquid => "Intermediate bulk container, steel, liquid",
+            UnitCode::IntermediateBulkContainerAluminiumLiquid => "Intermediate bulk container, aluminium, liquid",
+            UnitCode::IntermediateBulkContainerMetalLiquid => "Intermediate bulk container, metal, liquid",
+            UnitCode::IntermediateBulkContainerWovenPlasticWithoutCoatPerLiner => "Intermediate bulk container, woven plastic, without coat/liner",
+            UnitCode::IntermediateBulkContainerWovenPlasticCoated => "Intermediate bulk container, woven plastic, coated",
+            UnitCode::IntermediateBulkContainerWovenPlasticWithLiner => "Intermediate bulk container, woven plastic, with liner",
+            UnitCode::IntermediateBulkContainerWovenPlasticCoatedAndLiner => "Intermediate bulk container, woven plastic, coated and liner",
+            UnitCode::IntermediateBulkContainerPlasticFilm => "Intermediate bulk container, plastic film",
+            UnitCode::IntermediateBulkContainerTextileWithOutCoatPerLiner => "Intermediate bulk container, textile with out coat/liner",
+            UnitCode::IntermediateBulkContainerNaturalWoodWithInnerLiner => "Intermediate bulk container, natural wood, with inner liner",
+            UnitCode::IntermediateBulkContainerTextileCoated => "Intermediate bulk container, textile, coated",
+            UnitCode::IntermediateBulkContainerTextileWithLiner => "Intermediate bulk container, textile, with liner",
+            UnitCode::IntermediateBulkContainerTextileCoatedAndLiner => "Intermediate bulk container, textile, coated and liner",
+            UnitCode::IntermediateBulkContainerPlywoodWithInnerLiner => "Intermediate bulk container, plywood, with inner liner",
+            UnitCode::IntermediateBulkContainerReconstitutedWoodWithInnerLiner => "Intermediate bulk container, reconstituted wood, with inner liner",
+            UnitCode::BagWovenPlasticWithoutInnerCoatPerLiner => "Bag, woven plastic, without inner coat/liner",
+            UnitCode::BagWovenPlasticSiftProof => "Bag, woven plastic, sift proof",
+            UnitCode::BagWovenPlasticWaterResistant => "Bag, woven plastic, water resistant",
+            UnitCode::BagPlasticsFilm => "Bag, plastics film",
+            UnitCode::BagTextileWithoutInnerCoatPerLiner => "Bag, textile, without inner coat/liner",
+            UnitCode::BagTextileSiftProof => "Bag, textile, sift proof",
+            UnitCode::BagTextileWaterResistant => "Bag, textile, water resistant",
+            UnitCode::BagPaperMultiWall => "Bag, paper, multi-wall",
+            UnitCode::BagPaperMultiWallWaterResistant => "Bag, paper, multi-wall, water resistant",
+            UnitCode::CompositePackagingPlasticReceptacleInSteelDrum => "Composite packaging, plastic receptacle in steel drum",
+            UnitCode::CompositePackagingPlasticReceptacleInSteelCrateBox => "Composite packaging, plastic receptacle in steel crate box",
+            UnitCode::CompositePackagingPlasticReceptacleInAluminiumDrum => "Composite packaging, plastic receptacle in aluminium drum",
+            UnitCode::CompositePackagingPlasticReceptacleInAluminiumCrate => "Composite packaging, plastic receptacle in aluminium crate",
+            UnitCode::CompositePackagingPlasticReceptacleInWoodenBox => "Composite packaging, plastic receptacle in wooden box",
+            UnitCode::CompositePackagingPlasticReceptacleInPlywoodDrum => "Composite packaging, plastic receptacle in plywood drum",
+            UnitCode::CompositePackagingPlasticReceptacleInPlywoodBox => "Composite packaging, plastic receptacle in plywood box",
+            UnitCode::CompositePackagingPlasticReceptacleInFibreDrum => "Composite packaging, plastic receptacle in fibre drum",
+            UnitCode::CompositePackagingPlasticReceptacleInFibreboardBox => "Composite packaging, plastic receptacle in fibreboard box",
+            UnitCode::CompositePackagingPlasticReceptacleInPlasticDrum => "Composite packaging, plastic receptacle in plastic drum",
+            UnitCode::CompositePackagingPlasticReceptacleInSolidPlasticBox => "Composite packaging, plastic receptacle in solid plastic box",
+            UnitCode::CompositePackagingGlassReceptacleInSteelDrum => "Composite packaging, glass receptacle in steel drum",
+            UnitCode::CompositePackagingGlassReceptacleInSteelCrateBox => "Composite packaging, glass receptacle in steel crate box",
+            UnitCode::CompositePackagingGlassReceptacleInAluminiumDrum => "Composite packaging, glass receptacle in aluminium drum",
+            UnitCode::CompositePackagingGlassReceptacleInAluminiumCrate => "Composite packaging, glass receptacle in aluminium crate",
+            UnitCode::CompositePackagingGlassReceptacleInWoodenBox => "Composite packaging, glass receptacle in wooden box",
+            UnitCode::CompositePackagingGlassReceptacleInPlywoodDrum => "Composite packaging, glass receptacle in plywood drum",
+            UnitCode::CompositePackagingGlassReceptacleInWickerworkHamper => "Composite packaging, glass receptacle in wickerwork hamper",
+            UnitCode::CompositePackagingGlassReceptacleInFibreDrum => "Composite packaging, glass receptacle in fibre drum",
+            UnitCode::CompositePackagingGlassReceptacleInFibreboardBox => "Composite packaging, glass receptacle in fibreboard box",
+            UnitCode::CompositePackagingGlassReceptacleInExpandablePlasticPack => "Composite packaging, glass receptacle in expandable plastic pack",
+            UnitCode::CompositePackagingGlassReceptacleInSolidPlasticPack => "Composite packaging, glass receptacle in solid plastic pack",
+            UnitCode::IntermediateBulkContainerPaperMultiWall => "Intermediate bulk container, paper, multi-wall",
+            UnitCode::BagLarge => "Bag, large",
+            UnitCode::IntermediateBulkContainerPaperMultiWallWaterResistant => "Intermediate bulk container, paper, multi-wall, water resistant",
+            UnitCode::IntermediateBulkContainerRigidPlasticWithStructuralEquipmentSolids => "Intermediate bulk container, rigid plastic, with structural equipment, solids",
+            UnitCode::IntermediateBulkContainerRigidPlasticFreestandingSolids => "Intermediate bulk container, rigid plastic, freestanding, solids",
+            UnitCode::IntermediateBulkContainerRigidPlasticWithStructuralEquipmentPressurised => "Intermediate bulk container, rigid plastic, with structural equipment, pressurised",
+            UnitCode::IntermediateBulkContainerRigidPlasticFreestandingPressurised => "Intermediate bulk container, rigid plastic, freestanding, pressurised",
+            UnitCode::IntermediateBulkContainerRigidPlasticWithStructuralEquipmentLiquids => "Intermediate bulk container, rigid plastic, with structural equipment, liquids",
+            UnitCode::IntermediateBulkContainerRigidPlasticFreestandingLiquids => "Intermediate bulk container, rigid plastic, freestanding, liquids",
+            UnitCode::IntermediateBulkContainerCompositeRigidPlasticSolids => "Intermediate bulk container, composite, rigid plastic, solids",
+            UnitCode::IntermediateBulkContainerCompositeFlexiblePlasticSolids => "Intermediate bulk container, composite, flexible plastic, solids",
+            UnitCode::IntermediateBulkContainerCompositeRigidPlasticPressurised => "Intermediate bulk container, composite, rigid plastic, pressurised",
+            UnitCode::IntermediateBulkContainerCompositeFlexiblePlasticPressurised => "Intermediate bulk container, composite, flexible plastic, pressurised",
+            UnitCode::IntermediateBulkContainerCompositeRigidPlasticLiquids => "Intermediate bulk container, composite, rigid plastic, liquids",
+            UnitCode::IntermediateBulkContainerCompositeFlexiblePlasticLiquids => "Intermediate bulk container, composite, flexible plastic, liquids",
+            UnitCode::IntermediateBulkContainerComposite => "Intermediate bulk container, composite",
+            UnitCode::IntermediateBulkContainerFibreboard => "Intermediate bulk container, fibreboard",
+            UnitCode::IntermediateBulkContainerFlexible => "Intermediate bulk container, flexible",
+            UnitCode::IntermediateBulkContainerMetalOtherThanSteel => "Intermediate bulk container, metal, other than steel",
+            UnitCode::IntermediateBulkContainerNaturalWood => "Intermediate bulk container, natural wood",
+            UnitCode::IntermediateBulkContainerPlywood => "Intermediate bulk container, plywood",
+            UnitCode::IntermediateBulkContainerReconstitutedWood => "Intermediate bulk container, reconstituted wood",
+            UnitCode::PackageMutuallyDefined => "Mutually defined",
         }
     }
 }
@@ -20,4 +8677,2176 @@ impl Serialize for UnitCode {
     {
         serializer.serialize_str(self.as_str())
     }
-}
\ No newline at end of file
+}
+
+impl TryFrom<&str> for UnitCode {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        match value {
+            "10" => Ok(UnitCode::Group),
+            "11" => Ok(UnitCode::Outfit),
+            "13" => Ok(UnitCode::Ration),
+            "14" => Ok(UnitCode::Shot),
+            "15" => Ok(UnitCode::StickMilitary),
+            "20" => Ok(UnitCode::TwentyFootContainer),
+            "21" => Ok(UnitCode::FortyFootContainer),
+            "22" => Ok(UnitCode::DecilitrePerGram),
+            "23" => Ok(UnitCode::GramPerCubicCentimetre),
+            "24" => Ok(UnitCode::TheoreticalPound),
+            "25" => Ok(UnitCode::GramPerSquareCentimetre),
+            "27" => Ok(UnitCode::TheoreticalTon),
+            "28" => Ok(UnitCode::KilogramPerSquareMetre),
+            "33" => Ok(UnitCode::KilopascalSquareMetrePerGram),
+            "34" => Ok(UnitCode::KilopascalPerMillimetre),
+            "35" => Ok(UnitCode::MillilitrePerSquareCentimetreSecond),
+            "37" => Ok(UnitCode::OuncePerSquareFoot),
+            "38" => Ok(UnitCode::OuncePerSquareFootPer001inch),
+            "40" => Ok(UnitCode::MillilitrePerSecond),
+            "41" => Ok(UnitCode::MillilitrePerMinute),
+            "56" => Ok(UnitCode::Sitas),
+            "57" => Ok(UnitCode::Mesh),
+            "58" => Ok(UnitCode::NetKilogram),
+            "59" => Ok(UnitCode::PartPerMillion),
+            "60" => Ok(UnitCode::PercentWeight),
+            "61" => Ok(UnitCode::PartPerBillionUs),
+            "74" => Ok(UnitCode::Millipascal),
+            "77" => Ok(UnitCode::MilliInch),
+            "80" => Ok(UnitCode::PoundPerSquareInchAbsolute),
+            "81" => Ok(UnitCode::Henry),
+            "85" => Ok(UnitCode::FootPoundForce),
+            "87" => Ok(UnitCode::PoundPerCubicFoot),
+            "89" => Ok(UnitCode::Poise),
+            "91" => Ok(UnitCode::Stokes),
+            "1I" => Ok(UnitCode::FixedRate),
+            "2A" => Ok(UnitCode::RadianPerSecond),
+            "2B" => Ok(UnitCode::RadianPerSecondSquared),
+            "2C" => Ok(UnitCode::Roentgen),
+            "2G" => Ok(UnitCode::VoltAc),
+            "2H" => Ok(UnitCode::VoltDc),
+            "2I" => Ok(UnitCode::BritishThermalUnitInternationalTablePerHour),
+            "2J" => Ok(UnitCode::CubicCentimetrePerSecond),
+            "2K" => Ok(UnitCode::CubicFootPerHour),
+            "2L" => Ok(UnitCode::CubicFootPerMinute),
+            "2M" => Ok(UnitCode::CentimetrePerSecond),
+            "2N" => Ok(UnitCode::Decibel),
+            "2P" => Ok(UnitCode::Kilobyte),
+            "2Q" => Ok(UnitCode::Kilobecquerel),
+            "2R" => Ok(UnitCode::Kilocurie),
+            "2U" => Ok(UnitCode::Megagram),
+            "2X" => Ok(UnitCode::MetrePerMinute),
+            "2Y" => Ok(UnitCode::Milliroentgen),
+            "2Z" => Ok(UnitCode::Millivolt),
+            "3B" => Ok(UnitCode::Megajoule),
+            "3C" => Ok(UnitCode::Manmonth),
+            "4C" => Ok(UnitCode::Centistokes),
+            "4G" => Ok(UnitCode::Microlitre),
+            "4H" => Ok(UnitCode::MicrometreMicron),
+            "4K" => Ok(UnitCode::Milliampere),
+            "4L" => Ok(UnitCode::Megabyte),
+            "4M" => Ok(UnitCode::MilligramPerHour),
+            "4N" => Ok(UnitCode::Megabecquerel),
+            "4O" => Ok(UnitCode::Microfarad),
+            "4P" => Ok(UnitCode::NewtonPerMetre),
+            "4Q" => Ok(UnitCode::OunceInch),
+            "4R" => Ok(UnitCode::OunceFoot),
+            "4T" => Ok(UnitCode::Picofarad),
+            "4U" => Ok(UnitCode::PoundPerHour),
+            "4W" => Ok(UnitCode::TonUsPerHour),
+            "4X" => Ok(UnitCode::KilolitrePerHour),
+            "5A" => Ok(UnitCode::BarrelUsPerMinute),
+            "5B" => Ok(UnitCode::Batch),
+            "5E" => Ok(UnitCode::MmscfPerDay),
+            "5J" => Ok(UnitCode::HydraulicHorsePower),
+            "A10" => Ok(UnitCode::AmpereSquareMetrePerJouleSecond),
+            "A11" => Ok(UnitCode::Angstrom),
+            "A12" => Ok(UnitCode::AstronomicalUnit),
+            "A13" => Ok(UnitCode::Attojoule),
+            "A14" => Ok(UnitCode::Barn),
+            "A15" => Ok(UnitCode::BarnPerElectronvolt),
+            "A16" => Ok(UnitCode::BarnPerSteradianElectronvolt),
+            "A17" => Ok(UnitCode::BarnPerSteradian),
+            "A18" => Ok(UnitCode::BecquerelPerKilogram),
+            "A19" => Ok(UnitCode::BecquerelPerCubicMetre),
+            "A2" => Ok(UnitCode::AmperePerCentimetre),
+            "A20" => Ok(UnitCode::BritishThermalUnitInternationalTablePerSecondSquareFootDegreeRankine),
+            "A21" => Ok(UnitCode::BritishThermalUnitInternationalTablePerPoundDegreeRankine),
+            "A22" => Ok(UnitCode::BritishThermalUnitInternationalTablePerSecondFootDegreeRankine),
+            "A23" => Ok(UnitCode::BritishThermalUnitInternationalTablePerHourSquareFootDegreeRankine),
+            "A24" => Ok(UnitCode::CandelaPerSquareMetre),
+            "A26" => Ok(UnitCode::CoulombMetre),
+            "A27" => Ok(UnitCode::CoulombMetreSquaredPerVolt),
+            "A28" => Ok(UnitCode::CoulombPerCubicCentimetre),
+            "A29" => Ok(UnitCode::CoulombPerCubicMetre),
+            "A3" => Ok(UnitCode::AmperePerMillimetre),
+            "A30" => Ok(UnitCode::CoulombPerCubicMillimetre),
+            "A31" => Ok(UnitCode::CoulombPerKilogramSecond),
+            "A32" => Ok(UnitCode::CoulombPerMole),
+            "A33" => Ok(UnitCode::CoulombPerSquareCentimetre),
+            "A34" => Ok(UnitCode::CoulombPerSquareMetre),
+            "A35" => Ok(UnitCode::CoulombPerSquareMillimetre),
+            "A36" => Ok(UnitCode::CubicCentimetrePerMole),
+            "A37" => Ok(UnitCode::CubicDecimetrePerMole),
+            "A38" => Ok(UnitCode::CubicMetrePerCoulomb),
+            "A39" => Ok(UnitCode::CubicMetrePerKilogram),
+            "A4" => Ok(UnitCode::AmperePerSquareCentimetre),
+            "A40" => Ok(UnitCode::CubicMetrePerMole),
+            "A41" => Ok(UnitCode::AmperePerSquareMetre),
+            "A42" => Ok(UnitCode::CuriePerKilogram),
+            "A43" => Ok(UnitCode::DeadweightTonnage),
+            "A44" => Ok(UnitCode::Decalitre),
+            "A45" => Ok(UnitCode::Decametre),
+            "A47" => Ok(UnitCode::Decitex),
+            "A48" => Ok(UnitCode::DegreeRankine),
+            "A49" => Ok(UnitCode::Denier),
+            "A5" => Ok(UnitCode::AmpereSquareMetre),
+            "A53" => Ok(UnitCode::Electronvolt),
+            "A54" => Ok(UnitCode::ElectronvoltPerMetre),
+            "A55" => Ok(UnitCode::ElectronvoltSquareMetre),
+            "A56" => Ok(UnitCode::ElectronvoltSquareMetrePerKilogram),
+            "A59" => Ok(UnitCode::EightPartCloudCover),
+            "A6" => Ok(UnitCode::AmperePerSquareMetreKelvinSquared),
+            "A68" => Ok(UnitCode::Exajoule),
+            "A69" => Ok(UnitCode::FaradPerMetre),
+            "A7" => Ok(UnitCode::AmperePerSquareMillimetre),
+            "A70" => Ok(UnitCode::Femtojoule),
+            "A71" => Ok(UnitCode::Femtometre),
+            "A73" => Ok(UnitCode::FootPerSecondSquared),
+            "A74" => Ok(UnitCode::FootPoundForcePerSecond),
+            "A75" => Ok(UnitCode::FreightTon),
+            "A76" => Ok(UnitCode::Gal),
+            "A8" => Ok(UnitCode::AmpereSecond),
+            "A84" => Ok(UnitCode::GigacoulombPerCubicMetre),
+            "A85" => Ok(UnitCode::Gigaelectronvolt),
+            "A86" => Ok(UnitCode::Gigahertz),
+            "A87" => Ok(UnitCode::Gigaohm),
+            "A88" => Ok(UnitCode::GigaohmMetre),
+            "A89" => Ok(UnitCode::Gigapascal),
+            "A9" => Ok(UnitCode::Rate),
+            "A90" => Ok(UnitCode::Gigawatt),
+            "A91" => Ok(UnitCode::Gon),
+            "A93" => Ok(UnitCode::GramPerCubicMetre),
+            "A94" => Ok(UnitCode::GramPerMole),
+            "A95" => Ok(UnitCode::Gray),
+            "A96" => Ok(UnitCode::GrayPerSecond),
+            "A97" => Ok(UnitCode::Hectopascal),
+            "A98" => Ok(UnitCode::HenryPerMetre),
+            "A99" => Ok(UnitCode::Bit),
+            "AA" => Ok(UnitCode::Ball),
+            "AB" => Ok(UnitCode::BulkPack),
+            "ACR" => Ok(UnitCode::Acre),
+            "ACT" => Ok(UnitCode::Activity),
+            "AD" => Ok(UnitCode::Byte),
+            "AE" => Ok(UnitCode::AmperePerMetre),
+            "AH" => Ok(UnitCode::AdditionalMinute),
+            "AI" => Ok(UnitCode::AverageMinutePerCall),
+            "AK" => Ok(UnitCode::Fathom),
+            "AL" => Ok(UnitCode::AccessLine),
+            "AMH" => Ok(UnitCode::AmpereHour),
+            "AMP" => Ok(UnitCode::Ampere),
+            "ANN" => Ok(UnitCode::Year),
+            "APZ" => Ok(UnitCode::TroyOunceOrApothecaryOunce),
+            "AQ" => Ok(UnitCode::AntiHemophilicFactorAhfUnit),
+            "AS" => Ok(UnitCode::Assortment),
+            "ASM" => Ok(UnitCode::AlcoholicStrengthByMass),
+            "ASU" => Ok(UnitCode::AlcoholicStrengthByVolume),
+            "ATM" => Ok(UnitCode::StandardAtmosphere),
+            "AWG" => Ok(UnitCode::AmericanWireGauge),
+            "AY" => Ok(UnitCode::Assembly),
+            "AZ" => Ok(UnitCode::BritishThermalUnitInternationalTablePerPound),
+            "B1" => Ok(UnitCode::BarrelUsPerDay),
+            "B10" => Ok(UnitCode::BitPerSecond),
+            "B11" => Ok(UnitCode::JoulePerKilogramKelvin),
+            "B12" => Ok(UnitCode::JoulePerMetre),
+            "B13" => Ok(UnitCode::JoulePerSquareMetre),
+            "B14" => Ok(UnitCode::JoulePerMetreToTheFourthPower),
+            "B15" => Ok(UnitCode::JoulePerMole),
+            "B16" => Ok(UnitCode::JoulePerMoleKelvin),
+            "B17" => Ok(UnitCode::Credit),
+            "B18" => Ok(UnitCode::JouleSecond),
+            "B19" => Ok(UnitCode::Digit),
+            "B20" => Ok(UnitCode::JouleSquareMetrePerKilogram),
+            "B21" => Ok(UnitCode::KelvinPerWatt),
+            "B22" => Ok(UnitCode::Kiloampere),
+            "B23" => Ok(UnitCode::KiloamperePerSquareMetre),
+            "B24" => Ok(UnitCode::KiloamperePerMetre),
+            "B25" => Ok(UnitCode::KilobecquerelPerKilogram),
+            "B26" => Ok(UnitCode::Kilocoulomb),
+            "B27" => Ok(UnitCode::KilocoulombPerCubicMetre),
+            "B28" => Ok(UnitCode::KilocoulombPerSquareMetre),
+            "B29" => Ok(UnitCode::Kiloelectronvolt),
+            "B3" => Ok(UnitCode::BattingPound),
+            "B30" => Ok(UnitCode::Gibibit),
+            "B31" => Ok(UnitCode::KilogramMetrePerSecond),
+            "B32" => Ok(UnitCode::KilogramMetreSquared),
+            "B33" => Ok(UnitCode::KilogramMetreSquaredPerSecond),
+            "B34" => Ok(UnitCode::KilogramPerCubicDecimetre),
+            "B35" => Ok(UnitCode::KilogramPerLitre),
+            "B4" => Ok(UnitCode::BarrelImperial),
+            "B41" => Ok(UnitCode::KilojoulePerKelvin),
+            "B42" => Ok(UnitCode::KilojoulePerKilogram),
+            "B43" => Ok(UnitCode::KilojoulePerKilogramKelvin),
+            "B44" => Ok(UnitCode::KilojoulePerMole),
+            "B45" => Ok(UnitCode::Kilomole),
+            "B46" => Ok(UnitCode::KilomolePerCubicMetre),
+            "B47" => Ok(UnitCode::Kilonewton),
+            "B48" => Ok(UnitCode::KilonewtonMetre),
+            "B49" => Ok(UnitCode::Kiloohm),
+            "B50" => Ok(UnitCode::KiloohmMetre),
+            "B52" => Ok(UnitCode::Kilosecond),
+            "B53" => Ok(UnitCode::Kilosiemens),
+            "B54" => Ok(UnitCode::KilosiemensPerMetre),
+            "B55" => Ok(UnitCode::KilovoltPerMetre),
+            "B56" => Ok(UnitCode::KiloweberPerMetre),
+            "B57" => Ok(UnitCode::LightYear),
+            "B58" => Ok(UnitCode::LitrePerMole),
+            "B59" => Ok(UnitCode::LumenHour),
+            "B60" => Ok(UnitCode::LumenPerSquareMetre),
+            "B61" => Ok(UnitCode::LumenPerWatt),
+            "B62" => Ok(UnitCode::LumenSecond),
+            "B63" => Ok(UnitCode::LuxHour),
+            "B64" => Ok(UnitCode::LuxSecond),
+            "B66" => Ok(UnitCode::MegaamperePerSquareMetre),
+            "B67" => Ok(UnitCode::MegabecquerelPerKilogram),
+            "B68" => Ok(UnitCode::Gigabit),
+            "B69" => Ok(UnitCode::MegacoulombPerCubicMetre),
+            "B7" => Ok(UnitCode::Cycle),
+            "B70" => Ok(UnitCode::MegacoulombPerSquareMetre),
+            "B71" => Ok(UnitCode::Megaelectronvolt),
+            "B72" => Ok(UnitCode::MegagramPerCubicMetre),
+            "B73" => Ok(UnitCode::Meganewton),
+            "B74" => Ok(UnitCode::MeganewtonMetre),
+            "B75" => Ok(UnitCode::Megaohm),
+            "B76" => Ok(UnitCode::MegaohmMetre),
+            "B77" => Ok(UnitCode::MegasiemensPerMetre),
+            "B78" => Ok(UnitCode::Megavolt),
+            "B79" => Ok(UnitCode::MegavoltPerMetre),
+            "B8" => Ok(UnitCode::JoulePerCubicMetre),
+            "B80" => Ok(UnitCode::GigabitPerSecond),
+            "B81" => Ok(UnitCode::ReciprocalMetreSquaredReciprocalSecond),
+            "B82" => Ok(UnitCode::InchPerLinearFoot),
+            "B83" => Ok(UnitCode::MetreToTheFourthPower),
+            "B84" => Ok(UnitCode::Microampere),
+            "B85" => Ok(UnitCode::Microbar),
+            "B86" => Ok(UnitCode::Microcoulomb),
+            "B87" => Ok(UnitCode::MicrocoulombPerCubicMetre),
+            "B88" => Ok(UnitCode::MicrocoulombPerSquareMetre),
+            "B89" => Ok(UnitCode::MicrofaradPerMetre),
+            "B90" => Ok(UnitCode::Microhenry),
+            "B91" => Ok(UnitCode::MicrohenryPerMetre),
+            "B92" => Ok(UnitCode::Micronewton),
+            "B93" => Ok(UnitCode::MicronewtonMetre),
+            "B94" => Ok(UnitCode::Microohm),
+            "B95" => Ok(UnitCode::MicroohmMetre),
+            "B96" => Ok(UnitCode::Micropascal),
+            "B97" => Ok(UnitCode::Microradian),
+            "B98" => Ok(UnitCode::Microsecond),
+            "B99" => Ok(UnitCode::Microsiemens),
+            "BAR" => Ok(UnitCode::BarUnitOfPressure),
+            "BB" => Ok(UnitCode::BaseBox),
+            "BFT" => Ok(UnitCode::BoardFoot),
+            "BHP" => Ok(UnitCode::BrakeHorsePower),
+            "BIL" => Ok(UnitCode::BillionEur),
+            "BLD" => Ok(UnitCode::DryBarrelUs),
+            "BLL" => Ok(UnitCode::BarrelUs),
+            "BP" => Ok(UnitCode::HundredBoardFoot),
+            "BPM" => Ok(UnitCode::BeatsPerMinute),
+            "BQL" => Ok(UnitCode::Becquerel),
+            "BTU" => Ok(UnitCode::BritishThermalUnitInternationalTable),
+            "BUA" => Ok(UnitCode::BushelUs),
+            "BUI" => Ok(UnitCode::BushelUk),
+            "C0" => Ok(UnitCode::Call),
+            "C10" => Ok(UnitCode::Millifarad),
+            "C11" => Ok(UnitCode::Milligal),
+            "C12" => Ok(UnitCode::MilligramPerMetre),
+            "C13" => Ok(UnitCode::Milligray),
+            "C14" => Ok(UnitCode::Millihenry),
+            "C15" => Ok(UnitCode::Millijoule),
+            "C16" => Ok(UnitCode::MillimetrePerSecond),
+            "C17" => Ok(UnitCode::MillimetreSquaredPerSecond),
+            "C18" => Ok(UnitCode::Millimole),
+            "C19" => Ok(UnitCode::MolePerKilogram),
+            "C20" => Ok(UnitCode::Millinewton),
+            "C21" => Ok(UnitCode::Kibibit),
+            "C22" => Ok(UnitCode::MillinewtonPerMetre),
+            "C23" => Ok(UnitCode::MilliohmMetre),
+            "C24" => Ok(UnitCode::MillipascalSecond),
+            "C25" => Ok(UnitCode::Milliradian),
+            "C26" => Ok(UnitCode::Millisecond),
+            "C27" => Ok(UnitCode::Millisiemens),
+            "C28" => Ok(UnitCode::Millisievert),
+            "C29" => Ok(UnitCode::Millitesla),
+            "C3" => Ok(UnitCode::MicrovoltPerMetre),
+            "C30" => Ok(UnitCode::MillivoltPerMetre),
+            "C31" => Ok(UnitCode::Milliwatt),
+            "C32" => Ok(UnitCode::MilliwattPerSquareMetre),
+            "C33" => Ok(UnitCode::Milliweber),
+            "C34" => Ok(UnitCode::Mole),
+            "C35" => Ok(UnitCode::MolePerCubicDecimetre),
+            "C36" => Ok(UnitCode::MolePerCubicMetre),
+            "C37" => Ok(UnitCode::Kilobit),
+            "C38" => Ok(UnitCode::MolePerLitre),
+            "C39" => Ok(UnitCode::Nanoampere),
+            "C40" => Ok(UnitCode::Nanocoulomb),
+            "C41" => Ok(UnitCode::Nanofarad),
+            "C42" => Ok(UnitCode::NanofaradPerMetre),
+            "C43" => Ok(UnitCode::Nanohenry),
+            "C44" => Ok(UnitCode::NanohenryPerMetre),
+            "C45" => Ok(UnitCode::Nanometre),
+            "C46" => Ok(UnitCode::NanoohmMetre),
+            "C47" => Ok(UnitCode::Nanosecond),
+            "C48" => Ok(UnitCode::Nanotesla),
+            "C49" => Ok(UnitCode::Nanowatt),
+            "C50" => Ok(UnitCode::Neper),
+            "C51" => Ok(UnitCode::NeperPerSecond),
+            "C52" => Ok(UnitCode::Picometre),
+            "C53" => Ok(UnitCode::NewtonMetreSecond),
+            "C54" => Ok(UnitCode::NewtonMetreSquaredPerKilogramSquared),
+            "C55" => Ok(UnitCode::NewtonPerSquareMetre),
+            "C56" => Ok(UnitCode::NewtonPerSquareMillimetre),
+            "C57" => Ok(UnitCode::NewtonSecond),
+            "C58" => Ok(UnitCode::NewtonSecondPerMetre),
+            "C59" => Ok(UnitCode::Octave),
+            "C60" => Ok(UnitCode::OhmCentimetre),
+            "C61" => Ok(UnitCode::OhmMetre),
+            "C62" => Ok(UnitCode::One),
+            "C63" => Ok(UnitCode::Parsec),
+            "C64" => Ok(UnitCode::PascalPerKelvin),
+            "C65" => Ok(UnitCode::PascalSecond),
+            "C66" => Ok(UnitCode::PascalSecondPerCubicMetre),
+            "C67" => Ok(UnitCode::PascalSecondPerMetre),
+            "C68" => Ok(UnitCode::Petajoule),
+            "C69" => Ok(UnitCode::Phon),
+            "C7" => Ok(UnitCode::Centipoise),
+            "C70" => Ok(UnitCode::Picoampere),
+            "C71" => Ok(UnitCode::Picocoulomb),
+            "C72" => Ok(UnitCode::PicofaradPerMetre),
+            "C73" => Ok(UnitCode::Picohenry),
+            "C74" => Ok(UnitCode::KilobitPerSecond),
+            "C75" => Ok(UnitCode::Picowatt),
+            "C76" => Ok(UnitCode::PicowattPerSquareMetre),
+            "C78" => Ok(UnitCode::PoundForce),
+            "C79" => Ok(UnitCode::KilovoltAmpereHour),
+            "C8" => Ok(UnitCode::MillicoulombPerKilogram),
+            "C80" => Ok(UnitCode::Rad),
+            "C81" => Ok(UnitCode::Radian),
+            "C82" => Ok(UnitCode::RadianSquareMetrePerMole),
+            "C83" => Ok(UnitCode::RadianSquareMetrePerKilogram),
+            "C84" => Ok(UnitCode::RadianPerMetre),
+            "C85" => Ok(UnitCode::ReciprocalAngstrom),
+            "C86" => Ok(UnitCode::ReciprocalCubicMetre),
+            "C87" => Ok(UnitCode::ReciprocalCubicMetrePerSecond),
+            "C88" => Ok(UnitCode::ReciprocalElectronVoltPerCubicMetre),
+            "C89" => Ok(UnitCode::ReciprocalHenry),
+            "C9" => Ok(UnitCode::CoilGroup),
+            "C90" => Ok(UnitCode::ReciprocalJoulePerCubicMetre),
+            "C91" => Ok(UnitCode::ReciprocalKelvinOrKelvinToThePowerMinusOne),
+            "C92" => Ok(UnitCode::ReciprocalMetre),
+            "C93" => Ok(UnitCode::ReciprocalSquareMetre),
+            "C94" => Ok(UnitCode::ReciprocalMinute),
+            "C95" => Ok(UnitCode::ReciprocalMole),
+            "C96" => Ok(UnitCode::ReciprocalPascalOrPascalToThePowerMinusOne),
+            "C97" => Ok(UnitCode::ReciprocalSecond),
+            "C99" => Ok(UnitCode::ReciprocalSecondPerMetreSquared),
+            "CCT" => Ok(UnitCode::CarryingCapacityInMetricTon),
+            "CDL" => Ok(UnitCode::Candela),
+            "CEL" => Ok(UnitCode::DegreeCelsius),
+            "CEN" => Ok(UnitCode::Hundred),
+            "CG" => Ok(UnitCode::Card),
+            "CGM" => Ok(UnitCode::Centigram),
+            "CKG" => Ok(UnitCode::CoulombPerKilogram),
+            "CLF" => Ok(UnitCode::HundredLeave),
+            "CLT" => Ok(UnitCode::Centilitre),
+            "CMK" => Ok(UnitCode::SquareCentimetre),
+            "CMQ" => Ok(UnitCode::CubicCentimetre),
+            "CMT" => Ok(UnitCode::Centimetre),
+            "CNP" => Ok(UnitCode::HundredPack),
+            "CNT" => Ok(UnitCode::CentalUk),
+            "COU" => Ok(UnitCode::Coulomb),
+            "CTG" => Ok(UnitCode::ContentGram),
+            "CTM" => Ok(UnitCode::MetricCarat),
+            "CTN" => Ok(UnitCode::ContentTonMetric),
+            "CUR" => Ok(UnitCode::Curie),
+            "CWA" => Ok(UnitCode::HundredPoundCwtPerHundredWeightUs),
+            "CWI" => Ok(UnitCode::HundredWeightUk),
+            "D03" => Ok(UnitCode::KilowattHourPerHour),
+            "D04" => Ok(UnitCode::LotUnitOfWeight),
+            "D1" => Ok(UnitCode::ReciprocalSecondPerSteradian),
+            "D10" => Ok(UnitCode::SiemensPerMetre),
+            "D11" => Ok(UnitCode::Mebibit),
+            "D12" => Ok(UnitCode::SiemensSquareMetrePerMole),
+            "D13" => Ok(UnitCode::Sievert),
+            "D15" => Ok(UnitCode::Sone),
+            "D16" => Ok(UnitCode::SquareCentimetrePerErg),
+            "D17" => Ok(UnitCode::SquareCentimetrePerSteradianErg),
+            "D18" => Ok(UnitCode::MetreKelvin),
+            "D19" => Ok(UnitCode::SquareMetreKelvinPerWatt),
+            "D2" => Ok(UnitCode::ReciprocalSecondPerSteradianMetreSquared),
+            "D20" => Ok(UnitCode::SquareMetrePerJoule),
+            "D21" => Ok(UnitCode::SquareMetrePerKilogram),
+            "D22" => Ok(UnitCode::SquareMetrePerMole),
+            "D23" => Ok(UnitCode::PenGramProtein),
+            "D24" => Ok(UnitCode::SquareMetrePerSteradian),
+            "D25" => Ok(UnitCode::SquareMetrePerSteradianJoule),
+            "D26" => Ok(UnitCode::SquareMetrePerVoltSecond),
+            "D27" => Ok(UnitCode::Steradian),
+            "D29" => Ok(UnitCode::Terahertz),
+            "D30" => Ok(UnitCode::Terajoule),
+            "D31" => Ok(UnitCode::Terawatt),
+            "D32" => Ok(UnitCode::TerawattHour),
+            "D33" => Ok(UnitCode::Tesla),
+            "D34" => Ok(UnitCode::Tex),
+            "D36" => Ok(UnitCode::Megabit),
+            "D41" => Ok(UnitCode::TonnePerCubicMetre),
+            "D42" => Ok(UnitCode::TropicalYear),
+            "D43" => Ok(UnitCode::UnifiedAtomicMassUnit),
+            "D44" => Ok(UnitCode::Var),
+            "D45" => Ok(UnitCode::VoltSquaredPerKelvinSquared),
+            "D46" => Ok(UnitCode::VoltAmpere),
+            "D47" => Ok(UnitCode::VoltPerCentimetre),
+            "D48" => Ok(UnitCode::VoltPerKelvin),
+            "D49" => Ok(UnitCode::MillivoltPerKelvin),
+            "D5" => Ok(UnitCode::KilogramPerSquareCentimetre),
+            "D50" => Ok(UnitCode::VoltPerMetre),
+            "D51" => Ok(UnitCode::VoltPerMillimetre),
+            "D52" => Ok(UnitCode::WattPerKelvin),
+            "D53" => Ok(UnitCode::WattPerMetreKelvin),
+            "D54" => Ok(UnitCode::WattPerSquareMetre),
+            "D55" => Ok(UnitCode::WattPerSquareMetreKelvin),
+            "D56" => Ok(UnitCode::WattPerSquareMetreKelvinToTheFourthPower),
+            "D57" => Ok(UnitCode::WattPerSteradian),
+            "D58" => Ok(UnitCode::WattPerSteradianSquareMetre),
+            "D59" => Ok(UnitCode::WeberPerMetre),
+            "D6" => Ok(UnitCode::RoentgenPerSecond),
+            "D60" => Ok(UnitCode::WeberPerMillimetre),
+            "D61" => Ok(UnitCode::MinuteUnitOfAngle),
+            "D62" => Ok(UnitCode::SecondUnitOfAngle),
+            "D63" => Ok(UnitCode::Book),
+            "D65" => Ok(UnitCode::Round),
+            "D68" => Ok(UnitCode::NumberOfWords),
+            "D69" => Ok(UnitCode::InchToTheFourthPower),
+            "D73" => Ok(UnitCode::JouleSquareMetre),
+            "D74" => Ok(UnitCode::KilogramPerMole),
+            "D77" => Ok(UnitCode::Megacoulomb),
+            "D78" => Ok(UnitCode::MegajoulePerSecond),
+            "D80" => Ok(UnitCode::Microwatt),
+            "D81" => Ok(UnitCode::Microtesla),
+            "D82" => Ok(UnitCode::Microvolt),
+            "D83" => Ok(UnitCode::MillinewtonMetre),
+            "D85" => Ok(UnitCode::MicrowattPerSquareMetre),
+            "D86" => Ok(UnitCode::Millicoulomb),
+            "D87" => Ok(UnitCode::MillimolePerKilogram),
+            "D88" => Ok(UnitCode::MillicoulombPerCubicMetre),
+            "D89" => Ok(UnitCode::MillicoulombPerSquareMetre),
+            "D91" => Ok(UnitCode::Rem),
+            "D93" => Ok(UnitCode::SecondPerCubicMetre),
+            "D94" => Ok(UnitCode::SecondPerCubicMetreRadian),
+            "D95" => Ok(UnitCode::JoulePerGram),
+            "DAA" => Ok(UnitCode::Decare),
+            "DAD" => Ok(UnitCode::TenDay),
+            "DAY" => Ok(UnitCode::Day),
+            "DB" => Ok(UnitCode::DryPound),
+            "DBM" => Ok(UnitCode::DecibelMilliwatts),
+            "DBW" => Ok(UnitCode::DecibelWatt),
+            "DC" => Ok(UnitCode::DiskDisc),
+            "DD" => Ok(UnitCode::DegreeUnitOfAngle),
+            "DEC" => Ok(UnitCode::Decade),
+            "DG" => Ok(UnitCode::Decigram),
+            "DJ" => Ok(UnitCode::Decagram),
+            "DLT" => Ok(UnitCode::Decilitre),
+            "DMA" => Ok(UnitCode::CubicDecametre),
+            "DMK" => Ok(UnitCode::SquareDecimetre),
+            "DMO" => Ok(UnitCode::StandardKilolitre),
+            "DMQ" => Ok(UnitCode::CubicDecimetre),
+            "DMT" => Ok(UnitCode::Decimetre),
+            "DN" => Ok(UnitCode::DecinewtonMetre),
+            "DPC" => Ok(UnitCode::DozenPiece),
+            "DPR" => Ok(UnitCode::DozenPair),
+            "DPT" => Ok(UnitCode::DisplacementTonnage),
+            "DRA" => Ok(UnitCode::DramUs),
+            "DRI" => Ok(UnitCode::DramUk),
+            "DRL" => Ok(UnitCode::DozenRoll),
+            "DT" => Ok(UnitCode::DryTon),
+            "DTN" => Ok(UnitCode::Decitonne),
+            "DWT" => Ok(UnitCode::Pennyweight),
+            "DZN" => Ok(UnitCode::Dozen),
+            "DZP" => Ok(UnitCode::DozenPack),
+            "E01" => Ok(UnitCode::NewtonPerSquareCentimetre),
+            "E07" => Ok(UnitCode::MegawattHourPerHour),
+            "E08" => Ok(UnitCode::MegawattPerHertz),
+            "E09" => Ok(UnitCode::MilliampereHour),
+            "E10" => Ok(UnitCode::DegreeDay),
+            "E12" => Ok(UnitCode::Mille),
+            "E14" => Ok(UnitCode::KilocalorieInternationalTable),
+            "E15" => Ok(UnitCode::KilocalorieThermochemicalPerHour),
+            "E16" => Ok(UnitCode::MillionBtuItPerHour),
+            "E17" => Ok(UnitCode::CubicFootPerSecond),
+            "E18" => Ok(UnitCode::TonnePerHour),
+            "E19" => Ok(UnitCode::Ping),
+            "E20" => Ok(UnitCode::MegabitPerSecond),
+            "E21" => Ok(UnitCode::Shares),
+            "E22" => Ok(UnitCode::Teu),
+            "E23" => Ok(UnitCode::Tyre),
+            "E25" => Ok(UnitCode::ActiveUnit),
+            "E27" => Ok(UnitCode::Dose),
+            "E28" => Ok(UnitCode::AirDryTon),
+            "E30" => Ok(UnitCode::Strand),
+            "E31" => Ok(UnitCode::SquareMetrePerLitre),
+            "E32" => Ok(UnitCode::LitrePerHour),
+            "E33" => Ok(UnitCode::FootPerThousand),
+            "E34" => Ok(UnitCode::Gigabyte),
+            "E35" => Ok(UnitCode::Terabyte),
+            "E36" => Ok(UnitCode::Petabyte),
+            "E37" => Ok(UnitCode::Pixel),
+            "E38" => Ok(UnitCode::Megapixels),
+            "E39" => Ok(UnitCode::DotsPerInch),
+            "E4" => Ok(UnitCode::GrossKilogram),
+            "E40" => Ok(UnitCode::PartPerHundredThousand),
+            "E41" => Ok(UnitCode::KilogramForcePerSquareMillimetre),
+            "E42" => Ok(UnitCode::KilogramForcePerSquareCentimetre),
+            "E43" => Ok(UnitCode::JoulePerSquareCentimetre),
+            "E44" => Ok(UnitCode::KilogramForceMetrePerSquareCentimetre),
+            "E45" => Ok(UnitCode::Milliohm),
+            "E46" => Ok(UnitCode::KilowattHourPerCubicMetre),
+            "E47" => Ok(UnitCode::KilowattHourPerKelvin),
+            "E48" => Ok(UnitCode::ServiceUnit),
+            "E49" => Ok(UnitCode::WorkingDay),
+            "E50" => Ok(UnitCode::AccountingUnit),
+            "E51" => Ok(UnitCode::Job),
+            "E52" => Ok(UnitCode::RunFoot),
+            "E53" => Ok(UnitCode::Test),
+            "E54" => Ok(UnitCode::Trip),
+            "E55" => Ok(UnitCode::Use),
+            "E56" => Ok(UnitCode::Well),
+            "E57" => Ok(UnitCode::Zone),
+            "E58" => Ok(UnitCode::ExabitPerSecond),
+            "E59" => Ok(UnitCode::Exbibyte),
+            "E60" => Ok(UnitCode::Pebibyte),
+            "E61" => Ok(UnitCode::Tebibyte),
+            "E62" => Ok(UnitCode::Gibibyte),
+            "E63" => Ok(UnitCode::Mebibyte),
+            "E64" => Ok(UnitCode::Kibibyte),
+            "E65" => Ok(UnitCode::ExbibitPerMetre),
+            "E66" => Ok(UnitCode::ExbibitPerSquareMetre),
+            "E67" => Ok(UnitCode::ExbibitPerCubicMetre),
+            "E68" => Ok(UnitCode::GigabytePerSecond),
+            "E69" => Ok(UnitCode::GibibitPerMetre),
+            "E70" => Ok(UnitCode::GibibitPerSquareMetre),
+            "E71" => Ok(UnitCode::GibibitPerCubicMetre),
+            "E72" => Ok(UnitCode::KibibitPerMetre),
+            "E73" => Ok(UnitCode::KibibitPerSquareMetre),
+            "E74" => Ok(UnitCode::KibibitPerCubicMetre),
+            "E75" => Ok(UnitCode::MebibitPerMetre),
+            "E76" => Ok(UnitCode::MebibitPerSquareMetre),
+            "E77" => Ok(UnitCode::MebibitPerCubicMetre),
+            "E78" => Ok(UnitCode::Petabit),
+            "E79" => Ok(UnitCode::PetabitPerSecond),
+            "E80" => Ok(UnitCode::PebibitPerMetre),
+            "E81" => Ok(UnitCode::PebibitPerSquareMetre),
+            "E82" => Ok(UnitCode::PebibitPerCubicMetre),
+            "E83" => Ok(UnitCode::Terabit),
+            "E84" => Ok(UnitCode::TerabitPerSecond),
+            "E85" => Ok(UnitCode::TebibitPerMetre),
+            "E86" => Ok(UnitCode::TebibitPerCubicMetre),
+            "E87" => Ok(UnitCode::TebibitPerSquareMetre),
+            "E88" => Ok(UnitCode::BitPerMetre),
+            "E89" => Ok(UnitCode::BitPerSquareMetre),
+            "E90" => Ok(UnitCode::ReciprocalCentimetre),
+            "E91" => Ok(UnitCode::ReciprocalDay),
+            "E92" => Ok(UnitCode::CubicDecimetrePerHour),
+            "E93" => Ok(UnitCode::KilogramPerHour),
+            "E94" => Ok(UnitCode::KilomolePerSecond),
+            "E95" => Ok(UnitCode::MolePerSecond),
+            "E96" => Ok(UnitCode::DegreePerSecond),
+            "E97" => Ok(UnitCode::MillimetrePerDegreeCelciusMetre),
+            "E98" => Ok(UnitCode::DegreeCelsiusPerKelvin),
+            "E99" => Ok(UnitCode::HectopascalPerBar),
+            "EA" => Ok(UnitCode::Each),
+            "EB" => Ok(UnitCode::ElectronicMailBox),
+            "EQ" => Ok(UnitCode::EquivalentGallon),
+            "F01" => Ok(UnitCode::BitPerCubicMetre),
+            "F02" => Ok(UnitCode::KelvinPerKelvin),
+            "F03" => Ok(UnitCode::KilopascalPerBar),
+            "F04" => Ok(UnitCode::MillibarPerBar),
+            "F05" => Ok(UnitCode::MegapascalPerBar),
+            "F06" => Ok(UnitCode::PoisePerBar),
+            "F07" => Ok(UnitCode::PascalPerBar),
+            "F08" => Ok(UnitCode::MilliamperePerInch),
+            "F10" => Ok(UnitCode::KelvinPerHour),
+            "F11" => Ok(UnitCode::KelvinPerMinute),
+            "F12" => Ok(UnitCode::KelvinPerSecond),
+            "F13" => Ok(UnitCode::Slug),
+            "F14" => Ok(UnitCode::GramPerKelvin),
+            "F15" => Ok(UnitCode::KilogramPerKelvin),
+            "F16" => Ok(UnitCode::MilligramPerKelvin),
+            "F17" => Ok(UnitCode::PoundForcePerFoot),
+            "F18" => Ok(UnitCode::KilogramSquareCentimetre),
+            "F19" => Ok(UnitCode::KilogramSquareMillimetre),
+            "F20" => Ok(UnitCode::PoundInchSquared),
+            "F21" => Ok(UnitCode::PoundForceInch),
+            "F22" => Ok(UnitCode::PoundForceFootPerAmpere),
+            "F23" => Ok(UnitCode::GramPerCubicDecimetre),
+            "F24" => Ok(UnitCode::KilogramPerKilomol),
+            "F25" => Ok(UnitCode::GramPerHertz),
+            "F26" => Ok(UnitCode::GramPerDay),
+            "F27" => Ok(UnitCode::GramPerHour),
+            "F28" => Ok(UnitCode::GramPerMinute),
+            "F29" => Ok(UnitCode::GramPerSecond),
+            "F30" => Ok(UnitCode::KilogramPerDay),
+            "F31" => Ok(UnitCode::KilogramPerMinute),
+            "F32" => Ok(UnitCode::MilligramPerDay),
+            "F33" => Ok(UnitCode::MilligramPerMinute),
+            "F34" => Ok(UnitCode::MilligramPerSecond),
+            "F35" => Ok(UnitCode::GramPerDayKelvin),
+            "F36" => Ok(UnitCode::GramPerHourKelvin),
+            "F37" => Ok(UnitCode::GramPerMinuteKelvin),
+            "F38" => Ok(UnitCode::GramPerSecondKelvin),
+            "F39" => Ok(UnitCode::KilogramPerDayKelvin),
+            "F40" => Ok(UnitCode::KilogramPerHourKelvin),
+            "F41" => Ok(UnitCode::KilogramPerMinuteKelvin),
+            "F42" => Ok(UnitCode::KilogramPerSecondKelvin),
+            "F43" => Ok(UnitCode::MilligramPerDayKelvin),
+            "F44" => Ok(UnitCode::MilligramPerHourKelvin),
+            "F45" => Ok(UnitCode::MilligramPerMinuteKelvin),
+            "F46" => Ok(UnitCode::MilligramPerSecondKelvin),
+            "F47" => Ok(UnitCode::NewtonPerMillimetre),
+            "F48" => Ok(UnitCode::PoundForcePerInch),
+            "F49" => Ok(UnitCode::RodUnitOfDistance),
+            "F50" => Ok(UnitCode::MicrometrePerKelvin),
+            "F51" => Ok(UnitCode::CentimetrePerKelvin),
+            "F52" => Ok(UnitCode::MetrePerKelvin),
+            "F53" => Ok(UnitCode::MillimetrePerKelvin),
+            "F54" => Ok(UnitCode::MilliohmPerMetre),
+            "F55" => Ok(UnitCode::OhmPerMileStatuteMile),
+            "F56" => Ok(UnitCode::OhmPerKilometre),
+            "F57" => Ok(UnitCode::MilliamperePerPoundForcePerSquareInch),
+            "F58" => Ok(UnitCode::ReciprocalBar),
+            "F59" => Ok(UnitCode::MilliamperePerBar),
+            "F60" => Ok(UnitCode::DegreeCelsiusPerBar),
+            "F61" => Ok(UnitCode::KelvinPerBar),
+            "F62" => Ok(UnitCode::GramPerDayBar),
+            "F63" => Ok(UnitCode::GramPerHourBar),
+            "F64" => Ok(UnitCode::GramPerMinuteBar),
+            "F65" => Ok(UnitCode::GramPerSecondBar),
+            "F66" => Ok(UnitCode::KilogramPerDayBar),
+            "F67" => Ok(UnitCode::KilogramPerHourBar),
+            "F68" => Ok(UnitCode::KilogramPerMinuteBar),
+            "F69" => Ok(UnitCode::KilogramPerSecondBar),
+            "F70" => Ok(UnitCode::MilligramPerDayBar),
+            "F71" => Ok(UnitCode::MilligramPerHourBar),
+            "F72" => Ok(UnitCode::MilligramPerMinuteBar),
+            "F73" => Ok(UnitCode::MilligramPerSecondBar),
+            "F74" => Ok(UnitCode::GramPerBar),
+            "F75" => Ok(UnitCode::MilligramPerBar),
+            "F76" => Ok(UnitCode::MilliamperePerMillimetre),
+            "F77" => Ok(UnitCode::PascalSecondPerKelvin),
+            "F78" => Ok(UnitCode::InchOfWater),
+            "F79" => Ok(UnitCode::InchOfMercury),
+            "F80" => Ok(UnitCode::WaterHorsePower),
+            "F81" => Ok(UnitCode::BarPerKelvin),
+            "F82" => Ok(UnitCode::HectopascalPerKelvin),
+            "F83" => Ok(UnitCode::KilopascalPerKelvin),
+            "F84" => Ok(UnitCode::MillibarPerKelvin),
+            "F85" => Ok(UnitCode::MegapascalPerKelvin),
+            "F86" => Ok(UnitCode::PoisePerKelvin),
+            "F87" => Ok(UnitCode::VoltPerLitreMinute),
+            "F88" => Ok(UnitCode::NewtonCentimetre),
+            "F89" => Ok(UnitCode::NewtonMetrePerDegree),
+            "F90" => Ok(UnitCode::NewtonPerAmpere),
+            "F91" => Ok(UnitCode::BarLitrePerSecond),
+            "F92" => Ok(UnitCode::BarCubicMetrePerSecond),
+            "F93" => Ok(UnitCode::HectopascalLitrePerSecond),
+            "F94" => Ok(UnitCode::HectopascalCubicMetrePerSecond),
+            "F95" => Ok(UnitCode::MillibarLitrePerSecond),
+            "F96" => Ok(UnitCode::MillibarCubicMetrePerSecond),
+            "F97" => Ok(UnitCode::MegapascalLitrePerSecond),
+            "F98" => Ok(UnitCode::MegapascalCubicMetrePerSecond),
+            "F99" => Ok(UnitCode::PascalLitrePerSecond),
+            "FAH" => Ok(UnitCode::DegreeFahrenheit),
+            "FAR" => Ok(UnitCode::Farad),
+            "FBM" => Ok(UnitCode::FibreMetre),
+            "FC" => Ok(UnitCode::ThousandCubicFoot),
+            "FF" => Ok(UnitCode::HundredCubicMetre),
+            "FH" => Ok(UnitCode::Micromole),
+            "FIT" => Ok(UnitCode::FailuresInTime),
+            "FL" => Ok(UnitCode::FlakeTon),
+            "FNU" => Ok(UnitCode::FormazinNephelometricUnit),
+            "FOT" => Ok(UnitCode::Foot),
+            "FP" => Ok(UnitCode::PoundPerSquareFoot),
+            "FR" => Ok(UnitCode::FootPerMinute),
+            "FS" => Ok(UnitCode::FootPerSecond),
+            "FTK" => Ok(UnitCode::SquareFoot),
+            "FTQ" => Ok(UnitCode::CubicFoot),
+            "G01" => Ok(UnitCode::PascalCubicMetrePerSecond),
+            "G04" => Ok(UnitCode::CentimetrePerBar),
+            "G05" => Ok(UnitCode::MetrePerBar),
+            "G06" => Ok(UnitCode::MillimetrePerBar),
+            "G08" => Ok(UnitCode::SquareInchPerSecond),
+            "G09" => Ok(UnitCode::SquareMetrePerSecondKelvin),
+            "G10" => Ok(UnitCode::StokesPerKelvin),
+            "G11" => Ok(UnitCode::GramPerCubicCentimetreBar),
+            "G12" => Ok(UnitCode::GramPerCubicDecimetreBar),
+            "G13" => Ok(UnitCode::GramPerLitreBar),
+            "G14" => Ok(UnitCode::GramPerCubicMetreBar),
+            "G15" => Ok(UnitCode::GramPerMillilitreBar),
+            "G16" => Ok(UnitCode::KilogramPerCubicCentimetreBar),
+            "G17" => Ok(UnitCode::KilogramPerLitreBar),
+            "G18" => Ok(UnitCode::KilogramPerCubicMetreBar),
+            "G19" => Ok(UnitCode::NewtonMetrePerKilogram),
+            "G2" => Ok(UnitCode::UsGallonPerMinute),
+            "G20" => Ok(UnitCode::PoundForceFootPerPound),
+            "G21" => Ok(UnitCode::CupUnitOfVolume),
+            "G23" => Ok(UnitCode::Peck),
+            "G24" => Ok(UnitCode::TablespoonUs),
+            "G25" => Ok(UnitCode::TeaspoonUs),
+            "G26" => Ok(UnitCode::Stere),
+            "G27" => Ok(UnitCode::CubicCentimetrePerKelvin),
+            "G28" => Ok(UnitCode::LitrePerKelvin),
+            "G29" => Ok(UnitCode::CubicMetrePerKelvin),
+            "G3" => Ok(UnitCode::ImperialGallonPerMinute),
+            "G30" => Ok(UnitCode::MillilitrePerKelvin),
+            "G31" => Ok(UnitCode::KilogramPerCubicCentimetre),
+            "G32" => Ok(UnitCode::OunceAvoirdupoisPerCubicYard),
+            "G33" => Ok(UnitCode::GramPerCubicCentimetreKelvin),
+            "G34" => Ok(UnitCode::GramPerCubicDecimetreKelvin),
+            "G35" => Ok(UnitCode::GramPerLitreKelvin),
+            "G36" => Ok(UnitCode::GramPerCubicMetreKelvin),
+            "G37" => Ok(UnitCode::GramPerMillilitreKelvin),
+            "G38" => Ok(UnitCode::KilogramPerCubicCentimetreKelvin),
+            "G39" => Ok(UnitCode::KilogramPerLitreKelvin),
+            "G40" => Ok(UnitCode::KilogramPerCubicMetreKelvin),
+            "G41" => Ok(UnitCode::SquareMetrePerSecondBar),
+            "G42" => Ok(UnitCode::MicrosiemensPerCentimetre),
+            "G43" => Ok(UnitCode::MicrosiemensPerMetre),
+            "G44" => Ok(UnitCode::NanosiemensPerCentimetre),
+            "G45" => Ok(UnitCode::NanosiemensPerMetre),
+            "G46" => Ok(UnitCode::StokesPerBar),
+            "G47" => Ok(UnitCode::CubicCentimetrePerDay),
+            "G48" => Ok(UnitCode::CubicCentimetrePerHour),
+            "G49" => Ok(UnitCode::CubicCentimetrePerMinute),
+            "G50" => Ok(UnitCode::GallonUsPerHour),
+            "G51" => Ok(UnitCode::LitrePerSecond),
+            "G52" => Ok(UnitCode::CubicMetrePerDay),
+            "G53" => Ok(UnitCode::CubicMetrePerMinute),
+            "G54" => Ok(UnitCode::MillilitrePerDay),
+            "G55" => Ok(UnitCode::MillilitrePerHour),
+            "G56" => Ok(UnitCode::CubicInchPerHour),
+            "G57" => Ok(UnitCode::CubicInchPerMinute),
+            "G58" => Ok(UnitCode::CubicInchPerSecond),
+            "G59" => Ok(UnitCode::MilliamperePerLitreMinute),
+            "G60" => Ok(UnitCode::VoltPerBar),
+            "G61" => Ok(UnitCode::CubicCentimetrePerDayKelvin),
+            "G62" => Ok(UnitCode::CubicCentimetrePerHourKelvin),
+            "G63" => Ok(UnitCode::CubicCentimetrePerMinuteKelvin),
+            "G64" => Ok(UnitCode::CubicCentimetrePerSecondKelvin),
+            "G65" => Ok(UnitCode::LitrePerDayKelvin),
+            "G66" => Ok(UnitCode::LitrePerHourKelvin),
+            "G67" => Ok(UnitCode::LitrePerMinuteKelvin),
+            "G68" => Ok(UnitCode::LitrePerSecondKelvin),
+            "G69" => Ok(UnitCode::CubicMetrePerDayKelvin),
+            "G70" => Ok(UnitCode::CubicMetrePerHourKelvin),
+            "G71" => Ok(UnitCode::CubicMetrePerMinuteKelvin),
+            "G72" => Ok(UnitCode::CubicMetrePerSecondKelvin),
+            "G73" => Ok(UnitCode::MillilitrePerDayKelvin),
+            "G74" => Ok(UnitCode::MillilitrePerHourKelvin),
+            "G75" => Ok(UnitCode::MillilitrePerMinuteKelvin),
+            "G76" => Ok(UnitCode::MillilitrePerSecondKelvin),
+            "G77" => Ok(UnitCode::MillipascalSecondPerKelvin),
+            "G78" => Ok(UnitCode::CubicCentimetrePerDayBar),
+            "G79" => Ok(UnitCode::CubicCentimetrePerHourBar),
+            "G80" => Ok(UnitCode::CubicCentimetrePerMinuteBar),
+            "G81" => Ok(UnitCode::CubicCentimetrePerSecondBar),
+            "G82" => Ok(UnitCode::LitrePerDayBar),
+            "G83" => Ok(UnitCode::LitrePerHourBar),
+            "G84" => Ok(UnitCode::LitrePerMinuteBar),
+            "G85" => Ok(UnitCode::LitrePerSecondBar),
+            "G86" => Ok(UnitCode::CubicMetrePerDayBar),
+            "G87" => Ok(UnitCode::CubicMetrePerHourBar),
+            "G88" => Ok(UnitCode::CubicMetrePerMinuteBar),
+            "G89" => Ok(UnitCode::CubicMetrePerSecondBar),
+            "G90" => Ok(UnitCode::MillilitrePerDayBar),
+            "G91" => Ok(UnitCode::MillilitrePerHourBar),
+            "G92" => Ok(UnitCode::MillilitrePerMinuteBar),
+            "G93" => Ok(UnitCode::MillilitrePerSecondBar),
+            "G94" => Ok(UnitCode::CubicCentimetrePerBar),
+            "G95" => Ok(UnitCode::LitrePerBar),
+            "G96" => Ok(UnitCode::CubicMetrePerBar),
+            "G97" => Ok(UnitCode::MillilitrePerBar),
+            "G98" => Ok(UnitCode::MicrohenryPerKiloohm),
+            "G99" => Ok(UnitCode::MicrohenryPerOhm),
+            "GB" => Ok(UnitCode::GallonUsPerDay),
+            "GBQ" => Ok(UnitCode::Gigabecquerel),
+            "GDW" => Ok(UnitCode::GramDryWeight),
+            "GE" => Ok(UnitCode::PoundPerGallonUs),
+            "GF" => Ok(UnitCode::GramPerMetreGramPer100Centimetres),
+            "GFI" => Ok(UnitCode::GramOfFissileIsotope),
+            "GGR" => Ok(UnitCode::GreatGross),
+            "GIA" => Ok(UnitCode::GillUs),
+            "GIC" => Ok(UnitCode::GramIncludingContainer),
+            "GII" => Ok(UnitCode::GillUk),
+            "GIP" => Ok(UnitCode::GramIncludingInnerPackaging),
+            "GJ" => Ok(UnitCode::GramPerMillilitre),
+            "GL" => Ok(UnitCode::GramPerLitre),
+            "GLD" => Ok(UnitCode::DryGallonUs),
+            "GLI" => Ok(UnitCode::GallonUk),
+            "GLL" => Ok(UnitCode::GallonUs),
+            "GM" => Ok(UnitCode::GramPerSquareMetre),
+            "GO" => Ok(UnitCode::MilligramPerSquareMetre),
+            "GP" => Ok(UnitCode::MilligramPerCubicMetre),
+            "GQ" => Ok(UnitCode::MicrogramPerCubicMetre),
+            "GRM" => Ok(UnitCode::Gram),
+            "GRN" => Ok(UnitCode::Grain),
+            "GRO" => Ok(UnitCode::Gross),
+            "GV" => Ok(UnitCode::Gigajoule),
+            "GWH" => Ok(UnitCode::GigawattHour),
+            "H03" => Ok(UnitCode::HenryPerKiloohm),
+            "H04" => Ok(UnitCode::HenryPerOhm),
+            "H05" => Ok(UnitCode::MillihenryPerKiloohm),
+            "H06" => Ok(UnitCode::MillihenryPerOhm),
+            "H07" => Ok(UnitCode::PascalSecondPerBar),
+            "H08" => Ok(UnitCode::Microbecquerel),
+            "H09" => Ok(UnitCode::ReciprocalYear),
+            "H10" => Ok(UnitCode::ReciprocalHour),
+            "H11" => Ok(UnitCode::ReciprocalMonth),
+            "H12" => Ok(UnitCode::DegreeCelsiusPerHour),
+            "H13" => Ok(UnitCode::DegreeCelsiusPerMinute),
+            "H14" => Ok(UnitCode::DegreeCelsiusPerSecond),
+            "H15" => Ok(UnitCode::SquareCentimetrePerGram),
+            "H16" => Ok(UnitCode::SquareDecametre),
+            "H18" => Ok(UnitCode::SquareHectometre),
+            "H19" => Ok(UnitCode::CubicHectometre),
+            "H20" => Ok(UnitCode::CubicKilometre),
+            "H21" => Ok(UnitCode::Blank),
+            "H22" => Ok(UnitCode::VoltSquareInchPerPoundForce),
+            "H23" => Ok(UnitCode::VoltPerInch),
+            "H24" => Ok(UnitCode::VoltPerMicrosecond),
+            "H25" => Ok(UnitCode::PercentPerKelvin),
+            "H26" => Ok(UnitCode::OhmPerMetre),
+            "H27" => Ok(UnitCode::DegreePerMetre),
+            "H28" => Ok(UnitCode::MicrofaradPerKilometre),
+            "H29" => Ok(UnitCode::MicrogramPerLitre),
+            "H30" => Ok(UnitCode::SquareMicrometreSquareMicron),
+            "H31" => Ok(UnitCode::AmperePerKilogram),
+            "H32" => Ok(UnitCode::AmpereSquaredSecond),
+            "H33" => Ok(UnitCode::FaradPerKilometre),
+            "H34" => Ok(UnitCode::HertzMetre),
+            "H35" => Ok(UnitCode::KelvinMetrePerWatt),
+            "H36" => Ok(UnitCode::MegaohmPerKilometre),
+            "H37" => Ok(UnitCode::MegaohmPerMetre),
+            "H38" => Ok(UnitCode::Megaampere),
+            "H39" => Ok(UnitCode::MegahertzKilometre),
+            "H40" => Ok(UnitCode::NewtonPerAmpereH40),
+            "H41" => Ok(UnitCode::NewtonMetreWattToThePowerMinus05),
+            "H42" => Ok(UnitCode::PascalPerMetre),
+            "H43" => Ok(UnitCode::SiemensPerCentimetre),
+            "H44" => Ok(UnitCode::Teraohm),
+            "H45" => Ok(UnitCode::VoltSecondPerMetre),
+            "H46" => Ok(UnitCode::VoltPerSecond),
+            "H47" => Ok(UnitCode::WattPerCubicMetre),
+            "H48" => Ok(UnitCode::Attofarad),
+            "H49" => Ok(UnitCode::CentimetrePerHour),
+            "H50" => Ok(UnitCode::ReciprocalCubicCentimetre),
+            "H51" => Ok(UnitCode::DecibelPerKilometre),
+            "H52" => Ok(UnitCode::DecibelPerMetre),
+            "H53" => Ok(UnitCode::KilogramPerBar),
+            "H54" => Ok(UnitCode::KilogramPerCubicDecimetreKelvin),
+            "H55" => Ok(UnitCode::KilogramPerCubicDecimetreBar),
+            "H56" => Ok(UnitCode::KilogramPerSquareMetreSecond),
+            "H57" => Ok(UnitCode::InchPerTwoPiRadiant),
+            "H58" => Ok(UnitCode::MetrePerVoltSecond),
+            "H59" => Ok(UnitCode::SquareMetrePerNewton),
+            "H60" => Ok(UnitCode::CubicMetrePerCubicMetre),
+            "H61" => Ok(UnitCode::MillisiemensPerCentimetre),
+            "H62" => Ok(UnitCode::MillivoltPerMinute),
+            "H63" => Ok(UnitCode::MilligramPerSquareCentimetre),
+            "H64" => Ok(UnitCode::MilligramPerGram),
+            "H65" => Ok(UnitCode::MillilitrePerCubicMetre),
+            "H66" => Ok(UnitCode::MillimetrePerYear),
+            "H67" => Ok(UnitCode::MillimetrePerHour),
+            "H68" => Ok(UnitCode::MillimolePerGram),
+            "H69" => Ok(UnitCode::PicopascalPerKilometre),
+            "H70" => Ok(UnitCode::Picosecond),
+            "H71" => Ok(UnitCode::PercentPerMonth),
+            "H72" => Ok(UnitCode::PercentPerHectobar),
+            "H73" => Ok(UnitCode::PercentPerDecakelvin),
+            "H74" => Ok(UnitCode::WattPerMetre),
+            "H75" => Ok(UnitCode::Decapascal),
+            "H76" => Ok(UnitCode::GramPerMillimetre),
+            "H77" => Ok(UnitCode::ModuleWidth),
+            "H79" => Ok(UnitCode::FrenchGauge),
+            "H80" => Ok(UnitCode::RackUnit),
+            "H81" => Ok(UnitCode::MillimetrePerMinute),
+            "H82" => Ok(UnitCode::BigPoint),
+            "H83" => Ok(UnitCode::LitrePerKilogram),
+            "H84" => Ok(UnitCode::GramMillimetre),
+            "H85" => Ok(UnitCode::ReciprocalWeek),
+            "H87" => Ok(UnitCode::Piece),
+            "H88" => Ok(UnitCode::MegaohmKilometre),
+            "H89" => Ok(UnitCode::PercentPerOhm),
+            "H90" => Ok(UnitCode::PercentPerDegree),
+            "H91" => Ok(UnitCode::PercentPerTenThousand),
+            "H92" => Ok(UnitCode::PercentPerOneHundredThousand),
+            "H93" => Ok(UnitCode::PercentPerHundred),
+            "H94" => Ok(UnitCode::PercentPerThousand),
+            "H95" => Ok(UnitCode::PercentPerVolt),
+            "H96" => Ok(UnitCode::PercentPerBar),
+            "H98" => Ok(UnitCode::PercentPerInch),
+            "H99" => Ok(UnitCode::PercentPerMetre),
+            "HA" => Ok(UnitCode::Hank),
+            "HAD" => Ok(UnitCode::PieceDay),
+            "HBA" => Ok(UnitCode::Hectare),
+            "HBX" => Ok(UnitCode::HundredBoxes),
+            "HC" => Ok(UnitCode::HundredCount),
+            "HDW" => Ok(UnitCode::HundredKilogramDryWeight),
+            "HEA" => Ok(UnitCode::Head),
+            "HGM" => Ok(UnitCode::Hectogram),
+            "HH" => Ok(UnitCode::HundredCubicFoot),
+            "HIU" => Ok(UnitCode::HundredInternationalUnit),
+            "HKM" => Ok(UnitCode::HundredKilogramNetMass),
+            "HLT" => Ok(UnitCode::Hectolitre),
+            "HM" => Ok(UnitCode::MilePerHourStatuteMile),
+            "HMO" => Ok(UnitCode::PieceMonth),
+            "HMQ" => Ok(UnitCode::MillionCubicMetre),
+            "HMT" => Ok(UnitCode::Hectometre),
+            "HPA" => Ok(UnitCode::HectolitreOfPureAlcohol),
+            "HTZ" => Ok(UnitCode::Hertz),
+            "HUR" => Ok(UnitCode::Hour),
+            "HWE" => Ok(UnitCode::PieceWeek),
+            "IA" => Ok(UnitCode::InchPoundPoundInch),
+            "IE" => Ok(UnitCode::Person),
+            "INH" => Ok(UnitCode::Inch),
+            "INK" => Ok(UnitCode::SquareInch),
+            "INQ" => Ok(UnitCode::CubicInch),
+            "ISD" => Ok(UnitCode::InternationalSugarDegree),
+            "IU" => Ok(UnitCode::InchPerSecond),
+            "IUG" => Ok(UnitCode::InternationalUnitPerGram),
+            "IV" => Ok(UnitCode::InchPerSecondSquared),
+            "J10" => Ok(UnitCode::PercentPerMillimetre),
+            "J12" => Ok(UnitCode::PerMillePerPsi),
+            "J13" => Ok(UnitCode::DegreeApi),
+            "J14" => Ok(UnitCode::DegreeBaumeOriginScale),
+            "J15" => Ok(UnitCode::DegreeBaumeUsHeavy),
+            "J16" => Ok(UnitCode::DegreeBaumeUsLight),
+            "J17" => Ok(UnitCode::DegreeBalling),
+            "J18" => Ok(UnitCode::DegreeBrix),
+            "J19" => Ok(UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitThermochemical),
+            "J2" => Ok(UnitCode::JoulePerKilogram),
+            "J20" => Ok(UnitCode::DegreeFahrenheitPerKelvin),
+            "J21" => Ok(UnitCode::DegreeFahrenheitPerBar),
+            "J22" => Ok(UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitInternationalTable),
+            "J23" => Ok(UnitCode::DegreeFahrenheitPerHour),
+            "J24" => Ok(UnitCode::DegreeFahrenheitPerMinute),
+            "J25" => Ok(UnitCode::DegreeFahrenheitPerSecond),
+            "J26" => Ok(UnitCode::ReciprocalDegreeFahrenheit),
+            "J27" => Ok(UnitCode::DegreeOechsle),
+            "J28" => Ok(UnitCode::DegreeRankinePerHour),
+            "J29" => Ok(UnitCode::DegreeRankinePerMinute),
+            "J30" => Ok(UnitCode::DegreeRankinePerSecond),
+            "J31" => Ok(UnitCode::DegreeTwaddell),
+            "J32" => Ok(UnitCode::Micropoise),
+            "J33" => Ok(UnitCode::MicrogramPerKilogram),
+            "J34" => Ok(UnitCode::MicrogramPerCubicMetreKelvin),
+            "J35" => Ok(UnitCode::MicrogramPerCubicMetreBar),
+            "J36" => Ok(UnitCode::MicrolitrePerLitre),
+            "J38" => Ok(UnitCode::Baud),
+            "J39" => Ok(UnitCode::BritishThermalUnitMean),
+            "J40" => Ok(UnitCode::BritishThermalUnitInternationalTableFootPerHourSquareFootDegreeFahrenheit),
+            "J41" => Ok(UnitCode::BritishThermalUnitInternationalTableInchPerHourSquareFootDegreeFahrenheit),
+            "J42" => Ok(UnitCode::BritishThermalUnitInternationalTableInchPerSecondSquareFootDegreeFahrenheit),
+            "J43" => Ok(UnitCode::BritishThermalUnitInternationalTablePerPoundDegreeFahrenheit),
+            "J44" => Ok(UnitCode::BritishThermalUnitInternationalTablePerMinute),
+            "J45" => Ok(UnitCode::BritishThermalUnitInternationalTablePerSecond),
+            "J46" => Ok(UnitCode::BritishThermalUnitThermochemicalFootPerHourSquareFootDegreeFahrenheit),
+            "J47" => Ok(UnitCode::BritishThermalUnitThermochemicalPerHour),
+            "J48" => Ok(UnitCode::BritishThermalUnitThermochemicalInchPerHourSquareFootDegreeFahrenheit),
+            "J49" => Ok(UnitCode::BritishThermalUnitThermochemicalInchPerSecondSquareFootDegreeFahrenheit),
+            "J50" => Ok(UnitCode::BritishThermalUnitThermochemicalPerPoundDegreeFahrenheit),
+            "J51" => Ok(UnitCode::BritishThermalUnitThermochemicalPerMinute),
+            "J52" => Ok(UnitCode::BritishThermalUnitThermochemicalPerSecond),
+            "J53" => Ok(UnitCode::CoulombSquareMetrePerKilogram),
+            "J54" => Ok(UnitCode::Megabaud),
+            "J55" => Ok(UnitCode::WattSecond),
+            "J56" => Ok(UnitCode::BarPerBar),
+            "J57" => Ok(UnitCode::BarrelUkPetroleum),
+            "J58" => Ok(UnitCode::BarrelUkPetroleumPerMinute),
+            "J59" => Ok(UnitCode::BarrelUkPetroleumPerDay),
+            "J60" => Ok(UnitCode::BarrelUkPetroleumPerHour),
+            "J61" => Ok(UnitCode::BarrelUkPetroleumPerSecond),
+            "J62" => Ok(UnitCode::BarrelUsPetroleumPerHour),
+            "J63" => Ok(UnitCode::BarrelUsPetroleumPerSecond),
+            "J64" => Ok(UnitCode::BushelUkPerDay),
+            "J65" => Ok(UnitCode::BushelUkPerHour),
+            "J66" => Ok(UnitCode::BushelUkPerMinute),
+            "J67" => Ok(UnitCode::BushelUkPerSecond),
+            "J68" => Ok(UnitCode::BushelUsDryPerDay),
+            "J69" => Ok(UnitCode::BushelUsDryPerHour),
+            "J70" => Ok(UnitCode::BushelUsDryPerMinute),
+            "J71" => Ok(UnitCode::BushelUsDryPerSecond),
+            "J72" => Ok(UnitCode::CentinewtonMetre),
+            "J73" => Ok(UnitCode::CentipoisePerKelvin),
+            "J74" => Ok(UnitCode::CentipoisePerBar),
+            "J75" => Ok(UnitCode::CalorieMean),
+            "J76" => Ok(UnitCode::CalorieInternationalTablePerGramDegreeCelsius),
+            "J78" => Ok(UnitCode::CalorieThermochemicalPerCentimetreSecondDegreeCelsius),
+            "J79" => Ok(UnitCode::CalorieThermochemicalPerGramDegreeCelsius),
+            "J81" => Ok(UnitCode::CalorieThermochemicalPerMinute),
+            "J82" => Ok(UnitCode::CalorieThermochemicalPerSecond),
+            "J83" => Ok(UnitCode::Clo),
+            "J84" => Ok(UnitCode::CentimetrePerSecondKelvin),
+            "J85" => Ok(UnitCode::CentimetrePerSecondBar),
+            "J87" => Ok(UnitCode::CubicCentimetrePerCubicMetre),
+            "J90" => Ok(UnitCode::CubicDecimetrePerDay),
+            "J92" => Ok(UnitCode::CubicDecimetrePerMinute),
+            "J93" => Ok(UnitCode::CubicDecimetrePerSecond),
+            "J95" => Ok(UnitCode::OunceUkFluidPerDay),
+            "J96" => Ok(UnitCode::OunceUkFluidPerHour),
+            "J97" => Ok(UnitCode::OunceUkFluidPerMinute),
+            "J98" => Ok(UnitCode::OunceUkFluidPerSecond),
+            "J99" => Ok(UnitCode::OunceUsFluidPerDay),
+            "JE" => Ok(UnitCode::JoulePerKelvin),
+            "JK" => Ok(UnitCode::MegajoulePerKilogram),
+            "JM" => Ok(UnitCode::MegajoulePerCubicMetre),
+            "JNT" => Ok(UnitCode::PipelineJoint),
+            "JOU" => Ok(UnitCode::Joule),
+            "JPS" => Ok(UnitCode::HundredMetre),
+            "JWL" => Ok(UnitCode::NumberOfJewels),
+            "K1" => Ok(UnitCode::KilowattDemand),
+            "K10" => Ok(UnitCode::OunceUsFluidPerHour),
+            "K11" => Ok(UnitCode::OunceUsFluidPerMinute),
+            "K12" => Ok(UnitCode::OunceUsFluidPerSecond),
+            "K13" => Ok(UnitCode::FootPerDegreeFahrenheit),
+            "K14" => Ok(UnitCode::FootPerHour),
+            "K15" => Ok(UnitCode::FootPoundForcePerHour),
+            "K16" => Ok(UnitCode::FootPoundForcePerMinute),
+            "K17" => Ok(UnitCode::FootPerPsi),
+            "K18" => Ok(UnitCode::FootPerSecondDegreeFahrenheit),
+            "K19" => Ok(UnitCode::FootPerSecondPsi),
+            "K2" => Ok(UnitCode::KilovoltAmpereReactiveDemand),
+            "K20" => Ok(UnitCode::ReciprocalCubicFoot),
+            "K21" => Ok(UnitCode::CubicFootPerDegreeFahrenheit),
+            "K22" => Ok(UnitCode::CubicFootPerDay),
+            "K23" => Ok(UnitCode::CubicFootPerPsi),
+            "K26" => Ok(UnitCode::GallonUkPerDay),
+            "K27" => Ok(UnitCode::GallonUkPerHour),
+            "K28" => Ok(UnitCode::GallonUkPerSecond),
+            "K3" => Ok(UnitCode::KilovoltAmpereReactiveHour),
+            "K30" => Ok(UnitCode::GallonUsLiquidPerSecond),
+            "K31" => Ok(UnitCode::GramForcePerSquareCentimetre),
+            "K32" => Ok(UnitCode::GillUkPerDay),
+            "K33" => Ok(UnitCode::GillUkPerHour),
+            "K34" => Ok(UnitCode::GillUkPerMinute),
+            "K35" => Ok(UnitCode::GillUkPerSecond),
+            "K36" => Ok(UnitCode::GillUsPerDay),
+            "K37" => Ok(UnitCode::GillUsPerHour),
+            "K38" => Ok(UnitCode::GillUsPerMinute),
+            "K39" => Ok(UnitCode::GillUsPerSecond),
+            "K40" => Ok(UnitCode::StandardAccelerationOfFreeFall),
+            "K41" => Ok(UnitCode::GrainPerGallonUs),
+            "K42" => Ok(UnitCode::HorsepowerBoiler),
+            "K43" => Ok(UnitCode::HorsepowerElectric),
+            "K45" => Ok(UnitCode::InchPerDegreeFahrenheit),
+            "K46" => Ok(UnitCode::InchPerPsi),
+            "K47" => Ok(UnitCode::InchPerSecondDegreeFahrenheit),
+            "K48" => Ok(UnitCode::InchPerSecondPsi),
+            "K49" => Ok(UnitCode::ReciprocalCubicInch),
+            "K50" => Ok(UnitCode::Kilobaud),
+            "K51" => Ok(UnitCode::KilocalorieMean),
+            "K52" => Ok(UnitCode::KilocalorieInternationalTablePerHourMetreDegreeCelsius),
+            "K53" => Ok(UnitCode::KilocalorieThermochemical),
+            "K54" => Ok(UnitCode::KilocalorieThermochemicalPerMinute),
+            "K55" => Ok(UnitCode::KilocalorieThermochemicalPerSecond),
+            "K58" => Ok(UnitCode::KilomolePerHour),
+            "K59" => Ok(UnitCode::KilomolePerCubicMetreKelvin),
+            "K6" => Ok(UnitCode::Kilolitre),
+            "K60" => Ok(UnitCode::KilomolePerCubicMetreBar),
+            "K61" => Ok(UnitCode::KilomolePerMinute),
+            "K62" => Ok(UnitCode::LitrePerLitre),
+            "K63" => Ok(UnitCode::ReciprocalLitre),
+            "K64" => Ok(UnitCode::PoundAvoirdupoisPerDegreeFahrenheit),
+            "K65" => Ok(UnitCode::PoundAvoirdupoisSquareFoot),
+            "K66" => Ok(UnitCode::PoundAvoirdupoisPerDay),
+            "K67" => Ok(UnitCode::PoundPerFootHour),
+            "K68" => Ok(UnitCode::PoundPerFootSecond),
+            "K69" => Ok(UnitCode::PoundAvoirdupoisPerCubicFootDegreeFahrenheit),
+            "K70" => Ok(UnitCode::PoundAvoirdupoisPerCubicFootPsi),
+            "K71" => Ok(UnitCode::PoundAvoirdupoisPerGallonUk),
+            "K73" => Ok(UnitCode::PoundAvoirdupoisPerHourDegreeFahrenheit),
+            "K74" => Ok(UnitCode::PoundAvoirdupoisPerHourPsi),
+            "K75" => Ok(UnitCode::PoundAvoirdupoisPerCubicInchDegreeFahrenheit),
+            "K76" => Ok(UnitCode::PoundAvoirdupoisPerCubicInchPsi),
+            "K77" => Ok(UnitCode::PoundAvoirdupoisPerPsi),
+            "K78" => Ok(UnitCode::PoundAvoirdupoisPerMinute),
+            "K79" => Ok(UnitCode::PoundAvoirdupoisPerMinuteDegreeFahrenheit),
+            "K80" => Ok(UnitCode::PoundAvoirdupoisPerMinutePsi),
+            "K81" => Ok(UnitCode::PoundAvoirdupoisPerSecond),
+            "K82" => Ok(UnitCode::PoundAvoirdupoisPerSecondDegreeFahrenheit),
+            "K83" => Ok(UnitCode::PoundAvoirdupoisPerSecondPsi),
+            "K84" => Ok(UnitCode::PoundPerCubicYard),
+            "K85" => Ok(UnitCode::PoundForcePerSquareFoot),
+            "K86" => Ok(UnitCode::PoundForcePerSquareInchDegreeFahrenheit),
+            "K87" => Ok(UnitCode::PsiCubicInchPerSecond),
+            "K88" => Ok(UnitCode::PsiLitrePerSecond),
+            "K89" => Ok(UnitCode::PsiCubicMetrePerSecond),
+            "K90" => Ok(UnitCode::PsiCubicYardPerSecond),
+            "K91" => Ok(UnitCode::PoundForceSecondPerSquareFoot),
+            "K92" => Ok(UnitCode::PoundForceSecondPerSquareInch),
+            "K93" => Ok(UnitCode::ReciprocalPsi),
+            "K94" => Ok(UnitCode::QuartUkLiquidPerDay),
+            "K95" => Ok(UnitCode::QuartUkLiquidPerHour),
+            "K96" => Ok(UnitCode::QuartUkLiquidPerMinute),
+            "K97" => Ok(UnitCode::QuartUkLiquidPerSecond),
+            "K98" => Ok(UnitCode::QuartUsLiquidPerDay),
+            "K99" => Ok(UnitCode::QuartUsLiquidPerHour),
+            "KA" => Ok(UnitCode::Cake),
+            "KAT" => Ok(UnitCode::Katal),
+            "KB" => Ok(UnitCode::Kilocharacter),
+            "KBA" => Ok(UnitCode::Kilobar),
+            "KCC" => Ok(UnitCode::KilogramOfCholineChloride),
+            "KDW" => Ok(UnitCode::KilogramDrainedNetWeight),
+            "KEL" => Ok(UnitCode::Kelvin),
+            "KGM" => Ok(UnitCode::Kilogram),
+            "KGS" => Ok(UnitCode::KilogramPerSecond),
+            "KHY" => Ok(UnitCode::KilogramOfHydrogenPeroxide),
+            "KHZ" => Ok(UnitCode::Kilohertz),
+            "KI" => Ok(UnitCode::KilogramPerMillimetreWidth),
+            "KIC" => Ok(UnitCode::KilogramIncludingContainer),
+            "KIP" => Ok(UnitCode::KilogramIncludingInnerPackaging),
+            "KJ" => Ok(UnitCode::Kilosegment),
+            "KJO" => Ok(UnitCode::Kilojoule),
+            "KL" => Ok(UnitCode::KilogramPerMetre),
+            "KLK" => Ok(UnitCode::LacticDryMaterialPercentage),
+            "KLX" => Ok(UnitCode::Kilolux),
+            "KMA" => Ok(UnitCode::KilogramOfMethylamine),
+            "KMH" => Ok(UnitCode::KilometrePerHour),
+            "KMK" => Ok(UnitCode::SquareKilometre),
+            "KMQ" => Ok(UnitCode::KilogramPerCubicMetre),
+            "KMT" => Ok(UnitCode::Kilometre),
+            "KNI" => Ok(UnitCode::KilogramOfNitrogen),
+            "KNM" => Ok(UnitCode::KilonewtonPerSquareMetre),
+            "KNS" => Ok(UnitCode::KilogramNamedSubstance),
+            "KNT" => Ok(UnitCode::Knot),
+            "KO" => Ok(UnitCode::MilliequivalenceCausticPotashPerGramOfProduct),
+            "KPA" => Ok(UnitCode::Kilopascal),
+            "KPH" => Ok(UnitCode::KilogramOfPotassiumHydroxideCausticPotash),
+            "KPO" => Ok(UnitCode::KilogramOfPotassiumOxide),
+            "KPP" => Ok(UnitCode::KilogramOfPhosphorusPentoxidePhosphoricAnhydride),
+            "KR" => Ok(UnitCode::Kiloroentgen),
+            "KSD" => Ok(UnitCode::KilogramOfSubstance90PercentDry),
+            "KSH" => Ok(UnitCode::KilogramOfSodiumHydroxideCausticSoda),
+            "KT" => Ok(UnitCode::Kit),
+            "KTN" => Ok(UnitCode::Kilotonne),
+            "KUR" => Ok(UnitCode::KilogramOfUranium),
+            "KVA" => Ok(UnitCode::KilovoltAmpere),
+            "KVR" => Ok(UnitCode::Kilovar),
+            "KVT" => Ok(UnitCode::Kilovolt),
+            "KW" => Ok(UnitCode::KilogramPerMillimetre),
+            "KWH" => Ok(UnitCode::KilowattHour),
+            "KWN" => Ok(UnitCode::KilowattHourPerNormalizedCubicMetre),
+            "KWO" => Ok(UnitCode::KilogramOfTungstenTrioxide),
+            "KWS" => Ok(UnitCode::KilowattHourPerStandardCubicMetre),
+            "KWT" => Ok(UnitCode::Kilowatt),
+            "KWY" => Ok(UnitCode::KilowattYear),
+            "KX" => Ok(UnitCode::MillilitrePerKilogram),
+            "L10" => Ok(UnitCode::QuartUsLiquidPerMinute),
+            "L11" => Ok(UnitCode::QuartUsLiquidPerSecond),
+            "L12" => Ok(UnitCode::MetrePerSecondKelvin),
+            "L13" => Ok(UnitCode::MetrePerSecondBar),
+            "L14" => Ok(UnitCode::SquareMetreHourDegreeCelsiusPerKilocalorieInternationalTable),
+            "L15" => Ok(UnitCode::MillipascalSecondPerKelvinL15),
+            "L16" => Ok(UnitCode::MillipascalSecondPerBar),
+            "L17" => Ok(UnitCode::MilligramPerCubicMetreKelvin),
+            "L18" => Ok(UnitCode::MilligramPerCubicMetreBar),
+            "L19" => Ok(UnitCode::MillilitrePerLitre),
+            "L2" => Ok(UnitCode::LitrePerMinute),
+            "L20" => Ok(UnitCode::ReciprocalCubicMillimetre),
+            "L21" => Ok(UnitCode::CubicMillimetrePerCubicMetre),
+            "L23" => Ok(UnitCode::MolePerHour),
+            "L24" => Ok(UnitCode::MolePerKilogramKelvin),
+            "L25" => Ok(UnitCode::MolePerKilogramBar),
+            "L26" => Ok(UnitCode::MolePerLitreKelvin),
+            "L27" => Ok(UnitCode::MolePerLitreBar),
+            "L28" => Ok(UnitCode::MolePerCubicMetreKelvin),
+            "L29" => Ok(UnitCode::MolePerCubicMetreBar),
+            "L30" => Ok(UnitCode::MolePerMinute),
+            "L31" => Ok(UnitCode::MilliroentgenAequivalentMen),
+            "L32" => Ok(UnitCode::NanogramPerKilogram),
+            "L33" => Ok(UnitCode::OunceAvoirdupoisPerDay),
+            "L34" => Ok(UnitCode::OunceAvoirdupoisPerHour),
+            "L35" => Ok(UnitCode::OunceAvoirdupoisPerMinute),
+            "L36" => Ok(UnitCode::OunceAvoirdupoisPerSecond),
+            "L37" => Ok(UnitCode::OunceAvoirdupoisPerGallonUk),
+            "L38" => Ok(UnitCode::OunceAvoirdupoisPerGallonUs),
+            "L39" => Ok(UnitCode::OunceAvoirdupoisPerCubicInch),
+            "L40" => Ok(UnitCode::OunceAvoirdupoisForce),
+            "L41" => Ok(UnitCode::OunceAvoirdupoisForceInch),
+            "L42" => Ok(UnitCode::PicosiemensPerMetre),
+            "L43" => Ok(UnitCode::PeckUk),
+            "L44" => Ok(UnitCode::PeckUkPerDay),
+            "L45" => Ok(UnitCode::PeckUkPerHour),
+            "L46" => Ok(UnitCode::PeckUkPerMinute),
+            "L47" => Ok(UnitCode::PeckUkPerSecond),
+            "L48" => Ok(UnitCode::PeckUsDryPerDay),
+            "L49" => Ok(UnitCode::PeckUsDryPerHour),
+            "L50" => Ok(UnitCode::PeckUsDryPerMinute),
+            "L51" => Ok(UnitCode::PeckUsDryPerSecond),
+            "L52" => Ok(UnitCode::PsiPerPsi),
+            "L53" => Ok(UnitCode::PintUkPerDay),
+            "L54" => Ok(UnitCode::PintUkPerHour),
+            "L55" => Ok(UnitCode::PintUkPerMinute),
+            "L56" => Ok(UnitCode::PintUkPerSecond),
+            "L57" => Ok(UnitCode::PintUsLiquidPerDay),
+            "L58" => Ok(UnitCode::PintUsLiquidPerHour),
+            "L59" => Ok(UnitCode::PintUsLiquidPerMinute),
+            "L60" => Ok(UnitCode::PintUsLiquidPerSecond),
+            "L63" => Ok(UnitCode::SlugPerDay),
+            "L64" => Ok(UnitCode::SlugPerFootSecond),
+            "L65" => Ok(UnitCode::SlugPerCubicFoot),
+            "L66" => Ok(UnitCode::SlugPerHour),
+            "L67" => Ok(UnitCode::SlugPerMinute),
+            "L68" => Ok(UnitCode::SlugPerSecond),
+            "L69" => Ok(UnitCode::TonnePerKelvin),
+            "L70" => Ok(UnitCode::TonnePerBar),
+            "L71" => Ok(UnitCode::TonnePerDay),
+            "L72" => Ok(UnitCode::TonnePerDayKelvin),
+            "L73" => Ok(UnitCode::TonnePerDayBar),
+            "L74" => Ok(UnitCode::TonnePerHourKelvin),
+            "L75" => Ok(UnitCode::TonnePerHourBar),
+            "L76" => Ok(UnitCode::TonnePerCubicMetreKelvin),
+            "L77" => Ok(UnitCode::TonnePerCubicMetreBar),
+            "L78" => Ok(UnitCode::TonnePerMinute),
+            "L79" => Ok(UnitCode::TonnePerMinuteKelvin),
+            "L80" => Ok(UnitCode::TonnePerMinuteBar),
+            "L81" => Ok(UnitCode::TonnePerSecond),
+            "L82" => Ok(UnitCode::TonnePerSecondKelvin),
+            "L83" => Ok(UnitCode::TonnePerSecondBar),
+            "L84" => Ok(UnitCode::TonUkShipping),
+            "L85" => Ok(UnitCode::TonLongPerDay),
+            "L86" => Ok(UnitCode::TonUsShipping),
+            "L87" => Ok(UnitCode::TonShortPerDegreeFahrenheit),
+            "L88" => Ok(UnitCode::TonShortPerDay),
+            "L89" => Ok(UnitCode::TonShortPerHourDegreeFahrenheit),
+            "L90" => Ok(UnitCode::TonShortPerHourPsi),
+            "L91" => Ok(UnitCode::TonShortPerPsi),
+            "L92" => Ok(UnitCode::TonUkLongPerCubicYard),
+            "L93" => Ok(UnitCode::TonUsShortPerCubicYard),
+            "L94" => Ok(UnitCode::TonForceUsShort),
+            "L95" => Ok(UnitCode::CommonYear),
+            "L96" => Ok(UnitCode::SiderealYear),
+            "L98" => Ok(UnitCode::YardPerDegreeFahrenheit),
+            "L99" => Ok(UnitCode::YardPerPsi),
+            "LA" => Ok(UnitCode::PoundPerCubicInch),
+            "LAC" => Ok(UnitCode::LactoseExcessPercentage),
+            "LBR" => Ok(UnitCode::Pound),
+            "LBT" => Ok(UnitCode::TroyPoundUs),
+            "LD" => Ok(UnitCode::LitrePerDay),
+            "LEF" => Ok(UnitCode::Leaf),
+            "LF" => Ok(UnitCode::LinearFoot),
+            "LH" => Ok(UnitCode::LabourHour),
+            "LK" => Ok(UnitCode::Link),
+            "LM" => Ok(UnitCode::LinearMetre),
+            "LN" => Ok(UnitCode::Length),
+            "LO" => Ok(UnitCode::LotUnitOfProcurement),
+            "LP" => Ok(UnitCode::LiquidPound),
+            "LPA" => Ok(UnitCode::LitreOfPureAlcohol),
+            "LR" => Ok(UnitCode::Layer),
+            "LS" => Ok(UnitCode::LumpSum),
+            "LTN" => Ok(UnitCode::TonUkOrLongTonUs),
+            "LTR" => Ok(UnitCode::Litre),
+            "LUB" => Ok(UnitCode::MetricTonLubricatingOil),
+            "LUM" => Ok(UnitCode::Lumen),
+            "LUX" => Ok(UnitCode::Lux),
+            "LY" => Ok(UnitCode::LinearYard),
+            "M1" => Ok(UnitCode::MilligramPerLitre),
+            "M10" => Ok(UnitCode::ReciprocalCubicYard),
+            "M11" => Ok(UnitCode::CubicYardPerDegreeFahrenheit),
+            "M12" => Ok(UnitCode::CubicYardPerDay),
+            "M13" => Ok(UnitCode::CubicYardPerHour),
+            "M14" => Ok(UnitCode::CubicYardPerPsi),
+            "M15" => Ok(UnitCode::CubicYardPerMinute),
+            "M16" => Ok(UnitCode::CubicYardPerSecond),
+            "M17" => Ok(UnitCode::KilohertzMetre),
+            "M18" => Ok(UnitCode::GigahertzMetre),
+            "M19" => Ok(UnitCode::Beaufort),
+            "M20" => Ok(UnitCode::ReciprocalMegakelvinOrMegakelvinToThePowerMinusOne),
+            "M21" => Ok(UnitCode::ReciprocalKilovoltAmpereReciprocalHour),
+            "M22" => Ok(UnitCode::MillilitrePerSquareCentimetreMinute),
+            "M23" => Ok(UnitCode::NewtonPerCentimetre),
+            "M24" => Ok(UnitCode::OhmKilometre),
+            "M25" => Ok(UnitCode::PercentPerDegreeCelsius),
+            "M26" => Ok(UnitCode::GigaohmPerMetre),
+            "M27" => Ok(UnitCode::MegahertzMetre),
+            "M29" => Ok(UnitCode::KilogramPerKilogram),
+            "M30" => Ok(UnitCode::ReciprocalVoltAmpereReciprocalSecond),
+            "M31" => Ok(UnitCode::KilogramPerKilometre),
+            "M32" => Ok(UnitCode::PascalSecondPerLitre),
+            "M33" => Ok(UnitCode::MillimolePerLitre),
+            "M34" => Ok(UnitCode::NewtonMetrePerSquareMetre),
+            "M35" => Ok(UnitCode::MillivoltAmpere),
+            "M36" => Ok(UnitCode::ThirtyDayMonth),
+            "M37" => Ok(UnitCode::ActualPer360),
+            "M38" => Ok(UnitCode::KilometrePerSecondSquared),
+            "M39" => Ok(UnitCode::CentimetrePerSecondSquared),
+            "M4" => Ok(UnitCode::MonetaryValue),
+            "M40" => Ok(UnitCode::YardPerSecondSquared),
+            "M41" => Ok(UnitCode::MillimetrePerSecondSquared),
+            "M42" => Ok(UnitCode::MileStatuteMilePerSecondSquared),
+            "M43" => Ok(UnitCode::Mil),
+            "M44" => Ok(UnitCode::Revolution),
+            "M45" => Ok(UnitCode::DegreeUnitOfAnglePerSecondSquared),
+            "M46" => Ok(UnitCode::RevolutionPerMinute),
+            "M47" => Ok(UnitCode::CircularMil),
+            "M48" => Ok(UnitCode::SquareMileBasedOnUSSurveyFoot),
+            "M49" => Ok(UnitCode::ChainBasedOnUSSurveyFoot),
+            "M5" => Ok(UnitCode::Microcurie),
+            "M50" => Ok(UnitCode::Furlong),
+            "M51" => Ok(UnitCode::FootUSSurvey),
+            "M52" => Ok(UnitCode::MileBasedOnUSSurveyFoot),
+            "M53" => Ok(UnitCode::MetrePerPascal),
+            "M55" => Ok(UnitCode::MetrePerRadiant),
+            "M56" => Ok(UnitCode::Shake),
+            "M57" => Ok(UnitCode::MilePerMinute),
+            "M58" => Ok(UnitCode::MilePerSecond),
+            "M59" => Ok(UnitCode::MetrePerSecondPascal),
+            "M60" => Ok(UnitCode::MetrePerHour),
+            "M61" => Ok(UnitCode::InchPerYear),
+            "M62" => Ok(UnitCode::KilometrePerSecond),
+            "M63" => Ok(UnitCode::InchPerMinute),
+            "M64" => Ok(UnitCode::YardPerSecond),
+            "M65" => Ok(UnitCode::YardPerMinute),
+            "M66" => Ok(UnitCode::YardPerHour),
+            "M67" => Ok(UnitCode::AcreFootBasedOnUSSurveyFoot),
+            "M68" => Ok(UnitCode::Cord128Ft3),
+            "M69" => Ok(UnitCode::CubicMileUkStatute),
+            "M7" => Ok(UnitCode::MicroInch),
+            "M70" => Ok(UnitCode::TonRegister),
+            "M71" => Ok(UnitCode::CubicMetrePerPascal),
+            "M72" => Ok(UnitCode::Bel),
+            "M73" => Ok(UnitCode::KilogramPerCubicMetrePascal),
+            "M74" => Ok(UnitCode::KilogramPerPascal),
+            "M75" => Ok(UnitCode::KilopoundForce),
+            "M76" => Ok(UnitCode::Poundal),
+            "M77" => Ok(UnitCode::KilogramMetrePerSecondSquared),
+            "M78" => Ok(UnitCode::Pond),
+            "M79" => Ok(UnitCode::SquareFootPerHour),
+            "M80" => Ok(UnitCode::StokesPerPascal),
+            "M81" => Ok(UnitCode::SquareCentimetrePerSecond),
+            "M82" => Ok(UnitCode::SquareMetrePerSecondPascal),
+            "M83" => Ok(UnitCode::DenierTextile),
+            "M84" => Ok(UnitCode::PoundPerYard),
+            "M85" => Ok(UnitCode::TonAssay),
+            "M86" => Ok(UnitCode::Pfund),
+            "M87" => Ok(UnitCode::KilogramPerSecondPascal),
+            "M88" => Ok(UnitCode::TonnePerMonth),
+            "M89" => Ok(UnitCode::TonnePerYear),
+            "M9" => Ok(UnitCode::MillionBtuPer1000CubicFoot),
+            "M90" => Ok(UnitCode::KilopoundPerHour),
+            "M91" => Ok(UnitCode::PoundPerDay),
+            "M92" => Ok(UnitCode::PoundForcePerFootTextile),
+            "M93" => Ok(UnitCode::NewtonMetrePerRadian),
+            "M94" => Ok(UnitCode::KilogramMetre),
+            "M95" => Ok(UnitCode::PoundalFoot),
+            "M96" => Ok(UnitCode::PoundalInch),
+            "M97" => Ok(UnitCode::DyneMetre),
+            "M98" => Ok(UnitCode::KilogramCentimetrePerSecond),
+            "M99" => Ok(UnitCode::GramCentimetrePerSecond),
+            "MAH" => Ok(UnitCode::MegavoltAmpereReactiveHour),
+            "MAL" => Ok(UnitCode::Megalitre),
+            "MAM" => Ok(UnitCode::Megametre),
+            "MAR" => Ok(UnitCode::Megavar),
+            "MAW" => Ok(UnitCode::Megawatt),
+            "MBE" => Ok(UnitCode::ThousandStandardBrickEquivalent),
+            "MBF" => Ok(UnitCode::ThousandBoardFoot),
+            "MBR" => Ok(UnitCode::Millibar),
+            "MC" => Ok(UnitCode::Microgram),
+            "MCU" => Ok(UnitCode::Millicurie),
+            "MD" => Ok(UnitCode::AirDryMetricTon),
+            "MGM" => Ok(UnitCode::Milligram),
+            "MHZ" => Ok(UnitCode::Megahertz),
+            "MIK" => Ok(UnitCode::SquareMileStatuteMile),
+            "MIL" => Ok(UnitCode::Thousand),
+            "MIN" => Ok(UnitCode::MinuteUnitOfTime),
+            "MIO" => Ok(UnitCode::Million),
+            "MIU" => Ok(UnitCode::MillionInternationalUnit),
+            "MKD" => Ok(UnitCode::SquareMetreDay),
+            "MKM" => Ok(UnitCode::SquareMetreMonth),
+            "MKW" => Ok(UnitCode::SquareMetreWeek),
+            "MLD" => Ok(UnitCode::Milliard),
+            "MLT" => Ok(UnitCode::Millilitre),
+            "MMK" => Ok(UnitCode::SquareMillimetre),
+            "MMQ" => Ok(UnitCode::CubicMillimetre),
+            "MMT" => Ok(UnitCode::Millimetre),
+            "MND" => Ok(UnitCode::KilogramDryWeight),
+            "MNJ" => Ok(UnitCode::MegaJoulePerNormalisedCubicMetre),
+            "MON" => Ok(UnitCode::Month),
+            "MPA" => Ok(UnitCode::Megapascal),
+            "MQD" => Ok(UnitCode::CubicMetreDay),
+            "MQH" => Ok(UnitCode::CubicMetrePerHour),
+            "MQM" => Ok(UnitCode::CubicMetreMonth),
+            "MQS" => Ok(UnitCode::CubicMetrePerSecond),
+            "MQW" => Ok(UnitCode::CubicMetreWeek),
+            "MRD" => Ok(UnitCode::MetreDay),
+            "MRM" => Ok(UnitCode::MetreMonth),
+            "MRW" => Ok(UnitCode::MetreWeek),
+            "MSK" => Ok(UnitCode::MetrePerSecondSquared),
+            "MTK" => Ok(UnitCode::SquareMetre),
+            "MTQ" => Ok(UnitCode::CubicMetre),
+            "MTR" => Ok(UnitCode::Metre),
+            "MTS" => Ok(UnitCode::MetrePerSecond),
+            "MTZ" => Ok(UnitCode::Millihertz),
+            "MVA" => Ok(UnitCode::MegavoltAmpere),
+            "MWH" => Ok(UnitCode::MegawattHour1000KwH),
+            "N1" => Ok(UnitCode::PenCalorie),
+            "N10" => Ok(UnitCode::PoundFootPerSecond),
+            "N11" => Ok(UnitCode::PoundInchPerSecond),
+            "N12" => Ok(UnitCode::Pferdestaerke),
+            "N13" => Ok(UnitCode::CentimetreOfMercury0Degc),
+            "N14" => Ok(UnitCode::CentimetreOfWater4Degc),
+            "N15" => Ok(UnitCode::FootOfWater392Degf),
+            "N16" => Ok(UnitCode::InchOfMercury32Degf),
+            "N17" => Ok(UnitCode::InchOfMercury60Degf),
+            "N18" => Ok(UnitCode::InchOfWater392Degf),
+            "N19" => Ok(UnitCode::InchOfWater60Degf),
+            "N20" => Ok(UnitCode::KipPerSquareInch),
+            "N21" => Ok(UnitCode::PoundalPerSquareFoot),
+            "N22" => Ok(UnitCode::OunceAvoirdupoisPerSquareInch),
+            "N23" => Ok(UnitCode::ConventionalMetreOfWater),
+            "N24" => Ok(UnitCode::GramPerSquareMillimetre),
+            "N25" => Ok(UnitCode::PoundPerSquareYard),
+            "N26" => Ok(UnitCode::PoundalPerSquareInch),
+            "N27" => Ok(UnitCode::FootToTheFourthPower),
+            "N28" => Ok(UnitCode::CubicDecimetrePerKilogram),
+            "N29" => Ok(UnitCode::CubicFootPerPound),
+            "N3" => Ok(UnitCode::PrintPoint),
+            "N30" => Ok(UnitCode::CubicInchPerPound),
+            "N31" => Ok(UnitCode::KilonewtonPerMetre),
+            "N32" => Ok(UnitCode::PoundalPerInch),
+            "N33" => Ok(UnitCode::PoundForcePerYard),
+            "N34" => Ok(UnitCode::PoundalSecondPerSquareFoot),
+            "N35" => Ok(UnitCode::PoisePerPascal),
+            "N36" => Ok(UnitCode::NewtonSecondPerSquareMetre),
+            "N37" => Ok(UnitCode::KilogramPerMetreSecond),
+            "N38" => Ok(UnitCode::KilogramPerMetreMinute),
+            "N39" => Ok(UnitCode::KilogramPerMetreDay),
+            "N40" => Ok(UnitCode::KilogramPerMetreHour),
+            "N41" => Ok(UnitCode::GramPerCentimetreSecond),
+            "N42" => Ok(UnitCode::PoundalSecondPerSquareInch),
+            "N43" => Ok(UnitCode::PoundPerFootMinute),
+            "N44" => Ok(UnitCode::PoundPerFootDay),
+            "N45" => Ok(UnitCode::CubicMetrePerSecondPascal),
+            "N46" => Ok(UnitCode::FootPoundal),
+            "N47" => Ok(UnitCode::InchPoundal),
+            "N48" => Ok(UnitCode::WattPerSquareCentimetre),
+            "N49" => Ok(UnitCode::WattPerSquareInch),
+            "N50" => Ok(UnitCode::BritishThermalUnitInternationalTablePerSquareFootHour),
+            "N51" => Ok(UnitCode::BritishThermalUnitThermochemicalPerSquareFootHour),
+            "N52" => Ok(UnitCode::BritishThermalUnitThermochemicalPerSquareFootMinute),
+            "N53" => Ok(UnitCode::BritishThermalUnitInternationalTablePerSquareFootSecond),
+            "N54" => Ok(UnitCode::BritishThermalUnitThermochemicalPerSquareFootSecond),
+            "N55" => Ok(UnitCode::BritishThermalUnitInternationalTablePerSquareInchSecond),
+            "N56" => Ok(UnitCode::CalorieThermochemicalPerSquareCentimetreMinute),
+            "N57" => Ok(UnitCode::CalorieThermochemicalPerSquareCentimetreSecond),
+            "N58" => Ok(UnitCode::BritishThermalUnitInternationalTablePerCubicFoot),
+            "N59" => Ok(UnitCode::BritishThermalUnitThermochemicalPerCubicFoot),
+            "N60" => Ok(UnitCode::BritishThermalUnitInternationalTablePerDegreeFahrenheit),
+            "N61" => Ok(UnitCode::BritishThermalUnitThermochemicalPerDegreeFahrenheit),
+            "N62" => Ok(UnitCode::BritishThermalUnitInternationalTablePerDegreeRankine),
+            "N63" => Ok(UnitCode::BritishThermalUnitThermochemicalPerDegreeRankine),
+            "N64" => Ok(UnitCode::BritishThermalUnitThermochemicalPerPoundDegreeRankine),
+            "N65" => Ok(UnitCode::KilocalorieInternationalTablePerGramKelvin),
+            "N66" => Ok(UnitCode::BritishThermalUnit39Degf),
+            "N67" => Ok(UnitCode::BritishThermalUnit59Degf),
+            "N68" => Ok(UnitCode::BritishThermalUnit60Degf),
+            "N69" => Ok(UnitCode::Calorie20Degc),
+            "N70" => Ok(UnitCode::Quad1015Btuit),
+            "N71" => Ok(UnitCode::ThermEc),
+            "N72" => Ok(UnitCode::ThermUS),
+            "N73" => Ok(UnitCode::BritishThermalUnitThermochemicalPerPound),
+            "N74" => Ok(UnitCode::BritishThermalUnitInternationalTablePerHourSquareFootDegreeFahrenheit),
+            "N75" => Ok(UnitCode::BritishThermalUnitThermochemicalPerHourSquareFootDegreeFahrenheit),
+            "N76" => Ok(UnitCode::BritishThermalUnitInternationalTablePerSecondSquareFootDegreeFahrenheit),
+            "N77" => Ok(UnitCode::BritishThermalUnitThermochemicalPerSecondSquareFootDegreeFahrenheit),
+            "N78" => Ok(UnitCode::KilowattPerSquareMetreKelvin),
+            "N79" => Ok(UnitCode::KelvinPerPascal),
+            "N80" => Ok(UnitCode::WattPerMetreDegreeCelsius),
+            "N81" => Ok(UnitCode::KilowattPerMetreKelvin),
+            "N82" => Ok(UnitCode::KilowattPerMetreDegreeCelsius),
+            "N83" => Ok(UnitCode::MetrePerDegreeCelciusMetre),
+            "N84" => Ok(UnitCode::DegreeFahrenheitHourPerBritishThermalUnitInternationalTable),
+            "N85" => Ok(UnitCode::DegreeFahrenheitHourPerBritishThermalUnitThermochemical),
+            "N86" => Ok(UnitCode::DegreeFahrenheitSecondPerBritishThermalUnitInternationalTable),
+            "N87" => Ok(UnitCode::DegreeFahrenheitSecondPerBritishThermalUnitThermochemical),
+            "N88" => Ok(UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitInternationalTableInch),
+            "N89" => Ok(UnitCode::DegreeFahrenheitHourSquareFootPerBritishThermalUnitThermochemicalInch),
+            "N90" => Ok(UnitCode::Kilofarad),
+            "N91" => Ok(UnitCode::ReciprocalJoule),
+            "N92" => Ok(UnitCode::Picosiemens),
+            "N93" => Ok(UnitCode::AmperePerPascal),
+            "N94" => Ok(UnitCode::Franklin),
+            "N95" => Ok(UnitCode::AmpereMinute),
+            "N96" => Ok(UnitCode::Biot),
+            "N97" => Ok(UnitCode::Gilbert),
+            "N98" => Ok(UnitCode::VoltPerPascal),
+            "N99" => Ok(UnitCode::Picovolt),
+            "NA" => Ok(UnitCode::MilligramPerKilogram),
+            "NAR" => Ok(UnitCode::NumberOfArticles),
+            "NCL" => Ok(UnitCode::NumberOfCells),
+            "NEW" => Ok(UnitCode::Newton),
+            "NF" => Ok(UnitCode::Message),
+            "NIL" => Ok(UnitCode::Nil),
+            "NIU" => Ok(UnitCode::NumberOfInternationalUnits),
+            "NL" => Ok(UnitCode::Load),
+            "NM3" => Ok(UnitCode::NormalisedCubicMetre),
+            "NMI" => Ok(UnitCode::NauticalMile),
+            "NMP" => Ok(UnitCode::NumberOfPacks),
+            "NPT" => Ok(UnitCode::NumberOfParts),
+            "NT" => Ok(UnitCode::NetTon),
+            "NTU" => Ok(UnitCode::NephelometricTurbidityUnit),
+            "NU" => Ok(UnitCode::NewtonMetre),
+            "NX" => Ok(UnitCode::PartPerThousand),
+            "OA" => Ok(UnitCode::Panel),
+            "ODE" => Ok(UnitCode::OzoneDepletionEquivalent),
+            "ODG" => Ok(UnitCode::OdsGrams),
+            "ODK" => Ok(UnitCode::OdsKilograms),
+            "ODM" => Ok(UnitCode::OdsMilligrams),
+            "OHM" => Ok(UnitCode::Ohm),
+            "ON" => Ok(UnitCode::OuncePerSquareYard),
+            "ONZ" => Ok(UnitCode::OunceAvoirdupois),
+            "OPM" => Ok(UnitCode::OscillationsPerMinute),
+            "OT" => Ok(UnitCode::OvertimeHour),
+            "OZA" => Ok(UnitCode::FluidOunceUs),
+            "OZI" => Ok(UnitCode::FluidOunceUk),
+            "P1" => Ok(UnitCode::Percent),
+            "P10" => Ok(UnitCode::CoulombPerMetre),
+            "P11" => Ok(UnitCode::Kiloweber),
+            "P12" => Ok(UnitCode::Gamma),
+            "P13" => Ok(UnitCode::Kilotesla),
+            "P14" => Ok(UnitCode::JoulePerSecond),
+            "P15" => Ok(UnitCode::JoulePerMinute),
+            "P16" => Ok(UnitCode::JoulePerHour),
+            "P17" => Ok(UnitCode::JoulePerDay),
+            "P18" => Ok(UnitCode::KilojoulePerSecond),
+            "P19" => Ok(UnitCode::KilojoulePerMinute),
+            "P2" => Ok(UnitCode::PoundPerFoot),
+            "P20" => Ok(UnitCode::KilojoulePerHour),
+            "P21" => Ok(UnitCode::KilojoulePerDay),
+            "P22" => Ok(UnitCode::Nanoohm),
+            "P23" => Ok(UnitCode::OhmCircularMilPerFoot),
+            "P24" => Ok(UnitCode::Kilohenry),
+            "P25" => Ok(UnitCode::LumenPerSquareFoot),
+            "P26" => Ok(UnitCode::Phot),
+            "P27" => Ok(UnitCode::Footcandle),
+            "P28" => Ok(UnitCode::CandelaPerSquareInch),
+            "P29" => Ok(UnitCode::Footlambert),
+            "P30" => Ok(UnitCode::Lambert),
+            "P31" => Ok(UnitCode::Stilb),
+            "P32" => Ok(UnitCode::CandelaPerSquareFoot),
+            "P33" => Ok(UnitCode::Kilocandela),
+            "P34" => Ok(UnitCode::Millicandela),
+            "P35" => Ok(UnitCode::HefnerKerze),
+            "P36" => Ok(UnitCode::InternationalCandle),
+            "P37" => Ok(UnitCode::BritishThermalUnitInternationalTablePerSquareFoot),
+            "P38" => Ok(UnitCode::BritishThermalUnitThermochemicalPerSquareFoot),
+            "P39" => Ok(UnitCode::CalorieThermochemicalPerSquareCentimetre),
+            "P40" => Ok(UnitCode::Langley),
+            "P41" => Ok(UnitCode::DecadeLogarithmic),
+            "P42" => Ok(UnitCode::PascalSquaredSecond),
+            "P43" => Ok(UnitCode::BelPerMetre),
+            "P44" => Ok(UnitCode::PoundMole),
+            "P45" => Ok(UnitCode::PoundMolePerSecond),
+            "P46" => Ok(UnitCode::PoundMolePerMinute),
+            "P47" => Ok(UnitCode::KilomolePerKilogram),
+            "P48" => Ok(UnitCode::PoundMolePerPound),
+            "P49" => Ok(UnitCode::NewtonSquareMetrePerAmpere),
+            "P5" => Ok(UnitCode::FivePack),
+            "P50" => Ok(UnitCode::WeberMetre),
+            "P51" => Ok(UnitCode::MolPerKilogramPascal),
+            "P52" => Ok(UnitCode::MolPerCubicMetrePascal),
+            "P53" => Ok(UnitCode::UnitPole),
+            "P54" => Ok(UnitCode::MilligrayPerSecond),
+            "P55" => Ok(UnitCode::MicrograyPerSecond),
+            "P56" => Ok(UnitCode::NanograyPerSecond),
+            "P57" => Ok(UnitCode::GrayPerMinute),
+            "P58" => Ok(UnitCode::MilligrayPerMinute),
+            "P59" => Ok(UnitCode::MicrograyPerMinute),
+            "P60" => Ok(UnitCode::NanograyPerMinute),
+            "P61" => Ok(UnitCode::GrayPerHour),
+            "P62" => Ok(UnitCode::MilligrayPerHour),
+            "P63" => Ok(UnitCode::MicrograyPerHour),
+            "P64" => Ok(UnitCode::NanograyPerHour),
+            "P65" => Ok(UnitCode::SievertPerSecond),
+            "P66" => Ok(UnitCode::MillisievertPerSecond),
+            "P67" => Ok(UnitCode::MicrosievertPerSecond),
+            "P68" => Ok(UnitCode::NanosievertPerSecond),
+            "P69" => Ok(UnitCode::RemPerSecond),
+            "P70" => Ok(UnitCode::SievertPerHour),
+            "P71" => Ok(UnitCode::MillisievertPerHour),
+            "P72" => Ok(UnitCode::MicrosievertPerHour),
+            "P73" => Ok(UnitCode::NanosievertPerHour),
+            "P74" => Ok(UnitCode::SievertPerMinute),
+            "P75" => Ok(UnitCode::MillisievertPerMinute),
+            "P76" => Ok(UnitCode::MicrosievertPerMinute),
+            "P77" => Ok(UnitCode::NanosievertPerMinute),
+            "P78" => Ok(UnitCode::ReciprocalSquareInch),
+            "P79" => Ok(UnitCode::PascalSquareMetrePerKilogram),
+            "P80" => Ok(UnitCode::MillipascalPerMetre),
+            "P81" => Ok(UnitCode::KilopascalPerMetre),
+            "P82" => Ok(UnitCode::HectopascalPerMetre),
+            "P83" => Ok(UnitCode::StandardAtmospherePerMetre),
+            "P84" => Ok(UnitCode::TechnicalAtmospherePerMetre),
+            "P85" => Ok(UnitCode::TorrPerMetre),
+            "P86" => Ok(UnitCode::PsiPerInch),
+            "P87" => Ok(UnitCode::CubicMetrePerSecondSquareMetre),
+            "P88" => Ok(UnitCode::Rhe),
+            "P89" => Ok(UnitCode::PoundForceFootPerInch),
+            "P90" => Ok(UnitCode::PoundForceInchPerInch),
+            "P91" => Ok(UnitCode::Perm0Degc),
+            "P92" => Ok(UnitCode::Perm23Degc),
+            "P93" => Ok(UnitCode::BytePerSecond),
+            "P94" => Ok(UnitCode::KilobytePerSecond),
+            "P95" => Ok(UnitCode::MegabytePerSecond),
+            "P96" => Ok(UnitCode::ReciprocalVolt),
+            "P97" => Ok(UnitCode::ReciprocalRadian),
+            "P98" => Ok(UnitCode::PascalToThePowerSumOfStoichiometricNumbers),
+            "P99" => Ok(UnitCode::MolePerCubicMetreToThePowerSumOfStoichiometricNumbers),
+            "PAL" => Ok(UnitCode::Pascal),
+            "PD" => Ok(UnitCode::Pad),
+            "PFL" => Ok(UnitCode::ProofLitre),
+            "PGL" => Ok(UnitCode::ProofGallon),
+            "PI" => Ok(UnitCode::Pitch),
+            "PLA" => Ok(UnitCode::DegreePlato),
+            "PO" => Ok(UnitCode::PoundPerInchOfLength),
+            "PQ" => Ok(UnitCode::PagePerInch),
+            "PR" => Ok(UnitCode::Pair),
+            "PS" => Ok(UnitCode::PoundForcePerSquareInch),
+            "PTD" => Ok(UnitCode::DryPintUs),
+            "PTI" => Ok(UnitCode::PintUk),
+            "PTL" => Ok(UnitCode::LiquidPintUs),
+            "PTN" => Ok(UnitCode::Portion),
+            "Q10" => Ok(UnitCode::JoulePerTesla),
+            "Q11" => Ok(UnitCode::Erlang),
+            "Q12" => Ok(UnitCode::Octet),
+            "Q13" => Ok(UnitCode::OctetPerSecond),
+            "Q14" => Ok(UnitCode::Shannon),
+            "Q15" => Ok(UnitCode::Hartley),
+            "Q16" => Ok(UnitCode::NaturalUnitOfInformation),
+            "Q17" => Ok(UnitCode::ShannonPerSecond),
+            "Q18" => Ok(UnitCode::HartleyPerSecond),
+            "Q19" => Ok(UnitCode::NaturalUnitOfInformationPerSecond),
+            "Q20" => Ok(UnitCode::SecondPerKilogramm),
+            "Q21" => Ok(UnitCode::WattSquareMetre),
+            "Q22" => Ok(UnitCode::SecondPerRadianCubicMetre),
+            "Q23" => Ok(UnitCode::WeberToThePowerMinusOne),
+            "Q24" => Ok(UnitCode::ReciprocalInch),
+            "Q25" => Ok(UnitCode::Dioptre),
+            "Q26" => Ok(UnitCode::OnePerOne),
+            "Q27" => Ok(UnitCode::NewtonMetrePerMetre),
+            "Q28" => Ok(UnitCode::KilogramPerSquareMetrePascalSecond),
+            "Q29" => Ok(UnitCode::MicrogramPerHectogram),
+            "Q3" => Ok(UnitCode::Meal),
+            "Q30" => Ok(UnitCode::PhPotentialOfHydrogen),
+            "Q31" => Ok(UnitCode::KilojoulePerGram),
+            "Q32" => Ok(UnitCode::Femtolitre),
+            "Q33" => Ok(UnitCode::Picolitre),
+            "Q34" => Ok(UnitCode::Nanolitre),
+            "Q35" => Ok(UnitCode::MegawattsPerMinute),
+            "Q36" => Ok(UnitCode::SquareMetrePerCubicMetre),
+            "Q37" => Ok(UnitCode::StandardCubicMetrePerDay),
+            "Q38" => Ok(UnitCode::StandardCubicMetrePerHour),
+            "Q39" => Ok(UnitCode::NormalizedCubicMetrePerDay),
+            "Q40" => Ok(UnitCode::NormalizedCubicMetrePerHour),
+            "Q41" => Ok(UnitCode::JoulePerNormalisedCubicMetre),
+            "Q42" => Ok(UnitCode::JoulePerStandardCubicMetre),
+            "QA" => Ok(UnitCode::PageFacsimile),
+            "QAN" => Ok(UnitCode::QuarterOfAYear),
+            "QB" => Ok(UnitCode::PageHardcopy),
+            "QR" => Ok(UnitCode::Quire),
+            "QTD" => Ok(UnitCode::DryQuartUs),
+            "QTI" => Ok(UnitCode::QuartUk),
+            "QTL" => Ok(UnitCode::LiquidQuartUs),
+            "QTR" => Ok(UnitCode::QuarterUk),
+            "R1" => Ok(UnitCode::Pica),
+            "R9" => Ok(UnitCode::ThousandCubicMetre),
+            "RH" => Ok(UnitCode::RunningOrOperatingHour),
+            "RM" => Ok(UnitCode::Ream),
+            "ROM" => Ok(UnitCode::Room),
+            "RP" => Ok(UnitCode::PoundPerReam),
+            "RPM" => Ok(UnitCode::RevolutionsPerMinute),
+            "RPS" => Ok(UnitCode::RevolutionsPerSecond),
+            "RT" => Ok(UnitCode::RevenueTonMile),
+            "S3" => Ok(UnitCode::SquareFootPerSecond),
+            "S4" => Ok(UnitCode::SquareMetrePerSecond),
+            "SAN" => Ok(UnitCode::HalfYear6Months),
+            "SCO" => Ok(UnitCode::Score),
+            "SCR" => Ok(UnitCode::Scruple),
+            "SEC" => Ok(UnitCode::SecondUnitOfTime),
+            "SET" => Ok(UnitCode::Set),
+            "SG" => Ok(UnitCode::Segment),
+            "SIE" => Ok(UnitCode::Siemens),
+            "SM3" => Ok(UnitCode::StandardCubicMetre),
+            "SMI" => Ok(UnitCode::MileStatuteMile),
+            "SQ" => Ok(UnitCode::Square),
+            "SQR" => Ok(UnitCode::SquareRoofing),
+            "SR" => Ok(UnitCode::Strip),
+            "STC" => Ok(UnitCode::Stick),
+            "STI" => Ok(UnitCode::StoneUk),
+            "STK" => Ok(UnitCode::StickCigarette),
+            "STL" => Ok(UnitCode::StandardLitre),
+            "STN" => Ok(UnitCode::TonUsOrShortTonUkPerUs),
+            "STW" => Ok(UnitCode::Straw),
+            "SW" => Ok(UnitCode::Skein),
+            "SX" => Ok(UnitCode::Shipment),
+            "SYR" => Ok(UnitCode::Syringe),
+            "T0" => Ok(UnitCode::TelecommunicationLineInService),
+            "T3" => Ok(UnitCode::ThousandPiece),
+            "TAH" => Ok(UnitCode::KiloampereHourThousandAmpereHour),
+            "TAN" => Ok(UnitCode::TotalAcidNumber),
+            "TI" => Ok(UnitCode::ThousandSquareInch),
+            "TIC" => Ok(UnitCode::MetricTonIncludingContainer),
+            "TIP" => Ok(UnitCode::MetricTonIncludingInnerPackaging),
+            "TKM" => Ok(UnitCode::TonneKilometre),
+            "TMS" => Ok(UnitCode::KilogramOfImportedMeatLessOffal),
+            "TNE" => Ok(UnitCode::TonneMetricTon),
+            "TP" => Ok(UnitCode::TenPack),
+            "TPI" => Ok(UnitCode::TeethPerInch),
+            "TPR" => Ok(UnitCode::TenPair),
+            "TQD" => Ok(UnitCode::ThousandCubicMetrePerDay),
+            "TRL" => Ok(UnitCode::TrillionEur),
+            "TST" => Ok(UnitCode::TenSet),
+            "TTS" => Ok(UnitCode::TenThousandSticks),
+            "U1" => Ok(UnitCode::Treatment),
+            "U2" => Ok(UnitCode::Tablet),
+            "UB" => Ok(UnitCode::TelecommunicationLineInServiceAverage),
+            "UC" => Ok(UnitCode::TelecommunicationPort),
+            "VA" => Ok(UnitCode::VoltAmperePerKilogram),
+            "VLT" => Ok(UnitCode::Volt),
+            "VP" => Ok(UnitCode::PercentVolume),
+            "W2" => Ok(UnitCode::WetKilo),
+            "WA" => Ok(UnitCode::WattPerKilogram),
+            "WB" => Ok(UnitCode::WetPound),
+            "WCD" => Ok(UnitCode::Cord),
+            "WE" => Ok(UnitCode::WetTon),
+            "WEB" => Ok(UnitCode::Weber),
+            "WEE" => Ok(UnitCode::Week),
+            "WG" => Ok(UnitCode::WineGallon),
+            "WHR" => Ok(UnitCode::WattHour),
+            "WM" => Ok(UnitCode::WorkingMonth),
+            "WSD" => Ok(UnitCode::Standard),
+            "WTT" => Ok(UnitCode::Watt),
+            "X1" => Ok(UnitCode::GuntersChain),
+            "YDK" => Ok(UnitCode::SquareYard),
+            "YDQ" => Ok(UnitCode::CubicYard),
+            "YRD" => Ok(UnitCode::Yard),
+            "Z11" => Ok(UnitCode::HangingContainer),
+            "Z9" => Ok(UnitCode::Nanomole),
+            "ZP" => Ok(UnitCode::Page),
+            "ZZ" => Ok(UnitCode::MutuallyDefined),
+            "X1A" => Ok(UnitCode::DrumSteel),
+            "X1B" => Ok(UnitCode::DrumAluminium),
+            "X1D" => Ok(UnitCode::DrumPlywood),
+            "X1F" => Ok(UnitCode::ContainerFlexible),
+            "X1G" => Ok(UnitCode::DrumFibre),
+            "X1W" => Ok(UnitCode::DrumWooden),
+            "X2C" => Ok(UnitCode::BarrelWooden),
+            "X3A" => Ok(UnitCode::JerricanSteel),
+            "X3H" => Ok(UnitCode::JerricanPlastic),
+            "X43" => Ok(UnitCode::BagSuperBulk),
+            "X44" => Ok(UnitCode::BagPolybag),
+            "X4A" => Ok(UnitCode::BoxSteel),
+            "X4B" => Ok(UnitCode::BoxAluminium),
+            "X4C" => Ok(UnitCode::BoxNaturalWood),
+            "X4D" => Ok(UnitCode::BoxPlywood),
+            "X4F" => Ok(UnitCode::BoxReconstitutedWood),
+            "X4G" => Ok(UnitCode::BoxFibreboard),
+            "X4H" => Ok(UnitCode::BoxPlastic),
+            "X5H" => Ok(UnitCode::BagWovenPlastic),
+            "X5L" => Ok(UnitCode::BagTextile),
+            "X5M" => Ok(UnitCode::BagPaper),
+            "X6H" => Ok(UnitCode::CompositePackagingPlasticReceptacle),
+            "X6P" => Ok(UnitCode::CompositePackagingGlassReceptacle),
+            "X7A" => Ok(UnitCode::CaseCar),
+            "X7B" => Ok(UnitCode::CaseWooden),
+            "X8A" => Ok(UnitCode::PalletWooden),
+            "X8B" => Ok(UnitCode::CrateWooden),
+            "X8C" => Ok(UnitCode::BundleWooden),
+            "XAA" => Ok(UnitCode::IntermediateBulkContainerRigidPlastic),
+            "XAB" => Ok(UnitCode::ReceptacleFibre),
+            "XAC" => Ok(UnitCode::ReceptaclePaper),
+            "XAD" => Ok(UnitCode::ReceptacleWooden),
+            "XAE" => Ok(UnitCode::Aerosol),
+            "XAF" => Ok(UnitCode::PalletModularCollars80cms60cms),
+            "XAG" => Ok(UnitCode::PalletShrinkwrapped),
+            "XAH" => Ok(UnitCode::Pallet100cms110cms),
+            "XAI" => Ok(UnitCode::Clamshell),
+            "XAJ" => Ok(UnitCode::Cone),
+            "XAL" => Ok(UnitCode::PackageBall),
+            "XAM" => Ok(UnitCode::AmpouleNonProtected),
+            "XAP" => Ok(UnitCode::AmpouleProtected),
+            "XAT" => Ok(UnitCode::Atomizer),
+            "XAV" => Ok(UnitCode::Capsule),
+            "XB4" => Ok(UnitCode::Belt),
+            "XBA" => Ok(UnitCode::Barrel),
+            "XBB" => Ok(UnitCode::Bobbin),
+            "XBC" => Ok(UnitCode::BottlecratePerBottlerack),
+            "XBD" => Ok(UnitCode::Board),
+            "XBE" => Ok(UnitCode::Bundle),
+            "XBF" => Ok(UnitCode::BalloonNonProtected),
+            "XBG" => Ok(UnitCode::Bag),
+            "XBH" => Ok(UnitCode::Bunch),
+            "XBI" => Ok(UnitCode::Bin),
+            "XBJ" => Ok(UnitCode::Bucket),
+            "XBK" => Ok(UnitCode::Basket),
+            "XBL" => Ok(UnitCode::BaleCompressed),
+            "XBM" => Ok(UnitCode::Basin),
+            "XBN" => Ok(UnitCode::BaleNonCompressed),
+            "XBO" => Ok(UnitCode::BottleNonProtectedCylindrical),
+            "XBP" => Ok(UnitCode::BalloonProtected),
+            "XBQ" => Ok(UnitCode::BottleProtectedCylindrical),
+            "XBR" => Ok(UnitCode::Bar),
+            "XBS" => Ok(UnitCode::BottleNonProtectedBulbous),
+            "XBT" => Ok(UnitCode::Bolt),
+            "XBU" => Ok(UnitCode::Butt),
+            "XBV" => Ok(UnitCode::BottleProtectedBulbous),
+            "XBW" => Ok(UnitCode::BoxForLiquids),
+            "XBX" => Ok(UnitCode::Box),
+            "XBY" => Ok(UnitCode::BoardInBundlePerBunchPerTruss),
+            "XBZ" => Ok(UnitCode::BarsInBundlePerBunchPerTruss),
+            "XCA" => Ok(UnitCode::CanRectangular),
+            "XCB" => Ok(UnitCode::CrateBeer),
+            "XCC" => Ok(UnitCode::Churn),
+            "XCD" => Ok(UnitCode::CanWithHandleAndSpout),
+            "XCE" => Ok(UnitCode::Creel),
+            "XCF" => Ok(UnitCode::Coffer),
+            "XCG" => Ok(UnitCode::Cage),
+            "XCH" => Ok(UnitCode::Chest),
+            "XCI" => Ok(UnitCode::Canister),
+            "XCJ" => Ok(UnitCode::Coffin),
+            "XCK" => Ok(UnitCode::Cask),
+            "XCL" => Ok(UnitCode::Coil),
+            "XCM" => Ok(UnitCode::PackageCard),
+            "XCN" => Ok(UnitCode::ContainerNotOtherwiseSpecifiedAsTransportEquipment),
+            "XCO" => Ok(UnitCode::CarboyNonProtected),
+            "XCP" => Ok(UnitCode::CarboyProtected),
+            "XCQ" => Ok(UnitCode::Cartridge),
+            "XCR" => Ok(UnitCode::Crate),
+            "XCS" => Ok(UnitCode::Case),
+            "XCT" => Ok(UnitCode::Carton),
+            "XCU" => Ok(UnitCode::Cup),
+            "XCV" => Ok(UnitCode::Cover),
+            "XCW" => Ok(UnitCode::CageRoll),
+            "XCX" => Ok(UnitCode::CanCylindrical),
+            "XCY" => Ok(UnitCode::Cylinder),
+            "XCZ" => Ok(UnitCode::Canvas),
+            "XDA" => Ok(UnitCode::CrateMultipleLayerPlastic),
+            "XDB" => Ok(UnitCode::CrateMultipleLayerWooden),
+            "XDC" => Ok(UnitCode::CrateMultipleLayerCardboard),
+            "XDG" => Ok(UnitCode::CageCommonwealthHandlingEquipmentPoolChep),
+            "XDH" => Ok(UnitCode::BoxCommonwealthHandlingEquipmentPoolChepEurobox),
+            "XDI" => Ok(UnitCode::DrumIron),
+            "XDJ" => Ok(UnitCode::DemijohnNonProtected),
+            "XDK" => Ok(UnitCode::CrateBulkCardboard),
+            "XDL" => Ok(UnitCode::CrateBulkPlastic),
+            "XDM" => Ok(UnitCode::CrateBulkWooden),
+            "XDN" => Ok(UnitCode::Dispenser),
+            "XDP" => Ok(UnitCode::DemijohnProtected),
+            "XDR" => Ok(UnitCode::Drum),
+            "XDS" => Ok(UnitCode::TrayOneLayerNoCoverPlastic),
+            "XDT" => Ok(UnitCode::TrayOneLayerNoCoverWooden),
+            "XDU" => Ok(UnitCode::TrayOneLayerNoCoverPolystyrene),
+            "XDV" => Ok(UnitCode::TrayOneLayerNoCoverCardboard),
+            "XDW" => Ok(UnitCode::TrayTwoLayersNoCoverPlasticTray),
+            "XDX" => Ok(UnitCode::TrayTwoLayersNoCoverWooden),
+            "XDY" => Ok(UnitCode::TrayTwoLayersNoCoverCardboard),
+            "XEC" => Ok(UnitCode::BagPlastic),
+            "XED" => Ok(UnitCode::CaseWithPalletBase),
+            "XEE" => Ok(UnitCode::CaseWithPalletBaseWooden),
+            "XEF" => Ok(UnitCode::CaseWithPalletBaseCardboard),
+            "XEG" => Ok(UnitCode::CaseWithPalletBasePlastic),
+            "XEH" => Ok(UnitCode::CaseWithPalletBaseMetal),
+            "XEI" => Ok(UnitCode::CaseIsothermic),
+            "XEN" => Ok(UnitCode::Envelope),
+            "XFB" => Ok(UnitCode::Flexibag),
+            "XFC" => Ok(UnitCode::CrateFruit),
+            "XFD" => Ok(UnitCode::CrateFramed),
+            "XFE" => Ok(UnitCode::Flexitank),
+            "XFI" => Ok(UnitCode::Firkin),
+            "XFL" => Ok(UnitCode::Flask),
+            "XFO" => Ok(UnitCode::Footlocker),
+            "XFP" => Ok(UnitCode::Filmpack),
+            "XFR" => Ok(UnitCode::Frame),
+            "XFT" => Ok(UnitCode::Foodtainer),
+            "XFW" => Ok(UnitCode::CartFlatbed),
+            "XFX" => Ok(UnitCode::BagFlexibleContainer),
+            "XGB" => Ok(UnitCode::BottleGas),
+            "XGI" => Ok(UnitCode::Girder),
+            "XGL" => Ok(UnitCode::ContainerGallon),
+            "XGR" => Ok(UnitCode::ReceptacleGlass),
+            "XGU" => Ok(UnitCode::TrayContainingHorizontallyStackedFlatItems),
+            "XGY" => Ok(UnitCode::BagGunny),
+            "XGZ" => Ok(UnitCode::GirdersInBundlePerBunchPerTruss),
+            "XHA" => Ok(UnitCode::BasketWithHandlePlastic),
+            "XHB" => Ok(UnitCode::BasketWithHandleWooden),
+            "XHC" => Ok(UnitCode::BasketWithHandleCardboard),
+            "XHG" => Ok(UnitCode::Hogshead),
+            "XHN" => Ok(UnitCode::Hanger),
+            "XHR" => Ok(UnitCode::Hamper),
+            "XIA" => Ok(UnitCode::PackageDisplayWooden),
+            "XIB" => Ok(UnitCode::PackageDisplayCardboard),
+            "XIC" => Ok(UnitCode::PackageDisplayPlastic),
+            "XID" => Ok(UnitCode::PackageDisplayMetal),
+            "XIE" => Ok(UnitCode::PackageShow),
+            "XIF" => Ok(UnitCode::PackageFlow),
+            "XIG" => Ok(UnitCode::PackagePaperWrapped),
+            "XIH" => Ok(UnitCode::DrumPlastic),
+            "XIK" => Ok(UnitCode::PackageCardboardWithBottleGripHoles),
+            "XIL" => Ok(UnitCode::TrayRigidLiddedStackableCenTs144822002),
+            "XIN" => Ok(UnitCode::Ingot),
+            "XIZ" => Ok(UnitCode::IngotsInBundlePerBunchPerTruss),
+            "XJB" => Ok(UnitCode::BagJumbo),
+            "XJC" => Ok(UnitCode::JerricanRectangular),
+            "XJG" => Ok(UnitCode::Jug),
+            "XJR" => Ok(UnitCode::Jar),
+            "XJT" => Ok(UnitCode::Jutebag),
+            "XJY" => Ok(UnitCode::JerricanCylindrical),
+            "XKG" => Ok(UnitCode::Keg),
+            "XKI" => Ok(UnitCode::PackageKit),
+            "XLE" => Ok(UnitCode::Luggage),
+            "XLG" => Ok(UnitCode::Log),
+            "XLT" => Ok(UnitCode::Lot),
+            "XLU" => Ok(UnitCode::Lug),
+            "XLV" => Ok(UnitCode::Liftvan),
+            "XLZ" => Ok(UnitCode::LogsInBundlePerBunchPerTruss),
+            "XMA" => Ok(UnitCode::CrateMetal),
+            "XMB" => Ok(UnitCode::BagMultiply),
+            "XMC" => Ok(UnitCode::CrateMilk),
+            "XME" => Ok(UnitCode::ContainerMetal),
+            "XMR" => Ok(UnitCode::ReceptacleMetal),
+            "XMS" => Ok(UnitCode::SackMultiWall),
+            "XMT" => Ok(UnitCode::Mat),
+            "XMW" => Ok(UnitCode::ReceptaclePlasticWrapped),
+            "XMX" => Ok(UnitCode::Matchbox),
+            "XNA" => Ok(UnitCode::NotAvailable),
+            "XNE" => Ok(UnitCode::UnpackedOrUnpackaged),
+            "XNF" => Ok(UnitCode::UnpackedOrUnpackagedSingleUnit),
+            "XNG" => Ok(UnitCode::UnpackedOrUnpackagedMultipleUnits),
+            "XNS" => Ok(UnitCode::Nest),
+            "XNT" => Ok(UnitCode::Net),
+            "XNU" => Ok(UnitCode::NetTubePlastic),
+            "XNV" => Ok(UnitCode::NetTubeTextile),
+            "XO1" => Ok(UnitCode::TwoSidedCageOnWheelsWithFixingStrap),
+            "XO2" => Ok(UnitCode::Trolley),
+            "XO3" => Ok(UnitCode::OnewayPalletIso01Per2EuroPallet),
+            "XO4" => Ok(UnitCode::OnewayPalletIso11Per1EuroPallet),
+            "XO5" => Ok(UnitCode::OnewayPalletIso22Per1EuroPallet),
+            "XO6" => Ok(UnitCode::PalletWithExceptionalDimensions),
+            "XO7" => Ok(UnitCode::WoodenPallet40CmX80Cm),
+            "XO8" => Ok(UnitCode::PlasticPalletSrs60CmX80Cm),
+            "XO9" => Ok(UnitCode::PlasticPalletSrs80CmX120Cm),
+            "XOA" => Ok(UnitCode::PalletChep40CmX60Cm),
+            "XOB" => Ok(UnitCode::PalletChep80CmX120Cm),
+            "XOC" => Ok(UnitCode::PalletChep100CmX120Cm),
+            "XOD" => Ok(UnitCode::PalletAs40681993),
+            "XOE" => Ok(UnitCode::PalletIsoT11),
+            "XOF" => Ok(UnitCode::PlatformUnspecifiedWeightOrDimension),
+            "XOG" => Ok(UnitCode::PalletIso01Per2EuroPallet),
+            "XOH" => Ok(UnitCode::PalletIso11Per1EuroPallet),
+            "XOI" => Ok(UnitCode::PalletIso22Per1EuroPallet),
+            "XOJ" => Ok(UnitCode::QuarterEuroPallet),
+            "XOK" => Ok(UnitCode::Block),
+            "XOL" => Ok(UnitCode::EighthEuroPallet),
+            "XOM" => Ok(UnitCode::SyntheticPalletIso1),
+            "XON" => Ok(UnitCode::SyntheticPalletIso2),
+            "XOP" => Ok(UnitCode::WholesalerPallet),
+            "XOQ" => Ok(UnitCode::Pallet80X100Cm),
+            "XOR" => Ok(UnitCode::Pallet60X100Cm),
+            "XOS" => Ok(UnitCode::OnewayPallet),
+            "XOT" => Ok(UnitCode::Octabin),
+            "XOU" => Ok(UnitCode::ContainerOuter),
+            "XOV" => Ok(UnitCode::ReturnablePallet),
+            "XOW" => Ok(UnitCode::LargeBagPalletSized),
+            "XOX" => Ok(UnitCode::AWheeledPalletWithRaisedRim81X67X135),
+            "XOY" => Ok(UnitCode::AWheeledPalletWithRaisedRim81X72X135),
+            "XOZ" => Ok(UnitCode::WheeledPalletWithRaisedRim81X60X16),
+            "XP1" => Ok(UnitCode::ChepPallet60CmX80Cm),
+            "XP2" => Ok(UnitCode::Pan),
+            "XP3" => Ok(UnitCode::LprPallet60CmX80Cm),
+            "XP4" => Ok(UnitCode::LprPallet80CmX120Cm),
+            "XPA" => Ok(UnitCode::Packet),
+            "XPB" => Ok(UnitCode::PalletBoxCombinedOpenEndedBoxAndPallet),
+            "XPC" => Ok(UnitCode::Parcel),
+            "XPD" => Ok(UnitCode::PalletModularCollars80cms100cms),
+            "XPE" => Ok(UnitCode::PalletModularCollars80cms120cms),
+            "XPF" => Ok(UnitCode::Pen),
+            "XPG" => Ok(UnitCode::Plate),
+            "XPH" => Ok(UnitCode::Pitcher),
+            "XPI" => Ok(UnitCode::Pipe),
+            "XPJ" => Ok(UnitCode::Punnet),
+            "XPK" => Ok(UnitCode::Package),
+            "XPL" => Ok(UnitCode::Pail),
+            "XPN" => Ok(UnitCode::Plank),
+            "XPO" => Ok(UnitCode::Pouch),
+            "XPP" => Ok(UnitCode::PackagePiece),
+            "XPR" => Ok(UnitCode::ReceptaclePlastic),
+            "XPT" => Ok(UnitCode::Pot),
+            "XPU" => Ok(UnitCode::Tray),
+            "XPV" => Ok(UnitCode::PipesInBundlePerBunchPerTruss),
+            "XPX" => Ok(UnitCode::Pallet),
+            "XPY" => Ok(UnitCode::PlatesInBundlePerBunchPerTruss),
+            "XPZ" => Ok(UnitCode::PlanksInBundlePerBunchPerTruss),
+            "XQA" => Ok(UnitCode::DrumSteelNonRemovableHead),
+            "XQB" => Ok(UnitCode::DrumSteelRemovableHead),
+            "XQC" => Ok(UnitCode::DrumAluminiumNonRemovableHead),
+            "XQD" => Ok(UnitCode::DrumAluminiumRemovableHead),
+            "XQF" => Ok(UnitCode::DrumPlasticNonRemovableHead),
+            "XQG" => Ok(UnitCode::DrumPlasticRemovableHead),
+            "XQH" => Ok(UnitCode::BarrelWoodenBungType),
+            "XQJ" => Ok(UnitCode::BarrelWoodenRemovableHead),
+            "XQK" => Ok(UnitCode::JerricanSteelNonRemovableHead),
+            "XQL" => Ok(UnitCode::JerricanSteelRemovableHead),
+            "XQM" => Ok(UnitCode::JerricanPlasticNonRemovableHead),
+            "XQN" => Ok(UnitCode::JerricanPlasticRemovableHead),
+            "XQP" => Ok(UnitCode::BoxWoodenNaturalWoodOrdinary),
+            "XQQ" => Ok(UnitCode::BoxWoodenNaturalWoodWithSiftProofWalls),
+            "XQR" => Ok(UnitCode::BoxPlasticExpanded),
+            "XQS" => Ok(UnitCode::BoxPlasticSolid),
+            "XRD" => Ok(UnitCode::Rod),
+            "XRG" => Ok(UnitCode::Ring),
+            "XRJ" => Ok(UnitCode::RackClothingHanger),
+            "XRK" => Ok(UnitCode::Rack),
+            "XRL" => Ok(UnitCode::Reel),
+            "XRO" => Ok(UnitCode::Roll),
+            "XRT" => Ok(UnitCode::Rednet),
+            "XRZ" => Ok(UnitCode::RodsInBundlePerBunchPerTruss),
+            "XSA" => Ok(UnitCode::Sack),
+            "XSB" => Ok(UnitCode::Slab),
+            "XSC" => Ok(UnitCode::CrateShallow),
+            "XSD" => Ok(UnitCode::Spindle),
+            "XSE" => Ok(UnitCode::SeaChest),
+            "XSH" => Ok(UnitCode::Sachet),
+            "XSI" => Ok(UnitCode::Skid),
+            "XSK" => Ok(UnitCode::CaseSkeleton),
+            "XSL" => Ok(UnitCode::Slipsheet),
+            "XSM" => Ok(UnitCode::Sheetmetal),
+            "XSO" => Ok(UnitCode::Spool),
+            "XSP" => Ok(UnitCode::SheetPlasticWrapping),
+            "XSS" => Ok(UnitCode::CaseSteel),
+            "XST" => Ok(UnitCode::Sheet),
+            "XSU" => Ok(UnitCode::Suitcase),
+            "XSV" => Ok(UnitCode::EnvelopeSteel),
+            "XSW" => Ok(UnitCode::Shrinkwrapped),
+            "XSX" => Ok(UnitCode::PackageSet),
+            "XSY" => Ok(UnitCode::Sleeve),
+            "XSZ" => Ok(UnitCode::SheetsInBundlePerBunchPerTruss),
+            "XT1" => Ok(UnitCode::PackageTablet),
+            "XTB" => Ok(UnitCode::Tub),
+            "XTC" => Ok(UnitCode::TeaChest),
+            "XTD" => Ok(UnitCode::CollapsibleTube),
+            "XTE" => Ok(UnitCode::PackageTyre),
+            "XTG" => Ok(UnitCode::TankContainerGeneric),
+            "XTI" => Ok(UnitCode::Tierce),
+            "XTK" => Ok(UnitCode::TankRectangular),
+            "XTL" => Ok(UnitCode::TubWithLid),
+            "XTN" => Ok(UnitCode::Tin),
+            "XTO" => Ok(UnitCode::Tun),
+            "XTR" => Ok(UnitCode::Trunk),
+            "XTS" => Ok(UnitCode::Truss),
+            "XTT" => Ok(UnitCode::BagTote),
+            "XTU" => Ok(UnitCode::Tube),
+            "XTV" => Ok(UnitCode::TubeWithNozzle),
+            "XTW" => Ok(UnitCode::PalletTriwall),
+            "XTY" => Ok(UnitCode::TankCylindrical),
+            "XTZ" => Ok(UnitCode::TubesInBundlePerBunchPerTruss),
+            "XUC" => Ok(UnitCode::Uncaged),
+            "XUN" => Ok(UnitCode::Unit),
+            "XVA" => Ok(UnitCode::Vat),
+            "XVG" => Ok(UnitCode::BulkGasAt1031MbarAnd15Degc),
+            "XVI" => Ok(UnitCode::Vial),
+            "XVK" => Ok(UnitCode::Vanpack),
+            "XVL" => Ok(UnitCode::BulkLiquid),
+            "XVN" => Ok(UnitCode::Vehicle),
+            "XVO" => Ok(UnitCode::BulkSolidLargeParticlesNodules),
+            "XVP" => Ok(UnitCode::VacuumPacked),
+            "XVQ" => Ok(UnitCode::BulkLiquefiedGasAtAbnormalTemperaturePerPressure),
+            "XVR" => Ok(UnitCode::BulkSolidGranularParticlesGrains),
+            "XVS" => Ok(UnitCode::BulkScrapMetal),
+            "XVY" => Ok(UnitCode::BulkSolidFineParticlesPowders),
+            "XWA" => Ok(UnitCode::IntermediateBulkContainer),
+            "XWB" => Ok(UnitCode::Wickerbottle),
+            "XWC" => Ok(UnitCode::IntermediateBulkContainerSteel),
+            "XWD" => Ok(UnitCode::IntermediateBulkContainerAluminium),
+            "XWF" => Ok(UnitCode::IntermediateBulkContainerMetal),
+            "XWG" => Ok(UnitCode::IntermediateBulkContainerSteelPressurisedOver10Kpa),
+            "XWH" => Ok(UnitCode::IntermediateBulkContainerAluminiumPressurisedOver10Kpa),
+            "XWJ" => Ok(UnitCode::IntermediateBulkContainerMetalPressure10Kpa),
+            "XWK" => Ok(UnitCode::IntermediateBulkContainerSteelLiquid),
+            "XWL" => Ok(UnitCode::IntermediateBulkContainerAluminiumLiquid),
+            "XWM" => Ok(UnitCode::IntermediateBulkContainerMetalLiquid),
+            "XWN" => Ok(UnitCode::IntermediateBulkContainerWovenPlasticWithoutCoatPerLiner),
+            "XWP" => Ok(UnitCode::IntermediateBulkContainerWovenPlasticCoated),
+            "XWQ" => Ok(UnitCode::IntermediateBulkContainerWovenPlasticWithLiner),
+            "XWR" => Ok(UnitCode::IntermediateBulkContainerWovenPlasticCoatedAndLiner),
+            "XWS" => Ok(UnitCode::IntermediateBulkContainerPlasticFilm),
+            "XWT" => Ok(UnitCode::IntermediateBulkContainerTextileWithOutCoatPerLiner),
+            "XWU" => Ok(UnitCode::IntermediateBulkContainerNaturalWoodWithInnerLiner),
+            "XWV" => Ok(UnitCode::IntermediateBulkContainerTextileCoated),
+            "XWW" => Ok(UnitCode::IntermediateBulkContainerTextileWithLiner),
+            "XWX" => Ok(UnitCode::IntermediateBulkContainerTextileCoatedAndLiner),
+            "XWY" => Ok(UnitCode::IntermediateBulkContainerPlywoodWithInnerLiner),
+            "XWZ" => Ok(UnitCode::IntermediateBulkContainerReconstitutedWoodWithInnerLiner),
+            "XXA" => Ok(UnitCode::BagWovenPlasticWithoutInnerCoatPerLiner),
+            "XXB" => Ok(UnitCode::BagWovenPlasticSiftProof),
+            "XXC" => Ok(UnitCode::BagWovenPlasticWaterResistant),
+            "XXD" => Ok(UnitCode::BagPlasticsFilm),
+            "XXF" => Ok(UnitCode::BagTextileWithoutInnerCoatPerLiner),
+            "XXG" => Ok(UnitCode::BagTextileSiftProof),
+            "XXH" => Ok(UnitCode::BagTextileWaterResistant),
+            "XXJ" => Ok(UnitCode::BagPaperMultiWall),
+            "XXK" => Ok(UnitCode::BagPaperMultiWallWaterResistant),
+            "XYA" => Ok(UnitCode::CompositePackagingPlasticReceptacleInSteelDrum),
+            "XYB" => Ok(UnitCode::CompositePackagingPlasticReceptacleInSteelCrateBox),
+            "XYC" => Ok(UnitCode::CompositePackagingPlasticReceptacleInAluminiumDrum),
+            "XYD" => Ok(UnitCode::CompositePackagingPlasticReceptacleInAluminiumCrate),
+            "XYF" => Ok(UnitCode::CompositePackagingPlasticReceptacleInWoodenBox),
+            "XYG" => Ok(UnitCode::CompositePackagingPlasticReceptacleInPlywoodDrum),
+            "XYH" => Ok(UnitCode::CompositePackagingPlasticReceptacleInPlywoodBox),
+            "XYJ" => Ok(UnitCode::CompositePackagingPlasticReceptacleInFibreDrum),
+            "XYK" => Ok(UnitCode::CompositePackagingPlasticReceptacleInFibreboardBox),
+            "XYL" => Ok(UnitCode::CompositePackagingPlasticReceptacleInPlasticDrum),
+            "XYM" => Ok(UnitCode::CompositePackagingPlasticReceptacleInSolidPlasticBox),
+            "XYN" => Ok(UnitCode::CompositePackagingGlassReceptacleInSteelDrum),
+            "XYP" => Ok(UnitCode::CompositePackagingGlassReceptacleInSteelCrateBox),
+            "XYQ" => Ok(UnitCode::CompositePackagingGlassReceptacleInAluminiumDrum),
+            "XYR" => Ok(UnitCode::CompositePackagingGlassReceptacleInAluminiumCrate),
+            "XYS" => Ok(UnitCode::CompositePackagingGlassReceptacleInWoodenBox),
+            "XYT" => Ok(UnitCode::CompositePackagingGlassReceptacleInPlywoodDrum),
+            "XYV" => Ok(UnitCode::CompositePackagingGlassReceptacleInWickerworkHamper),
+            "XYW" => Ok(UnitCode::CompositePackagingGlassReceptacleInFibreDrum),
+            "XYX" => Ok(UnitCode::CompositePackagingGlassReceptacleInFibreboardBox),
+            "XYY" => Ok(UnitCode::CompositePackagingGlassReceptacleInExpandablePlasticPack),
+            "XYZ" => Ok(UnitCode::CompositePackagingGlassReceptacleInSolidPlasticPack),
+            "XZA" => Ok(UnitCode::IntermediateBulkContainerPaperMultiWall),
+            "XZB" => Ok(UnitCode::BagLarge),
+            "XZC" => Ok(UnitCode::IntermediateBulkContainerPaperMultiWallWaterResistant),
+            "XZD" => Ok(UnitCode::IntermediateBulkContainerRigidPlasticWithStructuralEquipmentSolids),
+            "XZF" => Ok(UnitCode::IntermediateBulkContainerRigidPlasticFreestandingSolids),
+            "XZG" => Ok(UnitCode::IntermediateBulkContainerRigidPlasticWithStructuralEquipmentPressurised),
+            "XZH" => Ok(UnitCode::IntermediateBulkContainerRigidPlasticFreestandingPressurised),
+            "XZJ" => Ok(UnitCode::IntermediateBulkContainerRigidPlasticWithStructuralEquipmentLiquids),
+            "XZK" => Ok(UnitCode::IntermediateBulkContainerRigidPlasticFreestandingLiquids),
+            "XZL" => Ok(UnitCode::IntermediateBulkContainerCompositeRigidPlasticSolids),
+            "XZM" => Ok(UnitCode::IntermediateBulkContainerCompositeFlexiblePlasticSolids),
+            "XZN" => Ok(UnitCode::IntermediateBulkContainerCompositeRigidPlasticPressurised),
+            "XZP" => Ok(UnitCode::IntermediateBulkContainerCompositeFlexiblePlasticPressurised),
+            "XZQ" => Ok(UnitCode::IntermediateBulkContainerCompositeRigidPlasticLiquids),
+            "XZR" => Ok(UnitCode::IntermediateBulkContainerCompositeFlexiblePlasticLiquids),
+            "XZS" => Ok(UnitCode::IntermediateBulkContainerComposite),
+            "XZT" => Ok(UnitCode::IntermediateBulkContainerFibreboard),
+            "XZU" => Ok(UnitCode::IntermediateBulkContainerFlexible),
+            "XZV" => Ok(UnitCode::IntermediateBulkContainerMetalOtherThanSteel),
+            "XZW" => Ok(UnitCode::IntermediateBulkContainerNaturalWood),
+            "XZX" => Ok(UnitCode::IntermediateBulkContainerPlywood),
+            "XZY" => Ok(UnitCode::IntermediateBulkContainerReconstitutedWood),
+            "XZZ" => Ok(UnitCode::PackageMutuallyDefined),
+            _ => Err("Unit code not detected".into()),
+        }
+    }
+}
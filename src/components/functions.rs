@@ -21,4 +21,75 @@ pub fn write_xml_to_file (
         },
         Err(e) => return Err(e.to_string()),
     };
-}
\ No newline at end of file
+}
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Marks bytes in [BASE64_VALUES] that are not part of the alphabet
+const INVALID_BASE64_SYMBOL: u8 = 0xFF;
+
+/// Value of every byte in [BASE64_ALPHABET], indexed by the byte itself
+const BASE64_VALUES: [u8; 256] = {
+    let mut values = [INVALID_BASE64_SYMBOL; 256];
+    let mut index = 0;
+    while index < BASE64_ALPHABET.len() {
+        values[BASE64_ALPHABET[index] as usize] = index as u8;
+        index += 1;
+    }
+    values
+};
+
+/// Encodes binary data as standard base64 with padding, e.g. for embedded attachments
+pub fn base64_encode(data: &[u8]) -> String {
+    let mut encoded = String::with_capacity(data.len().div_ceil(3) * 4);
+
+    for chunk in data.chunks(3) {
+        let bytes = [chunk[0], *chunk.get(1).unwrap_or(&0), *chunk.get(2).unwrap_or(&0)];
+        let triple = (bytes[0] as u32) << 16 | (bytes[1] as u32) << 8 | bytes[2] as u32;
+
+        for i in 0..4 {
+            if i <= chunk.len() {
+                encoded.push(BASE64_ALPHABET[(triple >> (18 - 6 * i) & 0x3F) as usize] as char);
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+    encoded
+}
+
+/// Decodes standard base64 with padding, ignoring whitespace.
+/// Like the encoder, it only accepts the canonical form, so bits beyond the padding have to be zero.
+pub fn base64_decode(encoded: &str) -> Result<Vec<u8>, String> {
+    let symbols: Vec<u8> = encoded.bytes().filter(|byte| !byte.is_ascii_whitespace()).collect();
+    let chunks = symbols.chunks_exact(4);
+    if !chunks.remainder().is_empty() {
+        return Err("Base64 input length is not a multiple of 4".to_string());
+    }
+
+    let mut decoded = Vec::with_capacity(symbols.len() / 4 * 3);
+    let chunk_count = chunks.len();
+    for (index, chunk) in chunks.enumerate() {
+        let padding = chunk.iter().rev().take_while(|&&byte| byte == b'=').count();
+        if padding > 2 || (padding > 0 && index + 1 != chunk_count) {
+            return Err("Invalid base64 padding".to_string());
+        }
+
+        let mut triple: u32 = 0;
+        for &byte in &chunk[..4 - padding] {
+            let value = BASE64_VALUES[byte as usize];
+            if value == INVALID_BASE64_SYMBOL {
+                return Err(format!("Invalid base64 character '{}'", byte as char));
+            }
+            triple = triple << 6 | value as u32;
+        }
+        triple <<= 6 * padding;
+
+        let bytes = triple.to_be_bytes();
+        if bytes[4 - padding..].iter().any(|&byte| byte != 0) {
+            return Err("Non-canonical base64 padding bits".to_string());
+        }
+        decoded.extend_from_slice(&bytes[1..4 - padding]);
+    }
+    Ok(decoded)
+}
@@ -2,40 +2,39 @@ use serde::{Serialize,Serializer};
 use chrono::NaiveDate;
 
 use crate::components::enums::{
-    allowance_charge_reason_code::AllowanceChargeReasonCode,
+    allowance_charge_reason_code::AllowanceChargeReasonCode, charge_reason_code::ChargeReasonCode,
     country_code::CountryCode,
     currency_code::CurrencyCode,
+    electronic_address_scheme_code::ElectronicAddressSchemeCode,
     identifier_scheme_code::IdentifierSchemeCode,
     invoice_type_code::InvoiceTypeCode,
+    mime_code::MimeCode,
     specification_level::SpecificationLevel,
+    tax_registration_scheme_code::TaxRegistrationSchemeCode,
     unit_code::UnitCode,
     payment_means_code::PaymentMeansCode,
+    referenced_document_type_code::ReferencedDocumentTypeCode,
     vat_category_code::VATCategoryCode,
 };
 
 use crate::components::constants;
+use crate::components::functions::{base64_decode, base64_encode};
 
 //Formatting and serializing functions
-fn f64_format <S> (value: &f64, serializer: S) -> Result<S::Ok, S::Error> where S:Serializer {
-    let formatted = format!("{:.2}",value);
+fn f64_format_with_precision_4 <S> (value: &f64, serializer: S) -> Result<S::Ok, S::Error> where S:Serializer {
+    let formatted = format!("{:.4}",value);
     serializer.serialize_str(&formatted)
 }
 
-fn f64_format_with_precision_4 <S> (value: &f64, serializer: S) -> Result<S::Ok, S::Error> where S:Serializer {
-    let formatted = format!("{:.4}",value);
+fn percent_format <S> (value: &f64, serializer: S) -> Result<S::Ok, S::Error> where S:Serializer {
+    let formatted = format!("{:.2}",value);
     serializer.serialize_str(&formatted)
 }
 
-fn format_f64_option <S> (option: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error>
-where S:Serializer
-{
-    if option.is_some() {
-        let formatted = format!("{:.2}",option.unwrap());
-        return serializer.serialize_str(&formatted);
-    }
-    else {
-        return serializer.serialize_none();
-        //return Err(serde::ser::Error::custom("Expected a value, got None"));
+fn format_percent_option <S> (option: &Option<f64>, serializer: S) -> Result<S::Ok, S::Error> where S:Serializer {
+    match option {
+        Some(value) => percent_format(value, serializer),
+        None => serializer.serialize_none(),
     }
 }
 
@@ -43,6 +42,7 @@ fn vector_is_empty <S> (vector: &Vec<S>) -> bool {
     vector.is_empty()
 }
 
+
 //Specifications
 #[derive(Serialize, Debug)]
 #[serde(rename="rsm:CrossIndustryInvoice")]
@@ -152,11 +152,14 @@ impl<'invoice> DateTimeString<'invoice> {
             value: date.format(constants::DATE_TIME_FORMAT_102).to_string(),
         }
     }
+
+    pub fn to_naive_date(&self) -> NaiveDate {
+        NaiveDate::parse_from_str(&self.value, constants::DATE_TIME_FORMAT_102).unwrap()
+    }
 }
 impl std::fmt::Display for DateTimeString<'_> {
     fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        let date = NaiveDate::parse_from_str(&self.value, constants::DATE_TIME_FORMAT_102).unwrap();
-        write!(f, "{}", date.format("%Y-%m-%d"))
+        write!(f, "{}", self.to_naive_date().format("%Y-%m-%d"))
     }
 }
 
@@ -190,13 +193,56 @@ pub struct IncludedSupplyChainTradeLineItem<'invoice> {
     #[serde(rename="ram:SpecifiedTradeProduct")]
     pub specified_trade_product: SpecifiedTradeProduct<'invoice>,
     #[serde(rename="ram:SpecifiedLineTradeAgreement")]
-    pub specified_line_trade_agreement: SpecifiedLineTradeAgreement,
+    pub specified_line_trade_agreement: SpecifiedLineTradeAgreement<'invoice>,
     #[serde(rename="ram:SpecifiedLineTradeDelivery")]
     pub specified_line_trade_delivery: SpecifiedLineTradeDelivery,
     #[serde(rename="ram:SpecifiedLineTradeSettlement")]
     pub specified_line_trade_settlement: SpecifiedLineTradeSettlement<'invoice>,
 }
 
+impl<'invoice> IncludedSupplyChainTradeLineItem<'invoice> {
+    /// Invoiced quantity multiplied by the net price and divided by the price base quantity,
+    /// minus line allowances plus line charges, rounded to the minor units of the given currency
+    ///
+    /// BT-131
+    pub fn line_net_amount(&self, currency_code: CurrencyCode) -> f64 {
+        let quantity = self.specified_line_trade_delivery.billed_quantity.value;
+        let net_price = &self.specified_line_trade_agreement.net_price_product_trade_price;
+        let base_quantity = net_price.basis_quantity.as_ref().map_or(1.0, |basis_quantity| basis_quantity.value);
+        let allowances_and_charges: f64 = self.specified_line_trade_settlement.specified_trade_allowance_charge.iter()
+            .map(|allowance_charge| if allowance_charge.is_charge() {
+                allowance_charge.actual_amount.value
+            } else {
+                -allowance_charge.actual_amount.value
+            })
+            .sum();
+
+        currency_code.round(quantity * net_price.charge_amount / base_quantity + allowances_and_charges)
+    }
+
+    /// Adds an allowance (BG-27) or charge (BG-28) to this line and recalculates the line net amount (BT-131)
+    pub fn add_specified_trade_allowance_charge(&mut self, allowance_charge: SpecifiedLineTradeAllowanceCharge<'invoice>, currency_code: CurrencyCode) -> &mut Self {
+        self.specified_line_trade_settlement.specified_trade_allowance_charge.push(allowance_charge);
+        self.calculate_line_total_amount(currency_code)
+    }
+
+    /// Sets the line net amount (BT-131) to [Self::line_net_amount]
+    pub fn calculate_line_total_amount(&mut self, currency_code: CurrencyCode) -> &mut Self {
+        self.specified_line_trade_settlement.specified_trade_settlement_line_monetary_summation.line_total_amount =
+            Amount::new(self.line_net_amount(currency_code)).in_currency(currency_code);
+        self
+    }
+
+    /// Rounds the line allowances and charges to the minor units of the given currency and recalculates the line net amount (BT-131)
+    pub fn in_currency(mut self, currency_code: CurrencyCode) -> Self {
+        for allowance_charge in &mut self.specified_line_trade_settlement.specified_trade_allowance_charge {
+            *allowance_charge = allowance_charge.clone().in_currency(currency_code);
+        }
+        self.calculate_line_total_amount(currency_code);
+        self
+    }
+}
+
 
 /// Groups general line item information
 ///
@@ -207,7 +253,7 @@ pub struct AssociatedDocumentLineDocument<'invoice> {
     ///
     /// BT-126
     #[serde(rename="ram:LineID")]
-    pub line_id: &'invoice str,
+    pub line_id: String,
     /// Unstructured additional information that are relevant to this line item
     ///
     /// BT-127-00
@@ -226,6 +272,16 @@ pub struct SpecifiedTradeProduct<'invoice> {
     /// BT-157
     #[serde(rename="ram:GlobalID", skip_serializing_if = "Option::is_none")]
     pub global_id: Option<GlobalID<'invoice>>,
+    /// Identifier the seller assigned to this item, e.g. the article number
+    ///
+    /// BT-155
+    #[serde(rename="ram:SellerAssignedID", skip_serializing_if = "Option::is_none")]
+    pub seller_assigned_id: Option<&'invoice str>,
+    /// Identifier the buyer assigned to this item
+    ///
+    /// BT-156
+    #[serde(rename="ram:BuyerAssignedID", skip_serializing_if = "Option::is_none")]
+    pub buyer_assigned_id: Option<&'invoice str>,
     /// Name of the article
     ///
     /// BT-153
@@ -234,6 +290,78 @@ pub struct SpecifiedTradeProduct<'invoice> {
     /// BT-154
     #[serde(rename="ram:Description", skip_serializing_if = "Option::is_none")]
     pub description: Option<&'invoice str>,
+    /// BG-32
+    #[serde(rename="ram:ApplicableProductCharacteristic", skip_serializing_if = "vector_is_empty")]
+    pub applicable_product_characteristic: Vec<ApplicableProductCharacteristic<'invoice>>,
+    /// BT-158
+    #[serde(rename="ram:DesignatedProductClassification", skip_serializing_if = "vector_is_empty")]
+    pub designated_product_classification: Vec<DesignatedProductClassification<'invoice>>,
+    /// BT-159
+    #[serde(rename="ram:OriginTradeCountry", skip_serializing_if = "Option::is_none")]
+    pub origin_trade_country: Option<OriginTradeCountry>,
+}
+
+/// Attribute of the invoiced item, e.g. its colour
+///
+/// BG-32
+#[derive(Serialize, Clone, Debug)]
+pub struct ApplicableProductCharacteristic<'invoice> {
+    /// Name of the attribute, e.g. "Colour"
+    ///
+    /// BT-160
+    #[serde(rename="ram:Description")]
+    pub description: &'invoice str,
+    /// Value of the attribute, e.g. "Red"
+    ///
+    /// BT-161
+    #[serde(rename="ram:Value")]
+    pub value: &'invoice str,
+}
+
+/// Classification of the invoiced item by type or nature
+///
+/// BT-158
+#[derive(Serialize, Clone, Debug)]
+pub struct DesignatedProductClassification<'invoice> {
+    #[serde(rename="ram:ClassCode")]
+    pub class_code: ClassCode<'invoice>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct ClassCode<'invoice> {
+    /// Classification scheme from UNTDID 7143, e.g. "TST" for UNSPSC
+    ///
+    /// BT-158-1
+    #[serde(rename="@listID")]
+    pub list_id: &'invoice str,
+    /// Version of the classification scheme
+    ///
+    /// BT-158-2
+    #[serde(rename="@listVersionID", skip_serializing_if = "Option::is_none")]
+    pub list_version_id: Option<&'invoice str>,
+    #[serde(rename="$value")]
+    pub value: &'invoice str,
+}
+
+impl<'invoice> DesignatedProductClassification<'invoice> {
+    pub fn new(list_id: &'invoice str, list_version_id: Option<&'invoice str>, value: &'invoice str) -> Self {
+        Self {
+            class_code: ClassCode {
+                list_id,
+                list_version_id,
+                value,
+            },
+        }
+    }
+}
+
+/// Country the invoiced item comes from
+///
+/// BT-159
+#[derive(Serialize, Clone, Debug)]
+pub struct OriginTradeCountry {
+    #[serde(rename="ram:ID")]
+    pub id: CountryCode,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -258,21 +386,88 @@ impl <'invoice> GlobalID<'invoice> {
 ///
 /// BG-29
 #[derive(Serialize, Clone, Debug)]
-pub struct SpecifiedLineTradeAgreement {
+pub struct SpecifiedLineTradeAgreement<'invoice> {
+    /// BT-132
+    #[serde(rename="ram:BuyerOrderReferencedDocument", skip_serializing_if = "Option::is_none")]
+    pub buyer_order_referenced_document: Option<LineReferencedDocument<'invoice>>,
     #[serde(rename="ram:GrossPriceProductTradePrice", skip_serializing_if = "Option::is_none")]
     pub gross_price_product_trade_price: Option<GrossPriceProductTradePrice>,
     #[serde(rename="ram:NetPriceProductTradePrice")]
     pub net_price_product_trade_price: NetPriceProductTradePrice,
 }
 
+/// Reference to a line of a document, e.g. of the purchase order issued by the buyer
+#[derive(Serialize, Clone, Debug)]
+pub struct LineReferencedDocument<'invoice> {
+    /// Identifier of the referenced line
+    ///
+    /// BT-132
+    #[serde(rename="ram:LineID")]
+    pub line_id: &'invoice str,
+}
+
 /// BT-148-00
 #[derive(Serialize, Clone, Debug)]
 pub struct GrossPriceProductTradePrice {
     /// The item price without VAT before deductions
     ///
-    /// BR-28
-    #[serde(rename="ram:ChargeAmount",serialize_with="f64_format")]
+    /// BT-148
+    #[serde(rename="ram:ChargeAmount",serialize_with="f64_format_with_precision_4")]
     pub charge_amount: f64,
+    /// BT-149-1
+    #[serde(rename="ram:BasisQuantity", skip_serializing_if = "Option::is_none")]
+    pub basis_quantity: Option<BasisQuantity>,
+    /// BT-147-00
+    #[serde(rename="ram:AppliedTradeAllowanceCharge", skip_serializing_if = "Option::is_none")]
+    pub applied_trade_allowance_charge: Option<AppliedTradeAllowanceCharge>,
+}
+
+impl GrossPriceProductTradePrice {
+    pub fn new(charge_amount: f64, basis_quantity: Option<BasisQuantity>) -> Self {
+        Self {
+            charge_amount,
+            basis_quantity,
+            applied_trade_allowance_charge: None,
+        }
+    }
+
+    /// Gross price reduced by the price discount (BT-147)
+    pub fn with_discount(mut self, discount: f64) -> Self {
+        self.applied_trade_allowance_charge = Some(AppliedTradeAllowanceCharge::new(discount));
+        self
+    }
+
+    /// Item net price derived from the gross price minus the price discount
+    ///
+    /// BT-146 = BT-148 - BT-147
+    pub fn net_price(&self) -> NetPriceProductTradePrice {
+        let discount = self.applied_trade_allowance_charge.as_ref().map_or(0.0, |allowance| allowance.actual_amount);
+        NetPriceProductTradePrice {
+            charge_amount: self.charge_amount - discount,
+            basis_quantity: self.basis_quantity.clone(),
+        }
+    }
+}
+
+/// Discount on the item price, only allowances are permitted
+///
+/// BT-147-00
+#[derive(Serialize, Clone, Debug)]
+pub struct AppliedTradeAllowanceCharge {
+    #[serde(rename="ram:ChargeIndicator")]
+    pub charge_indicator: ChargeIndicator,
+    /// BT-147
+    #[serde(rename="ram:ActualAmount",serialize_with="f64_format_with_precision_4")]
+    pub actual_amount: f64,
+}
+
+impl AppliedTradeAllowanceCharge {
+    pub fn new(actual_amount: f64) -> Self {
+        Self {
+            charge_indicator: ChargeIndicator::new(false),
+            actual_amount,
+        }
+    }
 }
 
 /// The item price without vat with deductions and charges
@@ -283,8 +478,45 @@ pub struct NetPriceProductTradePrice {
     /// Price of one item without VAT and after deductions
     ///
     /// BT-146
-    #[serde(rename="ram:ChargeAmount",serialize_with="f64_format")]
+    #[serde(rename="ram:ChargeAmount",serialize_with="f64_format_with_precision_4")]
     pub charge_amount: f64,
+    /// Number of items the price refers to, e.g. 100 if the price is given per 100 pieces
+    ///
+    /// BT-149
+    #[serde(rename="ram:BasisQuantity", skip_serializing_if = "Option::is_none")]
+    pub basis_quantity: Option<BasisQuantity>,
+}
+
+impl NetPriceProductTradePrice {
+    pub fn new(charge_amount: f64, basis_quantity: Option<BasisQuantity>) -> Self {
+        Self {
+            charge_amount,
+            basis_quantity,
+        }
+    }
+}
+
+/// Item price base quantity
+///
+/// BT-149
+#[derive(Serialize, Clone, Debug)]
+pub struct BasisQuantity {
+    /// Unit of the base quantity
+    ///
+    /// BT-150
+    #[serde(rename="@unitCode")]
+    pub unit_code: UnitCode,
+    #[serde(rename="$value",serialize_with="f64_format_with_precision_4")]
+    pub value: f64,
+}
+
+impl BasisQuantity {
+    pub fn new(unit_code: UnitCode, value: f64) -> Self {
+        Self {
+            unit_code,
+            value,
+        }
+    }
 }
 
 
@@ -326,38 +558,123 @@ impl BilledQuantity {
 pub struct SpecifiedLineTradeSettlement<'invoice> {
     #[serde(rename="ram:ApplicableTradeTax")]
     pub applicable_trade_tax: ApplicableTradeTax<'invoice>,
+    /// BG-26
+    #[serde(rename="ram:BillingSpecifiedPeriod", skip_serializing_if = "Option::is_none")]
+    pub billing_specified_period: Option<BillingSpecifiedPeriod<'invoice>>,
+    /// BG-27, BG-28
+    #[serde(rename="ram:SpecifiedTradeAllowanceCharge", skip_serializing_if = "vector_is_empty")]
+    pub specified_trade_allowance_charge: Vec<SpecifiedLineTradeAllowanceCharge<'invoice>>,
     #[serde(rename="ram:SpecifiedTradeSettlementLineMonetarySummation")]
     pub specified_trade_settlement_line_monetary_summation: SpecifiedTradeSettlementLineMonetarySummation,
-    #[serde(rename="ram:SpecifiedTradeAllowanceCharge")]
-    pub specified_trade_allowance_charge: Vec<SpecifiedLineTradeAllowanceCharge<'invoice>>,
+    /// Invoice line object identifier (BT-128)
+    #[serde(rename="ram:AdditionalReferencedDocument", skip_serializing_if = "Option::is_none")]
+    pub additional_referenced_document: Option<AdditionalReferencedDocument<'invoice>>,
+    /// BT-133
+    #[serde(rename="ram:ReceivableSpecifiedTradeAccountingAccount", skip_serializing_if = "Option::is_none")]
+    pub receivable_specified_trade_accounting_account: Option<ReceivableSpecifiedTradeAccountingAccount<'invoice>>,
 }
 
+/// Buyer's accounting account the invoice line is booked to
+#[derive(Serialize, Clone, Debug)]
+pub struct ReceivableSpecifiedTradeAccountingAccount<'invoice> {
+    /// Reference to the buyer's account, e.g. a cost centre
+    ///
+    /// BT-133
+    #[serde(rename="ram:ID")]
+    pub id: &'invoice str,
+}
+
+/// Allowance (BG-27) or charge (BG-28) on line level
 #[derive(Serialize, Clone, Debug)]
 pub struct SpecifiedLineTradeAllowanceCharge<'invoice> {
+    /// `false` for an allowance, `true` for a charge
     #[serde(rename="ram:ChargeIndicator")]
     pub charge_indicator: ChargeIndicator,
-    #[serde(rename="ram:CalculationPercent", serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
+    /// `BT-138` / `BT-143`: Percentage applied to the basis amount
+    #[serde(rename="ram:CalculationPercent", serialize_with="format_percent_option", skip_serializing_if = "Option::is_none")]
     pub calculation_percent: Option<f64>,
-    #[serde(rename="ram:BasisAmount", serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
-    pub basis_amount: Option<f64>,
-    #[serde(rename="ram:ActualAmount", serialize_with="f64_format")]
-    pub actual_amount: f64,
+    /// `BT-137` / `BT-142`: Base amount the percentage is applied to
+    #[serde(rename="ram:BasisAmount", skip_serializing_if = "Option::is_none")]
+    pub basis_amount: Option<Amount>,
+    /// `BT-136` / `BT-141`: Amount of the allowance or charge, without VAT
+    #[serde(rename="ram:ActualAmount")]
+    pub actual_amount: Amount,
+    /// `BT-140` / `BT-145`
     #[serde(rename="ram:ReasonCode", skip_serializing_if = "Option::is_none")]
-    pub reason_code: Option<AllowanceChargeReasonCode>,
+    pub reason_code: Option<ReasonCode>,
+    /// `BT-139` / `BT-144`
     #[serde(rename="ram:Reason", skip_serializing_if = "Option::is_none")]
     pub reason: Option<&'invoice str>,
 }
 
+impl<'invoice> SpecifiedLineTradeAllowanceCharge<'invoice> {
+    /// Line level allowance of a fixed amount
+    pub fn allowance(actual_amount: f64) -> Self {
+        Self::new(false, actual_amount)
+    }
+
+    /// Line level charge of a fixed amount
+    pub fn charge(actual_amount: f64) -> Self {
+        Self::new(true, actual_amount)
+    }
+
+    fn new(charge: bool, actual_amount: f64) -> Self {
+        Self {
+            charge_indicator: ChargeIndicator::new(charge),
+            calculation_percent: None,
+            basis_amount: None,
+            actual_amount: Amount::new(actual_amount),
+            reason_code: None,
+            reason: None,
+        }
+    }
+
+    /// Calculates the amount as percentage of the basis amount, rounded by [Self::in_currency]
+    pub fn with_percentage(mut self, calculation_percent: f64, basis_amount: f64) -> Self {
+        self.calculation_percent = Some(calculation_percent);
+        self.basis_amount = Some(Amount::new(basis_amount));
+        self.actual_amount = Amount::new(basis_amount * calculation_percent / 100.0);
+        self
+    }
+
+    /// Rounds the amounts to the minor units of the given currency
+    pub fn in_currency(mut self, currency_code: CurrencyCode) -> Self {
+        self.basis_amount = self.basis_amount.map(|amount| amount.in_currency(currency_code));
+        self.actual_amount = self.actual_amount.in_currency(currency_code);
+        self
+    }
+
+    pub fn with_reason_code<C: Into<ReasonCode>>(mut self, reason_code: C) -> Self {
+        self.reason_code = Some(reason_code.into());
+        self
+    }
+
+    pub fn with_reason(mut self, reason: &'invoice str) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    pub fn is_charge(&self) -> bool {
+        self.charge_indicator.indicator
+    }
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub struct ChargeIndicator {
     #[serde(rename="udt:Indicator")]
     pub indicator: bool,
 }
 
+impl ChargeIndicator {
+    pub fn new(indicator: bool) -> Self {
+        Self { indicator }
+    }
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub struct SpecifiedTradeSettlementLineMonetarySummation {
-    #[serde(rename="ram:LineTotalAmount", serialize_with="f64_format")]
-    pub line_total_amount: f64,
+    #[serde(rename="ram:LineTotalAmount")]
+    pub line_total_amount: Amount,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -368,41 +685,202 @@ pub struct ApplicableHeaderTradeAgreement<'invoice> {
     pub seller_trade_party: SellerTradeParty<'invoice>,
     #[serde(rename="ram:BuyerTradeParty")]
     pub buyer_trade_party: BuyerTradeParty<'invoice>,
+    /// BG-11
+    #[serde(rename="ram:SellerTaxRepresentativeTradeParty", skip_serializing_if = "Option::is_none")]
+    pub seller_tax_representative_trade_party: Option<SellerTaxRepresentativeTradeParty<'invoice>>,
+    /// Identifier of the sales order, issued by the seller
+    ///
+    /// BT-14
+    #[serde(rename="ram:SellerOrderReferencedDocument", skip_serializing_if = "Option::is_none")]
+    pub seller_order_referenced_document: Option<ReferencedDocument<'invoice>>,
+    /// BT-13
     #[serde(rename="ram:BuyerOrderReferencedDocument", skip_serializing_if = "Option::is_none")]
     pub buyer_order_referenced_document: Option<BuyerOrderReferencedDocument<'invoice>>,
+    /// Identifier of the contract the invoice is based on
+    ///
+    /// BT-12
+    #[serde(rename="ram:ContractReferencedDocument", skip_serializing_if = "Option::is_none")]
+    pub contract_referenced_document: Option<ReferencedDocument<'invoice>>,
+    /// Supporting documents (BG-24), tender or lot reference (BT-17) and invoiced object identifier (BT-18)
+    #[serde(rename="ram:AdditionalReferencedDocument", skip_serializing_if = "vector_is_empty")]
+    pub additional_referenced_document: Vec<AdditionalReferencedDocument<'invoice>>,
+    /// BT-11
+    #[serde(rename="ram:SpecifiedProcuringProject", skip_serializing_if = "Option::is_none")]
+    pub specified_procuring_project: Option<SpecifiedProcuringProject<'invoice>>,
+}
+
+/// Document referenced by the invoice, distinguished by its type code
+#[derive(Serialize, Clone, Debug)]
+pub struct AdditionalReferencedDocument<'invoice> {
+    /// Identifier of the referenced document or object
+    ///
+    /// BT-17, BT-18, BT-122, BT-128
+    #[serde(rename="ram:IssuerAssignedID")]
+    pub issuer_assigned_id: &'invoice str,
+    /// Location of an external supporting document
+    ///
+    /// BT-124
+    #[serde(rename="ram:URIID", skip_serializing_if = "Option::is_none")]
+    pub uriid: Option<&'invoice str>,
+    #[serde(rename="ram:TypeCode")]
+    pub type_code: ReferencedDocumentTypeCode,
+    /// Description of the supporting document, e.g. "Timesheet"
+    ///
+    /// BT-123
+    #[serde(rename="ram:Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'invoice str>,
+    /// BT-125
+    #[serde(rename="ram:AttachmentBinaryObject", skip_serializing_if = "Option::is_none")]
+    pub attachment_binary_object: Option<AttachmentBinaryObject<'invoice>>,
+    /// Scheme of the invoiced object identifier, e.g. "AAG" for a meter number (UNTDID 1153)
+    ///
+    /// BT-18-1, BT-128-1
+    #[serde(rename="ram:ReferenceTypeCode", skip_serializing_if = "Option::is_none")]
+    pub reference_type_code: Option<&'invoice str>,
 }
 
+impl<'invoice> AdditionalReferencedDocument<'invoice> {
+    pub fn new(type_code: ReferencedDocumentTypeCode, issuer_assigned_id: &'invoice str) -> Self {
+        Self {
+            issuer_assigned_id,
+            uriid: None,
+            type_code,
+            name: None,
+            attachment_binary_object: None,
+            reference_type_code: None,
+        }
+    }
+}
+
+/// Supporting document embedded into the invoice as base64
+///
+/// BT-125
 #[derive(Serialize, Clone, Debug)]
-pub struct SellerTradeParty<'invoice> {
+pub struct AttachmentBinaryObject<'invoice> {
+    /// BT-125-1
+    #[serde(rename="@mimeCode")]
+    pub mime_code: MimeCode,
+    /// BT-125-2
+    #[serde(rename="@filename")]
+    pub filename: &'invoice str,
+    /// Base64 encoded content
+    #[serde(rename="$value")]
+    pub content: String,
+}
+
+impl<'invoice> AttachmentBinaryObject<'invoice> {
+    pub fn new(mime_code: MimeCode, filename: &'invoice str, content: &[u8]) -> Self {
+        Self {
+            mime_code,
+            filename,
+            content: base64_encode(content),
+        }
+    }
+
+    /// Size of the decoded attachment in bytes, calculated without decoding it
+    pub fn size(&self) -> usize {
+        let symbols = self.content.bytes().filter(|byte| !byte.is_ascii_whitespace()).count();
+        let padding = self.content.trim_end().bytes().rev().take_while(|&byte| byte == b'=').count();
+        (symbols / 4 * 3).saturating_sub(padding)
+    }
+
+    /// Decoded attachment, refused if it is larger than `max_size` bytes,
+    /// so untrusted invoices can't force large allocations
+    pub fn decoded_content(&self, max_size: usize) -> Result<Vec<u8>, String> {
+        let size = self.size();
+        if size > max_size {
+            return Err(format!("Attachment {} has {} bytes, exceeding the limit of {} bytes", self.filename, size, max_size));
+        }
+        base64_decode(&self.content)
+    }
+}
+
+/// Project the invoice refers to
+///
+/// BT-11
+#[derive(Serialize, Clone, Debug)]
+pub struct SpecifiedProcuringProject<'invoice> {
+    /// Identifier of the project
+    ///
+    /// BT-11
     #[serde(rename="ram:ID")]
+    pub id: &'invoice str,
+    /// Name of the project
+    ///
+    /// BT-11-0
+    #[serde(rename="ram:Name")]
+    pub name: &'invoice str,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct SellerTradeParty<'invoice> {
+    /// Identifiers of the seller, e.g. the supplier number assigned by the buyer
+    ///
+    /// BT-29
+    #[serde(rename="ram:ID", skip_serializing_if = "vector_is_empty")]
     pub id: Vec<&'invoice str>,
-    #[serde(rename="ram:GlobalID")]
+    /// Identifiers of the seller with a registered scheme, e.g. a GLN
+    ///
+    /// BT-29-0, BT-29-1
+    #[serde(rename="ram:GlobalID", skip_serializing_if = "vector_is_empty")]
     pub global_id: Vec<GlobalID<'invoice>>,
     #[serde(rename="ram:Name")]
     pub name: &'invoice str,
     #[serde(rename="ram:SpecifiedLegalOrganization", skip_serializing_if = "Option::is_none")]
     pub specified_legal_organization: Option<SpecifiedLegalOrganization<'invoice>>,
+    /// BG-6
+    #[serde(rename="ram:DefinedTradeContact", skip_serializing_if = "Option::is_none")]
+    pub defined_trade_contact: Option<DefinedTradeContact<'invoice>>,
     #[serde(rename="ram:PostalTradeAddress")]
     pub postal_trade_address: PostalTradeAddress<'invoice>,
     #[serde(rename="ram:URIUniversalCommunication", skip_serializing_if = "Option::is_none")]
     /// BT-34-00
     pub uri_universal_communication: Option<URIUniversalCommunication<'invoice>>,
-    #[serde(rename="ram:SpecifiedTaxRegistration")]
+    /// BT-31-00, BT-32-00
+    #[serde(rename="ram:SpecifiedTaxRegistration", skip_serializing_if = "vector_is_empty")]
     pub specified_tax_registration: Vec<SpecifiedTaxRegistration<'invoice>>,
 }
 
+/// Party that accounts for the VAT on behalf of the seller, e.g. a fiscal representative
+///
+/// BG-11
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct SellerTaxRepresentativeTradeParty<'invoice> {
+    /// Name of the seller's tax representative
+    ///
+    /// BT-62
+    #[serde(rename="ram:Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'invoice str>,
+    /// Address of the seller's tax representative
+    ///
+    /// BG-12
+    #[serde(rename="ram:PostalTradeAddress", skip_serializing_if = "Option::is_none")]
+    pub postal_trade_address: Option<PostalTradeAddress<'invoice>>,
+    /// VAT identifier of the seller's tax representative
+    ///
+    /// BT-63
+    #[serde(rename="ram:SpecifiedTaxRegistration", skip_serializing_if = "Option::is_none")]
+    pub specified_tax_registration: Option<SpecifiedTaxRegistration<'invoice>>,
+}
+
+/// Legal registration identifier of a trade party
+///
+/// BT-30 (seller), BT-47 (buyer), BT-61 (payee)
 #[derive(Serialize, Clone, Debug)]
 pub struct LegalOrganizationID<'invoice> {
-    #[serde(rename = "@schemeID")]
-    scheme_id: &'static str,
+    /// Registrar of the identifier, e.g. [`IdentifierSchemeCode::SIRENE`]. Omitted if the scheme is unknown
+    ///
+    /// BT-30-1 (seller), BT-47-1 (buyer)
+    #[serde(rename = "@schemeID", skip_serializing_if = "Option::is_none")]
+    pub scheme_id: Option<IdentifierSchemeCode>,
     #[serde(rename = "$value")]
-    value: &'invoice str,
+    pub value: &'invoice str,
 }
 
 impl<'invoice> LegalOrganizationID<'invoice> {
-    pub fn new(value: &'invoice str) -> Self {
+    pub fn new(scheme_id: Option<IdentifierSchemeCode>, value: &'invoice str) -> Self {
         Self {
-            scheme_id: "0002",
+            scheme_id,
             value,
         }
     }
@@ -410,8 +888,13 @@ impl<'invoice> LegalOrganizationID<'invoice> {
 
 #[derive(Serialize, Clone, Debug)]
 pub struct SpecifiedLegalOrganization<'invoice> {
-    #[serde(rename="ram:ID")]
-    pub id: LegalOrganizationID<'invoice>,
+    #[serde(rename="ram:ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<LegalOrganizationID<'invoice>>,
+    /// Name under which the trade party is known, if different from its legal name
+    ///
+    /// BT-28 (seller), BT-45 (buyer)
+    #[serde(rename="ram:TradingBusinessName", skip_serializing_if = "Option::is_none")]
+    pub trading_business_name: Option<&'invoice str>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -445,24 +928,65 @@ impl<'invoice> Default for PostalTradeAddress<'invoice> {
     }
 }
 
+/// Contact point of a trade party, e.g. a person or a department
+///
+/// BG-6 (seller), BG-9 (buyer)
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct DefinedTradeContact<'invoice> {
+    /// Name of the contact person
+    ///
+    /// BT-41 (seller), BT-56 (buyer)
+    #[serde(rename="ram:PersonName", skip_serializing_if = "Option::is_none")]
+    pub person_name: Option<&'invoice str>,
+    /// Name of the department of the contact point
+    ///
+    /// BT-41-0 (seller), BT-56-0 (buyer)
+    #[serde(rename="ram:DepartmentName", skip_serializing_if = "Option::is_none")]
+    pub department_name: Option<&'invoice str>,
+    /// BT-42-00 (seller), BT-57-00 (buyer)
+    #[serde(rename="ram:TelephoneUniversalCommunication", skip_serializing_if = "Option::is_none")]
+    pub telephone_universal_communication: Option<TelephoneUniversalCommunication<'invoice>>,
+    /// BT-43-00 (seller), BT-58-00 (buyer)
+    #[serde(rename="ram:EmailURIUniversalCommunication", skip_serializing_if = "Option::is_none")]
+    pub email_uri_universal_communication: Option<EmailURIUniversalCommunication<'invoice>>,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct TelephoneUniversalCommunication<'invoice> {
+    /// Phone number of the contact point
+    ///
+    /// BT-42 (seller), BT-57 (buyer)
+    #[serde(rename="ram:CompleteNumber")]
+    pub complete_number: &'invoice str,
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct EmailURIUniversalCommunication<'invoice> {
+    /// Email address of the contact point
+    ///
+    /// BT-43 (seller), BT-58 (buyer)
+    #[serde(rename="ram:URIID")]
+    pub uriid: &'invoice str,
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub struct SpecifiedTaxRegistrationID<'invoice> {
     #[serde(rename = "@schemeID")]
-    scheme_id: &'static str,
+    pub scheme_id: TaxRegistrationSchemeCode,
     #[serde(rename = "$value")]
-    value: &'invoice str,
+    pub value: &'invoice str,
 }
 
 impl<'invoice> SpecifiedTaxRegistrationID<'invoice> {
     pub fn new(value: &'invoice str) -> Self {
         Self {
-            scheme_id: "VA",
+            scheme_id: TaxRegistrationSchemeCode::VatIdentifier,
             value,
         }
     }
     pub fn new_fc(value: &'invoice str) -> Self {
         Self {
-            scheme_id: "FC",
+            scheme_id: TaxRegistrationSchemeCode::FiscalNumber,
             value,
         }
     }
@@ -474,28 +998,73 @@ pub struct SpecifiedTaxRegistration<'invoice> {
     pub id: SpecifiedTaxRegistrationID<'invoice>,
 }
 
+impl<'invoice> SpecifiedTaxRegistration<'invoice> {
+    pub fn new(scheme_id: TaxRegistrationSchemeCode, value: &'invoice str) -> Self {
+        Self {
+            id: SpecifiedTaxRegistrationID {
+                scheme_id,
+                value,
+            },
+        }
+    }
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub struct URIUniversalCommunication<'invoice> {
     #[serde(rename="ram:URIID")]
     pub uriid: UriId<'invoice>,
 }
 
+/// Electronic address of a trade party, e.g. an email address or a Leitweg-ID
+///
+/// BT-34 (seller), BT-49 (buyer)
 #[derive(Serialize, Clone, Debug)]
 pub struct UriId<'invoice> {
+    /// BT-34-1 (seller), BT-49-1 (buyer)
     #[serde(rename="@schemeID")]
-    pub scheme_id: &'invoice str,    
+    pub scheme_id: ElectronicAddressSchemeCode,
     #[serde(rename="$value")]
     pub value: &'invoice str,
 }
 
+impl<'invoice> URIUniversalCommunication<'invoice> {
+    pub fn new(scheme_id: ElectronicAddressSchemeCode, value: &'invoice str) -> Self {
+        Self {
+            uriid: UriId {
+                scheme_id,
+                value,
+            },
+        }
+    }
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub struct BuyerTradeParty<'invoice> {
+    /// Identifier of the buyer, e.g. the customer number assigned by the seller
+    ///
+    /// BT-46
+    #[serde(rename="ram:ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'invoice str>,
+    /// Identifier of the buyer with a registered scheme, e.g. a GLN
+    ///
+    /// BT-46-0, BT-46-1
+    #[serde(rename="ram:GlobalID", skip_serializing_if = "Option::is_none")]
+    pub global_id: Option<GlobalID<'invoice>>,
     #[serde(rename="ram:Name")]
     pub name: &'invoice str,
-    #[serde(rename="ram:PostalTradeAddress")]
-    pub postal_trade_address: PostalTradeAddress<'invoice>,
     #[serde(rename="ram:SpecifiedLegalOrganization", skip_serializing_if = "Option::is_none")]
     pub specified_legal_organization: Option<SpecifiedLegalOrganization<'invoice>>,
+    /// BG-9
+    #[serde(rename="ram:DefinedTradeContact", skip_serializing_if = "Option::is_none")]
+    pub defined_trade_contact: Option<DefinedTradeContact<'invoice>>,
+    #[serde(rename="ram:PostalTradeAddress")]
+    pub postal_trade_address: PostalTradeAddress<'invoice>,
+    /// BT-49-00
+    #[serde(rename="ram:URIUniversalCommunication", skip_serializing_if = "Option::is_none")]
+    pub uri_universal_communication: Option<URIUniversalCommunication<'invoice>>,
+    /// BT-48-00
+    #[serde(rename="ram:SpecifiedTaxRegistration", skip_serializing_if = "vector_is_empty")]
+    pub specified_tax_registration: Vec<SpecifiedTaxRegistration<'invoice>>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -504,10 +1073,63 @@ pub struct BuyerOrderReferencedDocument<'invoice> {
     pub issuer_assigned_id: &'invoice str,
 }
 
+/// Reference to a document by the identifier its issuer assigned to it
+#[derive(Serialize, Clone, Debug)]
+pub struct ReferencedDocument<'invoice> {
+    #[serde(rename="ram:IssuerAssignedID")]
+    pub issuer_assigned_id: &'invoice str,
+}
+
+impl<'invoice> ReferencedDocument<'invoice> {
+    pub fn new(issuer_assigned_id: &'invoice str) -> Self {
+        Self { issuer_assigned_id }
+    }
+}
+
 #[derive(Serialize, Clone, Debug)]
 pub struct ApplicableHeaderTradeDelivery<'invoice> {
+    /// BG-13
+    #[serde(rename="ram:ShipToTradeParty", skip_serializing_if = "Option::is_none")]
+    pub ship_to_trade_party: Option<ShipToTradeParty<'invoice>>,
     #[serde(rename="ram:ActualDeliverySupplyChainEvent", skip_serializing_if = "Option::is_none")]
     pub actual_delivery_supply_chain_event: Option<ActualDeliverySupplyChainEvent<'invoice>>,
+    /// Despatch advice reference
+    ///
+    /// BT-16
+    #[serde(rename="ram:DespatchAdviceReferencedDocument", skip_serializing_if = "Option::is_none")]
+    pub despatch_advice_referenced_document: Option<ReferencedDocument<'invoice>>,
+    /// Receiving advice reference
+    ///
+    /// BT-15
+    #[serde(rename="ram:ReceivingAdviceReferencedDocument", skip_serializing_if = "Option::is_none")]
+    pub receiving_advice_referenced_document: Option<ReferencedDocument<'invoice>>,
+}
+
+/// Party to which the goods and services are delivered, if it differs from the buyer
+///
+/// BG-13
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct ShipToTradeParty<'invoice> {
+    /// Identifier of the location to which the goods and services are delivered
+    ///
+    /// BT-71
+    #[serde(rename="ram:ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'invoice str>,
+    /// Location identifier with a registered scheme, e.g. a GLN
+    ///
+    /// BT-71-0, BT-71-1
+    #[serde(rename="ram:GlobalID", skip_serializing_if = "Option::is_none")]
+    pub global_id: Option<GlobalID<'invoice>>,
+    /// Name of the party to which the goods and services are delivered
+    ///
+    /// BT-70
+    #[serde(rename="ram:Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'invoice str>,
+    /// Deliver to address
+    ///
+    /// BG-15
+    #[serde(rename="ram:PostalTradeAddress", skip_serializing_if = "Option::is_none")]
+    pub postal_trade_address: Option<PostalTradeAddress<'invoice>>,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -525,6 +1147,7 @@ pub struct OccurrenceDateTime<'invoice> {
 impl<'invoice> ApplicableHeaderTradeDelivery<'invoice> {
     pub fn new_with_event(occurrence_date_time: Option<DateTimeString<'invoice>>) -> Self {
         Self {
+            ship_to_trade_party: None,
             actual_delivery_supply_chain_event: if occurrence_date_time.is_some() {
                 Some(ActualDeliverySupplyChainEvent {
                     occurrence_date_time: occurrence_date_time.map(|actual_delivery_date| OccurrenceDateTime {
@@ -533,64 +1156,252 @@ impl<'invoice> ApplicableHeaderTradeDelivery<'invoice> {
                 })
             } else {
                 None
-            }
+            },
+            despatch_advice_referenced_document: None,
+            receiving_advice_referenced_document: None,
         }
     }
 }
 
 #[derive(Serialize, Clone, Debug)]
 pub struct ApplicableHeaderTradeSettlement <'invoice>{
+    /// Identifier of the seller as creditor for SEPA direct debits
+    ///
+    /// BT-90
+    #[serde(rename="ram:CreditorReferenceID", skip_serializing_if = "Option::is_none")]
+    pub creditor_reference_id: Option<&'invoice str>,
+    /// Reference that the buyer should use when paying, so the seller can link the payment to the invoice
+    ///
+    /// BT-83
+    #[serde(rename="ram:PaymentReference", skip_serializing_if = "Option::is_none")]
+    pub payment_reference: Option<&'invoice str>,
+    /// Currency in which the VAT is accounted, if it differs from the invoice currency
+    ///
+    /// BT-6
+    #[serde(rename="ram:TaxCurrencyCode", skip_serializing_if = "Option::is_none")]
+    pub tax_currency_code: Option<CurrencyCode>,
     #[serde(rename="ram:InvoiceCurrencyCode")]
     pub invoice_currency_code: CurrencyCode,
-    #[serde(rename="ram:SpecifiedTradeSettlementPaymentMeans")]
+    /// BG-10
+    #[serde(rename="ram:PayeeTradeParty", skip_serializing_if = "Option::is_none")]
+    pub payee_trade_party: Option<PayeeTradeParty<'invoice>>,
+    /// BG-16
+    #[serde(rename="ram:SpecifiedTradeSettlementPaymentMeans", skip_serializing_if = "vector_is_empty")]
     pub specified_trade_settlement_payment_means: Vec<SpecifiedTradeSettlementPaymentMeans<'invoice>>,
     #[serde(rename="ram:ApplicableTradeTax", skip_serializing_if = "Option::is_none")]
     pub applicable_trade_tax: Option<ApplicableTradeTax<'invoice>>,
-    #[serde(rename="ram:SpecifiedTradeAllowanceCharge")]
+    /// BG-14
+    #[serde(rename="ram:BillingSpecifiedPeriod", skip_serializing_if = "Option::is_none")]
+    pub billing_specified_period: Option<BillingSpecifiedPeriod<'invoice>>,
+    /// BG-20, BG-21
+    #[serde(rename="ram:SpecifiedTradeAllowanceCharge", skip_serializing_if = "vector_is_empty")]
     pub specified_trade_allowance_charge: Vec<SpecifiedTradeAllowanceCharge<'invoice>>,
-    #[serde(rename="ram:SpecifiedTradePaymentTerms", skip_serializing_if = "Option::is_none")]
-    pub specified_trade_payment_terms: Option<SpecifiedTradePaymentTerms<'invoice>>,
+    #[serde(rename="ram:SpecifiedTradePaymentTerms", skip_serializing_if = "vector_is_empty")]
+    pub specified_trade_payment_terms: Vec<SpecifiedTradePaymentTerms<'invoice>>,
     #[serde(rename="ram:SpecifiedTradeSettlementHeaderMonetarySummation")]
     pub specified_trade_settlement_header_monetary_summation: SpecifiedTradeSettlementHeaderMonetarySummation,
 }
 
+/// Party that receives the payment, if it differs from the seller, e.g. a factoring company
+///
+/// BG-10
+#[derive(Serialize, Clone, Debug, Default)]
+pub struct PayeeTradeParty<'invoice> {
+    /// Identifier of the payee
+    ///
+    /// BT-60
+    #[serde(rename="ram:ID", skip_serializing_if = "Option::is_none")]
+    pub id: Option<&'invoice str>,
+    /// Identifier of the payee with a registered scheme, e.g. a GLN
+    ///
+    /// BT-60-0, BT-60-1
+    #[serde(rename="ram:GlobalID", skip_serializing_if = "Option::is_none")]
+    pub global_id: Option<GlobalID<'invoice>>,
+    /// Name of the payee
+    ///
+    /// BT-59
+    #[serde(rename="ram:Name", skip_serializing_if = "Option::is_none")]
+    pub name: Option<&'invoice str>,
+    /// Legal registration identifier of the payee
+    ///
+    /// BT-61
+    #[serde(rename="ram:SpecifiedLegalOrganization", skip_serializing_if = "Option::is_none")]
+    pub specified_legal_organization: Option<SpecifiedLegalOrganization<'invoice>>,
+}
+
+/// Allowance (BG-20) or charge (BG-21) on document level
 #[derive(Serialize, Clone, Debug)]
 pub struct SpecifiedTradeAllowanceCharge<'invoice> {
+    /// `false` for an allowance, `true` for a charge
     #[serde(rename="ram:ChargeIndicator")]
     pub charge_indicator: ChargeIndicator,
-    #[serde(rename="ram:CalculationPercent", serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
+    /// `BT-94` / `BT-101`: Percentage applied to the basis amount
+    #[serde(rename="ram:CalculationPercent", serialize_with="format_percent_option", skip_serializing_if = "Option::is_none")]
     pub calculation_percent: Option<f64>,
-    #[serde(rename="ram:BasisAmount", serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
-    pub basis_amount: Option<f64>,
-    #[serde(rename="ram:ActualAmount", serialize_with="f64_format")]
-    pub actual_amount: f64,
+    /// `BT-93` / `BT-100`: Base amount the percentage is applied to
+    #[serde(rename="ram:BasisAmount", skip_serializing_if = "Option::is_none")]
+    pub basis_amount: Option<Amount>,
+    /// `BT-92` / `BT-99`: Amount of the allowance or charge, without VAT
+    #[serde(rename="ram:ActualAmount")]
+    pub actual_amount: Amount,
+    /// `BT-98` / `BT-105`
     #[serde(rename="ram:ReasonCode", skip_serializing_if = "Option::is_none")]
-    pub reason_code: Option<AllowanceChargeReasonCode>,
+    pub reason_code: Option<ReasonCode>,
+    /// `BT-97` / `BT-104`
     #[serde(rename="ram:Reason", skip_serializing_if = "Option::is_none")]
     pub reason: Option<&'invoice str>,
+    /// `BT-95`, `BT-96` / `BT-102`, `BT-103`: VAT category and rate of the allowance or charge
     #[serde(rename="ram:CategoryTradeTax")]
     pub category_trade_tax: CategoryTradeTax<'invoice>
     
 }
 
+impl<'invoice> SpecifiedTradeAllowanceCharge<'invoice> {
+    /// Document level allowance of a fixed amount
+    pub fn allowance(actual_amount: f64, category_code: VATCategoryCode, rate_applicable_percent: Option<f64>) -> Self {
+        Self::new(false, actual_amount, category_code, rate_applicable_percent)
+    }
+
+    /// Document level charge of a fixed amount
+    pub fn charge(actual_amount: f64, category_code: VATCategoryCode, rate_applicable_percent: Option<f64>) -> Self {
+        Self::new(true, actual_amount, category_code, rate_applicable_percent)
+    }
+
+    fn new(charge: bool, actual_amount: f64, category_code: VATCategoryCode, rate_applicable_percent: Option<f64>) -> Self {
+        Self {
+            charge_indicator: ChargeIndicator::new(charge),
+            calculation_percent: None,
+            basis_amount: None,
+            actual_amount: Amount::new(actual_amount),
+            reason_code: None,
+            reason: None,
+            category_trade_tax: CategoryTradeTax::new(category_code, rate_applicable_percent),
+        }
+    }
+
+    /// Calculates the amount as percentage of the basis amount, rounded by [Self::in_currency]
+    pub fn with_percentage(mut self, calculation_percent: f64, basis_amount: f64) -> Self {
+        self.calculation_percent = Some(calculation_percent);
+        self.basis_amount = Some(Amount::new(basis_amount));
+        self.actual_amount = Amount::new(basis_amount * calculation_percent / 100.0);
+        self
+    }
+
+    /// Rounds the amounts to the minor units of the given currency
+    pub fn in_currency(mut self, currency_code: CurrencyCode) -> Self {
+        self.basis_amount = self.basis_amount.map(|amount| amount.in_currency(currency_code));
+        self.actual_amount = self.actual_amount.in_currency(currency_code);
+        self
+    }
+
+    pub fn with_reason_code<C: Into<ReasonCode>>(mut self, reason_code: C) -> Self {
+        self.reason_code = Some(reason_code.into());
+        self
+    }
+
+    pub fn with_reason(mut self, reason: &'invoice str) -> Self {
+        self.reason = Some(reason);
+        self
+    }
+
+    pub fn is_charge(&self) -> bool {
+        self.charge_indicator.indicator
+    }
+}
+
+/// Reason code of an allowance (UNTDID 5189) or a charge (UNTDID 7161)
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub enum ReasonCode {
+    Allowance(AllowanceChargeReasonCode),
+    Charge(ChargeReasonCode),
+}
+
+impl ReasonCode {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ReasonCode::Allowance(code) => code.as_str(),
+            ReasonCode::Charge(code) => code.as_str(),
+        }
+    }
+}
+
+impl Serialize for ReasonCode {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+impl From<AllowanceChargeReasonCode> for ReasonCode {
+    fn from(code: AllowanceChargeReasonCode) -> Self {
+        ReasonCode::Allowance(code)
+    }
+}
+
+impl From<ChargeReasonCode> for ReasonCode {
+    fn from(code: ChargeReasonCode) -> Self {
+        ReasonCode::Charge(code)
+    }
+}
+
+/// Reason of an allowance or charge, given as code, as free text or both.
+///
+/// Allowances take an [`AllowanceChargeReasonCode`], charges a [`ChargeReasonCode`].
+#[derive(Clone, Copy, Debug)]
+pub enum AllowanceChargeReason<'invoice, C> {
+    Code(C),
+    Text(&'invoice str),
+    CodeAndText(C, &'invoice str),
+}
+
+impl<'invoice, C: Into<ReasonCode>> AllowanceChargeReason<'invoice, C> {
+    pub fn apply(self, allowance_charge: SpecifiedTradeAllowanceCharge<'invoice>) -> SpecifiedTradeAllowanceCharge<'invoice> {
+        match self {
+            AllowanceChargeReason::Code(code) => allowance_charge.with_reason_code(code),
+            AllowanceChargeReason::Text(text) => allowance_charge.with_reason(text),
+            AllowanceChargeReason::CodeAndText(code, text) => allowance_charge.with_reason_code(code).with_reason(text),
+        }
+    }
+}
+
 #[derive(Serialize, Clone, Copy, Debug)]
 pub struct CategoryTradeTax<'invoice> {
     #[serde(rename="ram:TypeCode")]
     pub type_code: &'invoice str,
     #[serde(rename="ram:CategoryCode")]
     pub category_code: VATCategoryCode,
-    #[serde(rename="ram:RateApplicablePercent", serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
+    #[serde(rename="ram:RateApplicablePercent", serialize_with="format_percent_option", skip_serializing_if = "Option::is_none")]
     pub rate_applicable_percent: Option<f64>,
 }
 
+impl CategoryTradeTax<'_> {
+    pub fn new(category_code: VATCategoryCode, rate_applicable_percent: Option<f64>) -> Self {
+        Self {
+            type_code: "VAT",
+            category_code,
+            rate_applicable_percent,
+        }
+    }
+}
+
+/// Information about how the payment is expected to be made
+///
+/// BG-16
 #[derive(Serialize, Clone, Copy, Debug)]
 pub struct SpecifiedTradeSettlementPaymentMeans<'invoice> {
+    /// BT-81
     #[serde(rename="ram:TypeCode")]
     pub type_code: PaymentMeansCode<'invoice>,
+    /// Text describing the means of payment
+    ///
+    /// BT-82
     #[serde(rename="ram:Information", skip_serializing_if = "Option::is_none")]
     pub information: Option<&'invoice str>,
-    #[serde(rename="ram:Information", skip_serializing_if = "Option::is_none")]
-    pub applicable_trade_settlement_financial_card: Option<&'invoice str>,
+    /// BG-18
+    #[serde(rename="ram:ApplicableTradeSettlementFinancialCard", skip_serializing_if = "Option::is_none")]
+    pub applicable_trade_settlement_financial_card: Option<ApplicableTradeSettlementFinancialCard<'invoice>>,
     #[serde(rename="ram:PayerPartyDebtorFinancialAccount", skip_serializing_if = "Option::is_none")]
     pub payer_party_debtor_financial_account: Option<PayerPartyDebtorFinancialAccount<'invoice>>,
     #[serde(rename="ram:PayeePartyCreditorFinancialAccount", skip_serializing_if = "Option::is_none")]
@@ -599,30 +1410,87 @@ pub struct SpecifiedTradeSettlementPaymentMeans<'invoice> {
     pub payee_specified_creditor_financial_institution: Option<PayeeSpecifiedCreditorFinancialInstitution<'invoice>>
 }
 
+impl<'invoice> SpecifiedTradeSettlementPaymentMeans<'invoice> {
+    pub fn new(type_code: PaymentMeansCode<'invoice>) -> Self {
+        Self {
+            type_code,
+            information: None,
+            applicable_trade_settlement_financial_card: None,
+            payer_party_debtor_financial_account: None,
+            payee_party_creditor_financial_account: None,
+            payee_specified_creditor_financial_institution: None,
+        }
+    }
+}
+
+/// Information about the card that was used for the payment
+///
+/// BG-18
 #[derive(Serialize, Clone, Copy, Debug)]
 pub struct ApplicableTradeSettlementFinancialCard<'invoice> {
-    pub id: &'invoice str, // TODO: should this be an enum?
-    pub cardholder_name: &'invoice str,
+    /// Last digits of the card number. Never the full card number, see BR-51
+    ///
+    /// BT-87
+    #[serde(rename="ram:ID")]
+    pub id: &'invoice str,
+    /// BT-88
+    #[serde(rename="ram:CardholderName", skip_serializing_if = "Option::is_none")]
+    pub cardholder_name: Option<&'invoice str>,
+}
+
+impl<'invoice> ApplicableTradeSettlementFinancialCard<'invoice> {
+    /// Maximum number of digits of the card number that may be shown on an invoice (first 6 and last 4)
+    pub const MAX_VISIBLE_DIGITS: usize = 10;
+
+    /// Refuses card numbers that show more than [Self::MAX_VISIBLE_DIGITS] digits
+    pub fn new(id: &'invoice str, cardholder_name: Option<&'invoice str>) -> Result<Self, String> {
+        let visible_digits = id.chars().filter(|c| c.is_ascii_digit()).count();
+        if visible_digits > Self::MAX_VISIBLE_DIGITS {
+            return Err(format!(
+                "Card number shows {} digits, only the last digits (at most {}) may be stated on an invoice",
+                visible_digits,
+                Self::MAX_VISIBLE_DIGITS
+            ));
+        }
+
+        Ok(Self {
+            id,
+            cardholder_name,
+        })
+    }
 }
 
+/// Account of the buyer that is debited by direct debit
+///
+/// BT-91-00
 #[derive(Serialize, Clone, Copy, Debug)]
 pub struct PayerPartyDebtorFinancialAccount<'invoice> {
+    /// BT-91
     #[serde(rename="ram:IBANID")]
     pub ibanid: &'invoice str,
 }
 
+/// Account of the payee that the payment should be transferred to
+///
+/// BG-17
 #[derive(Serialize, Clone, Copy, Debug)]
 pub struct PayeePartyCreditorFinancialAccount<'invoice> {
+    /// BT-84
     #[serde(rename="ram:IBANID", skip_serializing_if = "Option::is_none")]
     pub ibanid: Option<&'invoice str>, // TODO: should this be a custom type? Or the crate `iban`?
+    /// BT-85
     #[serde(rename="ram:AccountName", skip_serializing_if = "Option::is_none")]
     pub account_name: Option<&'invoice str>, // TODO: should this be a custom type? Or the crate `iban`?
+    /// Account identifier for non-IBAN accounts
+    ///
+    /// BT-84-0
     #[serde(rename="ram:ProprietaryID", skip_serializing_if = "Option::is_none")]
     pub proprietary_id: Option<&'invoice str>,
 }
 
 #[derive(Serialize, Clone, Copy, Debug)]
 pub struct PayeeSpecifiedCreditorFinancialInstitution<'invoice> {
+    /// BT-86
     #[serde(rename="ram:BICID")]
     pub bicid: &'invoice str
 }
@@ -636,8 +1504,8 @@ pub struct ApplicableTradeTax <'invoice> {
     /// The total amount of tax that has to be paid for this tax category
     ///
     /// BT-117
-    #[serde(rename="ram:CalculatedAmount",serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
-    pub calculated_amount: Option<f64>,
+    #[serde(rename="ram:CalculatedAmount", skip_serializing_if = "Option::is_none")]
+    pub calculated_amount: Option<Amount>,
     /// Should always be "VAT"
     ///
     /// BT-118-0
@@ -651,8 +1519,8 @@ pub struct ApplicableTradeTax <'invoice> {
     /// Sum of all netto amoounts
     ///
     /// BT-116
-    #[serde(rename="ram:BasisAmount",serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
-    pub basis_amount: Option<f64>,
+    #[serde(rename="ram:BasisAmount", skip_serializing_if = "Option::is_none")]
+    pub basis_amount: Option<Amount>,
     /// Identifies a VAT category, has to match [Self::rate_applicable_percent]
     ///
     /// BT 118
@@ -666,10 +1534,19 @@ pub struct ApplicableTradeTax <'invoice> {
     /// VAT percentage, has to match [Self::category_code]
     ///
     /// BT-119
-    #[serde(rename="ram:RateApplicablePercent",serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
+    #[serde(rename="ram:RateApplicablePercent",serialize_with="format_percent_option", skip_serializing_if = "Option::is_none")]
     pub rate_applicable_percent: Option<f64>,
 }
 
+impl ApplicableTradeTax<'_> {
+    /// Rounds the amounts to the minor units of the given currency
+    pub fn in_currency(mut self, currency_code: CurrencyCode) -> Self {
+        self.calculated_amount = self.calculated_amount.map(|amount| amount.in_currency(currency_code));
+        self.basis_amount = self.basis_amount.map(|amount| amount.in_currency(currency_code));
+        self
+    }
+}
+
 impl<'invoice> Default for ApplicableTradeTax<'invoice> {
     fn default() -> Self {
         Self {
@@ -684,14 +1561,100 @@ impl<'invoice> Default for ApplicableTradeTax<'invoice> {
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Serialize, Clone, Debug, Default)]
 pub struct SpecifiedTradePaymentTerms <'invoice> {
     /// `BT-20`: A textual description of the payment terms that apply to the amount due for payment (Including description of possible penalties).
     #[serde(rename="ram:Description", skip_serializing_if = "Option::is_none")]
-    pub description: Option<&'invoice str>,
+    pub description: Option<String>,
     /// `BT-9-00`: The date when the payment is due.
     #[serde(rename="ram:DueDateDateTime", skip_serializing_if = "Option::is_none")]
     pub due_date_time: Option<DueDateDateTime<'invoice>>,
+    /// `BT-89`: Reference of the mandate that allows the seller to collect the amount by direct debit.
+    #[serde(rename="ram:DirectDebitMandateID", skip_serializing_if = "Option::is_none")]
+    pub direct_debit_mandate_id: Option<&'invoice str>,
+    /// Early payment discount, only used in [`SpecificationLevel::Extended`].
+    /// Lower levels state the discount in [Self::description], see [`ApplicableTradePaymentDiscountTerms::to_skonto_string`].
+    #[serde(rename="ram:ApplicableTradePaymentDiscountTerms", skip_serializing_if = "Option::is_none")]
+    pub applicable_trade_payment_discount_terms: Option<ApplicableTradePaymentDiscountTerms>,
+}
+
+impl<'invoice> SpecifiedTradePaymentTerms<'invoice> {
+    pub fn new(description: Option<String>, due_date: Option<NaiveDate>) -> Self {
+        Self {
+            description,
+            due_date_time: due_date.map(DueDateDateTime::new),
+            ..Default::default()
+        }
+    }
+}
+
+/// Early payment discount (Skonto), e.g. 2% if paid within 10 days
+#[derive(Serialize, Clone, Debug)]
+pub struct ApplicableTradePaymentDiscountTerms {
+    /// Number of days after the invoice date in which the discount may be deducted
+    #[serde(rename="ram:BasisPeriodMeasure")]
+    pub basis_period_measure: BasisPeriodMeasure,
+    /// Amount the discount is calculated from. If not set, the discount applies to the amount due for payment
+    #[serde(rename="ram:BasisAmount", skip_serializing_if = "Option::is_none")]
+    pub basis_amount: Option<Amount>,
+    /// Discount in percent
+    #[serde(rename="ram:CalculationPercent", serialize_with="percent_format")]
+    pub calculation_percent: f64,
+    #[serde(rename="ram:ActualDiscountAmount", skip_serializing_if = "Option::is_none")]
+    pub actual_discount_amount: Option<Amount>,
+}
+
+impl ApplicableTradePaymentDiscountTerms {
+    /// The discount amount is not rounded until [Self::in_currency] is called with the invoice currency
+    pub fn new(days: u32, calculation_percent: f64, basis_amount: Option<f64>) -> Self {
+        Self {
+            basis_period_measure: BasisPeriodMeasure {
+                unit_code: "DAY",
+                value: days,
+            },
+            basis_amount: basis_amount.map(Amount::new),
+            calculation_percent,
+            actual_discount_amount: basis_amount.map(|amount| Amount::new(amount * calculation_percent / 100.0)),
+        }
+    }
+
+    /// Rounds the amounts to the minor units of the given currency
+    pub fn in_currency(mut self, currency_code: CurrencyCode) -> Self {
+        self.basis_amount = self.basis_amount.map(|amount| amount.in_currency(currency_code));
+        self.actual_discount_amount = self.actual_discount_amount.map(|amount| amount.in_currency(currency_code));
+        self
+    }
+
+    /// Representation in the payment terms description as agreed for ZUGFeRD and XRechnung,
+    /// e.g. `#SKONTO#TAGE=10#PROZENT=2.00#`
+    pub fn to_skonto_string(&self) -> String {
+        let mut skonto = format!(
+            "#SKONTO#TAGE={}#PROZENT={:.2}#",
+            self.basis_period_measure.value, self.calculation_percent
+        );
+        if let Some(basis_amount) = self.basis_amount {
+            skonto += &format!("BASISBETRAG={:.2}#", basis_amount.value);
+        }
+        skonto
+    }
+
+    /// Discount that may be deducted from the given amount due for payment, rounded to the minor units of the given currency
+    pub fn discount_amount(&self, due_payable_amount: f64, currency_code: CurrencyCode) -> f64 {
+        currency_code.round(self.basis_amount.map_or(due_payable_amount, |amount| amount.value) * self.calculation_percent / 100.0)
+    }
+
+    /// Amount that has to be paid if the payment is made within the discount period
+    pub fn discounted_amount(&self, due_payable_amount: f64, currency_code: CurrencyCode) -> f64 {
+        currency_code.round(due_payable_amount - self.discount_amount(due_payable_amount, currency_code))
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct BasisPeriodMeasure {
+    #[serde(rename="@unitCode")]
+    unit_code: &'static str,
+    #[serde(rename="$value")]
+    pub value: u32,
 }
 
 #[derive(Serialize, Clone, Debug)]
@@ -701,34 +1664,97 @@ pub struct DueDateDateTime<'invoice> {
     pub payment_due_date: DateTimeString<'invoice>,
 }
 
+impl<'invoice> DueDateDateTime<'invoice> {
+    pub fn new(date: NaiveDate) -> Self {
+        Self {
+            payment_due_date: DateTimeString::new(date),
+        }
+    }
+}
+
+/// Invoicing period (BG-14) on document level or invoice line period (BG-26) on line level
+#[derive(Serialize, Clone, Debug)]
+pub struct BillingSpecifiedPeriod<'invoice> {
+    /// BT-73, BT-134
+    #[serde(rename="ram:StartDateTime", skip_serializing_if = "Option::is_none")]
+    pub start_date_time: Option<PeriodDateTime<'invoice>>,
+    /// BT-74, BT-135
+    #[serde(rename="ram:EndDateTime", skip_serializing_if = "Option::is_none")]
+    pub end_date_time: Option<PeriodDateTime<'invoice>>,
+}
+
+impl<'invoice> BillingSpecifiedPeriod<'invoice> {
+    pub fn new(start_date: Option<NaiveDate>, end_date: Option<NaiveDate>) -> Self {
+        Self {
+            start_date_time: start_date.map(PeriodDateTime::new),
+            end_date_time: end_date.map(PeriodDateTime::new),
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Debug)]
+pub struct PeriodDateTime<'invoice> {
+    #[serde(rename="udt:DateTimeString")]
+    pub date_time_string: DateTimeString<'invoice>,
+}
+
+impl<'invoice> PeriodDateTime<'invoice> {
+    pub fn new(date: NaiveDate) -> Self {
+        Self {
+            date_time_string: DateTimeString::new(date),
+        }
+    }
+}
+
 /// `BG-22`: A group of business terms providing the monetary totals for the Invoice.
 #[derive(Serialize, Clone, Debug)]
 pub struct SpecifiedTradeSettlementHeaderMonetarySummation {
     /// `BT-106`: Sum of all Invoice line net amounts in the Invoice.
-    #[serde(rename="ram:LineTotalAmount", serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
-    pub line_total_amount: Option<f64>,
+    #[serde(rename="ram:LineTotalAmount", skip_serializing_if = "Option::is_none")]
+    pub line_total_amount: Option<Amount>,
     /// `BT-108`: Sum of all charges on document level in the Invoice.
-    #[serde(rename="ram:ChargeTotalAmount", serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
-    pub charge_total_amount: Option<f64>,
+    #[serde(rename="ram:ChargeTotalAmount", skip_serializing_if = "Option::is_none")]
+    pub charge_total_amount: Option<Amount>,
     /// `BT-107`: Sum of all allowances on document level in the Invoice.
-    #[serde(rename="ram:AllowanceTotalAmount", serialize_with="format_f64_option", skip_serializing_if = "Option::is_none")]
-    pub allowance_total_amount: Option<f64>,
+    #[serde(rename="ram:AllowanceTotalAmount", skip_serializing_if = "Option::is_none")]
+    pub allowance_total_amount: Option<Amount>,
     
 
     //Required for minimum specification
     
     /// `BT-109`: The total amount of the Invoice without VAT.
-    #[serde(rename="ram:TaxBasisTotalAmount",serialize_with="format_f64_option")]
-    pub tax_basis_total_amount: Option<f64>,
+    #[serde(rename="ram:TaxBasisTotalAmount")]
+    pub tax_basis_total_amount: Option<Amount>,
     /// `BT-110`: The total VAT amount for the Invoice.
     #[serde(rename="ram:TaxTotalAmount", skip_serializing_if = "Option::is_none")]
     pub tax_total_amount: Option<TaxTotalAmount>,
+    /// `BT-111`: The total VAT amount expressed in the VAT accounting currency (BT-6).
+    #[serde(rename="ram:TaxTotalAmount", skip_serializing_if = "Option::is_none")]
+    pub tax_total_amount_in_tax_currency: Option<TaxTotalAmount>,
     /// `BT-112`: The total amount of the Invoice with VAT.
-    #[serde(rename="ram:GrandTotalAmount",serialize_with="format_f64_option")]
-    pub grand_total_amount: Option<f64>,
+    #[serde(rename="ram:GrandTotalAmount")]
+    pub grand_total_amount: Option<Amount>,
     /// `BT-115`: The outstanding amount that is requested to be paid.
-    #[serde(rename="ram:DuePayableAmount",serialize_with="format_f64_option")]
-    pub due_payable_amount: Option<f64>,
+    #[serde(rename="ram:DuePayableAmount")]
+    pub due_payable_amount: Option<Amount>,
+}
+
+impl SpecifiedTradeSettlementHeaderMonetarySummation {
+    /// Rounds the amounts to the minor units of the given currency.
+    /// The total VAT amounts keep their own currency.
+    pub fn in_currency(mut self, currency_code: CurrencyCode) -> Self {
+        for amount in [
+            &mut self.line_total_amount,
+            &mut self.charge_total_amount,
+            &mut self.allowance_total_amount,
+            &mut self.tax_basis_total_amount,
+            &mut self.grand_total_amount,
+            &mut self.due_payable_amount,
+        ] {
+            *amount = amount.map(|amount| amount.in_currency(currency_code));
+        }
+        self
+    }
 }
 
 impl Default for SpecifiedTradeSettlementHeaderMonetarySummation {
@@ -739,20 +1765,40 @@ impl Default for SpecifiedTradeSettlementHeaderMonetarySummation {
             allowance_total_amount: None,
             tax_basis_total_amount: None,
             tax_total_amount: None,
+            tax_total_amount_in_tax_currency: None,
             grand_total_amount: None,
             due_payable_amount: None,
         }
     }
 }
 
-#[derive(Serialize, Clone, Debug)]
+#[derive(Clone, Debug)]
 pub struct TaxTotalAmount {
-    #[serde(rename="@currencyID")]
     pub currency_id: CurrencyCode,
-    #[serde(rename="$value",serialize_with="f64_format")]
     pub amount: f64,
 }
 
+// The amount is formatted with the minor units of its own currency, which may differ from the invoice currency
+impl Serialize for TaxTotalAmount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        #[derive(Serialize)]
+        struct FormattedTaxTotalAmount {
+            #[serde(rename="@currencyID")]
+            currency_id: CurrencyCode,
+            #[serde(rename="$value")]
+            amount: String,
+        }
+
+        FormattedTaxTotalAmount {
+            currency_id: self.currency_id,
+            amount: format!("{:.*}", self.currency_id.minor_units() as usize, self.amount),
+        }.serialize(serializer)
+    }
+}
+
 impl TaxTotalAmount {
     pub fn new(currency_id: CurrencyCode, amount: f64) -> Self {
         TaxTotalAmount {
@@ -761,3 +1807,48 @@ impl TaxTotalAmount {
         }
     }
 }
+
+/// Monetary amount that is written with the minor units of its currency, e.g. no decimals for JPY.
+///
+/// Amounts are created without currency and rounded to the invoice currency (BT-5)
+/// by [`InvoiceBuilder::build`](crate::InvoiceBuilder::build), see [Self::in_currency].
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub struct Amount {
+    pub value: f64,
+    /// Currency whose minor units the amount is rounded to, `None` until the currency is known
+    pub currency_code: Option<CurrencyCode>,
+}
+
+impl Amount {
+    pub fn new(value: f64) -> Self {
+        Self {
+            value,
+            currency_code: None,
+        }
+    }
+
+    /// Rounds the amount to the minor units of the given currency
+    pub fn in_currency(self, currency_code: CurrencyCode) -> Self {
+        Self {
+            value: currency_code.round(self.value),
+            currency_code: Some(currency_code),
+        }
+    }
+}
+
+impl From<f64> for Amount {
+    fn from(value: f64) -> Self {
+        Self::new(value)
+    }
+}
+
+// Amounts without currency are written with two decimals, the minor units of most currencies
+impl Serialize for Amount {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let minor_units = self.currency_code.map_or(2, |currency_code| currency_code.minor_units());
+        serializer.serialize_str(&format!("{:.*}", minor_units as usize, self.value))
+    }
+}
@@ -64,8 +64,16 @@ use serde::Serialize;
 pub mod components;
 
 pub use crate::components::enums::{
-    country_code::CountryCode, currency_code::CurrencyCode, invoice_type_code::InvoiceTypeCode,
-    specification_level::SpecificationLevel, vat_category_code::VATCategoryCode,
+    allowance_charge_reason_code::AllowanceChargeReasonCode,
+    charge_reason_code::ChargeReasonCode, country_code::CountryCode, currency_code::CurrencyCode,
+    electronic_address_scheme_code::ElectronicAddressSchemeCode,
+    identifier_scheme_code::IdentifierSchemeCode, invoice_type_code::InvoiceTypeCode,
+    mime_code::MimeCode,
+    payment_means_code::PaymentMeansCode,
+    referenced_document_type_code::ReferencedDocumentTypeCode,
+    specification_level::SpecificationLevel,
+    tax_registration_scheme_code::TaxRegistrationSchemeCode, unit_code::UnitCode,
+    vat_category_code::VATCategoryCode,
 };
 pub use components::business_rules::validate as validate_business_rules;
 pub use components::structs::*;
@@ -80,23 +88,56 @@ pub struct InvoiceBuilder<'invoice_builder> {
     document_notes: Option<Vec<IncludedNote>>,
     buyer_reference: Option<&'invoice_builder str>,
     sellers_name: Option<&'invoice_builder str>,
+    sellers_id: Vec<&'invoice_builder str>,
+    sellers_global_id: Vec<GlobalID<'invoice_builder>>,
     sellers_specified_legal_organization: Option<&'invoice_builder str>,
-    sellers_specified_tax_registration: Option<&'invoice_builder str>,
+    sellers_specified_legal_organization_scheme_id: Option<IdentifierSchemeCode>,
+    sellers_trading_business_name: Option<&'invoice_builder str>,
+    sellers_specified_tax_registration: Vec<SpecifiedTaxRegistration<'invoice_builder>>,
     buyers_name: Option<&'invoice_builder str>,
+    buyers_id: Option<&'invoice_builder str>,
+    buyers_global_id: Option<GlobalID<'invoice_builder>>,
     buyers_specified_legal_organization: Option<&'invoice_builder str>,
+    buyers_specified_legal_organization_scheme_id: Option<IdentifierSchemeCode>,
+    buyers_trading_business_name: Option<&'invoice_builder str>,
+    buyers_specified_tax_registration: Vec<SpecifiedTaxRegistration<'invoice_builder>>,
     buyers_order_specified_document: Option<&'invoice_builder str>,
+    sellers_order_referenced_document: Option<&'invoice_builder str>,
+    contract_referenced_document: Option<&'invoice_builder str>,
+    specified_procuring_project: Option<SpecifiedProcuringProject<'invoice_builder>>,
+    additional_referenced_documents: Vec<AdditionalReferencedDocument<'invoice_builder>>,
+    sellers_tax_representative_trade_party: Option<SellerTaxRepresentativeTradeParty<'invoice_builder>>,
+    payee_trade_party: Option<PayeeTradeParty<'invoice_builder>>,
     occurrence_date: Option<DateTimeString<'invoice_builder>>,
+    ship_to_trade_party: Option<ShipToTradeParty<'invoice_builder>>,
+    despatch_advice_referenced_document: Option<&'invoice_builder str>,
+    receiving_advice_referenced_document: Option<&'invoice_builder str>,
     invoice_currency_code: Option<CurrencyCode>,
+    tax_currency_code: Option<CurrencyCode>,
+    payment_reference: Option<&'invoice_builder str>,
+    creditor_reference_id: Option<&'invoice_builder str>,
+    specified_trade_settlement_payment_means:
+        Vec<SpecifiedTradeSettlementPaymentMeans<'invoice_builder>>,
     monetary_summation: SpecifiedTradeSettlementHeaderMonetarySummation,
+    monetary_summation_tax_total_amount: Option<f64>,
+    monetary_summation_tax_total_amount_in_tax_currency: Option<f64>,
 
     //Basic WL specification
     applicable_trade_tax: Option<ApplicableTradeTax<'invoice_builder>>,
     sellers_postal_trade_address: PostalTradeAddress<'invoice_builder>,
     buyers_postal_trade_address: PostalTradeAddress<'invoice_builder>,
+    sellers_defined_trade_contact: Option<DefinedTradeContact<'invoice_builder>>,
+    buyers_defined_trade_contact: Option<DefinedTradeContact<'invoice_builder>>,
+    sellers_uri_universal_communication: Option<URIUniversalCommunication<'invoice_builder>>,
+    buyers_uri_universal_communication: Option<URIUniversalCommunication<'invoice_builder>>,
     monetary_summation_line_total_amount: Option<f64>,
     monetary_summation_charge_total_amount: Option<f64>,
     monetary_summation_allowance_total_amount: Option<f64>,
     specified_trade_payment_terms: Option<SpecifiedTradePaymentTerms<'invoice_builder>>,
+    payment_discount_terms: Vec<ApplicableTradePaymentDiscountTerms>,
+    additional_specified_trade_payment_terms: Vec<SpecifiedTradePaymentTerms<'invoice_builder>>,
+    specified_trade_allowance_charges: Vec<SpecifiedTradeAllowanceCharge<'invoice_builder>>,
+    billing_specified_period: Option<BillingSpecifiedPeriod<'invoice_builder>>,
 
     //Basic specification
     included_supply_chain_trade_line_items: Vec<IncludedSupplyChainTradeLineItem<'invoice_builder>>,
@@ -112,22 +153,54 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
             document_notes: None,
             buyer_reference: None,
             sellers_name: None,
+            sellers_id: Vec::new(),
+            sellers_global_id: Vec::new(),
             sellers_specified_legal_organization: None,
-            sellers_specified_tax_registration: None,
+            sellers_specified_legal_organization_scheme_id: None,
+            sellers_trading_business_name: None,
+            sellers_specified_tax_registration: Vec::new(),
             buyers_name: None,
+            buyers_id: None,
+            buyers_global_id: None,
             buyers_specified_legal_organization: None,
+            buyers_specified_legal_organization_scheme_id: None,
+            buyers_trading_business_name: None,
+            buyers_specified_tax_registration: Vec::new(),
             buyers_order_specified_document: None,
+            sellers_order_referenced_document: None,
+            contract_referenced_document: None,
+            specified_procuring_project: None,
+            additional_referenced_documents: Vec::new(),
+            sellers_tax_representative_trade_party: None,
+            payee_trade_party: None,
             occurrence_date: None,
+            ship_to_trade_party: None,
+            despatch_advice_referenced_document: None,
+            receiving_advice_referenced_document: None,
             invoice_currency_code: None,
+            tax_currency_code: None,
+            payment_reference: None,
+            creditor_reference_id: None,
+            specified_trade_settlement_payment_means: Vec::new(),
             monetary_summation: SpecifiedTradeSettlementHeaderMonetarySummation::default(),
+            monetary_summation_tax_total_amount: None,
+            monetary_summation_tax_total_amount_in_tax_currency: None,
 
             applicable_trade_tax: None,
             sellers_postal_trade_address: PostalTradeAddress::default(),
             buyers_postal_trade_address: PostalTradeAddress::default(),
+            sellers_defined_trade_contact: None,
+            buyers_defined_trade_contact: None,
+            sellers_uri_universal_communication: None,
+            buyers_uri_universal_communication: None,
             monetary_summation_line_total_amount: None,
             monetary_summation_charge_total_amount: None,
             monetary_summation_allowance_total_amount: None,
             specified_trade_payment_terms: None,
+            payment_discount_terms: Vec::new(),
+            additional_specified_trade_payment_terms: Vec::new(),
+            specified_trade_allowance_charges: Vec::new(),
+            billing_specified_period: None,
 
             included_supply_chain_trade_line_items: Vec::new(),
         }
@@ -155,20 +228,23 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
         if self.sellers_postal_trade_address.country_id == CountryCode::NotSet {
             error_text += "Seller's postal trade address country code not set\n";
         }
-        if self.sellers_specified_tax_registration.is_none() {
+        if self.sellers_specified_tax_registration.is_empty()
+            && self
+                .sellers_tax_representative_trade_party
+                .as_ref()
+                .and_then(|tax_representative| tax_representative.specified_tax_registration.as_ref())
+                .is_none()
+        {
             error_text += "Seller's specified tax registration not set\n";
         }
         if self.buyers_name.is_none() {
             error_text += "Buyer's name not set\n";
         }
-        if self.buyers_order_specified_document.is_none() {
-            error_text += "Buyer's order specified document not set\n";
-        }
         if self.monetary_summation.tax_basis_total_amount.is_none() {
             error_text +=
                 "Specified trade settlement monetary summation: Tax basis total amount not set\n";
         }
-        if self.monetary_summation.tax_total_amount.is_none() {
+        if self.monetary_summation_tax_total_amount.is_none() {
             error_text +=
                 "Specified trade settlement monetary summation: Tax total amount not set\n";
         }
@@ -184,6 +260,40 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
         if self.invoice_currency_code.is_none() {
             error_text += "Invoice currency code not set\n";
         }
+        if self.monetary_summation_tax_total_amount_in_tax_currency.is_some()
+            && self.tax_currency_code.is_none()
+        {
+            error_text += "Tax currency code not set\n";
+        }
+
+        let mut direct_debit_payment_means = self
+            .specified_trade_settlement_payment_means
+            .iter()
+            .filter(|payment_means| {
+                matches!(
+                    payment_means.type_code,
+                    PaymentMeansCode::DirectDebit | PaymentMeansCode::SepaDirectDebit
+                )
+            })
+            .peekable();
+        if direct_debit_payment_means.peek().is_some() {
+            if self
+                .specified_trade_payment_terms
+                .as_ref()
+                .and_then(|payment_terms| payment_terms.direct_debit_mandate_id)
+                .is_none()
+            {
+                error_text += "Direct debit: Mandate reference not set\n";
+            }
+            if self.creditor_reference_id.is_none() {
+                error_text += "Direct debit: Creditor reference ID not set\n";
+            }
+        }
+        if direct_debit_payment_means
+            .any(|payment_means| payment_means.payer_party_debtor_financial_account.is_none())
+        {
+            error_text += "Direct debit: Debited account not set\n";
+        }
 
         // Additional checks for different specification levels
         if specification_level >= SpecificationLevel::BasicWithoutLines {
@@ -242,7 +352,10 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
                 error_text += "Specified trade payment terms: Due date time not set\n";
             }
 
-            if self.monetary_summation.line_total_amount.is_none() {
+            // Calculated by build if line items were added
+            if self.monetary_summation.line_total_amount.is_none()
+                && self.included_supply_chain_trade_line_items.is_empty()
+            {
                 error_text +=
                     "Specified trade settlement monetary summation: Line total amount not set\n";
             }
@@ -263,6 +376,156 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
             }
         }
 
+        // BT-126 has to be unique within the invoice
+        for (index, line) in self.included_supply_chain_trade_line_items.iter().enumerate() {
+            let line_id = &line.associated_document_line_document.line_id;
+            if self.included_supply_chain_trade_line_items[..index]
+                .iter()
+                .any(|previous_line| &previous_line.associated_document_line_document.line_id == line_id)
+            {
+                error_text += &format!("Line item: Line ID {} is used more than once\n", line_id);
+            }
+        }
+
+        if specification_level < SpecificationLevel::En16931
+            && self.included_supply_chain_trade_line_items.iter().any(|line| {
+                let product = &line.specified_trade_product;
+                product.seller_assigned_id.is_some()
+                    || product.buyer_assigned_id.is_some()
+                    || !product.applicable_product_characteristic.is_empty()
+                    || !product.designated_product_classification.is_empty()
+                    || product.origin_trade_country.is_some()
+            })
+        {
+            error_text += "Line item: Item identifiers, classification, origin and attributes are only allowed from EN 16931 specification\n";
+        }
+
+        if specification_level < SpecificationLevel::En16931
+            && self.included_supply_chain_trade_line_items.iter().any(|line| {
+                let settlement = &line.specified_line_trade_settlement;
+                line.specified_line_trade_agreement.buyer_order_referenced_document.is_some()
+                    || settlement.additional_referenced_document.is_some()
+                    || settlement.receivable_specified_trade_accounting_account.is_some()
+            })
+        {
+            error_text += "Line item: Order line reference, object identifier and accounting reference are only allowed from EN 16931 specification\n";
+        }
+
+        if specification_level == SpecificationLevel::XRechnung {
+            if let Some(sellers_defined_trade_contact) = self.sellers_defined_trade_contact.as_ref() {
+                if sellers_defined_trade_contact.person_name.is_none()
+                    && sellers_defined_trade_contact.department_name.is_none()
+                {
+                    error_text += "Seller's contact: Person or department name not set\n";
+                }
+                if sellers_defined_trade_contact
+                    .telephone_universal_communication
+                    .is_none()
+                {
+                    error_text += "Seller's contact: Telephone number not set\n";
+                }
+                if sellers_defined_trade_contact
+                    .email_uri_universal_communication
+                    .is_none()
+                {
+                    error_text += "Seller's contact: Email address not set\n";
+                }
+            } else {
+                error_text += "Seller's contact not set\n";
+            }
+            if self.specified_trade_settlement_payment_means.is_empty() {
+                error_text += "Payment instructions not set\n";
+            }
+            if self.sellers_uri_universal_communication.is_none() {
+                error_text += "Seller's electronic address not set\n";
+            }
+            if self.buyers_uri_universal_communication.is_none() {
+                error_text += "Buyer's electronic address not set\n";
+            }
+        }
+
+        if specification_level == SpecificationLevel::Minimum
+            && !self.specified_trade_allowance_charges.is_empty()
+        {
+            error_text += "Document level allowances and charges are not allowed in Minimum specification\n";
+        }
+
+        if specification_level == SpecificationLevel::Minimum && self.billing_specified_period.is_some() {
+            error_text += "Invoicing period is not allowed in Minimum specification\n";
+        }
+
+        if specification_level == SpecificationLevel::Minimum {
+            if self.ship_to_trade_party.is_some() {
+                error_text += "Ship to trade party is not allowed in Minimum specification\n";
+            }
+            if self.despatch_advice_referenced_document.is_some() {
+                error_text += "Despatch advice reference is not allowed in Minimum specification\n";
+            }
+        }
+
+        if specification_level == SpecificationLevel::Minimum {
+            if self.payee_trade_party.is_some() {
+                error_text += "Payee trade party is not allowed in Minimum specification\n";
+            }
+            if self.sellers_tax_representative_trade_party.is_some() {
+                error_text += "Seller's tax representative is not allowed in Minimum specification\n";
+            }
+            if self
+                .specified_trade_payment_terms
+                .as_ref()
+                .is_some_and(|payment_terms| {
+                    payment_terms.description.is_some() || payment_terms.due_date_time.is_some()
+                })
+                || !self.payment_discount_terms.is_empty()
+            {
+                error_text += "Payment terms are not allowed in Minimum specification\n";
+            }
+        }
+
+        if specification_level < SpecificationLevel::En16931
+            && self.receiving_advice_referenced_document.is_some()
+        {
+            error_text += "Receiving advice reference is only allowed from EN 16931 specification\n";
+        }
+
+        if specification_level == SpecificationLevel::Minimum && self.contract_referenced_document.is_some() {
+            error_text += "Contract reference is not allowed in Minimum specification\n";
+        }
+
+        if specification_level < SpecificationLevel::En16931 {
+            if self.sellers_order_referenced_document.is_some() {
+                error_text += "Seller's order reference is only allowed from EN 16931 specification\n";
+            }
+            if self.specified_procuring_project.is_some() {
+                error_text += "Project reference is only allowed from EN 16931 specification\n";
+            }
+            if self.additional_referenced_documents.iter()
+                .any(|document| document.type_code != ReferencedDocumentTypeCode::RelatedDocument)
+            {
+                error_text += "Tender or lot reference and invoiced object identifier are only allowed from EN 16931 specification\n";
+            }
+            if self.additional_referenced_documents.iter()
+                .any(|document| document.type_code == ReferencedDocumentTypeCode::RelatedDocument)
+            {
+                error_text += "Supporting documents are only allowed from EN 16931 specification\n";
+            }
+        }
+
+        if self
+            .ship_to_trade_party
+            .as_ref()
+            .and_then(|ship_to_trade_party| ship_to_trade_party.postal_trade_address.as_ref())
+            .is_some_and(|postal_trade_address| postal_trade_address.country_id == CountryCode::NotSet)
+        {
+            error_text += "Ship to postal trade address: Country code not set\n";
+        }
+
+        if specification_level < SpecificationLevel::Extended
+            && !self.additional_specified_trade_payment_terms.is_empty()
+        {
+            error_text += "Multiple payment terms are only allowed in Extended specification\n";
+        }
+
         if specification_level >= SpecificationLevel::Extended {
             if self.buyer_reference.is_none() {
                 error_text += "Buyer reference not set\n";
@@ -363,6 +626,31 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
         self
     }
 
+    /// Adds an identifier of the seller, e.g. the supplier number assigned by the buyer
+    ///
+    /// BT-29
+    ///
+    /// ram:SellerTradeParty -> ram:ID
+    pub fn add_sellers_id<T: Into<&'invoice_builder str>>(&mut self, id: T) -> &mut Self {
+        self.sellers_id.push(id.into());
+        self
+    }
+
+    /// Adds an identifier of the seller with a registered scheme, e.g. a GLN with [`IdentifierSchemeCode::EAN_Location_Code`]
+    ///
+    /// BT-29-0, BT-29-1
+    ///
+    /// ram:SellerTradeParty -> ram:GlobalID
+    pub fn add_sellers_global_id<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: IdentifierSchemeCode,
+        global_id: T,
+    ) -> &mut Self {
+        self.sellers_global_id
+            .push(GlobalID::new(scheme_id, global_id.into()));
+        self
+    }
+
     /// An identifier issued by an official registrar that identifies the Seller as a legal entity or person.
     ///
     /// BT-30
@@ -377,6 +665,33 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
         self
     }
 
+    /// Registrar of the seller's legal registration identifier, e.g. [`IdentifierSchemeCode::SIRENE`].
+    /// If not set, the identifier is written without a scheme.
+    ///
+    /// BT-30-1
+    ///
+    /// ram:SpecifiedLegalOrganization -> ram:ID/@schemeID
+    pub fn set_sellers_specified_legal_organization_scheme_id(
+        &mut self,
+        scheme_id: IdentifierSchemeCode,
+    ) -> &mut Self {
+        self.sellers_specified_legal_organization_scheme_id = Some(scheme_id);
+        self
+    }
+
+    /// Name under which the seller is known, if different from the seller's name
+    ///
+    /// BT-28
+    ///
+    /// ram:SpecifiedLegalOrganization -> ram:TradingBusinessName
+    pub fn set_sellers_trading_business_name<T: Into<&'invoice_builder str>>(
+        &mut self,
+        trading_business_name: T,
+    ) -> &mut Self {
+        self.sellers_trading_business_name = Some(trading_business_name.into());
+        self
+    }
+
     /// Postal code, zip code or similar
     ///
     /// BT-38
@@ -443,11 +758,118 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
         self
     }
 
+    /// Electronic address of the seller, e.g. an email address with [`ElectronicAddressSchemeCode::ElectronicMail`]
+    ///
+    /// BT-34, BT-34-1
+    ///
+    /// ram:URIUniversalCommunication -> ram:URIID
+    pub fn set_sellers_uri_universal_communication<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: ElectronicAddressSchemeCode,
+        uri: T,
+    ) -> &mut Self {
+        self.sellers_uri_universal_communication =
+            Some(URIUniversalCommunication::new(scheme_id, uri.into()));
+        self
+    }
+
+    /// VAT identifier of the seller, replaces a previously set one.
+    /// Use [Self::add_sellers_specified_tax_registration] for a local tax number.
+    ///
+    /// BT-31
+    ///
+    /// ram:SpecifiedTaxRegistration -> ram:ID[@schemeID='VA']
     pub fn set_sellers_specified_tax_registration<T: Into<&'invoice_builder str>>(
         &mut self,
         sellers_specified_tax_registration: T,
     ) -> &mut Self {
-        self.sellers_specified_tax_registration = Some(sellers_specified_tax_registration.into());
+        self.sellers_specified_tax_registration
+            .retain(|registration| registration.id.scheme_id != TaxRegistrationSchemeCode::VatIdentifier);
+        self.add_sellers_specified_tax_registration(
+            TaxRegistrationSchemeCode::VatIdentifier,
+            sellers_specified_tax_registration,
+        )
+    }
+
+    /// Adds a tax registration of the seller, e.g. the VAT identifier
+    /// with [`TaxRegistrationSchemeCode::VatIdentifier`] or the local tax number
+    /// with [`TaxRegistrationSchemeCode::FiscalNumber`]
+    ///
+    /// BT-31, BT-32
+    ///
+    /// ram:SpecifiedTaxRegistration -> ram:ID
+    pub fn add_sellers_specified_tax_registration<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: TaxRegistrationSchemeCode,
+        id: T,
+    ) -> &mut Self {
+        self.sellers_specified_tax_registration
+            .push(SpecifiedTaxRegistration::new(scheme_id, id.into()));
+        self
+    }
+
+    /// Removes all tax registrations of the seller, e.g. for a seller without an EU VAT
+    /// registration that invoices through its tax representative
+    ///
+    /// BT-31, BT-32
+    pub fn clear_sellers_specified_tax_registration(&mut self) -> &mut Self {
+        self.sellers_specified_tax_registration.clear();
+        self
+    }
+
+    /// Name of the contact person at the seller
+    ///
+    /// BT-41
+    pub fn set_sellers_defined_trade_contact_person_name<T: Into<&'invoice_builder str>>(
+        &mut self,
+        person_name: T,
+    ) -> &mut Self {
+        self.sellers_defined_trade_contact
+            .get_or_insert_with(DefinedTradeContact::default)
+            .person_name = Some(person_name.into());
+        self
+    }
+
+    /// Department of the contact point at the seller
+    ///
+    /// BT-41-0
+    pub fn set_sellers_defined_trade_contact_department_name<T: Into<&'invoice_builder str>>(
+        &mut self,
+        department_name: T,
+    ) -> &mut Self {
+        self.sellers_defined_trade_contact
+            .get_or_insert_with(DefinedTradeContact::default)
+            .department_name = Some(department_name.into());
+        self
+    }
+
+    /// Phone number of the contact point at the seller
+    ///
+    /// BT-42
+    pub fn set_sellers_defined_trade_contact_telephone<T: Into<&'invoice_builder str>>(
+        &mut self,
+        complete_number: T,
+    ) -> &mut Self {
+        self.sellers_defined_trade_contact
+            .get_or_insert_with(DefinedTradeContact::default)
+            .telephone_universal_communication = Some(TelephoneUniversalCommunication {
+            complete_number: complete_number.into(),
+        });
+        self
+    }
+
+    /// Email address of the contact point at the seller
+    ///
+    /// BT-43
+    pub fn set_sellers_defined_trade_contact_email<T: Into<&'invoice_builder str>>(
+        &mut self,
+        email: T,
+    ) -> &mut Self {
+        self.sellers_defined_trade_contact
+            .get_or_insert_with(DefinedTradeContact::default)
+            .email_uri_universal_communication = Some(EmailURIUniversalCommunication {
+            uriid: email.into(),
+        });
         self
     }
 
@@ -456,6 +878,32 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
         self
     }
 
+    /// Identifier of the buyer, e.g. the customer number assigned by the seller, replaces a previously set one.
+    /// Unlike the seller identifiers (BT-29, see [Self::add_sellers_id]), BT-46 occurs at most once.
+    ///
+    /// BT-46
+    ///
+    /// ram:BuyerTradeParty -> ram:ID
+    pub fn set_buyers_id<T: Into<&'invoice_builder str>>(&mut self, id: T) -> &mut Self {
+        self.buyers_id = Some(id.into());
+        self
+    }
+
+    /// Identifier of the buyer with a registered scheme, e.g. a GLN with [`IdentifierSchemeCode::EAN_Location_Code`],
+    /// replaces a previously set one. BT-46 occurs at most once, see [Self::set_buyers_id].
+    ///
+    /// BT-46-0, BT-46-1
+    ///
+    /// ram:BuyerTradeParty -> ram:GlobalID
+    pub fn set_buyers_global_id<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: IdentifierSchemeCode,
+        global_id: T,
+    ) -> &mut Self {
+        self.buyers_global_id = Some(GlobalID::new(scheme_id, global_id.into()));
+        self
+    }
+
     /// An identifier issued by an official registrar that identifies the Buyer as a legal entity or person.
     ///
     /// BT-47
@@ -469,6 +917,33 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
         self
     }
 
+    /// Registrar of the buyer's legal registration identifier, e.g. [`IdentifierSchemeCode::SIRENE`].
+    /// If not set, the identifier is written without a scheme.
+    ///
+    /// BT-47-1
+    ///
+    /// ram:SpecifiedLegalOrganization -> ram:ID/@schemeID
+    pub fn set_buyers_specified_legal_organization_scheme_id(
+        &mut self,
+        scheme_id: IdentifierSchemeCode,
+    ) -> &mut Self {
+        self.buyers_specified_legal_organization_scheme_id = Some(scheme_id);
+        self
+    }
+
+    /// Name under which the buyer is known, if different from the buyer's name
+    ///
+    /// BT-45
+    ///
+    /// ram:SpecifiedLegalOrganization -> ram:TradingBusinessName
+    pub fn set_buyers_trading_business_name<T: Into<&'invoice_builder str>>(
+        &mut self,
+        trading_business_name: T,
+    ) -> &mut Self {
+        self.buyers_trading_business_name = Some(trading_business_name.into());
+        self
+    }
+
     /// Postal code, zip code or similar
     ///
     /// BT-53
@@ -537,160 +1012,877 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
         self
     }
 
-    /// ## Buyer Order Referenced Document
-    /// An identifier of a referenced purchase order, issued by the Buyer.
+    /// Name of the contact person at the buyer
     ///
-    /// /ram:BuyerOrderReferencedDocument
-    pub fn set_buyers_order_specified_document<T: Into<&'invoice_builder str>>(
+    /// BT-56
+    pub fn set_buyers_defined_trade_contact_person_name<T: Into<&'invoice_builder str>>(
         &mut self,
-        buyers_order_specified_document: T,
+        person_name: T,
     ) -> &mut Self {
-        self.buyers_order_specified_document = Some(buyers_order_specified_document.into());
+        self.buyers_defined_trade_contact
+            .get_or_insert_with(DefinedTradeContact::default)
+            .person_name = Some(person_name.into());
         self
     }
 
-    /// Service date, date when the service was delivered
+    /// Department of the contact point at the buyer
     ///
-    /// BT-72
-    pub fn set_occurrence_date(&mut self, date: NaiveDate) -> &mut Self {
-        self.occurrence_date = Some(DateTimeString::new(date));
+    /// BT-56-0
+    pub fn set_buyers_defined_trade_contact_department_name<T: Into<&'invoice_builder str>>(
+        &mut self,
+        department_name: T,
+    ) -> &mut Self {
+        self.buyers_defined_trade_contact
+            .get_or_insert_with(DefinedTradeContact::default)
+            .department_name = Some(department_name.into());
         self
     }
 
-    /// Tax amount that needs to be paid.
-    /// Calculated by multiplying the net total by the tax percentage
+    /// Phone number of the contact point at the buyer
     ///
-    /// BT-117
-    pub fn set_applicable_trade_tax_calculated_amount(&mut self, amount: f64) -> &mut Self {
-        if let Some(applicable_trade_tax) = self.applicable_trade_tax.as_mut() {
-            applicable_trade_tax.calculated_amount = Some(amount);
-        } else {
-            let mut new_struct = ApplicableTradeTax::default();
-            new_struct.calculated_amount = Some(amount);
-            self.applicable_trade_tax = Some(new_struct);
-        }
-
+    /// BT-57
+    pub fn set_buyers_defined_trade_contact_telephone<T: Into<&'invoice_builder str>>(
+        &mut self,
+        complete_number: T,
+    ) -> &mut Self {
+        self.buyers_defined_trade_contact
+            .get_or_insert_with(DefinedTradeContact::default)
+            .telephone_universal_communication = Some(TelephoneUniversalCommunication {
+            complete_number: complete_number.into(),
+        });
         self
     }
-    /// Sum of all net amounts of a single tax category
-    ///
-    /// BT-116
-    pub fn set_applicable_trade_tax_basis_amount(&mut self, amount: f64) -> &mut Self {
-        if let Some(applicable_trade_tax) = self.applicable_trade_tax.as_mut() {
-            applicable_trade_tax.basis_amount = Some(amount);
-        } else {
-            let mut new_struct = ApplicableTradeTax::default();
-            new_struct.basis_amount = Some(amount);
-            self.applicable_trade_tax = Some(new_struct);
-        }
 
+    /// Email address of the contact point at the buyer
+    ///
+    /// BT-58
+    pub fn set_buyers_defined_trade_contact_email<T: Into<&'invoice_builder str>>(
+        &mut self,
+        email: T,
+    ) -> &mut Self {
+        self.buyers_defined_trade_contact
+            .get_or_insert_with(DefinedTradeContact::default)
+            .email_uri_universal_communication = Some(EmailURIUniversalCommunication {
+            uriid: email.into(),
+        });
         self
     }
 
-    /// Category of the VAT that is applied. Choose from [`VATCategoryCode`]
+    /// Electronic address of the buyer, e.g. a Leitweg-ID with [`ElectronicAddressSchemeCode::LeitwegId`]
     ///
-    /// BT-118
-    pub fn set_applicable_trade_tax_category_code(&mut self, code: VATCategoryCode) -> &mut Self {
-        if let Some(applicable_trade_tax) = self.applicable_trade_tax.as_mut() {
-            applicable_trade_tax.category_code = code;
-        } else {
-            let mut new_struct = ApplicableTradeTax::default();
-            new_struct.category_code = code;
-            self.applicable_trade_tax = Some(new_struct);
-        }
-
-        self
-    }
-    /// VAT Percentage for the given [`VATCategoryCode`]
+    /// BT-49, BT-49-1
     ///
-    /// BT-119
-    pub fn set_applicable_trade_tax_rate_applicable_percent(&mut self, amount: f64) -> &mut Self {
-        if let Some(applicable_trade_tax) = self.applicable_trade_tax.as_mut() {
-            applicable_trade_tax.rate_applicable_percent = Some(amount);
-        } else {
-            let mut new_struct = ApplicableTradeTax::default();
-            new_struct.rate_applicable_percent = Some(amount);
-            self.applicable_trade_tax = Some(new_struct);
-        }
-
-        self
-    }
-
-    pub fn set_specified_trade_payment_terms_due_date(&mut self, date: NaiveDate) -> &mut Self {
-        let due_date_time = DueDateDateTime {
-            payment_due_date: DateTimeString::new(date),
-        };
-        if let Some(specified_trade_payment_terms) = self.specified_trade_payment_terms.as_mut() {
-            specified_trade_payment_terms.due_date_time = Some(due_date_time);
-        } else {
-            let new_struct = SpecifiedTradePaymentTerms {
-                description: None,
-                due_date_time: Some(due_date_time),
-            };
-
-            self.specified_trade_payment_terms = Some(new_struct);
-        }
-
-        self
-    }
-
-    pub fn set_invoice_currency_code(&mut self, invoice_currency_code: CurrencyCode) -> &mut Self {
-        self.invoice_currency_code = Some(invoice_currency_code);
+    /// ram:URIUniversalCommunication -> ram:URIID
+    pub fn set_buyers_uri_universal_communication<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: ElectronicAddressSchemeCode,
+        uri: T,
+    ) -> &mut Self {
+        self.buyers_uri_universal_communication =
+            Some(URIUniversalCommunication::new(scheme_id, uri.into()));
         self
     }
 
-    /// Sum of all net amounts
+    /// VAT identifier of the buyer, replaces a previously set one.
+    /// Required for reverse charge and intra-community supplies.
     ///
-    /// BT-106
-    pub fn set_monetary_summation_line_total_amount(&mut self, amount: f64) -> &mut Self {
-        self.monetary_summation.line_total_amount = Some(amount);
-        self
+    /// BT-48
+    ///
+    /// ram:SpecifiedTaxRegistration -> ram:ID[@schemeID='VA']
+    pub fn set_buyers_specified_tax_registration<T: Into<&'invoice_builder str>>(
+        &mut self,
+        buyers_specified_tax_registration: T,
+    ) -> &mut Self {
+        self.buyers_specified_tax_registration
+            .retain(|registration| registration.id.scheme_id != TaxRegistrationSchemeCode::VatIdentifier);
+        self.add_buyers_specified_tax_registration(
+            TaxRegistrationSchemeCode::VatIdentifier,
+            buyers_specified_tax_registration,
+        )
     }
 
-    /// Surcharge on document level. Surcharges on item level are contained in their net amounts
+    /// Adds a tax registration of the buyer
     ///
-    /// BT-108
-    pub fn set_monetary_summation_charge_total_amount(&mut self, amount: f64) -> &mut Self {
-        self.monetary_summation.charge_total_amount = Some(amount);
-        self
-    }
-    /// Deductions on document level. Deductions on item level are contained in their net amounts
+    /// BT-48
     ///
-    /// BT-107
-    pub fn set_monetary_summation_allowance_total_amount(&mut self, amount: f64) -> &mut Self {
-        self.monetary_summation.allowance_total_amount = Some(amount);
+    /// ram:SpecifiedTaxRegistration -> ram:ID
+    pub fn add_buyers_specified_tax_registration<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: TaxRegistrationSchemeCode,
+        id: T,
+    ) -> &mut Self {
+        self.buyers_specified_tax_registration
+            .push(SpecifiedTaxRegistration::new(scheme_id, id.into()));
         self
     }
 
-    /// Invoice amoiunt without VAT. Calculated by summing the net amounts
-    /// minus document surcharge [Self::set_monetary_summation_charge_total_amount]
-    /// plus document deductions [Self::set_monetary_summation_allowance_total_amount]
+    /// Name of the seller's tax representative, e.g. a fiscal representative
     ///
-    /// BT-109
-    pub fn set_monetary_summation_tax_basis_total_amount(&mut self, amount: f64) -> &mut Self {
-        self.monetary_summation.tax_basis_total_amount = Some(amount);
+    /// BT-62
+    pub fn set_sellers_tax_representative_name<T: Into<&'invoice_builder str>>(
+        &mut self,
+        name: T,
+    ) -> &mut Self {
+        self.sellers_tax_representative_trade_party
+            .get_or_insert_with(Default::default)
+            .name = Some(name.into());
         self
     }
 
-    /// Tax amount for the full invoice in the country of the seller, calculated from tax percentage and net sum
+    /// VAT identifier of the seller's tax representative
     ///
-    /// BT-110
-    pub fn set_monetary_summation_tax_total_amount(&mut self, amount: f64) -> &mut Self {
-        self.monetary_summation.tax_total_amount = Some(TaxTotalAmount::new(
-            self.invoice_currency_code
-                .expect("Invoice currency not set."),
-            amount,
+    /// BT-63
+    ///
+    /// ram:SpecifiedTaxRegistration -> ram:ID[@schemeID='VA']
+    pub fn set_sellers_tax_representative_specified_tax_registration<T: Into<&'invoice_builder str>>(
+        &mut self,
+        id: T,
+    ) -> &mut Self {
+        self.sellers_tax_representative_trade_party
+            .get_or_insert_with(Default::default)
+            .specified_tax_registration = Some(SpecifiedTaxRegistration::new(
+            TaxRegistrationSchemeCode::VatIdentifier,
+            id.into(),
         ));
         self
     }
 
-    /// Gross invoice amount
+    /// Address of the seller's tax representative, created on first use of any of the
+    /// `set_sellers_tax_representative_postal_trade_address_*` setters
+    ///
+    /// BG-12
+    fn sellers_tax_representative_postal_trade_address(&mut self) -> &mut PostalTradeAddress<'invoice_builder> {
+        self.sellers_tax_representative_trade_party
+            .get_or_insert_with(Default::default)
+            .postal_trade_address
+            .get_or_insert_with(Default::default)
+    }
+
+    /// BT-67
+    pub fn set_sellers_tax_representative_postal_trade_address_postcode_code<T: Into<&'invoice_builder str>>(
+        &mut self,
+        postcode_code: T,
+    ) -> &mut Self {
+        self.sellers_tax_representative_postal_trade_address().postcode_code = Some(postcode_code.into());
+        self
+    }
+
+    /// BT-64
+    pub fn set_sellers_tax_representative_postal_trade_address_line_one<T: Into<&'invoice_builder str>>(
+        &mut self,
+        line: T,
+    ) -> &mut Self {
+        self.sellers_tax_representative_postal_trade_address().line_one = Some(line.into());
+        self
+    }
+
+    /// BT-65
+    pub fn set_sellers_tax_representative_postal_trade_address_line_two<T: Into<&'invoice_builder str>>(
+        &mut self,
+        line: T,
+    ) -> &mut Self {
+        self.sellers_tax_representative_postal_trade_address().line_two = Some(line.into());
+        self
+    }
+
+    /// BT-164
+    pub fn set_sellers_tax_representative_postal_trade_address_line_three<T: Into<&'invoice_builder str>>(
+        &mut self,
+        line: T,
+    ) -> &mut Self {
+        self.sellers_tax_representative_postal_trade_address().line_three = Some(line.into());
+        self
+    }
+
+    /// BT-66
+    pub fn set_sellers_tax_representative_postal_trade_address_city_name<T: Into<&'invoice_builder str>>(
+        &mut self,
+        city_name: T,
+    ) -> &mut Self {
+        self.sellers_tax_representative_postal_trade_address().city_name = Some(city_name.into());
+        self
+    }
+
+    /// BT-69
+    pub fn set_sellers_tax_representative_postal_trade_address_country_code(
+        &mut self,
+        country_code: CountryCode,
+    ) -> &mut Self {
+        self.sellers_tax_representative_postal_trade_address().country_id = country_code;
+        self
+    }
+
+    /// Name of the party receiving the payment, if it differs from the seller
+    ///
+    /// BT-59
+    pub fn set_payee_name<T: Into<&'invoice_builder str>>(&mut self, name: T) -> &mut Self {
+        self.payee_trade_party
+            .get_or_insert_with(Default::default)
+            .name = Some(name.into());
+        self
+    }
+
+    /// Identifier of the payee
+    ///
+    /// BT-60
+    pub fn set_payee_id<T: Into<&'invoice_builder str>>(&mut self, id: T) -> &mut Self {
+        self.payee_trade_party
+            .get_or_insert_with(Default::default)
+            .id = Some(id.into());
+        self
+    }
+
+    /// Identifier of the payee with a registered scheme, e.g. a GLN with [`IdentifierSchemeCode::EAN_Location_Code`]
+    ///
+    /// BT-60-0, BT-60-1
+    pub fn set_payee_global_id<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: IdentifierSchemeCode,
+        global_id: T,
+    ) -> &mut Self {
+        self.payee_trade_party
+            .get_or_insert_with(Default::default)
+            .global_id = Some(GlobalID::new(scheme_id, global_id.into()));
+        self
+    }
+
+    /// Legal registration identifier of the payee, optionally with its registrar, e.g. [`IdentifierSchemeCode::SIRENE`]
+    ///
+    /// BT-61, BT-61-1
+    ///
+    /// ram:SpecifiedLegalOrganization -> ram:ID
+    pub fn set_payee_specified_legal_organization<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: Option<IdentifierSchemeCode>,
+        id: T,
+    ) -> &mut Self {
+        self.payee_trade_party
+            .get_or_insert_with(Default::default)
+            .specified_legal_organization = Some(SpecifiedLegalOrganization {
+            id: Some(LegalOrganizationID::new(scheme_id, id.into())),
+            trading_business_name: None,
+        });
+        self
+    }
+
+    /// ## Buyer Order Referenced Document
+    /// An identifier of a referenced purchase order, issued by the Buyer.
+    ///
+    /// BT-13
+    ///
+    /// /ram:BuyerOrderReferencedDocument
+    pub fn set_buyers_order_specified_document<T: Into<&'invoice_builder str>>(
+        &mut self,
+        buyers_order_specified_document: T,
+    ) -> &mut Self {
+        self.buyers_order_specified_document = Some(buyers_order_specified_document.into());
+        self
+    }
+
+    /// Identifier of the sales order, issued by the seller
+    ///
+    /// BT-14
+    pub fn set_sellers_order_referenced_document<T: Into<&'invoice_builder str>>(
+        &mut self,
+        id: T,
+    ) -> &mut Self {
+        self.sellers_order_referenced_document = Some(id.into());
+        self
+    }
+
+    /// Identifier of the contract the invoice is based on
+    ///
+    /// BT-12
+    pub fn set_contract_referenced_document<T: Into<&'invoice_builder str>>(
+        &mut self,
+        id: T,
+    ) -> &mut Self {
+        self.contract_referenced_document = Some(id.into());
+        self
+    }
+
+    /// Identifier and name of the project the invoice refers to
+    ///
+    /// BT-11, BT-11-0
+    pub fn set_specified_procuring_project<T: Into<&'invoice_builder str>>(
+        &mut self,
+        id: T,
+        name: T,
+    ) -> &mut Self {
+        self.specified_procuring_project = Some(SpecifiedProcuringProject {
+            id: id.into(),
+            name: name.into(),
+        });
+        self
+    }
+
+    /// Identifier of the call for tender or lot the invoice relates to
+    ///
+    /// BT-17
+    pub fn set_tender_or_lot_reference<T: Into<&'invoice_builder str>>(
+        &mut self,
+        id: T,
+    ) -> &mut Self {
+        self.additional_referenced_documents
+            .retain(|document| document.type_code != ReferencedDocumentTypeCode::ValidatedPricedTender);
+        self.additional_referenced_documents.push(AdditionalReferencedDocument::new(
+            ReferencedDocumentTypeCode::ValidatedPricedTender,
+            id.into(),
+        ));
+        self
+    }
+
+    /// Identifier of the object the invoice is based on, e.g. a subscription or meter number,
+    /// optionally with its scheme, e.g. "AAG" (UNTDID 1153)
+    ///
+    /// BT-18, BT-18-1
+    pub fn set_invoiced_object_identifier<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: Option<&'invoice_builder str>,
+        id: T,
+    ) -> &mut Self {
+        self.additional_referenced_documents
+            .retain(|document| document.type_code != ReferencedDocumentTypeCode::InvoicingDataSheet);
+        let mut document = AdditionalReferencedDocument::new(ReferencedDocumentTypeCode::InvoicingDataSheet, id.into());
+        document.reference_type_code = scheme_id;
+        self.additional_referenced_documents.push(document);
+        self
+    }
+
+    /// Adds a supporting document, e.g. a timesheet or a delivery note, optionally with a description
+    /// and the location where it can be retrieved
+    ///
+    /// BG-24 (BT-122, BT-123, BT-124)
+    pub fn add_additional_referenced_document<T: Into<&'invoice_builder str>>(
+        &mut self,
+        id: T,
+        description: Option<&'invoice_builder str>,
+        uri: Option<&'invoice_builder str>,
+    ) -> &mut Self {
+        let mut document = AdditionalReferencedDocument::new(ReferencedDocumentTypeCode::RelatedDocument, id.into());
+        document.name = description;
+        document.uriid = uri;
+        self.additional_referenced_documents.push(document);
+        self
+    }
+
+    /// Adds a supporting document embedded into the invoice, e.g. a PDF or an image.
+    /// Returns an error if the MIME code is not in the list allowed by EN 16931, see [`MimeCode`]
+    ///
+    /// BG-24 (BT-122, BT-123, BT-125, BT-125-1, BT-125-2)
+    pub fn add_additional_referenced_document_attachment<T: Into<&'invoice_builder str>>(
+        &mut self,
+        id: T,
+        description: Option<&'invoice_builder str>,
+        filename: &'invoice_builder str,
+        mime_code: &str,
+        content: &[u8],
+    ) -> Result<&mut Self, String> {
+        let mime_code = MimeCode::try_from(mime_code)?;
+        let mut document = AdditionalReferencedDocument::new(ReferencedDocumentTypeCode::RelatedDocument, id.into());
+        document.name = description;
+        document.attachment_binary_object = Some(AttachmentBinaryObject::new(mime_code, filename, content));
+        self.additional_referenced_documents.push(document);
+        Ok(self)
+    }
+
+    /// Service date, date when the service was delivered
+    ///
+    /// BT-72
+    pub fn set_occurrence_date(&mut self, date: NaiveDate) -> &mut Self {
+        self.occurrence_date = Some(DateTimeString::new(date));
+        self
+    }
+
+    /// Name of the party the goods and services are delivered to, if it differs from the buyer
+    ///
+    /// BT-70
+    pub fn set_ship_to_name<T: Into<&'invoice_builder str>>(&mut self, name: T) -> &mut Self {
+        self.ship_to_trade_party
+            .get_or_insert_with(Default::default)
+            .name = Some(name.into());
+        self
+    }
+
+    /// Identifier of the delivery location
+    ///
+    /// BT-71
+    pub fn set_ship_to_id<T: Into<&'invoice_builder str>>(&mut self, id: T) -> &mut Self {
+        self.ship_to_trade_party
+            .get_or_insert_with(Default::default)
+            .id = Some(id.into());
+        self
+    }
+
+    /// Identifier of the delivery location with a registered scheme, e.g. a GLN with [`IdentifierSchemeCode::EAN_Location_Code`]
+    ///
+    /// BT-71-0, BT-71-1
+    pub fn set_ship_to_global_id<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: IdentifierSchemeCode,
+        global_id: T,
+    ) -> &mut Self {
+        self.ship_to_trade_party
+            .get_or_insert_with(Default::default)
+            .global_id = Some(GlobalID::new(scheme_id, global_id.into()));
+        self
+    }
+
+    /// Deliver to address, created on first use of any of the `set_ship_to_postal_trade_address_*` setters
+    ///
+    /// BG-15
+    fn ship_to_postal_trade_address(&mut self) -> &mut PostalTradeAddress<'invoice_builder> {
+        self.ship_to_trade_party
+            .get_or_insert_with(Default::default)
+            .postal_trade_address
+            .get_or_insert_with(Default::default)
+    }
+
+    /// BT-78
+    pub fn set_ship_to_postal_trade_address_postcode_code<T: Into<&'invoice_builder str>>(
+        &mut self,
+        postcode_code: T,
+    ) -> &mut Self {
+        self.ship_to_postal_trade_address().postcode_code = Some(postcode_code.into());
+        self
+    }
+
+    /// BT-75
+    pub fn set_ship_to_postal_trade_address_line_one<T: Into<&'invoice_builder str>>(
+        &mut self,
+        line: T,
+    ) -> &mut Self {
+        self.ship_to_postal_trade_address().line_one = Some(line.into());
+        self
+    }
+
+    /// BT-76
+    pub fn set_ship_to_postal_trade_address_line_two<T: Into<&'invoice_builder str>>(
+        &mut self,
+        line: T,
+    ) -> &mut Self {
+        self.ship_to_postal_trade_address().line_two = Some(line.into());
+        self
+    }
+
+    /// BT-165
+    pub fn set_ship_to_postal_trade_address_line_three<T: Into<&'invoice_builder str>>(
+        &mut self,
+        line: T,
+    ) -> &mut Self {
+        self.ship_to_postal_trade_address().line_three = Some(line.into());
+        self
+    }
+
+    /// BT-77
+    pub fn set_ship_to_postal_trade_address_city_name<T: Into<&'invoice_builder str>>(
+        &mut self,
+        city_name: T,
+    ) -> &mut Self {
+        self.ship_to_postal_trade_address().city_name = Some(city_name.into());
+        self
+    }
+
+    /// BT-80
+    pub fn set_ship_to_postal_trade_address_country_code(
+        &mut self,
+        country_code: CountryCode,
+    ) -> &mut Self {
+        self.ship_to_postal_trade_address().country_id = country_code;
+        self
+    }
+
+    /// Identifier of the despatch advice the invoice refers to
+    ///
+    /// BT-16
+    pub fn set_despatch_advice_referenced_document<T: Into<&'invoice_builder str>>(
+        &mut self,
+        id: T,
+    ) -> &mut Self {
+        self.despatch_advice_referenced_document = Some(id.into());
+        self
+    }
+
+    /// Identifier of the receiving advice the invoice refers to
+    ///
+    /// BT-15
+    pub fn set_receiving_advice_referenced_document<T: Into<&'invoice_builder str>>(
+        &mut self,
+        id: T,
+    ) -> &mut Self {
+        self.receiving_advice_referenced_document = Some(id.into());
+        self
+    }
+
+    /// Period the invoice refers to, e.g. the month of a subscription, given by start and/or end date
+    ///
+    /// BG-14 (BT-73, BT-74)
+    pub fn set_billing_specified_period(
+        &mut self,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+    ) -> &mut Self {
+        self.billing_specified_period = Some(BillingSpecifiedPeriod::new(start_date, end_date));
+        self
+    }
+
+    /// Tax amount that needs to be paid.
+    /// Calculated by multiplying the net total by the tax percentage
+    ///
+    /// BT-117
+    pub fn set_applicable_trade_tax_calculated_amount(&mut self, amount: f64) -> &mut Self {
+        if let Some(applicable_trade_tax) = self.applicable_trade_tax.as_mut() {
+            applicable_trade_tax.calculated_amount = Some(Amount::new(amount));
+        } else {
+            let mut new_struct = ApplicableTradeTax::default();
+            new_struct.calculated_amount = Some(Amount::new(amount));
+            self.applicable_trade_tax = Some(new_struct);
+        }
+
+        self
+    }
+    /// Sum of all net amounts of a single tax category
+    ///
+    /// BT-116
+    pub fn set_applicable_trade_tax_basis_amount(&mut self, amount: f64) -> &mut Self {
+        if let Some(applicable_trade_tax) = self.applicable_trade_tax.as_mut() {
+            applicable_trade_tax.basis_amount = Some(Amount::new(amount));
+        } else {
+            let mut new_struct = ApplicableTradeTax::default();
+            new_struct.basis_amount = Some(Amount::new(amount));
+            self.applicable_trade_tax = Some(new_struct);
+        }
+
+        self
+    }
+
+    /// Category of the VAT that is applied. Choose from [`VATCategoryCode`]
+    ///
+    /// BT-118
+    pub fn set_applicable_trade_tax_category_code(&mut self, code: VATCategoryCode) -> &mut Self {
+        if let Some(applicable_trade_tax) = self.applicable_trade_tax.as_mut() {
+            applicable_trade_tax.category_code = code;
+        } else {
+            let mut new_struct = ApplicableTradeTax::default();
+            new_struct.category_code = code;
+            self.applicable_trade_tax = Some(new_struct);
+        }
+
+        self
+    }
+    /// VAT Percentage for the given [`VATCategoryCode`]
+    ///
+    /// BT-119
+    pub fn set_applicable_trade_tax_rate_applicable_percent(&mut self, amount: f64) -> &mut Self {
+        if let Some(applicable_trade_tax) = self.applicable_trade_tax.as_mut() {
+            applicable_trade_tax.rate_applicable_percent = Some(amount);
+        } else {
+            let mut new_struct = ApplicableTradeTax::default();
+            new_struct.rate_applicable_percent = Some(amount);
+            self.applicable_trade_tax = Some(new_struct);
+        }
+
+        self
+    }
+
+    /// The date when the payment is due, not allowed in [`SpecificationLevel::Minimum`]
+    ///
+    /// BT-9
+    pub fn set_specified_trade_payment_terms_due_date(&mut self, date: NaiveDate) -> &mut Self {
+        self.specified_trade_payment_terms
+            .get_or_insert_with(SpecifiedTradePaymentTerms::default)
+            .due_date_time = Some(DueDateDateTime::new(date));
+
+        self
+    }
+
+    /// Sets the due date to the given number of days after the date of issue, e.g. 30 for "net 30 days".
+    /// Fails if the date of issue is not set yet.
+    ///
+    /// BT-9
+    pub fn set_specified_trade_payment_terms_due_date_in_days(
+        &mut self,
+        days: u32,
+    ) -> Result<&mut Self, String> {
+        let date_of_issue = self
+            .date_of_issue
+            .as_ref()
+            .ok_or("Date of issue not set, cannot calculate the due date")?
+            .to_naive_date();
+        let due_date = date_of_issue
+            .checked_add_days(chrono::Days::new(days.into()))
+            .ok_or("Due date out of range")?;
+
+        Ok(self.set_specified_trade_payment_terms_due_date(due_date))
+    }
+
+    /// A textual description of the payment terms, e.g. "Payable within 30 days net", not allowed in [`SpecificationLevel::Minimum`]
+    ///
+    /// BT-20
+    pub fn set_specified_trade_payment_terms_description<S: AsRef<str>>(
+        &mut self,
+        description: S,
+    ) -> &mut Self {
+        self.specified_trade_payment_terms
+            .get_or_insert_with(SpecifiedTradePaymentTerms::default)
+            .description = Some(description.as_ref().to_string());
+
+        self
+    }
+
+    /// Adds further payment terms, e.g. for partial payments with different due dates.
+    /// Only allowed in [`SpecificationLevel::Extended`].
+    ///
+    /// ram:SpecifiedTradePaymentTerms
+    pub fn add_specified_trade_payment_terms(
+        &mut self,
+        payment_terms: SpecifiedTradePaymentTerms<'invoice_builder>,
+    ) -> &mut Self {
+        self.additional_specified_trade_payment_terms
+            .push(payment_terms);
+        self
+    }
+
+    /// Reference of the SEPA mandate that allows the seller to collect the amount by direct debit
+    ///
+    /// BT-89
+    pub fn set_specified_trade_payment_terms_direct_debit_mandate_id<
+        T: Into<&'invoice_builder str>,
+    >(
+        &mut self,
+        mandate_id: T,
+    ) -> &mut Self {
+        self.specified_trade_payment_terms
+            .get_or_insert_with(SpecifiedTradePaymentTerms::default)
+            .direct_debit_mandate_id = Some(mandate_id.into());
+
+        self
+    }
+
+    /// Adds an early payment discount (Skonto), e.g. 2% if paid within 10 days.
+    /// Can be called several times for several tiers.
+    ///
+    /// Up to [`SpecificationLevel::XRechnung`] the discount is written into the payment terms
+    /// description (BT-20) as `#SKONTO#TAGE=10#PROZENT=2.00#`, in [`SpecificationLevel::Extended`]
+    /// as `ram:ApplicableTradePaymentDiscountTerms`. As payment terms hold only one discount there,
+    /// every further tier is written as payment terms of its own that contain nothing but the discount.
+    /// Not allowed in [`SpecificationLevel::Minimum`].
+    pub fn add_specified_trade_payment_terms_discount(
+        &mut self,
+        days: u32,
+        calculation_percent: f64,
+    ) -> &mut Self {
+        self.payment_discount_terms
+            .push(ApplicableTradePaymentDiscountTerms::new(
+                days,
+                calculation_percent,
+                None,
+            ));
+        self
+    }
+
+    /// Adds an early payment discount (Skonto) that is calculated from the given basis amount
+    /// instead of the amount due for payment, see [Self::add_specified_trade_payment_terms_discount].
+    /// The discount amount is rounded to the invoice currency by [Self::build].
+    pub fn add_specified_trade_payment_terms_discount_with_basis_amount(
+        &mut self,
+        days: u32,
+        calculation_percent: f64,
+        basis_amount: f64,
+    ) -> &mut Self {
+        self.payment_discount_terms.push(ApplicableTradePaymentDiscountTerms::new(
+            days,
+            calculation_percent,
+            Some(basis_amount),
+        ));
+        self
+    }
+
+    /// SEPA creditor identifier of the seller
+    ///
+    /// BT-90
+    pub fn set_creditor_reference_id<T: Into<&'invoice_builder str>>(
+        &mut self,
+        creditor_reference_id: T,
+    ) -> &mut Self {
+        self.creditor_reference_id = Some(creditor_reference_id.into());
+        self
+    }
+
+    pub fn set_invoice_currency_code(&mut self, invoice_currency_code: CurrencyCode) -> &mut Self {
+        self.invoice_currency_code = Some(invoice_currency_code);
+        self
+    }
+
+    /// Currency used for VAT accounting and reporting, if it differs from the invoice currency.
+    /// Requires the total VAT amount in this currency, see [Self::set_monetary_summation_tax_total_amount_in_tax_currency]
+    ///
+    /// BT-6
+    pub fn set_tax_currency_code(&mut self, tax_currency_code: CurrencyCode) -> &mut Self {
+        self.tax_currency_code = Some(tax_currency_code);
+        self
+    }
+
+    /// Reference the buyer should state when paying, e.g. the invoice number
+    ///
+    /// BT-83
+    pub fn set_payment_reference<T: Into<&'invoice_builder str>>(
+        &mut self,
+        payment_reference: T,
+    ) -> &mut Self {
+        self.payment_reference = Some(payment_reference.into());
+        self
+    }
+
+    /// Adds a payment instruction (BG-16). Can be called several times if
+    /// the buyer can choose between several means of payment.
+    ///
+    /// ram:SpecifiedTradeSettlementPaymentMeans
+    pub fn add_specified_trade_settlement_payment_means(
+        &mut self,
+        payment_means: SpecifiedTradeSettlementPaymentMeans<'invoice_builder>,
+    ) -> &mut Self {
+        self.specified_trade_settlement_payment_means
+            .push(payment_means);
+        self
+    }
+
+    /// Adds a SEPA credit transfer to the given account of the seller
+    ///
+    /// BT-81 = 58, BT-84, BT-85, BT-86
+    pub fn add_payment_means_credit_transfer<T: Into<&'invoice_builder str>>(
+        &mut self,
+        iban: T,
+        account_name: Option<&'invoice_builder str>,
+        bic: Option<&'invoice_builder str>,
+    ) -> &mut Self {
+        let mut payment_means =
+            SpecifiedTradeSettlementPaymentMeans::new(PaymentMeansCode::SepaCreditTransfer);
+        payment_means.payee_party_creditor_financial_account =
+            Some(PayeePartyCreditorFinancialAccount {
+                ibanid: Some(iban.into()),
+                account_name,
+                proprietary_id: None,
+            });
+        payment_means.payee_specified_creditor_financial_institution =
+            bic.map(|bicid| PayeeSpecifiedCreditorFinancialInstitution { bicid });
+
+        self.add_specified_trade_settlement_payment_means(payment_means)
+    }
+
+    /// Adds a card payment, e.g. [`PaymentMeansCode::BankCard`], [`PaymentMeansCode::CreditCard`] or [`PaymentMeansCode::DebitCard`]
+    ///
+    /// Only the last digits of the card number may be passed, full card numbers are refused (BR-51).
+    ///
+    /// BT-81, BT-87, BT-88
+    pub fn add_payment_means_card<T: Into<&'invoice_builder str>>(
+        &mut self,
+        type_code: PaymentMeansCode<'invoice_builder>,
+        card_number_last_digits: T,
+        cardholder_name: Option<&'invoice_builder str>,
+    ) -> Result<&mut Self, String> {
+        let mut payment_means = SpecifiedTradeSettlementPaymentMeans::new(type_code);
+        payment_means.applicable_trade_settlement_financial_card = Some(
+            ApplicableTradeSettlementFinancialCard::new(
+                card_number_last_digits.into(),
+                cardholder_name,
+            )?,
+        );
+
+        Ok(self.add_specified_trade_settlement_payment_means(payment_means))
+    }
+
+    /// Adds a SEPA direct debit from the given account of the buyer
+    ///
+    /// BT-81 = 59, BT-91
+    pub fn add_payment_means_direct_debit<T: Into<&'invoice_builder str>>(
+        &mut self,
+        debtor_iban: T,
+    ) -> &mut Self {
+        let mut payment_means =
+            SpecifiedTradeSettlementPaymentMeans::new(PaymentMeansCode::SepaDirectDebit);
+        payment_means.payer_party_debtor_financial_account = Some(PayerPartyDebtorFinancialAccount {
+            ibanid: debtor_iban.into(),
+        });
+
+        self.add_specified_trade_settlement_payment_means(payment_means)
+    }
+
+    /// Sum of all net amounts. Recalculated by [Self::build] if line items were added
+    ///
+    /// BT-106
+    pub fn set_monetary_summation_line_total_amount(&mut self, amount: f64) -> &mut Self {
+        self.monetary_summation.line_total_amount = Some(Amount::new(amount));
+        self
+    }
+
+    /// Surcharge on document level. Surcharges on item level are contained in their net amounts.
+    /// Recalculated by [Self::build] if charges were added, see [Self::add_specified_trade_allowance_charge]
+    ///
+    /// BT-108
+    pub fn set_monetary_summation_charge_total_amount(&mut self, amount: f64) -> &mut Self {
+        self.monetary_summation.charge_total_amount = Some(Amount::new(amount));
+        self
+    }
+    /// Deductions on document level. Deductions on item level are contained in their net amounts.
+    /// Recalculated by [Self::build] if allowances were added, see [Self::add_specified_trade_allowance_charge]
+    ///
+    /// BT-107
+    pub fn set_monetary_summation_allowance_total_amount(&mut self, amount: f64) -> &mut Self {
+        self.monetary_summation.allowance_total_amount = Some(Amount::new(amount));
+        self
+    }
+
+    /// Invoice amoiunt without VAT. Calculated by summing the net amounts
+    /// minus document deductions [Self::set_monetary_summation_allowance_total_amount]
+    /// plus document surcharges [Self::set_monetary_summation_charge_total_amount].
+    /// Recalculated by [Self::build] if allowances or charges were added
+    ///
+    /// BT-109
+    pub fn set_monetary_summation_tax_basis_total_amount(&mut self, amount: f64) -> &mut Self {
+        self.monetary_summation.tax_basis_total_amount = Some(Amount::new(amount));
+        self
+    }
+
+    /// Tax amount for the full invoice in the country of the seller, calculated from tax percentage and net sum.
+    /// Written in the invoice currency by [Self::build]
+    ///
+    /// BT-110
+    pub fn set_monetary_summation_tax_total_amount(&mut self, amount: f64) -> &mut Self {
+        self.monetary_summation_tax_total_amount = Some(amount);
+        self
+    }
+
+    /// Total VAT amount in the tax currency set by [Self::set_tax_currency_code]
+    ///
+    /// BT-111
+    pub fn set_monetary_summation_tax_total_amount_in_tax_currency(&mut self, amount: f64) -> &mut Self {
+        self.monetary_summation_tax_total_amount_in_tax_currency = Some(amount);
+        self
+    }
+
+    /// Converts the total VAT amount (BT-110) into the tax currency, with the exchange rate given as
+    /// units of tax currency per unit of invoice currency, and rounds it to the minor units of the tax currency
+    ///
+    /// BT-111
+    pub fn calculate_tax_total_amount_in_tax_currency(&mut self, exchange_rate: f64) -> Result<&mut Self, String> {
+        let tax_currency_code = self.tax_currency_code
+            .ok_or("Tax currency not set")?;
+        let tax_total_amount = self.monetary_summation_tax_total_amount
+            .ok_or("Total VAT amount not set")?;
+
+        Ok(self.set_monetary_summation_tax_total_amount_in_tax_currency(
+            tax_currency_code.round(tax_total_amount * exchange_rate),
+        ))
+    }
+
+    /// Gross invoice amount
     ///
     /// [Self::set_monetary_summation_tax_basis_total_amount] + [Self::set_monetary_summation_tax_total_amount]
     ///
     /// BT-112
     pub fn set_monetary_summation_grand_total_amount(&mut self, amount: f64) -> &mut Self {
-        self.monetary_summation.grand_total_amount = Some(amount);
+        self.monetary_summation.grand_total_amount = Some(Amount::new(amount));
         self
     }
 
@@ -698,10 +1890,98 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
     ///
     /// BT-112
     pub fn set_monetary_summation_due_payable_amount(&mut self, amount: f64) -> &mut Self {
-        self.monetary_summation.due_payable_amount = Some(amount);
+        self.monetary_summation.due_payable_amount = Some(Amount::new(amount));
         self
     }
 
+    /// Adds an allowance or charge on document level. [Self::build] sums up the amounts into
+    /// [Self::set_monetary_summation_allowance_total_amount] and [Self::set_monetary_summation_charge_total_amount]
+    /// and recalculates [Self::set_monetary_summation_tax_basis_total_amount]
+    ///
+    /// BG-20, BG-21
+    pub fn add_specified_trade_allowance_charge(
+        &mut self,
+        allowance_charge: SpecifiedTradeAllowanceCharge<'invoice_builder>,
+    ) -> &mut Self {
+        self.specified_trade_allowance_charges
+            .push(allowance_charge);
+        self
+    }
+
+    /// Document level allowance, e.g. a volume discount
+    ///
+    /// BG-20
+    pub fn add_allowance(
+        &mut self,
+        amount: f64,
+        category_code: VATCategoryCode,
+        rate_applicable_percent: Option<f64>,
+        reason: AllowanceChargeReason<'invoice_builder, AllowanceChargeReasonCode>,
+    ) -> &mut Self {
+        let allowance = reason.apply(SpecifiedTradeAllowanceCharge::allowance(
+            amount,
+            category_code,
+            rate_applicable_percent,
+        ));
+        self.add_specified_trade_allowance_charge(allowance)
+    }
+
+    /// Document level allowance as percentage of a basis amount,
+    /// rounded to the invoice currency by [Self::build]
+    ///
+    /// BG-20
+    pub fn add_allowance_percentage(
+        &mut self,
+        percent: f64,
+        basis_amount: f64,
+        category_code: VATCategoryCode,
+        rate_applicable_percent: Option<f64>,
+        reason: AllowanceChargeReason<'invoice_builder, AllowanceChargeReasonCode>,
+    ) -> &mut Self {
+        let allowance = reason.apply(
+            SpecifiedTradeAllowanceCharge::allowance(0.0, category_code, rate_applicable_percent)
+                .with_percentage(percent, basis_amount),
+        );
+        self.add_specified_trade_allowance_charge(allowance)
+    }
+
+    /// Document level charge, e.g. freight
+    ///
+    /// BG-21
+    pub fn add_charge(
+        &mut self,
+        amount: f64,
+        category_code: VATCategoryCode,
+        rate_applicable_percent: Option<f64>,
+        reason: AllowanceChargeReason<'invoice_builder, ChargeReasonCode>,
+    ) -> &mut Self {
+        let charge = reason.apply(SpecifiedTradeAllowanceCharge::charge(
+            amount,
+            category_code,
+            rate_applicable_percent,
+        ));
+        self.add_specified_trade_allowance_charge(charge)
+    }
+
+    /// Document level charge as percentage of a basis amount,
+    /// rounded to the invoice currency by [Self::build]
+    ///
+    /// BG-21
+    pub fn add_charge_percentage(
+        &mut self,
+        percent: f64,
+        basis_amount: f64,
+        category_code: VATCategoryCode,
+        rate_applicable_percent: Option<f64>,
+        reason: AllowanceChargeReason<'invoice_builder, ChargeReasonCode>,
+    ) -> &mut Self {
+        let charge = reason.apply(
+            SpecifiedTradeAllowanceCharge::charge(0.0, category_code, rate_applicable_percent)
+                .with_percentage(percent, basis_amount),
+        );
+        self.add_specified_trade_allowance_charge(charge)
+    }
+
     pub fn add_supply_chain_trade_line_item(
         &mut self,
         line_item: IncludedSupplyChainTradeLineItem<'invoice_builder>,
@@ -710,6 +1990,170 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
         self
     }
 
+    /// Builds the line item, numbering it with the next free line ID if none is set.
+    /// [Self::build] calculates its line net amount in the invoice currency and sums it up into
+    /// [Self::set_monetary_summation_line_total_amount]
+    ///
+    /// BG-25
+    pub fn add_line_item(
+        &mut self,
+        line_item_builder: &LineItemBuilder<'invoice_builder>,
+    ) -> Result<&mut Self, String> {
+        let line_item = if line_item_builder.line_id.is_some() {
+            line_item_builder.build()?
+        } else {
+            line_item_builder
+                .clone()
+                .line_id(self.next_free_line_id())
+                .build()?
+        };
+
+        Ok(self.add_supply_chain_trade_line_item(line_item))
+    }
+
+    /// Number of the line items plus one, or the next higher number that is not used as line ID yet
+    fn next_free_line_id(&self) -> String {
+        (self.included_supply_chain_trade_line_items.len() + 1..)
+            .map(|line_number| line_number.to_string())
+            .find(|line_id| {
+                !self
+                    .included_supply_chain_trade_line_items
+                    .iter()
+                    .any(|line| &line.associated_document_line_document.line_id == line_id)
+            })
+            .unwrap()
+    }
+
+    /// Line items with their line net amounts (BT-131) calculated in the given currency
+    fn included_supply_chain_trade_line_items_for(
+        &self,
+        currency_code: CurrencyCode,
+    ) -> Vec<IncludedSupplyChainTradeLineItem<'invoice_builder>> {
+        self.included_supply_chain_trade_line_items
+            .iter()
+            .map(|line_item| line_item.clone().in_currency(currency_code))
+            .collect()
+    }
+
+    /// Document level allowances and charges, amounts rounded to the given currency
+    fn specified_trade_allowance_charges_for(
+        &self,
+        currency_code: CurrencyCode,
+    ) -> Vec<SpecifiedTradeAllowanceCharge<'invoice_builder>> {
+        self.specified_trade_allowance_charges
+            .iter()
+            .map(|allowance_charge| allowance_charge.clone().in_currency(currency_code))
+            .collect()
+    }
+
+    /// Monetary totals, amounts rounded to the given currency. If line items were added, their sum (BT-106)
+    /// is recalculated, if allowances or charges on document level were added, their sums (BT-107, BT-108).
+    /// In both cases the total amount without VAT (BT-109) is recalculated as well.
+    fn monetary_summation_for(
+        &self,
+        currency_code: CurrencyCode,
+    ) -> SpecifiedTradeSettlementHeaderMonetarySummation {
+        let mut monetary_summation = self.monetary_summation.clone().in_currency(currency_code);
+        monetary_summation.tax_total_amount = self
+            .monetary_summation_tax_total_amount
+            .map(|amount| TaxTotalAmount::new(currency_code, amount));
+        monetary_summation.tax_total_amount_in_tax_currency = self
+            .monetary_summation_tax_total_amount_in_tax_currency
+            .zip(self.tax_currency_code)
+            .map(|(amount, tax_currency_code)| TaxTotalAmount::new(tax_currency_code, amount));
+        if self.included_supply_chain_trade_line_items.is_empty()
+            && self.specified_trade_allowance_charges.is_empty()
+        {
+            return monetary_summation;
+        }
+        let sum = |amounts: Vec<Amount>| {
+            Amount::new(amounts.iter().map(|amount| amount.value).sum()).in_currency(currency_code)
+        };
+
+        if !self.included_supply_chain_trade_line_items.is_empty() {
+            let line_net_amounts = self
+                .included_supply_chain_trade_line_items_for(currency_code)
+                .iter()
+                .map(|line_item| {
+                    line_item
+                        .specified_line_trade_settlement
+                        .specified_trade_settlement_line_monetary_summation
+                        .line_total_amount
+                })
+                .collect();
+            monetary_summation.line_total_amount = Some(sum(line_net_amounts));
+        }
+        if !self.specified_trade_allowance_charges.is_empty() {
+            let (charges, allowances): (Vec<_>, Vec<_>) = self
+                .specified_trade_allowance_charges_for(currency_code)
+                .into_iter()
+                .partition(|allowance_charge| allowance_charge.is_charge());
+            let actual_amounts = |allowances_or_charges: Vec<SpecifiedTradeAllowanceCharge>| {
+                allowances_or_charges.iter().map(|allowance_charge| allowance_charge.actual_amount).collect()
+            };
+            monetary_summation.allowance_total_amount = Some(sum(actual_amounts(allowances)));
+            monetary_summation.charge_total_amount = Some(sum(actual_amounts(charges)));
+        }
+        if let Some(line_total_amount) = monetary_summation.line_total_amount {
+            let allowance_total_amount = monetary_summation.allowance_total_amount.map_or(0.0, |amount| amount.value);
+            let charge_total_amount = monetary_summation.charge_total_amount.map_or(0.0, |amount| amount.value);
+            monetary_summation.tax_basis_total_amount = Some(
+                Amount::new(line_total_amount.value - allowance_total_amount + charge_total_amount)
+                    .in_currency(currency_code),
+            );
+        }
+
+        monetary_summation
+    }
+
+    /// Payment terms with the discount terms in the representation of the given [`SpecificationLevel`],
+    /// amounts rounded to the given currency
+    fn specified_trade_payment_terms_for(
+        &self,
+        specification_level: SpecificationLevel,
+        currency_code: CurrencyCode,
+    ) -> Vec<SpecifiedTradePaymentTerms<'invoice_builder>> {
+        if self.specified_trade_payment_terms.is_none()
+            && self.payment_discount_terms.is_empty()
+            && self.additional_specified_trade_payment_terms.is_empty()
+        {
+            return Vec::new();
+        }
+        let mut payment_terms = self.specified_trade_payment_terms.clone().unwrap_or_default();
+
+        if specification_level == SpecificationLevel::Extended {
+            let mut discount_terms = self
+                .payment_discount_terms
+                .iter()
+                .map(|discount_terms| discount_terms.clone().in_currency(currency_code));
+            payment_terms.applicable_trade_payment_discount_terms = discount_terms.next();
+
+            let mut all_payment_terms = vec![payment_terms];
+            all_payment_terms.extend(self.additional_specified_trade_payment_terms.iter().cloned());
+            all_payment_terms.extend(discount_terms.map(|discount_terms| {
+                SpecifiedTradePaymentTerms {
+                    applicable_trade_payment_discount_terms: Some(discount_terms),
+                    ..Default::default()
+                }
+            }));
+            return all_payment_terms;
+        }
+
+        if !self.payment_discount_terms.is_empty() {
+            let mut description = payment_terms
+                .description
+                .map(|description| description + "\n")
+                .unwrap_or_default();
+            for discount_terms in &self.payment_discount_terms {
+                description += &discount_terms.to_skonto_string();
+                description += "\n";
+            }
+            payment_terms.description = Some(description);
+        }
+
+        vec![payment_terms]
+    }
+
     // What the whole crate is actually about
     pub fn to_xml_string(
         mut self,
@@ -727,6 +2171,8 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
     ) -> Result<Invoice<'invoice_builder>, String> {
         //Check if none of the fields is empty
         self.all_fields_are_set(specification_level)?;
+        // All amounts are rounded to and written with the minor units of the invoice currency
+        let currency_code = self.invoice_currency_code.unwrap();
 
         // Build the invoice structure
         Ok(Invoice::new(
@@ -746,19 +2192,31 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
             },
             SupplyChainTradeTransaction {
                 included_supply_chain_trade_line_items: self
-                    .included_supply_chain_trade_line_items
-                    .clone(),
+                    .included_supply_chain_trade_line_items_for(currency_code),
                 applicable_header_trade_agreement: ApplicableHeaderTradeAgreement {
                     buyer_reference: self.buyer_reference,
                     seller_trade_party: SellerTradeParty {
-                        id: Vec::new(),
-                        global_id: Vec::new(),
+                        id: self.sellers_id.clone(),
+                        global_id: self.sellers_global_id.clone(),
                         name: self.sellers_name.unwrap(),
-                        specified_legal_organization: self
+                        specified_legal_organization: if self
                             .sellers_specified_legal_organization
-                            .map(|v| SpecifiedLegalOrganization {
-                                id: LegalOrganizationID::new(v),
-                            }),
+                            .is_some()
+                            || self.sellers_trading_business_name.is_some()
+                        {
+                            Some(SpecifiedLegalOrganization {
+                                id: self.sellers_specified_legal_organization.map(|v| {
+                                    LegalOrganizationID::new(
+                                        self.sellers_specified_legal_organization_scheme_id,
+                                        v,
+                                    )
+                                }),
+                                trading_business_name: self.sellers_trading_business_name,
+                            })
+                        } else {
+                            None
+                        },
+                        defined_trade_contact: self.sellers_defined_trade_contact.clone(),
                         postal_trade_address: PostalTradeAddress {
                             country_id: self.sellers_postal_trade_address.country_id,
                             postcode_code: self.sellers_postal_trade_address.postcode_code,
@@ -767,20 +2225,31 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
                             line_three: self.sellers_postal_trade_address.line_three,
                             city_name: self.sellers_postal_trade_address.city_name,
                         },
-                        uri_universal_communication: None,
-                        specified_tax_registration: vec![SpecifiedTaxRegistration {
-                            id: SpecifiedTaxRegistrationID::new(
-                                self.sellers_specified_tax_registration.unwrap(),
-                            ),
-                        }],
+                        uri_universal_communication: self.sellers_uri_universal_communication.clone(),
+                        specified_tax_registration: self.sellers_specified_tax_registration.clone(),
                     },
                     buyer_trade_party: BuyerTradeParty {
+                        id: self.buyers_id,
+                        global_id: self.buyers_global_id.clone(),
                         name: self.buyers_name.unwrap(),
-                        specified_legal_organization: self
-                            .sellers_specified_legal_organization
-                            .map(|v| SpecifiedLegalOrganization {
-                                id: LegalOrganizationID::new(v),
-                            }),
+                        specified_legal_organization: if self
+                            .buyers_specified_legal_organization
+                            .is_some()
+                            || self.buyers_trading_business_name.is_some()
+                        {
+                            Some(SpecifiedLegalOrganization {
+                                id: self.buyers_specified_legal_organization.map(|v| {
+                                    LegalOrganizationID::new(
+                                        self.buyers_specified_legal_organization_scheme_id,
+                                        v,
+                                    )
+                                }),
+                                trading_business_name: self.buyers_trading_business_name,
+                            })
+                        } else {
+                            None
+                        },
+                        defined_trade_contact: self.buyers_defined_trade_contact.clone(),
                         postal_trade_address: PostalTradeAddress {
                             country_id: self.buyers_postal_trade_address.country_id,
                             postcode_code: self.buyers_postal_trade_address.postcode_code,
@@ -789,14 +2258,28 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
                             line_three: self.buyers_postal_trade_address.line_three,
                             city_name: self.buyers_postal_trade_address.city_name,
                         },
+                        uri_universal_communication: self.buyers_uri_universal_communication.clone(),
+                        specified_tax_registration: self.buyers_specified_tax_registration.clone(),
                     },
+                    seller_tax_representative_trade_party: self
+                        .sellers_tax_representative_trade_party
+                        .clone(),
+                    seller_order_referenced_document: self
+                        .sellers_order_referenced_document
+                        .map(ReferencedDocument::new),
                     buyer_order_referenced_document: self.buyers_order_specified_document.map(
                         |v| BuyerOrderReferencedDocument {
                             issuer_assigned_id: v,
                         },
                     ),
+                    contract_referenced_document: self
+                        .contract_referenced_document
+                        .map(ReferencedDocument::new),
+                    additional_referenced_document: self.additional_referenced_documents.clone(),
+                    specified_procuring_project: self.specified_procuring_project.clone(),
                 },
                 applicable_header_trade_delivery: ApplicableHeaderTradeDelivery {
+                    ship_to_trade_party: self.ship_to_trade_party.clone(),
                     actual_delivery_supply_chain_event: Some(ActualDeliverySupplyChainEvent {
                         occurrence_date_time: self.occurrence_date.clone().map(
                             |actual_delivery_date| OccurrenceDateTime {
@@ -804,25 +2287,423 @@ impl<'invoice_builder> InvoiceBuilder<'invoice_builder> {
                             },
                         ),
                     }),
+                    despatch_advice_referenced_document: self
+                        .despatch_advice_referenced_document
+                        .map(ReferencedDocument::new),
+                    receiving_advice_referenced_document: self
+                        .receiving_advice_referenced_document
+                        .map(ReferencedDocument::new),
                 },
                 applicable_header_trade_settlement: ApplicableHeaderTradeSettlement {
-                    invoice_currency_code: self.invoice_currency_code.clone().unwrap(),
-                    specified_trade_settlement_payment_means: Vec::new(),
-                    applicable_trade_tax: self.applicable_trade_tax,
-                    specified_trade_allowance_charge: Vec::new(),
-                    specified_trade_payment_terms: self.specified_trade_payment_terms.clone(),
-                    specified_trade_settlement_header_monetary_summation: self
-                        .monetary_summation
+                    creditor_reference_id: self.creditor_reference_id,
+                    payment_reference: self.payment_reference,
+                    tax_currency_code: self.tax_currency_code,
+                    invoice_currency_code: currency_code,
+                    payee_trade_party: self.payee_trade_party.clone(),
+                    specified_trade_settlement_payment_means: self
+                        .specified_trade_settlement_payment_means
                         .clone(),
+                    applicable_trade_tax: self
+                        .applicable_trade_tax
+                        .map(|applicable_trade_tax| applicable_trade_tax.in_currency(currency_code)),
+                    billing_specified_period: self.billing_specified_period.clone(),
+                    specified_trade_allowance_charge: self
+                        .specified_trade_allowance_charges_for(currency_code),
+                    specified_trade_payment_terms: self
+                        .specified_trade_payment_terms_for(specification_level, currency_code),
+                    specified_trade_settlement_header_monetary_summation: self
+                        .monetary_summation_for(currency_code),
                 },
             },
         ))
     }
-}
+}
+
+/// Builds a single line item ([`IncludedSupplyChainTradeLineItem`]) for [`InvoiceBuilder::add_line_item`]
+///
+/// ```rust
+/// # use zugferd::*;
+/// let mut invoice_builder = InvoiceBuilder::new();
+///
+/// invoice_builder
+///     .add_line_item(
+///         LineItemBuilder::new()
+///             .product("Product 1", Some("4012345678901"))
+///             .quantity(3.0)
+///             .unit(UnitCode::Piece)
+///             .net_price(12.5)
+///             .vat(VATCategoryCode::StandardRate, Some(19.0)),
+///     )
+///     .unwrap();
+/// ```
+#[derive(Clone, Default)]
+pub struct LineItemBuilder<'invoice_builder> {
+    line_id: Option<String>,
+    note: Option<&'invoice_builder str>,
+    product_name: Option<&'invoice_builder str>,
+    product_gtin: Option<&'invoice_builder str>,
+    product_description: Option<&'invoice_builder str>,
+    product_seller_assigned_id: Option<&'invoice_builder str>,
+    product_buyer_assigned_id: Option<&'invoice_builder str>,
+    product_characteristics: Vec<ApplicableProductCharacteristic<'invoice_builder>>,
+    product_classifications: Vec<DesignatedProductClassification<'invoice_builder>>,
+    product_origin_country: Option<CountryCode>,
+    quantity: Option<f64>,
+    unit: Option<UnitCode>,
+    net_price: Option<f64>,
+    gross_price: Option<f64>,
+    price_discount: Option<f64>,
+    base_quantity: Option<BasisQuantity>,
+    vat_category_code: Option<VATCategoryCode>,
+    vat_rate_applicable_percent: Option<f64>,
+    billing_specified_period: Option<BillingSpecifiedPeriod<'invoice_builder>>,
+    allowance_charges: Vec<SpecifiedLineTradeAllowanceCharge<'invoice_builder>>,
+    buyer_order_line_id: Option<&'invoice_builder str>,
+    object_identifier: Option<AdditionalReferencedDocument<'invoice_builder>>,
+    accounting_reference: Option<&'invoice_builder str>,
+}
+
+impl<'invoice_builder> LineItemBuilder<'invoice_builder> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Identifier of the line, numbered automatically by [`InvoiceBuilder::add_line_item`] if not set
+    ///
+    /// BT-126
+    pub fn line_id<T: Into<String>>(&mut self, line_id: T) -> &mut Self {
+        self.line_id = Some(line_id.into());
+        self
+    }
+
+    /// BT-127
+    pub fn note<T: Into<&'invoice_builder str>>(&mut self, note: T) -> &mut Self {
+        self.note = Some(note.into());
+        self
+    }
+
+    /// Name (BT-153) and optional GTIN (BT-157) of the invoiced item
+    pub fn product<T: Into<&'invoice_builder str>>(
+        &mut self,
+        name: T,
+        gtin: Option<&'invoice_builder str>,
+    ) -> &mut Self {
+        self.product_name = Some(name.into());
+        self.product_gtin = gtin;
+        self
+    }
+
+    /// BT-154
+    pub fn description<T: Into<&'invoice_builder str>>(&mut self, description: T) -> &mut Self {
+        self.product_description = Some(description.into());
+        self
+    }
+
+    /// Identifier the seller assigned to the item, e.g. the article number
+    ///
+    /// BT-155
+    pub fn seller_assigned_id<T: Into<&'invoice_builder str>>(&mut self, id: T) -> &mut Self {
+        self.product_seller_assigned_id = Some(id.into());
+        self
+    }
+
+    /// Identifier the buyer assigned to the item
+    ///
+    /// BT-156
+    pub fn buyer_assigned_id<T: Into<&'invoice_builder str>>(&mut self, id: T) -> &mut Self {
+        self.product_buyer_assigned_id = Some(id.into());
+        self
+    }
+
+    /// Classification code (BT-158) of the item in the given scheme (BT-158-1) and its version (BT-158-2),
+    /// e.g. an UNSPSC code with list ID "TST"
+    pub fn add_classification<T: Into<&'invoice_builder str>>(
+        &mut self,
+        list_id: T,
+        list_version_id: Option<&'invoice_builder str>,
+        class_code: T,
+    ) -> &mut Self {
+        self.product_classifications.push(DesignatedProductClassification::new(
+            list_id.into(),
+            list_version_id,
+            class_code.into(),
+        ));
+        self
+    }
+
+    /// BT-159
+    pub fn origin_country(&mut self, country_code: CountryCode) -> &mut Self {
+        self.product_origin_country = Some(country_code);
+        self
+    }
+
+    /// Attribute of the item given by name (BT-160) and value (BT-161), e.g. "Colour" and "Red"
+    ///
+    /// BG-32
+    pub fn add_attribute<T: Into<&'invoice_builder str>>(&mut self, name: T, value: T) -> &mut Self {
+        self.product_characteristics.push(ApplicableProductCharacteristic {
+            description: name.into(),
+            value: value.into(),
+        });
+        self
+    }
+
+    /// Invoiced quantity
+    ///
+    /// BT-129
+    pub fn quantity(&mut self, quantity: f64) -> &mut Self {
+        self.quantity = Some(quantity);
+        self
+    }
+
+    /// Unit of the invoiced quantity
+    ///
+    /// BT-130
+    pub fn unit(&mut self, unit: UnitCode) -> &mut Self {
+        self.unit = Some(unit);
+        self
+    }
+
+    /// Price of one item without VAT, after price discounts
+    ///
+    /// BT-146
+    pub fn net_price(&mut self, net_price: f64) -> &mut Self {
+        self.net_price = Some(net_price);
+        self
+    }
+
+    /// Price of one item without VAT before the price discount (BT-148), and the discount (BT-147).
+    /// The net price is derived from it unless [Self::net_price] is set.
+    /// The discount must not exceed the gross price, since the net price can't be negative (BR-27).
+    pub fn gross_price(&mut self, gross_price: f64, discount: Option<f64>) -> &mut Self {
+        self.gross_price = Some(gross_price);
+        self.price_discount = discount;
+        self
+    }
+
+    /// Number of items (BT-149) in the given unit (BT-150) the prices refer to, e.g. 100 pieces
+    pub fn base_quantity(&mut self, quantity: f64, unit: UnitCode) -> &mut Self {
+        self.base_quantity = Some(BasisQuantity::new(unit, quantity));
+        self
+    }
+
+    /// VAT category (BT-151) and rate (BT-152) of the invoiced item
+    pub fn vat(
+        &mut self,
+        category_code: VATCategoryCode,
+        rate_applicable_percent: Option<f64>,
+    ) -> &mut Self {
+        self.vat_category_code = Some(category_code);
+        self.vat_rate_applicable_percent = rate_applicable_percent;
+        self
+    }
+
+    /// Period the line refers to, given by start and/or end date
+    ///
+    /// BG-26 (BT-134, BT-135)
+    pub fn billing_period(
+        &mut self,
+        start_date: Option<NaiveDate>,
+        end_date: Option<NaiveDate>,
+    ) -> &mut Self {
+        self.billing_specified_period = Some(BillingSpecifiedPeriod::new(start_date, end_date));
+        self
+    }
+
+    /// Line allowance or charge, included in the line net amount
+    ///
+    /// BG-27, BG-28
+    pub fn add_allowance_charge(
+        &mut self,
+        allowance_charge: SpecifiedLineTradeAllowanceCharge<'invoice_builder>,
+    ) -> &mut Self {
+        self.allowance_charges.push(allowance_charge);
+        self
+    }
+
+    /// Identifier of the line of the buyer's purchase order this line refers to
+    ///
+    /// BT-132
+    pub fn buyer_order_line_reference<T: Into<&'invoice_builder str>>(&mut self, line_id: T) -> &mut Self {
+        self.buyer_order_line_id = Some(line_id.into());
+        self
+    }
+
+    /// Identifier of the object the line is based on, e.g. a meter number,
+    /// optionally with its scheme, e.g. "AAG" (UNTDID 1153)
+    ///
+    /// BT-128, BT-128-1
+    pub fn object_identifier<T: Into<&'invoice_builder str>>(
+        &mut self,
+        scheme_id: Option<&'invoice_builder str>,
+        id: T,
+    ) -> &mut Self {
+        let mut document = AdditionalReferencedDocument::new(ReferencedDocumentTypeCode::InvoicingDataSheet, id.into());
+        document.reference_type_code = scheme_id;
+        self.object_identifier = Some(document);
+        self
+    }
+
+    /// Reference to the buyer's account the line is booked to, e.g. a cost centre
+    ///
+    /// BT-133
+    pub fn accounting_reference<T: Into<&'invoice_builder str>>(&mut self, id: T) -> &mut Self {
+        self.accounting_reference = Some(id.into());
+        self
+    }
+
+    pub(crate) fn build(&self) -> Result<IncludedSupplyChainTradeLineItem<'invoice_builder>, String> {
+        let mut error_text = String::new();
+        if self.line_id.is_none() {
+            error_text += "Line item: Line ID not set\n";
+        }
+        if self.product_name.is_none() {
+            error_text += "Line item: Product name not set\n";
+        }
+        if self.quantity.is_none() {
+            error_text += "Line item: Quantity not set\n";
+        }
+        if self.unit.is_none() {
+            error_text += "Line item: Unit not set\n";
+        }
+        if self.net_price.is_none() && self.gross_price.is_none() {
+            error_text += "Line item: Net price not set\n";
+        }
+        if self.vat_category_code.is_none() {
+            error_text += "Line item: VAT category not set\n";
+        }
+        if let (Some(gross_price), Some(discount)) = (self.gross_price, self.price_discount) {
+            if discount > gross_price {
+                error_text += "Line item: Price discount exceeds gross price\n";
+            }
+        }
+        if !error_text.is_empty() {
+            return Err(error_text);
+        }
+
+        let gross_price_product_trade_price = self.gross_price.map(|gross_price| {
+            let gross_price =
+                GrossPriceProductTradePrice::new(gross_price, self.base_quantity.clone());
+            match self.price_discount {
+                Some(discount) => gross_price.with_discount(discount),
+                None => gross_price,
+            }
+        });
+        let net_price_product_trade_price = match self.net_price {
+            Some(net_price) => NetPriceProductTradePrice::new(net_price, self.base_quantity.clone()),
+            None => gross_price_product_trade_price.as_ref().unwrap().net_price(),
+        };
+
+        // The line net amount (BT-131) is calculated by InvoiceBuilder::build in the invoice currency
+        let line_item = IncludedSupplyChainTradeLineItem {
+            associated_document_line_document: AssociatedDocumentLineDocument {
+                line_id: self.line_id.clone().unwrap(),
+                included_note: self.note,
+            },
+            specified_trade_product: SpecifiedTradeProduct {
+                global_id: self
+                    .product_gtin
+                    .map(|gtin| GlobalID::new(IdentifierSchemeCode::GTIN, gtin)),
+                seller_assigned_id: self.product_seller_assigned_id,
+                buyer_assigned_id: self.product_buyer_assigned_id,
+                name: self.product_name.unwrap(),
+                description: self.product_description,
+                applicable_product_characteristic: self.product_characteristics.clone(),
+                designated_product_classification: self.product_classifications.clone(),
+                origin_trade_country: self.product_origin_country.map(|id| OriginTradeCountry { id }),
+            },
+            specified_line_trade_agreement: SpecifiedLineTradeAgreement {
+                buyer_order_referenced_document: self
+                    .buyer_order_line_id
+                    .map(|line_id| LineReferencedDocument { line_id }),
+                gross_price_product_trade_price,
+                net_price_product_trade_price,
+            },
+            specified_line_trade_delivery: SpecifiedLineTradeDelivery {
+                billed_quantity: BilledQuantity::new(self.unit.unwrap(), self.quantity.unwrap()),
+            },
+            specified_line_trade_settlement: SpecifiedLineTradeSettlement {
+                // The line VAT breakdown only carries category and rate, amounts are given in BG-23
+                applicable_trade_tax: ApplicableTradeTax {
+                    category_code: self.vat_category_code.unwrap(),
+                    rate_applicable_percent: self.vat_rate_applicable_percent,
+                    ..Default::default()
+                },
+                billing_specified_period: self.billing_specified_period.clone(),
+                specified_trade_allowance_charge: self.allowance_charges.clone(),
+                specified_trade_settlement_line_monetary_summation:
+                    SpecifiedTradeSettlementLineMonetarySummation {
+                        line_total_amount: Amount::default(),
+                    },
+                additional_referenced_document: self.object_identifier.clone(),
+                receivable_specified_trade_accounting_account: self
+                    .accounting_reference
+                    .map(|id| ReceivableSpecifiedTradeAccountingAccount { id }),
+            },
+        };
+        Ok(line_item)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// Builder with only the fields required for the given specification level, without line items.
+    /// From Basic on the line total amount is summed up from the line items the test adds.
+    fn minimal_invoice_builder<'a>(specification_level: SpecificationLevel) -> InvoiceBuilder<'a> {
+        let mut invoice_builder = InvoiceBuilder::new();
+
+        invoice_builder
+            .set_invoice_type_code(InvoiceTypeCode::CommercialInvoice)
+            .set_invoice_nr("INV-123456")
+            .set_date_of_issue(chrono::NaiveDate::from_ymd_opt(2024, 8, 10).unwrap())
+            .set_sellers_name("Seller Corp.")
+            .set_sellers_postal_trade_address_country_code(CountryCode::Germany)
+            .set_sellers_specified_tax_registration("DE123456789")
+            .set_buyers_name("Buyer Inc.")
+            .set_invoice_currency_code(CurrencyCode::Euro)
+            .set_monetary_summation_tax_basis_total_amount(100.0)
+            .set_monetary_summation_tax_total_amount(19.0)
+            .set_monetary_summation_grand_total_amount(119.0)
+            .set_monetary_summation_due_payable_amount(119.0);
+
+        if specification_level >= SpecificationLevel::BasicWithoutLines {
+            invoice_builder
+                .set_sellers_postal_trade_address_line_one("Best street")
+                .set_sellers_postal_trade_address_postcode_code("66666")
+                .set_sellers_postal_trade_address_city_name("Hometown")
+                .set_buyers_postal_trade_address_line_one("Main street")
+                .set_buyers_postal_trade_address_postcode_code("77777")
+                .set_buyers_postal_trade_address_city_name("Springfield")
+                .set_occurrence_date(chrono::NaiveDate::from_ymd_opt(2024, 8, 5).unwrap())
+                .set_applicable_trade_tax_basis_amount(100.0)
+                .set_applicable_trade_tax_calculated_amount(19.0)
+                .set_applicable_trade_tax_rate_applicable_percent(19.0)
+                .set_specified_trade_payment_terms_due_date(chrono::NaiveDate::from_ymd_opt(2024, 8, 24).unwrap())
+                .set_monetary_summation_charge_total_amount(0.0)
+                .set_monetary_summation_allowance_total_amount(0.0);
+        }
+        if specification_level == SpecificationLevel::BasicWithoutLines {
+            invoice_builder.set_monetary_summation_line_total_amount(100.0);
+        }
+
+        invoice_builder
+    }
 
-#[cfg(test)]
-mod test {
-    use super::*;
+    /// Standard rated line item with a net amount of 100.0, matching the totals of [minimal_invoice_builder]
+    fn minimal_line_item<'a>() -> LineItemBuilder<'a> {
+        let mut line_item_builder = LineItemBuilder::new();
+
+        line_item_builder
+            .product("Consulting", None)
+            .quantity(1.0)
+            .unit(UnitCode::Hour)
+            .net_price(100.0)
+            .vat(VATCategoryCode::StandardRate, Some(19.0));
+
+        line_item_builder
+    }
 
     #[test]
     /// Tests if a minimum invoice can be built without specifying the legal organization
@@ -860,4 +2741,1155 @@ mod test {
             .is_ok());
         assert!(invoice_builder.build(specification_level).is_ok());
     }
+
+    #[test]
+    /// Tests if the legal organizations of seller and buyer are kept apart and carry their own scheme
+    fn test_specified_legal_organization_scheme_id() {
+        let mut invoice_builder = InvoiceBuilder::new();
+
+        invoice_builder
+            .set_invoice_type_code(InvoiceTypeCode::CommercialInvoice)
+            .set_invoice_nr("INV-123456")
+            .set_date_of_issue(chrono::NaiveDate::from_ymd_opt(2024, 8, 10).unwrap())
+            .set_sellers_name("Seller Corp.")
+            .set_sellers_specified_legal_organization("HRB 12345")
+            .set_sellers_trading_business_name("Seller")
+            .set_sellers_postal_trade_address_country_code(CountryCode::Germany)
+            .set_sellers_specified_tax_registration("DE123456789")
+            .set_buyers_name("Buyer Inc.")
+            .set_buyers_specified_legal_organization("12345678")
+            .set_buyers_specified_legal_organization_scheme_id(IdentifierSchemeCode::Dutch_Chambers)
+            .set_buyers_order_specified_document("OD-2024-001")
+            .set_invoice_currency_code(CurrencyCode::Euro)
+            .set_monetary_summation_tax_basis_total_amount(100.0)
+            .set_monetary_summation_tax_total_amount(19.0)
+            .set_monetary_summation_grand_total_amount(119.0)
+            .set_monetary_summation_due_payable_amount(119.0);
+
+        let xml_string = invoice_builder
+            .to_xml_string(SpecificationLevel::Minimum)
+            .unwrap();
+
+        assert!(xml_string.contains("<ram:SpecifiedLegalOrganization><ram:ID>HRB 12345</ram:ID><ram:TradingBusinessName>Seller</ram:TradingBusinessName></ram:SpecifiedLegalOrganization>"));
+        assert!(xml_string.contains("<ram:SpecifiedLegalOrganization><ram:ID schemeID=\"0106\">12345678</ram:ID></ram:SpecifiedLegalOrganization>"));
+    }
+
+    #[test]
+    /// Tests if a direct debit is only accepted with mandate reference and creditor identifier
+    fn test_all_fields_are_set_direct_debit() {
+        let specification_level = SpecificationLevel::Minimum;
+
+        let mut invoice_builder = InvoiceBuilder::new();
+
+        invoice_builder
+            .set_invoice_type_code(InvoiceTypeCode::CommercialInvoice)
+            .set_invoice_nr("INV-123456")
+            .set_date_of_issue(chrono::NaiveDate::from_ymd_opt(2024, 8, 10).unwrap())
+            .set_sellers_name("Seller Corp.")
+            .set_sellers_postal_trade_address_country_code(CountryCode::Germany)
+            .set_sellers_specified_tax_registration("DE123456789")
+            .set_buyers_name("Buyer Inc.")
+            .set_buyers_order_specified_document("OD-2024-001")
+            .set_invoice_currency_code(CurrencyCode::Euro)
+            .set_monetary_summation_tax_basis_total_amount(100.0)
+            .set_monetary_summation_tax_total_amount(19.0)
+            .set_monetary_summation_grand_total_amount(119.0)
+            .set_monetary_summation_due_payable_amount(119.0)
+            .add_payment_means_direct_debit("DE75512108001245126199");
+
+        let error_text = invoice_builder
+            .all_fields_are_set(specification_level)
+            .unwrap_err();
+        assert!(error_text.contains("Mandate reference not set"));
+        assert!(error_text.contains("Creditor reference ID not set"));
+
+        invoice_builder
+            .set_specified_trade_payment_terms_direct_debit_mandate_id("MANDATE-0815")
+            .set_creditor_reference_id("DE98ZZZ09999999999");
+
+        assert!(invoice_builder
+            .all_fields_are_set(specification_level)
+            .is_ok());
+    }
+
+    #[test]
+    /// Tests if the discount terms are rendered as description below Extended and computed correctly
+    fn test_payment_discount_terms() {
+        let mut invoice_builder = InvoiceBuilder::new();
+
+        invoice_builder
+            .add_specified_trade_payment_terms_discount(10, 2.0)
+            .add_specified_trade_payment_terms_discount_with_basis_amount(20, 1.0, 100.0);
+
+        let payment_terms = invoice_builder.specified_trade_payment_terms_for(SpecificationLevel::En16931, CurrencyCode::Euro);
+        assert_eq!(payment_terms.len(), 1);
+        assert_eq!(
+            payment_terms[0].description.as_deref(),
+            Some("#SKONTO#TAGE=10#PROZENT=2.00#\n#SKONTO#TAGE=20#PROZENT=1.00#BASISBETRAG=100.00#\n")
+        );
+
+        // Every further tier becomes payment terms of its own, holding only the discount terms
+        let payment_terms = invoice_builder.specified_trade_payment_terms_for(SpecificationLevel::Extended, CurrencyCode::Euro);
+        assert_eq!(payment_terms.len(), 2);
+        assert!(payment_terms[0].description.is_none());
+        assert!(payment_terms[1].description.is_none());
+        assert!(payment_terms[1].due_date_time.is_none());
+        assert_eq!(
+            payment_terms[1]
+                .applicable_trade_payment_discount_terms
+                .as_ref()
+                .map(|discount_terms| discount_terms.basis_period_measure.value),
+            Some(20)
+        );
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::Minimum).unwrap_err();
+        assert!(error.contains("Payment terms are not allowed in Minimum specification"));
+
+        let discount_terms = &invoice_builder.payment_discount_terms;
+        assert_eq!(discount_terms[0].discounted_amount(119.0, CurrencyCode::Euro), 116.62);
+        assert_eq!(discount_terms[1].discount_amount(119.0, CurrencyCode::Euro), 1.0);
+
+        // The discount amount is rounded to the invoice currency when building, regardless of when the currency is set
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Extended);
+        invoice_builder
+            .add_line_item(&minimal_line_item())
+            .unwrap()
+            .set_buyer_reference("REF-1")
+            .set_sellers_specified_legal_organization("HRB 1")
+            .set_buyers_specified_legal_organization("HRB 2")
+            .add_specified_trade_payment_terms_discount_with_basis_amount(10, 2.5, 1234.0)
+            .set_invoice_currency_code(CurrencyCode::JapaneseYen);
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::Extended).unwrap();
+        assert!(xml.contains("<ram:ApplicableTradePaymentDiscountTerms><ram:BasisPeriodMeasure unitCode=\"DAY\">10</ram:BasisPeriodMeasure><ram:BasisAmount>1234</ram:BasisAmount><ram:CalculationPercent>2.50</ram:CalculationPercent><ram:ActualDiscountAmount>31</ram:ActualDiscountAmount></ram:ApplicableTradePaymentDiscountTerms>"));
+    }
+
+    #[test]
+    /// Tests if the due date is calculated from the date of issue
+    fn test_set_specified_trade_payment_terms_due_date_in_days() {
+        let mut invoice_builder = InvoiceBuilder::new();
+
+        assert!(invoice_builder
+            .set_specified_trade_payment_terms_due_date_in_days(30)
+            .is_err());
+
+        invoice_builder
+            .set_date_of_issue(chrono::NaiveDate::from_ymd_opt(2024, 11, 15).unwrap())
+            .set_specified_trade_payment_terms_due_date_in_days(30)
+            .unwrap()
+            .set_specified_trade_payment_terms_description("Payable within 30 days net");
+
+        let payment_terms = invoice_builder.specified_trade_payment_terms.as_ref().unwrap();
+        assert_eq!(
+            payment_terms.due_date_time.as_ref().unwrap().payment_due_date.to_naive_date(),
+            chrono::NaiveDate::from_ymd_opt(2024, 12, 15).unwrap()
+        );
+        assert_eq!(payment_terms.description.as_deref(), Some("Payable within 30 days net"));
+    }
+
+    #[test]
+    /// Tests if document level allowances and charges are rounded to the invoice currency and
+    /// summed up into BT-107 and BT-108 when building, and if BT-109 is recalculated
+    fn test_add_allowances_and_charges() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::BasicWithoutLines);
+
+        invoice_builder
+            .add_allowance_percentage(
+                3.0,
+                250.3,
+                VATCategoryCode::StandardRate,
+                Some(19.0),
+                AllowanceChargeReason::Code(AllowanceChargeReasonCode::Discount),
+            )
+            .add_allowance(
+                5.0,
+                VATCategoryCode::StandardRate,
+                Some(19.0),
+                AllowanceChargeReason::Text("Loyalty bonus"),
+            )
+            .add_charge(
+                12.5,
+                VATCategoryCode::StandardRate,
+                Some(19.0),
+                AllowanceChargeReason::Code(ChargeReasonCode::FreightService),
+            );
+
+        assert!(invoice_builder
+            .all_fields_are_set(SpecificationLevel::Minimum)
+            .unwrap_err()
+            .contains("allowances and charges are not allowed"));
+
+        let invoice = invoice_builder.build(SpecificationLevel::BasicWithoutLines).unwrap();
+        let settlement = &invoice.supply_chain_trade_transaction.applicable_header_trade_settlement;
+        assert_eq!(settlement.specified_trade_allowance_charge[0].actual_amount.value, 7.51);
+        let monetary_summation = &settlement.specified_trade_settlement_header_monetary_summation;
+        assert_eq!(monetary_summation.allowance_total_amount.map(|amount| amount.value), Some(12.51));
+        assert_eq!(monetary_summation.charge_total_amount.map(|amount| amount.value), Some(12.5));
+        assert_eq!(monetary_summation.tax_basis_total_amount.map(|amount| amount.value), Some(99.99));
+
+        // The invoice currency set after adding the allowances and charges is used for rounding
+        invoice_builder.set_invoice_currency_code(CurrencyCode::JapaneseYen);
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::BasicWithoutLines).unwrap();
+        assert!(xml.contains("<ram:CalculationPercent>3.00</ram:CalculationPercent><ram:BasisAmount>250</ram:BasisAmount><ram:ActualAmount>8</ram:ActualAmount>"));
+        assert!(xml.contains("<ram:LineTotalAmount>100</ram:LineTotalAmount><ram:ChargeTotalAmount>13</ram:ChargeTotalAmount><ram:AllowanceTotalAmount>13</ram:AllowanceTotalAmount><ram:TaxBasisTotalAmount>100</ram:TaxBasisTotalAmount>"));
+    }
+
+    #[test]
+    /// Tests if allowance and charge reasons are written and validated by BR-33, BR-38, BR-42, BR-44, BR-CL-19 and BR-CL-20
+    fn test_allowance_charge_reasons() {
+        fn violations(invoice_builder: &mut InvoiceBuilder) -> Vec<String> {
+            let invoice = invoice_builder.build(SpecificationLevel::Basic).unwrap();
+            validate_business_rules(&invoice)
+                .into_iter()
+                .map(|violation| violation.rule_id)
+                .collect()
+        }
+        let reason_rules = ["BR-33", "BR-38", "BR-42", "BR-44", "BR-CL-19", "BR-CL-20"];
+
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Basic);
+        invoice_builder
+            .add_line_item(
+                minimal_line_item()
+                    .add_allowance_charge(
+                        SpecifiedLineTradeAllowanceCharge::allowance(1.0)
+                            .with_reason_code(AllowanceChargeReasonCode::SampleDiscount),
+                    )
+                    .add_allowance_charge(SpecifiedLineTradeAllowanceCharge::charge(2.0).with_reason("Engraving")),
+            )
+            .unwrap()
+            .add_allowance(
+                5.0,
+                VATCategoryCode::StandardRate,
+                Some(19.0),
+                AllowanceChargeReason::CodeAndText(AllowanceChargeReasonCode::SampleDiscount, "Samples"),
+            )
+            .add_charge(
+                10.0,
+                VATCategoryCode::StandardRate,
+                Some(19.0),
+                AllowanceChargeReason::Code(ChargeReasonCode::FreightService),
+            );
+
+        let violations_with_reasons = violations(&mut invoice_builder);
+        assert!(!reason_rules.iter().any(|rule| violations_with_reasons.contains(&rule.to_string())));
+        let xml = invoice_builder.build(SpecificationLevel::Basic).unwrap().to_xml_string().unwrap();
+        assert!(xml.contains("<ram:ActualAmount>1.00</ram:ActualAmount><ram:ReasonCode>67</ram:ReasonCode></ram:SpecifiedTradeAllowanceCharge>"));
+        assert!(xml.contains("<ram:ActualAmount>2.00</ram:ActualAmount><ram:Reason>Engraving</ram:Reason></ram:SpecifiedTradeAllowanceCharge>"));
+        assert!(xml.contains("<ram:ActualAmount>5.00</ram:ActualAmount><ram:ReasonCode>67</ram:ReasonCode><ram:Reason>Samples</ram:Reason>"));
+        assert!(xml.contains("<ram:ActualAmount>10.00</ram:ActualAmount><ram:ReasonCode>FC</ram:ReasonCode>"));
+
+        // Allowances and charges without any reason
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Basic);
+        invoice_builder
+            .add_line_item(
+                minimal_line_item()
+                    .add_allowance_charge(SpecifiedLineTradeAllowanceCharge::allowance(1.0))
+                    .add_allowance_charge(SpecifiedLineTradeAllowanceCharge::charge(2.0)),
+            )
+            .unwrap()
+            .add_specified_trade_allowance_charge(SpecifiedTradeAllowanceCharge::allowance(
+                5.0,
+                VATCategoryCode::StandardRate,
+                Some(19.0),
+            ))
+            .add_specified_trade_allowance_charge(SpecifiedTradeAllowanceCharge::charge(
+                10.0,
+                VATCategoryCode::StandardRate,
+                Some(19.0),
+            ));
+
+        let violations_without_reasons = violations(&mut invoice_builder);
+        for rule in ["BR-33", "BR-38", "BR-42", "BR-44"] {
+            assert!(violations_without_reasons.contains(&rule.to_string()), "{rule} not violated");
+        }
+
+        // Reason codes of the wrong code list for allowances and charges
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Basic);
+        invoice_builder
+            .add_line_item(minimal_line_item().add_allowance_charge(
+                SpecifiedLineTradeAllowanceCharge::allowance(1.0).with_reason_code(ChargeReasonCode::Packing),
+            ))
+            .unwrap()
+            .add_specified_trade_allowance_charge(
+                SpecifiedTradeAllowanceCharge::charge(10.0, VATCategoryCode::StandardRate, Some(19.0))
+                    .with_reason_code(AllowanceChargeReasonCode::Discount),
+            );
+
+        let violations_with_wrong_codes = violations(&mut invoice_builder);
+        assert!(violations_with_wrong_codes.contains(&"BR-CL-19".to_string()));
+        assert!(violations_with_wrong_codes.contains(&"BR-CL-20".to_string()));
+    }
+
+    #[test]
+    /// Tests if line allowances and charges are included in the line net amount
+    fn test_line_net_amount_with_allowances_and_charges() {
+        let mut line_item = IncludedSupplyChainTradeLineItem {
+            associated_document_line_document: AssociatedDocumentLineDocument {
+                line_id: "1".to_string(),
+                included_note: None,
+            },
+            specified_trade_product: SpecifiedTradeProduct {
+                global_id: None,
+                seller_assigned_id: None,
+                buyer_assigned_id: None,
+                name: "Product 1",
+                description: None,
+                applicable_product_characteristic: Vec::new(),
+                designated_product_classification: Vec::new(),
+                origin_trade_country: None,
+            },
+            specified_line_trade_agreement: SpecifiedLineTradeAgreement {
+                buyer_order_referenced_document: None,
+                gross_price_product_trade_price: None,
+                net_price_product_trade_price: NetPriceProductTradePrice::new(12.5, None),
+            },
+            specified_line_trade_delivery: SpecifiedLineTradeDelivery {
+                billed_quantity: BilledQuantity::new(UnitCode::Piece, 8.0),
+            },
+            specified_line_trade_settlement: SpecifiedLineTradeSettlement {
+                applicable_trade_tax: ApplicableTradeTax::default(),
+                billing_specified_period: None,
+                specified_trade_allowance_charge: Vec::new(),
+                specified_trade_settlement_line_monetary_summation:
+                    SpecifiedTradeSettlementLineMonetarySummation {
+                        line_total_amount: Amount::default(),
+                    },
+                additional_referenced_document: None,
+                receivable_specified_trade_accounting_account: None,
+            },
+        };
+
+        line_item
+            .add_specified_trade_allowance_charge(
+                SpecifiedLineTradeAllowanceCharge::allowance(0.0)
+                    .with_percentage(10.0, 100.0)
+                    .with_reason_code(AllowanceChargeReasonCode::Discount),
+                CurrencyCode::Euro,
+            )
+            .add_specified_trade_allowance_charge(
+                SpecifiedLineTradeAllowanceCharge::charge(2.35)
+                    .with_reason_code(ChargeReasonCode::Packing),
+                CurrencyCode::Euro,
+            );
+
+        assert_eq!(line_item.line_net_amount(CurrencyCode::Euro), 92.35);
+        assert_eq!(
+            line_item
+                .specified_line_trade_settlement
+                .specified_trade_settlement_line_monetary_summation
+                .line_total_amount
+                .value,
+            92.35
+        );
+    }
+
+    #[test]
+    /// Tests if line items are numbered automatically and their net amounts summed up into BT-106 when building
+    fn test_add_line_item() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Basic);
+
+        invoice_builder
+            .add_line_item(
+                LineItemBuilder::new()
+                    .product("Product 1", Some("4012345678901"))
+                    .quantity(3.0)
+                    .unit(UnitCode::Piece)
+                    .net_price(12.5)
+                    .vat(VATCategoryCode::StandardRate, Some(19.0)),
+            )
+            .unwrap()
+            .add_line_item(
+                LineItemBuilder::new()
+                    .product("Product 2", None)
+                    .quantity(2.0)
+                    .unit(UnitCode::Piece)
+                    .net_price(10.0)
+                    .vat(VATCategoryCode::StandardRate, Some(19.0))
+                    .add_allowance_charge(
+                        SpecifiedLineTradeAllowanceCharge::allowance(1.0)
+                            .with_reason_code(AllowanceChargeReasonCode::Discount),
+                    ),
+            )
+            .unwrap();
+
+        let invoice = invoice_builder.build(SpecificationLevel::Basic).unwrap();
+        let line_items = &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items;
+        assert_eq!(line_items[0].associated_document_line_document.line_id, "1");
+        assert_eq!(line_items[1].associated_document_line_document.line_id, "2");
+        assert_eq!(
+            line_items[1]
+                .specified_line_trade_settlement
+                .specified_trade_settlement_line_monetary_summation
+                .line_total_amount
+                .value,
+            19.0
+        );
+        assert!(line_items[0]
+            .specified_line_trade_settlement
+            .applicable_trade_tax
+            .calculated_amount
+            .is_none());
+        let monetary_summation = &invoice
+            .supply_chain_trade_transaction
+            .applicable_header_trade_settlement
+            .specified_trade_settlement_header_monetary_summation;
+        assert_eq!(monetary_summation.line_total_amount.map(|amount| amount.value), Some(56.5));
+        assert_eq!(monetary_summation.tax_basis_total_amount.map(|amount| amount.value), Some(56.5));
+
+        // Line net amounts are calculated in the invoice currency set after adding the line items
+        invoice_builder.set_invoice_currency_code(CurrencyCode::JapaneseYen);
+        let xml = invoice_builder.build(SpecificationLevel::Basic).unwrap().to_xml_string().unwrap();
+        assert!(xml.contains("<ram:LineTotalAmount>38</ram:LineTotalAmount>"));
+        assert!(xml.contains("<ram:LineTotalAmount>57</ram:LineTotalAmount>"));
+
+        assert!(invoice_builder
+            .add_line_item(LineItemBuilder::new().product("Product 3", None))
+            .is_err());
+    }
+
+    #[test]
+    /// Tests if automatic line IDs skip explicitly set ones and duplicate line IDs are rejected
+    fn test_add_line_item_line_ids() {
+        let line_item = |line_id: Option<&str>| {
+            let mut line_item_builder = LineItemBuilder::new();
+            line_item_builder
+                .product("Product", None)
+                .quantity(1.0)
+                .unit(UnitCode::Piece)
+                .net_price(50.0)
+                .vat(VATCategoryCode::StandardRate, Some(19.0));
+            if let Some(line_id) = line_id {
+                line_item_builder.line_id(line_id);
+            }
+            line_item_builder
+        };
+
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Basic);
+        invoice_builder
+            .add_line_item(&line_item(Some("2")))
+            .unwrap()
+            .add_line_item(&line_item(None))
+            .unwrap();
+
+        let invoice = invoice_builder.build(SpecificationLevel::Basic).unwrap();
+        let line_items = &invoice.supply_chain_trade_transaction.included_supply_chain_trade_line_items;
+        assert_eq!(line_items[0].associated_document_line_document.line_id, "2");
+        assert_eq!(line_items[1].associated_document_line_document.line_id, "3");
+
+        invoice_builder.add_line_item(&line_item(Some("3"))).unwrap();
+        let error = invoice_builder.build(SpecificationLevel::Basic).unwrap_err();
+        assert!(error.contains("Line ID 3 is used more than once"));
+    }
+
+    #[test]
+    /// Tests if the net price is derived from gross price and discount, per base quantity
+    fn test_line_item_gross_price_and_base_quantity() {
+        let line_item = LineItemBuilder::new()
+            .line_id("1")
+            .product("Screws", None)
+            .quantity(250.0)
+            .unit(UnitCode::Piece)
+            .gross_price(8.0, Some(1.5))
+            .base_quantity(100.0, UnitCode::Piece)
+            .vat(VATCategoryCode::StandardRate, Some(19.0))
+            .build()
+            .unwrap();
+
+        let agreement = &line_item.specified_line_trade_agreement;
+        assert_eq!(agreement.net_price_product_trade_price.charge_amount, 6.5);
+        assert_eq!(
+            agreement
+                .net_price_product_trade_price
+                .basis_quantity
+                .as_ref()
+                .unwrap()
+                .value,
+            100.0
+        );
+        assert_eq!(line_item.line_net_amount(CurrencyCode::Euro), 16.25);
+
+        let error = LineItemBuilder::new()
+            .line_id("1")
+            .product("Screws", None)
+            .quantity(250.0)
+            .unit(UnitCode::Piece)
+            .gross_price(8.0, Some(8.5))
+            .vat(VATCategoryCode::StandardRate, Some(19.0))
+            .build()
+            .unwrap_err();
+        assert!(error.contains("Price discount exceeds gross price"));
+    }
+
+    #[test]
+    /// Tests if full card numbers are refused while the last digits are accepted
+    fn test_add_payment_means_card() {
+        let mut invoice_builder = InvoiceBuilder::new();
+
+        assert!(invoice_builder
+            .add_payment_means_card(PaymentMeansCode::CreditCard, "4111 1111 1111 1111", None)
+            .is_err());
+        assert!(invoice_builder
+            .add_payment_means_card(PaymentMeansCode::CreditCard, "1111", Some("Max Mustermann"))
+            .is_ok());
+        assert_eq!(invoice_builder.specified_trade_settlement_payment_means.len(), 1);
+    }
+
+    #[test]
+    /// Tests if unit codes are parsed from and written as their UN/ECE Recommendation 20/21 code
+    fn test_unit_code() {
+        assert_eq!(UnitCode::try_from("HUR"), Ok(UnitCode::Hour));
+        assert_eq!(UnitCode::try_from("KWH"), Ok(UnitCode::KilowattHour));
+        assert_eq!(UnitCode::try_from("XPK"), Ok(UnitCode::Package));
+        assert!(UnitCode::try_from("NOPE").is_err());
+
+        assert_eq!(UnitCode::SquareMetre.as_str(), "MTK");
+        assert_eq!(UnitCode::LumpSum.name(), "Lump sum");
+        assert_eq!(UnitCode::One.as_str(), "C62");
+    }
+
+    #[test]
+    /// Tests if currencies are parsed from their ISO 4217 code and amounts are written with their minor units
+    fn test_currency_code_minor_units() {
+        assert_eq!(CurrencyCode::try_from("JPY"), Ok(CurrencyCode::JapaneseYen));
+        assert_eq!(CurrencyCode::try_from("AED"), Ok(CurrencyCode::UaeDirham));
+        assert!(CurrencyCode::try_from("HRK").is_err());
+        assert_eq!(CurrencyCode::KuwaitiDinar.round(1.23456), 1.235);
+
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Minimum);
+
+        invoice_builder
+            .set_invoice_currency_code(CurrencyCode::JapaneseYen)
+            .set_monetary_summation_tax_basis_total_amount(1000.0)
+            .set_monetary_summation_tax_total_amount(100.4)
+            .set_monetary_summation_grand_total_amount(1100.4)
+            .set_monetary_summation_due_payable_amount(1100.4)
+            .set_applicable_trade_tax_rate_applicable_percent(7.7);
+        invoice_builder
+            .add_line_item(
+                LineItemBuilder::new()
+                    .product("Screw", None)
+                    .quantity(8000.0)
+                    .unit(UnitCode::Piece)
+                    .net_price(0.125)
+                    .vat(VATCategoryCode::StandardRate, Some(7.7)),
+            )
+            .unwrap();
+
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::Minimum).unwrap();
+        assert!(xml.contains("<ram:TaxBasisTotalAmount>1000</ram:TaxBasisTotalAmount>"));
+        assert!(xml.contains("<ram:TaxTotalAmount currencyID=\"JPY\">100</ram:TaxTotalAmount>"));
+        assert!(xml.contains("<ram:DuePayableAmount>1100</ram:DuePayableAmount>"));
+        // Rates and unit prices keep their own precision independent of the currency
+        assert!(xml.contains("<ram:RateApplicablePercent>7.70</ram:RateApplicablePercent>"));
+        assert!(xml.contains("<ram:NetPriceProductTradePrice><ram:ChargeAmount>0.1250</ram:ChargeAmount></ram:NetPriceProductTradePrice>"));
+        assert!(xml.contains("<ram:LineTotalAmount>1000</ram:LineTotalAmount>"));
+    }
+
+    #[test]
+    /// Tests if the total VAT amount in tax currency is converted with the exchange rate and required by BR-53
+    fn test_tax_currency() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Minimum);
+
+        invoice_builder
+            .set_invoice_currency_code(CurrencyCode::SwissFranc)
+            .set_tax_currency_code(CurrencyCode::Euro);
+
+        let invoice = invoice_builder.build(SpecificationLevel::Minimum).unwrap();
+        assert!(validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-53"));
+
+        assert!(invoice_builder
+            .calculate_tax_total_amount_in_tax_currency(1.0627)
+            .is_ok());
+        let invoice = invoice_builder.build(SpecificationLevel::Minimum).unwrap();
+        assert!(!validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-53"));
+
+        let xml = invoice.to_xml_string().unwrap();
+        assert!(xml.contains("<ram:TaxCurrencyCode>EUR</ram:TaxCurrencyCode><ram:InvoiceCurrencyCode>CHF</ram:InvoiceCurrencyCode>"));
+        assert!(xml.contains("<ram:TaxTotalAmount currencyID=\"CHF\">19.00</ram:TaxTotalAmount><ram:TaxTotalAmount currencyID=\"EUR\">20.19</ram:TaxTotalAmount>"));
+
+        let error = minimal_invoice_builder(SpecificationLevel::Minimum)
+            .set_monetary_summation_tax_total_amount_in_tax_currency(20.19)
+            .build(SpecificationLevel::Minimum)
+            .unwrap_err();
+        assert!(error.contains("Tax currency code not set"));
+    }
+
+    #[test]
+    /// Tests if invoicing and line periods are written and validated by BR-29, BR-30, BR-CO-19 and BR-CO-20
+    fn test_billing_specified_period() {
+        let date = |month, day| chrono::NaiveDate::from_ymd_opt(2024, month, day);
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Basic);
+
+        invoice_builder.set_billing_specified_period(date(7, 31), date(7, 1));
+        invoice_builder
+            .add_line_item(
+                LineItemBuilder::new()
+                    .product("Subscription", None)
+                    .quantity(1.0)
+                    .unit(UnitCode::Month)
+                    .net_price(100.0)
+                    .vat(VATCategoryCode::StandardRate, Some(19.0))
+                    .billing_period(None, None),
+            )
+            .unwrap();
+
+        assert!(invoice_builder.all_fields_are_set(SpecificationLevel::Minimum).is_err());
+
+        let invoice = invoice_builder.build(SpecificationLevel::Basic).unwrap();
+        let violations: Vec<String> = validate_business_rules(&invoice)
+            .into_iter()
+            .map(|violation| violation.rule_id)
+            .collect();
+        assert!(violations.contains(&"BR-29".to_string()));
+        assert!(violations.contains(&"BR-CO-20".to_string()));
+        assert!(!violations.contains(&"BR-CO-19".to_string()));
+
+        invoice_builder.set_billing_specified_period(date(7, 1), date(7, 31));
+        let invoice = invoice_builder.build(SpecificationLevel::Basic).unwrap();
+        assert!(!validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-29"));
+        assert!(invoice.to_xml_string().unwrap().contains(
+            "<ram:BillingSpecifiedPeriod><ram:StartDateTime><udt:DateTimeString format=\"102\">20240701</udt:DateTimeString></ram:StartDateTime><ram:EndDateTime><udt:DateTimeString format=\"102\">20240731</udt:DateTimeString></ram:EndDateTime></ram:BillingSpecifiedPeriod>"
+        ));
+    }
+
+    #[test]
+    /// Tests if the deliver to party and the delivery references are written to the header trade delivery
+    fn test_ship_to_trade_party() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::En16931);
+
+        invoice_builder
+            .set_ship_to_name("Buyer Inc. Warehouse")
+            .set_ship_to_global_id(IdentifierSchemeCode::EAN_Location_Code, "4000001987658")
+            .set_ship_to_postal_trade_address_line_one("Dock 4")
+            .set_ship_to_postal_trade_address_city_name("Springfield")
+            .set_despatch_advice_referenced_document("DESADV-42");
+        invoice_builder
+            .add_line_item(
+                LineItemBuilder::new()
+                    .product("Pallet rack", None)
+                    .quantity(1.0)
+                    .unit(UnitCode::Piece)
+                    .net_price(100.0)
+                    .vat(VATCategoryCode::StandardRate, Some(19.0)),
+            )
+            .unwrap();
+
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::BasicWithoutLines).unwrap_err();
+        assert!(error.contains("Ship to postal trade address: Country code not set"));
+        assert!(!error.contains("Despatch advice reference"));
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::Minimum).unwrap_err();
+        assert!(error.contains("Ship to trade party is not allowed in Minimum specification"));
+        assert!(error.contains("Despatch advice reference is not allowed in Minimum specification"));
+
+        invoice_builder
+            .set_ship_to_postal_trade_address_country_code(CountryCode::Germany)
+            .set_receiving_advice_referenced_document("RECADV-7");
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::En16931).unwrap();
+        assert!(xml.contains("<ram:ShipToTradeParty><ram:GlobalID schemeID=\"0088\">4000001987658</ram:GlobalID><ram:Name>Buyer Inc. Warehouse</ram:Name><ram:PostalTradeAddress><ram:LineOne>Dock 4</ram:LineOne><ram:CityName>Springfield</ram:CityName><ram:CountryID>DE</ram:CountryID></ram:PostalTradeAddress></ram:ShipToTradeParty>"));
+        assert!(xml.contains("<ram:DespatchAdviceReferencedDocument><ram:IssuerAssignedID>DESADV-42</ram:IssuerAssignedID></ram:DespatchAdviceReferencedDocument><ram:ReceivingAdviceReferencedDocument><ram:IssuerAssignedID>RECADV-7</ram:IssuerAssignedID></ram:ReceivingAdviceReferencedDocument>"));
+    }
+
+    #[test]
+    /// Tests if payee and seller's tax representative are written and validated by BR-17 to BR-20, BR-56 and BR-S-02
+    fn test_payee_and_sellers_tax_representative() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::BasicWithoutLines);
+
+        invoice_builder
+            .set_sellers_name("Seller Ltd.")
+            .set_sellers_postal_trade_address_country_code(CountryCode::UnitedKingdom)
+            .set_applicable_trade_tax_category_code(VATCategoryCode::StandardRate)
+            .set_payee_id("FACT-1")
+            .set_sellers_tax_representative_postal_trade_address_city_name("Frankfurt");
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::Minimum).unwrap_err();
+        assert!(error.contains("Payee trade party is not allowed in Minimum specification"));
+        assert!(error.contains("Seller's tax representative is not allowed in Minimum specification"));
+
+        let invoice = invoice_builder.build(SpecificationLevel::BasicWithoutLines).unwrap();
+        let violations: Vec<String> = validate_business_rules(&invoice)
+            .into_iter()
+            .map(|violation| violation.rule_id)
+            .collect();
+        for rule_id in ["BR-17", "BR-18", "BR-20", "BR-56"] {
+            assert!(violations.contains(&rule_id.to_string()), "{} not violated", rule_id);
+        }
+        assert!(!violations.contains(&"BR-19".to_string()));
+
+        // The seller has no VAT registration in the EU and relies on its tax representative,
+        // which has to provide the VAT identifier (BR-S-02)
+        invoice_builder.clear_sellers_specified_tax_registration();
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::BasicWithoutLines).unwrap_err();
+        assert!(error.contains("Seller's specified tax registration not set"));
+
+        invoice_builder
+            .set_payee_name("Factoring GmbH")
+            .set_payee_specified_legal_organization(Some(IdentifierSchemeCode::EAN_Location_Code), "4000001000005")
+            .set_sellers_tax_representative_name("Fiscal Rep GmbH")
+            .set_sellers_tax_representative_postal_trade_address_country_code(CountryCode::Germany)
+            .set_sellers_tax_representative_specified_tax_registration("DE987654321");
+        let invoice = invoice_builder.build(SpecificationLevel::BasicWithoutLines).unwrap();
+        let violations: Vec<String> = validate_business_rules(&invoice)
+            .into_iter()
+            .map(|violation| violation.rule_id)
+            .collect();
+        for rule_id in ["BR-17", "BR-18", "BR-19", "BR-20", "BR-56", "BR-S-02"] {
+            assert!(!violations.contains(&rule_id.to_string()), "{} violated", rule_id);
+        }
+
+        let xml = invoice.to_xml_string().unwrap();
+        assert!(xml.contains("<ram:SellerTaxRepresentativeTradeParty><ram:Name>Fiscal Rep GmbH</ram:Name><ram:PostalTradeAddress><ram:CityName>Frankfurt</ram:CityName><ram:CountryID>DE</ram:CountryID></ram:PostalTradeAddress><ram:SpecifiedTaxRegistration><ram:ID schemeID=\"VA\">DE987654321</ram:ID></ram:SpecifiedTaxRegistration></ram:SellerTaxRepresentativeTradeParty>"));
+        assert!(xml.contains("<ram:InvoiceCurrencyCode>EUR</ram:InvoiceCurrencyCode><ram:PayeeTradeParty><ram:ID>FACT-1</ram:ID><ram:Name>Factoring GmbH</ram:Name><ram:SpecifiedLegalOrganization><ram:ID schemeID=\"0088\">4000001000005</ram:ID></ram:SpecifiedLegalOrganization></ram:PayeeTradeParty>"));
+    }
+
+    #[test]
+    /// Tests if the document references are optional, restricted to their specification level and written in schema order
+    fn test_document_references() {
+        let invoice = minimal_invoice_builder(SpecificationLevel::Minimum)
+            .build(SpecificationLevel::Minimum)
+            .unwrap();
+        assert!(!invoice.to_xml_string().unwrap().contains("ReferencedDocument"));
+
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::En16931);
+        invoice_builder
+            .set_contract_referenced_document("CON-2024-7")
+            .set_specified_procuring_project("PRJ-12", "New office")
+            .set_sellers_order_referenced_document("SO-555")
+            .set_tender_or_lot_reference("LOT-3")
+            .set_invoiced_object_identifier(Some("AAG"), "METER-0815")
+            .set_invoiced_object_identifier(Some("AAG"), "METER-0816");
+        invoice_builder
+            .add_line_item(
+                LineItemBuilder::new()
+                    .product("Office furniture", None)
+                    .quantity(1.0)
+                    .unit(UnitCode::LumpSum)
+                    .net_price(100.0)
+                    .vat(VATCategoryCode::StandardRate, Some(19.0)),
+            )
+            .unwrap();
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::Minimum).unwrap_err();
+        assert!(error.contains("Contract reference is not allowed in Minimum specification"));
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::BasicWithoutLines).unwrap_err();
+        assert!(!error.contains("Contract reference"));
+        assert!(error.contains("Seller's order reference is only allowed from EN 16931 specification"));
+        assert!(error.contains("Project reference is only allowed from EN 16931 specification"));
+        assert!(error.contains("Tender or lot reference and invoiced object identifier are only allowed from EN 16931 specification"));
+
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::En16931).unwrap();
+        assert!(xml.contains("<ram:SellerOrderReferencedDocument><ram:IssuerAssignedID>SO-555</ram:IssuerAssignedID></ram:SellerOrderReferencedDocument><ram:ContractReferencedDocument><ram:IssuerAssignedID>CON-2024-7</ram:IssuerAssignedID></ram:ContractReferencedDocument><ram:AdditionalReferencedDocument><ram:IssuerAssignedID>LOT-3</ram:IssuerAssignedID><ram:TypeCode>50</ram:TypeCode></ram:AdditionalReferencedDocument><ram:AdditionalReferencedDocument><ram:IssuerAssignedID>METER-0816</ram:IssuerAssignedID><ram:TypeCode>130</ram:TypeCode><ram:ReferenceTypeCode>AAG</ram:ReferenceTypeCode></ram:AdditionalReferencedDocument><ram:SpecifiedProcuringProject><ram:ID>PRJ-12</ram:ID><ram:Name>New office</ram:Name></ram:SpecifiedProcuringProject>"));
+    }
+
+    #[test]
+    /// Tests if supporting documents are referenced or embedded as base64 with a validated MIME code
+    fn test_additional_referenced_document() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::En16931);
+
+        assert!(invoice_builder
+            .add_additional_referenced_document_attachment("TS-1", None, "timesheet.docx", "application/msword", b"Hello")
+            .is_err());
+        invoice_builder
+            .add_additional_referenced_document("DN-77", Some("Delivery note"), Some("https://seller-corp.example/dn/77"))
+            .add_additional_referenced_document_attachment("TS-1", Some("Timesheet"), "timesheet.csv", "text/csv", b"Hello")
+            .unwrap();
+        invoice_builder
+            .add_line_item(
+                LineItemBuilder::new()
+                    .product("Consulting", None)
+                    .quantity(1.0)
+                    .unit(UnitCode::Hour)
+                    .net_price(100.0)
+                    .vat(VATCategoryCode::StandardRate, Some(19.0)),
+            )
+            .unwrap();
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::Basic).unwrap_err();
+        assert!(error.contains("Supporting documents are only allowed from EN 16931 specification"));
+        assert!(!error.contains("Tender or lot reference"));
+
+        let invoice = invoice_builder.build(SpecificationLevel::En16931).unwrap();
+        let attachment = invoice.supply_chain_trade_transaction.applicable_header_trade_agreement.additional_referenced_document[1]
+            .attachment_binary_object
+            .clone()
+            .unwrap();
+        assert_eq!(attachment.content, "SGVsbG8=");
+        assert_eq!(attachment.size(), 5);
+        assert_eq!(attachment.decoded_content(5).unwrap(), b"Hello");
+        assert!(attachment.decoded_content(4).is_err());
+
+        assert!(!validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-52"));
+        let xml = invoice.to_xml_string().unwrap();
+        assert!(xml.contains("<ram:AdditionalReferencedDocument><ram:IssuerAssignedID>DN-77</ram:IssuerAssignedID><ram:URIID>https://seller-corp.example/dn/77</ram:URIID><ram:TypeCode>916</ram:TypeCode><ram:Name>Delivery note</ram:Name></ram:AdditionalReferencedDocument>"));
+        assert!(xml.contains("<ram:AdditionalReferencedDocument><ram:IssuerAssignedID>TS-1</ram:IssuerAssignedID><ram:TypeCode>916</ram:TypeCode><ram:Name>Timesheet</ram:Name><ram:AttachmentBinaryObject mimeCode=\"text/csv\" filename=\"timesheet.csv\">SGVsbG8=</ram:AttachmentBinaryObject></ram:AdditionalReferencedDocument>"));
+
+        invoice_builder.add_additional_referenced_document("", None, None);
+        let invoice = invoice_builder.build(SpecificationLevel::En16931).unwrap();
+        assert!(validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-52"));
+
+        for data in [&b""[..], b"a", b"ab", b"abc", b"\x00\xff\x10\x80"] {
+            let encoded = components::functions::base64_encode(data);
+            assert_eq!(components::functions::base64_decode(&encoded).unwrap(), data);
+        }
+        assert!(components::functions::base64_decode("AA==AAAA").is_err());
+        assert!(components::functions::base64_decode("AAA").is_err());
+        assert!(components::functions::base64_decode("SGVs*G8=").is_err());
+        // Only the canonical encoding is accepted, bits beyond the padding have to be zero
+        assert_eq!(components::functions::base64_decode("QQ==").unwrap(), b"A");
+        assert!(components::functions::base64_decode("QR==").is_err());
+        assert!(components::functions::base64_decode("SGVsbG9=").is_err());
+
+        // The size is calculated from the encoded content, also if it is wrapped into lines
+        let attachment = AttachmentBinaryObject {
+            content: "SGVs\nbG8=\n".to_string(),
+            ..attachment
+        };
+        assert_eq!(attachment.size(), 5);
+        assert_eq!(attachment.decoded_content(5).unwrap(), b"Hello");
+        let attachment = AttachmentBinaryObject {
+            content: "AAAA".repeat(1000),
+            ..attachment
+        };
+        assert_eq!(attachment.size(), 3000);
+        let error = attachment.decoded_content(2999).unwrap_err();
+        assert!(error.contains("exceeding the limit of 2999 bytes"));
+        assert_eq!(attachment.decoded_content(3000).unwrap().len(), 3000);
+    }
+
+    #[test]
+    /// Tests if item identifiers, classification, origin and attributes are written and validated by BR-54 and BR-65
+    fn test_line_item_product_details() {
+        let line_item = LineItemBuilder::new()
+            .line_id("1")
+            .product("T-Shirt", Some("4012345000016"))
+            .seller_assigned_id("TS-RED-L")
+            .buyer_assigned_id("B-4711")
+            .add_attribute("Colour", "Red")
+            .add_attribute("Size", "L")
+            .add_classification("TST", Some("19.0501"), "53103001")
+            .origin_country(CountryCode::Portugal)
+            .quantity(2.0)
+            .unit(UnitCode::Piece)
+            .net_price(9.5)
+            .vat(VATCategoryCode::StandardRate, Some(19.0))
+            .build()
+            .unwrap();
+        let product = quick_xml::se::to_string(&line_item.specified_trade_product).unwrap();
+        assert!(product.contains("<ram:GlobalID schemeID=\"0160\">4012345000016</ram:GlobalID><ram:SellerAssignedID>TS-RED-L</ram:SellerAssignedID><ram:BuyerAssignedID>B-4711</ram:BuyerAssignedID><ram:Name>T-Shirt</ram:Name>"));
+        assert!(product.contains("<ram:ApplicableProductCharacteristic><ram:Description>Colour</ram:Description><ram:Value>Red</ram:Value></ram:ApplicableProductCharacteristic><ram:ApplicableProductCharacteristic><ram:Description>Size</ram:Description><ram:Value>L</ram:Value></ram:ApplicableProductCharacteristic><ram:DesignatedProductClassification><ram:ClassCode listID=\"TST\" listVersionID=\"19.0501\">53103001</ram:ClassCode></ram:DesignatedProductClassification><ram:OriginTradeCountry><ram:ID>PT</ram:ID></ram:OriginTradeCountry>"));
+
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::En16931);
+        invoice_builder
+            .add_line_item(
+                LineItemBuilder::new()
+                    .product("T-Shirt", None)
+                    .add_attribute("Colour", "")
+                    .add_classification("", None, "53103001")
+                    .quantity(2.0)
+                    .unit(UnitCode::Piece)
+                    .net_price(50.0)
+                    .vat(VATCategoryCode::StandardRate, Some(19.0)),
+            )
+            .unwrap();
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::Basic).unwrap_err();
+        assert!(error.contains("Line item: Item identifiers, classification, origin and attributes are only allowed from EN 16931 specification"));
+
+        let invoice = invoice_builder.build(SpecificationLevel::En16931).unwrap();
+        let violations: Vec<String> = validate_business_rules(&invoice)
+            .into_iter()
+            .map(|violation| violation.rule_id)
+            .collect();
+        assert!(violations.contains(&"BR-54".to_string()));
+        assert!(violations.contains(&"BR-65".to_string()));
+    }
+
+    #[test]
+    /// Tests if the order line reference, object identifier and accounting reference are written to the line item
+    fn test_line_item_references() {
+        let mut line_item_builder = LineItemBuilder::new();
+        line_item_builder
+            .product("Electricity", None)
+            .quantity(350.0)
+            .unit(UnitCode::KilowattHour)
+            .net_price(0.3)
+            .vat(VATCategoryCode::StandardRate, Some(19.0))
+            .buyer_order_line_reference("10")
+            .object_identifier(Some("AAG"), "METER-0815")
+            .accounting_reference("4711-COST");
+
+        let mut invoice_builder = InvoiceBuilder::new();
+        invoice_builder.add_line_item(&line_item_builder).unwrap();
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::Basic).unwrap_err();
+        assert!(error.contains("Line item: Order line reference, object identifier and accounting reference are only allowed from EN 16931 specification"));
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::En16931).unwrap_err();
+        assert!(!error.contains("Order line reference"));
+
+        let line_item = quick_xml::se::to_string(&invoice_builder.included_supply_chain_trade_line_items[0]).unwrap();
+        assert!(line_item.contains("<ram:SpecifiedLineTradeAgreement><ram:BuyerOrderReferencedDocument><ram:LineID>10</ram:LineID></ram:BuyerOrderReferencedDocument><ram:NetPriceProductTradePrice>"));
+        assert!(line_item.contains("</ram:SpecifiedTradeSettlementLineMonetarySummation><ram:AdditionalReferencedDocument><ram:IssuerAssignedID>METER-0815</ram:IssuerAssignedID><ram:TypeCode>130</ram:TypeCode><ram:ReferenceTypeCode>AAG</ram:ReferenceTypeCode></ram:AdditionalReferencedDocument><ram:ReceivableSpecifiedTradeAccountingAccount><ram:ID>4711-COST</ram:ID></ram:ReceivableSpecifiedTradeAccountingAccount></ram:SpecifiedLineTradeSettlement>"));
+    }
+
+    #[test]
+    /// Tests if the seller's contact is written before the postal address and required by XRechnung (BT-41, BT-42, BT-43)
+    fn test_sellers_defined_trade_contact() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::XRechnung);
+
+        invoice_builder
+            .set_sellers_uri_universal_communication(ElectronicAddressSchemeCode::ElectronicMail, "invoice@seller-corp.example")
+            .set_buyers_uri_universal_communication(ElectronicAddressSchemeCode::LeitwegId, "991-12345-67")
+            .add_payment_means_credit_transfer("DE75512108001245126199", None, None)
+            .add_line_item(&minimal_line_item())
+            .unwrap();
+        assert!(invoice_builder.all_fields_are_set(SpecificationLevel::En16931).is_ok());
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::XRechnung).unwrap_err();
+        assert!(error.contains("Seller's contact not set"));
+
+        invoice_builder.set_sellers_defined_trade_contact_department_name("Accounting");
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::XRechnung).unwrap_err();
+        assert!(!error.contains("Person or department name not set"));
+        assert!(error.contains("Seller's contact: Telephone number not set"));
+        assert!(error.contains("Seller's contact: Email address not set"));
+
+        invoice_builder
+            .set_sellers_defined_trade_contact_person_name("Erika Musterfrau")
+            .set_sellers_defined_trade_contact_telephone("+49 30 1234567")
+            .set_sellers_defined_trade_contact_email("erika@seller-corp.example");
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::XRechnung).unwrap();
+        assert!(xml.contains("<ram:Name>Seller Corp.</ram:Name><ram:DefinedTradeContact><ram:PersonName>Erika Musterfrau</ram:PersonName><ram:DepartmentName>Accounting</ram:DepartmentName><ram:TelephoneUniversalCommunication><ram:CompleteNumber>+49 30 1234567</ram:CompleteNumber></ram:TelephoneUniversalCommunication><ram:EmailURIUniversalCommunication><ram:URIID>erika@seller-corp.example</ram:URIID></ram:EmailURIUniversalCommunication></ram:DefinedTradeContact><ram:PostalTradeAddress>"));
+    }
+
+    #[test]
+    /// Tests if the electronic addresses are written with their EAS scheme and required by XRechnung (BT-34, BT-49)
+    fn test_uri_universal_communication() {
+        assert_eq!(ElectronicAddressSchemeCode::ElectronicMail.as_str(), "EM");
+        assert_eq!(ElectronicAddressSchemeCode::LeitwegId.as_str(), "0204");
+
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::XRechnung);
+
+        invoice_builder
+            .set_sellers_defined_trade_contact_person_name("Erika Musterfrau")
+            .set_sellers_defined_trade_contact_telephone("+49 30 1234567")
+            .set_sellers_defined_trade_contact_email("erika@seller-corp.example")
+            .add_payment_means_credit_transfer("DE75512108001245126199", None, None)
+            .add_line_item(&minimal_line_item())
+            .unwrap();
+        assert!(invoice_builder.all_fields_are_set(SpecificationLevel::En16931).is_ok());
+        let error = invoice_builder.all_fields_are_set(SpecificationLevel::XRechnung).unwrap_err();
+        assert!(error.contains("Seller's electronic address not set"));
+        assert!(error.contains("Buyer's electronic address not set"));
+
+        invoice_builder
+            .set_sellers_uri_universal_communication(ElectronicAddressSchemeCode::ElectronicMail, "invoice@seller-corp.example")
+            .set_buyers_uri_universal_communication(ElectronicAddressSchemeCode::LeitwegId, "991-12345-67");
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::XRechnung).unwrap();
+        assert!(xml.contains("</ram:PostalTradeAddress><ram:URIUniversalCommunication><ram:URIID schemeID=\"EM\">invoice@seller-corp.example</ram:URIID></ram:URIUniversalCommunication>"));
+        assert!(xml.contains("<ram:URIUniversalCommunication><ram:URIID schemeID=\"0204\">991-12345-67</ram:URIID></ram:URIUniversalCommunication>"));
+    }
+
+    #[test]
+    /// Tests if several seller identifiers (BT-29) and a single buyer identifier (BT-46) are written with their scheme
+    fn test_seller_and_buyer_identifiers() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Minimum);
+
+        invoice_builder
+            .add_sellers_id("SUP-4711")
+            .add_sellers_id("SUP-4712")
+            .add_sellers_global_id(IdentifierSchemeCode::EAN_Location_Code, "4000001123452")
+            .set_buyers_id("CUST-1")
+            .set_buyers_id("CUST-2")
+            .set_buyers_global_id(IdentifierSchemeCode::EAN_Location_Code, "4000001000005");
+
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::Minimum).unwrap();
+        assert!(xml.contains("<ram:SellerTradeParty><ram:ID>SUP-4711</ram:ID><ram:ID>SUP-4712</ram:ID><ram:GlobalID schemeID=\"0088\">4000001123452</ram:GlobalID><ram:Name>Seller Corp.</ram:Name>"));
+        assert!(xml.contains("<ram:BuyerTradeParty><ram:ID>CUST-2</ram:ID><ram:GlobalID schemeID=\"0088\">4000001000005</ram:GlobalID><ram:Name>Buyer Inc.</ram:Name>"));
+        assert!(!xml.contains("CUST-1"));
+    }
+
+    #[test]
+    /// Tests the seller's tax registration rules BR-S-02, BR-Z-02, BR-E-02, BR-AE-02, BR-IC-02, BR-G-02 and BR-O-02
+    /// for every VAT category with a VAT identifier, a local tax number, a tax representative and without registration
+    fn test_sellers_tax_registration_per_vat_category() {
+        enum Registration {
+            None,
+            VatIdentifier,
+            FiscalNumber,
+            TaxRepresentative,
+        }
+
+        fn violations(category_code: VATCategoryCode, registration: Registration, buyers_vat_identifier: bool) -> Result<Vec<String>, String> {
+            let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::BasicWithoutLines);
+            invoice_builder
+                .set_applicable_trade_tax_category_code(category_code)
+                .clear_sellers_specified_tax_registration();
+            if category_code != VATCategoryCode::StandardRate {
+                invoice_builder.set_applicable_trade_tax_rate_applicable_percent(0.0);
+            }
+            match registration {
+                Registration::None => {}
+                Registration::VatIdentifier => {
+                    invoice_builder.set_sellers_specified_tax_registration("DE123456789");
+                }
+                Registration::FiscalNumber => {
+                    invoice_builder.add_sellers_specified_tax_registration(TaxRegistrationSchemeCode::FiscalNumber, "201/113/40209");
+                }
+                Registration::TaxRepresentative => {
+                    invoice_builder
+                        .set_sellers_tax_representative_name("Fiscal Rep GmbH")
+                        .set_sellers_tax_representative_postal_trade_address_country_code(CountryCode::Germany)
+                        .set_sellers_tax_representative_specified_tax_registration("DE987654321");
+                }
+            }
+            if buyers_vat_identifier {
+                invoice_builder.set_buyers_specified_tax_registration("FR12345678901");
+            }
+
+            let invoice = invoice_builder.build(SpecificationLevel::BasicWithoutLines)?;
+            Ok(validate_business_rules(&invoice)
+                .into_iter()
+                .map(|violation| violation.rule_id)
+                .collect())
+        }
+
+        let categories = [
+            (VATCategoryCode::StandardRate, "BR-S-02"),
+            (VATCategoryCode::ZeroRatedGoods, "BR-Z-02"),
+            (VATCategoryCode::ExemptFromTax, "BR-E-02"),
+            (VATCategoryCode::VatReverseCharge, "BR-AE-02"),
+            (VATCategoryCode::VatExemptEEAIntraCommunity, "BR-IC-02"),
+            (VATCategoryCode::FreeExportItemTaxNotCharged, "BR-G-02"),
+            (VATCategoryCode::ServiceOutsideScopeOfTax, "BR-O-02"),
+        ];
+        for (category_code, rule_id) in categories {
+            let buyers_vat_identifier = matches!(
+                category_code,
+                VATCategoryCode::VatReverseCharge | VATCategoryCode::VatExemptEEAIntraCommunity
+            );
+
+            // The builder requires BT-31, BT-32 or BT-63 regardless of the VAT category
+            let error = violations(category_code, Registration::None, buyers_vat_identifier).unwrap_err();
+            assert!(error.contains("Seller's specified tax registration not set"), "{}", rule_id);
+
+            // Only "Not subject to VAT" forbids the seller's VAT identifier
+            let violated = violations(category_code, Registration::VatIdentifier, buyers_vat_identifier).unwrap();
+            assert_eq!(violated.contains(&rule_id.to_string()), category_code == VATCategoryCode::ServiceOutsideScopeOfTax, "{} with VA", rule_id);
+
+            // Intra-community supplies and exports need a VAT identifier, a local tax number is not sufficient
+            let violated = violations(category_code, Registration::FiscalNumber, buyers_vat_identifier).unwrap();
+            assert_eq!(
+                violated.contains(&rule_id.to_string()),
+                matches!(category_code, VATCategoryCode::VatExemptEEAIntraCommunity | VATCategoryCode::FreeExportItemTaxNotCharged),
+                "{} with FC",
+                rule_id
+            );
+
+            // The tax representative's VAT identifier counts like the seller's one
+            let violated = violations(category_code, Registration::TaxRepresentative, buyers_vat_identifier).unwrap();
+            assert_eq!(violated.contains(&rule_id.to_string()), category_code == VATCategoryCode::ServiceOutsideScopeOfTax, "{} with tax representative", rule_id);
+        }
+
+        // Reverse charge and intra-community supplies need the buyer's VAT identifier
+        let violated = violations(VATCategoryCode::VatReverseCharge, Registration::VatIdentifier, false).unwrap();
+        assert!(violated.contains(&"BR-AE-02".to_string()));
+        let violated = violations(VATCategoryCode::VatExemptEEAIntraCommunity, Registration::VatIdentifier, false).unwrap();
+        assert!(violated.contains(&"BR-IC-02".to_string()));
+
+        // The buyer's legal registration identifier replaces the VAT identifier for reverse charge only
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::BasicWithoutLines);
+        invoice_builder
+            .set_applicable_trade_tax_category_code(VATCategoryCode::VatReverseCharge)
+            .set_applicable_trade_tax_rate_applicable_percent(0.0)
+            .set_buyers_specified_legal_organization("HRB 12345");
+        let invoice = invoice_builder.build(SpecificationLevel::BasicWithoutLines).unwrap();
+        assert!(!validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-AE-02"));
+        invoice_builder.set_applicable_trade_tax_category_code(VATCategoryCode::VatExemptEEAIntraCommunity);
+        let invoice = invoice_builder.build(SpecificationLevel::BasicWithoutLines).unwrap();
+        assert!(validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-IC-02"));
+
+        // "Not subject to VAT" rejects the buyer's VAT identifier as well
+        let violated = violations(VATCategoryCode::ServiceOutsideScopeOfTax, Registration::FiscalNumber, true).unwrap();
+        assert!(violated.contains(&"BR-O-02".to_string()));
+    }
+
+    #[test]
+    /// Tests if several VAT identifiers and local tax numbers of the seller are written in the order they were added
+    fn test_add_sellers_specified_tax_registration() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Minimum);
+        invoice_builder
+            .add_sellers_specified_tax_registration(TaxRegistrationSchemeCode::VatIdentifier, "ATU12345678")
+            .add_sellers_specified_tax_registration(TaxRegistrationSchemeCode::FiscalNumber, "201/113/40209")
+            .add_sellers_specified_tax_registration(TaxRegistrationSchemeCode::FiscalNumber, "99/815/08150");
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::Minimum).unwrap();
+        assert!(xml.contains("<ram:SpecifiedTaxRegistration><ram:ID schemeID=\"VA\">DE123456789</ram:ID></ram:SpecifiedTaxRegistration><ram:SpecifiedTaxRegistration><ram:ID schemeID=\"VA\">ATU12345678</ram:ID></ram:SpecifiedTaxRegistration><ram:SpecifiedTaxRegistration><ram:ID schemeID=\"FC\">201/113/40209</ram:ID></ram:SpecifiedTaxRegistration><ram:SpecifiedTaxRegistration><ram:ID schemeID=\"FC\">99/815/08150</ram:ID></ram:SpecifiedTaxRegistration>"));
+
+        // Setting the VAT identifier replaces all previous ones but keeps the local tax numbers
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::Minimum);
+        invoice_builder
+            .add_sellers_specified_tax_registration(TaxRegistrationSchemeCode::VatIdentifier, "ATU12345678")
+            .add_sellers_specified_tax_registration(TaxRegistrationSchemeCode::FiscalNumber, "201/113/40209")
+            .set_sellers_specified_tax_registration("DE999999999");
+        let xml = invoice_builder.to_xml_string(SpecificationLevel::Minimum).unwrap();
+        assert!(xml.contains("<ram:SpecifiedTaxRegistration><ram:ID schemeID=\"FC\">201/113/40209</ram:ID></ram:SpecifiedTaxRegistration><ram:SpecifiedTaxRegistration><ram:ID schemeID=\"VA\">DE999999999</ram:ID></ram:SpecifiedTaxRegistration>"));
+        assert!(!xml.contains("DE123456789"));
+        assert!(!xml.contains("ATU12345678"));
+    }
+
+    #[test]
+    /// Tests if a credit transfer is written in CII order and if payment instructions are validated by BR-49, BR-51 and BR-61
+    fn test_payment_means_credit_transfer_and_violations() {
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::En16931);
+        invoice_builder
+            .add_line_item(&minimal_line_item())
+            .unwrap()
+            .add_payment_means_credit_transfer("DE02120300000000202051", Some("Seller Corp."), Some("BYLADEM1001"));
+        let invoice = invoice_builder.build(SpecificationLevel::En16931).unwrap();
+        let violations: Vec<String> = validate_business_rules(&invoice)
+            .into_iter()
+            .map(|violation| violation.rule_id)
+            .collect();
+        for rule_id in ["BR-49", "BR-50", "BR-51", "BR-61"] {
+            assert!(!violations.contains(&rule_id.to_string()), "{} violated", rule_id);
+        }
+        assert!(invoice.to_xml_string().unwrap().contains("<ram:SpecifiedTradeSettlementPaymentMeans><ram:TypeCode>58</ram:TypeCode><ram:PayeePartyCreditorFinancialAccount><ram:IBANID>DE02120300000000202051</ram:IBANID><ram:AccountName>Seller Corp.</ram:AccountName></ram:PayeePartyCreditorFinancialAccount><ram:PayeeSpecifiedCreditorFinancialInstitution><ram:BICID>BYLADEM1001</ram:BICID></ram:PayeeSpecifiedCreditorFinancialInstitution></ram:SpecifiedTradeSettlementPaymentMeans>"));
+
+        // BR-49: empty payment means type code
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::BasicWithoutLines);
+        invoice_builder.add_specified_trade_settlement_payment_means(SpecifiedTradeSettlementPaymentMeans::new(
+            PaymentMeansCode::MutuallyDefined(" "),
+        ));
+        let invoice = invoice_builder.build(SpecificationLevel::BasicWithoutLines).unwrap();
+        assert!(validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-49"));
+
+        // BR-61: credit transfer without IBAN or proprietary account identifier
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::BasicWithoutLines);
+        invoice_builder.add_specified_trade_settlement_payment_means(SpecifiedTradeSettlementPaymentMeans::new(
+            PaymentMeansCode::SepaCreditTransfer,
+        ));
+        let invoice = invoice_builder.build(SpecificationLevel::BasicWithoutLines).unwrap();
+        assert!(validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-61"));
+
+        // BR-51: card number with more than the first 6 and last 4 digits, bypassing ApplicableTradeSettlementFinancialCard::new
+        let mut payment_means = SpecifiedTradeSettlementPaymentMeans::new(PaymentMeansCode::CreditCard);
+        payment_means.applicable_trade_settlement_financial_card = Some(ApplicableTradeSettlementFinancialCard {
+            id: "4111 11XX XXX1 1111",
+            cardholder_name: None,
+        });
+        let mut invoice_builder = minimal_invoice_builder(SpecificationLevel::BasicWithoutLines);
+        invoice_builder.add_specified_trade_settlement_payment_means(payment_means);
+        let invoice = invoice_builder.build(SpecificationLevel::BasicWithoutLines).unwrap();
+        assert!(validate_business_rules(&invoice)
+            .iter()
+            .any(|violation| violation.rule_id == "BR-51"));
+    }
 }
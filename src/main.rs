@@ -1,16 +1,6 @@
-use zugferd::components::enums::unit_code::UnitCode;
-use zugferd::{ApplicableTradeTax, BilledQuantity, CountryCode, CurrencyCode, InvoiceBuilder, InvoiceTypeCode, NetPriceProductTradePrice, SpecificationLevel, SpecifiedTradeSettlementLineMonetarySummation};
+use zugferd::{CountryCode, CurrencyCode, ElectronicAddressSchemeCode, IdentifierSchemeCode, InvoiceBuilder, TaxRegistrationSchemeCode, InvoiceTypeCode, SpecificationLevel};
 // For Basic specification
-use zugferd::{
-    IncludedSupplyChainTradeLineItem,
-    AssociatedDocumentLineDocument,
-    SpecifiedTradeProduct,
-    GlobalID,
-    SpecifiedLineTradeAgreement,
-    SpecifiedLineTradeDelivery,
-    SpecifiedLineTradeSettlement,
-    components::enums::identifier_scheme_code::IdentifierSchemeCode,
-};
+use zugferd::{LineItemBuilder, UnitCode, VATCategoryCode};
 
 fn main() {
     //Initialize and pass first data
@@ -80,6 +70,20 @@ fn main() {
         .set_sellers_postal_trade_address_postcode_code("66666")
         .set_sellers_postal_trade_address_city_name("Hometown");
 
+    invoice_builder
+        .set_sellers_defined_trade_contact_person_name("Max Mustermann")
+        .set_sellers_defined_trade_contact_telephone("+49891234567")
+        .set_sellers_defined_trade_contact_email("max@seller-corp.example")
+        .set_sellers_uri_universal_communication(ElectronicAddressSchemeCode::ElectronicMail, "invoices@seller-corp.example");
+
+    invoice_builder
+        .add_sellers_global_id(IdentifierSchemeCode::EAN_Location_Code, "4000001123452")
+        .add_sellers_specified_tax_registration(TaxRegistrationSchemeCode::FiscalNumber, "201/113/40209")
+        .set_buyers_id("GE2020211");
+
+    invoice_builder
+        .set_buyers_uri_universal_communication(ElectronicAddressSchemeCode::LeitwegId, "04011000-12345-34");
+
     invoice_builder
         .set_buyers_postal_trade_address_line_one("Main street")
         .set_buyers_postal_trade_address_line_two("Near pizzeria")
@@ -94,97 +98,36 @@ fn main() {
         .set_applicable_trade_tax_calculated_amount(12.44)
         .set_applicable_trade_tax_rate_applicable_percent(42.0);
 
+    invoice_builder
+        .set_payment_reference("INV-123456")
+        .add_payment_means_credit_transfer("DE02120300000000202051", Some("Seller Corp."), Some("BYLADEM1001"));
+
     invoice_builder.set_specified_trade_payment_terms_due_date(chrono::NaiveDate::from_ymd_opt(2025,4,12).unwrap());
 
+    // The line total amount is summed up from the line items below
     invoice_builder
-        .set_monetary_summation_line_total_amount(16.90)
         .set_monetary_summation_charge_total_amount(0.0)
         .set_monetary_summation_allowance_total_amount(0.0);
 
     //Going even further with Basic specification
-    invoice_builder.add_supply_chain_trade_line_item(IncludedSupplyChainTradeLineItem {
-        associated_document_line_document:AssociatedDocumentLineDocument {
-            line_id: "1",
-            included_note: None,
-        },
-        specified_trade_product:SpecifiedTradeProduct {
-            global_id: Some(GlobalID {
-                value: "1234567890123",
-                scheme_id: IdentifierSchemeCode::GTIN,
-            }),
-            name: "Product 1",
-            description: None,
-        },
-        specified_line_trade_agreement:SpecifiedLineTradeAgreement {
-            gross_price_product_trade_price: None,
-            net_price_product_trade_price: NetPriceProductTradePrice {
-                charge_amount: 100.0,
-            },
-        },
-        specified_line_trade_delivery:SpecifiedLineTradeDelivery {
-            billed_quantity: BilledQuantity {
-                value: 1.0,
-                unit_code: UnitCode::Piece,
-            },
-        },
-        specified_line_trade_settlement:SpecifiedLineTradeSettlement {
-            applicable_trade_tax: ApplicableTradeTax {
-                calculated_amount: Some(19.0),
-                type_code: "VAT",
-                category_code: zugferd::VATCategoryCode::StandardRate,
-                basis_amount: Some(100.0),
-                rate_applicable_percent: Some(19.0),
-                exemption_reason: None,
-                exemption_reason_code: None,
-            },
-            specified_trade_settlement_line_monetary_summation: SpecifiedTradeSettlementLineMonetarySummation {
-                line_total_amount: 119.0,
-            },
-            specified_trade_allowance_charge: Vec::new(),
-        },
-    });
-
-    invoice_builder.add_supply_chain_trade_line_item(IncludedSupplyChainTradeLineItem {
-        associated_document_line_document:AssociatedDocumentLineDocument {
-            line_id: "1",
-            included_note: None,
-        },
-        specified_trade_product:SpecifiedTradeProduct {
-            global_id: Some(GlobalID {
-                value: "2546585465423",
-                scheme_id: IdentifierSchemeCode::GTIN,
-            }),
-            name: "Product 2",
-            description: Some("This is an interesting second product."),
-        },
-        specified_line_trade_agreement:SpecifiedLineTradeAgreement {
-            gross_price_product_trade_price: None,
-            net_price_product_trade_price: NetPriceProductTradePrice {
-                charge_amount: 1.0,
-            },
-        },
-        specified_line_trade_delivery:SpecifiedLineTradeDelivery {
-            billed_quantity: BilledQuantity {
-                value: 44.0,
-                unit_code: UnitCode::Piece,
-            },
-        },
-        specified_line_trade_settlement:SpecifiedLineTradeSettlement {
-            applicable_trade_tax: ApplicableTradeTax {
-                calculated_amount: Some(0.44*19.0),
-                type_code: "VAT",
-                category_code: zugferd::VATCategoryCode::StandardRate,
-                basis_amount: Some(44.0),
-                rate_applicable_percent: Some(19.0),
-                exemption_reason: None,
-                exemption_reason_code: None,
-            },
-            specified_trade_settlement_line_monetary_summation: SpecifiedTradeSettlementLineMonetarySummation {
-                line_total_amount: (44.0*1.19),
-            },
-            specified_trade_allowance_charge: Vec::new(),
-        },
-    });
+    invoice_builder.add_line_item(
+        LineItemBuilder::new()
+            .product("Product 1", Some("1234567890123"))
+            .quantity(1.0)
+            .unit(UnitCode::Piece)
+            .net_price(100.0)
+            .vat(VATCategoryCode::StandardRate, Some(19.0))
+    ).expect("Line item incomplete");
+
+    invoice_builder.add_line_item(
+        LineItemBuilder::new()
+            .product("Product 2", Some("2546585465423"))
+            .description("This is an interesting second product.")
+            .quantity(44.0)
+            .unit(UnitCode::Piece)
+            .net_price(1.0)
+            .vat(VATCategoryCode::StandardRate, Some(19.0))
+    ).expect("Line item incomplete");
 
     //Convert the instance to an actual XML string
     let mut xml_string: String = String::new();